@@ -1,5 +1,5 @@
 use super::super::{
-    EvaluationFrame, Felt, FieldElement, TransitionConstraintDegree, CHIPLETS_OFFSET,
+    EvaluationFrame, Felt, FieldElement, TransitionConstraintDegree, VmProfile, CHIPLETS_OFFSET,
 };
 use crate::utils::{are_equal, binary_not, is_binary};
 use alloc::vec::Vec;
@@ -20,13 +20,23 @@ pub const CONSTRAINT_DEGREES: [usize; NUM_CONSTRAINTS] = [
     2, 3, 4, // Selector flags can only change from 0 -> 1.
 ];
 
+/// The degree of the constraint which excludes the bitwise chiplet from the trace when the chiplet
+/// is not included into the VM profile.
+pub const NO_BITWISE_CONSTRAINT_DEGREE: usize = 2;
+/// The degree of the constraint which excludes the memory chiplet from the trace when the chiplet
+/// is not included into the VM profile.
+pub const NO_MEMORY_CONSTRAINT_DEGREE: usize = 3;
+
 // PERIODIC COLUMNS
 // ================================================================================================
 
-/// Returns the set of periodic columns required by chiplets in the Chiplets module.
-pub fn get_periodic_column_values() -> Vec<Vec<Felt>> {
+/// Returns the set of periodic columns required by the chiplets included into the specified
+/// profile.
+pub fn get_periodic_column_values(profile: &VmProfile) -> Vec<Vec<Felt>> {
     let mut result = hasher::get_periodic_column_values();
-    result.append(&mut bitwise::get_periodic_column_values());
+    if profile.has_bitwise() {
+        result.append(&mut bitwise::get_periodic_column_values());
+    }
     result
 }
 
@@ -34,7 +44,10 @@ pub fn get_periodic_column_values() -> Vec<Vec<Felt>> {
 // ================================================================================================
 
 /// Builds the transition constraint degrees for the chiplets module and all chiplet components.
-pub fn get_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
+///
+/// The constraints of a chiplet which is not included into the specified profile are replaced
+/// with a single constraint excluding the chiplet from the trace.
+pub fn get_transition_constraint_degrees(profile: &VmProfile) -> Vec<TransitionConstraintDegree> {
     let mut degrees: Vec<TransitionConstraintDegree> = CONSTRAINT_DEGREES
         .iter()
         .map(|&degree| TransitionConstraintDegree::new(degree))
@@ -42,26 +55,45 @@ pub fn get_transition_constraint_degrees() -> Vec<TransitionConstraintDegree> {
 
     degrees.append(&mut hasher::get_transition_constraint_degrees());
 
-    degrees.append(&mut bitwise::get_transition_constraint_degrees());
+    if profile.has_bitwise() {
+        degrees.append(&mut bitwise::get_transition_constraint_degrees());
+    } else {
+        degrees.push(TransitionConstraintDegree::new(NO_BITWISE_CONSTRAINT_DEGREE));
+    }
 
-    degrees.append(&mut memory::get_transition_constraint_degrees());
+    if profile.has_memory() {
+        degrees.append(&mut memory::get_transition_constraint_degrees());
+    } else {
+        degrees.push(TransitionConstraintDegree::new(NO_MEMORY_CONSTRAINT_DEGREE));
+    }
 
     degrees
 }
 
-/// Returns the number of transition constraints for the chiplets.
-pub fn get_transition_constraint_count() -> usize {
-    NUM_CONSTRAINTS
-        + hasher::get_transition_constraint_count()
-        + bitwise::get_transition_constraint_count()
-        + memory::get_transition_constraint_count()
+/// Returns the number of transition constraints for the chiplets included into the specified
+/// profile.
+pub fn get_transition_constraint_count(profile: &VmProfile) -> usize {
+    let bitwise_count = if profile.has_bitwise() {
+        bitwise::get_transition_constraint_count()
+    } else {
+        1
+    };
+    let memory_count = if profile.has_memory() {
+        memory::get_transition_constraint_count()
+    } else {
+        1
+    };
+
+    NUM_CONSTRAINTS + hasher::get_transition_constraint_count() + bitwise_count + memory_count
 }
 
-/// Enforces constraints for the chiplets module and all chiplet components.
+/// Enforces constraints for the chiplets module and all chiplet components included into the
+/// specified profile.
 pub fn enforce_constraints<E: FieldElement<BaseField = Felt>>(
     frame: &EvaluationFrame<E>,
     periodic_values: &[E],
     result: &mut [E],
+    profile: &VmProfile,
 ) {
     // chiplets transition constraints
     enforce_selectors(frame, result);
@@ -77,16 +109,31 @@ pub fn enforce_constraints<E: FieldElement<BaseField = Felt>>(
     constraint_offset += hasher::get_transition_constraint_count();
 
     // bitwise transition constraints
-    bitwise::enforce_constraints(
-        frame,
-        &periodic_values[hasher::NUM_PERIODIC_COLUMNS..],
-        &mut result[constraint_offset..],
-        frame.bitwise_flag(),
-    );
-    constraint_offset += bitwise::get_transition_constraint_count();
+    if profile.has_bitwise() {
+        bitwise::enforce_constraints(
+            frame,
+            &periodic_values[hasher::NUM_PERIODIC_COLUMNS..],
+            &mut result[constraint_offset..],
+            frame.bitwise_flag(),
+        );
+        constraint_offset += bitwise::get_transition_constraint_count();
+    } else {
+        // no row of the trace may belong to the bitwise chiplet
+        result[constraint_offset] = frame.s(0) * binary_not(frame.s(1));
+        constraint_offset += 1;
+    }
 
     // memory transition constraints
-    memory::enforce_constraints(frame, &mut result[constraint_offset..], frame.memory_flag(false));
+    if profile.has_memory() {
+        memory::enforce_constraints(
+            frame,
+            &mut result[constraint_offset..],
+            frame.memory_flag(false),
+        );
+    } else {
+        // no row of the trace may belong to the memory chiplet
+        result[constraint_offset] = frame.memory_flag(true);
+    }
 }
 
 // TRANSITION CONSTRAINT HELPERS
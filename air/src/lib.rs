@@ -9,6 +9,7 @@ extern crate std;
 use alloc::vec::Vec;
 
 use vm_core::{
+    chiplets::hasher::Digest,
     utils::{ByteReader, ByteWriter, Deserializable, Serializable},
     ExtensionOf, ProgramInfo, StackInputs, StackOutputs, ONE, ZERO,
 };
@@ -36,8 +37,8 @@ use utils::TransitionConstraintRange;
 // ================================================================================================
 
 pub use errors::ExecutionOptionsError;
-pub use options::{ExecutionOptions, ProvingOptions};
-pub use proof::{ExecutionProof, HashFunction};
+pub use options::{ExecutionOptions, ProverMemoryBudget, ProvingOptions, VmProfile};
+pub use proof::{ExecutionProof, HashFunction, ProofMetadata};
 pub use vm_core::{
     utils::{DeserializationError, ToElements},
     Felt, FieldElement, StarkField,
@@ -52,6 +53,7 @@ pub struct ProcessorAir {
     context: AirContext<Felt>,
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
+    profile: VmProfile,
     constraint_ranges: TransitionConstraintRange,
 }
 
@@ -60,6 +62,11 @@ impl ProcessorAir {
     pub fn last_step(&self) -> usize {
         self.trace_length() - self.context().num_transition_exemptions()
     }
+
+    /// Returns the set of chiplets included into this AIR.
+    pub fn profile(&self) -> VmProfile {
+        self.profile
+    }
 }
 
 impl Air for ProcessorAir {
@@ -67,6 +74,8 @@ impl Air for ProcessorAir {
     type PublicInputs = PublicInputs;
 
     fn new(trace_info: TraceInfo, pub_inputs: PublicInputs, options: WinterProofOptions) -> Self {
+        let profile = pub_inputs.profile;
+
         // --- system -----------------------------------------------------------------------------
         let mut main_degrees = vec![
             TransitionConstraintDegree::new(1), // clk' = clk + 1
@@ -83,7 +92,7 @@ impl Air for ProcessorAir {
         let aux_degrees = range::get_aux_transition_constraint_degrees();
 
         // --- chiplets (hasher, bitwise, memory) -------------------------
-        let mut chiplets_degrees = chiplets::get_transition_constraint_degrees();
+        let mut chiplets_degrees = chiplets::get_transition_constraint_degrees(&profile);
         main_degrees.append(&mut chiplets_degrees);
 
         // Define the transition constraint ranges.
@@ -91,7 +100,7 @@ impl Air for ProcessorAir {
             1,
             stack::get_transition_constraint_count(),
             range::get_transition_constraint_count(),
-            chiplets::get_transition_constraint_count(),
+            chiplets::get_transition_constraint_count(&profile),
         );
 
         // Define the number of boundary constraints for the main execution trace segment.
@@ -117,6 +126,7 @@ impl Air for ProcessorAir {
             context,
             stack_inputs: pub_inputs.stack_inputs,
             stack_outputs: pub_inputs.stack_outputs,
+            profile,
             constraint_ranges,
         }
     }
@@ -126,7 +136,7 @@ impl Air for ProcessorAir {
 
     /// Returns a set of periodic columns for the ProcessorAir.
     fn get_periodic_column_values(&self) -> Vec<Vec<Felt>> {
-        chiplets::get_periodic_column_values()
+        chiplets::get_periodic_column_values(&self.profile)
     }
 
     // ASSERTIONS
@@ -229,6 +239,7 @@ impl Air for ProcessorAir {
             frame,
             periodic_values,
             select_result_range!(result, self.constraint_ranges.chiplets),
+            &self.profile,
         );
     }
 
@@ -255,11 +266,20 @@ impl Air for ProcessorAir {
 // PUBLIC INPUTS
 // ================================================================================================
 
+/// Public inputs of an execution proof.
+///
+/// The state of memory at the end of the execution is not a part of the public inputs. Binding
+/// memory outputs to them would require the AIR to connect the final values of the exported
+/// memory locations to the public inputs, and the verifier to process every exported word; a
+/// commitment supplied by the prover without such constraints would not be verified by the proof.
+/// Programs returning data via memory commit to it on the stack instead (see `std::output`).
 #[derive(Debug)]
 pub struct PublicInputs {
     program_info: ProgramInfo,
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
+    metadata_commitment: Option<Digest>,
+    profile: VmProfile,
 }
 
 impl PublicInputs {
@@ -272,15 +292,46 @@ impl PublicInputs {
             program_info,
             stack_inputs,
             stack_outputs,
+            metadata_commitment: None,
+            profile: VmProfile::default(),
         }
     }
+
+    /// Binds the specified proof metadata to these public inputs.
+    ///
+    /// The commitment to the metadata is appended to the public inputs, and thus, any change to
+    /// the metadata causes the proof verification to fail.
+    pub fn with_metadata(mut self, metadata: &ProofMetadata) -> Self {
+        self.metadata_commitment = Some(metadata.commitment());
+        self
+    }
+
+    /// Sets the [VmProfile] against which the proof is generated or verified.
+    ///
+    /// Profiles other than the full one are appended to the public inputs, and thus, a proof
+    /// cannot be verified against a profile other than the one it was generated with.
+    pub fn with_profile(mut self, profile: VmProfile) -> Self {
+        self.profile = profile;
+        self
+    }
 }
 
 impl vm_core::ToElements<Felt> for PublicInputs {
     fn to_elements(&self) -> Vec<Felt> {
         let mut result = self.program_info.to_elements();
-        result.append(&mut self.stack_inputs.to_elements());
+        // stack inputs beyond the top 16 slots are bound via a commitment followed by their count
+        result.extend_from_slice(self.stack_inputs.top());
+        if let Some(commitment) = self.stack_inputs.overflow_commitment() {
+            result.extend_from_slice(commitment.as_elements());
+            result.push(Felt::new(self.stack_inputs.overflow().len() as u64));
+        }
         result.append(&mut self.stack_outputs.to_elements());
+        if let Some(commitment) = self.metadata_commitment {
+            result.extend_from_slice(commitment.as_elements());
+        }
+        if !self.profile.is_full() {
+            result.push(Felt::from(self.profile.to_u8() as u32));
+        }
         result
     }
 }
@@ -293,6 +344,11 @@ impl Serializable for PublicInputs {
         self.program_info.write_into(target);
         self.stack_inputs.write_into(target);
         self.stack_outputs.write_into(target);
+        target.write_bool(self.metadata_commitment.is_some());
+        if let Some(commitment) = &self.metadata_commitment {
+            commitment.write_into(target);
+        }
+        self.profile.write_into(target);
     }
 }
 
@@ -301,11 +357,19 @@ impl Deserializable for PublicInputs {
         let program_info = ProgramInfo::read_from(source)?;
         let stack_inputs = StackInputs::read_from(source)?;
         let stack_outputs = StackOutputs::read_from(source)?;
+        let metadata_commitment = if source.read_bool()? {
+            Some(Digest::read_from(source)?)
+        } else {
+            None
+        };
+        let profile = VmProfile::read_from(source)?;
 
         Ok(PublicInputs {
             program_info,
             stack_inputs,
             stack_outputs,
+            metadata_commitment,
+            profile,
         })
     }
 }
@@ -1,16 +1,31 @@
+use alloc::string::{String, ToString};
+
 use super::{
-    trace::MIN_TRACE_LEN, ExecutionOptionsError, FieldExtension, HashFunction, WinterProofOptions,
+    proof::read_string, trace::MIN_TRACE_LEN, ExecutionOptionsError, FieldExtension, HashFunction,
+    WinterProofOptions,
+};
+use vm_core::{
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Felt, Word,
 };
 
 // PROVING OPTIONS
 // ================================================================================================
 
 /// A set of parameters specifying how Miden VM execution proofs are to be generated.
+///
+/// None of the options make proofs zero-knowledge: the underlying STARK prover does not salt its
+/// commitments nor randomize the trace and constraint composition polynomials, and thus, a proof
+/// may leak information about the execution trace, including the values of secret inputs.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct ProvingOptions {
     exec_options: ExecutionOptions,
     proof_options: WinterProofOptions,
     hash_fn: HashFunction,
+    embed_metadata: bool,
+    metadata_label: Option<String>,
+    memory_budget: Option<ProverMemoryBudget>,
+    seed: Option<Word>,
 }
 
 impl ProvingOptions {
@@ -33,6 +48,18 @@ impl ProvingOptions {
     pub const RECURSIVE_128_BITS: WinterProofOptions =
         WinterProofOptions::new(27, 16, 21, FieldExtension::Cubic, 4, 7);
 
+    /// Proof parameters for 128-bit proven security in non-recursive context.
+    ///
+    /// The security does not rely on grinding; instead, random values are drawn from the cubic
+    /// extension of the base field, and the number of queries is large enough for the query phase
+    /// to provide 128 bits of proven security on its own.
+    pub const REGULAR_128_BITS_PROVEN: WinterProofOptions =
+        WinterProofOptions::new(80, 16, 0, FieldExtension::Cubic, 8, 255);
+
+    /// Proof parameters for 128-bit proven security in recursive context.
+    pub const RECURSIVE_128_BITS_PROVEN: WinterProofOptions =
+        WinterProofOptions::new(80, 16, 0, FieldExtension::Cubic, 4, 7);
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
@@ -59,6 +86,10 @@ impl ProvingOptions {
             exec_options,
             proof_options,
             hash_fn,
+            embed_metadata: false,
+            metadata_label: None,
+            memory_budget: None,
+            seed: None,
         }
     }
 
@@ -73,12 +104,20 @@ impl ProvingOptions {
                 exec_options: ExecutionOptions::default(),
                 proof_options: Self::RECURSIVE_96_BITS,
                 hash_fn: HashFunction::Rpo256,
+                embed_metadata: false,
+                metadata_label: None,
+                memory_budget: None,
+                seed: None,
             }
         } else {
             Self {
                 exec_options: ExecutionOptions::default(),
                 proof_options: Self::REGULAR_96_BITS,
                 hash_fn: HashFunction::Blake3_192,
+                embed_metadata: false,
+                metadata_label: None,
+                memory_budget: None,
+                seed: None,
             }
         }
     }
@@ -94,16 +133,52 @@ impl ProvingOptions {
                 exec_options: ExecutionOptions::default(),
                 proof_options: Self::RECURSIVE_128_BITS,
                 hash_fn: HashFunction::Rpo256,
+                embed_metadata: false,
+                metadata_label: None,
+                memory_budget: None,
+                seed: None,
             }
         } else {
             Self {
                 exec_options: ExecutionOptions::default(),
                 proof_options: Self::REGULAR_128_BITS,
                 hash_fn: HashFunction::Blake3_256,
+                embed_metadata: false,
+                metadata_label: None,
+                memory_budget: None,
+                seed: None,
             }
         }
     }
 
+    /// Creates a new preset instance of [ProvingOptions] targeting 128-bit proven security level.
+    ///
+    /// Unlike the parameters used by [ProvingOptions::with_128_bit_security()], these parameters
+    /// do not rely on grinding or on conjectures about the soundness of FRI, which makes them
+    /// suitable for deployments with strict compliance requirements. The cost is roughly three
+    /// times as many queries, and thus, larger proofs and slower verification. Note that the
+    /// proven security slowly decreases as the length of the execution trace grows.
+    ///
+    /// If `recursive` flag is set to true, proofs will be generated using an arithmetization-
+    /// friendly hash function (RPO). Such proofs are well-suited for recursive proof verification,
+    /// but may take significantly longer to generate.
+    pub fn with_128bit_proven(recursive: bool) -> Self {
+        let (proof_options, hash_fn) = if recursive {
+            (Self::RECURSIVE_128_BITS_PROVEN, HashFunction::Rpo256)
+        } else {
+            (Self::REGULAR_128_BITS_PROVEN, HashFunction::Blake3_256)
+        };
+        Self {
+            exec_options: ExecutionOptions::default(),
+            proof_options,
+            hash_fn,
+            embed_metadata: false,
+            metadata_label: None,
+            memory_budget: None,
+            seed: None,
+        }
+    }
+
     /// Sets [ExecutionOptions] for this [ProvingOptions].
     ///
     /// This sets the maximum number of cycles a program is allowed to execute as well as
@@ -113,6 +188,42 @@ impl ProvingOptions {
         self
     }
 
+    /// Instructs the prover to embed [ProofMetadata](super::ProofMetadata) into generated proofs.
+    ///
+    /// If `label` is provided, it is included into the metadata as a user-defined label of the
+    /// proof.
+    pub fn with_metadata(mut self, label: Option<String>) -> Self {
+        self.embed_metadata = true;
+        self.metadata_label = label;
+        self
+    }
+
+    /// Sets the [ProverMemoryBudget] for proof generation.
+    ///
+    /// Proof generation fails early if the memory it is estimated to require exceeds the budget.
+    pub fn with_memory_budget(mut self, budget: ProverMemoryBudget) -> Self {
+        self.memory_budget = Some(budget);
+        self
+    }
+
+    /// Makes proof generation reproducible by deriving all prover-side randomness from the
+    /// specified seed.
+    ///
+    /// Proofs generated with the same seed for the same program, inputs, and options are
+    /// byte-identical, regardless of the machine they are generated on. This allows redundant
+    /// provers to cross-check their proofs (e.g., to reach consensus on a proof). Specifically:
+    /// - The random values injected into the last rows of the execution trace are drawn from a
+    ///   random coin seeded with the specified seed, rather than with the program hash.
+    /// - The timestamp in the proof metadata, if any, is set to zero.
+    ///
+    /// Note that when the prover is built with the `concurrent` feature, the proof-of-work nonce
+    /// is searched for in parallel, and the nonce which is found first depends on thread
+    /// scheduling. Thus, proofs are reproducible only if the prover is built without this feature.
+    pub fn with_seed(mut self, seed: Word) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -125,6 +236,26 @@ impl ProvingOptions {
     pub const fn execution_options(&self) -> &ExecutionOptions {
         &self.exec_options
     }
+
+    /// Returns true if proof metadata is to be embedded into generated proofs.
+    pub const fn embed_metadata(&self) -> bool {
+        self.embed_metadata
+    }
+
+    /// Returns the label to be included into the proof metadata, if any.
+    pub fn metadata_label(&self) -> Option<&str> {
+        self.metadata_label.as_deref()
+    }
+
+    /// Returns the memory budget for proof generation, if any.
+    pub const fn memory_budget(&self) -> Option<&ProverMemoryBudget> {
+        self.memory_budget.as_ref()
+    }
+
+    /// Returns the seed from which all prover-side randomness is derived, if any.
+    pub const fn seed(&self) -> Option<&Word> {
+        self.seed.as_ref()
+    }
 }
 
 impl Default for ProvingOptions {
@@ -146,12 +277,16 @@ impl From<ProvingOptions> for WinterProofOptions {
 ///
 /// - `max_cycles` specifies the maximum number of cycles a program is allowed to execute.
 /// - `expected_cycles` specifies the number of cycles a program is expected to execute.
+/// - `profile` specifies the set of chiplets which programs are allowed to use.
+/// - `stack_depth_warning` specifies the stack depth above which the VM emits a warning.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ExecutionOptions {
     max_cycles: u32,
     expected_cycles: u32,
     enable_tracing: bool,
     enable_debugging: bool,
+    profile: VmProfile,
+    stack_depth_warning: Option<u32>,
 }
 
 impl Default for ExecutionOptions {
@@ -161,6 +296,8 @@ impl Default for ExecutionOptions {
             expected_cycles: MIN_TRACE_LEN as u32,
             enable_tracing: false,
             enable_debugging: false,
+            profile: VmProfile::default(),
+            stack_depth_warning: None,
         }
     }
 }
@@ -194,6 +331,8 @@ impl ExecutionOptions {
             expected_cycles,
             enable_tracing,
             enable_debugging: false,
+            profile: VmProfile::default(),
+            stack_depth_warning: None,
         })
     }
 
@@ -214,6 +353,27 @@ impl ExecutionOptions {
         self
     }
 
+    /// Restricts the set of chiplets which programs are allowed to use to the ones enabled in the
+    /// specified [VmProfile].
+    ///
+    /// Execution of an operation which requires a disabled chiplet fails, and proofs of the
+    /// execution are generated against the AIR of the specified profile.
+    pub fn with_profile(mut self, profile: VmProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Makes the VM emit a warning (via the `tracing` crate) the first time the depth of the stack
+    /// exceeds the specified number of items.
+    ///
+    /// Stack items beyond the top 16 slots are kept in the overflow table; programs which keep
+    /// many items on the stack execute slower and produce larger auxiliary traces, but otherwise
+    /// behave correctly.
+    pub fn with_stack_depth_warning(mut self, threshold: u32) -> Self {
+        self.stack_depth_warning = Some(threshold);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -240,4 +400,335 @@ impl ExecutionOptions {
     pub fn enable_debugging(&self) -> bool {
         self.enable_debugging
     }
+
+    /// Returns the set of chiplets which programs are allowed to use.
+    pub fn profile(&self) -> VmProfile {
+        self.profile
+    }
+
+    /// Returns the stack depth above which the VM emits a warning, if any.
+    pub fn stack_depth_warning(&self) -> Option<u32> {
+        self.stack_depth_warning
+    }
+}
+
+// VM PROFILE
+// ================================================================================================
+
+/// A set of optional chiplets which are included into the VM.
+///
+/// The hasher and the kernel ROM chiplets are required for executing any program, and thus, they
+/// are always included. The bitwise and the memory chiplets can be excluded: programs executed
+/// under a profile without a chiplet cannot use the operations which rely on it, and in exchange,
+/// the constraints of the chiplet (together with the periodic columns it requires) are removed
+/// from the AIR. This reduces the number of constraints evaluated by the prover and the verifier
+/// for deployments which never use the excluded chiplets.
+///
+/// Excluding chiplets does not change the layout of the execution trace: all chiplets share the
+/// columns of the chiplets segment, the width of which is determined by the hasher chiplet. Thus,
+/// the trace has the same width under all profiles.
+///
+/// By default, all chiplets are included.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VmProfile {
+    bitwise: bool,
+    memory: bool,
+}
+
+impl VmProfile {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Bit of the encoded profile which is set when the bitwise chiplet is excluded.
+    const NO_BITWISE: u8 = 0b01;
+
+    /// Bit of the encoded profile which is set when the memory chiplet is excluded.
+    const NO_MEMORY: u8 = 0b10;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a profile which includes all chiplets.
+    pub const fn full() -> Self {
+        Self {
+            bitwise: true,
+            memory: true,
+        }
+    }
+
+    /// Excludes the bitwise chiplet, and thus, the `U32AND` and `U32XOR` operations from this
+    /// profile.
+    pub const fn without_bitwise(mut self) -> Self {
+        self.bitwise = false;
+        self
+    }
+
+    /// Excludes the memory chiplet, and thus, all operations which access memory from this
+    /// profile.
+    pub const fn without_memory(mut self) -> Self {
+        self.memory = false;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if the bitwise chiplet is included into this profile.
+    pub const fn has_bitwise(&self) -> bool {
+        self.bitwise
+    }
+
+    /// Returns true if the memory chiplet is included into this profile.
+    pub const fn has_memory(&self) -> bool {
+        self.memory
+    }
+
+    /// Returns true if all chiplets are included into this profile.
+    pub const fn is_full(&self) -> bool {
+        self.bitwise && self.memory
+    }
+
+    // ENCODING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns this profile encoded as a bit mask of the excluded chiplets.
+    ///
+    /// The full profile is encoded as zero.
+    pub const fn to_u8(&self) -> u8 {
+        let mut mask = 0;
+        if !self.bitwise {
+            mask |= Self::NO_BITWISE;
+        }
+        if !self.memory {
+            mask |= Self::NO_MEMORY;
+        }
+        mask
+    }
+
+    /// Returns a profile decoded from a bit mask of the excluded chiplets.
+    ///
+    /// # Errors
+    /// Returns an error if the mask has bits set which do not correspond to any chiplet.
+    pub fn from_u8(mask: u8) -> Result<Self, DeserializationError> {
+        if mask & !(Self::NO_BITWISE | Self::NO_MEMORY) != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid VM profile encoding {mask:#04x}"
+            )));
+        }
+        Ok(Self {
+            bitwise: mask & Self::NO_BITWISE == 0,
+            memory: mask & Self::NO_MEMORY == 0,
+        })
+    }
+}
+
+impl Default for VmProfile {
+    fn default() -> Self {
+        Self::full()
+    }
+}
+
+// PROVER MEMORY BUDGET
+// ================================================================================================
+
+/// Limits on the memory used by the prover.
+///
+/// - `max_bytes` is the maximum amount of memory proof generation is allowed to require, as
+///   estimated by the prover from the length of the execution trace and the proof options before
+///   the proof is generated.
+/// - `max_pooled_bytes` is the maximum amount of memory retained between proofs by the pool of
+///   execution trace buffers. Buffers of traces which are dropped are returned into the pool, and
+///   are reused when the next execution trace is built, which avoids repeatedly allocating large
+///   column buffers when many proofs are generated by the same process. By default, no memory is
+///   retained.
+/// - `memory_mapped_trace` specifies whether the low-degree extension of the main trace segment is
+///   stored in a memory-mapped scratch file rather than in memory. This allows machines with
+///   modest amounts of RAM to prove larger traces at the cost of lower throughput. Memory-mapped
+///   traces are supported only when the prover is compiled with the `mmap` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProverMemoryBudget {
+    max_bytes: usize,
+    max_pooled_bytes: usize,
+    memory_mapped_trace: bool,
+}
+
+impl ProverMemoryBudget {
+    /// Returns a new budget which allows proof generation to require at most `max_bytes` of
+    /// memory.
+    pub const fn new(max_bytes: usize) -> Self {
+        Self {
+            max_bytes,
+            max_pooled_bytes: 0,
+            memory_mapped_trace: false,
+        }
+    }
+
+    /// Returns a new budget which does not limit the memory required by proof generation.
+    pub const fn unlimited() -> Self {
+        Self::new(usize::MAX)
+    }
+
+    /// Allows the pool of execution trace buffers to retain at most `max_pooled_bytes` of memory
+    /// between proofs.
+    pub const fn with_buffer_pool(mut self, max_pooled_bytes: usize) -> Self {
+        self.max_pooled_bytes = max_pooled_bytes;
+        self
+    }
+
+    /// Stores the low-degree extension of the main trace segment in a memory-mapped scratch file
+    /// rather than in memory.
+    pub const fn with_memory_mapped_trace(mut self) -> Self {
+        self.memory_mapped_trace = true;
+        self
+    }
+
+    /// Returns the maximum amount of memory proof generation is allowed to require.
+    pub const fn max_bytes(&self) -> usize {
+        self.max_bytes
+    }
+
+    /// Returns the maximum amount of memory retained by the pool of execution trace buffers.
+    pub const fn max_pooled_bytes(&self) -> usize {
+        self.max_pooled_bytes
+    }
+
+    /// Returns true if the low-degree extension of the main trace segment is to be stored in a
+    /// memory-mapped scratch file.
+    pub const fn memory_mapped_trace(&self) -> bool {
+        self.memory_mapped_trace
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ProvingOptions {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.exec_options.write_into(target);
+        self.proof_options.write_into(target);
+        self.hash_fn.write_into(target);
+        target.write_bool(self.embed_metadata);
+        target.write_bool(self.metadata_label.is_some());
+        if let Some(label) = &self.metadata_label {
+            target.write_usize(label.len());
+            target.write_bytes(label.as_bytes());
+        }
+        target.write_bool(self.memory_budget.is_some());
+        if let Some(budget) = &self.memory_budget {
+            budget.write_into(target);
+        }
+        target.write_bool(self.seed.is_some());
+        if let Some(seed) = &self.seed {
+            target.write_many(seed);
+        }
+    }
+}
+
+impl Deserializable for ProvingOptions {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let exec_options = ExecutionOptions::read_from(source)?;
+        let proof_options = WinterProofOptions::read_from(source)?;
+        let hash_fn = HashFunction::read_from(source)?;
+        let embed_metadata = source.read_bool()?;
+        let metadata_label = if source.read_bool()? {
+            Some(read_string(source)?)
+        } else {
+            None
+        };
+        let memory_budget = if source.read_bool()? {
+            Some(ProverMemoryBudget::read_from(source)?)
+        } else {
+            None
+        };
+        let seed = if source.read_bool()? {
+            let seed = source.read_many::<Felt>(4)?;
+            Some(seed.try_into().expect("four elements were read"))
+        } else {
+            None
+        };
+
+        Ok(Self {
+            exec_options,
+            proof_options,
+            hash_fn,
+            embed_metadata,
+            metadata_label,
+            memory_budget,
+            seed,
+        })
+    }
+}
+
+impl Serializable for ProverMemoryBudget {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.max_bytes as u64);
+        target.write_u64(self.max_pooled_bytes as u64);
+        target.write_bool(self.memory_mapped_trace);
+    }
+}
+
+impl Deserializable for ProverMemoryBudget {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let max_bytes = source.read_u64()?.try_into().unwrap_or(usize::MAX);
+        let max_pooled_bytes = source.read_u64()?.try_into().unwrap_or(usize::MAX);
+        let memory_mapped_trace = source.read_bool()?;
+        Ok(Self {
+            max_bytes,
+            max_pooled_bytes,
+            memory_mapped_trace,
+        })
+    }
+}
+
+impl Serializable for ExecutionOptions {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.max_cycles);
+        target.write_u32(self.expected_cycles);
+        target.write_bool(self.enable_tracing);
+        target.write_bool(self.enable_debugging);
+        self.profile.write_into(target);
+        target.write_bool(self.stack_depth_warning.is_some());
+        if let Some(threshold) = self.stack_depth_warning {
+            target.write_u32(threshold);
+        }
+    }
+}
+
+impl Deserializable for ExecutionOptions {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let max_cycles = source.read_u32()?;
+        let expected_cycles = source.read_u32()?;
+        let enable_tracing = source.read_bool()?;
+        let enable_debugging = source.read_bool()?;
+        let profile = VmProfile::read_from(source)?;
+        let stack_depth_warning = if source.read_bool()? {
+            Some(source.read_u32()?)
+        } else {
+            None
+        };
+
+        // options are validated in the same way as when they are constructed
+        let mut options = Self::new(Some(max_cycles), expected_cycles, enable_tracing)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?
+            .with_profile(profile);
+        if enable_debugging {
+            options = options.with_debugging();
+        }
+        if let Some(threshold) = stack_depth_warning {
+            options = options.with_stack_depth_warning(threshold);
+        }
+        Ok(options)
+    }
+}
+
+impl Serializable for VmProfile {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.to_u8());
+    }
+}
+
+impl Deserializable for VmProfile {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Self::from_u8(source.read_u8()?)
+    }
 }
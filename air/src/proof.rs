@@ -1,9 +1,16 @@
-use alloc::vec::Vec;
+use super::{VmProfile, WinterProofOptions};
+use alloc::{string::String, vec::Vec};
 use vm_core::{
+    chiplets::hasher::Digest,
     crypto::hash::{Blake3_192, Blake3_256, Hasher, Rpo256},
-    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    utils::{
+        ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
+    },
+};
+use winter_air::{
+    proof::{Context, StarkProof},
+    TraceInfo,
 };
-use winter_air::proof::StarkProof;
 
 // EXECUTION PROOF
 // ================================================================================================
@@ -12,10 +19,18 @@ use winter_air::proof::StarkProof;
 ///
 /// The proof encodes the proof itself as well as STARK protocol parameters used to generate the
 /// proof. However, the proof does not contain public inputs needed to verify the proof.
+///
+/// Optionally, the proof may carry [ProofMetadata] describing the circumstances under which it
+/// was generated.
+///
+/// The proof also records the [VmProfile] under which the program was executed, as the profile
+/// determines the AIR against which the proof is verified.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ExecutionProof {
     pub proof: StarkProof,
     pub hash_fn: HashFunction,
+    pub metadata: Option<ProofMetadata>,
+    pub profile: VmProfile,
 }
 
 impl ExecutionProof {
@@ -25,7 +40,27 @@ impl ExecutionProof {
     /// Creates a new instance of [ExecutionProof] from the specified STARK proof and hash
     /// function.
     pub const fn new(proof: StarkProof, hash_fn: HashFunction) -> Self {
-        Self { proof, hash_fn }
+        Self {
+            proof,
+            hash_fn,
+            metadata: None,
+            profile: VmProfile::full(),
+        }
+    }
+
+    /// Attaches the specified metadata to this proof.
+    ///
+    /// The metadata must be the one the proof was generated with: its commitment is a part of the
+    /// public inputs of the proof, and thus, a proof with any other metadata fails verification.
+    pub fn with_metadata(mut self, metadata: ProofMetadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Sets the [VmProfile] under which the proven program was executed.
+    pub fn with_profile(mut self, profile: VmProfile) -> Self {
+        self.profile = profile;
+        self
     }
 
     // PUBLIC ACCESSORS
@@ -50,6 +85,16 @@ impl ExecutionProof {
         }
     }
 
+    /// Returns the metadata of this proof, if the proof was generated with metadata.
+    pub const fn metadata(&self) -> Option<&ProofMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Returns the [VmProfile] under which the proven program was executed.
+    pub const fn profile(&self) -> VmProfile {
+        self.profile
+    }
+
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
@@ -58,7 +103,13 @@ impl ExecutionProof {
         let mut bytes = self.proof.to_bytes();
         assert!(!bytes.is_empty(), "invalid STARK proof");
         // TODO: ideally we should write hash function into the proof first to avoid reallocations
-        bytes.insert(0, self.hash_fn as u8);
+        // the profile is encoded in the upper bits of the first byte so that proofs generated
+        // under the full profile keep their encoding
+        bytes.insert(0, self.hash_fn as u8 | (self.profile.to_u8() << 4));
+        // metadata is appended to the end so that proofs without metadata keep their encoding
+        if let Some(metadata) = &self.metadata {
+            metadata.write_into(&mut bytes);
+        }
         bytes
     }
 
@@ -67,9 +118,35 @@ impl ExecutionProof {
         if source.len() < 2 {
             return Err(DeserializationError::UnexpectedEOF);
         }
-        let hash_fn = HashFunction::try_from(source[0])?;
-        let proof = StarkProof::from_bytes(&source[1..])?;
-        Ok(Self::new(proof, hash_fn))
+        let mut source = SliceReader::new(source);
+        let first_byte = source.read_u8()?;
+        let hash_fn = HashFunction::try_from(first_byte & 0x0f)?;
+        let profile = VmProfile::from_u8(first_byte >> 4)?;
+        let proof = StarkProof::read_from(&mut source)?;
+        let metadata = if source.has_more_bytes() {
+            Some(ProofMetadata::read_from(&mut source)?)
+        } else {
+            None
+        };
+        Ok(Self {
+            proof,
+            hash_fn,
+            metadata,
+            profile,
+        })
+    }
+
+    /// Reads the execution trace info and the STARK protocol parameters from the header of the
+    /// serialized proof, without deserializing the rest of the proof.
+    pub fn read_header(
+        source: &[u8],
+    ) -> Result<(TraceInfo, WinterProofOptions), DeserializationError> {
+        let mut source = SliceReader::new(source);
+        let first_byte = source.read_u8()?;
+        HashFunction::try_from(first_byte & 0x0f)?;
+        VmProfile::from_u8(first_byte >> 4)?;
+        let context = Context::read_from(&mut source)?;
+        Ok((context.get_trace_info(), context.options().clone()))
     }
 
     // DESTRUCTOR
@@ -81,6 +158,95 @@ impl ExecutionProof {
     }
 }
 
+// PROOF METADATA
+// ================================================================================================
+
+/// Metadata describing how an [ExecutionProof] was generated.
+///
+/// The metadata contains the hash of the proven program, the version of the prover, the options
+/// used to generate the proof, the time at which the proof was generated, and an optional
+/// user-defined label. This allows auditing archived proofs without keeping track of this data
+/// separately.
+///
+/// The metadata is authenticated: its commitment (see [ProofMetadata::commitment()]) is included
+/// into the public inputs of the proof, and the verifier rejects proofs whose metadata does not
+/// match the verified program or the parameters of the STARK proof.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofMetadata {
+    program_hash: Digest,
+    version: String,
+    hash_fn: HashFunction,
+    options: WinterProofOptions,
+    timestamp: u64,
+    label: Option<String>,
+}
+
+impl ProofMetadata {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [ProofMetadata] instantiated from the specified parameters.
+    ///
+    /// `timestamp` is expected to be the number of seconds since the Unix epoch, or zero if the
+    /// time of proof generation is unknown.
+    pub fn new(
+        program_hash: Digest,
+        version: String,
+        hash_fn: HashFunction,
+        options: WinterProofOptions,
+        timestamp: u64,
+        label: Option<String>,
+    ) -> Self {
+        Self {
+            program_hash,
+            version,
+            hash_fn,
+            options,
+            timestamp,
+            label,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the hash of the program the execution of which was proven.
+    pub const fn program_hash(&self) -> &Digest {
+        &self.program_hash
+    }
+
+    /// Returns the version of the prover which generated the proof.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns the hash function used to generate the proof.
+    pub const fn hash_fn(&self) -> HashFunction {
+        self.hash_fn
+    }
+
+    /// Returns the STARK protocol parameters used to generate the proof.
+    pub const fn options(&self) -> &WinterProofOptions {
+        &self.options
+    }
+
+    /// Returns the number of seconds since the Unix epoch at which the proof was generated, or
+    /// zero if the time is unknown.
+    pub const fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Returns the user-defined label of the proof, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+
+    /// Returns a commitment to this metadata, computed as an RPO hash of its serialized form.
+    pub fn commitment(&self) -> Digest {
+        Rpo256::hash(&self.to_bytes())
+    }
+}
+
 // HASH FUNCTION
 // ================================================================================================
 
@@ -143,10 +309,55 @@ impl Deserializable for HashFunction {
     }
 }
 
+impl Serializable for ProofMetadata {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.program_hash.write_into(target);
+        target.write_usize(self.version.len());
+        target.write_bytes(self.version.as_bytes());
+        self.hash_fn.write_into(target);
+        self.options.write_into(target);
+        target.write_u64(self.timestamp);
+        target.write_bool(self.label.is_some());
+        if let Some(label) = &self.label {
+            target.write_usize(label.len());
+            target.write_bytes(label.as_bytes());
+        }
+    }
+}
+
+impl Deserializable for ProofMetadata {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let program_hash = Digest::read_from(source)?;
+        let version = read_string(source)?;
+        let hash_fn = HashFunction::read_from(source)?;
+        let options = WinterProofOptions::read_from(source)?;
+        let timestamp = source.read_u64()?;
+        let label = if source.read_bool()? {
+            Some(read_string(source)?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            program_hash,
+            version,
+            hash_fn,
+            options,
+            timestamp,
+            label,
+        })
+    }
+}
+
 impl Serializable for ExecutionProof {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.proof.write_into(target);
         self.hash_fn.write_into(target);
+        target.write_bool(self.metadata.is_some());
+        if let Some(metadata) = &self.metadata {
+            metadata.write_into(target);
+        }
+        self.profile.write_into(target);
     }
 }
 
@@ -154,7 +365,28 @@ impl Deserializable for ExecutionProof {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let proof = StarkProof::read_from(source)?;
         let hash_fn = HashFunction::read_from(source)?;
+        let metadata = if source.read_bool()? {
+            Some(ProofMetadata::read_from(source)?)
+        } else {
+            None
+        };
+        let profile = VmProfile::read_from(source)?;
 
-        Ok(ExecutionProof { proof, hash_fn })
+        Ok(ExecutionProof {
+            proof,
+            hash_fn,
+            metadata,
+            profile,
+        })
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads a UTF-8 string prefixed with its length from the specified source.
+pub(crate) fn read_string<R: ByteReader>(source: &mut R) -> Result<String, DeserializationError> {
+    let len = source.read_usize()?;
+    let bytes = source.read_vec(len)?;
+    String::from_utf8(bytes).map_err(|err| DeserializationError::InvalidValue(format!("{err}")))
+}
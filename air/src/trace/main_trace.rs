@@ -52,6 +52,11 @@ impl MainTrace {
         self.columns.num_rows()
     }
 
+    /// Consumes this trace and returns its columns.
+    pub fn into_columns(self) -> Vec<Vec<Felt>> {
+        self.columns.into_columns()
+    }
+
     #[cfg(any(test, feature = "internals"))]
     pub fn get_column_range(&self, range: Range<usize>) -> Vec<Vec<Felt>> {
         range.fold(vec![], |mut acc, col_idx| {
@@ -0,0 +1,100 @@
+use miden_assembly::ast::{AstSerdeOptions, ModuleAst, ProgramAst};
+use proc_macro::{TokenStream, TokenTree};
+
+// MACROS
+// ================================================================================================
+
+/// Builds a [ProgramAst] from Miden assembly code embedded in Rust source.
+///
+/// The code is parsed when the macro is expanded; thus, invalid instruction names, malformed
+/// immediate values, and any other parsing errors are reported as compilation errors. The macro
+/// expands into an expression of type `ProgramAst`. For example:
+///
+/// ```ignore
+/// let program = masm! {
+///     use.std::math::u64
+///
+///     begin
+///         push.1.2 push.3.4
+///         exec.u64::wrapping_add
+///     end
+/// };
+/// ```
+///
+/// Since the code must consist of valid Rust tokens, comments must be written using `//` rather
+/// than `#`. The expanded code refers to the `miden_assembly` crate, which must be a dependency of
+/// the crate using the macro.
+#[proc_macro]
+pub fn masm(input: TokenStream) -> TokenStream {
+    expand(input, |source| {
+        let ast = ProgramAst::parse(source).map_err(|err| err.to_string())?;
+        let bytes = ast.to_bytes(AstSerdeOptions::new(true));
+        Ok(format!(
+            "::miden_assembly::ast::ProgramAst::from_bytes(&{bytes:?}).expect(\"invalid program\")"
+        ))
+    })
+}
+
+/// Builds a [ModuleAst] from Miden assembly code embedded in Rust source.
+///
+/// This is the library module counterpart of the [masm!] macro: the code is parsed when the macro
+/// is expanded, and the macro expands into an expression of type `ModuleAst`.
+#[proc_macro]
+pub fn masm_module(input: TokenStream) -> TokenStream {
+    expand(input, |source| {
+        let ast = ModuleAst::parse(source).map_err(|err| err.to_string())?;
+        let bytes = ast.to_bytes(AstSerdeOptions::new(true));
+        Ok(format!(
+            "::miden_assembly::ast::ModuleAst::from_bytes(&{bytes:?}).expect(\"invalid module\")"
+        ))
+    })
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Converts the macro input into Miden assembly source and passes it to `build`, which returns
+/// the code of the expanded expression. Errors are expanded into a `compile_error!` invocation.
+fn expand<F>(input: TokenStream, build: F) -> TokenStream
+where
+    F: FnOnce(&str) -> Result<String, String>,
+{
+    let code = match to_source(input).and_then(|source| build(&source)) {
+        Ok(code) => code,
+        Err(err) => format!("compile_error!({:?})", format!("invalid Miden assembly: {err}")),
+    };
+    code.parse().expect("failed to build macro expansion")
+}
+
+/// Reconstructs Miden assembly source from the provided tokens.
+///
+/// Rust tokenization splits Miden assembly tokens on punctuation characters (e.g., `push.1`
+/// becomes `push`, `.`, and `1`). Since Miden assembly tokens are delimited by whitespace, such
+/// punctuation is glued to the surrounding tokens, while all other tokens are separated by a
+/// single space.
+fn to_source(input: TokenStream) -> Result<String, String> {
+    let mut source = String::new();
+    let mut glue_next = true;
+
+    for token in input {
+        let (text, is_glue) = match token {
+            TokenTree::Group(group) => {
+                return Err(format!("unexpected delimited group `{group}`"));
+            }
+            TokenTree::Punct(punct) => {
+                let ch = punct.as_char();
+                (ch.to_string(), matches!(ch, '.' | ':' | '=' | '-' | '>'))
+            }
+            TokenTree::Ident(ident) => (ident.to_string(), false),
+            TokenTree::Literal(literal) => (literal.to_string(), false),
+        };
+
+        if !glue_next && !is_glue {
+            source.push(' ');
+        }
+        source.push_str(&text);
+        glue_next = is_glue;
+    }
+
+    Ok(source)
+}
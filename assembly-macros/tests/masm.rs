@@ -0,0 +1,72 @@
+use miden_assembly::{
+    ast::{AstSerdeOptions, ModuleAst, ProgramAst},
+    Assembler,
+};
+use miden_assembly_macros::{masm, masm_module};
+
+#[test]
+fn masm_program() {
+    let program = masm! {
+        const.TWO=2
+
+        proc.foo.1
+            loc_store.0
+            push.TWO add
+            u32assert.err=42
+        end
+
+        begin
+            push.3 dup
+            if.true
+                exec.foo
+            else
+                repeat.2 drop end
+            end
+            u32wrapping_add.16 u32shr.3
+        end
+    };
+
+    let expected = ProgramAst::parse(
+        "const.TWO=2
+        proc.foo.1 loc_store.0 push.TWO add u32assert.err=42 end
+        begin
+            push.3 dup
+            if.true exec.foo else repeat.2 drop end end
+            u32wrapping_add.16 u32shr.3
+        end",
+    )
+    .unwrap();
+
+    let options = AstSerdeOptions::new(true);
+    assert_eq!(program.to_bytes(options), expected.to_bytes(options));
+
+    // the program built by the macro can be compiled as usual
+    let assembler = Assembler::default();
+    assert_eq!(
+        assembler.compile_ast(&program).unwrap().hash(),
+        assembler.compile_ast(&expected).unwrap().hash()
+    );
+}
+
+#[test]
+fn masm_library_module() {
+    let module = masm_module! {
+        use.std::math::u64
+
+        export.add
+            exec.u64::wrapping_add
+        end
+
+        export.u64::checked_sub->sub
+    };
+
+    let expected = ModuleAst::parse(
+        "use.std::math::u64
+        export.add exec.u64::wrapping_add end
+        export.u64::checked_sub->sub",
+    )
+    .unwrap();
+
+    let options = AstSerdeOptions::new(true);
+    assert_eq!(module.to_bytes(options), expected.to_bytes(options));
+}
@@ -1,8 +1,8 @@
 use super::{
-    AssemblyError, CallSet, CodeBlock, CodeBlockTable, Kernel, LibraryPath, NamedProcedure,
-    Procedure, ProcedureCache, ProcedureId, ProcedureName, RpoDigest,
+    AssemblyError, CallSet, CodeBlock, CodeBlockTable, Kernel, KernelMetadata, LibraryPath,
+    NamedProcedure, Procedure, ProcedureCache, ProcedureId, ProcedureName, RpoDigest,
 };
-use crate::ast::{ModuleAst, ProgramAst};
+use crate::ast::{ModuleAst, ProcedureAttributes, ProgramAst};
 use alloc::collections::BTreeMap;
 use alloc::string::ToString;
 use alloc::vec::Vec;
@@ -19,7 +19,7 @@ use alloc::vec::Vec;
 pub struct AssemblyContext {
     module_stack: Vec<ModuleContext>,
     is_kernel: bool,
-    kernel: Option<Kernel>,
+    kernel: Option<KernelMetadata>,
     allow_phantom_calls: bool,
 }
 
@@ -90,6 +90,15 @@ impl AssemblyContext {
         }
     }
 
+    /// Returns the name of the procedure by its index from the vector of local procedures.
+    pub fn get_local_procedure_name(&self, idx: u16) -> Option<&ProcedureName> {
+        let module_context = self.module_stack.last().expect("no modules");
+        module_context
+            .compiled_procs
+            .get(idx as usize)
+            .map(|named_proc| named_proc.name())
+    }
+
     /// Returns the [Procedure] by its index from the vector of local procedures.
     pub fn get_local_procedure(&self, idx: u16) -> Result<&Procedure, AssemblyError> {
         let module_context = self.module_stack.last().expect("no modules");
@@ -123,10 +132,14 @@ impl AssemblyContext {
             );
         }
 
-        // make sure this module is not in the chain of modules which are currently being compiled
-        if self.module_stack.iter().any(|m| &m.path == module_path) {
-            let dep_chain =
-                self.module_stack.iter().map(|m| m.path.to_string()).collect::<Vec<_>>();
+        // make sure this module is not in the chain of modules which are currently being compiled;
+        // if it is, report the chain starting and ending with this module
+        if let Some(pos) = self.module_stack.iter().position(|m| &m.path == module_path) {
+            let dep_chain = self.module_stack[pos..]
+                .iter()
+                .map(|m| m.path.to_string())
+                .chain(core::iter::once(module_path.to_string()))
+                .collect::<Vec<_>>();
             return Err(AssemblyError::circular_module_dependency(&dep_chain));
         }
 
@@ -146,15 +159,14 @@ impl AssemblyContext {
         let module_ctx = self.module_stack.pop().expect("no modules");
         if self.is_kernel && self.module_stack.is_empty() {
             // if we are compiling a kernel and this is the last module on the module stack, then
-            // it must be the Kernel module; thus, we build a Kernel struct from the procedures
-            // exported from the kernel module
-            let proc_roots = module_ctx
+            // it must be the Kernel module; thus, we build the kernel metadata from the roots and
+            // names of the procedures exported from the kernel module
+            let procs = module_ctx
                 .compiled_procs
                 .iter()
                 .filter(|proc| proc.is_export())
-                .map(|proc| proc.mast_root())
-                .collect::<Vec<_>>();
-            self.kernel = Some(Kernel::new(&proc_roots).map_err(AssemblyError::KernelError)?);
+                .map(|proc| (proc.mast_root(), Some(proc.name().to_string())));
+            self.kernel = Some(KernelMetadata::new(procs).map_err(AssemblyError::KernelError)?);
         }
 
         // return compiled procedures and callset from the module
@@ -177,11 +189,12 @@ impl AssemblyContext {
         name: &ProcedureName,
         is_export: bool,
         num_locals: u16,
+        attributes: ProcedureAttributes,
     ) -> Result<(), AssemblyError> {
         self.module_stack
             .last_mut()
             .expect("no modules")
-            .begin_proc(name, is_export, num_locals)
+            .begin_proc(name, is_export, num_locals, attributes)
     }
 
     /// Completes compilation of the current procedure and adds the compiled procedure to the list
@@ -251,6 +264,16 @@ impl AssemblyContext {
         Ok(())
     }
 
+    /// Returns an error if a syscall cannot be made from the procedure currently being compiled,
+    /// that is, if we are compiling a kernel.
+    pub fn ensure_syscall_allowed(&self) -> Result<(), AssemblyError> {
+        if self.is_kernel {
+            let proc_name = &self.current_proc_context().expect("no procedure").name;
+            return Err(AssemblyError::syscall_in_kernel(proc_name));
+        }
+        Ok(())
+    }
+
     /// Registers a "phantom" call to the procedure with the specified MAST root.
     ///
     /// A phantom call indicates that code for the procedure is not available. Executing a phantom
@@ -270,15 +293,16 @@ impl AssemblyContext {
     // CONTEXT FINALIZERS
     // --------------------------------------------------------------------------------------------
 
-    /// Transforms this context into a [Kernel].
+    /// Transforms this context into a [Kernel] and the [KernelMetadata] describing its procedures.
     ///
     /// This method is invoked at the end of the compilation of a kernel module.
     ///
     /// # Panics
     /// Panics if this context was not used for kernel compilation (i.e., was not instantiated with
     /// is_kernel == true) or if the kernel module has not been completed yet.
-    pub fn into_kernel(self) -> Kernel {
-        self.kernel.expect("no kernel")
+    pub fn into_kernel(self) -> (Kernel, KernelMetadata) {
+        let metadata = self.kernel.expect("no kernel");
+        (metadata.to_kernel(), metadata)
     }
 
     /// Transforms this context into a [CodeBlockTable] for the compiled program.
@@ -424,6 +448,7 @@ impl ModuleContext {
         name: &ProcedureName,
         is_export: bool,
         num_locals: u16,
+        attributes: ProcedureAttributes,
     ) -> Result<(), AssemblyError> {
         // make sure a procedure with this name has not been compiled yet and is also not currently
         // on the stack of procedures being compiled
@@ -433,7 +458,9 @@ impl ModuleContext {
             return Err(AssemblyError::duplicate_proc_name(name, &self.path));
         }
 
-        self.proc_stack.push(ProcedureContext::new(name.clone(), is_export, num_locals));
+        let proc_context =
+            ProcedureContext::new(name.clone(), is_export, num_locals).with_attributes(attributes);
+        self.proc_stack.push(proc_context);
         Ok(())
     }
 
@@ -538,6 +565,7 @@ struct ProcedureContext {
     name: ProcedureName,
     is_export: bool,
     num_locals: u16,
+    attributes: ProcedureAttributes,
     callset: CallSet,
 }
 
@@ -547,10 +575,16 @@ impl ProcedureContext {
             name,
             is_export,
             num_locals,
+            attributes: ProcedureAttributes::default(),
             callset: CallSet::default(),
         }
     }
 
+    pub fn with_attributes(mut self, attributes: ProcedureAttributes) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
     pub fn is_main(&self) -> bool {
         self.name.is_main()
     }
@@ -567,9 +601,11 @@ impl ProcedureContext {
             name,
             is_export,
             num_locals,
+            attributes,
             callset,
         } = self;
 
         NamedProcedure::new(name, is_export, num_locals as u32, code_root, callset)
+            .with_attributes(attributes)
     }
 }
@@ -1,5 +1,6 @@
-use super::{validate_param, AssemblyError, SpanBuilder};
+use super::{super::combine_blocks, u32_ops, validate_param, AssemblyError, SpanBuilder, ZERO};
 use crate::{ast::AdviceInjectorNode, ADVICE_READ_LIMIT};
+use alloc::vec::Vec;
 use vm_core::{code_blocks::CodeBlock, Operation};
 
 // NON-DETERMINISTIC (ADVICE) INPUTS
@@ -18,6 +19,57 @@ pub fn adv_push(span: &mut SpanBuilder, n: u8) -> Result<Option<CodeBlock>, Asse
     Ok(None)
 }
 
+/// Appends the code reading the number of words specified by the top of the stack from the advice
+/// stack into memory to the span.
+///
+/// Input: [n, ptr, ...]
+/// Output: [ptr + n, ...]
+///
+/// The words are written to memory at addresses ptr..ptr + n. This allows programs to read long
+/// inputs from the advice provider without looping over the reads themselves; the words are read
+/// two at a time via the PIPE operation in a loop, and the last word is read via the ADVPOPW
+/// operation if the number of words is odd. Besides a constant overhead, the instruction takes
+/// about 10 cycles for every two words.
+///
+/// The instruction fails if either of n and ptr is not a u32 value, or if ptr + n overflows.
+pub fn adv_pipe_words(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    use Operation::*;
+
+    // compute the end address, making sure that it is a valid u32 value, and set up the stack for
+    // the PIPE operation
+    span.push_ops([U32assert2(ZERO), Dup1]);
+    u32_ops::u32add(span, u32_ops::U32OpMode::Overflowing, None)?;
+    span.push_ops([Eqz, Assert(0), Swap]);
+    span.push_ops([Pad; 12]);
+    push_pipe_condition(span)?;
+    // => [ptr + 1 < end_ptr, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, ptr, end_ptr, ...]
+
+    // read two words at a time while at least two words remain
+    let mut body = SpanBuilder::default();
+    body.push_op(Pipe);
+    push_pipe_condition(&mut body)?;
+    let mut body_blocks = Vec::new();
+    body.extract_span_into(&mut body_blocks);
+
+    let mut blocks = Vec::new();
+    blocks.push(CodeBlock::new_loop(combine_blocks(body_blocks)));
+
+    // read the last word if the number of words is odd
+    blocks.push(CodeBlock::new_span(
+        [Drop; 12].into_iter().chain([Dup1, Dup1, Eq, Not]).collect(),
+    ));
+    let read_word = vec![Pad, Pad, Pad, Pad, AdvPopW, Dup4, MStoreW, Drop, Drop, Drop, Drop, Incr];
+    blocks.push(CodeBlock::new_split(
+        CodeBlock::new_span(read_word),
+        CodeBlock::new_span(vec![Noop]),
+    ));
+
+    // at this point the write pointer is equal to the end address
+    blocks.push(CodeBlock::new_span(vec![Drop]));
+
+    Ok(Some(combine_blocks(blocks)))
+}
+
 // ADVICE INJECTORS
 // ================================================================================================
 
@@ -29,3 +81,14 @@ pub fn adv_inject(
     span.push_advice_injector(injector.into());
     Ok(None)
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Appends the operations pushing a flag indicating whether at least two words remain to be read
+/// by the PIPE operation, i.e., whether ptr + 1 < end_ptr, onto the stack laid out as
+/// [C, B, A, ptr, end_ptr, ...].
+fn push_pipe_condition(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.push_ops([Operation::Dup13, Operation::Dup13, Operation::Incr]);
+    u32_ops::u32gt(span)
+}
@@ -2,6 +2,7 @@ use super::{
     mem_ops::local_to_absolute_addr, push_felt, AssemblyContext, AssemblyError, CodeBlock, Felt,
     Operation::*, SpanBuilder,
 };
+use vm_core::AdviceInjector::MaxCyclesToStack;
 
 // CONSTANT INPUTS
 // ================================================================================================
@@ -64,3 +65,28 @@ pub fn caller(
     }
     span.add_op(Caller)
 }
+
+/// Appends a sequence of operations to the span which pushes the maximum number of cycles the
+/// program is allowed to execute onto the stack.
+///
+/// The value is provided non-deterministically via the advice provider, and thus, is not
+/// constrained by the proof of execution.
+///
+/// This operation takes 1 VM cycle.
+pub fn max_cycles(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.push_advice_injector(MaxCyclesToStack);
+    span.add_op(AdvPop)
+}
+
+/// Appends a sequence of operations to the span which pushes the number of cycles remaining
+/// before the cycle limit is reached onto the stack. This is computed as `max_cycles - clk`, where
+/// `clk` is the clock cycle at which the `CLK` operation of this sequence is executed.
+///
+/// The maximum number of cycles is provided non-deterministically via the advice provider, and
+/// thus, is not constrained by the proof of execution.
+///
+/// This operation takes 4 VM cycles.
+pub fn remaining_cycles(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.push_advice_injector(MaxCyclesToStack);
+    span.add_ops([AdvPop, Clk, Neg, Add])
+}
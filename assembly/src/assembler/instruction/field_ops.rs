@@ -2,7 +2,8 @@ use super::{
     validate_param, AssemblyError, CodeBlock, Felt, FieldElement, Operation::*, SpanBuilder, ONE,
     ZERO,
 };
-use crate::MAX_EXP_BITS;
+use crate::{MAX_EXP_BITS, MAX_RANGE_CHECK_BITS};
+use core::cmp::Ordering;
 use vm_core::AdviceInjector::ILog2;
 
 /// Field element representing TWO in the base field of the VM.
@@ -30,6 +31,41 @@ pub fn assertw(span: &mut SpanBuilder, err_code: u32) -> Result<Option<CodeBlock
     ])
 }
 
+/// Appends a sequence of operations to assert that the value at the top of the stack is smaller
+/// than 2^num_bits. The value is left on the stack.
+///
+/// For num_bits = 32 this is the same as `u32assert`. Otherwise, the value is split into its lower
+/// and upper 32-bit limbs via U32SPLIT, which range checks both limbs. The limb which contains the
+/// most significant allowed bit is then shifted left such that the allowed bits occupy the most
+/// significant bits of a 32-bit value, and the result is checked again via U32ASSERT2. When
+/// num_bits < 32, the upper limb must also be zero.
+///
+/// VM cycles:
+/// - num_bits < 32: 8 cycles
+/// - num_bits = 32: 3 cycles
+/// - num_bits > 32: 7 cycles
+///
+/// # Errors
+/// Returns an error if num_bits is not in the range [1, 63].
+pub fn range_check(
+    span: &mut SpanBuilder,
+    num_bits: u8,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    validate_param(num_bits, 1..=MAX_RANGE_CHECK_BITS)?;
+
+    match num_bits.cmp(&32) {
+        Ordering::Less => {
+            let shift = Felt::new(1 << (32 - num_bits));
+            span.add_ops([Dup0, U32split, Eqz, Assert(0), Push(shift), Mul, U32assert2(ZERO), Drop])
+        }
+        Ordering::Equal => span.add_ops([Pad, U32assert2(ZERO), Drop]),
+        Ordering::Greater => {
+            let shift = Felt::new(1 << (64 - num_bits));
+            span.add_ops([Dup0, U32split, Push(shift), Mul, U32assert2(ZERO), Drop, Drop])
+        }
+    }
+}
+
 // BASIC ARITHMETIC OPERATIONS
 // ================================================================================================
 
@@ -158,28 +194,44 @@ pub fn exp(span: &mut SpanBuilder, num_pow_bits: u8) -> Result<Option<CodeBlock>
 
 /// Appends a sequence of operations to compute b^pow where b is the value at the top of the stack.
 ///
-/// VM cycles per mode:
-/// - pow = 0: 3 cycles
-/// - pow = 1: 1 cycles
-/// - pow = 2: 2 cycles
-/// - pow = 3: 4 cycles
-/// - pow = 4: 6 cycles
-/// - pow = 5: 8 cycles
-/// - pow = 6: 10 cycles
-/// - pow = 7: 12 cycles
-/// - pow > 7: 9 + Ceil(log2(pow))
+/// The cheapest of the following schedules is selected based on the value of pow:
+/// - Repeated multiplication (only for pow < 8): 2 * (pow - 1) cycles, see
+///   [perform_exp_for_small_power()].
+/// - Square-and-multiply: 2 * (num_pow_bits - 1) cycles if pow is a power of two, and
+///   2 * (num_pow_bits + num_set_bits) - 1 cycles otherwise, see [perform_square_and_multiply()].
+/// - Exponentiation via EXPACC: num_pow_bits + 10 cycles, see [exp()].
+///
+/// Thus, this takes 3 cycles for pow = 0, 1 cycle for pow = 1, and at most 74 cycles otherwise.
+///
+/// As all field operations, the result is reduced modulo the field modulus, and thus, this also
+/// serves as modular exponentiation over the field.
 pub fn exp_imm(span: &mut SpanBuilder, pow: Felt) -> Result<Option<CodeBlock>, AssemblyError> {
-    if pow.as_int() <= 7 {
-        perform_exp_for_small_power(span, pow.as_int());
-        Ok(None)
+    let pow = pow.as_int();
+    if pow <= 1 {
+        perform_exp_for_small_power(span, pow);
+        return Ok(None);
+    }
+
+    let num_pow_bits = 64 - pow.leading_zeros();
+    let num_set_bits = pow.count_ones();
+    let square_and_multiply_cycles = if num_set_bits == 1 {
+        2 * (num_pow_bits - 1)
     } else {
-        // compute the bits length of the exponent
-        let num_pow_bits = (64 - pow.as_int().leading_zeros()) as u8;
+        2 * (num_pow_bits + num_set_bits) - 1
+    };
+    let expacc_cycles = num_pow_bits + 10;
 
+    if pow <= 7 && 2 * (pow as u32 - 1) <= square_and_multiply_cycles {
+        perform_exp_for_small_power(span, pow);
+        Ok(None)
+    } else if square_and_multiply_cycles < expacc_cycles {
+        perform_square_and_multiply(span, pow);
+        Ok(None)
+    } else {
         // pushing the exponent onto the stack.
-        span.push_op(Push(pow));
+        span.push_op(Push(Felt::new(pow)));
 
-        exp(span, num_pow_bits)
+        exp(span, num_pow_bits as u8)
     }
 }
 
@@ -236,6 +288,45 @@ fn perform_exp_for_small_power(span: &mut SpanBuilder, pow: u64) {
     }
 }
 
+/// Appends a sequence of operations to compute b^pow using the square-and-multiply method, where
+/// pow > 1.
+///
+/// The expected starting state of the stack (from the top) is: [b, ...].
+///
+/// The bits of pow are processed starting from the most significant one: for every bit following
+/// the leading bit, the accumulated value is squared, and if the bit is set, it is also multiplied
+/// by b. When pow is a power of two, b is not needed for multiplication, and thus, the value at
+/// the top of the stack is just squared (num_pow_bits - 1) times.
+///
+/// After these operations, the stack state will be: [b^pow, ...].
+///
+/// VM cycles:
+/// - pow is a power of two: 2 * (num_pow_bits - 1) cycles
+/// - otherwise: 2 * (num_pow_bits + num_set_bits) - 1 cycles
+fn perform_square_and_multiply(span: &mut SpanBuilder, pow: u64) {
+    debug_assert!(pow > 1, "pow must be greater than 1");
+    let num_pow_bits = 64 - pow.leading_zeros();
+
+    if pow.is_power_of_two() {
+        for _ in 1..num_pow_bits {
+            span.push_ops([Dup0, Mul]);
+        }
+        return;
+    }
+
+    // keep a copy of b below the accumulated value: [acc, b, ...]
+    span.push_op(Dup0);
+    for bit_idx in (0..num_pow_bits - 1).rev() {
+        span.push_ops([Dup0, Mul]);
+        if (pow >> bit_idx) & 1 == 1 {
+            span.push_ops([Dup1, Mul]);
+        }
+    }
+
+    // drop the copy of b
+    span.push_ops([Swap, Drop]);
+}
+
 // LOGARITHMIC OPERATIONS
 // ================================================================================================
 
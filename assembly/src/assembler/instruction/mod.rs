@@ -1,6 +1,6 @@
 use super::{
-    Assembler, AssemblyContext, AssemblyError, CodeBlock, Felt, Instruction, Operation,
-    ProcedureId, RpoDigest, SpanBuilder, ONE, ZERO,
+    Assembler, AssemblyContext, AssemblyError, CodeBlock, Felt, InlineMode, Instruction, Operation,
+    Procedure, ProcedureId, RpoDigest, SpanBuilder, ONE, ZERO,
 };
 use crate::utils::bound_into_included_u64;
 use core::ops::RangeBounds;
@@ -79,6 +79,7 @@ impl Assembler {
             Instruction::Gt => field_ops::gt(span),
             Instruction::Gte => field_ops::gte(span),
             Instruction::IsOdd => field_ops::is_odd(span),
+            Instruction::RangeCheck(num_bits) => field_ops::range_check(span, *num_bits),
 
             // ----- ext2 instructions ------------------------------------------------------------
             Instruction::Ext2Add => ext2_ops::ext2_add(span),
@@ -103,6 +104,10 @@ impl Assembler {
             Instruction::U32AssertWWithError(err_code) => {
                 u32_ops::u32assertw(span, Felt::from(*err_code))
             }
+            Instruction::U32AssertN(num_elements) => u32_ops::u32assertn(span, *num_elements, ZERO),
+            Instruction::U32AssertNWithError(num_elements, err_code) => {
+                u32_ops::u32assertn(span, *num_elements, Felt::from(*err_code))
+            }
 
             Instruction::U32Cast => span.add_ops([U32split, Drop]),
             Instruction::U32Split => span.add_op(U32split),
@@ -150,6 +155,11 @@ impl Assembler {
             Instruction::U32Ctz => u32_ops::u32ctz(span),
             Instruction::U32Clo => u32_ops::u32clo(span),
             Instruction::U32Cto => u32_ops::u32cto(span),
+            Instruction::U32Bswap => u32_ops::u32bswap(span),
+            Instruction::U32Extract(offset, width) => u32_ops::u32extract(span, *offset, *width),
+            Instruction::U32Insert(offset, width) => u32_ops::u32insert(span, *offset, *width),
+            Instruction::U32Fshl(v) => u32_ops::u32fshl(span, *v),
+            Instruction::U32Fshr(v) => u32_ops::u32fshr(span, *v),
 
             Instruction::U32Lt => u32_ops::u32lt(span),
             Instruction::U32Lte => u32_ops::u32lte(span),
@@ -252,7 +262,10 @@ impl Assembler {
             Instruction::Sdepth => span.add_op(SDepth),
             Instruction::Caller => env_ops::caller(span, ctx),
             Instruction::Clk => span.add_op(Clk),
+            Instruction::MaxCycles => env_ops::max_cycles(span),
+            Instruction::RemainingCycles => env_ops::remaining_cycles(span),
             Instruction::AdvPipe => span.add_op(Pipe),
+            Instruction::AdvPipeWords => adv_ops::adv_pipe_words(span),
             Instruction::AdvPush(n) => adv_ops::adv_push(span, *n),
             Instruction::AdvLoadW => span.add_op(AdvPopW),
 
@@ -296,8 +309,10 @@ impl Assembler {
             Instruction::SysCall(id) => self.syscall(id, ctx),
             Instruction::DynExec => self.dynexec(),
             Instruction::DynCall => self.dyncall(),
+            Instruction::DynSysCall => self.dynsyscall(ctx),
             Instruction::ProcRefLocal(idx) => self.procref_local(*idx, ctx, span),
             Instruction::ProcRefImported(id) => self.procref_imported(id, ctx, span),
+            Instruction::ProcRefKernel(id) => self.procref_kernel(id, ctx, span),
 
             // ----- debug decorators -------------------------------------------------------------
             Instruction::Breakpoint => {
@@ -335,6 +350,27 @@ impl Assembler {
 
         result
     }
+
+    /// Returns the [InlineMode] of the procedure invoked by the specified instruction if the
+    /// instruction is an `exec` instruction; otherwise, returns [InlineMode::Auto].
+    ///
+    /// The invoked procedure is expected to have been compiled by the time this is called.
+    pub(super) fn exec_inline_mode(
+        &self,
+        instruction: &Instruction,
+        ctx: &AssemblyContext,
+    ) -> Result<InlineMode, AssemblyError> {
+        let inline = match instruction {
+            Instruction::ExecLocal(idx) => ctx.get_local_procedure(*idx)?.attributes().inline,
+            Instruction::ExecImported(id) => {
+                let proc_cache = self.proc_cache.borrow();
+                let proc = proc_cache.get_by_id(id).expect("procedure not in cache");
+                proc.attributes().inline
+            }
+            _ => InlineMode::Auto,
+        };
+        Ok(inline)
+    }
 }
 
 // HELPER FUNCTIONS
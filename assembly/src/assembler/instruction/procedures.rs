@@ -1,8 +1,10 @@
 use super::{
-    Assembler, AssemblyContext, AssemblyError, CodeBlock, Operation, ProcedureId, RpoDigest,
-    SpanBuilder,
+    Assembler, AssemblyContext, AssemblyError, CodeBlock, Operation, Procedure, ProcedureId,
+    RpoDigest, SpanBuilder,
 };
+use crate::ast::{event, Level};
 use alloc::vec::Vec;
+use core::fmt;
 
 // PROCEDURE INVOCATIONS
 // ================================================================================================
@@ -13,6 +15,8 @@ impl Assembler {
         proc_idx: u16,
         context: &mut AssemblyContext,
     ) -> Result<Option<CodeBlock>, AssemblyError> {
+        check_deprecated_local(proc_idx, context)?;
+
         // register an "inlined" call to the procedure at the specified index in the module
         // currently being complied; this updates the callset of the procedure currently being
         // compiled
@@ -36,6 +40,7 @@ impl Assembler {
         // get the procedure from the assembler
         let proc_cache = self.proc_cache.borrow();
         let proc = proc_cache.get_by_id(proc_id).expect("procedure not in cache");
+        check_deprecated_imported(proc, proc_id, context);
 
         // register an "inlined" call to the procedure; this updates the callset of the
         // procedure currently being compiled
@@ -53,6 +58,8 @@ impl Assembler {
         index: u16,
         context: &mut AssemblyContext,
     ) -> Result<Option<CodeBlock>, AssemblyError> {
+        check_deprecated_local(index, context)?;
+
         // register a "non-inlined" call to the procedure at the specified index in the module
         // currently being complied; this updates the callset of the procedure currently being
         // compiled
@@ -93,6 +100,7 @@ impl Assembler {
         // get the procedure from the assembler
         let proc_cache = self.proc_cache.borrow();
         let proc = proc_cache.get_by_id(proc_id).expect("procedure not in cache");
+        check_deprecated_imported(proc, proc_id, context);
 
         // register a "non-inlined" call to the procedure; this updates the callset of the
         // procedure currently being compiled
@@ -115,6 +123,7 @@ impl Assembler {
         let proc = proc_cache
             .get_by_id(proc_id)
             .ok_or_else(|| AssemblyError::kernel_proc_not_found(proc_id))?;
+        check_deprecated_imported(proc, proc_id, context);
 
         // since call and syscall instructions cannot be executed inside a kernel, a callset for
         // a kernel procedure must be empty.
@@ -138,12 +147,26 @@ impl Assembler {
         Ok(Some(CodeBlock::new_dyncall()))
     }
 
+    pub(super) fn dynsyscall(
+        &self,
+        context: &AssemblyContext,
+    ) -> Result<Option<CodeBlock>, AssemblyError> {
+        // syscalls cannot be made from within a kernel; the target of the syscall is validated to
+        // be a kernel procedure when the program is executed
+        context.ensure_syscall_allowed()?;
+
+        // create a new SYSCALL block whose target is DYN
+        Ok(Some(CodeBlock::new_dynsyscall()))
+    }
+
     pub(super) fn procref_local(
         &self,
         proc_idx: u16,
         context: &mut AssemblyContext,
         span: &mut SpanBuilder,
     ) -> Result<Option<CodeBlock>, AssemblyError> {
+        check_deprecated_local(proc_idx, context)?;
+
         // get root of the compiled local procedure and add it to the callset to be able to use
         // dynamic instructions with this procedure later
         let proc_root = context.register_local_call(proc_idx, false)?.mast_root();
@@ -165,6 +188,7 @@ impl Assembler {
         // get the procedure from the assembler
         let proc_cache = self.proc_cache.borrow();
         let proc = proc_cache.get_by_id(proc_id).expect("procedure not in cache");
+        check_deprecated_imported(proc, proc_id, context);
 
         // add the root of the procedure to the callset to be able to use dynamic instructions with
         // this procedure later
@@ -176,4 +200,60 @@ impl Assembler {
         let ops: Vec<Operation> = proc_root.iter().map(|elem| Operation::Push(*elem)).collect();
         span.add_ops(ops)
     }
+
+    pub(super) fn procref_kernel(
+        &self,
+        proc_id: &ProcedureId,
+        context: &mut AssemblyContext,
+        span: &mut SpanBuilder,
+    ) -> Result<Option<CodeBlock>, AssemblyError> {
+        // kernel procedures are expected to be in the procedure cache since the assembler was
+        // initialized with the kernel
+        let proc_cache = self.proc_cache.borrow();
+        let proc = proc_cache
+            .get_by_id(proc_id)
+            .ok_or_else(|| AssemblyError::kernel_proc_not_found(proc_id))?;
+        check_deprecated_imported(proc, proc_id, context);
+
+        // add the root of the procedure to the callset to be able to invoke the procedure via a
+        // dynamic syscall later
+        context.register_external_call(proc, false)?;
+
+        // create an array with `Push` operations containing root elements
+        let ops: Vec<Operation> =
+            proc.mast_root().iter().map(|elem| Operation::Push(*elem)).collect();
+        span.add_ops(ops)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Emits a warning if the procedure at the specified index in the module currently being compiled
+/// is deprecated.
+fn check_deprecated_local(proc_idx: u16, context: &AssemblyContext) -> Result<(), AssemblyError> {
+    if context.get_local_procedure(proc_idx)?.attributes().deprecated {
+        let proc_name = context.get_local_procedure_name(proc_idx).expect("no local procedure");
+        warn_deprecated_invocation(proc_name, context);
+    }
+    Ok(())
+}
+
+/// Emits a warning if the specified imported or kernel procedure is deprecated.
+fn check_deprecated_imported(proc: &Procedure, proc_id: &ProcedureId, context: &AssemblyContext) {
+    if proc.attributes().deprecated {
+        match context.get_imported_procedure_name(proc_id) {
+            Some(proc_name) => warn_deprecated_invocation(proc_name, context),
+            None => warn_deprecated_invocation(proc_id, context),
+        }
+    }
+}
+
+fn warn_deprecated_invocation(proc_name: impl fmt::Display, context: &AssemblyContext) {
+    event!(
+        Level::WARN,
+        "invocation of deprecated procedure \"{}\" in \"{}\"",
+        proc_name,
+        context.current_context_name()
+    );
 }
@@ -1,11 +1,15 @@
 use super::{
-    ast::{instrument, Instruction, ModuleAst, Node, ProcedureAst, ProgramAst},
+    ast::{
+        instrument, InlineMode, Instruction, InstructionRegistry, ModuleAst, Node, ProcedureAst,
+        ProgramAst,
+    },
     crypto::hash::RpoDigest,
-    AssemblyError, CallSet, CodeBlock, CodeBlockTable, Felt, Kernel, Library, LibraryError,
-    LibraryPath, Module, NamedProcedure, Operation, Procedure, ProcedureId, ProcedureName, Program,
-    ONE, ZERO,
+    AssemblyError, CallSet, CodeBlock, CodeBlockTable, ErrorCodeRegistry, Felt, Kernel,
+    KernelMetadata, Library, LibraryError, LibraryPath, Module, NamedProcedure, Operation,
+    Procedure, ProcedureId, ProcedureName, Program, ProgramPackage, ONE, ZERO,
 };
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::ToString;
 use alloc::vec::Vec;
 use core::{borrow::Borrow, cell::RefCell};
 use vm_core::{utils::group_vector_elements, Decorator, DecoratorList};
@@ -24,6 +28,14 @@ pub use context::AssemblyContext;
 mod procedure_cache;
 use procedure_cache::ProcedureCache;
 
+mod proc_roots;
+pub use proc_roots::{ProcedureRootChange, ProcedureRoots};
+
+#[cfg(feature = "std")]
+mod module_cache;
+#[cfg(feature = "std")]
+use module_cache::ModuleCache;
+
 #[cfg(test)]
 mod tests;
 
@@ -35,12 +47,28 @@ mod tests;
 /// - If `with_kernel()` or `with_kernel_module()` methods are not used, the assembler will be
 ///   instantiated with a default empty kernel. Programs compiled using such assembler
 ///   cannot make calls to kernel procedures via `syscall` instruction.
+/// - If `with_cache()` method is used, library modules compiled by the assembler are cached on
+///   disk, and are recompiled only if their source or any of their dependencies have changed.
+/// - If `with_instructions()` method is used, the custom instructions from the provided registry
+///   can be used in the source code compiled by the assembler.
+///
+/// Code blocks compiled by the assembler are deduplicated: whenever a code body compiles into a
+/// block identical to a block compiled earlier (e.g., bodies of identical procedures or branches),
+/// the nodes of the earlier block are shared instead.
 #[derive(Default)]
 pub struct Assembler {
     kernel: Kernel,
+    kernel_metadata: KernelMetadata,
     module_provider: ModuleProvider,
     proc_cache: RefCell<ProcedureCache>,
+    error_codes: RefCell<ErrorCodeRegistry>,
+    compiled_blocks: RefCell<BTreeMap<[u8; 32], CodeBlock>>,
     in_debug_mode: bool,
+    allow_constants_only_cycles: bool,
+    merge_inlined_spans: bool,
+    instructions: InstructionRegistry,
+    #[cfg(feature = "std")]
+    cache: Option<ModuleCache>,
 }
 
 impl Assembler {
@@ -53,6 +81,45 @@ impl Assembler {
         self
     }
 
+    /// Allows import cycles between modules as long as every cycle passes through at least one
+    /// module which does not contain any procedures (i.e., a module containing only constants).
+    ///
+    /// By default, all import cycles are rejected.
+    pub fn with_constants_only_cycles(mut self, allow: bool) -> Self {
+        self.allow_constants_only_cycles = allow;
+        self
+    }
+
+    /// Merges SPAN blocks across the boundaries of procedures inlined via `exec` instructions and
+    /// of `repeat` bodies.
+    ///
+    /// Code blocks of inlined procedures and of repeated bodies which contain control flow are
+    /// JOIN blocks, and by default, they are inserted into the enclosing code body as is. Thus,
+    /// straight-line code at the start and at the end of such blocks ends up in SPAN blocks
+    /// separate from the straight-line code surrounding them. When this option is enabled, such
+    /// JOIN blocks are flattened into the enclosing code body instead, and all consecutive SPAN
+    /// blocks are merged together. This reduces the number of blocks in the program MAST, and
+    /// the number of hasher and decoder trace rows required to execute the program, at the cost
+    /// of changing MAST roots of the affected procedures and programs.
+    ///
+    /// By default, SPAN blocks are not merged across these boundaries.
+    pub fn with_merged_spans(mut self, merge: bool) -> Self {
+        self.merge_inlined_spans = merge;
+        self
+    }
+
+    /// Sets the registry of custom instructions which can be used in the source code compiled by
+    /// this assembler.
+    ///
+    /// Custom instructions are lowered into built-in instructions when the source code is parsed;
+    /// thus, this method must be called before `with_kernel()` for the kernel source to be able to
+    /// use them. Modules provided by libraries are parsed when the libraries are built, and thus,
+    /// are not affected by this registry.
+    pub fn with_instructions(mut self, instructions: InstructionRegistry) -> Self {
+        self.instructions = instructions;
+        self
+    }
+
     /// Adds the library to provide modules for the compilation.
     pub fn with_library<L>(mut self, library: &L) -> Result<Self, AssemblyError>
     where
@@ -71,6 +138,26 @@ impl Assembler {
         libraries.try_fold(self, |slf, library| slf.with_library(&library))
     }
 
+    /// Enables the on-disk module cache located in the specified directory. The directory is
+    /// created if it does not exist.
+    ///
+    /// With the cache enabled, procedures compiled from library modules are persisted in the
+    /// cache directory, and subsequent compilations load them from there instead of compiling
+    /// the modules again. An entry is reused only if the source of the module, as well as MAST
+    /// roots and attributes of all procedures it imports, are unchanged. Kernel modules are never
+    /// cached.
+    ///
+    /// # Errors
+    /// Returns an error if the cache directory could not be created.
+    #[cfg(feature = "std")]
+    pub fn with_cache<P>(mut self, path: P) -> Result<Self, AssemblyError>
+    where
+        P: AsRef<std::path::Path>,
+    {
+        self.cache = Some(ModuleCache::new(path)?);
+        Ok(self)
+    }
+
     /// Sets the kernel for the assembler to the kernel defined by the provided source.
     ///
     /// # Errors
@@ -79,7 +166,7 @@ impl Assembler {
     /// # Panics
     /// Panics if the assembler has already been used to compile programs.
     pub fn with_kernel(self, kernel_source: &str) -> Result<Self, AssemblyError> {
-        let kernel_ast = ModuleAst::parse(kernel_source)?;
+        let kernel_ast = ModuleAst::parse_with_instructions(kernel_source, &self.instructions)?;
         self.with_kernel_module(kernel_ast)
     }
 
@@ -94,8 +181,8 @@ impl Assembler {
         self.compile_module(&kernel.ast, Some(&kernel.path), &mut context)?;
 
         // convert the context into Kernel; this builds the kernel from hashes of procedures
-        // exported form the kernel module
-        self.kernel = context.into_kernel();
+        // exported form the kernel module, and keeps the names of these procedures as metadata
+        (self.kernel, self.kernel_metadata) = context.into_kernel();
 
         Ok(self)
     }
@@ -115,6 +202,21 @@ impl Assembler {
         &self.kernel
     }
 
+    /// Returns the metadata describing the procedures of the kernel for this assembler, including
+    /// their names.
+    ///
+    /// If the assembler was instantiated without a kernel, the metadata will be empty.
+    pub fn kernel_metadata(&self) -> &KernelMetadata {
+        &self.kernel_metadata
+    }
+
+    /// Returns the error codes declared by all modules compiled by this assembler so far.
+    ///
+    /// Error codes declared by a module are registered under the path of the module.
+    pub fn error_codes(&self) -> ErrorCodeRegistry {
+        self.error_codes.borrow().clone()
+    }
+
     // PROGRAM COMPILER
     // --------------------------------------------------------------------------------------------
 
@@ -129,7 +231,7 @@ impl Assembler {
     {
         // parse the program into an AST
         let source = source.as_ref();
-        let program = ProgramAst::parse(source)?;
+        let program = ProgramAst::parse_with_instructions(source, &self.instructions)?;
 
         // compile the program and return
         self.compile_ast(&program)
@@ -149,8 +251,46 @@ impl Assembler {
         // convert the context into a call block table for the program
         let cb_table = context.into_cb_table(&self.proc_cache.borrow())?;
 
-        // build and return the program
-        Ok(Program::with_kernel(program_root, self.kernel.clone(), cb_table))
+        // build and return the program; the program carries error codes of all modules compiled
+        // by the assembler so that failed assertions can be decoded
+        Ok(Program::with_kernel(program_root, self.kernel.clone(), cb_table)
+            .with_error_codes(self.error_codes()))
+    }
+
+    /// Compiles the provided module source code into a [ProgramPackage] with one entrypoint for
+    /// each procedure exported from the module.
+    ///
+    /// # Errors
+    /// Returns an error if parsing of the module or compilation of any of the entrypoints fails.
+    pub fn compile_package<S>(&self, source: S) -> Result<ProgramPackage, AssemblyError>
+    where
+        S: AsRef<str>,
+    {
+        let module = ModuleAst::parse_with_instructions(source.as_ref(), &self.instructions)?;
+        self.compile_package_ast(&module)
+    }
+
+    /// Compiles the provided module abstract syntax tree into a [ProgramPackage].
+    ///
+    /// Each local procedure exported from the module becomes an entrypoint of the package named
+    /// after the procedure, as described in [ModuleAst::entrypoint_program()]. All entrypoints are
+    /// compiled against the kernel and the libraries of this assembler.
+    ///
+    /// # Errors
+    /// Returns an error if the module does not export any local procedures, or if compilation of
+    /// any of the entrypoints fails.
+    #[instrument("compile_package_ast", skip_all)]
+    pub fn compile_package_ast(&self, module: &ModuleAst) -> Result<ProgramPackage, AssemblyError> {
+        let mut entrypoints = BTreeMap::new();
+        for name in module.entrypoints() {
+            let program = module.entrypoint_program(name).expect("entrypoint not found");
+            entrypoints.insert(name.to_string(), self.compile_ast(&program)?);
+        }
+
+        if entrypoints.is_empty() {
+            return Err(AssemblyError::empty_program_package());
+        }
+        Ok(ProgramPackage::new(entrypoints))
     }
 
     /// Compiles the provided [ProgramAst] into a program and returns the program root
@@ -205,6 +345,23 @@ impl Assembler {
         path: Option<&LibraryPath>,
         context: &mut AssemblyContext,
     ) -> Result<Vec<RpoDigest>, AssemblyError> {
+        // make sure the module is not a part of an import cycle; modules without procedures are
+        // skipped because they cannot depend on other modules at compile time
+        if let Some(path) = path.filter(|_| !module.is_constants_only()) {
+            let cycle = self.module_provider.find_import_cycle(
+                path,
+                module,
+                self.allow_constants_only_cycles,
+            );
+            if let Some(cycle) = cycle {
+                let dep_chain = cycle.iter().map(|path| path.to_string()).collect::<Vec<_>>();
+                return Err(AssemblyError::circular_module_dependency(&dep_chain));
+            }
+        }
+
+        // register error codes declared by the module under the path of the module
+        self.register_error_codes(module, path.unwrap_or(&LibraryPath::anon_path()))?;
+
         // a variable to track MAST roots of all procedures exported from this module
         let mut proc_roots = Vec::new();
         context.begin_module(path.unwrap_or(&LibraryPath::anon_path()), module)?;
@@ -239,6 +396,17 @@ impl Assembler {
             proc_roots.push(proc_mast_root);
         }
 
+        // if the module has been compiled before and neither its source nor any of its
+        // dependencies have changed since, load its procedures from the module cache
+        #[cfg(feature = "std")]
+        let cache_key = self.module_cache_key(module, path, context)?;
+        #[cfg(feature = "std")]
+        if let Some(procs) = self.load_cached_module(cache_key.as_ref()) {
+            context.complete_module()?;
+            self.insert_module_procs(procs, path, &mut proc_roots)?;
+            return Ok(proc_roots);
+        }
+
         // compile all local (internal end exported) procedures in the module; once the compilation
         // is complete, we get all compiled procedures (and their combined callset) from the
         // context
@@ -247,29 +415,178 @@ impl Assembler {
         }
         let (module_procs, module_callset) = context.complete_module()?;
 
-        // add the compiled procedures to the assembler's cache. the procedures are added to the
-        // cache only if:
-        // - a procedure is exported from the module, or
-        // - a procedure is present in the combined callset - i.e., it is an internal procedure
-        //   which has been invoked via a local call instruction.
-        for (proc_index, proc) in module_procs.into_iter().enumerate() {
+        // retain only the procedures which need to be added to the assembler's cache; these are
+        // procedures which are either:
+        // - exported from the module, or
+        // - present in the combined callset - i.e., internal procedures which have been invoked
+        //   via a local call instruction.
+        let procs = module_procs
+            .into_iter()
+            .enumerate()
+            .filter(|(_, proc)| proc.is_export() || module_callset.contains(&proc.mast_root()))
+            .collect::<Vec<_>>();
+
+        #[cfg(feature = "std")]
+        if let (Some(cache), Some(cache_key)) = (&self.cache, &cache_key) {
+            cache.store(cache_key, &procs)?;
+        }
+
+        self.insert_module_procs(procs, path, &mut proc_roots)?;
+
+        Ok(proc_roots)
+    }
+
+    /// Registers the error code ranges and names declared by the provided module in the error code
+    /// registry of this assembler.
+    ///
+    /// # Errors
+    /// Returns an error if the declared ranges overlap with ranges of other modules.
+    fn register_error_codes(
+        &self,
+        module: &ModuleAst,
+        path: &LibraryPath,
+    ) -> Result<(), AssemblyError> {
+        let mut registry =
+            self.error_codes.try_borrow_mut().map_err(|_| AssemblyError::InvalidCacheLock)?;
+        let error_codes = module.error_codes();
+        for &(first, last) in error_codes.ranges() {
+            registry
+                .register_range(path.path(), first, last)
+                .map_err(AssemblyError::ErrorCodeError)?;
+        }
+        for (name, code) in error_codes.names() {
+            registry
+                .register_name(path.path(), name, *code)
+                .map_err(AssemblyError::ErrorCodeError)?;
+        }
+        Ok(())
+    }
+
+    /// Adds the provided module procedures to the assembler's procedure cache and appends MAST
+    /// roots of the exported procedures to `proc_roots`.
+    ///
+    /// Each procedure is accompanied by its index in the module, which is used to build IDs of
+    /// internal procedures.
+    fn insert_module_procs(
+        &self,
+        procs: Vec<(usize, NamedProcedure)>,
+        path: Option<&LibraryPath>,
+        proc_roots: &mut Vec<RpoDigest>,
+    ) -> Result<(), AssemblyError> {
+        for (proc_index, proc) in procs {
             if proc.is_export() {
                 proc_roots.push(proc.mast_root());
             }
 
-            if proc.is_export() || module_callset.contains(&proc.mast_root()) {
-                // build the procedure ID if this module has the library path
-                let proc_id = build_procedure_id(path, &proc, proc_index);
+            // build the procedure ID if this module has the library path
+            let proc_id = build_procedure_id(path, &proc, proc_index);
 
-                // this is safe because we fail if the cache is borrowed.
-                self.proc_cache
-                    .try_borrow_mut()
-                    .map_err(|_| AssemblyError::InvalidCacheLock)?
-                    .insert(proc, proc_id)?;
+            // this is safe because we fail if the cache is borrowed.
+            self.proc_cache
+                .try_borrow_mut()
+                .map_err(|_| AssemblyError::InvalidCacheLock)?
+                .insert(proc, proc_id)?;
+        }
+
+        Ok(())
+    }
+
+    // PROCEDURE ROOTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a report mapping every procedure exported from the modules of the provided library
+    /// to the MAST root of that procedure. Re-exported procedures are included under the path of
+    /// the re-exporting module.
+    ///
+    /// The library is expected to have been added to this assembler via `with_library()` (together
+    /// with all of its dependencies). Modules which have not been compiled yet are compiled on
+    /// demand.
+    ///
+    /// # Errors
+    /// Returns an error if compilation of any of the library modules fails.
+    pub fn procedure_roots<L>(&self, library: &L) -> Result<ProcedureRoots, AssemblyError>
+    where
+        L: Library,
+    {
+        let mut roots = ProcedureRoots::default();
+        for module in library.modules() {
+            self.add_module_roots(module, &mut roots)?;
+        }
+        Ok(roots)
+    }
+
+    /// Returns a report mapping every procedure exported from the provided module to the MAST
+    /// root of that procedure.
+    ///
+    /// This can be used to get the roots of kernel procedures by passing in the kernel module
+    /// (i.e., `Module::kernel(ast)`) used to instantiate this assembler.
+    ///
+    /// # Errors
+    /// Returns an error if the module cannot be found by the assembler or if its compilation fails.
+    pub fn module_procedure_roots(&self, module: &Module) -> Result<ProcedureRoots, AssemblyError> {
+        let mut roots = ProcedureRoots::default();
+        self.add_module_roots(module, &mut roots)?;
+        Ok(roots)
+    }
+
+    /// Returns a code block table containing code of every procedure exported from the modules of
+    /// the provided library (and of all procedures these procedures may call), keyed by MAST roots
+    /// of the procedures.
+    ///
+    /// The table (or its blocks, enumerated via [CodeBlockTable::iter()]) can be persisted in an
+    /// external [MastStore](vm_core::MastStore), from which the code of the procedures called by
+    /// programs is loaded when the programs are executed.
+    ///
+    /// The library is expected to have been added to this assembler via `with_library()` (together
+    /// with all of its dependencies). Modules which have not been compiled yet are compiled on
+    /// demand.
+    ///
+    /// # Errors
+    /// Returns an error if compilation of any of the library modules fails.
+    pub fn library_code_blocks<L>(&self, library: &L) -> Result<CodeBlockTable, AssemblyError>
+    where
+        L: Library,
+    {
+        let roots = self.procedure_roots(library)?;
+        let proc_cache = self.proc_cache.borrow();
+        let mut table = CodeBlockTable::default();
+        for (_, mast_root) in roots.iter() {
+            let proc = proc_cache.get_by_hash(mast_root).expect("procedure not in cache");
+            table.insert(proc.code().clone());
+
+            // include procedures which may be called by the exported procedure, as they are not
+            // necessarily exported themselves
+            for callee_root in proc.callset().iter() {
+                let callee = proc_cache
+                    .get_by_hash(callee_root)
+                    .ok_or(AssemblyError::CallSetProcedureNotFound(*callee_root))?;
+                table.insert(callee.code().clone());
             }
         }
+        Ok(table)
+    }
 
-        Ok(proc_roots)
+    /// Adds MAST roots of all procedures exported from the provided module to the report.
+    fn add_module_roots(
+        &self,
+        module: &Module,
+        roots: &mut ProcedureRoots,
+    ) -> Result<(), AssemblyError> {
+        let mut context = AssemblyContext::for_module(module.path.is_kernel_path());
+
+        let reexported = module.ast.reexported_procs().iter().map(|proc| proc.name());
+        let local = module.ast.procs().iter().filter(|proc| proc.is_export).map(|proc| &proc.name);
+        for proc_name in reexported.chain(local) {
+            let proc_id = ProcedureId::from_name(proc_name, &module.path);
+            self.ensure_procedure_is_in_cache(&proc_id, &mut context)?;
+            let mast_root =
+                self.proc_cache.borrow().get_proc_root_by_id(&proc_id).ok_or_else(|| {
+                    AssemblyError::imported_proc_not_found_in_module(&proc_id, &module.path)
+                })?;
+            roots.insert(&module.path, proc_name, mast_root);
+        }
+
+        Ok(())
     }
 
     // PROCEDURE COMPILER
@@ -281,7 +598,7 @@ impl Assembler {
         proc: &ProcedureAst,
         context: &mut AssemblyContext,
     ) -> Result<(), AssemblyError> {
-        context.begin_proc(&proc.name, proc.is_export, proc.num_locals)?;
+        context.begin_proc(&proc.name, proc.is_export, proc.num_locals, proc.attributes)?;
         let code = if proc.num_locals > 0 {
             // for procedures with locals, we need to update fmp register before and after the
             // procedure body is executed. specifically:
@@ -317,6 +634,8 @@ impl Assembler {
         N: Borrow<Node>,
     {
         let mut blocks: Vec<CodeBlock> = Vec::new();
+        // indexes of the blocks which must not be merged with adjacent SPAN blocks
+        let mut sealed_blocks = BTreeSet::new();
         let mut span = SpanBuilder::new(wrapper);
 
         for node in body {
@@ -324,7 +643,14 @@ impl Assembler {
                 Node::Instruction(inner) => {
                     if let Some(block) = self.compile_instruction(inner, &mut span, context)? {
                         span.extract_span_into(&mut blocks);
-                        blocks.push(block);
+                        match self.exec_inline_mode(inner, context)? {
+                            InlineMode::Auto => self.push_block(block, &mut blocks),
+                            InlineMode::Always => flatten_block(block, &mut blocks),
+                            InlineMode::Never => {
+                                sealed_blocks.insert(blocks.len());
+                                blocks.push(block);
+                            }
+                        }
                     }
                 }
 
@@ -355,7 +681,7 @@ impl Assembler {
                     let block = self.compile_body(body.nodes().iter(), context, None)?;
 
                     for _ in 0..*times {
-                        blocks.push(block.clone());
+                        self.push_block(block.clone(), &mut blocks);
                     }
                 }
 
@@ -371,11 +697,42 @@ impl Assembler {
         }
 
         span.extract_final_span_into(&mut blocks);
-        Ok(if blocks.is_empty() {
+        let block = if blocks.is_empty() {
             CodeBlock::new_span(vec![Operation::Noop])
         } else {
-            combine_blocks(blocks)
-        })
+            combine_sealed_blocks(blocks, &sealed_blocks)
+        };
+        Ok(self.dedup_block(block))
+    }
+
+    /// Appends the specified block to the list of blocks of a code body.
+    ///
+    /// If merging of SPAN blocks across inlined code is enabled, JOIN blocks are flattened into
+    /// the sequence of blocks they are composed of.
+    fn push_block(&self, block: CodeBlock, blocks: &mut Vec<CodeBlock>) {
+        if self.merge_inlined_spans {
+            flatten_block(block, blocks);
+        } else {
+            blocks.push(block);
+        }
+    }
+
+    /// Returns a previously compiled block identical to the specified block if there is one;
+    /// otherwise, records the specified block and returns it.
+    ///
+    /// Blocks with the same hash are not necessarily identical, as hashes of SPAN blocks do not
+    /// commit to their decorators; such blocks are not shared.
+    fn dedup_block(&self, block: CodeBlock) -> CodeBlock {
+        let key: [u8; 32] = block.hash().into();
+        let mut compiled_blocks = self.compiled_blocks.borrow_mut();
+        match compiled_blocks.get(&key) {
+            Some(compiled) if *compiled == block => compiled.clone(),
+            Some(_) => block,
+            None => {
+                compiled_blocks.insert(key, block.clone());
+                block
+            }
+        }
     }
 
     // PROCEDURE CACHE
@@ -473,7 +830,25 @@ pub fn group_vector_elements_2<T, const N: usize>(source: Vec<T>) -> Vec<[T; N]>
     result
 }
 
-fn combine_blocks(mut blocks: Vec<CodeBlock>) -> CodeBlock {
+/// Appends the specified block to the list of blocks, flattening JOIN blocks into the sequence of
+/// blocks they are composed of.
+fn flatten_block(block: CodeBlock, blocks: &mut Vec<CodeBlock>) {
+    match block {
+        CodeBlock::Join(join) => {
+            flatten_block(join.first().clone(), blocks);
+            flatten_block(join.second().clone(), blocks);
+        }
+        block => blocks.push(block),
+    }
+}
+
+fn combine_blocks(blocks: Vec<CodeBlock>) -> CodeBlock {
+    combine_sealed_blocks(blocks, &BTreeSet::new())
+}
+
+/// Combines the specified blocks into a single block; the blocks at the `sealed` indexes are
+/// never merged with adjacent SPAN blocks.
+fn combine_sealed_blocks(mut blocks: Vec<CodeBlock>, sealed: &BTreeSet<usize>) -> CodeBlock {
     debug_assert!(!blocks.is_empty(), "cannot combine empty block list");
     // merge consecutive Span blocks.
     let mut merged_blocks: Vec<CodeBlock> = Vec::with_capacity(blocks.len());
@@ -481,8 +856,8 @@ fn combine_blocks(mut blocks: Vec<CodeBlock>) -> CodeBlock {
     // there is a discontinuity.
     let mut contiguous_spans: Vec<CodeBlock> = Vec::new();
 
-    blocks.drain(0..).for_each(|block| {
-        if block.is_span() {
+    blocks.drain(0..).enumerate().for_each(|(idx, block)| {
+        if block.is_span() && !sealed.contains(&idx) {
             contiguous_spans.push(block);
         } else {
             if !contiguous_spans.is_empty() {
@@ -0,0 +1,221 @@
+use super::{
+    Assembler, AssemblyContext, AssemblyError, CallSet, CodeBlock, LibraryPath, NamedProcedure,
+    ProcedureName, RpoDigest,
+};
+use crate::{
+    ast::{AstSerdeOptions, ModuleAst, ProcedureAttributes},
+    crypto::hash::Blake3_256,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use vm_core::utils::to_hex;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Version of the on-disk cache format. Changing the format (or the way cache keys are derived)
+/// requires bumping this version so that stale entries are never read back.
+const CACHE_FORMAT_VERSION: u8 = 4;
+
+/// Magic bytes at the start of every cache entry.
+const MAGIC: &[u8; 4] = b"MASC";
+
+/// File extension of cache entries.
+const ENTRY_EXTENSION: &str = "masc";
+
+// MODULE CACHE
+// ================================================================================================
+
+/// An on-disk cache of compiled library modules.
+///
+/// Each entry contains procedures compiled from a single module and is keyed by a hash of:
+/// - the module path and its serialized AST,
+/// - MAST roots and attributes of all procedures the module imports from other modules,
+/// - MAST roots of the kernel procedures, and
+/// - the debug mode and SPAN merging flags of the assembler.
+///
+/// Thus, an entry is reused only if neither the module itself nor any of its dependencies have
+/// changed. Entries are stored in separate files named after their keys, so stale entries are
+/// never overwritten and can be safely removed at any time.
+pub struct ModuleCache {
+    dir: PathBuf,
+}
+
+impl ModuleCache {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ModuleCache] backed by the specified directory. The directory is created if
+    /// it does not exist.
+    pub fn new<P: AsRef<Path>>(dir: P) -> Result<Self, AssemblyError> {
+        fs::create_dir_all(dir.as_ref())?;
+        Ok(Self {
+            dir: dir.as_ref().to_path_buf(),
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns compiled procedures stored under the specified key.
+    ///
+    /// Returns None if the entry does not exist or cannot be read; in both cases the module is
+    /// expected to be compiled from source.
+    pub fn load(&self, key: &CacheKey) -> Option<Vec<(usize, NamedProcedure)>> {
+        let bytes = fs::read(self.entry_path(key)).ok()?;
+        read_entry(&bytes).ok()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Stores compiled procedures of a module under the specified key.
+    ///
+    /// The entry is written into a temporary file first and then moved into place so that a
+    /// partially written entry is never observed by a concurrent reader.
+    pub fn store(
+        &self,
+        key: &CacheKey,
+        procs: &[(usize, NamedProcedure)],
+    ) -> Result<(), AssemblyError> {
+        let mut bytes = Vec::new();
+        write_entry(&mut bytes, procs);
+
+        let entry_path = self.entry_path(key);
+        let tmp_path = entry_path.with_extension(format!("{ENTRY_EXTENSION}.tmp"));
+        fs::write(&tmp_path, bytes)?;
+        fs::rename(&tmp_path, &entry_path)?;
+        Ok(())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn entry_path(&self, key: &CacheKey) -> PathBuf {
+        self.dir.join(format!("{}.{ENTRY_EXTENSION}", key.0))
+    }
+}
+
+// CACHE KEY
+// ================================================================================================
+
+/// Hex-encoded hash identifying a cache entry.
+pub struct CacheKey(String);
+
+impl Assembler {
+    /// Returns the key under which compiled procedures of the specified module are cached, or
+    /// None if the module should not be cached.
+    ///
+    /// Only modules which have a library path and are not part of a kernel are cached. Building
+    /// the key requires MAST roots of all imported procedures; thus, all modules the specified
+    /// module depends on are compiled (or loaded from the cache) as a part of this process.
+    pub(super) fn module_cache_key(
+        &self,
+        module: &ModuleAst,
+        path: Option<&LibraryPath>,
+        context: &mut AssemblyContext,
+    ) -> Result<Option<CacheKey>, AssemblyError> {
+        let path = match path {
+            Some(path) if self.cache.is_some() && !context.is_kernel() => path,
+            _ => return Ok(None),
+        };
+
+        let mut data = Vec::new();
+        data.write_u8(CACHE_FORMAT_VERSION);
+        path.write_into(&mut data);
+        data.write_bytes(&module.to_bytes(AstSerdeOptions::new(true)));
+        data.write_bool(self.in_debug_mode);
+        data.write_bool(self.merge_inlined_spans);
+
+        data.write_usize(self.kernel.proc_hashes().len());
+        data.write_many(self.kernel.proc_hashes());
+
+        let imported_procs = module.import_info().get_imported_procedures();
+        data.write_usize(imported_procs.len());
+        for proc_id in imported_procs.keys() {
+            self.ensure_procedure_is_in_cache(proc_id, context)?;
+            let proc_cache = self.proc_cache.borrow();
+            let proc = proc_cache.get_by_id(proc_id).expect("procedure not in cache");
+            proc_id.write_into(&mut data);
+            proc.mast_root().write_into(&mut data);
+            // attributes of imported procedures determine how they are inlined into the module
+            proc.attributes().write_into(&mut data);
+        }
+
+        let hash = Blake3_256::hash(&data);
+        let key = to_hex(&(*hash)[..]).expect("failed to encode cache key");
+        Ok(Some(CacheKey(key)))
+    }
+
+    /// Returns procedures stored in the module cache under the specified key, if any.
+    pub(super) fn load_cached_module(
+        &self,
+        key: Option<&CacheKey>,
+    ) -> Option<Vec<(usize, NamedProcedure)>> {
+        self.cache.as_ref().zip(key).and_then(|(cache, key)| cache.load(key))
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+fn write_entry(target: &mut Vec<u8>, procs: &[(usize, NamedProcedure)]) {
+    target.write_bytes(MAGIC);
+    target.write_u8(CACHE_FORMAT_VERSION);
+
+    target.write_usize(procs.len());
+    for (proc_index, proc) in procs {
+        target.write_usize(*proc_index);
+        proc.name().write_into(target);
+        target.write_bool(proc.is_export());
+        proc.attributes().write_into(target);
+        target.write_u32(proc.num_locals());
+        proc.code().write_into(target);
+        target.write_usize(proc.callset().len());
+        proc.callset().iter().for_each(|mast_root| mast_root.write_into(target));
+    }
+}
+
+fn read_entry(bytes: &[u8]) -> Result<Vec<(usize, NamedProcedure)>, DeserializationError> {
+    let mut source = SliceReader::new(bytes);
+
+    let magic: [u8; 4] = source.read_array()?;
+    let version = source.read_u8()?;
+    if &magic != MAGIC || version != CACHE_FORMAT_VERSION {
+        return Err(DeserializationError::InvalidValue("unsupported cache entry".to_string()));
+    }
+
+    let num_procs = source.read_usize()?;
+    let mut procs = Vec::new();
+    for _ in 0..num_procs {
+        let proc_index = source.read_usize()?;
+        let name = ProcedureName::read_from(&mut source)?;
+        let is_export = source.read_bool()?;
+        let attributes = ProcedureAttributes::read_from(&mut source)?;
+        let num_locals = source.read_u32()?;
+        let code = CodeBlock::read_from(&mut source)?;
+
+        let mut callset = CallSet::default();
+        let callset_len = source.read_usize()?;
+        for _ in 0..callset_len {
+            callset.insert(RpoDigest::read_from(&mut source)?);
+        }
+
+        let proc = NamedProcedure::new(name, is_export, num_locals, code, callset)
+            .with_attributes(attributes);
+        procs.push((proc_index, proc));
+    }
+
+    if source.has_more_bytes() {
+        return Err(DeserializationError::UnconsumedBytes);
+    }
+
+    Ok(procs)
+}
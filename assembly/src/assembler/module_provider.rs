@@ -1,5 +1,5 @@
-use super::{Library, LibraryError, Module, ProcedureId};
-use alloc::collections::BTreeMap;
+use super::{Library, LibraryError, LibraryPath, Module, ModuleAst, ProcedureId};
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::vec::Vec;
 
 // MODULE PROVIDER
@@ -10,8 +10,14 @@ use alloc::vec::Vec;
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct ModuleProvider {
     modules: Vec<Module>,
+    /// Map from module path to the index of the module with this path.
+    paths: BTreeMap<LibraryPath, usize>,
     /// Map from procedure id to the index of a module in which the procedure is defined.
     procedures: BTreeMap<ProcedureId, usize>,
+    /// Map from the index of a module to an import cycle reachable from the module.
+    import_cycles: BTreeMap<usize, Vec<LibraryPath>>,
+    /// Same as `import_cycles`, but ignoring cycles passing through constants-only modules.
+    procedure_import_cycles: BTreeMap<usize, Vec<LibraryPath>>,
 }
 
 impl ModuleProvider {
@@ -24,6 +30,51 @@ impl ModuleProvider {
         self.procedures.get(id).map(|i| &self.modules[*i])
     }
 
+    /// Returns a chain of module paths forming an import cycle which is reachable from the
+    /// provided module via `use` statements, or None if there is no such cycle.
+    ///
+    /// The returned chain starts and ends with the same module path (e.g., `[a, b, c, a]`).
+    /// Imports of modules which are not available in this provider are ignored.
+    ///
+    /// Import cycles between the modules of this provider are detected when libraries are added
+    /// to it, and thus, for a module of this provider, this is a lookup by its path. For other
+    /// modules, only the imports of the provided module are examined.
+    ///
+    /// If `allow_constants_only` is set to true, cycles passing through modules which do not
+    /// contain any procedures (i.e., modules containing only constants) are not reported.
+    pub fn find_import_cycle(
+        &self,
+        path: &LibraryPath,
+        ast: &ModuleAst,
+        allow_constants_only: bool,
+    ) -> Option<Vec<LibraryPath>> {
+        let cycles = if allow_constants_only {
+            &self.procedure_import_cycles
+        } else {
+            &self.import_cycles
+        };
+        if let Some(module_idx) = self.paths.get(path) {
+            return cycles.get(module_idx).cloned();
+        }
+
+        for import_path in ast.import_info().import_paths() {
+            if import_path == path {
+                return Some(vec![path.clone(), path.clone()]);
+            }
+            let cycle = self
+                .paths
+                .get(import_path)
+                .filter(|&&idx| {
+                    !(allow_constants_only && self.modules[idx].ast.is_constants_only())
+                })
+                .and_then(|idx| cycles.get(idx));
+            if cycle.is_some() {
+                return cycle.cloned();
+            }
+        }
+        None
+    }
+
     // MODULE AND LIBRARY MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -33,7 +84,7 @@ impl ModuleProvider {
     ///
     /// Will error if there is a duplicated module path.
     fn add_module(&mut self, module: Module) -> Result<(), LibraryError> {
-        if self.modules.iter().any(|m| module.path == m.path) {
+        if self.paths.contains_key(&module.path) {
             return Err(LibraryError::duplicate_module_path(&module.path));
         }
         let module_idx = self.modules.len();
@@ -47,12 +98,16 @@ impl ModuleProvider {
             let proc_id = ProcedureId::from(&proc_path);
             self.procedures.insert(proc_id, module_idx);
         }
+        self.paths.insert(module.path.clone(), module_idx);
         self.modules.push(module);
         Ok(())
     }
 
     /// Adds all modules from the provided library to this module provider.
     ///
+    /// Once the modules are added, import cycles reachable from every module of this provider
+    /// are detected in a single pass over the import graph.
+    ///
     /// # Errors
     ///
     /// Will error if there is a duplicated module path.
@@ -64,6 +119,92 @@ impl ModuleProvider {
         library.modules().try_for_each(|module| {
             module.check_namespace(namespace)?;
             self.add_module(module.clone())
-        })
+        })?;
+
+        self.import_cycles = self.detect_import_cycles(false);
+        self.procedure_import_cycles = self.detect_import_cycles(true);
+        Ok(())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a map from the index of every module from which an import cycle is reachable to
+    /// the first such cycle found.
+    ///
+    /// Every module is explored only once via a depth-first search; modules reaching an explored
+    /// module are assigned the cycle reachable from it, if any.
+    fn detect_import_cycles(
+        &self,
+        allow_constants_only: bool,
+    ) -> BTreeMap<usize, Vec<LibraryPath>> {
+        let mut cycles = BTreeMap::new();
+        let mut visited = BTreeSet::new();
+        let mut chain = Vec::new();
+        for (module_idx, module) in self.modules.iter().enumerate() {
+            if visited.contains(&module_idx)
+                || (allow_constants_only && module.ast.is_constants_only())
+            {
+                continue;
+            }
+            self.visit_imports(
+                module_idx,
+                &mut chain,
+                &mut visited,
+                &mut cycles,
+                allow_constants_only,
+            );
+        }
+        cycles
+    }
+
+    /// Performs a depth-first search for an import cycle starting at the specified module, and
+    /// returns the cycle found, if any.
+    ///
+    /// `chain` holds the modules on the path from the module at which the search started, and
+    /// every module which has been fully explored is added to `visited`. If a cycle is found, it
+    /// is assigned to every module on the path.
+    fn visit_imports(
+        &self,
+        module_idx: usize,
+        chain: &mut Vec<usize>,
+        visited: &mut BTreeSet<usize>,
+        cycles: &mut BTreeMap<usize, Vec<LibraryPath>>,
+        allow_constants_only: bool,
+    ) -> Option<Vec<LibraryPath>> {
+        chain.push(module_idx);
+        let mut result = None;
+        for import_path in self.modules[module_idx].ast.import_info().import_paths() {
+            let import_idx = match self.paths.get(import_path) {
+                Some(&idx) => idx,
+                None => continue,
+            };
+            if allow_constants_only && self.modules[import_idx].ast.is_constants_only() {
+                continue;
+            }
+
+            result = if let Some(pos) = chain.iter().position(|&idx| idx == import_idx) {
+                let mut cycle = chain[pos..]
+                    .iter()
+                    .map(|&idx| self.modules[idx].path.clone())
+                    .collect::<Vec<_>>();
+                cycle.push(import_path.clone());
+                Some(cycle)
+            } else if visited.contains(&import_idx) {
+                cycles.get(&import_idx).cloned()
+            } else {
+                self.visit_imports(import_idx, chain, visited, cycles, allow_constants_only)
+            };
+            if result.is_some() {
+                break;
+            }
+        }
+        chain.pop();
+
+        visited.insert(module_idx);
+        if let Some(cycle) = &result {
+            cycles.insert(module_idx, cycle.clone());
+        }
+        result
     }
 }
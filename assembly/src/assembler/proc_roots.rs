@@ -0,0 +1,209 @@
+use super::{LibraryPath, ProcedureName, RpoDigest};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{fmt, str::from_utf8};
+use vm_core::utils::{
+    write_hex_bytes, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+};
+
+// PROCEDURE ROOTS
+// ================================================================================================
+
+/// A mapping of fully-qualified procedure paths to MAST roots of the respective procedures.
+///
+/// Entries are always kept sorted by procedure path. Thus, the textual representation of this
+/// struct (as produced by its [fmt::Display] implementation) is deterministic: it contains one
+/// procedure per line, where each line consists of the fully-qualified procedure path followed by
+/// a single space and the hex-encoded MAST root of the procedure. For example:
+///
+/// ```text
+/// std::math::u64::checked_add 0x1e5b...
+/// std::math::u64::checked_sub 0x7d2e...
+/// ```
+///
+/// This makes the report suitable for committing alongside a library or a kernel and for diffing
+/// across releases. The report can also be serialized into a binary form, which allows pinning
+/// the roots of a library at build time and checking them when the library is loaded.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ProcedureRoots {
+    roots: BTreeMap<String, RpoDigest>,
+}
+
+impl ProcedureRoots {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the MAST root of the procedure with the specified fully-qualified path, if any.
+    pub fn get(&self, proc_path: &str) -> Option<&RpoDigest> {
+        self.roots.get(proc_path)
+    }
+
+    /// Returns the number of procedures in this report.
+    pub fn len(&self) -> usize {
+        self.roots.len()
+    }
+
+    /// Returns true if this report does not contain any procedures.
+    pub fn is_empty(&self) -> bool {
+        self.roots.is_empty()
+    }
+
+    /// Returns an iterator over (procedure path, MAST root) pairs of this report sorted by
+    /// procedure path.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &RpoDigest)> {
+        self.roots.iter().map(|(path, root)| (path.as_str(), root))
+    }
+
+    /// Returns a list of differences between this report and the `other` report.
+    ///
+    /// This report is treated as the old version and `other` as the new one. The differences are
+    /// sorted by procedure path.
+    pub fn diff(&self, other: &Self) -> Vec<ProcedureRootChange> {
+        let mut changes = Vec::new();
+
+        for (path, old_root) in self.roots.iter() {
+            match other.roots.get(path) {
+                Some(new_root) if new_root != old_root => {
+                    changes.push(ProcedureRootChange::Changed {
+                        path: path.clone(),
+                        old_root: *old_root,
+                        new_root: *new_root,
+                    });
+                }
+                Some(_) => (),
+                None => changes.push(ProcedureRootChange::Removed {
+                    path: path.clone(),
+                    root: *old_root,
+                }),
+            }
+        }
+
+        for (path, new_root) in other.roots.iter() {
+            if !self.roots.contains_key(path) {
+                changes.push(ProcedureRootChange::Added {
+                    path: path.clone(),
+                    root: *new_root,
+                });
+            }
+        }
+
+        changes.sort_by(|a, b| a.path().cmp(b.path()));
+        changes
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the MAST root of the procedure with the specified name exported from the module at the
+    /// specified path. If the report already contained an entry for this procedure, the previous
+    /// MAST root is returned.
+    pub(super) fn insert(
+        &mut self,
+        module_path: &LibraryPath,
+        proc_name: &ProcedureName,
+        root: RpoDigest,
+    ) -> Option<RpoDigest> {
+        let proc_path = module_path.append_unchecked(proc_name.as_str());
+        self.roots.insert(proc_path, root)
+    }
+}
+
+impl fmt::Display for ProcedureRoots {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (path, root) in self.roots.iter() {
+            write!(f, "{path} ")?;
+            write_hex_bytes(f, &root.as_bytes())?;
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serializable for ProcedureRoots {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.roots.len());
+        for (path, root) in self.roots.iter() {
+            target.write_usize(path.len());
+            target.write_bytes(path.as_bytes());
+            root.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for ProcedureRoots {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_roots = source.read_usize()?;
+        let mut roots = BTreeMap::new();
+        for _ in 0..num_roots {
+            let path_len = source.read_usize()?;
+            let path = source.read_vec(path_len)?;
+            let path = from_utf8(&path)
+                .map_err(|e| DeserializationError::InvalidValue(e.to_string()))?
+                .to_string();
+            let root = RpoDigest::read_from(source)?;
+            if roots.insert(path, root).is_some() {
+                return Err(DeserializationError::InvalidValue(
+                    "duplicate procedure path".to_string(),
+                ));
+            }
+        }
+        Ok(Self { roots })
+    }
+}
+
+// PROCEDURE ROOT CHANGE
+// ================================================================================================
+
+/// A difference in a single procedure between two [ProcedureRoots] reports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProcedureRootChange {
+    /// The procedure is present only in the new report.
+    Added { path: String, root: RpoDigest },
+    /// The procedure is present only in the old report.
+    Removed { path: String, root: RpoDigest },
+    /// The procedure is present in both reports, but its MAST root has changed.
+    Changed {
+        path: String,
+        old_root: RpoDigest,
+        new_root: RpoDigest,
+    },
+}
+
+impl ProcedureRootChange {
+    /// Returns the fully-qualified path of the procedure affected by this change.
+    pub fn path(&self) -> &str {
+        match self {
+            Self::Added { path, .. } | Self::Removed { path, .. } | Self::Changed { path, .. } => {
+                path
+            }
+        }
+    }
+}
+
+impl fmt::Display for ProcedureRootChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Added { path, root } => {
+                write!(f, "+ {path} ")?;
+                write_hex_bytes(f, &root.as_bytes())
+            }
+            Self::Removed { path, root } => {
+                write!(f, "- {path} ")?;
+                write_hex_bytes(f, &root.as_bytes())
+            }
+            Self::Changed {
+                path,
+                old_root,
+                new_root,
+            } => {
+                write!(f, "~ {path} ")?;
+                write_hex_bytes(f, &old_root.as_bytes())?;
+                write!(f, " -> ")?;
+                write_hex_bytes(f, &new_root.as_bytes())
+            }
+        }
+    }
+}
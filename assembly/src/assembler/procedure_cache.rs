@@ -43,6 +43,8 @@ impl ProcedureCache {
 
     /// Returns a MAST root ([RpoDigest]) reference corresponding to the provided [ProcedureId].
     pub fn get_proc_root_by_id(&self, id: &ProcedureId) -> Option<RpoDigest> {
+        // if the procedure ID is an alias, the MAST root is stored under the ID it refers to
+        let id = self.proc_aliases.get(id).unwrap_or(id);
         self.proc_id_map.get(id).cloned()
     }
 
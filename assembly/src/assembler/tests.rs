@@ -141,3 +141,130 @@ fn nested_blocks() {
 
     assert_eq!(combined.hash(), program.hash());
 }
+
+#[test]
+fn merged_spans() {
+    let program = r#"
+    proc.foo
+        push.3
+        if.true
+            push.5
+        end
+        push.7
+    end
+
+    begin
+        push.2
+        exec.foo
+        push.11
+        repeat.2
+            exec.foo
+        end
+    end"#;
+
+    let push = |value: u32| Operation::Push(value.into());
+    let span = |ops: Vec<Operation>| CodeBlock::new_span(ops);
+    let r#if =
+        || CodeBlock::new_split(span(vec![push(5)]), CodeBlock::new_span(vec![Operation::Noop]));
+
+    // by default, the block of the inlined procedure is kept intact
+    let foo = combine_blocks(vec![span(vec![push(3)]), r#if(), span(vec![push(7)])]);
+    let expected = combine_blocks(vec![
+        span(vec![push(2)]),
+        foo.clone(),
+        span(vec![push(11)]),
+        foo.clone(),
+        foo,
+    ]);
+    let program_default = Assembler::default().compile(program).unwrap();
+    assert_eq!(expected.hash(), program_default.hash());
+
+    // when SPAN blocks are merged, straight-line code surrounding the control flow of the inlined
+    // procedure ends up in the same blocks
+    let expected = combine_blocks(vec![
+        span(vec![push(2), push(3)]),
+        r#if(),
+        span(vec![push(7), push(11), push(3)]),
+        r#if(),
+        span(vec![push(7), push(3)]),
+        r#if(),
+        span(vec![push(7)]),
+    ]);
+    let program_merged = Assembler::default().with_merged_spans(true).compile(program).unwrap();
+    assert_eq!(expected.hash(), program_merged.hash());
+}
+
+#[test]
+fn inline_attributes() {
+    let program = r#"
+    @inline
+    proc.foo
+        push.3
+        if.true
+            push.5
+        end
+        push.7
+    end
+
+    @no_inline
+    proc.bar
+        push.13
+    end
+
+    begin
+        push.2
+        exec.foo
+        exec.bar
+        push.11
+    end"#;
+
+    let push = |value: u32| Operation::Push(value.into());
+    let span = |ops: Vec<Operation>| CodeBlock::new_span(ops);
+    let r#if = CodeBlock::new_split(span(vec![push(5)]), span(vec![Operation::Noop]));
+
+    // the body of `foo` is always flattened into the caller, while the body of `bar` is never
+    // merged with the adjacent SPAN blocks
+    let expected = CodeBlock::new_join([
+        CodeBlock::new_join([
+            CodeBlock::new_join([span(vec![push(2), push(3)]), r#if]),
+            CodeBlock::new_join([span(vec![push(7)]), span(vec![push(13)])]),
+        ]),
+        span(vec![push(11)]),
+    ]);
+    let program_default = Assembler::default().compile(program).unwrap();
+    assert_eq!(expected.hash(), program_default.hash());
+    let program_merged = Assembler::default().with_merged_spans(true).compile(program).unwrap();
+    assert_eq!(expected.hash(), program_merged.hash());
+}
+
+#[test]
+fn kernel_metadata() {
+    const KERNEL: &str = r#"
+        proc.helper
+            push.1
+        end
+
+        export.foo
+            exec.helper
+            add
+        end
+
+        export.bar
+            mul
+        end"#;
+
+    let assembler = Assembler::default().with_kernel(KERNEL).unwrap();
+    let metadata = assembler.kernel_metadata();
+    assert!(metadata.describes(assembler.kernel()));
+
+    // only exported procedures are listed, together with their names
+    assert_eq!(metadata.procedures().len(), 2);
+    let foo = metadata.get_digest("foo").unwrap();
+    let bar = metadata.get_digest("bar").unwrap();
+    assert!(metadata.get_digest("helper").is_none());
+    assert_eq!(metadata.get_name(&foo), Some("foo"));
+    assert_eq!(metadata.get_name(&bar), Some("bar"));
+
+    // assemblers without a kernel have empty metadata
+    assert!(Assembler::default().kernel_metadata().procedures().is_empty());
+}
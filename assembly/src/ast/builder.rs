@@ -0,0 +1,467 @@
+use super::{CodeBody, CodeBuilderError, Felt, Instruction, Node, StarkField};
+use alloc::{string::ToString, vec::Vec};
+
+// CODE BUILDER
+// ================================================================================================
+
+/// A builder of Miden assembly code which tracks the depth of the operand stack.
+///
+/// The builder is instantiated with the number of elements which are expected to be on the stack
+/// when the code starts executing. Every appended instruction is checked against the current
+/// stack depth: if an instruction would consume more elements than there are on the stack, an
+/// error is returned and the instruction is not appended. This allows code generators to catch
+/// stack underflows when the code is built, rather than when the program is executed.
+///
+/// Control flow blocks are built via nested builders. For example:
+/// ```ignore
+/// let mut builder = CodeBuilder::new(1);
+/// builder.push(2)?.mem_load(0)?.add()?.dup(0)?;
+/// builder.if_else(
+///     |then| then.push(1)?.add().map(drop),
+///     |els| els.push(2)?.mul().map(drop),
+/// )?;
+/// let program = ProgramAst::new(builder.build(), Vec::new())?;
+/// ```
+///
+/// Note that the VM itself never underflows the stack (it is padded with zeros); thus, the depth
+/// tracked by the builder is the number of elements the code generator is aware of.
+#[derive(Debug, Clone, Default)]
+pub struct CodeBuilder {
+    nodes: Vec<Node>,
+    depth: usize,
+    min_depth: usize,
+}
+
+impl CodeBuilder {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [CodeBuilder] for code which expects `num_inputs` elements on the stack.
+    pub fn new(num_inputs: usize) -> Self {
+        Self {
+            nodes: Vec::new(),
+            depth: num_inputs,
+            min_depth: num_inputs,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of elements on the stack after executing the code built so far.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the nodes built so far.
+    pub fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// Consumes the builder and returns the built nodes.
+    pub fn build(self) -> Vec<Node> {
+        self.nodes
+    }
+
+    // INSTRUCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Appends the specified instruction.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The instruction consumes more elements than there are on the stack.
+    /// - The stack effect of the instruction is not known to the builder (e.g., `dynexec`); such
+    ///   instructions can be appended via [CodeBuilder::instruction_with_effect()].
+    pub fn instruction(&mut self, instruction: Instruction) -> Result<&mut Self, CodeBuilderError> {
+        let (num_inputs, num_outputs) = stack_effect(&instruction)
+            .ok_or_else(|| CodeBuilderError::UnknownStackEffect(instruction.to_string()))?;
+        self.instruction_with_effect(instruction, num_inputs, num_outputs)
+    }
+
+    /// Appends the specified instruction which consumes `num_inputs` elements from the top of the
+    /// stack and leaves `num_outputs` elements in their place.
+    ///
+    /// # Errors
+    /// Returns an error if the instruction consumes more elements than there are on the stack.
+    pub fn instruction_with_effect(
+        &mut self,
+        instruction: Instruction,
+        num_inputs: usize,
+        num_outputs: usize,
+    ) -> Result<&mut Self, CodeBuilderError> {
+        self.apply_effect(&instruction.to_string(), num_inputs, num_outputs)?;
+        self.nodes.push(Node::Instruction(instruction));
+        Ok(self)
+    }
+
+    /// Pushes the specified value onto the stack.
+    ///
+    /// # Errors
+    /// Returns an error if the value is not a valid field element.
+    pub fn push(&mut self, value: u64) -> Result<&mut Self, CodeBuilderError> {
+        let instruction = if let Ok(value) = u8::try_from(value) {
+            Instruction::PushU8(value)
+        } else if let Ok(value) = u16::try_from(value) {
+            Instruction::PushU16(value)
+        } else if let Ok(value) = u32::try_from(value) {
+            Instruction::PushU32(value)
+        } else if value < Felt::MODULUS {
+            Instruction::PushFelt(Felt::new(value))
+        } else {
+            return Err(CodeBuilderError::invalid_immediate("push", value));
+        };
+        self.instruction(instruction)
+    }
+
+    /// Pushes the value located in memory at the specified address onto the stack.
+    pub fn mem_load(&mut self, addr: u32) -> Result<&mut Self, CodeBuilderError> {
+        self.instruction(Instruction::MemLoadImm(addr))
+    }
+
+    /// Pops the value from the top of the stack and stores it in memory at the specified address.
+    pub fn mem_store(&mut self, addr: u32) -> Result<&mut Self, CodeBuilderError> {
+        self.instruction(Instruction::MemStoreImm(addr))
+    }
+
+    /// Pushes the value of the specified procedure local onto the stack.
+    pub fn loc_load(&mut self, index: u16) -> Result<&mut Self, CodeBuilderError> {
+        self.instruction(Instruction::LocLoad(index))
+    }
+
+    /// Pops the value from the top of the stack and stores it in the specified procedure local.
+    pub fn loc_store(&mut self, index: u16) -> Result<&mut Self, CodeBuilderError> {
+        self.instruction(Instruction::LocStore(index))
+    }
+
+    /// Appends the `add` instruction.
+    pub fn add(&mut self) -> Result<&mut Self, CodeBuilderError> {
+        self.instruction(Instruction::Add)
+    }
+
+    /// Appends the `sub` instruction.
+    pub fn sub(&mut self) -> Result<&mut Self, CodeBuilderError> {
+        self.instruction(Instruction::Sub)
+    }
+
+    /// Appends the `mul` instruction.
+    pub fn mul(&mut self) -> Result<&mut Self, CodeBuilderError> {
+        self.instruction(Instruction::Mul)
+    }
+
+    /// Appends the `eq` instruction.
+    pub fn eq(&mut self) -> Result<&mut Self, CodeBuilderError> {
+        self.instruction(Instruction::Eq)
+    }
+
+    /// Appends the `drop` instruction.
+    pub fn drop(&mut self) -> Result<&mut Self, CodeBuilderError> {
+        self.instruction(Instruction::Drop)
+    }
+
+    /// Pushes a copy of the n-th stack element onto the stack.
+    ///
+    /// # Errors
+    /// Returns an error if `n` is greater than 15.
+    pub fn dup(&mut self, n: u8) -> Result<&mut Self, CodeBuilderError> {
+        use Instruction::*;
+        #[rustfmt::skip]
+        let instruction = match n {
+            0 => Dup0, 1 => Dup1, 2 => Dup2, 3 => Dup3, 4 => Dup4, 5 => Dup5, 6 => Dup6,
+            7 => Dup7, 8 => Dup8, 9 => Dup9, 10 => Dup10, 11 => Dup11, 12 => Dup12, 13 => Dup13,
+            14 => Dup14, 15 => Dup15,
+            _ => return Err(CodeBuilderError::invalid_immediate("dup", n)),
+        };
+        self.instruction(instruction)
+    }
+
+    /// Swaps the top stack element with the n-th stack element.
+    ///
+    /// # Errors
+    /// Returns an error if `n` is zero or greater than 15.
+    pub fn swap(&mut self, n: u8) -> Result<&mut Self, CodeBuilderError> {
+        use Instruction::*;
+        #[rustfmt::skip]
+        let instruction = match n {
+            1 => Swap1, 2 => Swap2, 3 => Swap3, 4 => Swap4, 5 => Swap5, 6 => Swap6, 7 => Swap7,
+            8 => Swap8, 9 => Swap9, 10 => Swap10, 11 => Swap11, 12 => Swap12, 13 => Swap13,
+            14 => Swap14, 15 => Swap15,
+            _ => return Err(CodeBuilderError::invalid_immediate("swap", n)),
+        };
+        self.instruction(instruction)
+    }
+
+    /// Appends an `exec` of the local procedure with the specified index. The procedure is
+    /// expected to consume `num_inputs` elements and to leave `num_outputs` elements in their
+    /// place.
+    pub fn exec(
+        &mut self,
+        proc_index: u16,
+        num_inputs: usize,
+        num_outputs: usize,
+    ) -> Result<&mut Self, CodeBuilderError> {
+        self.instruction_with_effect(Instruction::ExecLocal(proc_index), num_inputs, num_outputs)
+    }
+
+    // CONTROL FLOW
+    // --------------------------------------------------------------------------------------------
+
+    /// Appends an `if.true ... else ... end` block. The condition is popped from the top of the
+    /// stack, and the branches are built via the provided closures.
+    ///
+    /// # Errors
+    /// Returns an error if the stack is empty, if building either of the branches fails, or if
+    /// the branches leave the stack at different depths.
+    pub fn if_else<T, E>(
+        &mut self,
+        then_branch: T,
+        else_branch: E,
+    ) -> Result<&mut Self, CodeBuilderError>
+    where
+        T: FnOnce(&mut CodeBuilder) -> Result<(), CodeBuilderError>,
+        E: FnOnce(&mut CodeBuilder) -> Result<(), CodeBuilderError>,
+    {
+        self.check_depth("if.true", 1)?;
+        let then_branch = self.build_block(then_branch, self.depth - 1)?;
+        let else_branch = self.build_block(else_branch, self.depth - 1)?;
+        if then_branch.depth != else_branch.depth {
+            return Err(CodeBuilderError::BranchDepthMismatch(
+                then_branch.depth,
+                else_branch.depth,
+            ));
+        }
+
+        self.apply_effect("if.true", 1, 0)?;
+        self.update_min_depth(then_branch.min_depth.min(else_branch.min_depth));
+        self.depth = then_branch.depth;
+        self.nodes.push(Node::IfElse {
+            true_case: CodeBody::new(then_branch.nodes),
+            false_case: CodeBody::new(else_branch.nodes),
+        });
+        Ok(self)
+    }
+
+    /// Appends a `while.true ... end` loop. The condition is popped from the top of the stack
+    /// before each iteration, and the body is built via the provided closure.
+    ///
+    /// # Errors
+    /// Returns an error if the stack is empty, if building the body fails, or if the body does
+    /// not leave exactly one more element (the condition for the next iteration) on the stack than
+    /// there was at its start.
+    pub fn while_true<B>(&mut self, body: B) -> Result<&mut Self, CodeBuilderError>
+    where
+        B: FnOnce(&mut CodeBuilder) -> Result<(), CodeBuilderError>,
+    {
+        self.check_depth("while.true", 1)?;
+        let body = self.build_block(body, self.depth - 1)?;
+        if body.depth != self.depth {
+            return Err(CodeBuilderError::LoopDepthMismatch(self.depth, body.depth));
+        }
+
+        self.apply_effect("while.true", 1, 0)?;
+        self.update_min_depth(body.min_depth);
+        self.nodes.push(Node::While {
+            body: CodeBody::new(body.nodes),
+        });
+        Ok(self)
+    }
+
+    /// Appends a `repeat.<times> ... end` block with the body built via the provided closure.
+    ///
+    /// # Errors
+    /// Returns an error if `times` is zero, if building the body fails, or if any of the
+    /// iterations would consume more elements than there are on the stack.
+    pub fn repeat<B>(&mut self, times: u32, body: B) -> Result<&mut Self, CodeBuilderError>
+    where
+        B: FnOnce(&mut CodeBuilder) -> Result<(), CodeBuilderError>,
+    {
+        if times == 0 {
+            return Err(CodeBuilderError::invalid_immediate("repeat", times));
+        }
+        let body = self.build_block(body, self.depth)?;
+
+        // the body requires `deficit` elements below its starting depth; if the body reduces the
+        // stack depth, the last iteration starts at the lowest depth
+        let deficit = self.depth - body.min_depth;
+        let depth = self.depth as i64;
+        let delta = body.depth as i64 - depth;
+        let last_start = depth + delta * (times as i64 - 1);
+        if delta < 0 && last_start < deficit as i64 {
+            return Err(CodeBuilderError::StackUnderflow {
+                instruction: format!("repeat.{times}"),
+                required: (deficit as i64 - delta * (times as i64 - 1)) as usize,
+                depth: self.depth,
+            });
+        }
+
+        let end_depth = depth + delta * times as i64;
+        self.update_min_depth((last_start.min(depth) - deficit as i64) as usize);
+        self.depth = end_depth as usize;
+        self.nodes.push(Node::Repeat {
+            times,
+            body: CodeBody::new(body.nodes),
+        });
+        Ok(self)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds a nested block starting at the specified stack depth.
+    fn build_block<B>(&self, build: B, depth: usize) -> Result<CodeBuilder, CodeBuilderError>
+    where
+        B: FnOnce(&mut CodeBuilder) -> Result<(), CodeBuilderError>,
+    {
+        let mut builder = CodeBuilder::new(depth);
+        build(&mut builder)?;
+        Ok(builder)
+    }
+
+    fn check_depth(&self, instruction: &str, num_inputs: usize) -> Result<(), CodeBuilderError> {
+        if num_inputs > self.depth {
+            return Err(CodeBuilderError::StackUnderflow {
+                instruction: instruction.to_string(),
+                required: num_inputs,
+                depth: self.depth,
+            });
+        }
+        Ok(())
+    }
+
+    fn apply_effect(
+        &mut self,
+        instruction: &str,
+        num_inputs: usize,
+        num_outputs: usize,
+    ) -> Result<(), CodeBuilderError> {
+        self.check_depth(instruction, num_inputs)?;
+        self.update_min_depth(self.depth - num_inputs);
+        self.depth = self.depth - num_inputs + num_outputs;
+        Ok(())
+    }
+
+    fn update_min_depth(&mut self, depth: usize) {
+        self.min_depth = self.min_depth.min(depth);
+    }
+}
+
+// STACK EFFECTS
+// ================================================================================================
+
+/// Returns the number of elements consumed from the top of the stack by the specified instruction
+/// and the number of elements the instruction leaves in their place, or None if the stack effect
+/// of the instruction is not known statically.
+pub fn stack_effect(instruction: &Instruction) -> Option<(usize, usize)> {
+    use Instruction::*;
+
+    let effect = match instruction {
+        Assert | AssertWithError(_) | Assertz | AssertzWithError(_) => (1, 0),
+        AssertEq | AssertEqWithError(_) => (2, 0),
+        AssertEqw | AssertEqwWithError(_) => (8, 0),
+
+        Add | Sub | Mul | Div | Exp | ExpBitLength(_) | And | Or | Xor | Eq | Neq | Lt | Lte
+        | Gt | Gte => (2, 1),
+        AddImm(_) | SubImm(_) | MulImm(_) | DivImm(_) | ExpImm(_) | EqImm(_) | NeqImm(_) | Neg
+        | Inv | Incr | Pow2 | ILog2 | Not | IsOdd | RangeCheck(_) => (1, 1),
+        Eqw => (8, 9),
+
+        Ext2Add | Ext2Sub | Ext2Mul | Ext2Div => (4, 2),
+        Ext2Neg | Ext2Inv => (2, 2),
+
+        U32Test => (1, 2),
+        U32TestW => (4, 5),
+        U32Assert | U32AssertWithError(_) | U32Cast => (1, 1),
+        U32Assert2 | U32Assert2WithError(_) => (2, 2),
+        U32AssertW | U32AssertWWithError(_) => (4, 4),
+        U32AssertN(n) | U32AssertNWithError(n, _) => (*n as usize, *n as usize),
+        U32Split => (1, 2),
+        U32WrappingAdd | U32WrappingSub | U32WrappingMul | U32Div | U32Mod | U32And | U32Or
+        | U32Xor | U32Shr | U32Shl | U32Rotr | U32Rotl | U32Lt | U32Lte | U32Gt | U32Gte
+        | U32Min | U32Max => (2, 1),
+        U32WrappingAddImm(_) | U32WrappingSubImm(_) | U32WrappingMulImm(_) | U32DivImm(_)
+        | U32ModImm(_) | U32ShrImm(_) | U32ShlImm(_) | U32RotrImm(_) | U32RotlImm(_) | U32Not
+        | U32Popcnt | U32Clz | U32Ctz | U32Clo | U32Cto | U32Bswap | U32Extract(..) => (1, 1),
+        U32Insert(..) | U32Fshl(_) | U32Fshr(_) => (2, 1),
+        U32OverflowingAdd | U32OverflowingSub | U32OverflowingMul | U32DivMod => (2, 2),
+        U32OverflowingAddImm(_)
+        | U32OverflowingSubImm(_)
+        | U32OverflowingMulImm(_)
+        | U32DivModImm(_) => (1, 2),
+        U32OverflowingAdd3 | U32OverflowingMadd => (3, 2),
+        U32WrappingAdd3 | U32WrappingMadd => (3, 1),
+
+        Drop => (1, 0),
+        DropW => (4, 0),
+        PadW => (0, 4),
+        Dup0 => (1, 2),
+        Dup1 => (2, 3),
+        Dup2 => (3, 4),
+        Dup3 => (4, 5),
+        Dup4 => (5, 6),
+        Dup5 => (6, 7),
+        Dup6 => (7, 8),
+        Dup7 => (8, 9),
+        Dup8 => (9, 10),
+        Dup9 => (10, 11),
+        Dup10 => (11, 12),
+        Dup11 => (12, 13),
+        Dup12 => (13, 14),
+        Dup13 => (14, 15),
+        Dup14 => (15, 16),
+        Dup15 => (16, 17),
+        DupW0 => (4, 8),
+        DupW1 => (8, 12),
+        DupW2 => (12, 16),
+        DupW3 => (16, 20),
+        Swap1 | MovUp2 | MovDn2 => (2, 2),
+        Swap2 | MovUp3 | MovDn3 => (3, 3),
+        Swap3 | MovUp4 | MovDn4 => (4, 4),
+        Swap4 | MovUp5 | MovDn5 => (5, 5),
+        Swap5 | MovUp6 | MovDn6 => (6, 6),
+        Swap6 | MovUp7 | MovDn7 => (7, 7),
+        Swap7 | MovUp8 | MovDn8 => (8, 8),
+        Swap8 | MovUp9 | MovDn9 => (9, 9),
+        Swap9 | MovUp10 | MovDn10 => (10, 10),
+        Swap10 | MovUp11 | MovDn11 => (11, 11),
+        Swap11 | MovUp12 | MovDn12 => (12, 12),
+        Swap12 | MovUp13 | MovDn13 => (13, 13),
+        Swap13 | MovUp14 | MovDn14 => (14, 14),
+        Swap14 | MovUp15 | MovDn15 => (15, 15),
+        Swap15 => (16, 16),
+        SwapW1 => (8, 8),
+        SwapW2 | MovUpW2 | MovDnW2 => (12, 12),
+        SwapW3 | SwapDw | MovUpW3 | MovDnW3 => (16, 16),
+        CSwap => (3, 2),
+        CSwapW => (9, 8),
+        CDrop => (3, 1),
+        CDropW => (9, 4),
+
+        PushU8(_) | PushU16(_) | PushU32(_) | PushFelt(_) => (0, 1),
+        PushWord(_) => (0, 4),
+        PushU8List(values) => (0, values.len()),
+        PushU16List(values) => (0, values.len()),
+        PushU32List(values) => (0, values.len()),
+        PushFeltList(values) => (0, values.len()),
+        Locaddr(_) | Sdepth | Clk | MaxCycles | RemainingCycles => (0, 1),
+        Caller => (4, 4),
+        MemLoad => (1, 1),
+        MemLoadImm(_) | LocLoad(_) => (0, 1),
+        MemLoadW | MemStoreW => (5, 4),
+        MemLoadWImm(_) | LocLoadW(_) | MemStoreWImm(_) | LocStoreW(_) => (4, 4),
+        MemStore => (2, 0),
+        MemStoreImm(_) | LocStore(_) => (1, 0),
+        AdvPush(n) => (0, *n as usize),
+        AdvLoadW => (4, 4),
+        AdvPipeWords => (2, 1),
+
+        Hash => (4, 4),
+        HMerge => (8, 4),
+        HPerm => (12, 12),
+
+        Breakpoint | Debug(_) | Emit(_) | Trace(_) => (0, 0),
+
+        _ => return None,
+    };
+
+    Some(effect)
+}
@@ -0,0 +1,105 @@
+use super::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::str::from_utf8;
+
+// MODULE ERROR CODES
+// ================================================================================================
+
+/// Error code declarations of a module.
+///
+/// A module can claim ranges of error codes via `errors.<first>.<last>` declarations, and name
+/// individual codes within these ranges via `error.<NAME>=<code>` declarations. The assembler
+/// registers the declarations under the path of the module, so that error codes of failed
+/// assertions can be decoded back into the module and the name of the error.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ModuleErrorCodes {
+    ranges: Vec<(u32, u32)>,
+    names: Vec<(String, u32)>,
+}
+
+impl ModuleErrorCodes {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the inclusive `(first, last)` ranges of error codes declared by the module.
+    pub fn ranges(&self) -> &[(u32, u32)] {
+        &self.ranges
+    }
+
+    /// Returns the `(name, code)` pairs of named error codes declared by the module.
+    pub fn names(&self) -> &[(String, u32)] {
+        &self.names
+    }
+
+    /// Returns true if the module does not declare any error codes.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty() && self.names.is_empty()
+    }
+
+    /// Returns true if the specified code belongs to one of the declared ranges.
+    pub fn contains(&self, code: u32) -> bool {
+        self.ranges.iter().any(|&(first, last)| first <= code && code <= last)
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the inclusive range of error codes `first..=last` to the declarations.
+    pub(super) fn add_range(&mut self, first: u32, last: u32) {
+        self.ranges.push((first, last));
+    }
+
+    /// Adds the named error code to the declarations.
+    pub(super) fn add_name(&mut self, name: String, code: u32) {
+        self.names.push((name, code));
+    }
+}
+
+impl Serializable for ModuleErrorCodes {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        // the number of declarations is bounded by the number of tokens in the module source
+        assert!(self.ranges.len() <= u16::MAX as usize, "too many error code ranges");
+        assert!(self.names.len() <= u16::MAX as usize, "too many error code names");
+
+        target.write_u16(self.ranges.len() as u16);
+        for (first, last) in self.ranges.iter() {
+            target.write_u32(*first);
+            target.write_u32(*last);
+        }
+
+        target.write_u16(self.names.len() as u16);
+        for (name, code) in self.names.iter() {
+            target.write_u8(name.len() as u8);
+            target.write_bytes(name.as_bytes());
+            target.write_u32(*code);
+        }
+    }
+}
+
+impl Deserializable for ModuleErrorCodes {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut result = Self::default();
+
+        let num_ranges = source.read_u16()?;
+        for _ in 0..num_ranges {
+            let first = source.read_u32()?;
+            let last = source.read_u32()?;
+            result.add_range(first, last);
+        }
+
+        let num_names = source.read_u16()?;
+        for _ in 0..num_names {
+            let name_len = source.read_u8()? as usize;
+            let name = source.read_vec(name_len)?;
+            let name =
+                from_utf8(&name).map_err(|e| DeserializationError::InvalidValue(e.to_string()))?;
+            let code = source.read_u32()?;
+            result.add_name(name.to_string(), code);
+        }
+
+        Ok(result)
+    }
+}
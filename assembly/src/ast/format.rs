@@ -89,6 +89,8 @@ impl fmt::Display for FormattableProcedureAst<'_> {
         if let Some(ref doc) = self.proc.docs {
             writeln!(f, "#! {doc}")?;
         }
+        // Attributes
+        write!(f, "{}", self.proc.attributes)?;
         // Procedure header
         self.context.indent(f)?;
         if self.proc.is_export {
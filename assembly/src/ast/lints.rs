@@ -0,0 +1,406 @@
+use super::{
+    CodeBody, Instruction, ModuleAst, Node, ParsingError, ProcedureAst, ProgramAst, SourceLocation,
+    Token, TokenStream,
+};
+use alloc::{
+    collections::BTreeSet,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Default maximum number of consecutive instructions moving elements from or to the deep end of
+/// the stack (i.e., positions 12 - 15) which are not reported by the linter.
+const DEFAULT_MAX_DEEP_STACK_MOVES: usize = 2;
+
+// LINTS
+// ================================================================================================
+
+/// Kinds of suspicious code patterns which are reported by the [Linter].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Lint {
+    /// A chain of instructions moving elements from or to the deep end of the stack, e.g.,
+    /// `movup.15 movup.15 movup.15`; such chains usually indicate that the stack layout should be
+    /// reorganized (or that some values should be kept in memory instead).
+    DeepStackManipulation,
+    /// Instructions following an assertion which always fails, e.g., `push.0 assert`.
+    UnreachableCode,
+    /// A constant which is declared but never referenced.
+    UnusedConstant,
+    /// A non-exported procedure which cannot be invoked from the program body or from any of the
+    /// exported procedures.
+    UnusedProcedure,
+}
+
+impl Lint {
+    /// Returns the name of this lint.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::DeepStackManipulation => "deep_stack_manipulation",
+            Self::UnreachableCode => "unreachable_code",
+            Self::UnusedConstant => "unused_constant",
+            Self::UnusedProcedure => "unused_procedure",
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+// LINT WARNING
+// ================================================================================================
+
+/// A warning reported by the [Linter].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintWarning {
+    lint: Lint,
+    location: Option<SourceLocation>,
+    message: String,
+}
+
+impl LintWarning {
+    /// Returns the lint which triggered this warning.
+    pub fn lint(&self) -> Lint {
+        self.lint
+    }
+
+    /// Returns the location in the source code this warning refers to, if known.
+    pub fn location(&self) -> Option<&SourceLocation> {
+        self.location.as_ref()
+    }
+
+    /// Returns the description of this warning.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for LintWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "warning[{}]", self.lint)?;
+        if let Some(location) = self.location {
+            write!(f, " {location}")?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+// LINTER
+// ================================================================================================
+
+/// Reports suspicious code patterns in Miden assembly source code as warnings.
+///
+/// All lints are enabled by default; individual lints can be disabled via
+/// [Linter::with_allowed()]. Warnings do not affect compilation of the source code: it is up to
+/// the caller to decide whether the code should be rejected when warnings are reported.
+#[derive(Debug, Clone)]
+pub struct Linter {
+    allowed: BTreeSet<Lint>,
+    max_deep_stack_moves: usize,
+}
+
+impl Default for Linter {
+    fn default() -> Self {
+        Self {
+            allowed: BTreeSet::new(),
+            max_deep_stack_moves: DEFAULT_MAX_DEEP_STACK_MOVES,
+        }
+    }
+}
+
+impl Linter {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [Linter] with all lints enabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Disables the specified lint, and returns the resulting linter.
+    pub fn with_allowed(mut self, lint: Lint) -> Self {
+        self.allowed.insert(lint);
+        self
+    }
+
+    /// Sets the maximum number of consecutive instructions moving elements from or to the deep end
+    /// of the stack (i.e., positions 12 - 15) which are not reported as
+    /// [Lint::DeepStackManipulation], and returns the resulting linter.
+    pub fn with_max_deep_stack_moves(mut self, max_moves: usize) -> Self {
+        self.max_deep_stack_moves = max_moves;
+        self
+    }
+
+    // LINTING
+    // --------------------------------------------------------------------------------------------
+
+    /// Parses the provided program source and returns the warnings reported for it, ordered by
+    /// their location in the source code.
+    ///
+    /// # Errors
+    /// Returns an error if the source cannot be parsed into a [ProgramAst].
+    pub fn lint_program(&self, source: &str) -> Result<Vec<LintWarning>, ParsingError> {
+        let program = ProgramAst::parse(source)?;
+
+        let mut warnings = Vec::new();
+        self.check_constants(source, &mut warnings)?;
+        self.check_procedures(program.procedures(), &[program.body()], &mut warnings);
+        self.check_body(program.body(), &mut warnings);
+        for proc in program.procedures() {
+            self.check_body(&proc.body, &mut warnings);
+        }
+
+        Ok(sort_warnings(warnings))
+    }
+
+    /// Parses the provided module source and returns the warnings reported for it, ordered by
+    /// their location in the source code.
+    ///
+    /// # Errors
+    /// Returns an error if the source cannot be parsed into a [ModuleAst].
+    pub fn lint_module(&self, source: &str) -> Result<Vec<LintWarning>, ParsingError> {
+        let module = ModuleAst::parse(source)?;
+
+        let mut warnings = Vec::new();
+        self.check_constants(source, &mut warnings)?;
+        self.check_procedures(module.procs(), &[], &mut warnings);
+        for proc in module.procs() {
+            self.check_body(&proc.body, &mut warnings);
+        }
+
+        Ok(sort_warnings(warnings))
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if the specified lint is enabled.
+    fn is_enabled(&self, lint: Lint) -> bool {
+        !self.allowed.contains(&lint)
+    }
+
+    /// Reports constants which are declared via `const` statements but are not referenced by any
+    /// other statement or instruction.
+    fn check_constants(
+        &self,
+        source: &str,
+        warnings: &mut Vec<LintWarning>,
+    ) -> Result<(), ParsingError> {
+        if !self.is_enabled(Lint::UnusedConstant) {
+            return Ok(());
+        }
+
+        let mut tokens = TokenStream::new(source)?;
+        let mut declared = Vec::new();
+        let mut referenced = BTreeSet::new();
+        while let Some(token) = tokens.read() {
+            let parts = token.parts();
+            match (parts[0], parts.get(1).and_then(|part| part.split_once('='))) {
+                (Token::CONST, Some((name, value))) => {
+                    declared.push((name.to_string(), *token.location()));
+                    referenced.extend(identifiers(value).map(String::from));
+                }
+                // named error codes are registered with the program, and thus, are never unused
+                (Token::ERROR, Some((_, value))) => {
+                    referenced.extend(identifiers(value).map(String::from))
+                }
+                _ => {
+                    for part in &parts[1..] {
+                        referenced.extend(identifiers(part).map(String::from));
+                    }
+                }
+            }
+            tokens.advance();
+        }
+
+        for (name, location) in declared {
+            if !referenced.contains(name.as_str()) {
+                warnings.push(LintWarning {
+                    lint: Lint::UnusedConstant,
+                    location: Some(location),
+                    message: format!("constant `{name}` is never used"),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reports non-exported procedures which are not reachable from the specified bodies or from
+    /// any of the exported procedures.
+    fn check_procedures(
+        &self,
+        procs: &[ProcedureAst],
+        roots: &[&CodeBody],
+        warnings: &mut Vec<LintWarning>,
+    ) {
+        if !self.is_enabled(Lint::UnusedProcedure) {
+            return;
+        }
+
+        let mut reachable = BTreeSet::new();
+        let mut pending = Vec::new();
+        for body in roots {
+            collect_local_invocations(body, &mut pending);
+        }
+        pending.extend(procs.iter().enumerate().filter(|(_, p)| p.is_export).map(|(i, _)| i));
+
+        while let Some(index) = pending.pop() {
+            if reachable.insert(index) {
+                collect_local_invocations(&procs[index].body, &mut pending);
+            }
+        }
+
+        for (index, proc) in procs.iter().enumerate() {
+            if !reachable.contains(&index) {
+                warnings.push(LintWarning {
+                    lint: Lint::UnusedProcedure,
+                    location: Some(proc.start),
+                    message: format!("procedure `{}` is never used", proc.name),
+                });
+            }
+        }
+    }
+
+    /// Reports unreachable code and deep stack manipulation in the specified body and in the
+    /// bodies of control flow blocks nested in it.
+    fn check_body(&self, body: &CodeBody, warnings: &mut Vec<LintWarning>) {
+        let nodes = body.nodes();
+        let location = |index: usize| body.source_locations().get(index).copied();
+
+        if self.is_enabled(Lint::UnreachableCode) {
+            let failing_assert = nodes.windows(2).position(|pair| match pair {
+                [Node::Instruction(push), Node::Instruction(assert)] => {
+                    is_failing_assertion(push, assert)
+                }
+                _ => false,
+            });
+            // the position of the first unreachable node is two past the start of the window
+            if let Some(index) = failing_assert.map(|i| i + 2).filter(|&i| i < nodes.len()) {
+                warnings.push(LintWarning {
+                    lint: Lint::UnreachableCode,
+                    location: location(index),
+                    message: "unreachable code after an assertion which always fails".to_string(),
+                });
+            }
+        }
+
+        if self.is_enabled(Lint::DeepStackManipulation) {
+            let mut index = 0;
+            while index < nodes.len() {
+                let chain_len = nodes[index..].iter().take_while(|n| is_deep_stack_move(n)).count();
+                if chain_len > self.max_deep_stack_moves {
+                    warnings.push(LintWarning {
+                        lint: Lint::DeepStackManipulation,
+                        location: location(index),
+                        message: format!(
+                            "{chain_len} consecutive instructions move elements from or to the \
+                            deep end of the stack"
+                        ),
+                    });
+                }
+                index += chain_len.max(1);
+            }
+        }
+
+        for node in nodes {
+            match node {
+                Node::Instruction(_) => (),
+                Node::IfElse {
+                    true_case,
+                    false_case,
+                } => {
+                    self.check_body(true_case, warnings);
+                    self.check_body(false_case, warnings);
+                }
+                Node::Repeat { body, .. } | Node::While { body } => {
+                    self.check_body(body, warnings);
+                }
+            }
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Sorts the warnings by their location in the source code; warnings without a location come
+/// last.
+fn sort_warnings(mut warnings: Vec<LintWarning>) -> Vec<LintWarning> {
+    warnings.sort_by_key(|warning| (warning.location.is_none(), warning.location));
+    warnings
+}
+
+/// Returns an iterator over the identifiers (e.g., constant names) referenced in the specified
+/// token part.
+fn identifiers(part: &str) -> impl Iterator<Item = &str> {
+    part.split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .filter(|ident| !ident.is_empty())
+}
+
+/// Adds indexes of the local procedures invoked in the specified body to `invoked`.
+fn collect_local_invocations(body: &CodeBody, invoked: &mut Vec<usize>) {
+    for node in body.nodes() {
+        match node {
+            Node::Instruction(
+                Instruction::ExecLocal(index)
+                | Instruction::CallLocal(index)
+                | Instruction::ProcRefLocal(index),
+            ) => invoked.push(*index as usize),
+            Node::Instruction(_) => (),
+            Node::IfElse {
+                true_case,
+                false_case,
+            } => {
+                collect_local_invocations(true_case, invoked);
+                collect_local_invocations(false_case, invoked);
+            }
+            Node::Repeat { body, .. } | Node::While { body } => {
+                collect_local_invocations(body, invoked);
+            }
+        }
+    }
+}
+
+/// Returns true if the assertion always fails when executed right after the push instruction.
+fn is_failing_assertion(push: &Instruction, assert: &Instruction) -> bool {
+    let pushed = match push {
+        Instruction::PushU8(value) => *value as u64,
+        Instruction::PushU16(value) => *value as u64,
+        Instruction::PushU32(value) => *value as u64,
+        Instruction::PushFelt(value) => value.as_int(),
+        _ => return false,
+    };
+
+    match assert {
+        Instruction::Assert | Instruction::AssertWithError(_) => pushed == 0,
+        Instruction::Assertz | Instruction::AssertzWithError(_) => pushed != 0,
+        _ => false,
+    }
+}
+
+/// Returns true if the node is an instruction moving an element (or a word) from or to the deep
+/// end of the stack, i.e., positions 12 - 15.
+fn is_deep_stack_move(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::Instruction(
+            Instruction::MovUp12
+                | Instruction::MovUp13
+                | Instruction::MovUp14
+                | Instruction::MovUp15
+                | Instruction::MovUpW3
+                | Instruction::MovDn12
+                | Instruction::MovDn13
+                | Instruction::MovDn14
+                | Instruction::MovDn15
+                | Instruction::MovDnW3
+        )
+    )
+}
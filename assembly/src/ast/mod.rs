@@ -3,9 +3,10 @@
 //! Structs in this module (specifically [ProgramAst] and [ModuleAst]) can be used to parse source
 //! code into relevant ASTs. This can be done via their `parse()` methods.
 use super::{
-    crypto::hash::RpoDigest, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt,
-    LabelError, LibraryPath, ParsingError, ProcedureId, ProcedureName, Serializable, SliceReader,
-    StarkField, Token, TokenStream, MAX_LABEL_LEN,
+    crypto::hash::RpoDigest, ByteReader, ByteWriter, CodeBuilderError, Deserializable,
+    DeserializationError, Felt, InstructionPluginError, LabelError, LibraryPath, ParsingError,
+    ProcedureId, ProcedureName, Serializable, SliceReader, StarkField, Token, TokenStream,
+    MAX_LABEL_LEN,
 };
 use alloc::collections::BTreeMap;
 use alloc::string::String;
@@ -23,9 +24,15 @@ pub use nodes::{AdviceInjectorNode, Instruction, Node};
 mod code_body;
 pub use code_body::CodeBody;
 
+mod builder;
+pub use builder::{stack_effect, CodeBuilder};
+
 mod format;
 use format::*;
 
+mod error_codes;
+pub use error_codes::ModuleErrorCodes;
+
 mod imports;
 pub use imports::ModuleImports;
 
@@ -34,11 +41,17 @@ pub use invocation_target::InvocationTarget;
 
 mod parsers;
 
+mod lints;
+pub use lints::{Lint, LintWarning, Linter};
+
+mod plugins;
+pub use plugins::{InstructionPlugin, InstructionRegistry};
+
 mod module;
 pub use module::ModuleAst;
 
 mod procedure;
-pub use procedure::{ProcReExport, ProcedureAst};
+pub use procedure::{InlineMode, ProcReExport, ProcedureAst, ProcedureAttributes};
 
 mod program;
 pub use program::ProgramAst;
@@ -2,10 +2,12 @@ use super::check_unused_imports;
 use super::{
     format::*,
     imports::ModuleImports,
-    parsers::{parse_constants, ParserContext},
+    parsers::{parse_constants, parse_error_codes, ParserContext},
+    plugins::InstructionRegistry,
     serde::AstSerdeOptions,
-    sort_procs_into_vec, LocalProcMap, ProcReExport, ProcedureAst, ReExportedProcMap, MAX_DOCS_LEN,
-    MAX_LOCAL_PROCS, MAX_REEXPORTED_PROCS,
+    sort_procs_into_vec, Instruction, LocalProcMap, ModuleErrorCodes, Node, ProcReExport,
+    ProcedureAst, ProgramAst, ReExportedProcMap, MAX_DOCS_LEN, MAX_LOCAL_PROCS,
+    MAX_REEXPORTED_PROCS,
     {
         ByteReader, ByteWriter, Deserializable, DeserializationError, ParsingError, SliceReader,
         Token, TokenStream,
@@ -23,12 +25,14 @@ use vm_core::utils::Serializable;
 /// An abstract syntax tree of a Miden module.
 ///
 /// A module AST consists of a list of procedure ASTs, a list of re-exported procedures, a list of
-/// imports, and module documentation. Local procedures could be internal or exported.
+/// imports, error code declarations, and module documentation. Local procedures could be internal
+/// or exported.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ModuleAst {
     pub(super) local_procs: Vec<ProcedureAst>,
     pub(super) reexported_procs: Vec<ProcReExport>,
     pub(super) import_info: ModuleImports,
+    pub(super) error_codes: ModuleErrorCodes,
     pub(super) docs: Option<String>,
 }
 
@@ -61,6 +65,7 @@ impl ModuleAst {
             local_procs,
             reexported_procs,
             import_info: Default::default(),
+            error_codes: Default::default(),
             docs,
         })
     }
@@ -75,21 +80,38 @@ impl ModuleAst {
         self
     }
 
+    /// Adds the provided error code declarations to the module.
+    pub fn with_error_codes(mut self, error_codes: ModuleErrorCodes) -> Self {
+        self.error_codes = error_codes;
+        self
+    }
+
     // PARSER
     // --------------------------------------------------------------------------------------------
     /// Parses the provided source into a [ModuleAst].
     ///
     /// A module consists of internal and exported procedures but does not contain a body.
     pub fn parse(source: &str) -> Result<Self, ParsingError> {
+        Self::parse_with_instructions(source, &InstructionRegistry::default())
+    }
+
+    /// Parses the provided source into a [ModuleAst], lowering the custom instructions defined
+    /// in the specified registry into built-in instructions.
+    pub fn parse_with_instructions(
+        source: &str,
+        instructions: &InstructionRegistry,
+    ) -> Result<Self, ParsingError> {
         let mut tokens = TokenStream::new(source)?;
         let mut import_info = ModuleImports::parse(&mut tokens)?;
-        let local_constants = parse_constants(&mut tokens)?;
+        let mut local_constants = parse_constants(&mut tokens)?;
+        let error_codes = parse_error_codes(&mut tokens, &mut local_constants)?;
         let mut context = ParserContext {
             import_info: &mut import_info,
             local_procs: LocalProcMap::default(),
             reexported_procs: ReExportedProcMap::default(),
             local_constants,
             num_proc_locals: 0,
+            instructions,
         };
         context.parse_procedures(&mut tokens, true)?;
 
@@ -113,7 +135,9 @@ impl ModuleAst {
 
         check_unused_imports(context.import_info);
 
-        Ok(Self::new(local_procs, reexported_procs, docs)?.with_import_info(import_info))
+        Ok(Self::new(local_procs, reexported_procs, docs)?
+            .with_import_info(import_info)
+            .with_error_codes(error_codes))
     }
 
     // PUBLIC ACCESSORS
@@ -129,6 +153,12 @@ impl ModuleAst {
         &self.reexported_procs
     }
 
+    /// Returns true if this module does not define or re-export any procedures - i.e., the module
+    /// may contain only constant definitions.
+    pub fn is_constants_only(&self) -> bool {
+        self.local_procs.is_empty() && self.reexported_procs.is_empty()
+    }
+
     /// Returns doc comments for this module.
     pub fn docs(&self) -> Option<&String> {
         self.docs.as_ref()
@@ -139,6 +169,46 @@ impl ModuleAst {
         &self.import_info
     }
 
+    /// Returns the error code declarations of this module.
+    pub fn error_codes(&self) -> &ModuleErrorCodes {
+        &self.error_codes
+    }
+
+    // ENTRYPOINTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a program which executes the exported procedure with the specified name, or None
+    /// if this module does not export a local procedure with this name.
+    ///
+    /// All procedures of this module become local procedures of the returned program, and the
+    /// program inherits the imports of this module. Thus, each exported procedure can serve as an
+    /// entrypoint of a separate program sharing procedure definitions with other entrypoints.
+    /// Re-exported procedures cannot serve as entrypoints.
+    pub fn entrypoint_program(&self, name: &str) -> Option<ProgramAst> {
+        let index = self.local_procs.iter().position(|p| p.is_export && p.name.as_ref() == name)?;
+        let procs = self
+            .local_procs
+            .iter()
+            .cloned()
+            .map(|proc| ProcedureAst {
+                is_export: false,
+                ..proc
+            })
+            .collect();
+
+        // the number of procedures is bounded by the module parser, and the program body consists
+        // of a single instruction
+        let body = vec![Node::Instruction(Instruction::ExecLocal(index as u16))];
+        let program = ProgramAst::new(body, procs).expect("invalid entrypoint program");
+        Some(program.with_import_info(self.import_info.clone()))
+    }
+
+    /// Returns the names of the local procedures exported from this module, which can serve as
+    /// entrypoints of programs returned by [ModuleAst::entrypoint_program()].
+    pub fn entrypoints(&self) -> impl Iterator<Item = &str> {
+        self.local_procs.iter().filter(|p| p.is_export).map(|p| p.name.as_ref())
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -175,6 +245,9 @@ impl ModuleAst {
             self.import_info.write_into(target);
         }
 
+        // serialize error code declarations
+        self.error_codes.write_into(target);
+
         // serialize procedures
         assert!(self.local_procs.len() <= u16::MAX as usize, "too many local procs");
         assert!(
@@ -212,6 +285,9 @@ impl ModuleAst {
             ModuleImports::default()
         };
 
+        // deserialize error code declarations
+        let error_codes = ModuleErrorCodes::read_from(source)?;
+
         // deserialize re-exports
         let num_reexported_procs = source.read_u16()? as usize;
         let reexported_procs = source.read_many::<ProcReExport>(num_reexported_procs)?;
@@ -222,7 +298,7 @@ impl ModuleAst {
 
         match Self::new(local_procs, reexported_procs, docs) {
             Err(err) => Err(DeserializationError::UnknownError(err.message().clone())),
-            Ok(res) => Ok(res.with_import_info(import_info)),
+            Ok(res) => Ok(res.with_import_info(import_info).with_error_codes(error_codes)),
         }
     }
 
@@ -304,6 +380,17 @@ impl fmt::Display for ModuleAst {
             writeln!(f)?;
         }
 
+        // Error codes
+        for (first, last) in self.error_codes.ranges() {
+            writeln!(f, "errors.{first}.{last}")?;
+        }
+        for (name, code) in self.error_codes.names() {
+            writeln!(f, "error.{name}={code}")?;
+        }
+        if !self.error_codes.is_empty() {
+            writeln!(f)?;
+        }
+
         // Re-exports
         for proc in self.reexported_procs.iter() {
             writeln!(f, "export.{}", proc.name())?;
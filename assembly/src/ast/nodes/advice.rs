@@ -24,16 +24,23 @@ pub enum AdviceInjectorNode {
     PushSmtGet,
     PushSmtSet,
     PushSmtPeek,
+    PushCompactSmtPeek,
     PushMapVal,
     PushMapValImm { offset: u8 },
     PushMapValN,
     PushMapValNImm { offset: u8 },
+    PushMapValNs,
+    PushMapValNNs,
     PushMtNode,
+    PushMtMultiProof,
+    PushDmtPath,
     InsertMem,
+    InsertMemRange,
     InsertHdword,
     InsertHdwordImm { domain: u8 },
     InsertHperm,
     PushSignature { kind: SignatureKind },
+    PushMemSignature { kind: SignatureKind },
 }
 
 impl From<&AdviceInjectorNode> for AdviceInjector {
@@ -45,6 +52,7 @@ impl From<&AdviceInjectorNode> for AdviceInjector {
             PushSmtGet => Self::SmtGet,
             PushSmtSet => Self::SmtSet,
             PushSmtPeek => Self::SmtPeek,
+            PushCompactSmtPeek => Self::CompactSmtPeek,
             PushMapVal => Self::MapValueToStack {
                 include_len: false,
                 key_offset: 0,
@@ -61,14 +69,20 @@ impl From<&AdviceInjectorNode> for AdviceInjector {
                 include_len: true,
                 key_offset: (*offset) as usize,
             },
+            PushMapValNs => Self::NamespacedMapValueToStack { include_len: false },
+            PushMapValNNs => Self::NamespacedMapValueToStack { include_len: true },
             PushMtNode => Self::MerkleNodeToStack,
+            PushMtMultiProof => Self::MerkleMultiProofToStack,
+            PushDmtPath => Self::DigestMerklePathToStack,
             InsertMem => Self::MemToMap,
+            InsertMemRange => Self::MemRangeToMap,
             InsertHdword => Self::HdwordToMap { domain: ZERO },
             InsertHdwordImm { domain } => Self::HdwordToMap {
                 domain: Felt::from(*domain),
             },
             InsertHperm => Self::HpermToMap,
             PushSignature { kind } => Self::SigToStack { kind: *kind },
+            PushMemSignature { kind } => Self::SigMemToStack { kind: *kind },
         }
     }
 }
@@ -82,16 +96,23 @@ impl fmt::Display for AdviceInjectorNode {
             PushSmtGet => write!(f, "push_smtget"),
             PushSmtSet => write!(f, "push_smtset"),
             PushSmtPeek => write!(f, "push_smtpeek"),
+            PushCompactSmtPeek => write!(f, "push_csmtpeek"),
             PushMapVal => write!(f, "push_mapval"),
             PushMapValImm { offset } => write!(f, "push_mapval.{offset}"),
             PushMapValN => write!(f, "push_mapvaln"),
             PushMapValNImm { offset } => write!(f, "push_mapvaln.{offset}"),
+            PushMapValNs => write!(f, "push_mapval_ns"),
+            PushMapValNNs => write!(f, "push_mapvaln_ns"),
             PushMtNode => write!(f, "push_mtnode"),
+            PushMtMultiProof => write!(f, "push_mtmultiproof"),
+            PushDmtPath => write!(f, "push_dmtpath"),
             InsertMem => write!(f, "insert_mem"),
+            InsertMemRange => write!(f, "insert_mem_range"),
             InsertHdword => write!(f, "insert_hdword"),
             InsertHdwordImm { domain } => write!(f, "insert_hdword.{domain}"),
             InsertHperm => writeln!(f, "insert_hperm"),
             PushSignature { kind } => write!(f, "push_sig.{kind}"),
+            PushMemSignature { kind } => write!(f, "push_sig_mem.{kind}"),
         }
     }
 }
@@ -114,6 +135,13 @@ const INSERT_HDWORD: u8 = 11;
 const INSERT_HDWORD_IMM: u8 = 12;
 const INSERT_HPERM: u8 = 13;
 const PUSH_SIG: u8 = 14;
+const PUSH_MAPVAL_NS: u8 = 15;
+const PUSH_MAPVALN_NS: u8 = 16;
+const PUSH_CSMTPEEK: u8 = 17;
+const PUSH_MTMULTIPROOF: u8 = 18;
+const PUSH_SIG_MEM: u8 = 19;
+const INSERT_MEM_RANGE: u8 = 20;
+const PUSH_DMTPATH: u8 = 21;
 
 impl Serializable for AdviceInjectorNode {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
@@ -124,6 +152,7 @@ impl Serializable for AdviceInjectorNode {
             PushSmtGet => target.write_u8(PUSH_SMTGET),
             PushSmtSet => target.write_u8(PUSH_SMTSET),
             PushSmtPeek => target.write_u8(PUSH_SMTPEEK),
+            PushCompactSmtPeek => target.write_u8(PUSH_CSMTPEEK),
             PushMapVal => target.write_u8(PUSH_MAPVAL),
             PushMapValImm { offset } => {
                 target.write_u8(PUSH_MAPVAL_IMM);
@@ -134,8 +163,13 @@ impl Serializable for AdviceInjectorNode {
                 target.write_u8(PUSH_MAPVALN_IMM);
                 target.write_u8(*offset);
             }
+            PushMapValNs => target.write_u8(PUSH_MAPVAL_NS),
+            PushMapValNNs => target.write_u8(PUSH_MAPVALN_NS),
             PushMtNode => target.write_u8(PUSH_MTNODE),
+            PushMtMultiProof => target.write_u8(PUSH_MTMULTIPROOF),
+            PushDmtPath => target.write_u8(PUSH_DMTPATH),
             InsertMem => target.write_u8(INSERT_MEM),
+            InsertMemRange => target.write_u8(INSERT_MEM_RANGE),
             InsertHdword => target.write_u8(INSERT_HDWORD),
             InsertHdwordImm { domain } => {
                 target.write_u8(INSERT_HDWORD_IMM);
@@ -146,6 +180,10 @@ impl Serializable for AdviceInjectorNode {
                 target.write_u8(PUSH_SIG);
                 signatures::write_options_into(target, kind)
             }
+            PushMemSignature { kind } => {
+                target.write_u8(PUSH_SIG_MEM);
+                signatures::write_options_into(target, kind)
+            }
         }
     }
 }
@@ -158,6 +196,7 @@ impl Deserializable for AdviceInjectorNode {
             PUSH_SMTGET => Ok(AdviceInjectorNode::PushSmtGet),
             PUSH_SMTSET => Ok(AdviceInjectorNode::PushSmtSet),
             PUSH_SMTPEEK => Ok(AdviceInjectorNode::PushSmtPeek),
+            PUSH_CSMTPEEK => Ok(AdviceInjectorNode::PushCompactSmtPeek),
             PUSH_MAPVAL => Ok(AdviceInjectorNode::PushMapVal),
             PUSH_MAPVAL_IMM => {
                 let offset = source.read_u8()?;
@@ -174,8 +213,13 @@ impl Deserializable for AdviceInjectorNode {
                 }
                 Ok(AdviceInjectorNode::PushMapValNImm { offset })
             }
+            PUSH_MAPVAL_NS => Ok(AdviceInjectorNode::PushMapValNs),
+            PUSH_MAPVALN_NS => Ok(AdviceInjectorNode::PushMapValNNs),
             PUSH_MTNODE => Ok(AdviceInjectorNode::PushMtNode),
+            PUSH_MTMULTIPROOF => Ok(AdviceInjectorNode::PushMtMultiProof),
+            PUSH_DMTPATH => Ok(AdviceInjectorNode::PushDmtPath),
             INSERT_MEM => Ok(AdviceInjectorNode::InsertMem),
+            INSERT_MEM_RANGE => Ok(AdviceInjectorNode::InsertMemRange),
             INSERT_HDWORD => Ok(AdviceInjectorNode::InsertHdword),
             INSERT_HDWORD_IMM => {
                 let domain = source.read_u8()?;
@@ -185,6 +229,9 @@ impl Deserializable for AdviceInjectorNode {
             PUSH_SIG => Ok(AdviceInjectorNode::PushSignature {
                 kind: signatures::read_options_from(source)?,
             }),
+            PUSH_SIG_MEM => Ok(AdviceInjectorNode::PushMemSignature {
+                kind: signatures::read_options_from(source)?,
+            }),
             val => Err(DeserializationError::InvalidValue(val.to_string())),
         }
     }
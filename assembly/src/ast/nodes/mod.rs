@@ -78,6 +78,7 @@ pub enum Instruction {
     Gt,
     Gte,
     IsOdd,
+    RangeCheck(u8),
 
     // ----- ext2 operations ----------------------------------------------------------------------
     Ext2Add,
@@ -96,6 +97,8 @@ pub enum Instruction {
     U32Assert2WithError(ErrorCode),
     U32AssertW,
     U32AssertWWithError(ErrorCode),
+    U32AssertN(u8),
+    U32AssertNWithError(u8, ErrorCode),
     U32Split,
     U32Cast,
     U32WrappingAdd,
@@ -137,6 +140,11 @@ pub enum Instruction {
     U32Ctz,
     U32Clo,
     U32Cto,
+    U32Bswap,
+    U32Extract(u8, u8),
+    U32Insert(u8, u8),
+    U32Fshl(u8),
+    U32Fshr(u8),
     U32Lt,
     U32Lte,
     U32Gt,
@@ -238,6 +246,8 @@ pub enum Instruction {
     Sdepth,
     Caller,
     Clk,
+    MaxCycles,
+    RemainingCycles,
 
     MemLoad,
     MemLoadImm(u32),
@@ -255,6 +265,7 @@ pub enum Instruction {
 
     MemStream,
     AdvPipe,
+    AdvPipeWords,
 
     AdvPush(u8),
     AdvLoadW,
@@ -283,8 +294,10 @@ pub enum Instruction {
     SysCall(ProcedureId),
     DynExec,
     DynCall,
+    DynSysCall,
     ProcRefLocal(u16),
     ProcRefImported(ProcedureId),
+    ProcRefKernel(ProcedureId),
 
     // ----- debug decorators ---------------------------------------------------------------------
     Breakpoint,
@@ -343,6 +356,7 @@ impl fmt::Display for Instruction {
             Self::Gt => write!(f, "gt"),
             Self::Gte => write!(f, "gte"),
             Self::IsOdd => write!(f, "is_odd"),
+            Self::RangeCheck(num_bits) => write!(f, "range_check.{num_bits}"),
 
             // ----- ext2 operations --------------------------------------------------------------
             Self::Ext2Add => write!(f, "ext2add"),
@@ -361,6 +375,10 @@ impl fmt::Display for Instruction {
             Self::U32Assert2WithError(err_code) => write!(f, "u32assert2.err={err_code}"),
             Self::U32AssertW => write!(f, "u32assertw"),
             Self::U32AssertWWithError(err_code) => write!(f, "u32assertw.err={err_code}"),
+            Self::U32AssertN(num_elements) => write!(f, "u32assertn.{num_elements}"),
+            Self::U32AssertNWithError(num_elements, err_code) => {
+                write!(f, "u32assertn.{num_elements}.err={err_code}")
+            }
             Self::U32Split => write!(f, "u32split"),
             Self::U32Cast => write!(f, "u32cast"),
             Self::U32WrappingAdd => write!(f, "u32wrapping_add"),
@@ -402,6 +420,11 @@ impl fmt::Display for Instruction {
             Self::U32Ctz => write!(f, "u32ctz"),
             Self::U32Clo => write!(f, "u32clo"),
             Self::U32Cto => write!(f, "u32cto"),
+            Self::U32Bswap => write!(f, "u32bswap"),
+            Self::U32Extract(offset, width) => write!(f, "u32extract.{offset}.{width}"),
+            Self::U32Insert(offset, width) => write!(f, "u32insert.{offset}.{width}"),
+            Self::U32Fshl(value) => write!(f, "u32fshl.{value}"),
+            Self::U32Fshr(value) => write!(f, "u32fshr.{value}"),
             Self::U32Lt => write!(f, "u32lt"),
             Self::U32Lte => write!(f, "u32lte"),
             Self::U32Gt => write!(f, "u32gt"),
@@ -504,6 +527,8 @@ impl fmt::Display for Instruction {
             Self::Sdepth => write!(f, "sdepth"),
             Self::Caller => write!(f, "caller"),
             Self::Clk => write!(f, "clk"),
+            Self::MaxCycles => write!(f, "max_cycles"),
+            Self::RemainingCycles => write!(f, "remaining_cycles"),
 
             Self::MemLoad => write!(f, "mem_load"),
             Self::MemLoadImm(value) => write!(f, "mem_load.{value}"),
@@ -521,6 +546,7 @@ impl fmt::Display for Instruction {
 
             Self::MemStream => write!(f, "mem_stream"),
             Self::AdvPipe => write!(f, "adv_pipe"),
+            Self::AdvPipeWords => write!(f, "adv_pipe_words"),
 
             Self::AdvPush(value) => write!(f, "adv_push.{value}"),
             Self::AdvLoadW => write!(f, "adv_loadw"),
@@ -552,8 +578,10 @@ impl fmt::Display for Instruction {
             Self::SysCall(proc_id) => write!(f, "syscall.{proc_id}"),
             Self::DynExec => write!(f, "dynexec"),
             Self::DynCall => write!(f, "dyncall"),
+            Self::DynSysCall => write!(f, "dynsyscall"),
             Self::ProcRefLocal(index) => write!(f, "procref.{index}"),
             Self::ProcRefImported(proc_id) => write!(f, "procref.{proc_id}"),
+            Self::ProcRefKernel(proc_id) => write!(f, "procref.$kernel::{proc_id}"),
 
             // ----- debug decorators -------------------------------------------------------------
             Self::Breakpoint => write!(f, "breakpoint"),
@@ -2,53 +2,77 @@ use super::{
     super::AdviceInjectorNode, debug, ByteReader, CodeBody, Deserializable, DeserializationError,
     Felt, Instruction, Node, OpCode, ProcedureId, RpoDigest, MAX_PUSH_INPUTS,
 };
-use alloc::string::ToString;
+use alloc::{string::ToString, vec::Vec};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum nesting depth of control flow blocks accepted by the deserializer.
+///
+/// Nodes are read recursively, and thus, the depth is bounded to make sure that malformed inputs
+/// cannot exhaust the stack of the deserializing thread.
+const MAX_NESTING_DEPTH: usize = 256;
 
 // NODE DESERIALIZATION
 // ================================================================================================
 
 impl Deserializable for Node {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let first_byte = source.peek_u8()?;
+        read_node(source, 0)
+    }
+}
 
-        if first_byte == OpCode::IfElse as u8 {
-            source.read_u8()?;
+/// Reads a node nested in the specified number of control flow blocks from the source.
+fn read_node<R: ByteReader>(source: &mut R, depth: usize) -> Result<Node, DeserializationError> {
+    let first_byte = source.peek_u8()?;
 
-            let if_block_len = source.read_u16()? as usize;
-            let nodes = source.read_many::<Node>(if_block_len)?;
-            let true_case = CodeBody::new(nodes);
+    if first_byte == OpCode::IfElse as u8 {
+        source.read_u8()?;
 
-            let else_block_len = source.read_u16()? as usize;
-            let nodes = source.read_many::<Node>(else_block_len)?;
-            let false_case = CodeBody::new(nodes);
+        let true_case = read_code_body(source, depth)?;
+        let false_case = read_code_body(source, depth)?;
 
-            Ok(Node::IfElse {
-                true_case,
-                false_case,
-            })
-        } else if first_byte == OpCode::Repeat as u8 {
-            source.read_u8()?;
+        Ok(Node::IfElse {
+            true_case,
+            false_case,
+        })
+    } else if first_byte == OpCode::Repeat as u8 {
+        source.read_u8()?;
 
-            let times = source.read_u32()?;
+        let times = source.read_u32()?;
+        let body = read_code_body(source, depth)?;
 
-            let nodes_len = source.read_u16()? as usize;
-            let nodes = source.read_many::<Node>(nodes_len)?;
-            let body = CodeBody::new(nodes);
+        Ok(Node::Repeat { times, body })
+    } else if first_byte == OpCode::While as u8 {
+        source.read_u8()?;
 
-            Ok(Node::Repeat { times, body })
-        } else if first_byte == OpCode::While as u8 {
-            source.read_u8()?;
+        let body = read_code_body(source, depth)?;
 
-            let nodes_len = source.read_u16()? as usize;
-            let nodes = source.read_many::<Node>(nodes_len)?;
-            let body = CodeBody::new(nodes);
+        Ok(Node::While { body })
+    } else {
+        let inner = Deserializable::read_from(source)?;
+        Ok(Node::Instruction(inner))
+    }
+}
 
-            Ok(Node::While { body })
-        } else {
-            let inner = Deserializable::read_from(source)?;
-            Ok(Node::Instruction(inner))
-        }
+/// Reads the body of a control flow block nested in the specified number of other control flow
+/// blocks from the source.
+fn read_code_body<R: ByteReader>(
+    source: &mut R,
+    depth: usize,
+) -> Result<CodeBody, DeserializationError> {
+    if depth >= MAX_NESTING_DEPTH {
+        return Err(DeserializationError::InvalidValue(format!(
+            "control flow blocks are nested deeper than {MAX_NESTING_DEPTH} levels"
+        )));
     }
+
+    let num_nodes = source.read_u16()? as usize;
+    let mut nodes = Vec::new();
+    for _ in 0..num_nodes {
+        nodes.push(read_node(source, depth + 1)?);
+    }
+    Ok(CodeBody::new(nodes))
 }
 
 // INSTRUCTION DESERIALIZATION
@@ -115,6 +139,11 @@ impl Deserializable for Instruction {
             OpCode::U32Assert2WithError => Ok(Instruction::U32Assert2WithError(source.read_u32()?)),
             OpCode::U32AssertW => Ok(Instruction::U32AssertW),
             OpCode::U32AssertWWithError => Ok(Instruction::U32AssertWWithError(source.read_u32()?)),
+            OpCode::U32AssertN => Ok(Instruction::U32AssertN(source.read_u8()?)),
+            OpCode::U32AssertNWithError => {
+                let num_elements = source.read_u8()?;
+                Ok(Instruction::U32AssertNWithError(num_elements, source.read_u32()?))
+            }
             OpCode::U32Split => Ok(Instruction::U32Split),
             OpCode::U32Cast => Ok(Instruction::U32Cast),
             OpCode::U32WrappingAdd => Ok(Instruction::U32WrappingAdd),
@@ -162,6 +191,17 @@ impl Deserializable for Instruction {
             OpCode::U32Ctz => Ok(Instruction::U32Ctz),
             OpCode::U32Clo => Ok(Instruction::U32Clo),
             OpCode::U32Cto => Ok(Instruction::U32Cto),
+            OpCode::U32Bswap => Ok(Instruction::U32Bswap),
+            OpCode::U32Extract => {
+                let offset = source.read_u8()?;
+                Ok(Instruction::U32Extract(offset, source.read_u8()?))
+            }
+            OpCode::U32Insert => {
+                let offset = source.read_u8()?;
+                Ok(Instruction::U32Insert(offset, source.read_u8()?))
+            }
+            OpCode::U32Fshl => Ok(Instruction::U32Fshl(source.read_u8()?)),
+            OpCode::U32Fshr => Ok(Instruction::U32Fshr(source.read_u8()?)),
             OpCode::U32Lt => Ok(Instruction::U32Lt),
             OpCode::U32Lte => Ok(Instruction::U32Lte),
             OpCode::U32Gt => Ok(Instruction::U32Gt),
@@ -293,6 +333,8 @@ impl Deserializable for Instruction {
             OpCode::Sdepth => Ok(Instruction::Sdepth),
             OpCode::Caller => Ok(Instruction::Caller),
             OpCode::Clk => Ok(Instruction::Clk),
+            OpCode::MaxCycles => Ok(Instruction::MaxCycles),
+            OpCode::RemainingCycles => Ok(Instruction::RemainingCycles),
 
             OpCode::MemLoad => Ok(Instruction::MemLoad),
             OpCode::MemLoadImm => Ok(Instruction::MemLoadImm(source.read_u32()?)),
@@ -309,6 +351,7 @@ impl Deserializable for Instruction {
 
             OpCode::MemStream => Ok(Instruction::MemStream),
             OpCode::AdvPipe => Ok(Instruction::AdvPipe),
+            OpCode::AdvPipeWords => Ok(Instruction::AdvPipeWords),
 
             OpCode::AdvPush => Ok(Instruction::AdvPush(source.read_u8()?)),
             OpCode::AdvLoadW => Ok(Instruction::AdvLoadW),
@@ -337,10 +380,14 @@ impl Deserializable for Instruction {
             OpCode::SysCall => Ok(Instruction::SysCall(ProcedureId::read_from(source)?)),
             OpCode::DynExec => Ok(Instruction::DynExec),
             OpCode::DynCall => Ok(Instruction::DynCall),
+            OpCode::DynSysCall => Ok(Instruction::DynSysCall),
             OpCode::ProcRefLocal => Ok(Instruction::ProcRefLocal(source.read_u16()?)),
             OpCode::ProcRefImported => {
                 Ok(Instruction::ProcRefImported(ProcedureId::read_from(source)?))
             }
+            OpCode::ProcRefKernel => {
+                Ok(Instruction::ProcRefKernel(ProcedureId::read_from(source)?))
+            }
 
             // ----- debugging --------------------------------------------------------------------
             OpCode::Debug => {
@@ -352,12 +399,15 @@ impl Deserializable for Instruction {
             OpCode::Emit => Ok(Instruction::Emit(source.read_u32()?)),
             OpCode::Trace => Ok(Instruction::Trace(source.read_u32()?)),
 
+            // ----- range checks -----------------------------------------------------------------
+            OpCode::RangeCheck => Ok(Instruction::RangeCheck(source.read_u8()?)),
+
             // ----- control flow -----------------------------------------------------------------
-            // control flow instructions should be parsed as a part of Node::read_from() and we
-            // should never get here
-            OpCode::IfElse => unreachable!(),
-            OpCode::Repeat => unreachable!(),
-            OpCode::While => unreachable!(),
+            // control flow instructions are read as a part of Node::read_from(), and thus, they
+            // cannot be read as standalone instructions
+            OpCode::IfElse | OpCode::Repeat | OpCode::While => {
+                Err(DeserializationError::InvalidValue(format!("{opcode:?} is not an instruction")))
+            }
         }
     }
 }
@@ -268,6 +268,31 @@ pub enum OpCode {
     Emit = 227,
     Trace = 228,
 
+    // ----- range checks -------------------------------------------------------------------------
+    RangeCheck = 229,
+
+    // ----- environment inputs -------------------------------------------------------------------
+    MaxCycles = 230,
+    RemainingCycles = 231,
+
+    // ----- kernel procedure invocations ---------------------------------------------------------
+    DynSysCall = 232,
+    ProcRefKernel = 233,
+
+    // ----- bulk advice reads --------------------------------------------------------------------
+    AdvPipeWords = 234,
+
+    // ----- bulk u32 assertions ------------------------------------------------------------------
+    U32AssertN = 235,
+    U32AssertNWithError = 236,
+
+    // ----- bit manipulation ---------------------------------------------------------------------
+    U32Bswap = 237,
+    U32Extract = 238,
+    U32Insert = 239,
+    U32Fshl = 240,
+    U32Fshr = 241,
+
     // ----- control flow -------------------------------------------------------------------------
     IfElse = 253,
     Repeat = 254,
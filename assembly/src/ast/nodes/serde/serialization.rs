@@ -126,6 +126,10 @@ impl Serializable for Instruction {
             Self::Gt => OpCode::Gt.write_into(target),
             Self::Gte => OpCode::Gte.write_into(target),
             Self::IsOdd => OpCode::IsOdd.write_into(target),
+            Self::RangeCheck(num_bits) => {
+                OpCode::RangeCheck.write_into(target);
+                target.write_u8(*num_bits);
+            }
 
             // ----- ext2 operations --------------------------------------------------------------
             Self::Ext2Add => OpCode::Ext2Add.write_into(target),
@@ -153,6 +157,15 @@ impl Serializable for Instruction {
                 OpCode::U32AssertWWithError.write_into(target);
                 target.write_u32(*err_code);
             }
+            Self::U32AssertN(num_elements) => {
+                OpCode::U32AssertN.write_into(target);
+                target.write_u8(*num_elements);
+            }
+            Self::U32AssertNWithError(num_elements, err_code) => {
+                OpCode::U32AssertNWithError.write_into(target);
+                target.write_u8(*num_elements);
+                target.write_u32(*err_code);
+            }
             Self::U32Split => OpCode::U32Split.write_into(target),
             Self::U32Cast => OpCode::U32Cast.write_into(target),
             Self::U32WrappingAdd => OpCode::U32WrappingAdd.write_into(target),
@@ -233,6 +246,25 @@ impl Serializable for Instruction {
             Self::U32Ctz => OpCode::U32Ctz.write_into(target),
             Self::U32Clo => OpCode::U32Clo.write_into(target),
             Self::U32Cto => OpCode::U32Cto.write_into(target),
+            Self::U32Bswap => OpCode::U32Bswap.write_into(target),
+            Self::U32Extract(offset, width) => {
+                OpCode::U32Extract.write_into(target);
+                target.write_u8(*offset);
+                target.write_u8(*width);
+            }
+            Self::U32Insert(offset, width) => {
+                OpCode::U32Insert.write_into(target);
+                target.write_u8(*offset);
+                target.write_u8(*width);
+            }
+            Self::U32Fshl(v) => {
+                OpCode::U32Fshl.write_into(target);
+                target.write_u8(*v);
+            }
+            Self::U32Fshr(v) => {
+                OpCode::U32Fshr.write_into(target);
+                target.write_u8(*v);
+            }
             Self::U32Lt => OpCode::U32Lt.write_into(target),
             Self::U32Lte => OpCode::U32Lte.write_into(target),
             Self::U32Gt => OpCode::U32Gt.write_into(target),
@@ -368,6 +400,8 @@ impl Serializable for Instruction {
             Self::Sdepth => OpCode::Sdepth.write_into(target),
             Self::Caller => OpCode::Caller.write_into(target),
             Self::Clk => OpCode::Clk.write_into(target),
+            Self::MaxCycles => OpCode::MaxCycles.write_into(target),
+            Self::RemainingCycles => OpCode::RemainingCycles.write_into(target),
 
             Self::MemLoad => OpCode::MemLoad.write_into(target),
             Self::MemLoadImm(v) => {
@@ -408,6 +442,7 @@ impl Serializable for Instruction {
 
             Self::MemStream => OpCode::MemStream.write_into(target),
             Self::AdvPipe => OpCode::AdvPipe.write_into(target),
+            Self::AdvPipeWords => OpCode::AdvPipeWords.write_into(target),
 
             Self::AdvPush(v) => {
                 OpCode::AdvPush.write_into(target);
@@ -460,6 +495,7 @@ impl Serializable for Instruction {
             }
             Self::DynExec => OpCode::DynExec.write_into(target),
             Self::DynCall => OpCode::DynCall.write_into(target),
+            Self::DynSysCall => OpCode::DynSysCall.write_into(target),
             Self::ProcRefLocal(v) => {
                 OpCode::ProcRefLocal.write_into(target);
                 target.write_u16(*v)
@@ -468,6 +504,10 @@ impl Serializable for Instruction {
                 OpCode::ProcRefImported.write_into(target);
                 imported.write_into(target)
             }
+            Self::ProcRefKernel(proc_id) => {
+                OpCode::ProcRefKernel.write_into(target);
+                proc_id.write_into(target)
+            }
 
             // ----- debug decorators -------------------------------------------------------------
             Self::Breakpoint => {
@@ -42,6 +42,10 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
             2 => AdvInject(PushSmtPeek),
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_csmtpeek" => match op.num_parts() {
+            2 => AdvInject(PushCompactSmtPeek),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "push_mapval" => match op.num_parts() {
             2 => AdvInject(PushMapVal),
             3 => {
@@ -66,14 +70,34 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
             }
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_mapval_ns" => match op.num_parts() {
+            2 => AdvInject(PushMapValNs),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_mapvaln_ns" => match op.num_parts() {
+            2 => AdvInject(PushMapValNNs),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "push_mtnode" => match op.num_parts() {
             2 => AdvInject(PushMtNode),
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_mtmultiproof" => match op.num_parts() {
+            2 => AdvInject(PushMtMultiProof),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_dmtpath" => match op.num_parts() {
+            2 => AdvInject(PushDmtPath),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "insert_mem" => match op.num_parts() {
             2 => AdvInject(InsertMem),
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "insert_mem_range" => match op.num_parts() {
+            2 => AdvInject(InsertMemRange),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "insert_hdword" => match op.num_parts() {
             2 => AdvInject(InsertHdword),
             3 => {
@@ -99,6 +123,15 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
             },
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_sig_mem" => match op.num_parts() {
+            3 => match op.parts()[2] {
+                "rpo_falcon512" => AdvInject(PushMemSignature {
+                    kind: SignatureKind::RpoFalcon512,
+                }),
+                _ => return Err(ParsingError::invalid_param(op, 1)),
+            },
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         _ => return Err(ParsingError::invalid_op(op)),
     };
 
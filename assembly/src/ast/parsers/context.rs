@@ -1,11 +1,22 @@
 use super::{
-    super::ProcReExport, adv_ops, debug, events, field_ops, io_ops, stack_ops, sys_ops, u32_ops,
-    CodeBody, Instruction, InvocationTarget, LibraryPath, LocalConstMap, LocalProcMap,
-    ModuleImports, Node, ParsingError, ProcedureAst, ProcedureId, ProcedureName, ReExportedProcMap,
-    Token, TokenStream, MAX_BODY_LEN, MAX_DOCS_LEN,
+    super::{
+        InlineMode, InstructionPlugin, InstructionRegistry, ProcReExport, ProcedureAttributes,
+        SourceLocation,
+    },
+    adv_ops, debug, events, field_ops, io_ops, stack_ops, sys_ops, u32_ops, CodeBody, Instruction,
+    InvocationTarget, LibraryPath, LocalConstMap, LocalProcMap, ModuleImports, Node, ParsingError,
+    ProcedureAst, ProcedureId, ProcedureName, ReExportedProcMap, Token, TokenStream, MAX_BODY_LEN,
+    MAX_DOCS_LEN,
 };
-use alloc::string::ToString;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
+use core::iter;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Prefix of `procref` labels referencing procedures exported from the kernel.
+const KERNEL_REF: &str = "$kernel::";
 
 // PARSER CONTEXT
 // ================================================================================================
@@ -17,9 +28,31 @@ pub struct ParserContext<'a> {
     pub reexported_procs: ReExportedProcMap,
     pub local_constants: LocalConstMap,
     pub num_proc_locals: u16,
+    pub instructions: &'a InstructionRegistry,
 }
 
 impl ParserContext<'_> {
+    /// Returns `true` if the specified name is the name of a built-in instruction, i.e., if a
+    /// token consisting of the name alone is not rejected as an invalid instruction.
+    pub fn is_builtin_instruction(name: &str) -> bool {
+        let mut import_info = ModuleImports::default();
+        let instructions = InstructionRegistry::default();
+        let mut context = ParserContext {
+            import_info: &mut import_info,
+            local_procs: LocalProcMap::default(),
+            reexported_procs: ReExportedProcMap::default(),
+            local_constants: LocalConstMap::default(),
+            num_proc_locals: 0,
+            instructions: &instructions,
+        };
+
+        let token = Token::new(name, SourceLocation::default());
+        match context.parse_op_token(&token) {
+            Err(err) => err != ParsingError::invalid_op(&token),
+            Ok(_) => true,
+        }
+    }
+
     // STATEMENT PARSERS
     // --------------------------------------------------------------------------------------------
 
@@ -219,7 +252,17 @@ impl ParserContext<'_> {
     // --------------------------------------------------------------------------------------------
 
     /// Parse a `procref` token into an instruction node.
+    ///
+    /// Procedures exported from the kernel are referenced via the reserved `$kernel` module name
+    /// (e.g., `procref.$kernel::foo`).
     pub fn parse_procref(&mut self, token: &Token) -> Result<Node, ParsingError> {
+        if let Some(label) = token.parts().get(1).and_then(|label| label.strip_prefix(KERNEL_REF)) {
+            let proc_name = ProcedureName::try_from(label)
+                .map_err(|err| ParsingError::invalid_proc_name(token, err))?;
+            let proc_id = ProcedureId::from_kernel_name(proc_name.as_ref());
+            return Ok(Node::Instruction(Instruction::ProcRefKernel(proc_id)));
+        }
+
         match token.parse_invocation(token.parts()[0])? {
             InvocationTarget::ProcedureName(proc_name) => {
                 let index = self.get_local_proc_index(proc_name, token)?;
@@ -245,20 +288,34 @@ impl ParserContext<'_> {
         allow_export: bool,
     ) -> Result<(), ParsingError> {
         // parse procedures until all `proc` or `exec` tokens have been consumed
-        while let Some(token) = tokens.read() {
-            let is_reexport = match token.parts()[0] {
-                Token::EXPORT => {
+        loop {
+            // parse the attributes (if any) preceding the procedure declaration
+            let proc_start = tokens.pos();
+            let attributes = self.parse_proc_attributes(tokens)?;
+
+            let is_reexport = match tokens.read() {
+                Some(token) if token.parts()[0] == Token::EXPORT => {
                     if !allow_export {
                         let proc_name = token.parts()[1];
                         return Err(ParsingError::proc_export_not_allowed(token, proc_name));
                     }
-                    token.parts()[1].contains(LibraryPath::PATH_DELIM)
+                    Some(token.parts()[1].contains(LibraryPath::PATH_DELIM))
                 }
-                Token::PROC => {
+                Some(token) if token.parts()[0] == Token::PROC => {
                     // no validation needed, parse the procedure below
-                    false
+                    Some(false)
+                }
+                _ => None,
+            };
+
+            // attributes can be attached to local procedures only
+            let is_reexport = match is_reexport {
+                Some(is_reexport) if !is_reexport || attributes.is_empty() => is_reexport,
+                None if attributes.is_empty() => break,
+                _ => {
+                    let token = tokens.read_at(proc_start).expect("no attribute token");
+                    return Err(ParsingError::dangling_proc_attributes(token));
                 }
-                _ => break,
             };
 
             if is_reexport {
@@ -267,7 +324,7 @@ impl ParserContext<'_> {
                 self.reexported_procs.insert(proc.name.clone(), proc);
             } else {
                 // parse the procedure body and add it to the list of local procedures
-                let proc = self.parse_procedure(tokens)?;
+                let proc = self.parse_procedure(tokens, proc_start)?.with_attributes(attributes);
                 let proc_idx = self.local_procs.len() as u16;
                 self.local_procs.insert(proc.name.clone(), (proc_idx, proc));
             }
@@ -276,15 +333,62 @@ impl ParserContext<'_> {
         Ok(())
     }
 
+    /// Parses procedure attributes (e.g., `@inline`) from the token stream, and consumes their
+    /// tokens. If the stream does not start with an attribute, no tokens are consumed and empty
+    /// attributes are returned.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - An attribute is unknown or malformed.
+    /// - An attribute is declared more than once, or both `@inline` and `@no_inline` are
+    ///   declared.
+    fn parse_proc_attributes(
+        &self,
+        tokens: &mut TokenStream,
+    ) -> Result<ProcedureAttributes, ParsingError> {
+        let mut attributes = ProcedureAttributes::default();
+        while let Some(token) = tokens.read() {
+            if !token.parts()[0].starts_with(Token::ATTRIBUTE_PREFIX) {
+                break;
+            }
+            if token.num_parts() > 1 {
+                return Err(ParsingError::invalid_proc_attribute(token));
+            }
+
+            match token.parts()[0] {
+                Token::INLINE | Token::NO_INLINE if attributes.inline != InlineMode::Auto => {
+                    return Err(ParsingError::conflicting_proc_attribute(token))
+                }
+                Token::INLINE => attributes.inline = InlineMode::Always,
+                Token::NO_INLINE => attributes.inline = InlineMode::Never,
+                Token::DEPRECATED if attributes.deprecated => {
+                    return Err(ParsingError::conflicting_proc_attribute(token))
+                }
+                Token::DEPRECATED => attributes.deprecated = true,
+                _ => return Err(ParsingError::invalid_proc_attribute(token)),
+            }
+            tokens.advance();
+        }
+
+        Ok(attributes)
+    }
+
     /// Parses a procedure from token stream and add it to the set of local procedures defined
     /// in this context.
     ///
+    /// The `docs_pos` specifies the position of the first token of the procedure declaration,
+    /// including its attributes (if any), to which doc comments of the procedure are attached.
+    ///
     /// # Errors
     /// Returns an error if:
     /// - Procedure declaration or procedure body is malformed.
     /// - A procedure with the same name has already been either declared or re-exported from this
     ///   context.
-    fn parse_procedure(&mut self, tokens: &mut TokenStream) -> Result<ProcedureAst, ParsingError> {
+    fn parse_procedure(
+        &mut self,
+        tokens: &mut TokenStream,
+        docs_pos: usize,
+    ) -> Result<ProcedureAst, ParsingError> {
         let proc_start = tokens.pos();
 
         // parse procedure declaration, make sure the procedure with the same name hasn't been
@@ -299,7 +403,7 @@ impl ParserContext<'_> {
 
         // attach doc comments (if any) to exported procedures
         let docs = if is_export {
-            let docs = tokens.take_doc_comment_at(proc_start);
+            let docs = tokens.take_doc_comment_at(docs_pos);
             // make sure procedure docs don't exceed the allowed limit
             if let Some(ref docs) = docs {
                 if docs.len() > MAX_DOCS_LEN {
@@ -439,8 +543,18 @@ impl ParserContext<'_> {
                     break;
                 }
                 _ => {
-                    locations.push(*token.location());
-                    nodes.push(self.parse_op_token(token)?);
+                    match self.instructions.get(token.parts()[0]) {
+                        Some(plugin) => {
+                            let instructions = self.lower_custom_op(plugin, token)?;
+                            locations
+                                .extend(iter::repeat(*token.location()).take(instructions.len()));
+                            nodes.extend(instructions.into_iter().map(Node::Instruction));
+                        }
+                        None => {
+                            locations.push(*token.location());
+                            nodes.push(self.parse_op_token(token)?);
+                        }
+                    }
                     tokens.advance();
                 }
             }
@@ -492,6 +606,7 @@ impl ParserContext<'_> {
             "gt" => simple_instruction(op, Gt),
             "gte" => simple_instruction(op, Gte),
             "is_odd" => simple_instruction(op, IsOdd),
+            "range_check" => field_ops::parse_range_check(op),
             "eqw" => simple_instruction(op, Eqw),
 
             // ----- ext2 operations -----------------------------------------------------
@@ -508,6 +623,7 @@ impl ParserContext<'_> {
             "u32assert" => u32_ops::parse_u32assert(op, &self.local_constants),
             "u32assert2" => u32_ops::parse_u32assert2(op, &self.local_constants),
             "u32assertw" => u32_ops::parse_u32assertw(op, &self.local_constants),
+            "u32assertn" => u32_ops::parse_u32assertn(op, &self.local_constants),
             "u32cast" => simple_instruction(op, U32Cast),
             "u32split" => simple_instruction(op, U32Split),
 
@@ -544,6 +660,11 @@ impl ParserContext<'_> {
             "u32rotl" => u32_ops::parse_u32_rotl(op),
 
             "u32popcnt" => simple_instruction(op, U32Popcnt),
+            "u32bswap" => simple_instruction(op, U32Bswap),
+            "u32extract" => u32_ops::parse_u32_bitfield(op),
+            "u32insert" => u32_ops::parse_u32_bitfield(op),
+            "u32fshl" => u32_ops::parse_u32_funnel_shift(op),
+            "u32fshr" => u32_ops::parse_u32_funnel_shift(op),
             "u32clz" => simple_instruction(op, U32Clz),
             "u32ctz" => simple_instruction(op, U32Ctz),
             "u32clo" => simple_instruction(op, U32Clo),
@@ -576,6 +697,9 @@ impl ParserContext<'_> {
             "cswapw" => simple_instruction(op, CSwapW),
             "cdrop" => simple_instruction(op, CDrop),
             "cdropw" => simple_instruction(op, CDropW),
+            // select instructions are aliases of conditional drops named after what they compute
+            "cselect" => alias_instruction(op, CDrop),
+            "cselectw" => alias_instruction(op, CDropW),
 
             // ----- input / output operations ----------------------------------------------------
             "push" => io_ops::parse_push(op, &self.local_constants),
@@ -584,6 +708,8 @@ impl ParserContext<'_> {
             "locaddr" => io_ops::parse_locaddr(op, &self.local_constants),
             "caller" => simple_instruction(op, Caller), // TODO: error if not in SYSCALL (issue #551)
             "clk" => simple_instruction(op, Clk),
+            "max_cycles" => simple_instruction(op, MaxCycles),
+            "remaining_cycles" => simple_instruction(op, RemainingCycles),
 
             "mem_load" => io_ops::parse_mem_load(op, &self.local_constants),
             "loc_load" => io_ops::parse_loc_load(op, &self.local_constants),
@@ -599,6 +725,7 @@ impl ParserContext<'_> {
 
             "mem_stream" => simple_instruction(op, MemStream),
             "adv_pipe" => simple_instruction(op, AdvPipe),
+            "adv_pipe_words" => simple_instruction(op, AdvPipeWords),
 
             "adv_push" => io_ops::parse_adv_push(op),
             "adv_loadw" => simple_instruction(op, AdvLoadW),
@@ -625,6 +752,7 @@ impl ParserContext<'_> {
             "syscall" => self.parse_syscall(op),
             "dynexec" => simple_instruction(op, DynExec),
             "dyncall" => simple_instruction(op, DynCall),
+            "dynsyscall" => simple_instruction(op, DynSysCall),
             "procref" => self.parse_procref(op),
 
             // ----- constant statements ----------------------------------------------------------
@@ -643,6 +771,49 @@ impl ParserContext<'_> {
         }
     }
 
+    /// Lowers a custom instruction token into the sequence of built-in instructions via the
+    /// specified plugin.
+    ///
+    /// Parameters of the token referring to local constants are replaced with the values of the
+    /// constants before they are passed to the plugin.
+    ///
+    /// # Errors
+    /// Returns an error if the plugin rejects the parameters of the token, or if the instruction
+    /// is lowered into instructions invoking procedures.
+    fn lower_custom_op(
+        &self,
+        plugin: &dyn InstructionPlugin,
+        op: &Token,
+    ) -> Result<Vec<Instruction>, ParsingError> {
+        let params: Vec<String> = op.parts()[1..]
+            .iter()
+            .map(|&param| match self.local_constants.get(param) {
+                Some(value) => value.to_string(),
+                None => param.to_string(),
+            })
+            .collect();
+        let params: Vec<&str> = params.iter().map(String::as_str).collect();
+        let instructions =
+            plugin.lower(&params).map_err(|err| ParsingError::invalid_custom_op(op, &err))?;
+
+        // procedures invoked by the lowered instructions cannot be resolved by the parser
+        for instruction in instructions.iter() {
+            if let Instruction::ExecLocal(_)
+            | Instruction::ExecImported(_)
+            | Instruction::CallLocal(_)
+            | Instruction::CallImported(_)
+            | Instruction::SysCall(_)
+            | Instruction::ProcRefLocal(_)
+            | Instruction::ProcRefImported(_)
+            | Instruction::ProcRefKernel(_) = instruction
+            {
+                return Err(ParsingError::custom_op_invokes_proc(op, &instruction.to_string()));
+            }
+        }
+
+        Ok(instructions)
+    }
+
     /// Returns an index of a local procedure for the specified procedure name.
     ///
     /// # Errors
@@ -681,3 +852,16 @@ fn simple_instruction(op: &Token, instruction: Instruction) -> Result<Node, Pars
         _ => Err(ParsingError::extra_param(op)),
     }
 }
+
+/// Validates that the provided token does not contain any immediate parameters and returns a node
+/// for the specified instruction, of which the token is an alias.
+///
+/// # Errors
+/// Returns an error if the token is not a simple operation (i.e., contains immediate values).
+fn alias_instruction(op: &Token, instruction: Instruction) -> Result<Node, ParsingError> {
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Ok(Node::Instruction(instruction)),
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
@@ -4,6 +4,7 @@ use super::{
     Node::{self, Instruction},
     ParsingError, Token,
 };
+use crate::MAX_RANGE_CHECK_BITS;
 use vm_core::{Felt, StarkField, ONE};
 
 // INSTRUCTION PARSERS
@@ -159,6 +160,24 @@ pub fn parse_neq(op: &Token) -> Result<Node, ParsingError> {
     }
 }
 
+/// Returns `RangeCheck` instruction node with the number of bits specified by the immediate value.
+///
+/// # Errors
+/// Returns an error if the instruction token does not have exactly one param, or if the param is
+/// not a number of bits in the range [1, 63].
+pub fn parse_range_check(op: &Token) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "range_check");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Err(ParsingError::missing_param(op, "range_check.<num_bits>")),
+        2 => {
+            let num_bits = parse_checked_param(op, 1, 1..=MAX_RANGE_CHECK_BITS)?;
+            Ok(Instruction(RangeCheck(num_bits)))
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
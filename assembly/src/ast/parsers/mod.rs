@@ -1,9 +1,9 @@
 use super::{
     bound_into_included_u64, AdviceInjectorNode, CodeBody, Deserializable, Felt, Instruction,
-    InvocationTarget, LabelError, LibraryPath, LocalConstMap, LocalProcMap, ModuleImports, Node,
-    ParsingError, ProcedureAst, ProcedureId, ProcedureName, ReExportedProcMap, RpoDigest,
-    SliceReader, StarkField, Token, TokenStream, MAX_BODY_LEN, MAX_DOCS_LEN, MAX_LABEL_LEN,
-    MAX_STACK_WORD_OFFSET,
+    InvocationTarget, LabelError, LibraryPath, LocalConstMap, LocalProcMap, ModuleErrorCodes,
+    ModuleImports, Node, ParsingError, ProcedureAst, ProcedureId, ProcedureName, ReExportedProcMap,
+    RpoDigest, SliceReader, StarkField, Token, TokenStream, MAX_BODY_LEN, MAX_DOCS_LEN,
+    MAX_LABEL_LEN, MAX_STACK_WORD_OFFSET,
 };
 use crate::HEX_CHUNK_SIZE;
 use alloc::string::{String, ToString};
@@ -90,6 +90,64 @@ fn parse_constant(token: &Token, constants: &LocalConstMap) -> Result<(String, u
     }
 }
 
+/// Parses all `errors` and `error` statements into the error code declarations of a module.
+///
+/// Named error codes are also added to the provided constants map, so that they can be referenced
+/// by name in the `err` parameter of assertion instructions.
+pub fn parse_error_codes(
+    tokens: &mut TokenStream,
+    constants: &mut LocalConstMap,
+) -> Result<ModuleErrorCodes, ParsingError> {
+    let mut error_codes = ModuleErrorCodes::default();
+
+    // ranges are parsed first, so that names can be checked against all ranges of the module
+    let mut names = Vec::new();
+    while let Some(token) = tokens.read() {
+        match token.parts()[0] {
+            Token::ERRORS => {
+                match token.num_parts() {
+                    0 => unreachable!(),
+                    1 | 2 => {
+                        return Err(ParsingError::missing_param(token, "errors.<first>.<last>"))
+                    }
+                    3 => {
+                        let first = parse_param_with_constant_lookup::<u32>(token, 1, constants)?;
+                        let last = parse_param_with_constant_lookup::<u32>(token, 2, constants)?;
+                        if first > last {
+                            return Err(ParsingError::invalid_error_code_range(token, first, last));
+                        }
+                        error_codes.add_range(first, last);
+                    }
+                    _ => return Err(ParsingError::extra_param(token)),
+                }
+                tokens.advance();
+            }
+            Token::ERROR => {
+                let (name, code) = parse_constant(token, constants)?;
+                if constants.contains_key(&name) {
+                    return Err(ParsingError::duplicate_const_name(token, &name));
+                }
+                let code = u32::try_from(code)
+                    .map_err(|_| ParsingError::const_conversion_failed(token, "u32"))?;
+
+                constants.insert(name.clone(), code as u64);
+                names.push((*token.location(), name, code));
+                tokens.advance();
+            }
+            _ => break,
+        }
+    }
+
+    for (location, name, code) in names {
+        if !error_codes.contains(code) {
+            return Err(ParsingError::error_code_out_of_range(location, &name, code));
+        }
+        error_codes.add_name(name, code);
+    }
+
+    Ok(error_codes)
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -210,20 +268,34 @@ fn check_div_by_zero(value: u64, op: &Token, param_idx: usize) -> Result<(), Par
 /// The code is expected to be specified via the first instruction parameter and have the form
 /// `err=<code>`.
 fn parse_error_code(token: &Token, constants: &LocalConstMap) -> Result<u32, ParsingError> {
+    parse_error_code_at(token, 1, constants)
+}
+
+/// Parses an error code specified as `err=<code>` in the parameter at the specified index of the
+/// token.
+fn parse_error_code_at(
+    token: &Token,
+    param_idx: usize,
+    constants: &LocalConstMap,
+) -> Result<u32, ParsingError> {
     let inst = token.parts()[0];
-    let err_code_parts: Vec<&str> = token.parts()[1].split('=').collect();
+    let err_code_parts: Vec<&str> = token.parts()[param_idx].split('=').collect();
     match err_code_parts.len() {
         0 => unreachable!(),
         1 => Err(ParsingError::missing_param(token, format!("{inst}.err=<code>").as_str())),
         2 => {
             if err_code_parts[0] != "err" {
-                return Err(ParsingError::invalid_param(token, 1));
+                return Err(ParsingError::invalid_param(token, param_idx));
             }
 
             let err_code_str = err_code_parts[1];
             let err_code = match try_get_constant_value(token, err_code_str, constants)? {
-                Some(val) => val.try_into().map_err(|_| ParsingError::invalid_param(token, 1))?,
-                None => err_code_str.parse().map_err(|_| ParsingError::invalid_param(token, 1))?,
+                Some(val) => {
+                    val.try_into().map_err(|_| ParsingError::invalid_param(token, param_idx))?
+                }
+                None => err_code_str
+                    .parse()
+                    .map_err(|_| ParsingError::invalid_param(token, param_idx))?,
             };
             Ok(err_code)
         }
@@ -1,11 +1,11 @@
 use super::{
-    check_div_by_zero, parse_checked_param, parse_error_code, parse_param,
+    check_div_by_zero, parse_checked_param, parse_error_code, parse_error_code_at, parse_param,
     Instruction::*,
     LocalConstMap,
     Node::{self, Instruction},
     ParsingError, Token,
 };
-use crate::{MAX_U32_ROTATE_VALUE, MAX_U32_SHIFT_VALUE};
+use crate::{MAX_U32_ASSERT_ELEMENTS, MAX_U32_ROTATE_VALUE, MAX_U32_SHIFT_VALUE};
 
 // INSTRUCTION PARSERS
 // ================================================================================================
@@ -79,6 +79,34 @@ pub fn parse_u32assertw(op: &Token, constants: &LocalConstMap) -> Result<Node, P
     }
 }
 
+/// Returns `U32AssertN` instruction node if no error code value is provided, or
+/// `U32AssertNWithError` instruction node otherwise.
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, if the number
+/// of elements is not in the range [1, 16], or if the error code is not a u32 value.
+pub fn parse_u32assertn(op: &Token, constants: &LocalConstMap) -> Result<Node, ParsingError> {
+    debug_assert_eq!(op.parts()[0], "u32assertn");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Err(ParsingError::missing_param(op, "u32assertn.<num_elements>")),
+        2 => {
+            let num_elements = parse_checked_param(op, 1, 1..=MAX_U32_ASSERT_ELEMENTS)?;
+            Ok(Instruction(U32AssertN(num_elements)))
+        }
+        3 => {
+            let num_elements = parse_checked_param(op, 1, 1..=MAX_U32_ASSERT_ELEMENTS)?;
+            let err_code = parse_error_code_at(op, 2, constants)?;
+            if err_code == 0 {
+                Ok(Instruction(U32AssertN(num_elements)))
+            } else {
+                Ok(Instruction(U32AssertNWithError(num_elements, err_code)))
+            }
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
 /// Returns `U32WrappingAdd` instruction node if no immediate value is provided or
 /// `U32WrappingAddImm` instruction node otherwise.
 ///
@@ -328,3 +356,59 @@ pub fn parse_u32_rotl(op: &Token) -> Result<Node, ParsingError> {
         _ => Err(ParsingError::extra_param(op)),
     }
 }
+
+/// Returns `U32Fshl` or `U32Fshr` instruction node with the shift amount specified by the
+/// immediate value.
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, or if the
+/// provided parameter is greater than 31.
+pub fn parse_u32_funnel_shift(op: &Token) -> Result<Node, ParsingError> {
+    let inst = op.parts()[0];
+    debug_assert!(inst == "u32fshl" || inst == "u32fshr");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 => Err(ParsingError::missing_param(op, &format!("{inst}.<shift>"))),
+        2 => {
+            let n = parse_checked_param::<u8, _>(op, 1, 0..=MAX_U32_SHIFT_VALUE)?;
+            match inst {
+                "u32fshl" => Ok(Instruction(U32Fshl(n))),
+                _ => Ok(Instruction(U32Fshr(n))),
+            }
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
+
+/// Returns `U32Extract` or `U32Insert` instruction node with the offset and the width of the bit
+/// field specified by the immediate values.
+///
+/// # Errors
+/// Returns an error if the instruction token contains wrong number of parameters, if the offset
+/// is greater than 31, if the width is not in the range [1, 32], or if the bit field does not fit
+/// into a 32-bit value.
+pub fn parse_u32_bitfield(op: &Token) -> Result<Node, ParsingError> {
+    let inst = op.parts()[0];
+    debug_assert!(inst == "u32extract" || inst == "u32insert");
+    match op.num_parts() {
+        0 => unreachable!(),
+        1 | 2 => Err(ParsingError::missing_param(op, &format!("{inst}.<offset>.<width>"))),
+        3 => {
+            let offset = parse_checked_param::<u8, _>(op, 1, 0..=MAX_U32_SHIFT_VALUE)?;
+            let width = parse_checked_param::<u8, _>(op, 2, 1..=32)?;
+            if offset + width > 32 {
+                return Err(ParsingError::invalid_param_with_reason(
+                    op,
+                    2,
+                    "the bit field must fit into a 32-bit value",
+                ));
+            }
+
+            match inst {
+                "u32extract" => Ok(Instruction(U32Extract(offset, width))),
+                _ => Ok(Instruction(U32Insert(offset, width))),
+            }
+        }
+        _ => Err(ParsingError::extra_param(op)),
+    }
+}
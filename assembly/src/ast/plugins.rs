@@ -0,0 +1,151 @@
+use super::{parsers::ParserContext, Instruction, InstructionPluginError, Token};
+use alloc::{
+    boxed::Box,
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+// INSTRUCTION PLUGIN
+// ================================================================================================
+
+/// A custom instruction (i.e., a pseudo-instruction) which can be used in Miden assembly source
+/// code in addition to the built-in instructions.
+///
+/// A custom instruction is identified by its name, and is lowered into a sequence of built-in
+/// instructions when the source code is parsed. Thus, custom instructions do not appear in the
+/// parsed ASTs, and do not require any support from the assembler or the VM.
+///
+/// For example, an instruction `add3`, which adds the top three elements of the stack, could be
+/// defined as follows:
+/// ```
+/// use miden_assembly::ast::{Instruction, InstructionPlugin};
+///
+/// struct Add3;
+///
+/// impl InstructionPlugin for Add3 {
+///     fn name(&self) -> &str {
+///         "add3"
+///     }
+///
+///     fn lower(&self, params: &[&str]) -> Result<Vec<Instruction>, String> {
+///         if !params.is_empty() {
+///             return Err("add3 does not take any parameters".to_string());
+///         }
+///         Ok(vec![Instruction::Add, Instruction::Add])
+///     }
+/// }
+/// ```
+pub trait InstructionPlugin {
+    /// Returns the name of this instruction.
+    ///
+    /// The name must start with a lowercase ASCII letter, and can contain only lowercase ASCII
+    /// letters, digits, and underscores.
+    fn name(&self) -> &str;
+
+    /// Parses the parameters of this instruction and returns the sequence of built-in
+    /// instructions this instruction is lowered into.
+    ///
+    /// The parameters are the dot-separated parts of the instruction token following the name
+    /// (e.g., `["1", "2"]` for `add3.1.2`). Parameters referring to constants defined in the
+    /// source code are replaced with the decimal values of the constants.
+    ///
+    /// The returned instructions must not invoke procedures (e.g., via `exec` or `call`), as the
+    /// procedures they refer to cannot be resolved.
+    ///
+    /// # Errors
+    /// Returns an error message if the parameters are invalid.
+    fn lower(&self, params: &[&str]) -> Result<Vec<Instruction>, String>;
+}
+
+// INSTRUCTION REGISTRY
+// ================================================================================================
+
+/// A set of custom instructions available to the parsers of Miden assembly source code.
+///
+/// When the parser encounters a token which is not a built-in instruction, it looks up a custom
+/// instruction with the name of the token in the registry, and replaces the token with the
+/// instructions it is lowered into. Parsing fails only if no such custom instruction exists.
+#[derive(Default)]
+pub struct InstructionRegistry {
+    plugins: BTreeMap<String, Box<dyn InstructionPlugin>>,
+}
+
+impl InstructionRegistry {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [InstructionRegistry] without any custom instructions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the specified custom instruction to this registry.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The name of the instruction is invalid.
+    /// - The name of the instruction is a keyword or the name of a built-in instruction.
+    /// - An instruction with the same name has already been added to this registry.
+    pub fn with_plugin<P>(mut self, plugin: P) -> Result<Self, InstructionPluginError>
+    where
+        P: InstructionPlugin + 'static,
+    {
+        let name = plugin.name();
+        let mut chars = name.chars();
+        let is_valid_name = chars.next().is_some_and(|c| c.is_ascii_lowercase())
+            && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+        if !is_valid_name {
+            return Err(InstructionPluginError::InvalidName(name.to_string()));
+        }
+        if KEYWORDS.contains(&name) || ParserContext::is_builtin_instruction(name) {
+            return Err(InstructionPluginError::ReservedName(name.to_string()));
+        }
+        if self.plugins.contains_key(name) {
+            return Err(InstructionPluginError::DuplicateName(name.to_string()));
+        }
+
+        self.plugins.insert(name.to_string(), Box::new(plugin));
+        Ok(self)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the custom instruction with the specified name, if it is present in this registry.
+    pub fn get(&self, name: &str) -> Option<&dyn InstructionPlugin> {
+        self.plugins.get(name).map(|plugin| plugin.as_ref())
+    }
+
+    /// Returns an iterator over the names of the custom instructions in this registry.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.plugins.keys().map(|name| name.as_str())
+    }
+
+    /// Returns `true` if this registry does not contain any custom instructions.
+    pub fn is_empty(&self) -> bool {
+        self.plugins.is_empty()
+    }
+}
+
+// CONSTANTS
+// ================================================================================================
+
+/// Keywords of Miden assembly which cannot be used as names of custom instructions.
+const KEYWORDS: [&str; 15] = [
+    Token::BEGIN,
+    Token::CONST,
+    Token::END,
+    Token::ERROR,
+    Token::ERRORS,
+    Token::EXPORT,
+    Token::PROC,
+    Token::USE,
+    Token::CALL,
+    Token::ELSE,
+    Token::EXEC,
+    Token::IF,
+    Token::REPEAT,
+    Token::SYSCALL,
+    Token::WHILE,
+];
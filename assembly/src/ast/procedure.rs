@@ -6,10 +6,13 @@ use alloc::{
 use crate::ast::{MAX_BODY_LEN, MAX_DOCS_LEN};
 
 use super::{
-    super::tokens::SourceLocation, code_body::CodeBody, nodes::Node, ByteReader, ByteWriter,
-    Deserializable, DeserializationError, LibraryPath, ProcedureId, ProcedureName, Serializable,
+    super::tokens::{SourceLocation, Token},
+    code_body::CodeBody,
+    nodes::Node,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, LibraryPath, ProcedureId,
+    ProcedureName, Serializable,
 };
-use core::{iter, str::from_utf8};
+use core::{fmt, iter, str::from_utf8};
 
 // PROCEDURE AST
 // ================================================================================================
@@ -17,8 +20,8 @@ use core::{iter, str::from_utf8};
 /// An abstract syntax tree of a Miden procedure.
 ///
 /// A procedure AST consists of a list of body nodes and additional metadata about the procedure
-/// (e.g., procedure name, number of memory locals used by the procedure, and whether a procedure
-/// is exported or internal).
+/// (e.g., procedure name, number of memory locals used by the procedure, whether a procedure
+/// is exported or internal, and the attributes the procedure was annotated with).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ProcedureAst {
     pub name: ProcedureName,
@@ -27,6 +30,7 @@ pub struct ProcedureAst {
     pub body: CodeBody,
     pub start: SourceLocation,
     pub is_export: bool,
+    pub attributes: ProcedureAttributes,
 }
 
 impl ProcedureAst {
@@ -52,9 +56,16 @@ impl ProcedureAst {
             body,
             is_export,
             start,
+            attributes: ProcedureAttributes::default(),
         }
     }
 
+    /// Sets the attributes of this procedure.
+    pub fn with_attributes(mut self, attributes: ProcedureAttributes) -> Self {
+        self.attributes = attributes;
+        self
+    }
+
     /// Binds the provided `locations` into the ast nodes.
     ///
     /// The `start` location points to the first node of this block.
@@ -128,6 +139,7 @@ impl Serializable for ProcedureAst {
         }
 
         target.write_bool(self.is_export);
+        self.attributes.write_into(target);
         target.write_u16(self.num_locals);
         assert!(self.body.nodes().len() <= MAX_BODY_LEN, "too many body instructions");
         target.write_u16(self.body.nodes().len() as u16);
@@ -149,6 +161,7 @@ impl Deserializable for ProcedureAst {
         };
 
         let is_export = source.read_bool()?;
+        let attributes = ProcedureAttributes::read_from(source)?;
         let num_locals = source.read_u16()?;
         let body_len = source.read_u16()? as usize;
         let nodes = source.read_many::<Node>(body_len)?;
@@ -161,10 +174,109 @@ impl Deserializable for ProcedureAst {
             start,
             is_export,
             docs,
+            attributes,
         })
     }
 }
 
+// PROCEDURE ATTRIBUTES
+// ================================================================================================
+
+/// Attributes of a procedure, declared via annotations (e.g., `@inline`) preceding the procedure
+/// declaration.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ProcedureAttributes {
+    /// Specifies how the procedure body is inlined into the MAST at its `exec` call sites.
+    pub inline: InlineMode,
+    /// Specifies whether the procedure is deprecated; the assembler emits a warning for each
+    /// invocation of a deprecated procedure.
+    pub deprecated: bool,
+}
+
+impl ProcedureAttributes {
+    /// Serialization flag of the `@inline` attribute.
+    const INLINE_FLAG: u8 = 0b001;
+    /// Serialization flag of the `@no_inline` attribute.
+    const NO_INLINE_FLAG: u8 = 0b010;
+    /// Serialization flag of the `@deprecated` attribute.
+    const DEPRECATED_FLAG: u8 = 0b100;
+
+    /// Returns `true` if no attributes are set.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl fmt::Display for ProcedureAttributes {
+    /// Writes the annotations of the attributes, each one followed by a new line.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.inline {
+            InlineMode::Auto => (),
+            InlineMode::Always => writeln!(f, "{}", Token::INLINE)?,
+            InlineMode::Never => writeln!(f, "{}", Token::NO_INLINE)?,
+        }
+        if self.deprecated {
+            writeln!(f, "{}", Token::DEPRECATED)?;
+        }
+        Ok(())
+    }
+}
+
+impl Serializable for ProcedureAttributes {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let mut flags = match self.inline {
+            InlineMode::Auto => 0,
+            InlineMode::Always => Self::INLINE_FLAG,
+            InlineMode::Never => Self::NO_INLINE_FLAG,
+        };
+        if self.deprecated {
+            flags |= Self::DEPRECATED_FLAG;
+        }
+        target.write_u8(flags);
+    }
+}
+
+impl Deserializable for ProcedureAttributes {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let flags = source.read_u8()?;
+        let inline = match flags & (Self::INLINE_FLAG | Self::NO_INLINE_FLAG) {
+            0 => InlineMode::Auto,
+            Self::INLINE_FLAG => InlineMode::Always,
+            Self::NO_INLINE_FLAG => InlineMode::Never,
+            _ => {
+                return Err(DeserializationError::InvalidValue(
+                    "conflicting procedure inline attributes".to_string(),
+                ))
+            }
+        };
+        if flags & !(Self::INLINE_FLAG | Self::NO_INLINE_FLAG | Self::DEPRECATED_FLAG) != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "invalid procedure attribute flags: {flags:#010b}"
+            )));
+        }
+        let deprecated = flags & Self::DEPRECATED_FLAG != 0;
+        Ok(Self { inline, deprecated })
+    }
+}
+
+/// Specifies how the body of a procedure is inlined into the MAST at its `exec` call sites.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum InlineMode {
+    /// The procedure body is inlined as configured by [Assembler::with_merged_spans()], i.e.,
+    /// SPAN blocks of the procedure are merged with the surrounding code only if the option is
+    /// enabled.
+    ///
+    /// [Assembler::with_merged_spans()]: crate::Assembler::with_merged_spans
+    #[default]
+    Auto,
+    /// Set by the `@inline` attribute: the procedure body is always flattened into the
+    /// surrounding code, so that its SPAN blocks are merged with adjacent SPAN blocks.
+    Always,
+    /// Set by the `@no_inline` attribute: the procedure body is always kept as a separate block,
+    /// and is never merged with the surrounding code.
+    Never,
+}
+
 // PROCEDURE RE-EXPORT
 // ================================================================================================
 
@@ -10,6 +10,7 @@ use super::{
     instrument,
     nodes::Node,
     parsers::{parse_constants, ParserContext},
+    plugins::InstructionRegistry,
     serde::AstSerdeOptions,
     {
         format::*, sort_procs_into_vec, LocalProcMap, ProcedureAst, ReExportedProcMap,
@@ -124,8 +125,17 @@ impl ProgramAst {
     /// Parses the provided source into a [ProgramAst].
     ///
     /// A program consist of a body and a set of internal (i.e., not exported) procedures.
-    #[instrument(name = "parse_program", skip_all)]
     pub fn parse(source: &str) -> Result<ProgramAst, ParsingError> {
+        Self::parse_with_instructions(source, &InstructionRegistry::default())
+    }
+
+    /// Parses the provided source into a [ProgramAst], lowering the custom instructions defined
+    /// in the specified registry into built-in instructions.
+    #[instrument(name = "parse_program", skip_all)]
+    pub fn parse_with_instructions(
+        source: &str,
+        instructions: &InstructionRegistry,
+    ) -> Result<ProgramAst, ParsingError> {
         let mut tokens = TokenStream::new(source)?;
         let mut import_info = ModuleImports::parse(&mut tokens)?;
         let local_constants = parse_constants(&mut tokens)?;
@@ -136,6 +146,7 @@ impl ProgramAst {
             reexported_procs: ReExportedProcMap::default(),
             local_constants,
             num_proc_locals: 0,
+            instructions,
         };
 
         context.parse_procedures(&mut tokens, false)?;
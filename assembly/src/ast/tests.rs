@@ -1,6 +1,8 @@
 use super::{
-    AstSerdeOptions, CodeBody, Felt, Instruction, LocalProcMap, ModuleAst, Node, ParsingError,
-    ProcedureAst, ProcedureId, ProcedureName, ProgramAst, SourceLocation, Token,
+    AstSerdeOptions, CodeBody, CodeBuilder, CodeBuilderError, Felt, InlineMode, Instruction,
+    InstructionPlugin, InstructionPluginError, InstructionRegistry, Lint, Linter, LocalProcMap,
+    ModuleAst, Node, ParsingError, ProcedureAst, ProcedureAttributes, ProcedureId, ProcedureName,
+    ProgramAst, SourceLocation, Token,
 };
 use alloc::{
     collections::BTreeMap,
@@ -775,6 +777,229 @@ fn test_ast_parsing_module_docs_fail() {
         .expect_err("Procedure comment is not immediately followed by a procedure declaration.");
 }
 
+// PROCEDURE ATTRIBUTES TESTS
+// ================================================================================================
+
+#[test]
+fn test_ast_parsing_proc_attributes() {
+    let source = "\
+    #! Adds two values.
+    @inline
+    @deprecated
+    export.foo
+        add
+    end
+
+    @no_inline proc.bar.1
+        loc_load.0
+    end
+
+    export.baz
+        exec.bar
+        exec.foo
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+    let procs = module.procs();
+
+    // doc comments preceding the attributes are attached to the procedure
+    assert_eq!(procs[0].docs, Some("Adds two values.".to_string()));
+    let expected = ProcedureAttributes {
+        inline: InlineMode::Always,
+        deprecated: true,
+    };
+    assert_eq!(procs[0].attributes, expected);
+    let expected = ProcedureAttributes {
+        inline: InlineMode::Never,
+        deprecated: false,
+    };
+    assert_eq!(procs[1].attributes, expected);
+    assert!(procs[2].attributes.is_empty());
+
+    // attributes are preserved by formatting and serialization
+    let formatted = ModuleAst::parse(&module.to_string()).unwrap();
+    assert_eq!(clear_procs_loc_module(module.clone()), clear_procs_loc_module(formatted));
+    assert_correct_module_serialization(source, true);
+
+    // attributes are attached to procedures of programs as well
+    let source = "\
+    @no_inline
+    proc.foo
+        push.1
+    end
+
+    begin
+        exec.foo
+    end";
+    let program = ProgramAst::parse(source).unwrap();
+    assert_eq!(program.procedures()[0].attributes.inline, InlineMode::Never);
+}
+
+#[test]
+fn test_ast_parsing_proc_attributes_fail() {
+    // unknown attribute
+    let source = "@pure\nexport.foo\n    add\nend";
+    let err = ModuleAst::parse(source).unwrap_err();
+    let location = SourceLocation::new(1, 1);
+    assert_eq!(err, ParsingError::invalid_proc_attribute(&Token::new("@pure", location)));
+
+    // attributes with parameters
+    let source = "@inline.1\nexport.foo\n    add\nend";
+    let err = ModuleAst::parse(source).unwrap_err();
+    assert_eq!(err, ParsingError::invalid_proc_attribute(&Token::new("@inline.1", location)));
+
+    // conflicting inline attributes
+    let source = "@inline\n@no_inline\nexport.foo\n    add\nend";
+    let err = ModuleAst::parse(source).unwrap_err();
+    let location = SourceLocation::new(2, 1);
+    assert_eq!(
+        err,
+        ParsingError::conflicting_proc_attribute(&Token::new("@no_inline", location))
+    );
+
+    // duplicate attributes
+    let source = "@deprecated\n@deprecated\nexport.foo\n    add\nend";
+    let err = ModuleAst::parse(source).unwrap_err();
+    assert_eq!(
+        err,
+        ParsingError::conflicting_proc_attribute(&Token::new("@deprecated", location))
+    );
+
+    // attributes not followed by a procedure declaration
+    let source = "export.foo\n    add\nend\n@inline";
+    let err = ModuleAst::parse(source).unwrap_err();
+    let location = SourceLocation::new(4, 1);
+    assert_eq!(err, ParsingError::dangling_proc_attributes(&Token::new("@inline", location)));
+
+    let source = "@inline\nbegin\n    add\nend";
+    let err = ProgramAst::parse(source).unwrap_err();
+    let location = SourceLocation::new(1, 1);
+    assert_eq!(err, ParsingError::dangling_proc_attributes(&Token::new("@inline", location)));
+
+    // attributes attached to a re-exported procedure
+    let source = "use.std::math::u64\n@deprecated\nexport.u64::checked_add";
+    let err = ModuleAst::parse(source).unwrap_err();
+    let location = SourceLocation::new(2, 1);
+    assert_eq!(
+        err,
+        ParsingError::dangling_proc_attributes(&Token::new("@deprecated", location))
+    );
+}
+
+// CUSTOM INSTRUCTIONS TESTS
+// ================================================================================================
+
+/// Custom instruction `push_sum.<a>.<b>` which pushes the sum of its parameters onto the stack.
+struct PushSum;
+
+impl InstructionPlugin for PushSum {
+    fn name(&self) -> &str {
+        "push_sum"
+    }
+
+    fn lower(&self, params: &[&str]) -> Result<Vec<Instruction>, String> {
+        let sum = params
+            .iter()
+            .map(|param| param.parse::<u32>().map_err(|_| format!("invalid parameter {param}")))
+            .sum::<Result<u32, String>>()?;
+        Ok(vec![Instruction::PushU32(sum), Instruction::Drop, Instruction::PushU32(sum)])
+    }
+}
+
+/// Custom instruction `exec_first` which executes the first local procedure.
+struct ExecFirst;
+
+impl InstructionPlugin for ExecFirst {
+    fn name(&self) -> &str {
+        "exec_first"
+    }
+
+    fn lower(&self, _params: &[&str]) -> Result<Vec<Instruction>, String> {
+        Ok(vec![Instruction::ExecLocal(0)])
+    }
+}
+
+/// Custom instruction with a configurable name, lowered into an `add`.
+struct Named(&'static str);
+
+impl InstructionPlugin for Named {
+    fn name(&self) -> &str {
+        self.0
+    }
+
+    fn lower(&self, _params: &[&str]) -> Result<Vec<Instruction>, String> {
+        Ok(vec![Instruction::Add])
+    }
+}
+
+#[test]
+fn test_ast_parsing_custom_instructions() {
+    let instructions = InstructionRegistry::new().with_plugin(PushSum).unwrap();
+    let source = "\
+    const.A=5
+    begin
+        push_sum.A.7
+        if.true
+            push_sum
+        end
+    end";
+
+    // without the registry, custom instructions are invalid
+    let err = ProgramAst::parse(source).unwrap_err();
+    let location = SourceLocation::new(3, 9);
+    assert_eq!(err, ParsingError::invalid_op(&Token::new("push_sum.A.7", location)));
+
+    // custom instructions are lowered into built-in instructions, and constants referenced by
+    // their parameters are resolved
+    let program = ProgramAst::parse_with_instructions(source, &instructions).unwrap();
+    let lowered = |sum| {
+        [Instruction::PushU32(sum), Instruction::Drop, Instruction::PushU32(sum)]
+            .map(Node::Instruction)
+            .to_vec()
+    };
+    let mut expected = lowered(12);
+    expected.push(Node::IfElse {
+        true_case: CodeBody::new(lowered(0)),
+        false_case: CodeBody::new([]),
+    });
+    assert_eq!(program.body().nodes(), expected.as_slice());
+
+    // all lowered instructions are mapped to the location of the custom instruction
+    let locations = program.body().source_locations();
+    assert_eq!(&locations[..3], &[location; 3]);
+
+    // errors of the plugin are reported at the location of the custom instruction
+    let source = "begin\n    push_sum.x\nend";
+    let err = ProgramAst::parse_with_instructions(source, &instructions).unwrap_err();
+    let token = Token::new("push_sum.x", SourceLocation::new(2, 5));
+    assert_eq!(err, ParsingError::invalid_custom_op(&token, "invalid parameter x"));
+
+    // custom instructions cannot be lowered into procedure invocations
+    let instructions = InstructionRegistry::new().with_plugin(ExecFirst).unwrap();
+    let source = "proc.foo\n    add\nend\nbegin\n    exec_first\nend";
+    let err = ProgramAst::parse_with_instructions(source, &instructions).unwrap_err();
+    let token = Token::new("exec_first", SourceLocation::new(5, 5));
+    assert_eq!(err, ParsingError::custom_op_invokes_proc(&token, "exec.0"));
+}
+
+#[test]
+fn test_instruction_registry_errors() {
+    let registry = InstructionRegistry::new().with_plugin(Named("push_sum")).unwrap();
+    assert_eq!(registry.names().collect::<Vec<_>>(), ["push_sum"]);
+
+    let err = registry.with_plugin(Named("push_sum")).err().unwrap();
+    assert_eq!(err, InstructionPluginError::DuplicateName("push_sum".to_string()));
+
+    for name in ["", "Push", "push.1", "1push", "push-sum"] {
+        let err = InstructionRegistry::new().with_plugin(Named(name)).err().unwrap();
+        assert_eq!(err, InstructionPluginError::InvalidName(name.to_string()));
+    }
+
+    for name in ["push", "add", "exp", "adv", "debug", "const", "if", "while", "end", "exec"] {
+        let err = InstructionRegistry::new().with_plugin(Named(name)).err().unwrap();
+        assert_eq!(err, InstructionPluginError::ReservedName(name.to_string()));
+    }
+}
+
 // SERIALIZATION AND DESERIALIZATION TESTS
 // ================================================================================================
 
@@ -971,6 +1196,47 @@ fn test_ast_module_serde_imports_not_serialized() {
     assert_correct_module_serialization(source, false);
 }
 
+#[test]
+fn test_ast_program_serde_malformed_inputs() {
+    let source = "\
+    proc.foo.1
+        loc_store.0
+    end
+
+    begin
+        push.1
+        if.true
+            exec.foo
+        else
+            repeat.2
+                push.0 while.true push.1 end
+            end
+        end
+    end";
+    let program = ProgramAst::parse(source).unwrap();
+    let program_serialized = program.to_bytes(AstSerdeOptions::new(false));
+
+    // all truncations of a well-formed program are rejected
+    for len in 0..program_serialized.len() {
+        assert!(ProgramAst::from_bytes(&program_serialized[..len]).is_err());
+    }
+
+    // control flow blocks nested deeper than the deserializer accepts are rejected
+    let nest = |depth: usize| {
+        let mut node = Node::Instruction(Instruction::Add);
+        for _ in 0..depth {
+            node = Node::While {
+                body: CodeBody::new(vec![node]),
+            };
+        }
+        ProgramAst::new(vec![node], vec![])
+            .unwrap()
+            .to_bytes(AstSerdeOptions::new(false))
+    };
+    assert!(ProgramAst::from_bytes(&nest(256)).is_ok());
+    assert!(ProgramAst::from_bytes(&nest(257)).is_err());
+}
+
 #[test]
 fn test_repeat_with_constant_count() {
     let source = "\
@@ -1018,6 +1284,181 @@ fn assert_program_output(source: &str, procedures: LocalProcMap, body: Vec<Node>
     }
 }
 
+// CODE BUILDER TESTS
+// ================================================================================================
+
+#[test]
+fn code_builder_matches_parsed_program() {
+    let source = "\
+    begin
+        push.2 mem_load.0 add dup
+        if.true
+            push.1 add
+        else
+            push.4294967296 mul
+        end
+        push.1
+        while.true
+            push.1 sub dup neq.0
+        end
+        repeat.3
+            dup.0 add
+        end
+        drop
+    end";
+
+    let mut builder = CodeBuilder::new(1);
+    builder.push(2).unwrap().mem_load(0).unwrap().add().unwrap().dup(0).unwrap();
+    builder
+        .if_else(|then| then.push(1)?.add().map(drop), |els| els.push(1 << 32)?.mul().map(drop))
+        .unwrap();
+    builder.push(1).unwrap();
+    builder
+        .while_true(|body| {
+            body.push(1)?
+                .sub()?
+                .dup(0)?
+                .instruction(Instruction::NeqImm(Felt::new(0)))
+                .map(drop)
+        })
+        .unwrap();
+    builder.repeat(3, |body| body.dup(0)?.add().map(drop)).unwrap();
+    builder.drop().unwrap();
+    assert_eq!(builder.depth(), 1);
+
+    assert_program_output(source, BTreeMap::new(), builder.build());
+}
+
+#[test]
+fn code_builder_errors() {
+    // instructions cannot consume more elements than there are on the stack
+    let mut builder = CodeBuilder::new(1);
+    let err = builder.push(1).unwrap().add().unwrap().add().unwrap_err();
+    assert_eq!(
+        err,
+        CodeBuilderError::StackUnderflow {
+            instruction: "add".to_string(),
+            required: 2,
+            depth: 1
+        }
+    );
+    assert_eq!(builder.nodes().len(), 2);
+
+    // branches must leave the stack at the same depth
+    let mut builder = CodeBuilder::new(2);
+    let err = builder.if_else(|then| then.drop().map(drop), |_| Ok(())).unwrap_err();
+    assert_eq!(err, CodeBuilderError::BranchDepthMismatch(0, 1));
+
+    // underflows are detected in nested blocks
+    let mut builder = CodeBuilder::new(1);
+    let err = builder.if_else(|then| then.drop().map(drop), |_| Ok(())).unwrap_err();
+    assert!(matches!(err, CodeBuilderError::StackUnderflow { .. }));
+
+    // loop bodies must leave the condition for the next iteration on the stack
+    let mut builder = CodeBuilder::new(2);
+    let err = builder.while_true(|body| body.drop().map(drop)).unwrap_err();
+    assert_eq!(err, CodeBuilderError::LoopDepthMismatch(2, 0));
+
+    // every iteration of a repeat block must have enough elements on the stack
+    let mut builder = CodeBuilder::new(3);
+    let err = builder.repeat(3, |body| body.add().map(drop)).unwrap_err();
+    assert_eq!(
+        err,
+        CodeBuilderError::StackUnderflow {
+            instruction: "repeat.3".to_string(),
+            required: 4,
+            depth: 3
+        }
+    );
+    assert!(builder.repeat(2, |body| body.add().map(drop)).is_ok());
+    assert_eq!(builder.depth(), 1);
+
+    // invalid immediate values and instructions with unknown stack effects are rejected
+    let mut builder = CodeBuilder::new(0);
+    assert!(matches!(builder.dup(16), Err(CodeBuilderError::InvalidImmediate { .. })));
+    assert!(matches!(builder.push(u64::MAX), Err(CodeBuilderError::InvalidImmediate { .. })));
+    assert!(matches!(
+        builder.instruction(Instruction::DynExec),
+        Err(CodeBuilderError::UnknownStackEffect(_))
+    ));
+}
+
+// LINTER TESTS
+// ================================================================================================
+
+#[test]
+fn linter_reports_warnings() {
+    let source = "\
+    const.A=1
+    const.B=2
+    const.C=B*2
+
+    proc.used
+        push.A
+    end
+
+    proc.helper
+        push.C
+    end
+
+    proc.unused
+        exec.helper
+    end
+
+    begin
+        exec.used
+        movup.15 movup.15 movup.15
+        if.true
+            push.0 assert
+            add
+        end
+    end";
+
+    let warnings = Linter::default().lint_program(source).unwrap();
+    let lints: Vec<Lint> = warnings.iter().map(|warning| warning.lint()).collect();
+    assert_eq!(
+        lints,
+        [
+            Lint::UnusedProcedure,
+            Lint::UnusedProcedure,
+            Lint::DeepStackManipulation,
+            Lint::UnreachableCode
+        ]
+    );
+    assert_eq!(warnings[0].location(), Some(&SourceLocation::new(9, 5)));
+    assert_eq!(warnings[0].message(), "procedure `helper` is never used");
+    assert_eq!(warnings[1].location(), Some(&SourceLocation::new(13, 5)));
+    assert_eq!(warnings[1].message(), "procedure `unused` is never used");
+    assert_eq!(warnings[3].location(), Some(&SourceLocation::new(22, 13)));
+
+    // lints can be disabled individually, and the deep stack threshold can be adjusted
+    let warnings = Linter::default()
+        .with_allowed(Lint::UnusedProcedure)
+        .with_max_deep_stack_moves(3)
+        .lint_program(source)
+        .unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].lint(), Lint::UnreachableCode);
+
+    // exported procedures are used by definition, but unused constants are still reported
+    let source = "\
+    const.A=1
+    const.ERR_BASE=100
+    errors.ERR_BASE.199
+    error.ERR_ONE=ERR_BASE+1
+
+    export.foo
+        push.1 assert.err=ERR_ONE
+    end";
+    let warnings = Linter::default().lint_module(source).unwrap();
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].lint(), Lint::UnusedConstant);
+    assert_eq!(
+        warnings[0].to_string(),
+        "warning[unused_constant] [1:1]: constant `A` is never used"
+    );
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
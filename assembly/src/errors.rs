@@ -1,6 +1,6 @@
 use super::{
-    ast::ProcReExport, crypto::hash::RpoDigest, tokens::SourceLocation, KernelError,
-    LibraryNamespace, ProcedureId, ProcedureName, Token,
+    ast::ProcReExport, crypto::hash::RpoDigest, tokens::SourceLocation, ErrorCodeError,
+    KernelError, LibraryNamespace, ProcedureId, ProcedureName, Token,
 };
 use alloc::{
     string::{String, ToString},
@@ -22,6 +22,8 @@ pub enum AssemblyError {
     DivisionByZero,
     DuplicateProcId(ProcedureId),
     DuplicateProcName(String, String),
+    EmptyProgramPackage,
+    ErrorCodeError(ErrorCodeError),
     ExportedProcInProgram(String),
     ImportedProcModuleNotFound(ProcedureId, String),
     ImportedProcNotFoundInModule(ProcedureId, String),
@@ -72,6 +74,10 @@ impl AssemblyError {
         Self::DuplicateProcId(*proc_id)
     }
 
+    pub fn empty_program_package() -> Self {
+        Self::EmptyProgramPackage
+    }
+
     pub fn exported_proc_in_program(proc_name: &str) -> Self {
         Self::ExportedProcInProgram(proc_name.to_string())
     }
@@ -141,11 +147,13 @@ impl fmt::Display for AssemblyError {
             CallInKernel(proc_name) => write!(f, "call instruction used kernel procedure '{proc_name}'"),
             CallSetProcedureNotFound(mast_root) => write!(f, "callset procedure not found in assembler cache for procedure with MAST root {mast_root}"),
             CallerOutOKernel => write!(f, "caller instruction used outside of kernel"),
-            CircularModuleDependency(dep_chain) => write!(f, "circular module dependency in the following chain: {dep_chain:?}"),
+            CircularModuleDependency(dep_chain) => write!(f, "circular module dependency in the following chain: {}", dep_chain.join(" -> ")),
             ConflictingNumLocals(proc_name) => write!(f, "procedure `{proc_name}` has the same MAST as another procedure but different number of locals"),
             DivisionByZero => write!(f, "division by zero"),
             DuplicateProcId(proc_id) => write!(f, "duplicate proc id {proc_id}"),
             DuplicateProcName(proc_name, module_path) => write!(f, "duplicate proc name '{proc_name}' in module {module_path}"),
+            EmptyProgramPackage => write!(f, "program package does not export any procedures"),
+            ErrorCodeError(error) => write!(f, "{}", error),
             ExportedProcInProgram(proc_name) => write!(f, "exported procedure '{proc_name}' in executable program"),
             ImportedProcModuleNotFound(proc_id, proc_name) => write!(f, "module for imported procedure `{proc_name}` with ID {proc_id} not found"),
             ImportedProcNotFoundInModule(proc_id, module_path) => write!(f, "imported procedure {proc_id} not found in module {module_path}"),
@@ -275,6 +283,29 @@ impl ParsingError {
         }
     }
 
+    // ERROR CODE DECLARATIONS
+    // --------------------------------------------------------------------------------------------
+
+    pub fn invalid_error_code_range(token: &Token, first: u32, last: u32) -> Self {
+        ParsingError {
+            message: format!(
+                "invalid error code range: first code {first} is greater than last code {last}"
+            ),
+            location: *token.location(),
+            op: token.to_string(),
+        }
+    }
+
+    pub fn error_code_out_of_range(location: SourceLocation, name: &str, code: u32) -> Self {
+        ParsingError {
+            message: format!(
+                "error code {code} of '{name}' does not belong to any error code range of the module"
+            ),
+            location,
+            op: "".to_string(),
+        }
+    }
+
     // INVALID / MALFORMED INSTRUCTIONS
     // --------------------------------------------------------------------------------------------
 
@@ -286,6 +317,24 @@ impl ParsingError {
         }
     }
 
+    pub fn invalid_custom_op(token: &Token, err: &str) -> Self {
+        ParsingError {
+            message: format!("custom instruction '{token}' is invalid: {err}"),
+            location: *token.location(),
+            op: token.to_string(),
+        }
+    }
+
+    pub fn custom_op_invokes_proc(token: &Token, instruction: &str) -> Self {
+        ParsingError {
+            message: format!(
+                "custom instruction '{token}' cannot be lowered into procedure invocation '{instruction}'"
+            ),
+            location: *token.location(),
+            op: token.to_string(),
+        }
+    }
+
     pub fn missing_param(token: &Token, expected_format: &str) -> Self {
         let _actual_params: usize = token.num_parts();
         ParsingError {
@@ -503,6 +552,33 @@ impl ParsingError {
         }
     }
 
+    pub fn invalid_proc_attribute(token: &Token) -> Self {
+        ParsingError {
+            message: format!("invalid procedure attribute: {token}"),
+            location: *token.location(),
+            op: token.to_string(),
+        }
+    }
+
+    pub fn conflicting_proc_attribute(token: &Token) -> Self {
+        ParsingError {
+            message: format!(
+                "procedure attribute {token} conflicts with a previously declared attribute"
+            ),
+            location: *token.location(),
+            op: token.to_string(),
+        }
+    }
+
+    pub fn dangling_proc_attributes(token: &Token) -> Self {
+        ParsingError {
+            message: "procedure attributes must be followed by a local procedure declaration"
+                .to_string(),
+            location: *token.location(),
+            op: token.to_string(),
+        }
+    }
+
     pub fn unmatched_proc(token: &Token, proc_name: &str) -> Self {
         ParsingError {
             message: format!("procedure '{proc_name}' has no matching end"),
@@ -1039,3 +1115,94 @@ impl fmt::Display for PathError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for PathError {}
+
+// CODE BUILDER ERROR
+// ================================================================================================
+
+/// An error which can be generated while building Miden assembly code via a
+/// [CodeBuilder](crate::ast::CodeBuilder).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodeBuilderError {
+    BranchDepthMismatch(usize, usize),
+    InvalidImmediate {
+        instruction: String,
+        value: u64,
+    },
+    LoopDepthMismatch(usize, usize),
+    StackUnderflow {
+        instruction: String,
+        required: usize,
+        depth: usize,
+    },
+    UnknownStackEffect(String),
+}
+
+impl CodeBuilderError {
+    pub fn invalid_immediate<V: Into<u64>>(instruction: &str, value: V) -> Self {
+        Self::InvalidImmediate {
+            instruction: instruction.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl fmt::Display for CodeBuilderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use CodeBuilderError::*;
+        match self {
+            BranchDepthMismatch(then_depth, else_depth) => write!(
+                f,
+                "branches of if.true leave the stack at different depths: {then_depth} and {else_depth}"
+            ),
+            InvalidImmediate { instruction, value } => {
+                write!(f, "invalid immediate value {value} for instruction '{instruction}'")
+            }
+            LoopDepthMismatch(expected, actual) => write!(
+                f,
+                "body of while.true must leave the stack at depth {expected}, but leaves it at depth {actual}"
+            ),
+            StackUnderflow {
+                instruction,
+                required,
+                depth,
+            } => write!(
+                f,
+                "instruction '{instruction}' requires {required} stack elements, but the stack depth is {depth}"
+            ),
+            UnknownStackEffect(instruction) => {
+                write!(f, "stack effect of instruction '{instruction}' is not known")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CodeBuilderError {}
+
+// INSTRUCTION PLUGIN ERROR
+// ================================================================================================
+
+/// An error which can be generated while adding a custom instruction to an
+/// [InstructionRegistry](crate::ast::InstructionRegistry).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InstructionPluginError {
+    DuplicateName(String),
+    InvalidName(String),
+    ReservedName(String),
+}
+
+impl fmt::Display for InstructionPluginError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use InstructionPluginError::*;
+        match self {
+            DuplicateName(name) => write!(f, "custom instruction '{name}' is already registered"),
+            InvalidName(name) => write!(f, "'{name}' is not a valid instruction name"),
+            ReservedName(name) => {
+                write!(f, "'{name}' is a keyword or the name of a built-in instruction")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InstructionPluginError {}
@@ -9,15 +9,19 @@ extern crate std;
 use vm_core::{
     code_blocks::CodeBlock,
     crypto,
-    errors::KernelError,
+    errors::{ErrorCodeError, KernelError},
     utils::{
         ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
     },
-    CodeBlockTable, Felt, Kernel, Operation, Program, StarkField, ONE, ZERO,
+    CodeBlockTable, ErrorCodeRegistry, Felt, Kernel, KernelMetadata, Operation, Program,
+    ProgramPackage, StarkField, ONE, ZERO,
 };
 
 mod library;
-pub use library::{Library, LibraryNamespace, LibraryPath, MaslLibrary, Module, Version};
+pub use library::{
+    DocFragment, Library, LibraryDocs, LibraryNamespace, LibraryPath, MaslLibrary, Module,
+    ModuleDocs, ProcedureDocs, Version,
+};
 
 mod procedures;
 use procedures::{CallSet, NamedProcedure, Procedure};
@@ -30,10 +34,13 @@ mod tokens;
 use tokens::{Token, TokenStream};
 
 mod errors;
-pub use errors::{AssemblyError, LabelError, LibraryError, ParsingError, PathError};
+pub use errors::{
+    AssemblyError, CodeBuilderError, InstructionPluginError, LabelError, LibraryError,
+    ParsingError, PathError,
+};
 
 mod assembler;
-pub use assembler::{Assembler, AssemblyContext};
+pub use assembler::{Assembler, AssemblyContext, ProcedureRootChange, ProcedureRoots};
 
 #[cfg(test)]
 mod tests;
@@ -53,6 +60,9 @@ const MAX_PUSH_INPUTS: usize = 16;
 /// instruction.
 const ADVICE_READ_LIMIT: u8 = 16;
 
+/// The maximum number of elements which can be checked by a single `u32assertn` instruction.
+const MAX_U32_ASSERT_ELEMENTS: u8 = 16;
+
 /// The maximum number of bits by which a u32 value can be shifted in a bitwise operation.
 const MAX_U32_SHIFT_VALUE: u8 = 31;
 
@@ -62,6 +72,9 @@ const MAX_U32_ROTATE_VALUE: u8 = 31;
 /// The maximum number of bits allowed for the exponent parameter for exponentiation instructions.
 const MAX_EXP_BITS: u8 = 64;
 
+/// The maximum number of bits of a value which can be checked by the `range_check` instruction.
+const MAX_RANGE_CHECK_BITS: u8 = 63;
+
 /// The maximum length (in bytes) of a constant, procedure, or library namespace labels.
 const MAX_LABEL_LEN: usize = 255;
 
@@ -0,0 +1,369 @@
+use super::{Library, LibraryNamespace, LibraryPath, Version};
+use crate::{
+    ast::{ModuleAst, ModuleImports},
+    ProcedureId, ProcedureName,
+};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt::Write;
+
+// LIBRARY DOCS
+// ================================================================================================
+
+/// Documentation of a library extracted from the doc comments (i.e., `#!` comments) of its modules
+/// and exported procedures.
+///
+/// References to procedures exported from modules of the library (e.g., `exec.u64::add` or
+/// `std::math::u64::add`) found in the doc comments are resolved into [DocFragment::ProcRef]
+/// fragments, which are rendered as cross-links by [LibraryDocs::to_markdown()] and
+/// [LibraryDocs::to_html()]. A module can be referenced by its full path, by the alias under which
+/// it is imported into the documented module, or by the last component of its path if this
+/// component identifies a single module of the library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LibraryDocs {
+    namespace: LibraryNamespace,
+    version: Version,
+    modules: Vec<ModuleDocs>,
+}
+
+/// Documentation of a single module of a library.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleDocs {
+    /// Absolute path of the module.
+    pub path: LibraryPath,
+    /// Doc comment of the module.
+    pub docs: Vec<DocFragment>,
+    /// Procedures exported from the module, with re-exported procedures listed first.
+    pub procedures: Vec<ProcedureDocs>,
+}
+
+/// Documentation of a procedure exported from a module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcedureDocs {
+    /// Name of the procedure.
+    pub name: ProcedureName,
+    /// Doc comment of the procedure.
+    pub docs: Vec<DocFragment>,
+    /// Path and name of the original procedure if this procedure is re-exported from another
+    /// module of the library.
+    pub reexport_of: Option<(LibraryPath, ProcedureName)>,
+}
+
+/// A fragment of a doc comment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DocFragment {
+    /// Plain text.
+    Text(String),
+    /// A reference to a procedure exported from a module of the documented library.
+    ProcRef {
+        text: String,
+        module: LibraryPath,
+        name: ProcedureName,
+    },
+}
+
+impl LibraryDocs {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Extracts the documentation of the provided library; modules are ordered by their paths.
+    pub fn new<L: Library>(library: &L) -> Self {
+        let mut modules: Vec<_> = library.modules().collect();
+        modules.sort_by(|a, b| a.path.cmp(&b.path));
+
+        // build the index of exported procedures used to resolve references in doc comments
+        let exports: BTreeMap<LibraryPath, Vec<ProcedureName>> = modules
+            .iter()
+            .map(|module| (module.path.clone(), exported_procs(&module.ast)))
+            .collect();
+        let resolver = ProcRefResolver { exports: &exports };
+
+        // build the index of exported procedures used to find the originals of re-exported ones
+        let proc_ids: BTreeMap<ProcedureId, (&LibraryPath, &ProcedureName)> = exports
+            .iter()
+            .flat_map(|(path, names)| names.iter().map(move |name| (path, name)))
+            .map(|(path, name)| (ProcedureId::from_name(name, path), (path, name)))
+            .collect();
+
+        let modules = modules
+            .into_iter()
+            .map(|module| {
+                let imports = module.ast.import_info();
+                let parse = |docs: Option<&str>| resolver.parse(docs.unwrap_or_default(), imports);
+
+                let reexported_procs =
+                    module.ast.reexported_procs().iter().map(|proc| ProcedureDocs {
+                        name: proc.name().clone(),
+                        docs: parse(proc.docs()),
+                        reexport_of: proc_ids
+                            .get(&proc.proc_id())
+                            .map(|&(path, name)| (path.clone(), name.clone())),
+                    });
+                let local_procs =
+                    module.ast.procs().iter().filter(|p| p.is_export).map(|proc| ProcedureDocs {
+                        name: proc.name.clone(),
+                        docs: parse(proc.docs.as_deref()),
+                        reexport_of: None,
+                    });
+
+                ModuleDocs {
+                    path: module.path.clone(),
+                    docs: parse(module.ast.docs().map(String::as_str)),
+                    procedures: reexported_procs.chain(local_procs).collect(),
+                }
+            })
+            .collect();
+
+        Self {
+            namespace: library.root_ns().clone(),
+            version: *library.version(),
+            modules,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root namespace of the documented library.
+    pub fn namespace(&self) -> &LibraryNamespace {
+        &self.namespace
+    }
+
+    /// Returns the version of the documented library.
+    pub fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// Returns the documentation of the modules of the library.
+    pub fn modules(&self) -> &[ModuleDocs] {
+        &self.modules
+    }
+
+    // RENDERERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Renders the documentation of the library as a single Markdown document.
+    ///
+    /// Every module is rendered as a section containing the module docs and a table of exported
+    /// procedures; procedure references link to the rows of the referenced procedures.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "# {} (v{})", self.namespace.as_ref(), self.version).unwrap();
+
+        for module in self.modules.iter() {
+            writeln!(out, "\n<a id=\"{}\"></a>\n## {}", module.path, module.path).unwrap();
+            if !module.docs.is_empty() {
+                writeln!(out, "{}", render_markdown(&module.docs).replace('\n', "<br />")).unwrap();
+            }
+            if module.procedures.is_empty() {
+                continue;
+            }
+
+            writeln!(out, "\n| Procedure | Description |\n| ----------- | ------------- |")
+                .unwrap();
+            for proc in module.procedures.iter() {
+                let mut description = String::new();
+                if let Some((path, name)) = &proc.reexport_of {
+                    write!(description, "Re-exported from [{path}::{name}](#{path}::{name}).")
+                        .unwrap();
+                    if !proc.docs.is_empty() {
+                        description.push_str("<br /><br />");
+                    }
+                }
+                description.push_str(
+                    &render_markdown(&proc.docs).replace('|', "\\|").replace('\n', "<br /><br />"),
+                );
+                writeln!(
+                    out,
+                    "| <a id=\"{}::{}\"></a>{} | {} |",
+                    module.path, proc.name, proc.name, description
+                )
+                .unwrap();
+            }
+        }
+
+        out
+    }
+
+    /// Renders the documentation of the library as a single HTML page.
+    ///
+    /// Every module is rendered as a section containing the module docs and a table of exported
+    /// procedures; procedure references link to the rows of the referenced procedures.
+    pub fn to_html(&self) -> String {
+        let title = format!("{} (v{})", escape_html(self.namespace.as_ref()), self.version);
+
+        let mut out = String::new();
+        writeln!(out, "<!DOCTYPE html>\n<html>\n<head>").unwrap();
+        writeln!(out, "<meta charset=\"utf-8\">\n<title>{title}</title>").unwrap();
+        writeln!(out, "</head>\n<body>\n<h1>{title}</h1>").unwrap();
+
+        for module in self.modules.iter() {
+            let path = escape_html(&module.path);
+            writeln!(out, "<section id=\"{path}\">\n<h2>{path}</h2>").unwrap();
+            if !module.docs.is_empty() {
+                writeln!(out, "<p>{}</p>", render_html(&module.docs)).unwrap();
+            }
+            if !module.procedures.is_empty() {
+                writeln!(out, "<table>\n<tr><th>Procedure</th><th>Description</th></tr>").unwrap();
+                for proc in module.procedures.iter() {
+                    let mut description = String::new();
+                    if let Some((source_path, name)) = &proc.reexport_of {
+                        let target = escape_html(&format!("{source_path}::{name}"));
+                        write!(description, "Re-exported from <a href=\"#{target}\">{target}</a>.")
+                            .unwrap();
+                        if !proc.docs.is_empty() {
+                            description.push_str("<br /><br />");
+                        }
+                    }
+                    description.push_str(&render_html(&proc.docs));
+                    writeln!(
+                        out,
+                        "<tr id=\"{path}::{}\"><td><code>{}</code></td><td>{description}</td></tr>",
+                        proc.name, proc.name
+                    )
+                    .unwrap();
+                }
+                writeln!(out, "</table>").unwrap();
+            }
+            writeln!(out, "</section>").unwrap();
+        }
+
+        writeln!(out, "</body>\n</html>").unwrap();
+        out
+    }
+}
+
+// PROCEDURE REFERENCE RESOLVER
+// ================================================================================================
+
+/// Resolves references to procedures exported from the modules of a library in doc comments.
+struct ProcRefResolver<'a> {
+    exports: &'a BTreeMap<LibraryPath, Vec<ProcedureName>>,
+}
+
+impl ProcRefResolver<'_> {
+    /// Splits the provided doc comment into text fragments and procedure references.
+    fn parse(&self, docs: &str, imports: &ModuleImports) -> Vec<DocFragment> {
+        let mut fragments = Vec::new();
+        let mut text_start = 0;
+        let mut chars = docs.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            if !is_path_char(c) {
+                continue;
+            }
+
+            // read the whole candidate reference, e.g., `u64::add`
+            let mut end = start + c.len_utf8();
+            while let Some((idx, c)) = chars.next_if(|&(_, c)| is_path_char(c)) {
+                end = idx + c.len_utf8();
+            }
+            let candidate = &docs[start..end];
+
+            if let Some((module, name)) = self.resolve(candidate, imports) {
+                if text_start < start {
+                    fragments.push(DocFragment::Text(docs[text_start..start].to_string()));
+                }
+                fragments.push(DocFragment::ProcRef {
+                    text: candidate.to_string(),
+                    module,
+                    name,
+                });
+                text_start = end;
+            }
+        }
+
+        if text_start < docs.len() {
+            fragments.push(DocFragment::Text(docs[text_start..].to_string()));
+        }
+        fragments
+    }
+
+    /// Returns the path of the module and the name of the procedure referenced by the candidate,
+    /// or None if the candidate does not reference a procedure exported from the library.
+    fn resolve(
+        &self,
+        candidate: &str,
+        imports: &ModuleImports,
+    ) -> Option<(LibraryPath, ProcedureName)> {
+        let (module, name) = candidate.rsplit_once(LibraryPath::PATH_DELIM)?;
+
+        let module = match LibraryPath::try_from(module) {
+            Ok(path) if self.exports.contains_key(&path) => path,
+            _ => match imports.get_module_path(module) {
+                Some(path) => path.clone(),
+                None => {
+                    let mut matches = self.exports.keys().filter(|path| path.last() == module);
+                    match (matches.next(), matches.next()) {
+                        (Some(path), None) => path.clone(),
+                        _ => return None,
+                    }
+                }
+            },
+        };
+
+        let name = self.exports.get(&module)?.iter().find(|proc| proc.as_ref() == name)?.clone();
+        Some((module, name))
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the names of the procedures exported (or re-exported) from the specified module.
+fn exported_procs(module: &ModuleAst) -> Vec<ProcedureName> {
+    let reexported_procs = module.reexported_procs().iter().map(|proc| proc.name().clone());
+    let local_procs = module.procs().iter().filter(|p| p.is_export).map(|p| p.name.clone());
+    reexported_procs.chain(local_procs).collect()
+}
+
+/// Returns true if the character can be a part of a procedure reference.
+fn is_path_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_' || c == ':'
+}
+
+/// Renders the doc fragments as Markdown text; references are rendered as links to anchors.
+fn render_markdown(fragments: &[DocFragment]) -> String {
+    let mut out = String::new();
+    for fragment in fragments {
+        match fragment {
+            DocFragment::Text(text) => out.push_str(text),
+            DocFragment::ProcRef { text, module, name } => {
+                write!(out, "[{text}](#{module}::{name})").unwrap();
+            }
+        }
+    }
+    out
+}
+
+/// Renders the doc fragments as HTML text; references are rendered as links to anchors.
+fn render_html(fragments: &[DocFragment]) -> String {
+    let mut out = String::new();
+    for fragment in fragments {
+        match fragment {
+            DocFragment::Text(text) => out.push_str(&escape_html(text).replace('\n', "<br />")),
+            DocFragment::ProcRef { text, module, name } => {
+                let target = escape_html(&format!("{module}::{name}"));
+                write!(out, "<a href=\"#{target}\">{}</a>", escape_html(text)).unwrap();
+            }
+        }
+    }
+    out
+}
+
+/// Escapes the characters which have a special meaning in HTML.
+fn escape_html(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
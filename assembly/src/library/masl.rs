@@ -337,20 +337,38 @@ impl Deserializable for MaslLibrary {
         let namespace = LibraryNamespace::read_from(source)?;
         let version = Version::read_from(source)?;
 
-        // read dependencies
+        // read dependencies; a library cannot depend on itself or list a dependency twice
         let num_deps = source.read_u16()? as usize;
-        // TODO: check for duplicate/self-referential dependencies?
-        let deps_set: BTreeSet<LibraryNamespace> = (0..num_deps)
-            .map(|_| LibraryNamespace::read_from(source))
-            .collect::<Result<_, _>>()?;
+        let mut deps_set = BTreeSet::new();
+        for _ in 0..num_deps {
+            let dep = LibraryNamespace::read_from(source)?;
+            if dep == namespace {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "library {} depends on itself",
+                    namespace.as_str()
+                )));
+            }
+            if !deps_set.insert(dep) {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "library {} lists a dependency more than once",
+                    namespace.as_str()
+                )));
+            }
+        }
 
-        // read modules
+        // read modules; module paths must be unique
         let num_modules = source.read_u16()? as usize;
-        let mut modules = Vec::with_capacity(num_modules);
+        let mut modules = Vec::new();
+        let mut paths = BTreeSet::new();
         for _ in 0..num_modules {
             let path = LibraryPath::read_from(source)?
                 .prepend(&namespace)
                 .map_err(|err| DeserializationError::InvalidValue(format!("{err}")))?;
+            if !paths.insert(path.clone()) {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "module {path} is defined more than once"
+                )));
+            }
             let ast = ModuleAst::read_from(source, AST_DEFAULT_SERDE_OPTIONS)?;
             modules.push(Module { path, ast });
         }
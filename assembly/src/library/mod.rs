@@ -5,6 +5,9 @@ use super::{
 };
 use core::{cmp::Ordering, fmt, ops::Deref, str::from_utf8};
 
+mod docs;
+pub use docs::{DocFragment, LibraryDocs, ModuleDocs, ProcedureDocs};
+
 mod masl;
 use alloc::string::{String, ToString};
 pub use masl::MaslLibrary;
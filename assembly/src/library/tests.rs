@@ -1,5 +1,8 @@
-use super::{Library, LibraryNamespace, LibraryPath, MaslLibrary, Module, ModuleAst, Version};
-use alloc::vec::Vec;
+use super::{
+    DocFragment, Library, LibraryDocs, LibraryNamespace, LibraryPath, MaslLibrary, Module,
+    ModuleAst, Version,
+};
+use alloc::{string::ToString, vec::Vec};
 use vm_core::utils::{Deserializable, Serializable, SliceReader};
 
 #[test]
@@ -91,3 +94,78 @@ end
 
     assert!(bundle.get_module_ast(&LibraryPath::new("test::bar").unwrap()).is_none());
 }
+
+#[test]
+fn library_docs() {
+    let foo_source = "\
+    #! Foo module.
+
+    #! Adds two elements.
+    export.add
+        add
+    end
+
+    proc.internal
+        mul
+    end";
+    let foo_path = LibraryPath::new("test::foo").unwrap();
+    let foo = Module::new(foo_path.clone(), ModuleAst::parse(foo_source).unwrap());
+
+    let bar_source = "\
+    use.test::foo
+
+    #! Re-exported adder.
+    export.foo::add->plus
+
+    #! Calls exec.foo::add and test::foo::add, but not foo::internal or baz::add.
+    export.twice
+        exec.foo::add
+        exec.foo::add
+    end";
+    let bar_path = LibraryPath::new("test::bar").unwrap();
+    let bar = Module::new(bar_path.clone(), ModuleAst::parse(bar_source).unwrap());
+
+    let namespace = LibraryNamespace::new("test").unwrap();
+    let library =
+        MaslLibrary::new(namespace, Version::MIN, false, vec![foo, bar], Vec::new()).unwrap();
+    let docs = LibraryDocs::new(&library);
+
+    // modules are ordered by their paths, and internal procedures are not documented
+    let modules = docs.modules();
+    assert_eq!(modules.len(), 2);
+    assert_eq!(modules[0].path, bar_path);
+    assert_eq!(modules[1].path, foo_path);
+    assert_eq!(modules[1].docs, [DocFragment::Text("Foo module.".to_string())]);
+    assert_eq!(modules[1].procedures.len(), 1);
+
+    // re-exported procedures are linked to their originals
+    let plus = &modules[0].procedures[0];
+    assert_eq!(plus.name.as_ref(), "plus");
+    assert_eq!(plus.reexport_of, Some((foo_path.clone(), "add".try_into().unwrap())));
+
+    // references to exported procedures are resolved via imports or full paths
+    let twice = &modules[0].procedures[1];
+    let proc_ref = |text: &str| DocFragment::ProcRef {
+        text: text.to_string(),
+        module: foo_path.clone(),
+        name: "add".try_into().unwrap(),
+    };
+    assert_eq!(
+        twice.docs,
+        [
+            DocFragment::Text("Calls exec.".to_string()),
+            proc_ref("foo::add"),
+            DocFragment::Text(" and ".to_string()),
+            proc_ref("test::foo::add"),
+            DocFragment::Text(", but not foo::internal or baz::add.".to_string()),
+        ]
+    );
+
+    let markdown = docs.to_markdown();
+    assert!(markdown.contains("Calls exec.[foo::add](#test::foo::add)"));
+    assert!(markdown.contains("| <a id=\"test::bar::plus\"></a>plus | Re-exported from "));
+
+    let html = docs.to_html();
+    assert!(html.contains("<tr id=\"test::foo::add\">"));
+    assert!(html.contains("Calls exec.<a href=\"#test::foo::add\">foo::add</a>"));
+}
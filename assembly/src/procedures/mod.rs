@@ -3,6 +3,7 @@ use super::{
     ByteReader, ByteWriter, CodeBlock, Deserializable, DeserializationError, LabelError,
     LibraryPath, Serializable, PROCEDURE_LABEL_PARSER,
 };
+use crate::ast::ProcedureAttributes;
 use alloc::{
     collections::BTreeSet,
     string::{String, ToString},
@@ -21,11 +22,13 @@ use core::{
 /// Procedure metadata includes:
 /// - Number of procedure locals available to the procedure.
 /// - A set of MAST roots of procedures which are invoked from this procedure.
+/// - Attributes the procedure was annotated with.
 #[derive(Clone, Debug)]
 pub struct Procedure {
     num_locals: u32,
     code: CodeBlock,
     callset: CallSet,
+    attributes: ProcedureAttributes,
 }
 
 impl Procedure {
@@ -49,6 +52,11 @@ impl Procedure {
     pub fn callset(&self) -> &CallSet {
         &self.callset
     }
+
+    /// Returns the attributes of this procedure.
+    pub fn attributes(&self) -> &ProcedureAttributes {
+        &self.attributes
+    }
 }
 
 // NAMED PROCEDURE
@@ -60,6 +68,7 @@ impl Procedure {
 /// - Procedure name.
 /// - A boolean flag indicating whether the procedure is exported from a module.
 /// - A set of MAST roots of procedures which are invoked from this procedure.
+/// - Attributes the procedure was annotated with.
 #[derive(Clone, Debug)]
 pub struct NamedProcedure {
     name: ProcedureName,
@@ -85,10 +94,17 @@ impl NamedProcedure {
                 num_locals,
                 code,
                 callset,
+                attributes: ProcedureAttributes::default(),
             },
         }
     }
 
+    /// Sets the attributes of this procedure.
+    pub fn with_attributes(mut self, attributes: ProcedureAttributes) -> Self {
+        self.procedure.attributes = attributes;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -123,6 +139,11 @@ impl NamedProcedure {
         &self.procedure.callset
     }
 
+    /// Returns the attributes of this procedure.
+    pub fn attributes(&self) -> &ProcedureAttributes {
+        &self.procedure.attributes
+    }
+
     /// Returns the inner procedure containing all procedure attributes except for procedure name
     /// and ID.
     pub fn inner(&self) -> &Procedure {
@@ -1,10 +1,16 @@
 use crate::{
-    ast::{ModuleAst, ProgramAst},
+    ast::{
+        AstSerdeOptions, Instruction, InstructionPlugin, InstructionRegistry, ModuleAst, ProgramAst,
+    },
     Assembler, AssemblyContext, AssemblyError, Library, LibraryNamespace, LibraryPath, MaslLibrary,
-    Module, ProcedureName, Version,
+    Module, ProcedureName, ProcedureRoots, Version,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
 };
-use alloc::{string::ToString, vec::Vec};
 use core::slice::Iter;
+use vm_core::utils::{Deserializable, Serializable};
 
 // SIMPLE PROGRAMS
 // ================================================================================================
@@ -909,6 +915,180 @@ fn u32assertw_with_code() {
     assert_eq!(expected, format!("{program}"));
 }
 
+#[test]
+fn u32assertn_with_code() {
+    let source = "\
+    const.ERR1=1
+
+    begin
+        u32assertn.3
+        u32assertn.2.err=ERR1
+        u32assertn.10.err=2
+    end
+    "
+    .to_string();
+    let assembler = Assembler::default();
+    let program = assembler.compile(source).unwrap();
+
+    let expected = "\
+        begin \
+            span \
+                u32assert2(0) movup2 u32assert2(0) movdn2 \
+                u32assert2(1) \
+                u32assert2(2) movup7 movup7 u32assert2(2) movup7 movup7 \
+                u32assert2(2) movup7 movup7 u32assert2(2) movup7 movup7 \
+                swapdw u32assert2(2) swapdw \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+
+    // the number of elements must be between 1 and 16
+    assert!(assembler.compile("begin u32assertn.0 end").is_err());
+    assert!(assembler.compile("begin u32assertn.17 end").is_err());
+    assert!(assembler.compile("begin u32assertn end").is_err());
+}
+
+#[test]
+fn u32_bitfield_ops() {
+    let source = "\
+    begin
+        u32extract.0.32
+        u32extract.0.8
+        u32extract.24.8
+        u32extract.4.8
+        u32insert.4.8
+    end
+    "
+    .to_string();
+    let assembler = Assembler::default();
+    let program = assembler.compile(source).unwrap();
+
+    let expected = "\
+        begin \
+            span \
+                noop \
+                push(255) u32and \
+                push(256) mul u32split swap drop \
+                push(268435456) mul u32split swap drop push(255) u32and \
+                push(255) u32and push(16) mul swap push(4294963215) u32and add \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+
+    // the bit field must fit into a 32-bit value
+    assert!(assembler.compile("begin u32extract.16.17 end").is_err());
+    assert!(assembler.compile("begin u32insert.32.1 end").is_err());
+    assert!(assembler.compile("begin u32insert.0.0 end").is_err());
+    assert!(assembler.compile("begin u32extract.8 end").is_err());
+}
+
+#[test]
+fn exp_imm_schedules() {
+    let source = "\
+    begin
+        exp.3
+        exp.6
+        exp.8
+    end
+    "
+    .to_string();
+    let assembler = Assembler::default();
+    let program = assembler.compile(source).unwrap();
+
+    // exp.3 uses repeated multiplication, while exp.6 and exp.8 use square-and-multiply
+    let expected = "\
+        begin \
+            span \
+                dup0 dup0 mul mul \
+                dup0 dup0 mul dup1 mul dup0 mul swap drop \
+                dup0 mul dup0 mul dup0 mul \
+            end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+}
+
+// ERROR CODES
+// ================================================================================================
+
+#[test]
+fn error_codes_registered_by_module() {
+    let namespace = LibraryNamespace::try_from("dummy".to_string()).unwrap();
+    let path = LibraryPath::try_from("math::checked".to_string())
+        .unwrap()
+        .prepend(&namespace)
+        .unwrap();
+    let ast = ModuleAst::parse(
+        "\
+    const.BASE=1000
+    errors.BASE.1099
+    error.ERR_OVERFLOW=BASE+1
+    error.ERR_ZERO=1002
+
+    export.check_nonzero
+        assert.err=ERR_ZERO
+    end",
+    )
+    .unwrap();
+    assert_eq!(ast.error_codes().ranges(), &[(1000, 1099)]);
+
+    // error code declarations survive serialization of the module
+    let bytes = ast.to_bytes(AstSerdeOptions::new(true));
+    assert_eq!(ModuleAst::from_bytes(&bytes).unwrap().error_codes(), ast.error_codes());
+
+    let library = DummyLibrary::new(namespace, vec![Module { path, ast }]);
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    let program = assembler
+        .compile("use.dummy::math::checked begin exec.checked::check_nonzero end")
+        .unwrap();
+
+    let expected = "\
+        begin \
+            span assert(1002) end \
+        end";
+    assert_eq!(expected, format!("{program}"));
+
+    let error = program.error_codes().decode(1002).unwrap();
+    assert_eq!(error.to_string(), "dummy::math::checked::ERR_ZERO");
+    assert_eq!(program.error_codes().decode(1050).unwrap().name, None);
+    assert_eq!(program.error_codes().decode(1100), None);
+}
+
+#[test]
+fn error_code_declaration_errors() {
+    let source = "errors.10.5 export.foo push.1 end";
+    let err = ModuleAst::parse(source).err().unwrap();
+    assert_eq!(
+        err.message(),
+        "invalid error code range: first code 10 is greater than last code 5"
+    );
+
+    let source = "errors.10.20 error.ERR_A=21 export.foo push.1 end";
+    let err = ModuleAst::parse(source).err().unwrap();
+    assert_eq!(
+        err.message(),
+        "error code 21 of 'ERR_A' does not belong to any error code range of the module"
+    );
+
+    let source = "const.ERR_A=1 errors.10.20 error.ERR_A=11 export.foo push.1 end";
+    let err = ModuleAst::parse(source).err().unwrap();
+    assert_eq!(err.message(), "duplicate constant name: 'ERR_A'");
+
+    // ranges of different modules may not overlap
+    let assembler = Assembler::default();
+    let mut context = AssemblyContext::for_module(false);
+    let module = ModuleAst::parse("errors.10.20 export.foo push.1 end").unwrap();
+    let path = LibraryPath::new("foo::bar").unwrap();
+    assembler.compile_module(&module, Some(&path), &mut context).unwrap();
+
+    let module = ModuleAst::parse("errors.20.30 export.foo push.2 end").unwrap();
+    let path = LibraryPath::new("foo::baz").unwrap();
+    let err = assembler.compile_module(&module, Some(&path), &mut context).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "error code range of foo::baz overlaps with a range of foo::bar"
+    );
+}
+
 // NESTED CONTROL BLOCKS
 // ================================================================================================
 
@@ -1045,6 +1225,37 @@ fn program_with_dynamic_code_execution_in_new_context() {
     assert_eq!(expected, format!("{program}"));
 }
 
+#[test]
+fn program_with_dynamic_syscall() {
+    let assembler = super::Assembler::default();
+    let source = "begin dynsyscall end";
+    let program = assembler.compile(source).unwrap();
+    let expected =
+        "begin syscall.0xc75c340ec6a69e708457544d38783abbb604d881b7dc62d00bfc2b10f52808e6 end";
+    assert_eq!(expected, format!("{program}"));
+
+    // dynamic syscalls cannot be made from within a kernel
+    let kernel = "export.foo dynsyscall end";
+    assert!(super::Assembler::default().with_kernel(kernel).is_err());
+}
+
+#[test]
+fn program_with_kernel_procref() {
+    let kernel = "export.foo add end";
+    let assembler = super::Assembler::default().with_kernel(kernel).unwrap();
+    let foo = assembler.kernel().proc_hashes()[0];
+
+    let program = assembler.compile("begin procref.$kernel::foo end").unwrap();
+    let expected = super::Assembler::default()
+        .compile(format!("begin push.{}.{}.{}.{} end", foo[0], foo[1], foo[2], foo[3]))
+        .unwrap();
+    assert_eq!(expected.hash(), program.hash());
+
+    // kernel procedures must exist
+    let source = "begin procref.$kernel::bar end";
+    assert!(assembler.compile(source).is_err());
+}
+
 // MAST ROOT CALLS
 // ================================================================================================
 
@@ -1103,6 +1314,80 @@ fn program_with_phantom_mast_call() {
     assert!(result.is_ok());
 }
 
+// PROGRAM PACKAGES
+// ================================================================================================
+
+#[test]
+fn program_package_entrypoints() {
+    let assembler = Assembler::default();
+    let procs = "\
+    proc.helper
+        push.2 mul
+    end
+
+    export.double
+        exec.helper
+    end
+
+    export.quadruple
+        exec.helper exec.helper
+    end
+    ";
+    let package = assembler.compile_package(procs).unwrap();
+    assert_eq!(package.len(), 2);
+    assert_eq!(package.names().collect::<Vec<_>>(), ["double", "quadruple"]);
+    assert!(package.entrypoint("helper").is_none());
+
+    // each entrypoint is the same program as the one which invokes the procedure from its body
+    for name in ["double", "quadruple"] {
+        let source = format!("{} begin exec.{name} end", procs.replace("export.", "proc."));
+        let expected = assembler.compile(source).unwrap();
+        assert_eq!(package.entrypoint(name).unwrap().hash(), expected.hash());
+    }
+    assert_ne!(
+        package.entrypoint("double").unwrap().hash(),
+        package.entrypoint("quadruple").unwrap().hash()
+    );
+}
+
+#[test]
+fn program_package_with_imports() {
+    let namespace = LibraryNamespace::try_from("dummy".to_string()).unwrap();
+    let path = LibraryPath::try_from("math::u256".to_string())
+        .unwrap()
+        .prepend(&namespace)
+        .unwrap();
+    let ast = ModuleAst::parse("export.iszero_unsafe eq.0 repeat.7 swap eq.0 and end end").unwrap();
+    let library = DummyLibrary::new(namespace, vec![Module { path, ast }]);
+    let assembler = Assembler::default().with_library(&library).unwrap();
+
+    // entrypoints inherit the imports of the module
+    let module = ModuleAst::parse(
+        "use.dummy::math::u256 export.check push.4 push.3 exec.u256::iszero_unsafe end",
+    )
+    .unwrap();
+    let program = module.entrypoint_program("check").unwrap();
+    assert_eq!(program.import_info(), module.import_info());
+    assert!(program.procedures().iter().all(|proc| !proc.is_export));
+
+    let package = assembler.compile_package_ast(&module).unwrap();
+    let expected = assembler
+        .compile(
+            "use.dummy::math::u256
+            proc.check push.4 push.3 exec.u256::iszero_unsafe end
+            begin exec.check end",
+        )
+        .unwrap();
+    assert_eq!(package.entrypoint("check").unwrap().hash(), expected.hash());
+}
+
+#[test]
+fn program_package_without_exports() {
+    let assembler = Assembler::default();
+    let result = assembler.compile_package("proc.foo push.1 end");
+    assert_eq!(result.unwrap_err(), AssemblyError::empty_program_package());
+}
+
 // IMPORTS
 // ================================================================================================
 
@@ -1479,6 +1764,315 @@ fn program_with_import_errors() {
     );
 }
 
+// PROCEDURE ROOTS
+// ================================================================================================
+
+#[test]
+fn procedure_roots_of_library() {
+    const NAMESPACE: &str = "dummy";
+    const U256_MODULE_BODY: &str = r#"
+        use.dummy::math::u64
+        export.u64::unchecked_eqz->eqz
+        export.iszero
+            eq.0
+        end
+    "#;
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let build_library = |helper_body: &str| {
+        let u64_body = format!(
+            r#"
+            export.checked_eqz
+                u32assert2
+                eq.0
+                swap
+                eq.0
+                and
+            end
+            proc.helper
+                {helper_body}
+            end
+            export.unchecked_eqz
+                exec.helper
+                swap
+                exec.helper
+                and
+            end"#
+        );
+        let modules = [("math::u64", u64_body.as_str()), ("math::u256", U256_MODULE_BODY)]
+            .into_iter()
+            .map(|(path, body)| {
+                let path =
+                    LibraryPath::try_from(path.to_string()).unwrap().prepend(&namespace).unwrap();
+                Module::new(path, ModuleAst::parse(body).unwrap())
+            })
+            .collect();
+        DummyLibrary::new(namespace.clone(), modules)
+    };
+
+    let library = build_library("eq.0");
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    let roots = assembler.procedure_roots(&library).unwrap();
+
+    // internal procedures are not part of the report, re-exported procedures are
+    let paths = roots.iter().map(|(path, _)| path).collect::<Vec<_>>();
+    assert_eq!(
+        paths,
+        vec![
+            "dummy::math::u256::eqz",
+            "dummy::math::u256::iszero",
+            "dummy::math::u64::checked_eqz",
+            "dummy::math::u64::unchecked_eqz",
+        ]
+    );
+    assert_eq!(
+        roots.get("dummy::math::u256::eqz"),
+        roots.get("dummy::math::u64::unchecked_eqz")
+    );
+
+    // the text format contains one line per procedure, sorted by procedure path
+    let report = roots.to_string();
+    let lines = report.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), 4);
+    assert!(lines[0].starts_with("dummy::math::u256::eqz 0x"));
+    assert_eq!(lines[0].len(), "dummy::math::u256::eqz 0x".len() + 64);
+
+    // computing roots again with a fresh assembler yields an identical report
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    assert_eq!(roots, assembler.procedure_roots(&library).unwrap());
+
+    // the report survives a serialization round trip
+    let bytes = roots.to_bytes();
+    assert_eq!(roots, ProcedureRoots::read_from_bytes(&bytes).unwrap());
+
+    // changing an internal procedure changes the roots of all exported procedures using it
+    let new_library = build_library("eq.0 not not");
+    let assembler = Assembler::default().with_library(&new_library).unwrap();
+    let new_roots = assembler.procedure_roots(&new_library).unwrap();
+    let changed = roots.diff(&new_roots).iter().map(|c| c.path().to_string()).collect::<Vec<_>>();
+    assert_eq!(changed, vec!["dummy::math::u256::eqz", "dummy::math::u64::unchecked_eqz"]);
+}
+
+// MODULE CACHE
+// ================================================================================================
+
+#[cfg(feature = "std")]
+#[test]
+fn module_cache() {
+    use std::{fs, process};
+
+    const NAMESPACE: &str = "dummy";
+    const U256_MODULE_BODY: &str = r#"
+        use.dummy::math::u64
+        export.iszero
+            exec.u64::eqz
+            swap
+            exec.u64::eqz
+            and
+        end
+    "#;
+    const PROGRAM: &str = r#"
+        use.dummy::math::u256
+        begin
+            exec.u256::iszero
+        end
+    "#;
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let build_library = |eqz_attributes: &str, eqz_body: &str| {
+        let u64_body = format!("{eqz_attributes} export.eqz {eqz_body} end");
+        let modules = [("math::u64", u64_body.as_str()), ("math::u256", U256_MODULE_BODY)]
+            .into_iter()
+            .map(|(path, body)| {
+                let path =
+                    LibraryPath::try_from(path.to_string()).unwrap().prepend(&namespace).unwrap();
+                Module::new(path, ModuleAst::parse(body).unwrap())
+            })
+            .collect();
+        DummyLibrary::new(namespace.clone(), modules)
+    };
+
+    let cache_dir = std::env::temp_dir().join(format!("miden-module-cache-{}", process::id()));
+    let num_entries = || fs::read_dir(&cache_dir).unwrap().count();
+    let compile = |library: &DummyLibrary, use_cache: bool| {
+        let mut assembler = Assembler::default().with_library(library).unwrap();
+        if use_cache {
+            assembler = assembler.with_cache(&cache_dir).unwrap();
+        }
+        assembler.compile(PROGRAM).unwrap()
+    };
+
+    // the first compilation populates the cache with one entry per module
+    let library = build_library("", "eq.0");
+    let program = compile(&library, true);
+    assert_eq!(program.hash(), compile(&library, false).hash());
+    assert_eq!(num_entries(), 2);
+
+    // compiling the same library again reuses the existing entries
+    assert_eq!(program.hash(), compile(&library, true).hash());
+    assert_eq!(num_entries(), 2);
+
+    // changing a module invalidates entries of the module and of all modules which depend on it
+    let new_library = build_library("", "eq.0 not not");
+    let new_program = compile(&new_library, true);
+    assert_ne!(program.hash(), new_program.hash());
+    assert_eq!(new_program.hash(), compile(&new_library, false).hash());
+    assert_eq!(num_entries(), 4);
+
+    // changing the attributes of an imported procedure leaves its MAST root intact, but changes
+    // the code of the importing module, and thus, invalidates its entry as well
+    let sealed_library = build_library("@no_inline", "eq.0");
+    let sealed_program = compile(&sealed_library, true);
+    assert_ne!(program.hash(), sealed_program.hash());
+    assert_eq!(sealed_program.hash(), compile(&sealed_library, false).hash());
+    assert_eq!(num_entries(), 6);
+
+    // corrupted entries are ignored and the modules are compiled from source
+    for entry in fs::read_dir(&cache_dir).unwrap() {
+        fs::write(entry.unwrap().path(), [0xff; 8]).unwrap();
+    }
+    assert_eq!(program.hash(), compile(&library, true).hash());
+
+    fs::remove_dir_all(&cache_dir).unwrap();
+}
+
+// IMPORT CYCLES
+// ================================================================================================
+
+#[test]
+fn import_cycles() {
+    const NAMESPACE: &str = "dummy";
+    const PROGRAM: &str = r#"
+        use.dummy::a
+        begin
+            exec.a::foo
+        end
+    "#;
+
+    let namespace = LibraryNamespace::try_from(NAMESPACE.to_string()).unwrap();
+    let build_library = |modules: &[(&str, &str)]| {
+        let modules = modules
+            .iter()
+            .map(|(path, body)| {
+                let path =
+                    LibraryPath::try_from(path.to_string()).unwrap().prepend(&namespace).unwrap();
+                Module::new(path, ModuleAst::parse(body).unwrap())
+            })
+            .collect();
+        DummyLibrary::new(namespace.clone(), modules)
+    };
+
+    // the full import chain is reported for a cycle between modules with procedures
+    let library = build_library(&[
+        ("a", "use.dummy::b export.foo exec.b::bar end"),
+        ("b", "use.dummy::c export.bar exec.c::baz end"),
+        ("c", "use.dummy::a export.baz push.1 end"),
+    ]);
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    let err = assembler.compile(PROGRAM).unwrap_err();
+    let dep_chain = ["dummy::a", "dummy::b", "dummy::c", "dummy::a"].map(String::from);
+    assert_eq!(err, AssemblyError::CircularModuleDependency(dep_chain.to_vec()));
+    assert_eq!(
+        err.to_string(),
+        "circular module dependency in the following chain: \
+        dummy::a -> dummy::b -> dummy::c -> dummy::a"
+    );
+
+    // cycles through constants-only modules are rejected by default
+    let library = build_library(&[
+        ("a", "use.dummy::consts export.foo push.1 end"),
+        ("consts", "use.dummy::a const.ONE=1"),
+    ]);
+    let assembler = Assembler::default().with_library(&library).unwrap();
+    let dep_chain = ["dummy::a", "dummy::consts", "dummy::a"].map(String::from);
+    assert_eq!(
+        assembler.compile(PROGRAM).unwrap_err(),
+        AssemblyError::CircularModuleDependency(dep_chain.to_vec())
+    );
+
+    // but can be allowed via the assembler option
+    let assembler = Assembler::default()
+        .with_constants_only_cycles(true)
+        .with_library(&library)
+        .unwrap();
+    assert!(assembler.compile(PROGRAM).is_ok());
+
+    // cycles between modules of different libraries are detected once both libraries are added
+    let library = build_library(&[("a", "use.other::b export.foo exec.b::bar end")]);
+    let other_namespace = LibraryNamespace::try_from("other".to_string()).unwrap();
+    let other_path = LibraryPath::try_from("other::b".to_string()).unwrap();
+    let other_module =
+        Module::new(other_path, ModuleAst::parse("use.dummy::a export.bar push.1 end").unwrap());
+    let other_library = DummyLibrary::new(other_namespace, vec![other_module]);
+    let assembler = Assembler::default()
+        .with_library(&library)
+        .and_then(|assembler| assembler.with_library(&other_library))
+        .unwrap();
+    let dep_chain = ["dummy::a", "other::b", "dummy::a"].map(String::from);
+    assert_eq!(
+        assembler.compile(PROGRAM).unwrap_err(),
+        AssemblyError::CircularModuleDependency(dep_chain.to_vec())
+    );
+}
+
+// CUSTOM INSTRUCTIONS
+// ================================================================================================
+
+/// Custom instruction `addn.<n>` which adds the top `n` elements of the stack.
+struct AddN;
+
+impl InstructionPlugin for AddN {
+    fn name(&self) -> &str {
+        "addn"
+    }
+
+    fn lower(&self, params: &[&str]) -> Result<Vec<Instruction>, String> {
+        let n = match params {
+            [n] => n.parse::<u8>().map_err(|err| err.to_string())?,
+            _ => return Err("expected a single parameter".to_string()),
+        };
+        if n < 2 {
+            return Err(format!("at least 2 elements must be added, but was {n}"));
+        }
+        Ok((1..n).map(|_| Instruction::Add).collect())
+    }
+}
+
+#[test]
+fn custom_instructions() {
+    let instructions = InstructionRegistry::new().with_plugin(AddN).unwrap();
+    let kernel = "\
+    export.foo
+        addn.2
+    end";
+    let assembler = Assembler::default()
+        .with_instructions(instructions)
+        .with_kernel(kernel)
+        .unwrap();
+
+    let source = "\
+    const.THREE=3
+    begin
+        addn.THREE
+        addn.2
+        syscall.foo
+    end";
+    let program = assembler.compile(source).unwrap();
+
+    // custom instructions are lowered into built-in instructions, also in the kernel
+    let expected = assembler.compile("begin add add add syscall.foo end").unwrap();
+    assert_eq!(expected.hash(), program.hash());
+    let expected = Assembler::default().with_kernel("export.foo add end").unwrap();
+    assert_eq!(expected.kernel(), assembler.kernel());
+
+    // custom instructions are not available to assemblers without the registry
+    assert!(Assembler::default().compile("begin addn.2 end").is_err());
+
+    // errors reported by the plugin are returned as parsing errors
+    let err = assembler.compile("begin addn.1 end").unwrap_err();
+    assert!(err.to_string().contains("at least 2 elements must be added, but was 1"));
+}
+
 // COMMENTS
 // ================================================================================================
 
@@ -1729,6 +2323,42 @@ fn invalid_while() {
     }
 }
 
+// LIBRARY DESERIALIZATION
+// ================================================================================================
+
+#[test]
+fn masl_library_deserialization_errors() {
+    let namespace = LibraryNamespace::new("dummy").unwrap();
+    let module = |path: &str| {
+        let path = LibraryPath::new(path).unwrap();
+        Module::new(path, ModuleAst::parse("export.foo push.1 drop end").unwrap())
+    };
+    let build_library = |modules, dependencies| {
+        MaslLibrary::new(namespace.clone(), Version::default(), false, modules, dependencies)
+            .unwrap()
+            .to_bytes()
+    };
+
+    // a well-formed library is read back, and all of its truncations are rejected
+    let bytes = build_library(vec![module("dummy::a"), module("dummy::b")], vec![]);
+    assert!(MaslLibrary::read_from_bytes(&bytes).is_ok());
+    for len in 0..bytes.len() {
+        assert!(MaslLibrary::read_from_bytes(&bytes[..len]).is_err());
+    }
+
+    // modules defined more than once are rejected
+    let bytes = build_library(vec![module("dummy::a"), module("dummy::a")], vec![]);
+    assert!(MaslLibrary::read_from_bytes(&bytes).is_err());
+
+    // self-referential and duplicate dependencies are rejected
+    let bytes = build_library(vec![module("dummy::a")], vec![namespace.clone()]);
+    assert!(MaslLibrary::read_from_bytes(&bytes).is_err());
+
+    let std = LibraryNamespace::new("std").unwrap();
+    let bytes = build_library(vec![module("dummy::a")], vec![std.clone(), std]);
+    assert!(MaslLibrary::read_from_bytes(&bytes).is_err());
+}
+
 // DUMMY LIBRARY
 // ================================================================================================
 
@@ -42,6 +42,8 @@ impl<'a> Token<'a> {
     pub const BEGIN: &'static str = "begin";
     pub const CONST: &'static str = "const";
     pub const END: &'static str = "end";
+    pub const ERROR: &'static str = "error";
+    pub const ERRORS: &'static str = "errors";
     pub const EXPORT: &'static str = "export";
     pub const PROC: &'static str = "proc";
     pub const USE: &'static str = "use";
@@ -56,11 +58,18 @@ impl<'a> Token<'a> {
     pub const SYSCALL: &'static str = "syscall";
     pub const WHILE: &'static str = "while";
 
+    // PROCEDURE ATTRIBUTE TOKENS
+    // --------------------------------------------------------------------------------------------
+    pub const DEPRECATED: &'static str = "@deprecated";
+    pub const INLINE: &'static str = "@inline";
+    pub const NO_INLINE: &'static str = "@no_inline";
+
     // DELIMITERS
     // --------------------------------------------------------------------------------------------
     pub const DOC_COMMENT_PREFIX: &'static str = "#!";
     pub const COMMENT_PREFIX: char = '#';
     pub const ALIAS_DELIM: &'static str = "->";
+    pub const ATTRIBUTE_PREFIX: char = '@';
 
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
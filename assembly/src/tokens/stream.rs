@@ -30,8 +30,12 @@ impl<'a> TokenStream<'a> {
         for line_info in LinesStream::from(source) {
             match line_info.contents() {
                 Some(line) => {
-                    // fill the doc comments for procedures
-                    if line.starts_with(Token::EXPORT) || line.starts_with(Token::PROC) {
+                    // fill the doc comments for procedures; the comments precede the procedure
+                    // attributes, if any
+                    if line.starts_with(Token::EXPORT)
+                        || line.starts_with(Token::PROC)
+                        || line.starts_with(Token::ATTRIBUTE_PREFIX)
+                    {
                         let doc_comment = build_comment(line_info.docs());
                         proc_comments.insert(tokens.len(), doc_comment);
                     } else if !line_info.docs().is_empty() {
@@ -0,0 +1,14 @@
+use std::{env, path::PathBuf};
+
+/// Regenerates the C header describing the exported functions of this crate.
+fn main() {
+    let crate_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))
+        .expect("failed to read cbindgen config");
+    cbindgen::generate_with_config(&crate_dir, config)
+        .expect("failed to generate C bindings")
+        .write_to_file(crate_dir.join("include").join("miden.h"));
+}
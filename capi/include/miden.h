@@ -0,0 +1,201 @@
+#ifndef MIDEN_H
+#define MIDEN_H
+
+/* This file is generated by cbindgen; do not edit it manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Outcome of a call into the Miden VM bindings.
+ */
+typedef enum MidenStatus {
+  /**
+   * The call completed successfully.
+   */
+  MIDEN_STATUS_OK = 0,
+  /**
+   * A required pointer argument was null.
+   */
+  MIDEN_STATUS_NULL_POINTER = 1,
+  /**
+   * A string argument was not valid UTF-8.
+   */
+  MIDEN_STATUS_INVALID_UTF8 = 2,
+  /**
+   * The program could not be compiled.
+   */
+  MIDEN_STATUS_COMPILATION_FAILED = 3,
+  /**
+   * Inputs or options were invalid (e.g., stack inputs are not valid field elements).
+   */
+  MIDEN_STATUS_INVALID_INPUTS = 4,
+  /**
+   * The program failed to execute.
+   */
+  MIDEN_STATUS_EXECUTION_FAILED = 5,
+  /**
+   * The proof failed verification.
+   */
+  MIDEN_STATUS_VERIFICATION_FAILED = 6,
+  /**
+   * A serialized buffer (advice inputs, stack outputs, or a proof) could not be deserialized.
+   */
+  MIDEN_STATUS_DESERIALIZATION_FAILED = 7,
+  /**
+   * The call panicked; this indicates a bug in the VM.
+   */
+  MIDEN_STATUS_PANIC = 8,
+} MidenStatus;
+
+/**
+ * Opaque handle to a compiled Miden program.
+ */
+typedef struct MidenProgram MidenProgram;
+
+/**
+ * A byte buffer allocated by the bindings.
+ *
+ * Buffers returned by the bindings must be released via [miden_buffer_free()].
+ */
+typedef struct MidenBuffer {
+  uint8_t *data;
+  size_t len;
+} MidenBuffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Returns a description of the last error which occurred on the calling thread.
+ *
+ * The returned string is owned by the bindings and remains valid until the next call into the
+ * bindings on the same thread. An empty string is returned if no error occurred.
+ */
+const char *miden_last_error_message(void);
+
+/**
+ * Compiles the specified Miden assembly source into a program, and writes a handle to the
+ * program into `program_out`. The program is compiled against the Miden standard library.
+ *
+ * The handle must be released via [miden_program_free()].
+ *
+ * # Safety
+ * `source` must point to a null-terminated string, and `program_out` must point to memory valid
+ * for writes of a pointer.
+ */
+enum MidenStatus miden_program_compile(const char *source, struct MidenProgram **program_out);
+
+/**
+ * Releases the program handle returned by [miden_program_compile()]. Null handles are ignored.
+ *
+ * # Safety
+ * `program` must be null or a handle returned by [miden_program_compile()] which has not been
+ * released yet.
+ */
+void miden_program_free(struct MidenProgram *program);
+
+/**
+ * Writes the 32-byte hash of the program into `hash_out`.
+ *
+ * # Safety
+ * `program` must be a valid program handle, and `hash_out` must point to memory valid for
+ * writes of 32 bytes.
+ */
+enum MidenStatus miden_program_hash(const struct MidenProgram *program, uint8_t *hash_out);
+
+/**
+ * Executes the program and writes the serialized stack outputs into `outputs_out`.
+ *
+ * Stack inputs are ordered as if they would be pushed onto the stack one by one. Advice inputs
+ * are optional; if provided, they must be serialized `AdviceInputs`. Values of the stack outputs
+ * can be read via [miden_stack_outputs_read()].
+ *
+ * # Safety
+ * `program` must be a valid program handle, `stack_inputs` must point to `num_stack_inputs`
+ * integers (or be null if there are none), `advice_inputs` must point to `advice_inputs_len`
+ * bytes (or be null if there are none), and `outputs_out` must be valid for writes.
+ */
+enum MidenStatus miden_execute(const struct MidenProgram *program,
+                               const uint64_t *stack_inputs,
+                               size_t num_stack_inputs,
+                               const uint8_t *advice_inputs,
+                               size_t advice_inputs_len,
+                               struct MidenBuffer *outputs_out);
+
+/**
+ * Executes and proves the program, and writes the serialized stack outputs and the serialized
+ * proof into `outputs_out` and `proof_out` respectively.
+ *
+ * `security` specifies the targeted security level in bits and must be either 96 or 128. If
+ * `recursive` is set, the proof is generated using an arithmetization-friendly hash function.
+ *
+ * # Safety
+ * The same requirements as for [miden_execute()] apply, and `proof_out` must be valid for
+ * writes.
+ */
+enum MidenStatus miden_prove(const struct MidenProgram *program,
+                             const uint64_t *stack_inputs,
+                             size_t num_stack_inputs,
+                             const uint8_t *advice_inputs,
+                             size_t advice_inputs_len,
+                             uint32_t security,
+                             bool recursive,
+                             struct MidenBuffer *outputs_out,
+                             struct MidenBuffer *proof_out);
+
+/**
+ * Verifies that the program with the specified 32-byte hash, executed against the specified
+ * stack inputs, produced the specified serialized stack outputs, and writes the security level
+ * of the proof into `security_level_out`.
+ *
+ * The program is expected to be executed against an empty kernel.
+ *
+ * # Safety
+ * `program_hash` must point to 32 bytes, `stack_inputs` must point to `num_stack_inputs`
+ * integers (or be null if there are none), `stack_outputs` and `proof` must point to buffers of
+ * the specified lengths, and `security_level_out` must be valid for writes.
+ */
+enum MidenStatus miden_verify(const uint8_t *program_hash,
+                              const uint64_t *stack_inputs,
+                              size_t num_stack_inputs,
+                              const uint8_t *stack_outputs,
+                              size_t stack_outputs_len,
+                              const uint8_t *proof,
+                              size_t proof_len,
+                              uint32_t *security_level_out);
+
+/**
+ * Reads values of the serialized stack outputs, with the value at the top of the stack first.
+ *
+ * Up to `capacity` values are written into `values_out`, and the total number of values is
+ * written into `num_values_out`.
+ *
+ * # Safety
+ * `stack_outputs` must point to `stack_outputs_len` bytes, `values_out` must be valid for writes
+ * of `capacity` integers (or be null if `capacity` is zero), and `num_values_out` must be valid
+ * for writes.
+ */
+enum MidenStatus miden_stack_outputs_read(const uint8_t *stack_outputs,
+                                          size_t stack_outputs_len,
+                                          uint64_t *values_out,
+                                          size_t capacity,
+                                          size_t *num_values_out);
+
+/**
+ * Releases a buffer returned by the bindings.
+ *
+ * # Safety
+ * `buffer` must be a buffer returned by the bindings which has not been released yet.
+ */
+void miden_buffer_free(struct MidenBuffer buffer);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* MIDEN_H */
@@ -0,0 +1,409 @@
+//! C bindings for Miden VM.
+//!
+//! The bindings expose program compilation, execution, proving, and verification via a stable C
+//! ABI. Compiled programs are passed around as opaque [MidenProgram] handles, while inputs and
+//! outputs are passed as plain integers or serialized byte buffers. All functions report their
+//! outcome via [MidenStatus]; a human-readable description of the last error is available via
+//! [miden_last_error_message()].
+//!
+//! The C header describing these bindings is generated by `cbindgen` into `include/miden.h`.
+
+use core::{
+    ffi::{c_char, CStr},
+    ptr, slice,
+};
+use miden_vm::{
+    utils::{Deserializable, Serializable},
+    AdviceInputs, Assembler, DefaultHost, Digest, ExecutionOptions, ExecutionProof, Kernel,
+    MemAdviceProvider, Program, ProgramInfo, ProvingOptions, StackInputs, StackOutputs,
+};
+use std::{
+    cell::RefCell,
+    ffi::CString,
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+use stdlib::StdLibrary;
+
+#[cfg(test)]
+mod tests;
+
+// TYPES
+// ================================================================================================
+
+/// Outcome of a call into the Miden VM bindings.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MidenStatus {
+    /// The call completed successfully.
+    Ok = 0,
+    /// A required pointer argument was null.
+    NullPointer = 1,
+    /// A string argument was not valid UTF-8.
+    InvalidUtf8 = 2,
+    /// The program could not be compiled.
+    CompilationFailed = 3,
+    /// Inputs or options were invalid (e.g., stack inputs are not valid field elements).
+    InvalidInputs = 4,
+    /// The program failed to execute.
+    ExecutionFailed = 5,
+    /// The proof failed verification.
+    VerificationFailed = 6,
+    /// A serialized buffer (advice inputs, stack outputs, or a proof) could not be deserialized.
+    DeserializationFailed = 7,
+    /// The call panicked; this indicates a bug in the VM.
+    Panic = 8,
+}
+
+/// Opaque handle to a compiled Miden program.
+pub struct MidenProgram(Program);
+
+/// A byte buffer allocated by the bindings.
+///
+/// Buffers returned by the bindings must be released via [miden_buffer_free()].
+#[repr(C)]
+#[derive(Debug)]
+pub struct MidenBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl MidenBuffer {
+    fn new(bytes: Vec<u8>) -> Self {
+        let len = bytes.len();
+        let data = Box::into_raw(bytes.into_boxed_slice()) as *mut u8;
+        Self { data, len }
+    }
+}
+
+/// Error raised by one of the bindings, which is reported to the caller via a status code and
+/// the last error message.
+struct FfiError {
+    status: MidenStatus,
+    message: String,
+}
+
+impl FfiError {
+    fn new(status: MidenStatus, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+// ERROR REPORTING
+// ================================================================================================
+
+/// Returns a description of the last error which occurred on the calling thread.
+///
+/// The returned string is owned by the bindings and remains valid until the next call into the
+/// bindings on the same thread. An empty string is returned if no error occurred.
+#[no_mangle]
+pub extern "C" fn miden_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|error| error.borrow().as_ptr())
+}
+
+// PROGRAMS
+// ================================================================================================
+
+/// Compiles the specified Miden assembly source into a program, and writes a handle to the
+/// program into `program_out`. The program is compiled against the Miden standard library.
+///
+/// The handle must be released via [miden_program_free()].
+///
+/// # Safety
+/// `source` must point to a null-terminated string, and `program_out` must point to memory valid
+/// for writes of a pointer.
+#[no_mangle]
+pub unsafe extern "C" fn miden_program_compile(
+    source: *const c_char,
+    program_out: *mut *mut MidenProgram,
+) -> MidenStatus {
+    ffi_call(|| {
+        if source.is_null() || program_out.is_null() {
+            return Err(null_pointer());
+        }
+        let source = CStr::from_ptr(source)
+            .to_str()
+            .map_err(|err| FfiError::new(MidenStatus::InvalidUtf8, err.to_string()))?;
+
+        let program = Assembler::default()
+            .with_library(&StdLibrary::default())
+            .and_then(|assembler| assembler.compile(source))
+            .map_err(|err| FfiError::new(MidenStatus::CompilationFailed, err.to_string()))?;
+
+        *program_out = Box::into_raw(Box::new(MidenProgram(program)));
+        Ok(())
+    })
+}
+
+/// Releases the program handle returned by [miden_program_compile()]. Null handles are ignored.
+///
+/// # Safety
+/// `program` must be null or a handle returned by [miden_program_compile()] which has not been
+/// released yet.
+#[no_mangle]
+pub unsafe extern "C" fn miden_program_free(program: *mut MidenProgram) {
+    if !program.is_null() {
+        drop(Box::from_raw(program));
+    }
+}
+
+/// Writes the 32-byte hash of the program into `hash_out`.
+///
+/// # Safety
+/// `program` must be a valid program handle, and `hash_out` must point to memory valid for
+/// writes of 32 bytes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_program_hash(
+    program: *const MidenProgram,
+    hash_out: *mut u8,
+) -> MidenStatus {
+    ffi_call(|| {
+        if program.is_null() || hash_out.is_null() {
+            return Err(null_pointer());
+        }
+        let hash = (*program).0.hash().as_bytes();
+        ptr::copy_nonoverlapping(hash.as_ptr(), hash_out, hash.len());
+        Ok(())
+    })
+}
+
+// EXECUTION
+// ================================================================================================
+
+/// Executes the program and writes the serialized stack outputs into `outputs_out`.
+///
+/// Stack inputs are ordered as if they would be pushed onto the stack one by one. Advice inputs
+/// are optional; if provided, they must be serialized `AdviceInputs`. Values of the stack outputs
+/// can be read via [miden_stack_outputs_read()].
+///
+/// # Safety
+/// `program` must be a valid program handle, `stack_inputs` must point to `num_stack_inputs`
+/// integers (or be null if there are none), `advice_inputs` must point to `advice_inputs_len`
+/// bytes (or be null if there are none), and `outputs_out` must be valid for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_execute(
+    program: *const MidenProgram,
+    stack_inputs: *const u64,
+    num_stack_inputs: usize,
+    advice_inputs: *const u8,
+    advice_inputs_len: usize,
+    outputs_out: *mut MidenBuffer,
+) -> MidenStatus {
+    ffi_call(|| {
+        if program.is_null() || outputs_out.is_null() {
+            return Err(null_pointer());
+        }
+        let stack_inputs = read_stack_inputs(stack_inputs, num_stack_inputs)?;
+        let host = read_host(advice_inputs, advice_inputs_len)?;
+
+        let trace =
+            miden_vm::execute(&(*program).0, stack_inputs, host, ExecutionOptions::default())
+                .map_err(|err| FfiError::new(MidenStatus::ExecutionFailed, format!("{err:?}")))?;
+
+        *outputs_out = MidenBuffer::new(trace.stack_outputs().to_bytes());
+        Ok(())
+    })
+}
+
+/// Executes and proves the program, and writes the serialized stack outputs and the serialized
+/// proof into `outputs_out` and `proof_out` respectively.
+///
+/// `security` specifies the targeted security level in bits and must be either 96 or 128. If
+/// `recursive` is set, the proof is generated using an arithmetization-friendly hash function.
+///
+/// # Safety
+/// The same requirements as for [miden_execute()] apply, and `proof_out` must be valid for
+/// writes.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn miden_prove(
+    program: *const MidenProgram,
+    stack_inputs: *const u64,
+    num_stack_inputs: usize,
+    advice_inputs: *const u8,
+    advice_inputs_len: usize,
+    security: u32,
+    recursive: bool,
+    outputs_out: *mut MidenBuffer,
+    proof_out: *mut MidenBuffer,
+) -> MidenStatus {
+    ffi_call(|| {
+        if program.is_null() || outputs_out.is_null() || proof_out.is_null() {
+            return Err(null_pointer());
+        }
+        let stack_inputs = read_stack_inputs(stack_inputs, num_stack_inputs)?;
+        let host = read_host(advice_inputs, advice_inputs_len)?;
+        let options = match security {
+            96 => ProvingOptions::with_96_bit_security(recursive),
+            128 => ProvingOptions::with_128_bit_security(recursive),
+            _ => {
+                return Err(FfiError::new(
+                    MidenStatus::InvalidInputs,
+                    format!("{security} is not a valid security level"),
+                ))
+            }
+        };
+
+        let (stack_outputs, proof) = miden_vm::prove(&(*program).0, stack_inputs, host, options)
+            .map_err(|err| FfiError::new(MidenStatus::ExecutionFailed, format!("{err:?}")))?;
+
+        *outputs_out = MidenBuffer::new(stack_outputs.to_bytes());
+        *proof_out = MidenBuffer::new(proof.to_bytes());
+        Ok(())
+    })
+}
+
+// VERIFICATION
+// ================================================================================================
+
+/// Verifies that the program with the specified 32-byte hash, executed against the specified
+/// stack inputs, produced the specified serialized stack outputs, and writes the security level
+/// of the proof into `security_level_out`.
+///
+/// The program is expected to be executed against an empty kernel.
+///
+/// # Safety
+/// `program_hash` must point to 32 bytes, `stack_inputs` must point to `num_stack_inputs`
+/// integers (or be null if there are none), `stack_outputs` and `proof` must point to buffers of
+/// the specified lengths, and `security_level_out` must be valid for writes.
+#[no_mangle]
+#[allow(clippy::too_many_arguments)]
+pub unsafe extern "C" fn miden_verify(
+    program_hash: *const u8,
+    stack_inputs: *const u64,
+    num_stack_inputs: usize,
+    stack_outputs: *const u8,
+    stack_outputs_len: usize,
+    proof: *const u8,
+    proof_len: usize,
+    security_level_out: *mut u32,
+) -> MidenStatus {
+    ffi_call(|| {
+        if program_hash.is_null() || security_level_out.is_null() {
+            return Err(null_pointer());
+        }
+        let program_hash = Digest::read_from_bytes(slice::from_raw_parts(program_hash, 32))
+            .map_err(deserialization_error)?;
+        let stack_inputs = read_stack_inputs(stack_inputs, num_stack_inputs)?;
+        let stack_outputs = read_stack_outputs(stack_outputs, stack_outputs_len)?;
+        let proof = ExecutionProof::from_bytes(read_slice(proof, proof_len)?)
+            .map_err(deserialization_error)?;
+
+        let program_info = ProgramInfo::new(program_hash, Kernel::default());
+        *security_level_out = miden_vm::verify(program_info, stack_inputs, stack_outputs, proof)
+            .map_err(|err| FfiError::new(MidenStatus::VerificationFailed, err.to_string()))?
+            .security_level();
+        Ok(())
+    })
+}
+
+// BUFFERS
+// ================================================================================================
+
+/// Reads values of the serialized stack outputs, with the value at the top of the stack first.
+///
+/// Up to `capacity` values are written into `values_out`, and the total number of values is
+/// written into `num_values_out`.
+///
+/// # Safety
+/// `stack_outputs` must point to `stack_outputs_len` bytes, `values_out` must be valid for writes
+/// of `capacity` integers (or be null if `capacity` is zero), and `num_values_out` must be valid
+/// for writes.
+#[no_mangle]
+pub unsafe extern "C" fn miden_stack_outputs_read(
+    stack_outputs: *const u8,
+    stack_outputs_len: usize,
+    values_out: *mut u64,
+    capacity: usize,
+    num_values_out: *mut usize,
+) -> MidenStatus {
+    ffi_call(|| {
+        if num_values_out.is_null() || (values_out.is_null() && capacity != 0) {
+            return Err(null_pointer());
+        }
+        let stack_outputs = read_stack_outputs(stack_outputs, stack_outputs_len)?;
+        let values = stack_outputs.stack();
+        for (i, value) in values.iter().take(capacity).enumerate() {
+            *values_out.add(i) = value.as_int();
+        }
+        *num_values_out = values.len();
+        Ok(())
+    })
+}
+
+/// Releases a buffer returned by the bindings.
+///
+/// # Safety
+/// `buffer` must be a buffer returned by the bindings which has not been released yet.
+#[no_mangle]
+pub unsafe extern "C" fn miden_buffer_free(buffer: MidenBuffer) {
+    if !buffer.data.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(buffer.data, buffer.len)));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Runs the specified call, recording its error (if any) as the last error of the calling thread
+/// and converting panics into [MidenStatus::Panic].
+fn ffi_call<F>(call: F) -> MidenStatus
+where
+    F: FnOnce() -> Result<(), FfiError>,
+{
+    let error = match catch_unwind(AssertUnwindSafe(call)) {
+        Ok(Ok(())) => return MidenStatus::Ok,
+        Ok(Err(err)) => err,
+        Err(_) => FfiError::new(MidenStatus::Panic, "the call panicked"),
+    };
+
+    // interior null bytes cannot be represented in a C string, so they are dropped
+    let message = CString::new(error.message.replace('\0', "")).unwrap_or_default();
+    LAST_ERROR.with(|last_error| *last_error.borrow_mut() = message);
+    error.status
+}
+
+fn null_pointer() -> FfiError {
+    FfiError::new(MidenStatus::NullPointer, "a required pointer argument is null")
+}
+
+fn deserialization_error(err: impl ToString) -> FfiError {
+    FfiError::new(MidenStatus::DeserializationFailed, err.to_string())
+}
+
+/// Returns a slice of `len` elements starting at `data`; `data` may be null only if `len` is zero.
+unsafe fn read_slice<'a, T>(data: *const T, len: usize) -> Result<&'a [T], FfiError> {
+    match (data.is_null(), len) {
+        (_, 0) => Ok(&[]),
+        (true, _) => Err(null_pointer()),
+        (false, _) => Ok(slice::from_raw_parts(data, len)),
+    }
+}
+
+unsafe fn read_stack_inputs(values: *const u64, len: usize) -> Result<StackInputs, FfiError> {
+    StackInputs::try_from_ints(read_slice(values, len)?.iter().copied())
+        .map_err(|err| FfiError::new(MidenStatus::InvalidInputs, err.to_string()))
+}
+
+unsafe fn read_stack_outputs(bytes: *const u8, len: usize) -> Result<StackOutputs, FfiError> {
+    StackOutputs::read_from_bytes(read_slice(bytes, len)?).map_err(deserialization_error)
+}
+
+/// Returns a host supplying the specified serialized advice inputs to the VM; empty advice inputs
+/// are used if no bytes are provided.
+unsafe fn read_host(
+    bytes: *const u8,
+    len: usize,
+) -> Result<DefaultHost<MemAdviceProvider>, FfiError> {
+    let advice_inputs = match read_slice(bytes, len)? {
+        [] => AdviceInputs::default(),
+        bytes => AdviceInputs::read_from_bytes(bytes).map_err(deserialization_error)?,
+    };
+    Ok(DefaultHost::new(MemAdviceProvider::from(advice_inputs)))
+}
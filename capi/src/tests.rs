@@ -0,0 +1,130 @@
+use super::*;
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn compile_execute_prove_verify() {
+    let source = CString::new("begin push.3 add end").unwrap();
+    let mut program = ptr::null_mut();
+    assert_eq!(unsafe { miden_program_compile(source.as_ptr(), &mut program) }, MidenStatus::Ok);
+
+    let mut hash = [0u8; 32];
+    assert_eq!(unsafe { miden_program_hash(program, hash.as_mut_ptr()) }, MidenStatus::Ok);
+    assert_eq!(hash, unsafe { (*program).0.hash().as_bytes() });
+
+    // execute the program and read its outputs
+    let stack_inputs = [5u64];
+    let mut outputs = MidenBuffer::new(Vec::new());
+    let status =
+        unsafe { miden_execute(program, stack_inputs.as_ptr(), 1, ptr::null(), 0, &mut outputs) };
+    assert_eq!(status, MidenStatus::Ok);
+    assert_eq!(stack_top(&outputs), 8);
+    unsafe { miden_buffer_free(outputs) };
+
+    // prove the program and verify the proof
+    let mut outputs = MidenBuffer::new(Vec::new());
+    let mut proof = MidenBuffer::new(Vec::new());
+    let status = unsafe {
+        miden_prove(
+            program,
+            stack_inputs.as_ptr(),
+            1,
+            ptr::null(),
+            0,
+            96,
+            false,
+            &mut outputs,
+            &mut proof,
+        )
+    };
+    assert_eq!(status, MidenStatus::Ok);
+
+    let mut security_level = 0;
+    let status = unsafe {
+        miden_verify(
+            hash.as_ptr(),
+            stack_inputs.as_ptr(),
+            1,
+            outputs.data,
+            outputs.len,
+            proof.data,
+            proof.len,
+            &mut security_level,
+        )
+    };
+    assert_eq!(status, MidenStatus::Ok);
+    assert!(security_level >= 90);
+
+    // the proof must not verify against other inputs
+    let other_inputs = [6u64];
+    let status = unsafe {
+        miden_verify(
+            hash.as_ptr(),
+            other_inputs.as_ptr(),
+            1,
+            outputs.data,
+            outputs.len,
+            proof.data,
+            proof.len,
+            &mut security_level,
+        )
+    };
+    assert_eq!(status, MidenStatus::VerificationFailed);
+
+    unsafe {
+        miden_buffer_free(outputs);
+        miden_buffer_free(proof);
+        miden_program_free(program);
+    }
+}
+
+#[test]
+fn errors_are_reported() {
+    let source = CString::new("begin push.3 foo end").unwrap();
+    let mut program = ptr::null_mut();
+    let status = unsafe { miden_program_compile(source.as_ptr(), &mut program) };
+    assert_eq!(status, MidenStatus::CompilationFailed);
+    assert!(program.is_null());
+    assert!(!last_error().is_empty());
+
+    let status = unsafe { miden_program_compile(ptr::null(), &mut program) };
+    assert_eq!(status, MidenStatus::NullPointer);
+
+    // stack inputs must be valid field elements
+    let source = CString::new("begin push.3 add end").unwrap();
+    assert_eq!(unsafe { miden_program_compile(source.as_ptr(), &mut program) }, MidenStatus::Ok);
+    let stack_inputs = [u64::MAX];
+    let mut outputs = MidenBuffer::new(Vec::new());
+    let status =
+        unsafe { miden_execute(program, stack_inputs.as_ptr(), 1, ptr::null(), 0, &mut outputs) };
+    assert_eq!(status, MidenStatus::InvalidInputs);
+    unsafe { miden_program_free(program) };
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the value at the top of the stack from the serialized stack outputs.
+fn stack_top(outputs: &MidenBuffer) -> u64 {
+    let mut values = [0u64; 16];
+    let mut num_values = 0;
+    let status = unsafe {
+        miden_stack_outputs_read(
+            outputs.data,
+            outputs.len,
+            values.as_mut_ptr(),
+            16,
+            &mut num_values,
+        )
+    };
+    assert_eq!(status, MidenStatus::Ok);
+    assert_eq!(num_values, 16);
+    values[0]
+}
+
+fn last_error() -> String {
+    unsafe { CStr::from_ptr(miden_last_error_message()) }
+        .to_string_lossy()
+        .into_owned()
+}
@@ -0,0 +1,38 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use miden_core::{batch, Felt};
+use rand_utils::rand_vector;
+use std::time::Duration;
+
+const SIZES: [usize; 3] = [1 << 10, 1 << 16, 1 << 20];
+
+fn batch_ops(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("batch_ops ({})", batch::Backend::detect()));
+    group.measurement_time(Duration::from_secs(10));
+
+    for size in SIZES {
+        let lhs: Vec<Felt> = rand_vector(size);
+        let rhs: Vec<Felt> = rand_vector(size);
+
+        group.bench_with_input(BenchmarkId::new("add_scalar", size), &size, |bench, _| {
+            let mut values = lhs.clone();
+            bench.iter(|| values.iter_mut().zip(&rhs).for_each(|(l, &r)| *l += r));
+        });
+        group.bench_with_input(BenchmarkId::new("add_batch", size), &size, |bench, _| {
+            let mut values = lhs.clone();
+            bench.iter(|| batch::add_assign(&mut values, &rhs));
+        });
+        group.bench_with_input(BenchmarkId::new("mul_scalar", size), &size, |bench, _| {
+            let mut values = lhs.clone();
+            bench.iter(|| values.iter_mut().zip(&rhs).for_each(|(l, &r)| *l *= r));
+        });
+        group.bench_with_input(BenchmarkId::new("mul_batch", size), &size, |bench, _| {
+            let mut values = lhs.clone();
+            bench.iter(|| batch::mul_assign(&mut values, &rhs));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(batch_ops_group, batch_ops);
+criterion_main!(batch_ops_group);
@@ -0,0 +1,171 @@
+//! AVX2 implementations of the batch routines, processing 4 elements at a time.
+
+use super::{scalar_add_assign, scalar_mul_assign, Felt, EPSILON, MODULUS};
+use core::arch::x86_64::{
+    __m256i, _mm256_add_epi64, _mm256_and_si256, _mm256_cmpgt_epi64, _mm256_loadu_si256,
+    _mm256_mul_epu32, _mm256_or_si256, _mm256_set1_epi64x, _mm256_slli_epi64, _mm256_srli_epi64,
+    _mm256_storeu_si256, _mm256_sub_epi64, _mm256_xor_si256,
+};
+
+/// Number of elements processed by a single instruction.
+const LANES: usize = 4;
+
+/// Returns true if AVX2 instructions are supported by the current machine.
+pub fn is_available() -> bool {
+    #[cfg(feature = "std")]
+    {
+        std::is_x86_feature_detected!("avx2")
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        cfg!(target_feature = "avx2")
+    }
+}
+
+// BATCH OPERATIONS
+// ================================================================================================
+
+/// # Safety
+/// The caller must ensure that AVX2 instructions are supported by the current machine.
+#[target_feature(enable = "avx2")]
+pub unsafe fn add_assign(lhs: &mut [Felt], rhs: &[Felt]) {
+    let split = lhs.len() - lhs.len() % LANES;
+    for (l, r) in lhs[..split].chunks_exact_mut(LANES).zip(rhs[..split].chunks_exact(LANES)) {
+        let sum = add(canonicalize(load(l)), canonicalize(load(r)));
+        store(l, sum);
+    }
+    scalar_add_assign(&mut lhs[split..], &rhs[split..]);
+}
+
+/// # Safety
+/// The caller must ensure that AVX2 instructions are supported by the current machine.
+#[target_feature(enable = "avx2")]
+pub unsafe fn mul_assign(lhs: &mut [Felt], rhs: &[Felt]) {
+    let split = lhs.len() - lhs.len() % LANES;
+    for (l, r) in lhs[..split].chunks_exact_mut(LANES).zip(rhs[..split].chunks_exact(LANES)) {
+        let product = mul(load(l), load(r));
+        store(l, product);
+    }
+    scalar_mul_assign(&mut lhs[split..], &rhs[split..]);
+}
+
+// ARITHMETIC
+// ================================================================================================
+
+/// Adds two vectors of canonical values modulo p, returning canonical values.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn add(a: __m256i, b: __m256i) -> __m256i {
+    // a + b < 2p, and thus, a single subtraction of p reduces the sum; if the addition overflows,
+    // subtracting p modulo 2^64 yields the correct result as well
+    let sum = _mm256_add_epi64(a, b);
+    let overflow = less_than(sum, a);
+    let reduce = _mm256_or_si256(overflow, greater_than(sum, splat(MODULUS - 1)));
+    _mm256_sub_epi64(sum, _mm256_and_si256(reduce, splat(MODULUS)))
+}
+
+/// Multiplies two vectors of Montgomery values modulo p, returning canonical Montgomery values.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn mul(a: __m256i, b: __m256i) -> __m256i {
+    let (hi, lo) = mul_wide(a, b);
+    canonicalize(montgomery_reduce(hi, lo))
+}
+
+/// Computes the full 128-bit products of the values in two vectors, returning the high and the
+/// low halves of the products.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn mul_wide(a: __m256i, b: __m256i) -> (__m256i, __m256i) {
+    let a_hi = _mm256_srli_epi64(a, 32);
+    let b_hi = _mm256_srli_epi64(b, 32);
+
+    // compute the 4 partial products of the 32-bit halves
+    let ll = _mm256_mul_epu32(a, b);
+    let lh = _mm256_mul_epu32(a, b_hi);
+    let hl = _mm256_mul_epu32(a_hi, b);
+    let hh = _mm256_mul_epu32(a_hi, b_hi);
+
+    // the middle partial products are aligned at bit 32; their carry is aligned at bit 96
+    let mid = _mm256_add_epi64(lh, hl);
+    let mid_carry = _mm256_and_si256(less_than(mid, lh), splat(1 << 32));
+
+    let lo = _mm256_add_epi64(ll, _mm256_slli_epi64(mid, 32));
+    let lo_carry = less_than(lo, ll);
+
+    // lo_carry is -1 in the lanes where the low half overflowed
+    let hi = _mm256_add_epi64(hh, _mm256_srli_epi64(mid, 32));
+    let hi = _mm256_sub_epi64(_mm256_add_epi64(hi, mid_carry), lo_carry);
+    (hi, lo)
+}
+
+/// Computes (hi * 2^64 + lo) / 2^64 mod p, returning values which are either canonical, or
+/// exceed p by less than p.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn montgomery_reduce(hi: __m256i, lo: __m256i) -> __m256i {
+    // m = lo * p^-1 mod 2^64, where p^-1 = 2^32 + 1 mod 2^64
+    let m = _mm256_add_epi64(lo, _mm256_slli_epi64(lo, 32));
+    let m_overflow = less_than(m, lo);
+
+    // t = m * p / 2^64, rounded down, with the overflow of the computation of m folded in
+    let t = _mm256_sub_epi64(m, _mm256_srli_epi64(m, 32));
+    let t = _mm256_add_epi64(t, m_overflow);
+
+    // result = hi - t mod p
+    let result = _mm256_sub_epi64(hi, t);
+    let borrow = less_than(hi, t);
+    _mm256_sub_epi64(result, _mm256_and_si256(borrow, splat(EPSILON)))
+}
+
+/// Reduces the values which are greater than or equal to p by p.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn canonicalize(x: __m256i) -> __m256i {
+    let reduce = greater_than(x, splat(MODULUS - 1));
+    _mm256_sub_epi64(x, _mm256_and_si256(reduce, splat(MODULUS)))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a mask with all bits set in the lanes where `a` is greater than `b` as unsigned values.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn greater_than(a: __m256i, b: __m256i) -> __m256i {
+    // AVX2 supports only signed comparisons; flipping the sign bits maps unsigned order to signed
+    let sign = splat(1 << 63);
+    _mm256_cmpgt_epi64(_mm256_xor_si256(a, sign), _mm256_xor_si256(b, sign))
+}
+
+/// Returns a mask with all bits set in the lanes where `a` is less than `b` as unsigned values.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn less_than(a: __m256i, b: __m256i) -> __m256i {
+    greater_than(b, a)
+}
+
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn splat(value: u64) -> __m256i {
+    _mm256_set1_epi64x(value as i64)
+}
+
+/// Loads the Montgomery representations of the first 4 specified elements into a vector.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn load(values: &[Felt]) -> __m256i {
+    debug_assert!(values.len() >= LANES);
+    // elements are stored as their Montgomery representations (the layout is asserted in the parent module)
+    _mm256_loadu_si256(values.as_ptr() as *const __m256i)
+}
+
+/// Stores the Montgomery representations in the vector into the first 4 specified elements.
+///
+/// The values in the vector must be canonical.
+#[inline]
+#[target_feature(enable = "avx2")]
+unsafe fn store(target: &mut [Felt], vector: __m256i) {
+    debug_assert!(target.len() >= LANES);
+    _mm256_storeu_si256(target.as_mut_ptr() as *mut __m256i, vector);
+}
@@ -0,0 +1,255 @@
+//! Element-wise arithmetic over slices of field elements.
+//!
+//! Batch inversion ([inv()] and [inv_in_place()]) is generic over the field, and can be applied to
+//! slices of extension field elements as well. The remaining routines dispatch to vectorized
+//! implementations when the target supports them: AVX2 on x86-64 (detected at runtime when the
+//! `std` feature is enabled, and at compile time otherwise), and NEON on AArch64. On other targets,
+//! the routines fall back to scalar arithmetic.
+//!
+//! Vectorized implementations operate directly on the Montgomery representation of the elements,
+//! and always return elements in canonical form.
+
+use super::{Felt, FieldElement};
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(target_arch = "x86_64")]
+mod avx2;
+
+#[cfg(target_arch = "aarch64")]
+mod neon;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The field modulus, 2^64 - 2^32 + 1.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+const MODULUS: u64 = 0xffff_ffff_0000_0001;
+
+/// 2^64 mod p; adding it to a value is equivalent to subtracting p modulo 2^64.
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+const EPSILON: u64 = 0xffff_ffff;
+
+// vectorized implementations load and store slices of elements directly as slices of their u64
+// Montgomery representations; a field element wraps a single u64, and as long as it has the same
+// size, the u64 is located at offset 0
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+const _: () = assert!(
+    core::mem::size_of::<Felt>() == core::mem::size_of::<u64>()
+        && core::mem::align_of::<Felt>() == core::mem::align_of::<u64>()
+);
+
+// BACKEND
+// ================================================================================================
+
+/// Implementation used by the batch routines on the current machine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Backend {
+    /// Scalar arithmetic.
+    Scalar,
+    /// AVX2 instructions processing 4 elements at a time.
+    Avx2,
+    /// NEON instructions processing 2 elements at a time.
+    Neon,
+}
+
+impl Backend {
+    /// Returns the backend used on the current machine.
+    pub fn detect() -> Self {
+        #[cfg(target_arch = "x86_64")]
+        if avx2::is_available() {
+            return Self::Avx2;
+        }
+
+        if cfg!(target_arch = "aarch64") {
+            Self::Neon
+        } else {
+            Self::Scalar
+        }
+    }
+}
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Scalar => write!(f, "scalar"),
+            Self::Avx2 => write!(f, "avx2"),
+            Self::Neon => write!(f, "neon"),
+        }
+    }
+}
+
+// BATCH OPERATIONS
+// ================================================================================================
+
+/// Adds each element of `rhs` to the element of `lhs` at the same position.
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+pub fn add_assign(lhs: &mut [Felt], rhs: &[Felt]) {
+    assert_eq!(lhs.len(), rhs.len(), "slices must have the same length");
+    match Backend::detect() {
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 => {
+            // SAFETY: AVX2 support has been detected
+            unsafe { avx2::add_assign(lhs, rhs) }
+        }
+        #[cfg(target_arch = "aarch64")]
+        Backend::Neon => neon::add_assign(lhs, rhs),
+        _ => scalar_add_assign(lhs, rhs),
+    }
+}
+
+/// Multiplies each element of `lhs` by the element of `rhs` at the same position.
+///
+/// # Panics
+/// Panics if the slices have different lengths.
+pub fn mul_assign(lhs: &mut [Felt], rhs: &[Felt]) {
+    assert_eq!(lhs.len(), rhs.len(), "slices must have the same length");
+    match Backend::detect() {
+        #[cfg(target_arch = "x86_64")]
+        Backend::Avx2 => {
+            // SAFETY: AVX2 support has been detected
+            unsafe { avx2::mul_assign(lhs, rhs) }
+        }
+        #[cfg(target_arch = "aarch64")]
+        Backend::Neon => neon::mul_assign(lhs, rhs),
+        _ => scalar_mul_assign(lhs, rhs),
+    }
+}
+
+/// Returns the multiplicative inverses of the specified values; zeros are mapped to zeros.
+///
+/// The inverses are computed using Montgomery's trick, which replaces all but one of the
+/// inversions with 3 multiplications each.
+pub fn inv<E: FieldElement>(values: &[E]) -> Vec<E> {
+    let mut result = values.to_vec();
+    inv_in_place(&mut result);
+    result
+}
+
+/// Replaces each of the specified values with its multiplicative inverse; zeros remain zeros.
+///
+/// See [inv()] for details.
+pub fn inv_in_place<E: FieldElement>(values: &mut [E]) {
+    // compute the running products of the non-zero values; prefix[i] holds the product of the
+    // non-zero values preceding position i
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = E::ONE;
+    for &value in values.iter() {
+        prefix.push(acc);
+        if value != E::ZERO {
+            acc *= value;
+        }
+    }
+
+    // invert the product of all non-zero values, and walk back to peel off the inverse of each
+    // value from it
+    let mut acc_inv = acc.inv();
+    for (value, prefix) in values.iter_mut().zip(prefix).rev() {
+        if *value != E::ZERO {
+            let inverse = acc_inv * prefix;
+            acc_inv *= *value;
+            *value = inverse;
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn scalar_add_assign(lhs: &mut [Felt], rhs: &[Felt]) {
+    lhs.iter_mut().zip(rhs).for_each(|(l, &r)| *l += r);
+}
+
+fn scalar_mul_assign(lhs: &mut [Felt], rhs: &[Felt]) {
+    lhs.iter_mut().zip(rhs).for_each(|(l, &r)| *l *= r);
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        add_assign, inv, inv_in_place, mul_assign, scalar_add_assign, scalar_mul_assign, Backend,
+        Felt,
+    };
+    use crate::{FieldElement, QuadExtension, StarkField, ZERO};
+    use alloc::vec::Vec;
+    use rand_utils::rand_vector;
+
+    #[test]
+    fn batch_add_matches_scalar() {
+        for (lhs, rhs) in test_inputs() {
+            let mut expected = lhs.clone();
+            scalar_add_assign(&mut expected, &rhs);
+            let mut actual = lhs;
+            add_assign(&mut actual, &rhs);
+            assert_eq!(as_ints(&expected), as_ints(&actual), "backend: {}", Backend::detect());
+        }
+    }
+
+    #[test]
+    fn batch_mul_matches_scalar() {
+        for (lhs, rhs) in test_inputs() {
+            let mut expected = lhs.clone();
+            scalar_mul_assign(&mut expected, &rhs);
+            let mut actual = lhs;
+            mul_assign(&mut actual, &rhs);
+            assert_eq!(as_ints(&expected), as_ints(&actual), "backend: {}", Backend::detect());
+        }
+    }
+
+    #[test]
+    fn batch_inv() {
+        let mut values: Vec<Felt> = rand_vector(100);
+        values[0] = ZERO;
+        values[42] = ZERO;
+        values[99] = ZERO;
+
+        let expected = values.iter().map(|value| value.inv()).collect::<Vec<_>>();
+        assert_eq!(expected, inv(&values));
+
+        inv_in_place(&mut values);
+        assert_eq!(expected, values);
+        assert_eq!(ZERO, values[42]);
+
+        // extension field elements are supported as well
+        let values: Vec<QuadExtension<Felt>> = rand_vector(17);
+        let expected = values.iter().map(|value| value.inv()).collect::<Vec<_>>();
+        assert_eq!(expected, inv(&values));
+
+        assert!(inv::<Felt>(&[]).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn batch_add_length_mismatch() {
+        add_assign(&mut [Felt::new(1)], &[]);
+    }
+
+    /// Returns pairs of slices of various lengths (to exercise the remainder handling), including
+    /// edge-case values around 0, 2^32 and the modulus.
+    fn test_inputs() -> Vec<(Vec<Felt>, Vec<Felt>)> {
+        let edge_values = [0, 1, 2, (1 << 32) - 1, 1 << 32, (1 << 32) + 1, Felt::MODULUS - 1]
+            .into_iter()
+            .map(Felt::new)
+            .collect::<Vec<_>>();
+        let (edge_lhs, edge_rhs): (Vec<_>, Vec<_>) = edge_values
+            .iter()
+            .flat_map(|&l| edge_values.iter().map(move |&r| (l, r)))
+            .unzip();
+
+        let mut inputs = vec![(edge_lhs, edge_rhs)];
+        inputs.push((Vec::new(), Vec::new()));
+        for len in [1, 3, 4, 5, 8, 1023] {
+            inputs.push((rand_vector(len), rand_vector(len)));
+        }
+        inputs
+    }
+
+    fn as_ints(values: &[Felt]) -> Vec<u64> {
+        values.iter().map(|value| value.as_int()).collect()
+    }
+}
@@ -0,0 +1,128 @@
+//! NEON implementations of the batch routines, processing 2 elements at a time.
+
+use super::{scalar_add_assign, scalar_mul_assign, Felt, EPSILON, MODULUS};
+use core::arch::aarch64::{
+    uint64x2_t, vaddq_u64, vandq_u64, vcgtq_u64, vcltq_u64, vdupq_n_u64, vld1q_u64, vmovn_u64,
+    vmull_u32, vorrq_u64, vshlq_n_u64, vshrn_n_u64, vshrq_n_u64, vst1q_u64, vsubq_u64,
+};
+
+/// Number of elements processed by a single instruction.
+const LANES: usize = 2;
+
+// BATCH OPERATIONS
+// ================================================================================================
+
+pub fn add_assign(lhs: &mut [Felt], rhs: &[Felt]) {
+    let split = lhs.len() - lhs.len() % LANES;
+    for (l, r) in lhs[..split].chunks_exact_mut(LANES).zip(rhs[..split].chunks_exact(LANES)) {
+        // SAFETY: NEON instructions are always available on AArch64
+        unsafe {
+            let sum = add(canonicalize(load(l)), canonicalize(load(r)));
+            store(l, sum);
+        }
+    }
+    scalar_add_assign(&mut lhs[split..], &rhs[split..]);
+}
+
+pub fn mul_assign(lhs: &mut [Felt], rhs: &[Felt]) {
+    let split = lhs.len() - lhs.len() % LANES;
+    for (l, r) in lhs[..split].chunks_exact_mut(LANES).zip(rhs[..split].chunks_exact(LANES)) {
+        // SAFETY: NEON instructions are always available on AArch64
+        unsafe {
+            let product = mul(load(l), load(r));
+            store(l, product);
+        }
+    }
+    scalar_mul_assign(&mut lhs[split..], &rhs[split..]);
+}
+
+// ARITHMETIC
+// ================================================================================================
+
+/// Adds two vectors of canonical values modulo p, returning canonical values.
+#[inline]
+unsafe fn add(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    // a + b < 2p, and thus, a single subtraction of p reduces the sum; if the addition overflows,
+    // subtracting p modulo 2^64 yields the correct result as well
+    let sum = vaddq_u64(a, b);
+    let overflow = vcltq_u64(sum, a);
+    let reduce = vorrq_u64(overflow, vcgtq_u64(sum, vdupq_n_u64(MODULUS - 1)));
+    vsubq_u64(sum, vandq_u64(reduce, vdupq_n_u64(MODULUS)))
+}
+
+/// Multiplies two vectors of Montgomery values modulo p, returning canonical Montgomery values.
+#[inline]
+unsafe fn mul(a: uint64x2_t, b: uint64x2_t) -> uint64x2_t {
+    let (hi, lo) = mul_wide(a, b);
+    canonicalize(montgomery_reduce(hi, lo))
+}
+
+/// Computes the full 128-bit products of the values in two vectors, returning the high and the
+/// low halves of the products.
+#[inline]
+unsafe fn mul_wide(a: uint64x2_t, b: uint64x2_t) -> (uint64x2_t, uint64x2_t) {
+    let (a_lo, a_hi) = (vmovn_u64(a), vshrn_n_u64(a, 32));
+    let (b_lo, b_hi) = (vmovn_u64(b), vshrn_n_u64(b, 32));
+
+    // compute the 4 partial products of the 32-bit halves
+    let ll = vmull_u32(a_lo, b_lo);
+    let lh = vmull_u32(a_lo, b_hi);
+    let hl = vmull_u32(a_hi, b_lo);
+    let hh = vmull_u32(a_hi, b_hi);
+
+    // the middle partial products are aligned at bit 32; their carry is aligned at bit 96
+    let mid = vaddq_u64(lh, hl);
+    let mid_carry = vandq_u64(vcltq_u64(mid, lh), vdupq_n_u64(1 << 32));
+
+    let lo = vaddq_u64(ll, vshlq_n_u64(mid, 32));
+    let lo_carry = vcltq_u64(lo, ll);
+
+    // lo_carry is all ones (i.e., -1) in the lanes where the low half overflowed
+    let hi = vaddq_u64(hh, vshrq_n_u64(mid, 32));
+    let hi = vsubq_u64(vaddq_u64(hi, mid_carry), lo_carry);
+    (hi, lo)
+}
+
+/// Computes (hi * 2^64 + lo) / 2^64 mod p, returning values which are either canonical, or
+/// exceed p by less than p.
+#[inline]
+unsafe fn montgomery_reduce(hi: uint64x2_t, lo: uint64x2_t) -> uint64x2_t {
+    // m = lo * p^-1 mod 2^64, where p^-1 = 2^32 + 1 mod 2^64
+    let m = vaddq_u64(lo, vshlq_n_u64(lo, 32));
+    let m_overflow = vcltq_u64(m, lo);
+
+    // t = m * p / 2^64, rounded down, with the overflow of the computation of m folded in
+    let t = vaddq_u64(vsubq_u64(m, vshrq_n_u64(m, 32)), m_overflow);
+
+    // result = hi - t mod p
+    let result = vsubq_u64(hi, t);
+    let borrow = vcltq_u64(hi, t);
+    vsubq_u64(result, vandq_u64(borrow, vdupq_n_u64(EPSILON)))
+}
+
+/// Reduces the values which are greater than or equal to p by p.
+#[inline]
+unsafe fn canonicalize(x: uint64x2_t) -> uint64x2_t {
+    let reduce = vcgtq_u64(x, vdupq_n_u64(MODULUS - 1));
+    vsubq_u64(x, vandq_u64(reduce, vdupq_n_u64(MODULUS)))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Loads the Montgomery representations of the first 2 specified elements into a vector.
+#[inline]
+unsafe fn load(values: &[Felt]) -> uint64x2_t {
+    debug_assert!(values.len() >= LANES);
+    // elements are stored as their Montgomery representations (the layout is asserted in the parent module)
+    vld1q_u64(values.as_ptr() as *const u64)
+}
+
+/// Stores the Montgomery representations in the vector into the first 2 specified elements.
+///
+/// The values in the vector must be canonical.
+#[inline]
+unsafe fn store(target: &mut [Felt], vector: uint64x2_t) {
+    debug_assert!(target.len() >= LANES);
+    vst1q_u64(target.as_mut_ptr() as *mut u64, vector);
+}
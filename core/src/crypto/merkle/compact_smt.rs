@@ -0,0 +1,301 @@
+use super::{EmptySubtreeRoots, InnerNodeInfo, MerkleError, SMT_DEPTH};
+use crate::{
+    crypto::hash::{Rpo256, RpoDigest},
+    Felt, Word, EMPTY_WORD,
+};
+use alloc::{collections::BTreeMap, vec::Vec};
+
+// COMPACT SPARSE MERKLE TREE
+// ================================================================================================
+
+/// A Sparse Merkle Tree with 256-bit keys in which leaves are placed at variable depth.
+///
+/// The path to a key is defined by the most significant bits of the most significant element of
+/// the key. Unlike in [Smt](super::Smt), where all leaves are at depth 64, a leaf is placed at the
+/// smallest depth (but at least 1) at which its subtree contains no other keys. For sparsely
+/// populated trees this considerably shortens Merkle paths, and thus, reduces the amount of
+/// hashing required to access the tree in the VM.
+///
+/// A leaf at depth `d` is defined as `hash(KEY || VALUE)` computed in domain `d`, which binds the
+/// leaf to its depth. Keys whose paths coincide down to the depth of the tree cannot be stored in
+/// the same tree.
+///
+/// The tree can be accessed in the VM via `get_compact` and `set_compact` procedures of the
+/// `std::collections::smt` module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompactSmt {
+    depth: u8,
+    root: RpoDigest,
+    entries: BTreeMap<u64, (RpoDigest, Word)>,
+    nodes: BTreeMap<RpoDigest, [RpoDigest; 2]>,
+}
+
+impl CompactSmt {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The minimum supported depth of a compact Sparse Merkle Tree.
+    pub const MIN_DEPTH: u8 = 1;
+
+    /// The maximum supported depth of a compact Sparse Merkle Tree.
+    pub const MAX_DEPTH: u8 = SMT_DEPTH;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [CompactSmt] of the specified depth.
+    ///
+    /// # Errors
+    /// Returns an error if the depth is smaller than 1 or greater than 64.
+    pub fn new(depth: u8) -> Result<Self, MerkleError> {
+        if depth < Self::MIN_DEPTH {
+            return Err(MerkleError::DepthTooSmall(depth));
+        } else if depth > Self::MAX_DEPTH {
+            return Err(MerkleError::DepthTooBig(depth as u64));
+        }
+
+        Ok(Self {
+            depth,
+            root: *EmptySubtreeRoots::entry(depth, 0),
+            entries: BTreeMap::new(),
+            nodes: BTreeMap::new(),
+        })
+    }
+
+    /// Returns a new [CompactSmt] of the specified depth instantiated with the specified entries.
+    ///
+    /// Entries with empty values are ignored.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The depth is smaller than 1 or greater than 64.
+    /// - The same key is provided more than once, or the paths to two keys coincide down to the
+    ///   depth of the tree.
+    pub fn with_entries<I>(depth: u8, entries: I) -> Result<Self, MerkleError>
+    where
+        I: IntoIterator<Item = (RpoDigest, Word)>,
+    {
+        let mut tree = Self::new(depth)?;
+        for (key, value) in entries {
+            if value == EMPTY_WORD {
+                continue;
+            }
+            let index = tree.leaf_index(&key);
+            if tree.entries.insert(index, (key, value)).is_some() {
+                return Err(MerkleError::DuplicateValuesForIndex(index));
+            }
+        }
+        tree.rebuild();
+        Ok(tree)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the depth of this tree.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the root of this tree.
+    pub fn root(&self) -> RpoDigest {
+        self.root
+    }
+
+    /// Returns the number of non-empty entries in this tree.
+    pub fn num_entries(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns the value associated with the specified key, or an empty word if the key is not in
+    /// the tree.
+    pub fn get_value(&self, key: &RpoDigest) -> Word {
+        match self.entries.get(&self.leaf_index(key)) {
+            Some((leaf_key, value)) if leaf_key == key => *value,
+            _ => EMPTY_WORD,
+        }
+    }
+
+    /// Returns the depth at which the leaf for the specified key is located, or `None` if the key
+    /// is not in the tree.
+    pub fn get_leaf_depth(&self, key: &RpoDigest) -> Option<u8> {
+        let index = self.leaf_index(key);
+        match self.entries.get(&index) {
+            Some((leaf_key, _)) if leaf_key == key => {}
+            _ => return None,
+        }
+
+        // the leaf is right below the longest path it shares with any of its neighbors
+        let common_prefix = |other: u64| (index ^ other).leading_zeros() as u8 - (64 - self.depth);
+        let prev = self.entries.range(..index).next_back().map(|(&other, _)| common_prefix(other));
+        let next = self.entries.range(index + 1..).next().map(|(&other, _)| common_prefix(other));
+        Some(prev.max(next).unwrap_or(0) + 1)
+    }
+
+    /// Returns an iterator over the inner nodes of this tree.
+    ///
+    /// Roots of empty subtrees are not included, as they are present in every
+    /// [MerkleStore](super::MerkleStore).
+    pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
+        self.nodes
+            .iter()
+            .map(|(&value, &[left, right])| InnerNodeInfo { value, left, right })
+    }
+
+    /// Returns an iterator over the leaves of this tree, where each leaf is mapped to the
+    /// concatenation of the key and the value stored in it.
+    ///
+    /// These are the advice map entries required to read the tree in the VM.
+    pub fn leaf_preimages(&self) -> impl Iterator<Item = (RpoDigest, Vec<Felt>)> + '_ {
+        let mut leaves = Vec::with_capacity(self.entries.len());
+        for (key, value) in self.entries.values() {
+            let depth = self.get_leaf_depth(key).expect("key not in the tree");
+            let mut elements = Vec::with_capacity(8);
+            elements.extend_from_slice(key.as_elements());
+            elements.extend_from_slice(value);
+            leaves.push((leaf_hash(key, value, depth), elements));
+        }
+        leaves.into_iter()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Inserts the specified value under the specified key and returns the value previously
+    /// associated with the key.
+    ///
+    /// Inserting an empty value removes the key from the tree.
+    ///
+    /// # Errors
+    /// Returns an error if the path to the key coincides down to the depth of the tree with the
+    /// path to another key in the tree.
+    pub fn insert(&mut self, key: RpoDigest, value: Word) -> Result<Word, MerkleError> {
+        let index = self.leaf_index(&key);
+        let old_value = match self.entries.get(&index) {
+            Some((leaf_key, old_value)) if *leaf_key == key => *old_value,
+            Some(_) if value == EMPTY_WORD => return Ok(EMPTY_WORD),
+            Some(_) => return Err(MerkleError::DuplicateValuesForIndex(index)),
+            None => EMPTY_WORD,
+        };
+
+        if value == EMPTY_WORD {
+            self.entries.remove(&index);
+        } else {
+            self.entries.insert(index, (key, value));
+        }
+        if old_value != value {
+            self.rebuild();
+        }
+        Ok(old_value)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the index of the node at the depth of the tree on the path to the specified key.
+    fn leaf_index(&self, key: &RpoDigest) -> u64 {
+        key[3].as_int() >> (SMT_DEPTH - self.depth)
+    }
+
+    /// Recomputes the root and the inner nodes of this tree from its entries.
+    fn rebuild(&mut self) {
+        let mut nodes = BTreeMap::new();
+        self.root = self.build_subtree(0, 0, &mut nodes);
+        self.nodes = nodes;
+    }
+
+    /// Returns the root of the subtree at the specified depth and index, and records its inner
+    /// nodes.
+    fn build_subtree(
+        &self,
+        depth: u8,
+        index: u64,
+        nodes: &mut BTreeMap<RpoDigest, [RpoDigest; 2]>,
+    ) -> RpoDigest {
+        let shift = self.depth - depth;
+        let first = ((index as u128) << shift) as u64;
+        let last = ((((index as u128) + 1) << shift) - 1) as u64;
+
+        let mut entries = self.entries.range(first..=last);
+        match (entries.next(), entries.next()) {
+            (None, _) => *EmptySubtreeRoots::entry(self.depth, depth),
+            (Some((_, (key, value))), None) if depth > 0 => leaf_hash(key, value, depth),
+            _ => {
+                let left = self.build_subtree(depth + 1, index << 1, nodes);
+                let right = self.build_subtree(depth + 1, (index << 1) + 1, nodes);
+                let node = Rpo256::merge(&[left, right]);
+                nodes.insert(node, [left, right]);
+                node
+            }
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the hash of a leaf with the specified key and value at the specified depth.
+fn leaf_hash(key: &RpoDigest, value: &Word, depth: u8) -> RpoDigest {
+    Rpo256::merge_in_domain(&[*key, (*value).into()], Felt::from(depth))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{leaf_hash, CompactSmt, EmptySubtreeRoots, Rpo256, RpoDigest};
+    use crate::{Felt, Word, EMPTY_WORD, ONE};
+
+    fn key(msb: u64) -> RpoDigest {
+        RpoDigest::new([ONE, ONE, ONE, Felt::new(msb)])
+    }
+
+    #[test]
+    fn compact_smt_leaf_depths() {
+        let value: Word = [Felt::new(7); 4];
+        let mut tree = CompactSmt::new(8).unwrap();
+
+        // a single leaf is placed at depth 1
+        let k1 = key(0b0001 << 60);
+        tree.insert(k1, value).unwrap();
+        assert_eq!(tree.get_leaf_depth(&k1), Some(1));
+        let expected_root =
+            Rpo256::merge(&[leaf_hash(&k1, &value, 1), *EmptySubtreeRoots::entry(8, 1)]);
+        assert_eq!(tree.root(), expected_root);
+
+        // keys sharing a prefix of 2 bits are placed at depth 3
+        let k2 = key(0b0010 << 60);
+        tree.insert(k2, value).unwrap();
+        assert_eq!(tree.get_leaf_depth(&k1), Some(3));
+        assert_eq!(tree.get_leaf_depth(&k2), Some(3));
+        assert_eq!(tree.inner_nodes().count(), 3);
+
+        // a key in the other half of the tree does not affect the depth of the other leaves
+        let k3 = key(0b1000 << 60);
+        tree.insert(k3, value).unwrap();
+        assert_eq!(tree.get_leaf_depth(&k3), Some(1));
+        assert_eq!(tree.get_leaf_depth(&k1), Some(3));
+        assert_eq!(tree.get_value(&k3), value);
+        assert_eq!(tree.get_value(&key(0b0011 << 60)), EMPTY_WORD);
+
+        // removing a key collapses its sibling leaf, so the tree does not depend on history
+        assert_eq!(tree.insert(k2, EMPTY_WORD).unwrap(), value);
+        let expected = CompactSmt::with_entries(8, [(k3, value), (k1, value)]).unwrap();
+        assert_eq!(tree, expected);
+        assert_eq!(tree.get_leaf_depth(&k1), Some(1));
+    }
+
+    #[test]
+    fn compact_smt_collisions() {
+        let value: Word = [Felt::new(7); 4];
+        let mut tree = CompactSmt::new(4).unwrap();
+        tree.insert(key(1 << 60), value).unwrap();
+
+        // paths to keys coincide down to the depth of the tree
+        assert!(tree.insert(key((1 << 60) + 1), value).is_err());
+        assert_eq!(tree.insert(key((1 << 60) + 1), EMPTY_WORD).unwrap(), EMPTY_WORD);
+        assert!(CompactSmt::new(0).is_err());
+        assert!(CompactSmt::new(65).is_err());
+    }
+}
@@ -0,0 +1,222 @@
+use super::{MerkleError, NodeIndex};
+use crate::{
+    crypto::hash::{Blake3_256, Rpo256, RpoDigest},
+    Felt,
+};
+use alloc::vec::Vec;
+use sha2::{Digest, Sha256};
+
+// DIGEST HASH FUNCTION
+// ================================================================================================
+
+/// Hash function of a [DigestMerkleTree].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigestHashFunction {
+    Blake3,
+    Sha256,
+}
+
+impl DigestHashFunction {
+    /// Returns the hash of the concatenation of the specified digests.
+    pub fn merge(&self, left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+        let mut bytes = [0_u8; 64];
+        bytes[..32].copy_from_slice(left);
+        bytes[32..].copy_from_slice(right);
+        match self {
+            Self::Blake3 => Blake3_256::hash(&bytes).into(),
+            Self::Sha256 => Sha256::digest(bytes).into(),
+        }
+    }
+
+    /// Returns the specified digest as eight 32-bit values, in the same way as the digests are
+    /// represented by the respective procedures of `std::crypto::hashes`.
+    ///
+    /// BLAKE3 digests are split into little-endian values, and SHA256 digests into big-endian
+    /// values.
+    pub fn digest_to_elements(&self, digest: &[u8; 32]) -> [Felt; 8] {
+        let mut elements = [Felt::new(0); 8];
+        for (element, bytes) in elements.iter_mut().zip(digest.chunks_exact(4)) {
+            let bytes = [bytes[0], bytes[1], bytes[2], bytes[3]];
+            let value = match self {
+                Self::Blake3 => u32::from_le_bytes(bytes),
+                Self::Sha256 => u32::from_be_bytes(bytes),
+            };
+            *element = Felt::from(value);
+        }
+        elements
+    }
+}
+
+// DIGEST MERKLE TREE
+// ================================================================================================
+
+/// A binary Merkle tree over 32-byte digests, in which nodes are hashed with BLAKE3 or SHA256.
+///
+/// Unlike trees in a [MerkleStore](super::MerkleStore), which are hashed with RPO, these trees
+/// cannot be accessed via the `mtree_*` instructions, as the VM can compute RPO hashes only.
+/// Instead, the nodes of a tree are provided to a program via the advice map (see
+/// [DigestMerkleTree::to_advice_map_entries()]), from which `adv.push_dmtpath` pushes a node
+/// together with its authentication path onto the advice stack. The node is then verified against
+/// the root of the tree by the `get_blake3_node` and `get_sha256_node` procedures of
+/// `std::crypto::merkle`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DigestMerkleTree {
+    hash_fn: DigestHashFunction,
+    depth: u8,
+    /// Nodes of the tree, in which the children of node k are nodes 2k and 2k + 1, and the root is
+    /// node 1.
+    nodes: Vec<[u8; 32]>,
+}
+
+impl DigestMerkleTree {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [DigestMerkleTree] with the specified leaves, hashed with the specified hash
+    /// function.
+    ///
+    /// # Errors
+    /// Returns an error if the number of leaves is smaller than 2 or is not a power of two.
+    pub fn new(hash_fn: DigestHashFunction, leaves: Vec<[u8; 32]>) -> Result<Self, MerkleError> {
+        let num_leaves = leaves.len();
+        if num_leaves < 2 || !num_leaves.is_power_of_two() {
+            return Err(MerkleError::NumLeavesNotPowerOfTwo(num_leaves));
+        }
+
+        let mut nodes = vec![[0_u8; 32]; num_leaves];
+        nodes.extend(leaves);
+        for k in (1..num_leaves).rev() {
+            nodes[k] = hash_fn.merge(&nodes[2 * k], &nodes[2 * k + 1]);
+        }
+
+        Ok(Self {
+            hash_fn,
+            depth: num_leaves.ilog2() as u8,
+            nodes,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the hash function of this tree.
+    pub fn hash_fn(&self) -> DigestHashFunction {
+        self.hash_fn
+    }
+
+    /// Returns the depth of this tree.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the root of this tree.
+    pub fn root(&self) -> [u8; 32] {
+        self.nodes[1]
+    }
+
+    /// Returns the root of this tree as it is represented on the stack of the VM.
+    pub fn root_elements(&self) -> [Felt; 8] {
+        self.hash_fn.digest_to_elements(&self.root())
+    }
+
+    /// Returns the node at the specified index.
+    ///
+    /// # Errors
+    /// Returns an error if the index is deeper than the leaves of this tree.
+    pub fn get_node(&self, index: NodeIndex) -> Result<[u8; 32], MerkleError> {
+        Ok(self.nodes[self.node_position(index)?])
+    }
+
+    /// Returns the authentication path of the node at the specified index, starting with the
+    /// sibling of the node.
+    ///
+    /// # Errors
+    /// Returns an error if the index is deeper than the leaves of this tree.
+    pub fn get_path(&self, index: NodeIndex) -> Result<Vec<[u8; 32]>, MerkleError> {
+        let mut k = self.node_position(index)?;
+        let mut path = Vec::with_capacity(index.depth() as usize);
+        while k > 1 {
+            path.push(self.nodes[k ^ 1]);
+            k /= 2;
+        }
+        Ok(path)
+    }
+
+    /// Returns the entries via which the nodes of this tree are provided to a program in the
+    /// advice map.
+    ///
+    /// Every node is stored under the key returned by [DigestMerkleTree::node_key()], as eight
+    /// 32-bit values.
+    pub fn to_advice_map_entries(&self) -> impl Iterator<Item = (RpoDigest, Vec<Felt>)> + '_ {
+        let root = self.root_elements();
+        self.nodes.iter().enumerate().skip(1).map(move |(k, node)| {
+            let depth = k.ilog2();
+            let index = k as u64 - (1 << depth);
+            let key = Self::node_key(&root, Felt::from(depth), Felt::new(index));
+            (key, self.hash_fn.digest_to_elements(node).to_vec())
+        })
+    }
+
+    /// Returns the advice map key of the node at the specified depth and index of the tree with
+    /// the specified root, computed as `hash(ROOT || depth || index)`.
+    pub fn node_key(root: &[Felt; 8], depth: Felt, index: Felt) -> RpoDigest {
+        let mut elements = Vec::with_capacity(10);
+        elements.extend_from_slice(root);
+        elements.push(depth);
+        elements.push(index);
+        Rpo256::hash_elements(&elements)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the position of the node with the specified index in the list of nodes.
+    fn node_position(&self, index: NodeIndex) -> Result<usize, MerkleError> {
+        if index.depth() > self.depth {
+            return Err(MerkleError::DepthTooBig(index.depth() as u64));
+        }
+        Ok(index.to_scalar_index() as usize)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{DigestHashFunction, DigestMerkleTree, MerkleError, NodeIndex};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn digest_tree_paths() {
+        let leaves: Vec<[u8; 32]> = (0..4_u8).map(|i| [i; 32]).collect();
+        for hash_fn in [DigestHashFunction::Blake3, DigestHashFunction::Sha256] {
+            let tree = DigestMerkleTree::new(hash_fn, leaves.clone()).unwrap();
+            assert_eq!(tree.depth(), 2);
+
+            let left = hash_fn.merge(&leaves[0], &leaves[1]);
+            let right = hash_fn.merge(&leaves[2], &leaves[3]);
+            assert_eq!(tree.root(), hash_fn.merge(&left, &right));
+
+            let index = NodeIndex::new(2, 2).unwrap();
+            assert_eq!(tree.get_node(index).unwrap(), leaves[2]);
+            assert_eq!(tree.get_path(index).unwrap(), vec![leaves[3], left]);
+            assert!(tree.get_path(NodeIndex::root()).unwrap().is_empty());
+            assert!(tree.get_node(NodeIndex::new(3, 0).unwrap()).is_err());
+
+            // every node is provided via the advice map
+            assert_eq!(tree.to_advice_map_entries().count(), 7);
+        }
+
+        let result = DigestMerkleTree::new(DigestHashFunction::Blake3, leaves[..3].to_vec());
+        assert!(matches!(result, Err(MerkleError::NumLeavesNotPowerOfTwo(3))));
+    }
+
+    #[test]
+    fn digest_elements() {
+        let mut digest = [0_u8; 32];
+        digest[..4].copy_from_slice(&[1, 0, 0, 0]);
+        assert_eq!(DigestHashFunction::Blake3.digest_to_elements(&digest)[0].as_int(), 1);
+        assert_eq!(DigestHashFunction::Sha256.digest_to_elements(&digest)[0].as_int(), 1 << 24);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::errors::InputError;
+use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+
+pub use miden_crypto::merkle::{
+    DefaultMerkleStore, EmptySubtreeRoots, InnerNodeInfo, LeafIndex, MerkleError, MerklePath,
+    MerkleStore, MerkleTree, Mmr, MmrPeaks, NodeIndex, PartialMerkleTree, RecordingMerkleStore,
+    SimpleSmt, Smt, SmtProof, SmtProofError, StoreNode, SMT_DEPTH,
+};
+
+mod compact_smt;
+pub use compact_smt::CompactSmt;
+
+mod digest_tree;
+pub use digest_tree::{DigestHashFunction, DigestMerkleTree};
+
+use miden_crypto::hash::rpo::RpoDigest;
+
+// PARTIAL MERKLE TREE CONSTRUCTION
+// ================================================================================================
+
+/// Returns a [PartialMerkleTree] built from the specified Merkle proofs, where each proof is a
+/// tuple of (leaf index, leaf value, authentication path).
+///
+/// This is the format in which Merkle proofs are commonly returned by external APIs. The nodes of
+/// the returned tree can be added to a [MerkleStore] via [PartialMerkleTree::inner_nodes()].
+///
+/// # Errors
+/// Returns an error if:
+/// - A leaf index is not valid for the depth implied by the length of its authentication path.
+/// - A proof does not open to the specified root, or the proofs are for trees of different depths.
+pub fn partial_merkle_tree_from_proofs<I>(
+    root: RpoDigest,
+    proofs: I,
+) -> Result<PartialMerkleTree, InputError>
+where
+    I: IntoIterator<Item = (u64, RpoDigest, MerklePath)>,
+{
+    let mut tree = PartialMerkleTree::new();
+    for (index, leaf, path) in proofs {
+        let computed_root = path
+            .compute_root(index, leaf)
+            .map_err(|err| InputError::InvalidMerkleProof(index, err.to_string()))?;
+        if computed_root != root {
+            return Err(InputError::MerkleRootMismatch(root.as_bytes(), computed_root.as_bytes()));
+        }
+        tree.add_path(index, leaf, path)
+            .map_err(|err| InputError::InvalidMerkleProof(index, err.to_string()))?;
+    }
+    Ok(tree)
+}
+
+// MULTIPROOFS
+// ================================================================================================
+
+/// Returns the indices of the nodes which, together with the leaves at the specified indices, are
+/// required to compute the root of a Merkle tree of the specified depth.
+///
+/// Nodes which can be computed from the leaves are omitted. The remaining nodes are ordered level
+/// by level starting from the leaves, and by index within each level. This is the order in which
+/// `std::crypto::merkle::verify_multi` reads them from the advice stack. Duplicate leaf indices
+/// are ignored.
+///
+/// # Errors
+/// Returns an error if a leaf index is not valid for the specified depth.
+pub fn multiproof_sibling_indices<I>(depth: u8, indices: I) -> Result<Vec<NodeIndex>, MerkleError>
+where
+    I: IntoIterator<Item = u64>,
+{
+    let mut level = BTreeSet::new();
+    for index in indices {
+        level.insert(NodeIndex::new(depth, index)?.value());
+    }
+
+    let mut siblings = Vec::new();
+    for depth in (1..=depth).rev() {
+        let mut parents = BTreeSet::new();
+        let mut nodes = level.iter().peekable();
+        while let Some(&index) = nodes.next() {
+            // a left child followed by its right sibling requires no additional nodes
+            if index & 1 == 0 && nodes.peek() == Some(&&(index + 1)) {
+                nodes.next();
+            } else {
+                siblings.push(NodeIndex::new(depth, index ^ 1)?);
+            }
+            parents.insert(index >> 1);
+        }
+        level = parents;
+    }
+    Ok(siblings)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        multiproof_sibling_indices, partial_merkle_tree_from_proofs, InputError, MerkleTree,
+        NodeIndex, RpoDigest,
+    };
+    use crate::{Felt, Word, ONE};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn partial_tree_from_proofs() {
+        let leaves: Vec<Word> = (0..8).map(|i| [Felt::new(i), ONE, ONE, ONE]).collect();
+        let tree = MerkleTree::new(leaves.clone()).unwrap();
+        let proof = |index: u64| {
+            let path = tree.get_path(NodeIndex::new(3, index).unwrap()).unwrap();
+            (index, RpoDigest::from(leaves[index as usize]), path)
+        };
+
+        let partial = partial_merkle_tree_from_proofs(tree.root(), [proof(1), proof(6)]).unwrap();
+        assert_eq!(partial.root(), tree.root());
+        assert_eq!(partial.get_node(NodeIndex::new(3, 6).unwrap()).unwrap(), leaves[6].into());
+
+        // a proof for a different leaf does not open to the root
+        let (index, _, path) = proof(2);
+        let result =
+            partial_merkle_tree_from_proofs(tree.root(), [(index, RpoDigest::default(), path)]);
+        assert!(matches!(result, Err(InputError::MerkleRootMismatch(..))));
+
+        // a leaf index which does not fit into the depth of the path is rejected
+        let (_, leaf, path) = proof(2);
+        let result = partial_merkle_tree_from_proofs(tree.root(), [(8, leaf, path)]);
+        assert!(matches!(result, Err(InputError::InvalidMerkleProof(8, _))));
+    }
+
+    #[test]
+    fn multiproof_siblings() {
+        let node = |depth, index| NodeIndex::new(depth, index).unwrap();
+
+        // leaves 2 and 3 are siblings, and so are their parent and the parent of leaf 0
+        let siblings = multiproof_sibling_indices(3, [3, 0, 2, 6]).unwrap();
+        assert_eq!(siblings, vec![node(3, 1), node(3, 7), node(2, 2)]);
+
+        assert!(multiproof_sibling_indices(3, [8]).is_err());
+        assert!(multiproof_sibling_indices(0, [0]).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{chiplets::hasher::Digest, Felt, Word};
+use alloc::vec::Vec;
+
+pub use miden_crypto::rand::{RandomCoin, RandomCoinError, RpoRandomCoin, WinterRandomCoin};
+
+// RPO TRANSCRIPT
+// ================================================================================================
+
+/// A Fiat-Shamir transcript based on the RPO permutation.
+///
+/// The transcript wraps [RpoRandomCoin], which is the random coin used by the prover to generate
+/// recursive proofs, and draws values in exactly the same way as the `std::crypto::rng` module of
+/// the Miden standard library. Thus, challenges drawn from the transcript outside of the VM match
+/// the challenges recomputed inside the VM, as long as the transcript is seeded and reseeded with
+/// the same data.
+#[derive(Debug, Clone)]
+pub struct RpoTranscript {
+    coin: RpoRandomCoin,
+}
+
+impl RpoTranscript {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new transcript initialized from the specified seed.
+    ///
+    /// This corresponds to `std::crypto::rng::init`.
+    pub fn new(seed: Word) -> Self {
+        Self {
+            coin: RpoRandomCoin::new(seed),
+        }
+    }
+
+    /// Returns a new transcript initialized from the RPO hash of the specified elements.
+    pub fn from_elements(elements: &[Felt]) -> Self {
+        Self {
+            coin: <RpoRandomCoin as RandomCoin>::new(elements),
+        }
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Absorbs the specified data into the transcript.
+    ///
+    /// This corresponds to `std::crypto::rng::reseed`.
+    pub fn reseed(&mut self, data: Word) {
+        self.coin.reseed(Digest::new(data));
+    }
+
+    /// Draws the next field element from the transcript.
+    ///
+    /// This corresponds to `std::crypto::rng::next_felt`.
+    pub fn draw_felt(&mut self) -> Felt {
+        self.coin.draw().expect("drawing a base field element never fails")
+    }
+
+    /// Draws the next word from the transcript; the first element of the word is drawn first.
+    ///
+    /// This corresponds to `std::crypto::rng::next_word`.
+    pub fn draw_word(&mut self) -> Word {
+        [self.draw_felt(), self.draw_felt(), self.draw_felt(), self.draw_felt()]
+    }
+
+    /// Draws the next integer in the range [0, n) from the transcript.
+    ///
+    /// The integer is computed as the lower 32 bits of the next field element reduced modulo n.
+    /// This corresponds to `std::crypto::rng::next_u32_range`.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero.
+    pub fn draw_u32_range(&mut self, n: u32) -> u32 {
+        assert!(n != 0, "range must not be empty");
+        (self.draw_felt().as_int() as u32) % n
+    }
+
+    /// Draws the specified number of integers in the range [0, domain_size) after mixing the
+    /// specified nonce into the transcript, as done by the prover when drawing query positions.
+    ///
+    /// The returned integers may contain duplicates.
+    ///
+    /// # Errors
+    /// Returns an error if a valid integer could not be drawn; see
+    /// [RandomCoin::draw_integers()] for details.
+    pub fn draw_integers(
+        &mut self,
+        num_values: usize,
+        domain_size: usize,
+        nonce: u64,
+    ) -> Result<Vec<usize>, RandomCoinError> {
+        self.coin.draw_integers(num_values, domain_size, nonce)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the underlying random coin.
+    pub fn coin(&self) -> &RpoRandomCoin {
+        &self.coin
+    }
+
+    /// Consumes the transcript and returns the underlying random coin.
+    pub fn into_coin(self) -> RpoRandomCoin {
+        self.coin
+    }
+}
+
+impl From<RpoRandomCoin> for RpoTranscript {
+    fn from(coin: RpoRandomCoin) -> Self {
+        Self { coin }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Felt, RandomCoin, RpoRandomCoin, RpoTranscript};
+    use crate::chiplets::hasher::Digest;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn transcript_matches_random_coin() {
+        let seed = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let mut transcript = RpoTranscript::new(seed);
+        let mut coin = RpoRandomCoin::new(seed);
+
+        // draw enough elements to exhaust the rate at least once
+        let words = (0..3).map(|_| transcript.draw_word()).collect::<Vec<_>>();
+        for word in words {
+            for element in word {
+                assert_eq!(element, coin.draw::<Felt>().unwrap());
+            }
+        }
+
+        let data = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+        transcript.reseed(data);
+        coin.reseed(Digest::new(data));
+        let expected = (coin.draw::<Felt>().unwrap().as_int() as u32) % 10;
+        assert_eq!(transcript.draw_u32_range(10), expected);
+    }
+}
@@ -1,5 +1,6 @@
 use core::fmt;
 
+use crate::chiplets::hasher::Digest;
 use alloc::string::String;
 
 // INPUT ERROR
@@ -9,6 +10,9 @@ use alloc::string::String;
 pub enum InputError {
     DuplicateAdviceRoot([u8; 32]),
     InputLengthExceeded(usize, usize),
+    InvalidMemoryAddress(u64),
+    InvalidMerkleProof(u64, String),
+    MerkleRootMismatch([u8; 32], [u8; 32]),
     NotFieldElement(u64, String),
 }
 
@@ -25,6 +29,15 @@ impl fmt::Display for InputError {
                     "Number of input values can not exceed {limit}, but {provided} was provided"
                 )
             }
+            InvalidMemoryAddress(addr) => {
+                write!(f, "Memory address {addr} is greater than the maximum address {}", u32::MAX)
+            }
+            InvalidMerkleProof(index, description) => {
+                write!(f, "Merkle proof for the leaf at index {index} is invalid: {description}")
+            }
+            MerkleRootMismatch(expected, actual) => {
+                write!(f, "Merkle proof opens to root {actual:02x?} instead of {expected:02x?}")
+            }
             NotFieldElement(num, description) => {
                 write!(f, "{num} is not a valid field element: {description}")
             }
@@ -43,6 +56,8 @@ pub enum OutputError {
     InvalidOverflowAddress(String),
     InvalidOverflowAddressLength(usize, usize),
     InvalidStackElement(String),
+    InvalidStackValue(usize, &'static str),
+    NotEnoughOutputs(usize, usize),
     OutputSizeTooBig(usize),
 }
 
@@ -59,6 +74,12 @@ impl fmt::Display for OutputError {
             InvalidStackElement(description) => {
                 write!(f, "stack contains an invalid field element: {description}")
             }
+            InvalidStackValue(position, expected) => {
+                write!(f, "stack element at position {position} is not a valid {expected}")
+            }
+            NotEnoughOutputs(required, available) => {
+                write!(f, "decoding requires {required} stack elements, but only {available} exist")
+            }
             OutputSizeTooBig(size) => {
                 write!(f, "too many elements for output stack, {size} elements")
             }
@@ -72,9 +93,12 @@ impl fmt::Display for OutputError {
 #[cfg(feature = "std")]
 impl std::error::Error for OutputError {}
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum KernelError {
     DuplicatedProcedures,
+    IncompatibleKernel(Digest),
+    MetadataMismatch,
+    MissingProcedure(Digest),
     TooManyProcedures(usize, usize),
 }
 
@@ -84,6 +108,15 @@ impl fmt::Display for KernelError {
             KernelError::DuplicatedProcedures => {
                 write!(f, "Kernel can not have duplicated procedures",)
             }
+            KernelError::IncompatibleKernel(commitment) => {
+                write!(f, "Program is not compatible with kernel {commitment}")
+            }
+            KernelError::MetadataMismatch => {
+                write!(f, "Kernel metadata does not describe the procedures of the kernel")
+            }
+            KernelError::MissingProcedure(proc_hash) => {
+                write!(f, "Kernel does not contain procedure {proc_hash} invoked by the program")
+            }
             KernelError::TooManyProcedures(max, count) => {
                 write!(f, "Kernel can have at most {} procedures, received {}", max, count)
             }
@@ -93,3 +126,44 @@ impl fmt::Display for KernelError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for KernelError {}
+
+// ERROR CODE ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorCodeError {
+    CodeOutOfRange(String, String, u32),
+    DuplicateCode(String, u32),
+    DuplicateName(String, String),
+    InvalidRange(u32, u32),
+    OverlappingRanges(String, String),
+}
+
+impl fmt::Display for ErrorCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ErrorCodeError::*;
+        match self {
+            CodeOutOfRange(namespace, name, code) => {
+                write!(
+                    f,
+                    "error code {code} of {namespace}::{name} is outside of its declared ranges"
+                )
+            }
+            DuplicateCode(namespace, code) => {
+                write!(f, "error code {code} is declared more than once in {namespace}")
+            }
+            DuplicateName(namespace, name) => {
+                write!(f, "error name {name} is declared more than once in {namespace}")
+            }
+            InvalidRange(first, last) => {
+                write!(f, "error code range {first}..={last} is empty")
+            }
+            OverlappingRanges(namespace, other) => {
+                write!(f, "error code range of {namespace} overlaps with a range of {other}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ErrorCodeError {}
@@ -6,18 +6,13 @@ extern crate std;
 #[macro_use]
 extern crate alloc;
 
+pub mod batch;
 pub mod chiplets;
 pub mod errors;
 
 pub use miden_crypto::{Word, EMPTY_WORD, ONE, WORD_SIZE, ZERO};
 pub mod crypto {
-    pub mod merkle {
-        pub use miden_crypto::merkle::{
-            DefaultMerkleStore, EmptySubtreeRoots, InnerNodeInfo, LeafIndex, MerkleError,
-            MerklePath, MerkleStore, MerkleTree, Mmr, MmrPeaks, NodeIndex, PartialMerkleTree,
-            RecordingMerkleStore, SimpleSmt, Smt, SmtProof, SmtProofError, StoreNode, SMT_DEPTH,
-        };
-    }
+    pub mod merkle;
 
     pub mod hash {
         pub use miden_crypto::hash::{
@@ -27,11 +22,7 @@ pub mod crypto {
         };
     }
 
-    pub mod random {
-        pub use miden_crypto::rand::{
-            RandomCoin, RandomCoinError, RpoRandomCoin, WinterRandomCoin,
-        };
-    }
+    pub mod random;
 
     pub mod dsa {
         pub use miden_crypto::dsa::rpo_falcon512;
@@ -44,7 +35,10 @@ pub use math::{
 };
 
 mod program;
-pub use program::{blocks as code_blocks, CodeBlockTable, Kernel, Program, ProgramInfo};
+pub use program::{
+    blocks as code_blocks, CodeBlockTable, ErrorCodeInfo, ErrorCodeRegistry, Kernel,
+    KernelMetadata, KernelProcedure, MastStore, Program, ProgramInfo, ProgramPackage,
+};
 
 mod operations;
 pub use operations::{
@@ -53,7 +47,7 @@ pub use operations::{
 };
 
 pub mod stack;
-pub use stack::{StackInputs, StackOutputs};
+pub use stack::{StackDecode, StackEncode, StackInputs, StackOutputs, StackReader};
 
 pub mod utils;
 
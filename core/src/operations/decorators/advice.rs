@@ -48,6 +48,48 @@ pub enum AdviceInjector {
     ///   Merkle store: {TREE_ROOT<-NODE}
     MerkleNodeToStack,
 
+    /// Pushes onto the advice stack the nodes which are required to verify the leaves at the
+    /// specified indices against the root of a Merkle tree, omitting the nodes which can be
+    /// computed from the leaves.
+    ///
+    /// The leaf indices are read from the first elements of the memory words at addresses `ptr`,
+    /// `ptr + 2`, ..., `ptr + 2 * (n - 1)`.
+    ///
+    /// Inputs:
+    ///   Operand stack: [depth, n, ptr, TREE_ROOT, ...]
+    ///   Advice stack: [...]
+    ///   Merkle store: {TREE_ROOT<-NODES}
+    ///
+    /// Outputs:
+    ///   Operand stack: [depth, n, ptr, TREE_ROOT, ...]
+    ///   Advice stack: [NODES, ...]
+    ///   Merkle store: {TREE_ROOT<-NODES}
+    ///
+    /// Where the nodes are ordered as described in
+    /// [multiproof_sibling_indices()](crate::crypto::merkle::multiproof_sibling_indices).
+    MerkleMultiProofToStack,
+
+    /// Pushes onto the advice stack the node of a BLAKE3 or SHA256 Merkle tree specified by the
+    /// values on the top of the operand stack, followed by the authentication path of the node.
+    ///
+    /// The nodes of the tree are looked up in the advice map, in which they are stored as eight
+    /// 32-bit values under the keys described in
+    /// [DigestMerkleTree::node_key()](crate::crypto::merkle::DigestMerkleTree::node_key).
+    ///
+    /// Inputs:
+    ///   Operand stack: [depth, index, TREE_ROOT, ...]
+    ///   Advice stack: [...]
+    ///   Advice map: {hash(TREE_ROOT || d || i): NODE}
+    ///
+    /// Outputs:
+    ///   Operand stack: [depth, index, TREE_ROOT, ...]
+    ///   Advice stack: [NODE, SIBLING_0, ..., SIBLING_(depth-1), ...]
+    ///   Advice map: {hash(TREE_ROOT || d || i): NODE}
+    ///
+    /// Where TREE_ROOT and the nodes consist of 8 elements, and the elements of each node are
+    /// pushed in reverse order, so that the node is read from the advice stack via `adv_push.8`.
+    DigestMerklePathToStack,
+
     /// Updates the node of a Merkle tree specified by the values at the top of the operand stack.
     /// Returns the path from the updated node to the new root of the tree to the caller.
     ///
@@ -88,6 +130,22 @@ pub enum AdviceInjector {
         key_offset: usize,
     },
 
+    /// Pushes a list of field elements onto the advice stack. The list is looked up in the advice
+    /// map under the key derived from the namespace digest and the key located on the operand
+    /// stack as hash(NS || KEY). If `include_len` is set to true, the number of elements in the
+    /// value is also pushed onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [NS, KEY, ...]
+    ///   Advice stack: [...]
+    ///   Advice map: {hash(NS || KEY): values}
+    ///
+    /// Outputs:
+    ///   Operand stack: [NS, KEY, ...]
+    ///   Advice stack: [values_len?, values, ...]
+    ///   Advice map: {hash(NS || KEY): values}
+    NamespacedMapValueToStack { include_len: bool },
+
     /// Pushes the result of [u64] division (both the quotient and the remainder) onto the advice
     /// stack.
     ///
@@ -165,6 +223,30 @@ pub enum AdviceInjector {
     ///   Advice stack: [VALUE, ...]
     SmtPeek,
 
+    /// Pushes onto the advice stack the values required to navigate a compact Sparse Merkle Tree
+    /// to the specified key.
+    ///
+    /// In a compact Sparse Merkle Tree of depth `depth`, leaves are placed at the smallest depth
+    /// (but at least 1) at which the path to the key (defined by the most significant bits of the
+    /// most significant element of the key) does not contain any other leaf.
+    ///
+    /// Inputs:
+    ///   Operand stack: [depth, KEY, ROOT, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [depth, KEY, ROOT, ...]
+    ///   Advice stack: [leaf_depth, is_sibling_leaf, collapse_depth, ...]
+    ///
+    /// Where:
+    /// - `leaf_depth` is the depth of the first leaf or root of an empty subtree on the path to
+    ///   the key.
+    /// - `is_sibling_leaf` is 1 if the sibling of the node at `leaf_depth` is a leaf, and 0
+    ///   otherwise.
+    /// - `collapse_depth` is the depth to which the sibling leaf moves if the node at `leaf_depth`
+    ///   is removed, or 0 if the sibling is not a leaf.
+    CompactSmtPeek,
+
     /// Pushes the number of the leading zeros of the top stack element onto the advice stack.
     ///
     /// Inputs:
@@ -219,6 +301,21 @@ pub enum AdviceInjector {
     ///   Advice stack: [ilog2(n), ...]
     ILog2,
 
+    /// Pushes the maximum number of cycles the program is allowed to execute onto the advice
+    /// stack.
+    ///
+    /// The value comes from the execution options of the VM, and is not constrained by the proof
+    /// of execution.
+    ///
+    /// Inputs:
+    ///   Operand stack: [...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [...]
+    ///   Advice stack: [max_cycles, ...]
+    MaxCyclesToStack,
+
     // ADVICE MAP INJECTORS
     // --------------------------------------------------------------------------------------------
     /// Reads words from memory at the specified range and inserts them into the advice map under
@@ -235,6 +332,21 @@ pub enum AdviceInjector {
     /// Where `values` are the elements located in memory[start_addr..end_addr].
     MemToMap,
 
+    /// Reads words from memory at the specified range and inserts them into the advice map under
+    /// the commitment to these words located at the top of the stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [COM, start_addr, end_addr, ...]
+    ///   Advice map: {...}
+    ///
+    /// Outputs:
+    ///   Operand stack: [COM, start_addr, end_addr, ...]
+    ///   Advice map: {COM: values}
+    ///
+    /// Where `values` are the elements located in memory[start_addr..end_addr], and COM must be
+    /// the sequential RPO hash of these elements.
+    MemRangeToMap,
+
     /// Reads two word from the operand stack and inserts them into the advice map under the key
     /// defined by the hash of these words.
     ///
@@ -279,6 +391,25 @@ pub enum AdviceInjector {
     /// Where PK is the public key corresponding to the signing key, MSG is the message, SIG_DATA
     /// is the signature data.
     SigToStack { kind: SignatureKind },
+
+    /// Reads two words and a memory range from the stack, and pushes values onto the advice stack
+    /// which are required for verification of a DSA over the data located in the memory range in
+    /// Miden VM.
+    ///
+    /// Inputs:
+    ///   Operand stack: [PK, COM, start_addr, end_addr, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [PK, COM, start_addr, end_addr, ...]
+    ///   Advice stack: [SIG_DATA]
+    ///
+    /// Where PK is the public key corresponding to the signing key, COM is the commitment to the
+    /// words located in memory[start_addr..end_addr] computed as their sequential RPO hash, and
+    /// SIG_DATA is the signature data for the message COM. The request fails if COM is not the
+    /// commitment to the data in memory, and thus, the host never signs data other than the data
+    /// the program holds.
+    SigMemToStack { kind: SignatureKind },
 }
 
 impl fmt::Display for AdviceInjector {
@@ -286,6 +417,8 @@ impl fmt::Display for AdviceInjector {
         match self {
             Self::MerkleNodeMerge => write!(f, "merkle_node_merge"),
             Self::MerkleNodeToStack => write!(f, "merkle_node_to_stack"),
+            Self::MerkleMultiProofToStack => write!(f, "merkle_multiproof_to_stack"),
+            Self::DigestMerklePathToStack => write!(f, "digest_merkle_path_to_stack"),
             Self::UpdateMerkleNode => {
                 write!(f, "update_merkle_node")
             }
@@ -299,21 +432,32 @@ impl fmt::Display for AdviceInjector {
                     write!(f, "map_value_to_stack.{key_offset}")
                 }
             }
+            Self::NamespacedMapValueToStack { include_len } => {
+                if *include_len {
+                    write!(f, "namespaced_map_value_to_stack_with_len")
+                } else {
+                    write!(f, "namespaced_map_value_to_stack")
+                }
+            }
             Self::U64Div => write!(f, "div_u64"),
             Self::Ext2Inv => write!(f, "ext2_inv"),
             Self::Ext2Intt => write!(f, "ext2_intt"),
             Self::SmtGet => write!(f, "smt_get"),
             Self::SmtSet => write!(f, "smt_set"),
             Self::SmtPeek => write!(f, "smt_peek"),
+            Self::CompactSmtPeek => write!(f, "compact_smt_peek"),
             Self::U32Clz => write!(f, "u32clz"),
             Self::U32Ctz => write!(f, "u32ctz"),
             Self::U32Clo => write!(f, "u32clo"),
             Self::U32Cto => write!(f, "u32cto"),
             Self::ILog2 => write!(f, "ilog2"),
+            Self::MaxCyclesToStack => write!(f, "max_cycles_to_stack"),
             Self::MemToMap => write!(f, "mem_to_map"),
+            Self::MemRangeToMap => write!(f, "mem_range_to_map"),
             Self::HdwordToMap { domain } => write!(f, "hdword_to_map.{domain}"),
             Self::HpermToMap => write!(f, "hperm_to_map"),
             Self::SigToStack { kind } => write!(f, "sig_to_stack.{kind}"),
+            Self::SigMemToStack { kind } => write!(f, "sig_mem_to_stack.{kind}"),
         }
     }
 }
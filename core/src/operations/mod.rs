@@ -6,6 +6,8 @@ pub use decorators::{
     SignatureKind,
 };
 
+mod serialization;
+
 // OPERATIONS
 // ================================================================================================
 
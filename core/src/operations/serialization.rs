@@ -0,0 +1,416 @@
+use super::{AdviceInjector, AssemblyOp, DebugOptions, Decorator, Felt, Operation, SignatureKind};
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use alloc::string::{String, ToString};
+
+// OPERATION SERIALIZATION
+// ================================================================================================
+
+impl Serializable for Operation {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.op_code());
+        match self {
+            Self::Assert(err_code) => target.write_u32(*err_code),
+            Self::U32assert2(err_code) => err_code.write_into(target),
+            Self::Push(value) => value.write_into(target),
+            _ => (),
+        }
+    }
+}
+
+impl Deserializable for Operation {
+    #[rustfmt::skip]
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let op_code = source.read_u8()?;
+        let op = match op_code {
+            0b0000_0000 => Self::Noop,
+            0b0000_0001 => Self::Eqz,
+            0b0000_0010 => Self::Neg,
+            0b0000_0011 => Self::Inv,
+            0b0000_0100 => Self::Incr,
+            0b0000_0101 => Self::Not,
+            0b0000_0110 => Self::FmpAdd,
+            0b0000_0111 => Self::MLoad,
+            0b0000_1000 => Self::Swap,
+            0b0000_1001 => Self::Caller,
+            0b0000_1010 => Self::MovUp2,
+            0b0000_1011 => Self::MovDn2,
+            0b0000_1100 => Self::MovUp3,
+            0b0000_1101 => Self::MovDn3,
+            0b0000_1110 => Self::AdvPopW,
+            0b0000_1111 => Self::Expacc,
+            0b0001_0000 => Self::MovUp4,
+            0b0001_0001 => Self::MovDn4,
+            0b0001_0010 => Self::MovUp5,
+            0b0001_0011 => Self::MovDn5,
+            0b0001_0100 => Self::MovUp6,
+            0b0001_0101 => Self::MovDn6,
+            0b0001_0110 => Self::MovUp7,
+            0b0001_0111 => Self::MovDn7,
+            0b0001_1000 => Self::SwapW,
+            0b0001_1001 => Self::Ext2Mul,
+            0b0001_1010 => Self::MovUp8,
+            0b0001_1011 => Self::MovDn8,
+            0b0001_1100 => Self::SwapW2,
+            0b0001_1101 => Self::SwapW3,
+            0b0001_1110 => Self::SwapDW,
+            0b0010_0000 => Self::Assert(source.read_u32()?),
+            0b0010_0001 => Self::Eq,
+            0b0010_0010 => Self::Add,
+            0b0010_0011 => Self::Mul,
+            0b0010_0100 => Self::And,
+            0b0010_0101 => Self::Or,
+            0b0010_0110 => Self::U32and,
+            0b0010_0111 => Self::U32xor,
+            0b0010_1000 => Self::FriE2F4,
+            0b0010_1001 => Self::Drop,
+            0b0010_1010 => Self::CSwap,
+            0b0010_1011 => Self::CSwapW,
+            0b0010_1100 => Self::MLoadW,
+            0b0010_1101 => Self::MStore,
+            0b0010_1110 => Self::MStoreW,
+            0b0010_1111 => Self::FmpUpdate,
+            0b0011_0000 => Self::Pad,
+            0b0011_0001 => Self::Dup0,
+            0b0011_0010 => Self::Dup1,
+            0b0011_0011 => Self::Dup2,
+            0b0011_0100 => Self::Dup3,
+            0b0011_0101 => Self::Dup4,
+            0b0011_0110 => Self::Dup5,
+            0b0011_0111 => Self::Dup6,
+            0b0011_1000 => Self::Dup7,
+            0b0011_1001 => Self::Dup9,
+            0b0011_1010 => Self::Dup11,
+            0b0011_1011 => Self::Dup13,
+            0b0011_1100 => Self::Dup15,
+            0b0011_1101 => Self::AdvPop,
+            0b0011_1110 => Self::SDepth,
+            0b0011_1111 => Self::Clk,
+            0b0100_0000 => Self::U32add,
+            0b0100_0010 => Self::U32sub,
+            0b0100_0100 => Self::U32mul,
+            0b0100_0110 => Self::U32div,
+            0b0100_1000 => Self::U32split,
+            0b0100_1010 => Self::U32assert2(Felt::read_from(source)?),
+            0b0100_1100 => Self::U32add3,
+            0b0100_1110 => Self::U32madd,
+            0b0101_0000 => Self::HPerm,
+            0b0101_0001 => Self::MpVerify,
+            0b0101_0010 => Self::Pipe,
+            0b0101_0011 => Self::MStream,
+            0b0101_0100 => Self::Split,
+            0b0101_0101 => Self::Loop,
+            0b0101_0110 => Self::Span,
+            0b0101_0111 => Self::Join,
+            0b0101_1000 => Self::Dyn,
+            0b0101_1001 => Self::RCombBase,
+            0b0110_0000 => Self::MrUpdate,
+            0b0110_0100 => Self::Push(Felt::read_from(source)?),
+            0b0110_1000 => Self::SysCall,
+            0b0110_1100 => Self::Call,
+            0b0111_0000 => Self::End,
+            0b0111_0100 => Self::Repeat,
+            0b0111_1000 => Self::Respan,
+            0b0111_1100 => Self::Halt,
+            _ => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid operation opcode {op_code:#09b}"
+                )))
+            }
+        };
+        Ok(op)
+    }
+}
+
+// DECORATOR SERIALIZATION
+// ================================================================================================
+
+const DECORATOR_ADVICE: u8 = 0;
+const DECORATOR_ASM_OP: u8 = 1;
+const DECORATOR_DEBUG: u8 = 2;
+const DECORATOR_EVENT: u8 = 3;
+const DECORATOR_TRACE: u8 = 4;
+
+impl Serializable for Decorator {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Advice(injector) => {
+                target.write_u8(DECORATOR_ADVICE);
+                injector.write_into(target);
+            }
+            Self::AsmOp(assembly_op) => {
+                target.write_u8(DECORATOR_ASM_OP);
+                assembly_op.write_into(target);
+            }
+            Self::Debug(options) => {
+                target.write_u8(DECORATOR_DEBUG);
+                options.write_into(target);
+            }
+            Self::Event(event_id) => {
+                target.write_u8(DECORATOR_EVENT);
+                target.write_u32(*event_id);
+            }
+            Self::Trace(trace_id) => {
+                target.write_u8(DECORATOR_TRACE);
+                target.write_u32(*trace_id);
+            }
+        }
+    }
+}
+
+impl Deserializable for Decorator {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            DECORATOR_ADVICE => Ok(Self::Advice(AdviceInjector::read_from(source)?)),
+            DECORATOR_ASM_OP => Ok(Self::AsmOp(AssemblyOp::read_from(source)?)),
+            DECORATOR_DEBUG => Ok(Self::Debug(DebugOptions::read_from(source)?)),
+            DECORATOR_EVENT => Ok(Self::Event(source.read_u32()?)),
+            DECORATOR_TRACE => Ok(Self::Trace(source.read_u32()?)),
+            tag => Err(DeserializationError::InvalidValue(format!("invalid decorator tag {tag}"))),
+        }
+    }
+}
+
+// ADVICE INJECTOR SERIALIZATION
+// ================================================================================================
+
+const MERKLE_NODE_MERGE: u8 = 0;
+const MERKLE_NODE_TO_STACK: u8 = 1;
+const UPDATE_MERKLE_NODE: u8 = 2;
+const MAP_VALUE_TO_STACK: u8 = 3;
+const U64_DIV: u8 = 4;
+const EXT2_INV: u8 = 5;
+const EXT2_INTT: u8 = 6;
+const SMT_GET: u8 = 7;
+const SMT_SET: u8 = 8;
+const SMT_PEEK: u8 = 9;
+const U32_CLZ: u8 = 10;
+const U32_CTZ: u8 = 11;
+const U32_CLO: u8 = 12;
+const U32_CTO: u8 = 13;
+const ILOG2: u8 = 14;
+const MEM_TO_MAP: u8 = 15;
+const HDWORD_TO_MAP: u8 = 16;
+const HPERM_TO_MAP: u8 = 17;
+const SIG_TO_STACK: u8 = 18;
+const NAMESPACED_MAP_VALUE_TO_STACK: u8 = 19;
+const COMPACT_SMT_PEEK: u8 = 20;
+const MERKLE_MULTIPROOF_TO_STACK: u8 = 21;
+const MAX_CYCLES_TO_STACK: u8 = 22;
+const SIG_MEM_TO_STACK: u8 = 23;
+const MEM_RANGE_TO_MAP: u8 = 24;
+const DIGEST_MERKLE_PATH_TO_STACK: u8 = 25;
+
+impl Serializable for AdviceInjector {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::MerkleNodeMerge => target.write_u8(MERKLE_NODE_MERGE),
+            Self::MerkleNodeToStack => target.write_u8(MERKLE_NODE_TO_STACK),
+            Self::MerkleMultiProofToStack => target.write_u8(MERKLE_MULTIPROOF_TO_STACK),
+            Self::DigestMerklePathToStack => target.write_u8(DIGEST_MERKLE_PATH_TO_STACK),
+            Self::UpdateMerkleNode => target.write_u8(UPDATE_MERKLE_NODE),
+            Self::MapValueToStack {
+                include_len,
+                key_offset,
+            } => {
+                target.write_u8(MAP_VALUE_TO_STACK);
+                target.write_bool(*include_len);
+                target.write_usize(*key_offset);
+            }
+            Self::NamespacedMapValueToStack { include_len } => {
+                target.write_u8(NAMESPACED_MAP_VALUE_TO_STACK);
+                target.write_bool(*include_len);
+            }
+            Self::U64Div => target.write_u8(U64_DIV),
+            Self::Ext2Inv => target.write_u8(EXT2_INV),
+            Self::Ext2Intt => target.write_u8(EXT2_INTT),
+            Self::SmtGet => target.write_u8(SMT_GET),
+            Self::SmtSet => target.write_u8(SMT_SET),
+            Self::SmtPeek => target.write_u8(SMT_PEEK),
+            Self::CompactSmtPeek => target.write_u8(COMPACT_SMT_PEEK),
+            Self::U32Clz => target.write_u8(U32_CLZ),
+            Self::U32Ctz => target.write_u8(U32_CTZ),
+            Self::U32Clo => target.write_u8(U32_CLO),
+            Self::U32Cto => target.write_u8(U32_CTO),
+            Self::ILog2 => target.write_u8(ILOG2),
+            Self::MaxCyclesToStack => target.write_u8(MAX_CYCLES_TO_STACK),
+            Self::MemToMap => target.write_u8(MEM_TO_MAP),
+            Self::MemRangeToMap => target.write_u8(MEM_RANGE_TO_MAP),
+            Self::HdwordToMap { domain } => {
+                target.write_u8(HDWORD_TO_MAP);
+                domain.write_into(target);
+            }
+            Self::HpermToMap => target.write_u8(HPERM_TO_MAP),
+            Self::SigToStack { kind } => {
+                target.write_u8(SIG_TO_STACK);
+                kind.write_into(target);
+            }
+            Self::SigMemToStack { kind } => {
+                target.write_u8(SIG_MEM_TO_STACK);
+                kind.write_into(target);
+            }
+        }
+    }
+}
+
+impl Deserializable for AdviceInjector {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            MERKLE_NODE_MERGE => Ok(Self::MerkleNodeMerge),
+            MERKLE_NODE_TO_STACK => Ok(Self::MerkleNodeToStack),
+            MERKLE_MULTIPROOF_TO_STACK => Ok(Self::MerkleMultiProofToStack),
+            DIGEST_MERKLE_PATH_TO_STACK => Ok(Self::DigestMerklePathToStack),
+            UPDATE_MERKLE_NODE => Ok(Self::UpdateMerkleNode),
+            MAP_VALUE_TO_STACK => {
+                let include_len = source.read_bool()?;
+                let key_offset = source.read_usize()?;
+                Ok(Self::MapValueToStack {
+                    include_len,
+                    key_offset,
+                })
+            }
+            NAMESPACED_MAP_VALUE_TO_STACK => Ok(Self::NamespacedMapValueToStack {
+                include_len: source.read_bool()?,
+            }),
+            U64_DIV => Ok(Self::U64Div),
+            EXT2_INV => Ok(Self::Ext2Inv),
+            EXT2_INTT => Ok(Self::Ext2Intt),
+            SMT_GET => Ok(Self::SmtGet),
+            SMT_SET => Ok(Self::SmtSet),
+            SMT_PEEK => Ok(Self::SmtPeek),
+            COMPACT_SMT_PEEK => Ok(Self::CompactSmtPeek),
+            U32_CLZ => Ok(Self::U32Clz),
+            U32_CTZ => Ok(Self::U32Ctz),
+            U32_CLO => Ok(Self::U32Clo),
+            U32_CTO => Ok(Self::U32Cto),
+            ILOG2 => Ok(Self::ILog2),
+            MAX_CYCLES_TO_STACK => Ok(Self::MaxCyclesToStack),
+            MEM_TO_MAP => Ok(Self::MemToMap),
+            MEM_RANGE_TO_MAP => Ok(Self::MemRangeToMap),
+            HDWORD_TO_MAP => Ok(Self::HdwordToMap {
+                domain: Felt::read_from(source)?,
+            }),
+            HPERM_TO_MAP => Ok(Self::HpermToMap),
+            SIG_TO_STACK => Ok(Self::SigToStack {
+                kind: SignatureKind::read_from(source)?,
+            }),
+            SIG_MEM_TO_STACK => Ok(Self::SigMemToStack {
+                kind: SignatureKind::read_from(source)?,
+            }),
+            tag => Err(DeserializationError::InvalidValue(format!(
+                "invalid advice injector tag {tag}"
+            ))),
+        }
+    }
+}
+
+impl Serializable for SignatureKind {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::RpoFalcon512 => target.write_u8(0),
+        }
+    }
+}
+
+impl Deserializable for SignatureKind {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::RpoFalcon512),
+            tag => {
+                Err(DeserializationError::InvalidValue(format!("invalid signature kind tag {tag}")))
+            }
+        }
+    }
+}
+
+// ASSEMBLY OP SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AssemblyOp {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_str(target, self.context_name());
+        target.write_u8(self.num_cycles());
+        write_str(target, self.op());
+        target.write_bool(self.should_break());
+    }
+}
+
+impl Deserializable for AssemblyOp {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let context_name = read_string(source)?;
+        let num_cycles = source.read_u8()?;
+        let op = read_string(source)?;
+        let should_break = source.read_bool()?;
+        Ok(Self::new(context_name, num_cycles, op, should_break))
+    }
+}
+
+// DEBUG OPTIONS SERIALIZATION
+// ================================================================================================
+
+const STACK_ALL: u8 = 0;
+const STACK_TOP: u8 = 1;
+const MEM_ALL: u8 = 2;
+const MEM_INTERVAL: u8 = 3;
+const LOCAL_INTERVAL: u8 = 4;
+
+impl Serializable for DebugOptions {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::StackAll => target.write_u8(STACK_ALL),
+            Self::StackTop(n) => {
+                target.write_u8(STACK_TOP);
+                target.write_u16(*n);
+            }
+            Self::MemAll => target.write_u8(MEM_ALL),
+            Self::MemInterval(start, end) => {
+                target.write_u8(MEM_INTERVAL);
+                target.write_u32(*start);
+                target.write_u32(*end);
+            }
+            Self::LocalInterval(start, end, num_locals) => {
+                target.write_u8(LOCAL_INTERVAL);
+                target.write_u16(*start);
+                target.write_u16(*end);
+                target.write_u16(*num_locals);
+            }
+        }
+    }
+}
+
+impl Deserializable for DebugOptions {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            STACK_ALL => Ok(Self::StackAll),
+            STACK_TOP => Ok(Self::StackTop(source.read_u16()?)),
+            MEM_ALL => Ok(Self::MemAll),
+            MEM_INTERVAL => {
+                let start = source.read_u32()?;
+                let end = source.read_u32()?;
+                Ok(Self::MemInterval(start, end))
+            }
+            LOCAL_INTERVAL => {
+                let start = source.read_u16()?;
+                let end = source.read_u16()?;
+                let num_locals = source.read_u16()?;
+                Ok(Self::LocalInterval(start, end, num_locals))
+            }
+            tag => {
+                Err(DeserializationError::InvalidValue(format!("invalid debug options tag {tag}")))
+            }
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn write_str<W: ByteWriter>(target: &mut W, value: &str) {
+    target.write_usize(value.len());
+    target.write_bytes(value.as_bytes());
+}
+
+fn read_string<R: ByteReader>(source: &mut R) -> Result<String, DeserializationError> {
+    let len = source.read_usize()?;
+    let bytes = source.read_vec(len)?;
+    String::from_utf8(bytes).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+}
@@ -1,4 +1,4 @@
-use super::{fmt, hasher, Digest, Felt, Operation};
+use super::{fmt, hasher, Digest, Dyn, Felt, Operation};
 use crate::utils::to_hex;
 
 // CALL BLOCK
@@ -70,6 +70,12 @@ impl Call {
         self.is_syscall
     }
 
+    /// Returns true if this call block invokes the procedure whose MAST root is on top of the
+    /// stack (i.e., if the target of the call is DYN).
+    pub fn is_dynamic(&self) -> bool {
+        self.fn_hash == Dyn::dyn_hash()
+    }
+
     /// Returns the domain of the call block
     pub fn domain(&self) -> Felt {
         match self.is_syscall() {
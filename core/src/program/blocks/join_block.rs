@@ -1,5 +1,5 @@
 use super::{fmt, hasher, CodeBlock, Digest, Felt, Operation};
-use alloc::boxed::Box;
+use alloc::sync::Arc;
 
 // JOIN BLOCKS
 // ================================================================================================
@@ -12,9 +12,11 @@ use alloc::boxed::Box;
 /// > hash(left_block_hash || right_block_hash, domain=JOIN_DOMAIN)
 ///
 /// Where `left_block_hash` and `right_block_hash` are 4 field elements (256 bits) each.
+///
+/// Sub-blocks are reference-counted, and thus, clones of a join block share its sub-blocks.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Join {
-    body: Box<[CodeBlock; 2]>,
+    body: Arc<[CodeBlock; 2]>,
     hash: Digest,
 }
 
@@ -30,7 +32,7 @@ impl Join {
     pub fn new(body: [CodeBlock; 2]) -> Self {
         let hash = hasher::merge_in_domain(&[body[0].hash(), body[1].hash()], Self::DOMAIN);
         Self {
-            body: Box::new(body),
+            body: Arc::new(body),
             hash,
         }
     }
@@ -1,5 +1,5 @@
 use super::{fmt, hasher, CodeBlock, Digest, Felt, Operation};
-use alloc::boxed::Box;
+use alloc::sync::Arc;
 
 // LOOP BLOCK
 // ================================================================================================
@@ -13,9 +13,11 @@ use alloc::boxed::Box;
 /// > hash(body_hash || padding, domain=LOOP_DOMAIN)
 ///
 /// Where `body_hash` is 4 field elements (256 bits), and `padding` is 4 ZERO elements (256 bits).
+///
+/// The body is reference-counted, and thus, clones of a loop block share its body.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Loop {
-    body: Box<CodeBlock>,
+    body: Arc<CodeBlock>,
     hash: Digest,
 }
 
@@ -31,7 +33,7 @@ impl Loop {
     pub fn new(body: CodeBlock) -> Self {
         let hash = hasher::merge_in_domain(&[body.hash(), Digest::default()], Self::DOMAIN);
         Self {
-            body: Box::new(body),
+            body: Arc::new(body),
             hash,
         }
     }
@@ -8,6 +8,7 @@ mod dyn_block;
 mod join_block;
 mod loop_block;
 mod proxy_block;
+mod serialization;
 mod span_block;
 mod split_block;
 
@@ -85,6 +86,12 @@ impl CodeBlock {
         Self::Call(Call::new(Dyn::dyn_hash()))
     }
 
+    /// Returns a new SYSCALL block whose target is DYN; that is, a block which invokes the kernel
+    /// procedure whose MAST root is on top of the stack.
+    pub fn new_dynsyscall() -> Self {
+        Self::Call(Call::new_syscall(Dyn::dyn_hash()))
+    }
+
     /// TODO: add comments
     pub fn new_proxy(code_hash: Digest) -> Self {
         Self::Proxy(Proxy::new(code_hash))
@@ -0,0 +1,333 @@
+use super::{CodeBlock, Digest, Operation};
+use crate::{
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Decorator, DecoratorList,
+};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+
+// CODE BLOCK TAGS
+// ================================================================================================
+
+const SPAN: u8 = 0;
+const JOIN: u8 = 1;
+const SPLIT: u8 = 2;
+const LOOP: u8 = 3;
+const CALL: u8 = 4;
+const SYSCALL: u8 = 5;
+const DYN: u8 = 6;
+const PROXY: u8 = 7;
+const REF: u8 = 8;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Maximum nesting depth of code blocks accepted by the deserializer.
+///
+/// Code blocks are read recursively, and thus, the depth is bounded to make sure that malformed
+/// inputs cannot exhaust the stack of the deserializing thread.
+const MAX_CODE_BLOCK_DEPTH: usize = 256;
+
+// CODE BLOCK SERIALIZATION
+// ================================================================================================
+
+/// Code blocks are serialized in pre-order. Every block which is equal to a block serialized
+/// earlier is written out as a reference to that block; blocks are numbered in the order in which
+/// their serialization completes (i.e., in post-order), and references themselves are not
+/// numbered. Thus, identical subtrees of the MAST are serialized only once, and share their nodes
+/// in memory once read back.
+impl Serializable for CodeBlock {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        write_code_block(self, target, &mut WrittenBlocks::default());
+    }
+}
+
+/// Code blocks serialized so far, grouped by their hashes.
+#[derive(Default)]
+struct WrittenBlocks<'a> {
+    blocks: BTreeMap<[u8; 32], Vec<(usize, &'a CodeBlock)>>,
+    num_blocks: usize,
+}
+
+impl<'a> WrittenBlocks<'a> {
+    /// Returns the index of a serialized block which is equal to the specified block.
+    fn find(&self, block: &CodeBlock) -> Option<usize> {
+        let key: [u8; 32] = block.hash().into();
+        self.blocks
+            .get(&key)?
+            .iter()
+            .find(|(_, written)| *written == block)
+            .map(|(idx, _)| *idx)
+    }
+
+    /// Records the specified block as serialized.
+    fn insert(&mut self, block: &'a CodeBlock) {
+        let key: [u8; 32] = block.hash().into();
+        self.blocks.entry(key).or_default().push((self.num_blocks, block));
+        self.num_blocks += 1;
+    }
+}
+
+/// Writes the specified code block into the target, replacing blocks which have already been
+/// written with references to them.
+fn write_code_block<'a, W: ByteWriter>(
+    block: &'a CodeBlock,
+    target: &mut W,
+    written: &mut WrittenBlocks<'a>,
+) {
+    // a reference is never shorter than a DYN block
+    if !matches!(block, CodeBlock::Dyn(_)) {
+        if let Some(idx) = written.find(block) {
+            target.write_u8(REF);
+            target.write_usize(idx);
+            return;
+        }
+    }
+
+    match block {
+        CodeBlock::Span(block) => {
+            target.write_u8(SPAN);
+
+            // operations are written out without batching information; batches (and the hash
+            // of the block) are recomputed when the block is read back
+            let ops = block
+                .op_batches()
+                .iter()
+                .flat_map(|batch| batch.ops())
+                .copied()
+                .collect::<Vec<_>>();
+            target.write_usize(ops.len());
+            target.write_many(&ops);
+
+            target.write_usize(block.decorators().len());
+            for (op_idx, decorator) in block.decorators() {
+                target.write_usize(*op_idx);
+                decorator.write_into(target);
+            }
+        }
+        CodeBlock::Join(block) => {
+            target.write_u8(JOIN);
+            write_code_block(block.first(), target, written);
+            write_code_block(block.second(), target, written);
+        }
+        CodeBlock::Split(block) => {
+            target.write_u8(SPLIT);
+            write_code_block(block.on_true(), target, written);
+            write_code_block(block.on_false(), target, written);
+        }
+        CodeBlock::Loop(block) => {
+            target.write_u8(LOOP);
+            write_code_block(block.body(), target, written);
+        }
+        CodeBlock::Call(block) => {
+            target.write_u8(if block.is_syscall() { SYSCALL } else { CALL });
+            block.fn_hash().write_into(target);
+        }
+        CodeBlock::Dyn(_) => target.write_u8(DYN),
+        CodeBlock::Proxy(block) => {
+            target.write_u8(PROXY);
+            block.hash().write_into(target);
+        }
+    }
+
+    written.insert(block);
+}
+
+impl Deserializable for CodeBlock {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        read_code_block(source, 0, &mut Vec::new())
+    }
+}
+
+/// Reads a code block nested at the specified depth from the source; `read` contains the blocks
+/// read so far, in the order in which they were completed.
+///
+/// Lengths read from the source are never used to pre-allocate memory, as they are not
+/// authenticated until the corresponding number of elements has actually been read.
+fn read_code_block<R: ByteReader>(
+    source: &mut R,
+    depth: usize,
+    read: &mut Vec<CodeBlock>,
+) -> Result<CodeBlock, DeserializationError> {
+    if depth > MAX_CODE_BLOCK_DEPTH {
+        return Err(DeserializationError::InvalidValue(format!(
+            "code blocks are nested deeper than {MAX_CODE_BLOCK_DEPTH} levels"
+        )));
+    }
+
+    let block = match source.read_u8()? {
+        SPAN => {
+            let num_ops = source.read_usize()?;
+            if num_ops == 0 {
+                return Err(DeserializationError::InvalidValue(
+                    "span block must contain at least one operation".to_string(),
+                ));
+            }
+            let mut ops = Vec::new();
+            for _ in 0..num_ops {
+                ops.push(Operation::read_from(source)?);
+            }
+
+            let num_decorators = source.read_usize()?;
+            let mut decorators = DecoratorList::new();
+            for _ in 0..num_decorators {
+                let op_idx = source.read_usize()?;
+                let prev_idx = decorators.last().map(|(idx, _)| *idx).unwrap_or(0);
+                if op_idx < prev_idx || op_idx > num_ops {
+                    return Err(DeserializationError::InvalidValue(format!(
+                        "invalid decorator operation index {op_idx}"
+                    )));
+                }
+                decorators.push((op_idx, Decorator::read_from(source)?));
+            }
+
+            CodeBlock::new_span_with_decorators(ops, decorators)
+        }
+        JOIN => {
+            let first = read_code_block(source, depth + 1, read)?;
+            let second = read_code_block(source, depth + 1, read)?;
+            CodeBlock::new_join([first, second])
+        }
+        SPLIT => {
+            let on_true = read_code_block(source, depth + 1, read)?;
+            let on_false = read_code_block(source, depth + 1, read)?;
+            CodeBlock::new_split(on_true, on_false)
+        }
+        LOOP => CodeBlock::new_loop(read_code_block(source, depth + 1, read)?),
+        CALL => CodeBlock::new_call(Digest::read_from(source)?),
+        SYSCALL => CodeBlock::new_syscall(Digest::read_from(source)?),
+        DYN => CodeBlock::new_dyn(),
+        PROXY => CodeBlock::new_proxy(Digest::read_from(source)?),
+        REF => {
+            // references are not numbered, and thus, the block is returned as is
+            let idx = source.read_usize()?;
+            return read.get(idx).cloned().ok_or_else(|| {
+                DeserializationError::InvalidValue(format!("invalid code block reference {idx}"))
+            });
+        }
+        tag => {
+            return Err(DeserializationError::InvalidValue(format!("invalid code block tag {tag}")))
+        }
+    };
+
+    read.push(block.clone());
+    Ok(block)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        CodeBlock, Decorator, Deserializable, Operation, Serializable, DYN, LOOP,
+        MAX_CODE_BLOCK_DEPTH, REF, SPAN,
+    };
+    use crate::utils::ByteWriter;
+    use crate::{AdviceInjector, AssemblyOp, DebugOptions, Felt};
+    use alloc::string::ToString;
+
+    #[test]
+    fn code_block_serialization() {
+        let decorators = vec![
+            (
+                0,
+                Decorator::Advice(AdviceInjector::MapValueToStack {
+                    include_len: true,
+                    key_offset: 4,
+                }),
+            ),
+            (
+                1,
+                Decorator::AsmOp(AssemblyOp::new(
+                    "#main".to_string(),
+                    2,
+                    "push.7".to_string(),
+                    false,
+                )),
+            ),
+            (2, Decorator::Debug(DebugOptions::LocalInterval(1, 2, 3))),
+            (2, Decorator::Event(11)),
+        ];
+        let span = CodeBlock::new_span_with_decorators(
+            vec![
+                Operation::Push(Felt::new(7)),
+                Operation::Assert(3),
+                Operation::U32assert2(Felt::new(5)),
+            ],
+            decorators,
+        );
+        let body = CodeBlock::new_join([span, CodeBlock::new_call(CodeBlock::new_dyn().hash())]);
+        let block = CodeBlock::new_split(
+            CodeBlock::new_loop(body),
+            CodeBlock::new_join([
+                CodeBlock::new_dyncall(),
+                CodeBlock::new_syscall(CodeBlock::new_dyn().hash()),
+            ]),
+        );
+        let block = CodeBlock::new_join([block, CodeBlock::new_proxy(CodeBlock::new_dyn().hash())]);
+
+        let bytes = block.to_bytes();
+        let restored = CodeBlock::read_from_bytes(&bytes).unwrap();
+        assert_eq!(block, restored);
+        assert_eq!(block.hash(), restored.hash());
+    }
+
+    #[test]
+    fn code_block_serialization_shares_subtrees() {
+        let span = CodeBlock::new_span(vec![Operation::Push(Felt::new(7)); 100]);
+        let body = CodeBlock::new_join([span.clone(), CodeBlock::new_dyn()]);
+        let block = CodeBlock::new_join([body.clone(), CodeBlock::new_loop(body.clone())]);
+
+        // the repeated subtree is written out only once
+        let bytes = block.to_bytes();
+        assert!(bytes.len() < 2 * body.to_bytes().len());
+
+        let restored = CodeBlock::read_from_bytes(&bytes).unwrap();
+        assert_eq!(block, restored);
+        assert_eq!(block.hash(), restored.hash());
+
+        // spans with the same operations but different decorators have the same hash, but are not
+        // shared
+        let decorated = CodeBlock::new_span_with_decorators(
+            vec![Operation::Push(Felt::new(7)); 100],
+            vec![(0, Decorator::Event(1))],
+        );
+        assert_eq!(span.hash(), decorated.hash());
+        let block = CodeBlock::new_join([span, decorated]);
+        let restored = CodeBlock::read_from_bytes(&block.to_bytes()).unwrap();
+        assert_eq!(block, restored);
+    }
+
+    #[test]
+    fn code_block_deserialization_errors() {
+        // empty span block
+        let mut bytes = vec![SPAN];
+        bytes.write_usize(0);
+        bytes.write_usize(0);
+        assert!(CodeBlock::read_from_bytes(&bytes).is_err());
+
+        // unknown block tag
+        let bytes = [42u8];
+        assert!(CodeBlock::read_from_bytes(&bytes).is_err());
+
+        // huge number of operations in a truncated span block
+        let mut bytes = vec![SPAN];
+        bytes.write_usize(usize::MAX);
+        assert!(CodeBlock::read_from_bytes(&bytes).is_err());
+
+        // reference to a block which has not been read yet
+        let mut bytes = vec![LOOP, REF];
+        bytes.write_usize(0);
+        assert!(CodeBlock::read_from_bytes(&bytes).is_err());
+
+        // loops nested too deeply
+        let mut bytes = vec![LOOP; MAX_CODE_BLOCK_DEPTH + 1];
+        bytes.push(DYN);
+        assert!(CodeBlock::read_from_bytes(&bytes).is_err());
+
+        // loops nested at the maximum depth
+        let mut bytes = vec![LOOP; MAX_CODE_BLOCK_DEPTH];
+        bytes.push(DYN);
+        assert!(CodeBlock::read_from_bytes(&bytes).is_ok());
+    }
+}
@@ -1,6 +1,6 @@
 use super::{fmt, hasher, Digest, Felt, Operation};
 use crate::{DecoratorIterator, DecoratorList, ZERO};
-use alloc::vec::Vec;
+use alloc::{sync::Arc, vec::Vec};
 use winter_utils::flatten_slice_elements;
 
 // CONSTANTS
@@ -46,11 +46,14 @@ const MAX_OPS_PER_BATCH: usize = GROUP_SIZE * BATCH_SIZE;
 ///
 /// Where `batches` is the concatenation of each `batch` in the span, and each batch is 8 field
 /// elements (512 bits).
+///
+/// Operation batches and decorators are reference-counted, and thus, clones of a span block share
+/// them.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Span {
-    op_batches: Vec<OpBatch>,
+    op_batches: Arc<[OpBatch]>,
     hash: Digest,
-    decorators: DecoratorList,
+    decorators: Arc<DecoratorList>,
 }
 
 impl Span {
@@ -87,9 +90,9 @@ impl Span {
 
         let (op_batches, hash) = batch_ops(operations);
         Self {
-            op_batches,
+            op_batches: op_batches.into(),
             hash,
-            decorators,
+            decorators: Arc::new(decorators),
         }
     }
 
@@ -114,7 +117,7 @@ impl Span {
     #[must_use]
     pub fn replicate(&self, num_copies: usize) -> Self {
         let own_ops = self.get_ops();
-        let own_decorators = &self.decorators;
+        let own_decorators = self.decorators.as_ref();
         let mut ops = Vec::with_capacity(own_ops.len() * num_copies);
         let mut decorators = DecoratorList::new();
 
@@ -1,5 +1,5 @@
 use super::{fmt, hasher, CodeBlock, Digest, Felt, Operation};
-use alloc::boxed::Box;
+use alloc::sync::Arc;
 
 // SPLIT BLOCK
 // ================================================================================================
@@ -13,9 +13,11 @@ use alloc::boxed::Box;
 /// > hash(true_branch_hash || false_branch_hash, domain=SPLIT_DOMAIN)
 ///
 /// Where `true_branch_hash` and `false_branch_hash` are 4 field elements (256 bits) each.
+///
+/// Branches are reference-counted, and thus, clones of a split block share its branches.
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Split {
-    branches: Box<[CodeBlock; 2]>,
+    branches: Arc<[CodeBlock; 2]>,
     hash: Digest,
 }
 
@@ -31,7 +33,7 @@ impl Split {
     pub fn new(t_branch: CodeBlock, f_branch: CodeBlock) -> Self {
         let hash = hasher::merge_in_domain(&[t_branch.hash(), f_branch.hash()], Self::DOMAIN);
         Self {
-            branches: Box::new([t_branch, f_branch]),
+            branches: Arc::new([t_branch, f_branch]),
             hash,
         }
     }
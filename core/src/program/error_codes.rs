@@ -0,0 +1,320 @@
+use crate::errors::ErrorCodeError;
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+use core::fmt;
+
+// ERROR CODE REGISTRY
+// ================================================================================================
+
+/// A registry of error code ranges claimed by libraries, and of the names of the error codes
+/// within these ranges.
+///
+/// Assertion instructions fail with a numeric error code, and codes chosen independently by
+/// different libraries may collide. The registry makes the codes unambiguous: each range is owned
+/// by a single namespace (e.g., a library module path), ranges of different namespaces may not
+/// overlap, and named codes must lie within the ranges of their namespace. Given the registry, a
+/// code reported by a failed assertion can be decoded back into the namespace and the name of the
+/// error via [ErrorCodeRegistry::decode()].
+///
+/// The registry is debug information: it is not a part of the program MAST, and thus, does not
+/// affect the hash of the program.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ErrorCodeRegistry {
+    /// Registered ranges keyed by the first code of each range.
+    ranges: BTreeMap<u32, ErrorCodeRange>,
+}
+
+impl ErrorCodeRegistry {
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Registers the range of error codes `first..=last` as owned by the specified namespace.
+    ///
+    /// Registering the same range for the same namespace more than once has no effect.
+    ///
+    /// # Errors
+    /// Returns an error if `first` is greater than `last`, or if the range overlaps with a range
+    /// registered previously.
+    pub fn register_range(
+        &mut self,
+        namespace: &str,
+        first: u32,
+        last: u32,
+    ) -> Result<(), ErrorCodeError> {
+        if first > last {
+            return Err(ErrorCodeError::InvalidRange(first, last));
+        }
+
+        // since registered ranges do not overlap, only the last range starting before the end of
+        // the new range can overlap with it
+        if let Some((_, range)) = self.ranges.range(..=last).next_back() {
+            if range.first == first && range.last == last && range.namespace == namespace {
+                return Ok(());
+            }
+            if range.last >= first {
+                return Err(ErrorCodeError::OverlappingRanges(
+                    namespace.to_string(),
+                    range.namespace.clone(),
+                ));
+            }
+        }
+
+        self.ranges.insert(first, ErrorCodeRange::new(namespace, first, last));
+        Ok(())
+    }
+
+    /// Registers the specified name for the error code `code` of the specified namespace.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The code does not belong to any of the ranges of the namespace.
+    /// - The code has already been registered under a different name.
+    /// - The name has already been registered for a different code in the same namespace.
+    pub fn register_name(
+        &mut self,
+        namespace: &str,
+        name: &str,
+        code: u32,
+    ) -> Result<(), ErrorCodeError> {
+        if self.ranges.values().any(|range| {
+            range.namespace == namespace && range.name_code(name).is_some_and(|c| c != code)
+        }) {
+            return Err(ErrorCodeError::DuplicateName(namespace.to_string(), name.to_string()));
+        }
+
+        let range = match self.find_range_mut(code) {
+            Some(range) if range.namespace == namespace => range,
+            _ => {
+                return Err(ErrorCodeError::CodeOutOfRange(
+                    namespace.to_string(),
+                    name.to_string(),
+                    code,
+                ))
+            }
+        };
+
+        match range.names.get(&code) {
+            Some(existing) if existing != name => {
+                Err(ErrorCodeError::DuplicateCode(namespace.to_string(), code))
+            }
+            Some(_) => Ok(()),
+            None => {
+                range.names.insert(code, name.to_string());
+                Ok(())
+            }
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the namespace and the name (if one was registered) of the specified error code, or
+    /// None if the code does not belong to any of the registered ranges.
+    pub fn decode(&self, code: u32) -> Option<ErrorCodeInfo<'_>> {
+        let (_, range) = self.ranges.range(..=code).next_back()?;
+        if range.last < code {
+            return None;
+        }
+
+        Some(ErrorCodeInfo {
+            namespace: &range.namespace,
+            name: range.names.get(&code).map(String::as_str),
+        })
+    }
+
+    /// Returns an iterator over the `(namespace, first, last)` tuples describing the registered
+    /// ranges, sorted by the first code of each range.
+    pub fn ranges(&self) -> impl Iterator<Item = (&str, u32, u32)> {
+        self.ranges
+            .values()
+            .map(|range| (range.namespace.as_str(), range.first, range.last))
+    }
+
+    /// Returns true if no ranges have been registered.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the range containing the specified code, if any.
+    fn find_range_mut(&mut self, code: u32) -> Option<&mut ErrorCodeRange> {
+        let (_, range) = self.ranges.range_mut(..=code).next_back()?;
+        (range.last >= code).then_some(range)
+    }
+}
+
+impl Serializable for ErrorCodeRegistry {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.ranges.len());
+        for range in self.ranges.values() {
+            write_string(target, &range.namespace);
+            target.write_u32(range.first);
+            target.write_u32(range.last);
+            target.write_usize(range.names.len());
+            for (code, name) in range.names.iter() {
+                target.write_u32(*code);
+                write_string(target, name);
+            }
+        }
+    }
+}
+
+impl Deserializable for ErrorCodeRegistry {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut registry = Self::default();
+        let num_ranges = source.read_usize()?;
+        for _ in 0..num_ranges {
+            let namespace = read_string(source)?;
+            let first = source.read_u32()?;
+            let last = source.read_u32()?;
+            registry
+                .register_range(&namespace, first, last)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+            let num_names = source.read_usize()?;
+            for _ in 0..num_names {
+                let code = source.read_u32()?;
+                let name = read_string(source)?;
+                registry
+                    .register_name(&namespace, &name, code)
+                    .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            }
+        }
+        Ok(registry)
+    }
+}
+
+// ERROR CODE RANGE
+// ================================================================================================
+
+/// A range of error codes owned by a single namespace, together with the names of the codes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ErrorCodeRange {
+    namespace: String,
+    first: u32,
+    last: u32,
+    names: BTreeMap<u32, String>,
+}
+
+impl ErrorCodeRange {
+    fn new(namespace: &str, first: u32, last: u32) -> Self {
+        Self {
+            namespace: namespace.to_string(),
+            first,
+            last,
+            names: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the code registered under the specified name in this range, if any.
+    fn name_code(&self, name: &str) -> Option<u32> {
+        self.names.iter().find(|(_, n)| n.as_str() == name).map(|(code, _)| *code)
+    }
+}
+
+// ERROR CODE INFO
+// ================================================================================================
+
+/// Describes an error code decoded via an [ErrorCodeRegistry].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ErrorCodeInfo<'a> {
+    /// The namespace which owns the range containing the error code.
+    pub namespace: &'a str,
+    /// The name of the error code, if one was registered.
+    pub name: Option<&'a str>,
+}
+
+impl fmt::Display for ErrorCodeInfo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.name {
+            Some(name) => write!(f, "{}::{name}", self.namespace),
+            None => write!(f, "{}", self.namespace),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn write_string<W: ByteWriter>(target: &mut W, value: &str) {
+    target.write_usize(value.len());
+    target.write_bytes(value.as_bytes());
+}
+
+fn read_string<R: ByteReader>(source: &mut R) -> Result<String, DeserializationError> {
+    let len = source.read_usize()?;
+    String::from_utf8(source.read_vec(len)?)
+        .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{ErrorCodeError, ErrorCodeInfo, ErrorCodeRegistry};
+    use crate::utils::{Deserializable, Serializable};
+    use alloc::string::ToString;
+
+    #[test]
+    fn register_and_decode() {
+        let mut registry = ErrorCodeRegistry::default();
+        registry.register_range("std::math::u64", 100, 199).unwrap();
+        registry.register_range("std::crypto::hashes", 200, 299).unwrap();
+        registry.register_name("std::math::u64", "ERR_OVERFLOW", 101).unwrap();
+
+        // registering the same range or name again has no effect
+        registry.register_range("std::math::u64", 100, 199).unwrap();
+        registry.register_name("std::math::u64", "ERR_OVERFLOW", 101).unwrap();
+
+        let info = registry.decode(101).unwrap();
+        assert_eq!(
+            info,
+            ErrorCodeInfo {
+                namespace: "std::math::u64",
+                name: Some("ERR_OVERFLOW")
+            }
+        );
+        assert_eq!(info.to_string(), "std::math::u64::ERR_OVERFLOW");
+        assert_eq!(registry.decode(299).unwrap().to_string(), "std::crypto::hashes");
+        assert_eq!(registry.decode(99), None);
+        assert_eq!(registry.decode(300), None);
+
+        let bytes = registry.to_bytes();
+        assert_eq!(ErrorCodeRegistry::read_from_bytes(&bytes).unwrap(), registry);
+    }
+
+    #[test]
+    fn register_errors() {
+        let mut registry = ErrorCodeRegistry::default();
+        registry.register_range("a", 100, 199).unwrap();
+        registry.register_name("a", "ERR_X", 100).unwrap();
+
+        assert_eq!(registry.register_range("b", 10, 9), Err(ErrorCodeError::InvalidRange(10, 9)));
+        assert_eq!(
+            registry.register_range("b", 50, 100),
+            Err(ErrorCodeError::OverlappingRanges("b".into(), "a".into()))
+        );
+        assert_eq!(
+            registry.register_range("a", 150, 250),
+            Err(ErrorCodeError::OverlappingRanges("a".into(), "a".into()))
+        );
+        assert_eq!(
+            registry.register_name("b", "ERR_Y", 150),
+            Err(ErrorCodeError::CodeOutOfRange("b".into(), "ERR_Y".into(), 150))
+        );
+        assert_eq!(
+            registry.register_name("a", "ERR_Y", 100),
+            Err(ErrorCodeError::DuplicateCode("a".into(), 100))
+        );
+        assert_eq!(
+            registry.register_name("a", "ERR_X", 101),
+            Err(ErrorCodeError::DuplicateName("a".into(), "ERR_X".into()))
+        );
+    }
+}
@@ -1,9 +1,10 @@
 use super::{
     super::{ToElements, WORD_SIZE},
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Kernel, Program,
-    Serializable,
+    errors::KernelError,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Kernel,
+    KernelMetadata, Program, Serializable,
 };
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 
 // PROGRAM INFO
 // ================================================================================================
@@ -16,10 +17,23 @@ use alloc::vec::Vec;
 /// security guarantees provided by the kernel. We also allow the user to easily prove the
 /// membership of a given kernel procedure for a given proof, without compromising its
 /// zero-knowledge properties.
+///
+/// Optionally, the program info can carry [KernelMetadata] describing the kernel procedures
+/// (e.g., their names). The metadata is not a part of the public inputs, but it allows verifiers
+/// to check policies against the kernel (e.g., that a given syscall is available to the program).
+///
+/// The program info can also declare the versions of the kernel (identified by their commitments,
+/// see [Kernel::commitment()]) the program is compatible with in addition to its own kernel. This
+/// allows kernels to be upgraded without invalidating programs compiled against their earlier
+/// versions: the verifier can accept proofs generated against any of the compatible kernels (see
+/// [ProgramInfo::for_kernel()]).
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProgramInfo {
     program_hash: Digest,
     kernel: Kernel,
+    kernel_metadata: Option<KernelMetadata>,
+    compatible_kernels: Vec<Digest>,
 }
 
 impl ProgramInfo {
@@ -31,7 +45,35 @@ impl ProgramInfo {
         Self {
             program_hash,
             kernel,
+            kernel_metadata: None,
+            compatible_kernels: Vec::new(),
+        }
+    }
+
+    /// Declares the program compatible with the kernels with the specified commitments, in
+    /// addition to the kernel of this program info.
+    pub fn with_compatible_kernels<I>(mut self, commitments: I) -> Self
+    where
+        I: IntoIterator<Item = Digest>,
+    {
+        for commitment in commitments {
+            if !self.compatible_kernels.contains(&commitment) {
+                self.compatible_kernels.push(commitment);
+            }
+        }
+        self
+    }
+
+    /// Attaches the specified kernel metadata to this program info.
+    ///
+    /// # Errors
+    /// Returns an error if the metadata does not describe the kernel of this program info.
+    pub fn with_kernel_metadata(mut self, metadata: KernelMetadata) -> Result<Self, KernelError> {
+        if !metadata.describes(&self.kernel) {
+            return Err(KernelError::MetadataMismatch);
         }
+        self.kernel_metadata = Some(metadata);
+        Ok(self)
     }
 
     // PUBLIC ACCESSORS
@@ -51,6 +93,46 @@ impl ProgramInfo {
     pub fn kernel_procedures(&self) -> &[Digest] {
         self.kernel.proc_hashes()
     }
+
+    /// Returns commitments to the kernels the program is declared compatible with in addition to
+    /// the kernel of this program info.
+    pub fn compatible_kernels(&self) -> &[Digest] {
+        &self.compatible_kernels
+    }
+
+    /// Returns true if the program is compatible with the specified kernel, that is, if the kernel
+    /// is the kernel of this program info or one of the declared compatible kernels.
+    pub fn is_compatible_with(&self, kernel: &Kernel) -> bool {
+        kernel == &self.kernel || self.compatible_kernels.contains(&kernel.commitment())
+    }
+
+    /// Returns info of the same program executed against the specified kernel.
+    ///
+    /// The kernel metadata is dropped unless the kernel is the kernel of this program info, while
+    /// the declared compatible kernels are retained.
+    ///
+    /// # Errors
+    /// Returns an error if the program is not compatible with the specified kernel.
+    pub fn for_kernel(&self, kernel: Kernel) -> Result<Self, KernelError> {
+        if kernel == self.kernel {
+            return Ok(self.clone());
+        }
+        if !self.is_compatible_with(&kernel) {
+            return Err(KernelError::IncompatibleKernel(kernel.commitment()));
+        }
+        Ok(Self {
+            program_hash: self.program_hash,
+            kernel,
+            kernel_metadata: None,
+            compatible_kernels: self.compatible_kernels.clone(),
+        })
+    }
+
+    /// Returns the metadata describing the kernel procedures, if it was attached to this program
+    /// info.
+    pub fn kernel_metadata(&self) -> Option<&KernelMetadata> {
+        self.kernel_metadata.as_ref()
+    }
 }
 
 impl From<Program> for ProgramInfo {
@@ -61,6 +143,8 @@ impl From<Program> for ProgramInfo {
         Self {
             program_hash,
             kernel,
+            kernel_metadata: None,
+            compatible_kernels: Vec::new(),
         }
     }
 }
@@ -72,16 +156,42 @@ impl Serializable for ProgramInfo {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.program_hash.write_into(target);
         self.kernel.write_into(target);
+        target.write_bool(self.kernel_metadata.is_some());
+        if let Some(metadata) = &self.kernel_metadata {
+            metadata.write_into(target);
+        }
+        target.write_usize(self.compatible_kernels.len());
+        target.write_many(&self.compatible_kernels);
     }
 }
 
 impl Deserializable for ProgramInfo {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let program_hash = source.read()?;
-        let kernel = source.read()?;
+        let kernel: Kernel = source.read()?;
+        let kernel_metadata = if source.read_bool()? {
+            let metadata: KernelMetadata = source.read()?;
+            if !metadata.describes(&kernel) {
+                return Err(DeserializationError::InvalidValue(
+                    KernelError::MetadataMismatch.to_string(),
+                ));
+            }
+            Some(metadata)
+        } else {
+            None
+        };
+        // the number of compatible kernels is not used to pre-allocate memory, as it cannot be
+        // trusted
+        let num_compatible_kernels = source.read_usize()?;
+        let mut compatible_kernels = Vec::new();
+        for _ in 0..num_compatible_kernels {
+            compatible_kernels.push(source.read()?);
+        }
         Ok(Self {
             program_hash,
             kernel,
+            kernel_metadata,
+            compatible_kernels,
         })
     }
 }
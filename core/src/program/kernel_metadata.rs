@@ -0,0 +1,159 @@
+use super::{
+    errors::KernelError, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest,
+    Kernel, Serializable,
+};
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+// KERNEL METADATA
+// ================================================================================================
+
+/// Description of the procedures exported from a kernel (i.e., of the procedures which can be
+/// invoked via `syscall` instructions).
+///
+/// Procedures are listed in the same order as in the [Kernel] they describe, that is, in the order
+/// of their digests. Names of the procedures are available only if the metadata was built while
+/// compiling the kernel from source (see `Assembler::kernel_metadata()`); metadata built from a
+/// [Kernel] contains digests only.
+///
+/// The metadata is not a part of the public inputs of a proof, and thus, names of procedures are
+/// not bound to the proof. However, [ProgramInfo::with_kernel_metadata()](super::ProgramInfo)
+/// makes sure that the digests match the kernel, which allows verifiers to enforce policies on
+/// the syscalls available to a program (e.g., to require a kernel to export a given procedure).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KernelMetadata {
+    procedures: Vec<KernelProcedure>,
+}
+
+impl KernelMetadata {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns metadata describing the procedures with the specified digests and names.
+    ///
+    /// # Errors
+    /// Returns an error if the procedures do not form a valid kernel.
+    pub fn new<I>(procedures: I) -> Result<Self, KernelError>
+    where
+        I: IntoIterator<Item = (Digest, Option<String>)>,
+    {
+        let mut procedures = procedures
+            .into_iter()
+            .map(|(digest, name)| KernelProcedure { digest, name })
+            .collect::<Vec<_>>();
+
+        // make sure the procedures form a valid kernel, and list them in the order of the kernel
+        let digests = procedures.iter().map(|proc| proc.digest).collect::<Vec<_>>();
+        Kernel::new(&digests)?;
+        procedures.sort_by_key(|proc| proc.digest.as_bytes());
+
+        Ok(Self { procedures })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the procedures exported from the kernel.
+    pub fn procedures(&self) -> &[KernelProcedure] {
+        &self.procedures
+    }
+
+    /// Returns the digest of the procedure with the specified name, if the kernel exports it.
+    pub fn get_digest(&self, name: &str) -> Option<Digest> {
+        self.procedures
+            .iter()
+            .find(|proc| proc.name.as_deref() == Some(name))
+            .map(|proc| proc.digest)
+    }
+
+    /// Returns the name of the procedure with the specified digest, if the kernel exports it and
+    /// its name is known.
+    pub fn get_name(&self, digest: &Digest) -> Option<&str> {
+        self.procedures.iter().find(|proc| &proc.digest == digest)?.name()
+    }
+
+    /// Returns the kernel consisting of the procedures described by this metadata.
+    pub fn to_kernel(&self) -> Kernel {
+        Kernel(self.procedures.iter().map(|proc| proc.digest).collect())
+    }
+
+    /// Returns true if this metadata describes the specified kernel.
+    pub fn describes(&self, kernel: &Kernel) -> bool {
+        self.procedures.iter().map(|proc| &proc.digest).eq(kernel.proc_hashes())
+    }
+}
+
+impl From<&Kernel> for KernelMetadata {
+    fn from(kernel: &Kernel) -> Self {
+        let procedures = kernel
+            .proc_hashes()
+            .iter()
+            .map(|&digest| KernelProcedure { digest, name: None })
+            .collect();
+        Self { procedures }
+    }
+}
+
+// KERNEL PROCEDURE
+// ================================================================================================
+
+/// Description of a single procedure exported from a kernel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KernelProcedure {
+    digest: Digest,
+    name: Option<String>,
+}
+
+impl KernelProcedure {
+    /// Returns the MAST root of the procedure, which is the target of `syscall` instructions.
+    pub fn digest(&self) -> &Digest {
+        &self.digest
+    }
+
+    /// Returns the name of the procedure, if known.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for KernelMetadata {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.procedures.len());
+        for proc in self.procedures.iter() {
+            proc.digest.write_into(target);
+            target.write_bool(proc.name.is_some());
+            if let Some(name) = &proc.name {
+                target.write_usize(name.len());
+                target.write_bytes(name.as_bytes());
+            }
+        }
+    }
+}
+
+impl Deserializable for KernelMetadata {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_procedures = source.read_usize()?;
+        let mut procedures = Vec::new();
+        for _ in 0..num_procedures {
+            let digest = Digest::read_from(source)?;
+            let name = if source.read_bool()? {
+                let len = source.read_usize()?;
+                let name = String::from_utf8(source.read_vec(len)?)
+                    .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+                Some(name)
+            } else {
+                None
+            };
+            procedures.push((digest, name));
+        }
+
+        Self::new(procedures).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
@@ -3,15 +3,30 @@ use super::{
     errors, Felt, Operation,
 };
 use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
 use core::fmt;
 
 pub mod blocks;
 use blocks::CodeBlock;
 
+mod error_codes;
+pub use error_codes::{ErrorCodeInfo, ErrorCodeRegistry};
+
 mod info;
 pub use info::ProgramInfo;
 
+mod kernel_metadata;
+pub use kernel_metadata::{KernelMetadata, KernelProcedure};
+
+mod package;
+pub use package::ProgramPackage;
+
+mod store;
+pub use store::MastStore;
+
 #[cfg(test)]
 mod tests;
 
@@ -27,6 +42,7 @@ pub struct Program {
     root: CodeBlock,
     kernel: Kernel,
     cb_table: CodeBlockTable,
+    error_codes: ErrorCodeRegistry,
 }
 
 impl Program {
@@ -43,9 +59,40 @@ impl Program {
             root,
             kernel,
             cb_table,
+            error_codes: ErrorCodeRegistry::default(),
         }
     }
 
+    /// Attaches the specified error code registry to this program.
+    ///
+    /// The registry is used only to decode error codes of failed assertions, and does not affect
+    /// the hash of the program.
+    pub fn with_error_codes(mut self, error_codes: ErrorCodeRegistry) -> Self {
+        self.error_codes = error_codes;
+        self
+    }
+
+    /// Replaces the kernel of this program with the specified kernel.
+    ///
+    /// This allows executing a program against a kernel other than the one it was compiled
+    /// against (e.g., an upgraded version of the kernel) without recompiling the program. Since
+    /// the hash of a program does not depend on its kernel, the hash remains unchanged.
+    ///
+    /// # Errors
+    /// Returns an error if the program invokes a procedure via a `syscall` which the specified
+    /// kernel does not contain.
+    pub fn replace_kernel(mut self, kernel: Kernel) -> Result<Self, errors::KernelError> {
+        if let Some(&proc_hash) = self
+            .syscall_targets()
+            .iter()
+            .find(|&&proc_hash| !kernel.contains_proc(proc_hash))
+        {
+            return Err(errors::KernelError::MissingProcedure(proc_hash));
+        }
+        self.kernel = kernel;
+        Ok(self)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -68,6 +115,49 @@ impl Program {
     pub fn cb_table(&self) -> &CodeBlockTable {
         &self.cb_table
     }
+
+    /// Returns the error code registry of this program.
+    pub fn error_codes(&self) -> &ErrorCodeRegistry {
+        &self.error_codes
+    }
+
+    /// Returns hashes of the kernel procedures invoked by this program via `syscall` instructions,
+    /// in the order of their hashes.
+    ///
+    /// Code blocks of procedures invoked via `call` instructions are searched as well, as long as
+    /// they are in the code block table of this program. Targets of dynamic syscalls are not known
+    /// until the program is executed, and thus, are not included.
+    pub fn syscall_targets(&self) -> Vec<Digest> {
+        let mut targets = BTreeSet::new();
+        let mut visited = BTreeSet::new();
+        let mut blocks = vec![&self.root];
+        while let Some(block) = blocks.pop() {
+            match block {
+                CodeBlock::Join(block) => blocks.extend([block.first(), block.second()]),
+                CodeBlock::Split(block) => blocks.extend([block.on_true(), block.on_false()]),
+                CodeBlock::Loop(block) => blocks.push(block.body()),
+                CodeBlock::Call(block) if block.is_dynamic() => (),
+                CodeBlock::Call(block) if block.is_syscall() => {
+                    targets.insert(block.fn_hash());
+                }
+                CodeBlock::Call(block) => {
+                    if visited.insert(block.fn_hash()) {
+                        blocks.extend(self.cb_table.get(block.fn_hash()));
+                    }
+                }
+                CodeBlock::Span(_) | CodeBlock::Dyn(_) | CodeBlock::Proxy(_) => (),
+            }
+        }
+        targets.into_iter().collect()
+    }
+
+    /// Returns true if this program can be executed against the specified kernel, that is, if
+    /// the kernel contains all procedures invoked by this program via `syscall` instructions.
+    pub fn is_compatible_with(&self, kernel: &Kernel) -> bool {
+        self.syscall_targets()
+            .into_iter()
+            .all(|proc_hash| kernel.contains_proc(proc_hash))
+    }
 }
 
 impl fmt::Display for Program {
@@ -76,6 +166,25 @@ impl fmt::Display for Program {
     }
 }
 
+impl Serializable for Program {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.root.write_into(target);
+        self.kernel.write_into(target);
+        self.cb_table.write_into(target);
+        self.error_codes.write_into(target);
+    }
+}
+
+impl Deserializable for Program {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let root = CodeBlock::read_from(source)?;
+        let kernel = Kernel::read_from(source)?;
+        let cb_table = CodeBlockTable::read_from(source)?;
+        let error_codes = ErrorCodeRegistry::read_from(source)?;
+        Ok(Self::with_kernel(root, kernel, cb_table).with_error_codes(error_codes))
+    }
+}
+
 // CODE BLOCK TABLE
 // ================================================================================================
 
@@ -84,6 +193,9 @@ impl fmt::Display for Program {
 /// This table is used to hold code blocks which are referenced from the program MAST but are
 /// actually not a part of the MAST itself. Thus, for example, multiple nodes in the MAST can
 /// reference the same code block in the table.
+///
+/// The table is the simplest [MastStore]; its blocks can be enumerated via [CodeBlockTable::iter()]
+/// to move them into a different store.
 #[derive(Clone, Debug, Default)]
 pub struct CodeBlockTable(BTreeMap<[u8; 32], CodeBlock>);
 
@@ -111,6 +223,37 @@ impl CodeBlockTable {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Returns the number of code blocks in this table.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns an iterator over the hashes of the code blocks in this table and the code blocks
+    /// themselves, ordered by the hashes.
+    pub fn iter(&self) -> impl Iterator<Item = (Digest, &CodeBlock)> {
+        self.0.values().map(|block| (block.hash(), block))
+    }
+}
+
+impl Serializable for CodeBlockTable {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.0.len());
+        for block in self.0.values() {
+            block.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for CodeBlockTable {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut table = Self::default();
+        let num_blocks = source.read_usize()?;
+        for _ in 0..num_blocks {
+            table.insert(CodeBlock::read_from(source)?);
+        }
+        Ok(table)
+    }
 }
 
 // KERNEL
@@ -149,6 +292,20 @@ impl Kernel {
         self.0.is_empty()
     }
 
+    /// Returns the number of procedures in this kernel.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns a commitment to this kernel, computed as a hash of the hashes of its procedures.
+    ///
+    /// The commitment identifies a specific version of a kernel, and is used by programs to
+    /// declare the kernels they are compatible with (see [ProgramInfo::with_compatible_kernels()]).
+    pub fn commitment(&self) -> Digest {
+        let elements = self.0.iter().flat_map(|proc_hash| proc_hash.as_elements()).copied();
+        hasher::hash_elements(&elements.collect::<Vec<_>>())
+    }
+
     /// Returns true if a procedure with the specified hash belongs to this kernel.
     pub fn contains_proc(&self, proc_hash: Digest) -> bool {
         // linear search here is OK because we expect the kernels to have a relatively small number
@@ -184,3 +341,18 @@ impl Deserializable for Kernel {
         Ok(Self(kernel))
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Kernel {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Kernel {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let proc_hashes = Vec::<Digest>::deserialize(deserializer)?;
+        Self::new(&proc_hashes).map_err(serde::de::Error::custom)
+    }
+}
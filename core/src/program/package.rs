@@ -0,0 +1,88 @@
+use super::{ByteReader, ByteWriter, Deserializable, DeserializationError, Program, Serializable};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+
+// PROGRAM PACKAGE
+// ================================================================================================
+
+/// A set of programs compiled from the same source, each of which is an entrypoint of the package.
+///
+/// Entrypoints are identified by name, and each of them is a standalone [Program] with its own
+/// MAST root. Entrypoints share procedure definitions, and thus, a single package can expose
+/// multiple commands without maintaining a separate program source for each of them. Any of the
+/// entrypoints can be executed and proven independently from the others.
+#[derive(Clone, Debug, Default)]
+pub struct ProgramPackage {
+    entrypoints: BTreeMap<String, Program>,
+}
+
+impl ProgramPackage {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [ProgramPackage] instantiated from the specified entrypoints.
+    pub fn new(entrypoints: BTreeMap<String, Program>) -> Self {
+        Self { entrypoints }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the entrypoint with the specified name, or None if the package does not contain an
+    /// entrypoint with this name.
+    pub fn entrypoint(&self, name: &str) -> Option<&Program> {
+        self.entrypoints.get(name)
+    }
+
+    /// Returns an iterator over the (name, program) pairs of all entrypoints of this package,
+    /// sorted by name.
+    pub fn entrypoints(&self) -> impl Iterator<Item = (&str, &Program)> {
+        self.entrypoints.iter().map(|(name, program)| (name.as_str(), program))
+    }
+
+    /// Returns an iterator over the names of all entrypoints of this package, sorted by name.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entrypoints.keys().map(String::as_str)
+    }
+
+    /// Returns the number of entrypoints in this package.
+    pub fn len(&self) -> usize {
+        self.entrypoints.len()
+    }
+
+    /// Returns true if this package does not contain any entrypoints.
+    pub fn is_empty(&self) -> bool {
+        self.entrypoints.is_empty()
+    }
+}
+
+impl Serializable for ProgramPackage {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.entrypoints.len());
+        for (name, program) in self.entrypoints.iter() {
+            target.write_usize(name.len());
+            target.write_bytes(name.as_bytes());
+            program.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for ProgramPackage {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_entrypoints = source.read_usize()?;
+        let mut entrypoints = BTreeMap::new();
+        for _ in 0..num_entrypoints {
+            let name_len = source.read_usize()?;
+            let name = String::from_utf8(source.read_vec(name_len)?)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            let program = Program::read_from(source)?;
+            if entrypoints.insert(name, program).is_some() {
+                return Err(DeserializationError::InvalidValue(
+                    "duplicate package entrypoint".into(),
+                ));
+            }
+        }
+        Ok(Self { entrypoints })
+    }
+}
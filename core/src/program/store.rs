@@ -0,0 +1,24 @@
+use super::{CodeBlock, CodeBlockTable, Digest};
+
+// MAST STORE
+// ================================================================================================
+
+/// A source of MAST nodes which can be looked up by their digests.
+///
+/// MAST nodes of called procedures (i.e., procedures invoked via `call`, `syscall`, `dyncall` or
+/// `dynexec` instructions) are referenced from the program MAST by their digests only. Thus, such
+/// nodes do not need to be a part of a program: they can be kept in a store (e.g., a database
+/// holding compiled libraries, with every node serialized via
+/// [Serializable](crate::utils::Serializable) and keyed by its digest), and loaded only when a
+/// program actually calls them.
+pub trait MastStore {
+    /// Returns the code block with the specified digest, or None if the block is not in this
+    /// store.
+    fn get_code_block(&self, digest: Digest) -> Option<CodeBlock>;
+}
+
+impl MastStore for CodeBlockTable {
+    fn get_code_block(&self, digest: Digest) -> Option<CodeBlock> {
+        self.get(digest).cloned()
+    }
+}
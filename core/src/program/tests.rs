@@ -1,6 +1,10 @@
-use super::{blocks::Dyn, Deserializable, Digest, Felt, Kernel, ProgramInfo, Serializable};
-use crate::{chiplets::hasher, Word};
-use alloc::vec::Vec;
+use super::{
+    blocks::{CodeBlock, Dyn},
+    CodeBlockTable, Deserializable, Digest, Felt, Kernel, KernelMetadata, Program, ProgramInfo,
+    ProgramPackage, Serializable,
+};
+use crate::{chiplets::hasher, Operation, Word};
+use alloc::{string::ToString, vec::Vec};
 use proptest::prelude::*;
 use rand_utils::prng_array;
 
@@ -11,6 +15,100 @@ fn dyn_hash_is_correct() {
     assert_eq!(expected_constant, Dyn::new().hash());
 }
 
+#[test]
+fn program_serialization() {
+    let callee = CodeBlock::new_span(vec![Operation::Push(Felt::new(7)), Operation::Mul]);
+    let mut cb_table = CodeBlockTable::default();
+    cb_table.insert(callee.clone());
+
+    let root = CodeBlock::new_join([
+        CodeBlock::new_span(vec![Operation::Pad, Operation::Incr]),
+        CodeBlock::new_call(callee.hash()),
+    ]);
+    let kernel = Kernel::new(&[digest_from_seed([5; 32])]).unwrap();
+    let program = Program::with_kernel(root, kernel, cb_table);
+
+    let deser = Program::read_from_bytes(&program.to_bytes()).unwrap();
+    assert_eq!(program.hash(), deser.hash());
+    assert_eq!(program.kernel(), deser.kernel());
+    assert!(deser.cb_table().has(callee.hash()));
+}
+
+#[test]
+fn program_package_serialization() {
+    let first = Program::new(CodeBlock::new_span(vec![Operation::Pad, Operation::Incr]));
+    let second = Program::new(CodeBlock::new_span(vec![Operation::Pad, Operation::Neg]));
+    let entrypoints = [("first".to_string(), first), ("second".to_string(), second)];
+    let package = ProgramPackage::new(entrypoints.into_iter().collect());
+
+    let bytes = package.to_bytes();
+    let deser = ProgramPackage::read_from_bytes(&bytes).unwrap();
+    assert_eq!(deser.names().collect::<Vec<_>>(), ["first", "second"]);
+    for (name, program) in package.entrypoints() {
+        assert_eq!(deser.entrypoint(name).unwrap().hash(), program.hash());
+    }
+
+    // truncated packages cannot be deserialized
+    assert!(ProgramPackage::read_from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn program_info_kernel_metadata() {
+    let foo = digest_from_seed([1; 32]);
+    let bar = digest_from_seed([2; 32]);
+    let metadata =
+        KernelMetadata::new([(foo, Some("foo".to_string())), (bar, Some("bar".to_string()))])
+            .unwrap();
+    let kernel = Kernel::new(&[bar, foo]).unwrap();
+    assert_eq!(kernel, metadata.to_kernel());
+    assert_eq!(Some(foo), metadata.get_digest("foo"));
+    assert_eq!(Some("bar"), metadata.get_name(&bar));
+    assert_eq!(None, metadata.get_digest("baz"));
+
+    // metadata built from a kernel carries no names
+    let unnamed = KernelMetadata::from(&kernel);
+    assert!(unnamed.describes(&kernel));
+    assert_eq!(None, unnamed.get_name(&foo));
+
+    // metadata is attached only if it describes the kernel of the program info
+    let program_hash = digest_from_seed([3; 32]);
+    let program_info = ProgramInfo::new(program_hash, kernel.clone())
+        .with_kernel_metadata(metadata.clone())
+        .unwrap();
+    assert_eq!(Some(&metadata), program_info.kernel_metadata());
+    let other_kernel = Kernel::new(&[foo]).unwrap();
+    assert!(ProgramInfo::new(program_hash, other_kernel)
+        .with_kernel_metadata(metadata)
+        .is_err());
+
+    // metadata is serialized with the program info, but does not affect its public inputs
+    let deser = ProgramInfo::read_from_bytes(&program_info.to_bytes()).unwrap();
+    assert_eq!(program_info, deser);
+    assert_eq!(
+        crate::ToElements::to_elements(&ProgramInfo::new(program_hash, kernel)),
+        crate::ToElements::to_elements(&program_info)
+    );
+}
+
+#[test]
+fn program_info_truncated_compatible_kernels() {
+    use crate::utils::ByteWriter;
+
+    let program_info = ProgramInfo::new(digest_from_seed([3; 32]), Kernel::default())
+        .with_compatible_kernels([digest_from_seed([4; 32])]);
+    let deser = ProgramInfo::read_from_bytes(&program_info.to_bytes()).unwrap();
+    assert_eq!(program_info, deser);
+
+    // a truncated input declaring a huge number of compatible kernels is rejected without
+    // allocating memory for them
+    // the empty list of compatible kernels is encoded as a single zero length byte
+    let mut bytes = ProgramInfo::new(digest_from_seed([3; 32]), Kernel::default()).to_bytes();
+    bytes.pop();
+    bytes.write_usize(usize::MAX);
+    bytes.extend_from_slice(&digest_from_seed([4; 32]).to_bytes());
+    assert!(ProgramInfo::read_from_bytes(&bytes).is_err());
+}
+
 proptest! {
     #[test]
     fn arbitrary_program_info_serialization_works(
@@ -32,6 +130,22 @@ proptest! {
     }
 }
 
+#[cfg(feature = "serde")]
+#[test]
+fn program_info_serde_roundtrip() {
+    let kernel = Kernel::new(&[digest_from_seed([1; 32]), digest_from_seed([2; 32])]).unwrap();
+    let program_info = ProgramInfo::new(digest_from_seed([3; 32]), kernel);
+
+    let json = serde_json::to_string(&program_info).unwrap();
+    let deser: ProgramInfo = serde_json::from_str(&json).unwrap();
+    assert_eq!(program_info, deser);
+
+    // kernels with duplicated procedures are rejected
+    let proc_hash = serde_json::to_value(digest_from_seed([1; 32])).unwrap();
+    let kernel = serde_json::Value::Array(vec![proc_hash.clone(), proc_hash]);
+    assert!(serde_json::from_value::<Kernel>(kernel).is_err());
+}
+
 // HELPER FUNCTIONS
 // --------------------------------------------------------------------------------------------
 
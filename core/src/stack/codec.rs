@@ -0,0 +1,310 @@
+use alloc::vec::Vec;
+
+use super::{Felt, OutputError};
+use crate::Word;
+
+// STACK ENCODING
+// ================================================================================================
+
+/// A value which can be encoded into stack elements.
+///
+/// Values are encoded as follows, where the first element of an encoding is placed closest to the
+/// top of the stack:
+/// - A [Felt] is encoded as a single element.
+/// - `bool`, `u8`, `u16` and `u32` values are encoded as a single element.
+/// - A `u64` value is encoded as two 32-bit limbs, with the high limb first. This matches the
+///   representation used by `std::math::u64`.
+/// - A `[u8; N]` array is encoded as `ceil(N / 4)` 32-bit limbs, with each limb holding 4 bytes in
+///   little-endian order, and the last limb padded with zeros. This matches the representation of
+///   digests used by `std::crypto::hashes::blake3`.
+/// - A [Word] `[a, b, c, d]` is encoded as `[d, c, b, a]`, i.e., in the same way as it is placed
+///   on the stack by `mem_loadw`.
+/// - A `Vec<T>` is encoded as the number of items followed by the encodings of the items.
+/// - A tuple is encoded as the encodings of its fields in order.
+pub trait StackEncode {
+    /// Appends the elements encoding this value to `target`.
+    fn encode_into(&self, target: &mut Vec<Felt>);
+
+    /// Returns the elements encoding this value.
+    fn to_stack_elements(&self) -> Vec<Felt> {
+        let mut result = Vec::new();
+        self.encode_into(&mut result);
+        result
+    }
+}
+
+/// A value which can be decoded from stack elements.
+///
+/// See [StackEncode] for the description of the encoding of each type.
+pub trait StackDecode: Sized {
+    /// Reads the elements encoding a value of this type from `source`, and returns the value.
+    ///
+    /// # Errors
+    /// Returns an error if `source` does not contain enough elements, or if the elements do not
+    /// encode a valid value of this type.
+    fn decode_from(source: &mut StackReader) -> Result<Self, OutputError>;
+}
+
+// STACK READER
+// ================================================================================================
+
+/// A reader of stack elements, which reads the elements starting from the top of the stack.
+pub struct StackReader<'a> {
+    elements: &'a [Felt],
+    position: usize,
+}
+
+impl<'a> StackReader<'a> {
+    /// Returns a new reader of the specified elements, where the first element is at the top of
+    /// the stack.
+    pub fn new(elements: &'a [Felt]) -> Self {
+        Self {
+            elements,
+            position: 0,
+        }
+    }
+
+    /// Returns the position of the next element to be read.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the next element.
+    ///
+    /// # Errors
+    /// Returns an error if all elements have been read.
+    pub fn read_element(&mut self) -> Result<Felt, OutputError> {
+        let element = self
+            .elements
+            .get(self.position)
+            .copied()
+            .ok_or(OutputError::NotEnoughOutputs(self.position + 1, self.elements.len()))?;
+        self.position += 1;
+        Ok(element)
+    }
+
+    /// Returns the next element as an integer smaller than `2^bits`.
+    ///
+    /// # Errors
+    /// Returns an error if all elements have been read, or if the next element is not smaller
+    /// than `2^bits`, in which case the error refers to the value as `name`.
+    fn read_uint(&mut self, bits: u32, name: &'static str) -> Result<u64, OutputError> {
+        let value = self.read_element()?.as_int();
+        if value >> bits != 0 {
+            return Err(OutputError::InvalidStackValue(self.position - 1, name));
+        }
+        Ok(value)
+    }
+}
+
+// ENCODING IMPLEMENTATIONS
+// ================================================================================================
+
+impl StackEncode for Felt {
+    fn encode_into(&self, target: &mut Vec<Felt>) {
+        target.push(*self);
+    }
+}
+
+impl StackDecode for Felt {
+    fn decode_from(source: &mut StackReader) -> Result<Self, OutputError> {
+        source.read_element()
+    }
+}
+
+impl StackEncode for bool {
+    fn encode_into(&self, target: &mut Vec<Felt>) {
+        target.push(Felt::from(*self as u32));
+    }
+}
+
+impl StackDecode for bool {
+    fn decode_from(source: &mut StackReader) -> Result<Self, OutputError> {
+        Ok(source.read_uint(1, "bool")? == 1)
+    }
+}
+
+macro_rules! impl_uint_codec {
+    ($type:ty, $bits:expr) => {
+        impl StackEncode for $type {
+            fn encode_into(&self, target: &mut Vec<Felt>) {
+                target.push(Felt::from(*self));
+            }
+        }
+
+        impl StackDecode for $type {
+            fn decode_from(source: &mut StackReader) -> Result<Self, OutputError> {
+                Ok(source.read_uint($bits, stringify!($type))? as $type)
+            }
+        }
+    };
+}
+
+impl_uint_codec!(u8, 8);
+impl_uint_codec!(u16, 16);
+impl_uint_codec!(u32, 32);
+
+impl StackEncode for u64 {
+    fn encode_into(&self, target: &mut Vec<Felt>) {
+        target.push(Felt::from((self >> 32) as u32));
+        target.push(Felt::from(*self as u32));
+    }
+}
+
+impl StackDecode for u64 {
+    fn decode_from(source: &mut StackReader) -> Result<Self, OutputError> {
+        let hi = source.read_uint(32, "u64 limb")?;
+        let lo = source.read_uint(32, "u64 limb")?;
+        Ok((hi << 32) | lo)
+    }
+}
+
+impl<const N: usize> StackEncode for [u8; N] {
+    fn encode_into(&self, target: &mut Vec<Felt>) {
+        for chunk in self.chunks(4) {
+            let mut limb = [0; 4];
+            limb[..chunk.len()].copy_from_slice(chunk);
+            target.push(Felt::from(u32::from_le_bytes(limb)));
+        }
+    }
+}
+
+impl<const N: usize> StackDecode for [u8; N] {
+    fn decode_from(source: &mut StackReader) -> Result<Self, OutputError> {
+        let mut result = [0; N];
+        for chunk in result.chunks_mut(4) {
+            let limb = (source.read_uint(32, "byte limb")? as u32).to_le_bytes();
+            if limb[chunk.len()..].iter().any(|&byte| byte != 0) {
+                return Err(OutputError::InvalidStackValue(source.position() - 1, "byte limb"));
+            }
+            chunk.copy_from_slice(&limb[..chunk.len()]);
+        }
+        Ok(result)
+    }
+}
+
+impl StackEncode for Word {
+    fn encode_into(&self, target: &mut Vec<Felt>) {
+        target.extend(self.iter().rev());
+    }
+}
+
+impl StackDecode for Word {
+    fn decode_from(source: &mut StackReader) -> Result<Self, OutputError> {
+        let mut result = [Felt::default(); 4];
+        for element in result.iter_mut().rev() {
+            *element = source.read_element()?;
+        }
+        Ok(result)
+    }
+}
+
+impl<T: StackEncode> StackEncode for Vec<T> {
+    fn encode_into(&self, target: &mut Vec<Felt>) {
+        target.push(Felt::new(self.len() as u64));
+        for item in self {
+            item.encode_into(target);
+        }
+    }
+}
+
+impl<T: StackDecode> StackDecode for Vec<T> {
+    fn decode_from(source: &mut StackReader) -> Result<Self, OutputError> {
+        let len = source.read_uint(32, "length")? as usize;
+        // every item is encoded with at least one element, which bounds the allocation
+        let remaining = source.elements.len() - source.position;
+        if len > remaining {
+            return Err(OutputError::NotEnoughOutputs(
+                source.position + len,
+                source.elements.len(),
+            ));
+        }
+        (0..len).map(|_| T::decode_from(source)).collect()
+    }
+}
+
+macro_rules! impl_tuple_codec {
+    ($($name:ident),+) => {
+        impl<$($name: StackEncode),+> StackEncode for ($($name,)+) {
+            #[allow(non_snake_case)]
+            fn encode_into(&self, target: &mut Vec<Felt>) {
+                let ($($name,)+) = self;
+                $($name.encode_into(target);)+
+            }
+        }
+
+        impl<$($name: StackDecode),+> StackDecode for ($($name,)+) {
+            fn decode_from(source: &mut StackReader) -> Result<Self, OutputError> {
+                Ok(($($name::decode_from(source)?,)+))
+            }
+        }
+    };
+}
+
+impl_tuple_codec!(A);
+impl_tuple_codec!(A, B);
+impl_tuple_codec!(A, B, C);
+impl_tuple_codec!(A, B, C, D);
+impl_tuple_codec!(A, B, C, D, E);
+impl_tuple_codec!(A, B, C, D, E, F);
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Felt, OutputError, StackDecode, StackEncode, StackReader, Word};
+    use crate::{StackInputs, StackOutputs, ONE, ZERO};
+    use alloc::vec::Vec;
+
+    #[test]
+    fn encode_decode_roundtrip() {
+        let value: (u64, [u8; 32], Vec<Felt>, bool, Word) = (
+            u64::MAX - 5,
+            [7; 32],
+            vec![ONE, Felt::new(2), Felt::new(3)],
+            true,
+            [ONE, Felt::new(2), Felt::new(3), Felt::new(4)],
+        );
+        let elements = value.to_stack_elements();
+        assert_eq!(elements.len(), 2 + 8 + 4 + 1 + 4);
+        assert_eq!(&elements[..2], &[Felt::from(u32::MAX), Felt::from(u32::MAX - 5)]);
+
+        let decoded = StackDecode::decode_from(&mut StackReader::new(&elements)).unwrap();
+        assert_eq!(value, decoded);
+
+        // values are decoded from the stack left by a program in the same way; the value must fit
+        // into the top of the stack as there are no overflow addresses
+        let value = (value.0, value.2, value.3, value.4);
+        let inputs = StackInputs::encode(&value).unwrap();
+        let outputs = StackOutputs::new(inputs.values().to_vec(), vec![]).unwrap();
+        assert_eq!(outputs.decode::<(u64, Vec<Felt>, bool, Word)>().unwrap(), value);
+    }
+
+    #[test]
+    fn encode_bytes() {
+        let elements = [1u8, 2, 3, 4, 5, 6].to_stack_elements();
+        assert_eq!(elements, vec![Felt::new(0x04030201), Felt::new(0x0605)]);
+
+        // padding bytes must be zeros
+        let result = <[u8; 6]>::decode_from(&mut StackReader::new(&[ONE, Felt::new(1 << 16)]));
+        assert_eq!(result, Err(OutputError::InvalidStackValue(1, "byte limb")));
+    }
+
+    #[test]
+    fn decode_errors() {
+        let elements = [Felt::new(256), ZERO];
+        let result = u8::decode_from(&mut StackReader::new(&elements));
+        assert_eq!(result, Err(OutputError::InvalidStackValue(0, "u8")));
+
+        let result = <(u32, u64)>::decode_from(&mut StackReader::new(&elements));
+        assert_eq!(result, Err(OutputError::NotEnoughOutputs(3, 2)));
+
+        let result = bool::decode_from(&mut StackReader::new(&[Felt::new(2)]));
+        assert_eq!(result, Err(OutputError::InvalidStackValue(0, "bool")));
+
+        // the length of a vector cannot exceed the number of remaining elements
+        let result = Vec::<Felt>::decode_from(&mut StackReader::new(&[Felt::new(5), ONE]));
+        assert_eq!(result, Err(OutputError::NotEnoughOutputs(6, 2)));
+    }
+}
@@ -1,8 +1,11 @@
 use alloc::vec::Vec;
 
-use crate::utils::{ByteReader, Deserializable, DeserializationError};
+use crate::{
+    chiplets::hasher::{self, Digest},
+    utils::{ByteReader, Deserializable, DeserializationError},
+};
 
-use super::{ByteWriter, Felt, InputError, Serializable, ToElements};
+use super::{ByteWriter, Felt, InputError, Serializable, StackEncode, ToElements, STACK_TOP_SIZE};
 use core::slice;
 
 // STACK INPUTS
@@ -57,6 +60,18 @@ impl StackInputs {
         Self::new(values)
     }
 
+    /// Returns [StackInputs] which place the encoding of the specified value at the top of the
+    /// stack, such that the value can be read by the program as described by [StackEncode].
+    ///
+    /// # Errors
+    /// Returns an error if the number of encoded elements exceeds the allowed maximum.
+    pub fn encode<T: StackEncode>(value: &T) -> Result<Self, InputError> {
+        let mut values = value.to_stack_elements();
+        // the first element of the encoding must be at the top of the stack
+        values.reverse();
+        Self::new(values)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -64,6 +79,33 @@ impl StackInputs {
     pub fn values(&self) -> &[Felt] {
         &self.values
     }
+
+    /// Returns the initial values of the top 16 stack slots in stack/reversed order.
+    ///
+    /// If fewer than 16 values were provided, only the provided values are returned.
+    pub fn top(&self) -> &[Felt] {
+        &self.values[..self.values.len().min(STACK_TOP_SIZE)]
+    }
+
+    /// Returns the initial stack values beyond the top 16 stack slots in stack/reversed order.
+    ///
+    /// These values are placed into the stack overflow table before execution begins.
+    pub fn overflow(&self) -> &[Felt] {
+        &self.values[self.top().len()..]
+    }
+
+    /// Returns a commitment to the initial stack values beyond the top 16 stack slots, or None if
+    /// all values fit into the top 16 stack slots.
+    ///
+    /// The commitment is the RPO hash of [StackInputs::overflow()]. It is included into the
+    /// public inputs of a proof instead of the values themselves, so that the size of the public
+    /// inputs does not depend on the number of stack inputs.
+    pub fn overflow_commitment(&self) -> Option<Digest> {
+        match self.overflow().is_empty() {
+            true => None,
+            false => Some(hasher::hash_elements(self.overflow())),
+        }
+    }
 }
 
 impl<'a> IntoIterator for &'a StackInputs {
@@ -119,3 +161,44 @@ impl Deserializable for StackInputs {
         Ok(StackInputs { values })
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for StackInputs {
+    /// Serializes the inputs as a sequence of values in the order accepted by [StackInputs::new()]
+    /// (i.e., with the value at the top of the stack last).
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.values.iter().rev())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for StackInputs {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<Felt>::deserialize(deserializer)?;
+        Self::new(values).map_err(serde::de::Error::custom)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Felt, StackInputs, STACK_TOP_SIZE};
+    use crate::chiplets::hasher;
+
+    #[test]
+    fn overflow_commitment() {
+        let inputs = StackInputs::try_from_ints(1..=16).unwrap();
+        assert_eq!(inputs.top().len(), STACK_TOP_SIZE);
+        assert!(inputs.overflow().is_empty());
+        assert_eq!(inputs.overflow_commitment(), None);
+
+        // the first inputs are the deepest on the stack, and thus, end up in the overflow table
+        let inputs = StackInputs::try_from_ints(1..=20).unwrap();
+        assert_eq!(inputs.top()[0], Felt::new(20));
+        let overflow = [4, 3, 2, 1].map(Felt::new);
+        assert_eq!(inputs.overflow(), overflow);
+        assert_eq!(inputs.overflow_commitment(), Some(hasher::hash_elements(&overflow)));
+    }
+}
@@ -10,6 +10,9 @@ pub use inputs::StackInputs;
 mod outputs;
 pub use outputs::StackOutputs;
 
+mod codec;
+pub use codec::{StackDecode, StackEncode, StackReader};
+
 // CONSTANTS
 // ================================================================================================
 
@@ -3,7 +3,8 @@ use alloc::vec::Vec;
 use miden_crypto::{Word, ZERO};
 
 use super::{
-    ByteWriter, Felt, OutputError, Serializable, StackTopState, ToElements, STACK_TOP_SIZE,
+    ByteWriter, Felt, OutputError, Serializable, StackDecode, StackReader, StackTopState,
+    ToElements, STACK_TOP_SIZE,
 };
 
 // STACK OUTPUTS
@@ -126,6 +127,20 @@ impl StackOutputs {
         Some(word_elements)
     }
 
+    /// Decodes a value of the specified type from the top of the stack, as described by
+    /// [StackEncode](super::StackEncode).
+    ///
+    /// For example, `outputs.decode::<(u64, [u8; 32])>()` reads a u64 value from the first two
+    /// elements of the stack, followed by a 32-byte array from the next eight elements. Elements
+    /// below the decoded value are ignored.
+    ///
+    /// # Errors
+    /// Returns an error if the stack does not contain enough elements, or if the elements do not
+    /// encode a valid value of the specified type.
+    pub fn decode<T: StackDecode>(&self) -> Result<T, OutputError> {
+        T::decode_from(&mut StackReader::new(&self.stack))
+    }
+
     /// Returns the stack outputs, which is state of the stack at the end of execution converted to
     /// integers.
     pub fn stack(&self) -> &[Felt] {
@@ -245,3 +260,32 @@ impl Deserializable for StackOutputs {
         })
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for StackOutputs {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("StackOutputs", 2)?;
+        state.serialize_field("stack", &self.stack)?;
+        state.serialize_field("overflow_addrs", &self.overflow_addrs)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for StackOutputs {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        struct Outputs {
+            stack: Vec<Felt>,
+            overflow_addrs: Vec<Felt>,
+        }
+
+        let Outputs {
+            stack,
+            overflow_addrs,
+        } = Outputs::deserialize(deserializer)?;
+        Self::new(stack, overflow_addrs).map_err(serde::de::Error::custom)
+    }
+}
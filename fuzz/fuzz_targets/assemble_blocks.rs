@@ -0,0 +1,182 @@
+#![no_main]
+
+//! Structure-aware fuzzing of the assembler -> processor pipeline.
+//!
+//! This target generates syntactically valid programs with procedures and nested control flow
+//! blocks, so that the inputs almost always assemble and most of the fuzzing time is spent on
+//! assembling and executing programs rather than on rejecting malformed sources.
+
+use libfuzzer_sys::{arbitrary::Arbitrary, fuzz_target};
+
+mod pipeline;
+
+/// Maximum nesting depth of control flow blocks; deeper blocks are left empty.
+const MAX_DEPTH: usize = 8;
+
+/// Modulus of the base field; pushed values are reduced by it.
+const MODULUS: u64 = 0xffff_ffff_0000_0001;
+
+/// Instructions which start every block, since blocks must not be empty.
+const BLOCK_PREFIX: &str = "push.0 drop";
+
+/// Instructions without immediate values.
+const SIMPLE_INSTRUCTIONS: &[&str] = &[
+    "add",
+    "sub",
+    "mul",
+    "div",
+    "neg",
+    "inv",
+    "pow2",
+    "not",
+    "and",
+    "or",
+    "xor",
+    "eq",
+    "neq",
+    "lt",
+    "gt",
+    "is_odd",
+    "eqw",
+    "assert",
+    "assertz",
+    "assert_eq",
+    "u32assert2",
+    "u32split",
+    "u32wrapping_add",
+    "u32overflowing_sub",
+    "u32wrapping_mul",
+    "u32divmod",
+    "u32and",
+    "u32shl",
+    "u32shr",
+    "u32rotl",
+    "u32popcnt",
+    "u32clz",
+    "u32lt",
+    "u32max",
+    "drop",
+    "dropw",
+    "padw",
+    "swapdw",
+    "cswap",
+    "cswapw",
+    "cdrop",
+    "cdropw",
+    "sdepth",
+    "clk",
+    "mem_load",
+    "mem_storew",
+    "hperm",
+    "hmerge",
+    "adv_push.1",
+    "adv_loadw",
+    "ext2add",
+    "ext2mul",
+    "ext2inv",
+];
+
+#[derive(Debug, Arbitrary)]
+enum Node {
+    Simple(u8),
+    Push(u64),
+    Dup(u8),
+    Swap(u8),
+    MovUp(u8),
+    MovDn(u8),
+    MemStore(u32),
+    MemLoadW(u32),
+    LocStore(u8),
+    LocLoad(u8),
+    Exec(u8),
+    If(Vec<Node>, Vec<Node>),
+    While(Vec<Node>),
+    Repeat(u8, Vec<Node>),
+}
+
+#[derive(Debug, Arbitrary)]
+struct Procedure {
+    num_locals: u8,
+    body: Vec<Node>,
+}
+
+#[derive(Debug, Arbitrary)]
+struct Program {
+    procedures: Vec<Procedure>,
+    body: Vec<Node>,
+}
+
+impl Program {
+    fn to_source(&self) -> String {
+        let mut source = String::new();
+        for (idx, proc) in self.procedures.iter().enumerate() {
+            let num_locals = proc.num_locals % 4;
+            source.push_str(&format!("proc.p{idx}.{num_locals}\n"));
+            // procedures can only invoke procedures defined before them
+            write_block(&proc.body, idx, num_locals, 0, &mut source);
+            source.push_str("end\n");
+        }
+        source.push_str("begin\n");
+        write_block(&self.body, self.procedures.len(), 0, 0, &mut source);
+        source.push_str("end\n");
+        source
+    }
+}
+
+/// Writes the specified nodes into the source. Nodes which are invalid in the current context
+/// (e.g., local accesses outside of procedures) are skipped.
+fn write_block(
+    nodes: &[Node],
+    num_procs: usize,
+    num_locals: u8,
+    depth: usize,
+    source: &mut String,
+) {
+    source.push_str(BLOCK_PREFIX);
+    source.push('\n');
+    if depth > MAX_DEPTH {
+        return;
+    }
+
+    for node in nodes {
+        let line = match node {
+            Node::Simple(idx) => {
+                SIMPLE_INSTRUCTIONS[*idx as usize % SIMPLE_INSTRUCTIONS.len()].to_string()
+            }
+            Node::Push(value) => format!("push.{}", value % MODULUS),
+            Node::Dup(idx) => format!("dup.{}", idx % 16),
+            Node::Swap(idx) => format!("swap.{}", idx % 15 + 1),
+            Node::MovUp(idx) => format!("movup.{}", idx % 14 + 2),
+            Node::MovDn(idx) => format!("movdn.{}", idx % 14 + 2),
+            Node::MemStore(addr) => format!("mem_store.{addr}"),
+            Node::MemLoadW(addr) => format!("mem_loadw.{addr}"),
+            Node::LocStore(idx) if num_locals > 0 => format!("loc_store.{}", idx % num_locals),
+            Node::LocLoad(idx) if num_locals > 0 => format!("loc_load.{}", idx % num_locals),
+            Node::Exec(idx) if num_procs > 0 => format!("exec.p{}", *idx as usize % num_procs),
+            Node::If(on_true, on_false) => {
+                source.push_str("if.true\n");
+                write_block(on_true, num_procs, num_locals, depth + 1, source);
+                source.push_str("else\n");
+                write_block(on_false, num_procs, num_locals, depth + 1, source);
+                "end".to_string()
+            }
+            Node::While(body) => {
+                source.push_str("while.true\n");
+                write_block(body, num_procs, num_locals, depth + 1, source);
+                "end".to_string()
+            }
+            Node::Repeat(count, body) => {
+                source.push_str(&format!("repeat.{}\n", count % 8 + 1));
+                write_block(body, num_procs, num_locals, depth + 1, source);
+                "end".to_string()
+            }
+            _ => continue,
+        };
+        source.push_str(&line);
+        source.push('\n');
+    }
+}
+
+fuzz_target!(|program: Program| {
+    pipeline::run(&program.to_source());
+});
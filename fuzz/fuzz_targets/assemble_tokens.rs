@@ -0,0 +1,122 @@
+#![no_main]
+
+//! Fuzzing of the assembler -> processor pipeline with random token streams.
+//!
+//! Sources built from arbitrary bytes are almost never valid MASM. Instead, this target builds
+//! sources from keywords, instructions and numbers, so that the inputs mostly fail deep inside
+//! the parser or the assembler, and occasionally assemble into programs which are then executed.
+
+use libfuzzer_sys::{arbitrary::Arbitrary, fuzz_target};
+
+mod pipeline;
+
+/// Keywords and instructions which require a specific context or refer to other items.
+const KEYWORDS: &[&str] = &[
+    "begin",
+    "end",
+    "if.true",
+    "if.false",
+    "else",
+    "while.true",
+    "repeat.3",
+    "proc.foo",
+    "proc.bar.2",
+    "export.foo",
+    "export.u64::wrapping_add",
+    "exec.foo",
+    "exec.bar",
+    "call.foo",
+    "syscall.foo",
+    "procref.foo",
+    "dynexec",
+    "dyncall",
+    "use.std::math::u64",
+    "exec.u64::wrapping_add",
+    "const.A=3",
+    "push.A",
+    "#! doc",
+    "# comment",
+];
+
+/// Instructions which can be followed by an immediate value.
+const INSTRUCTIONS: &[&str] = &[
+    "add",
+    "sub",
+    "mul",
+    "div",
+    "neg",
+    "inv",
+    "pow2",
+    "exp",
+    "not",
+    "and",
+    "or",
+    "xor",
+    "eq",
+    "lt",
+    "assert",
+    "assertz",
+    "u32assert2",
+    "u32wrapping_add",
+    "u32overflowing_sub",
+    "u32divmod",
+    "u32shl",
+    "u32rotr",
+    "u32popcnt",
+    "drop",
+    "dup",
+    "swap",
+    "swapw",
+    "movup",
+    "movdn",
+    "cswap",
+    "push",
+    "mem_load",
+    "mem_storew",
+    "loc_store",
+    "locaddr",
+    "adv_push",
+    "hperm",
+    "mtree_get",
+    "ext2mul",
+    "debug.stack",
+];
+
+#[derive(Debug, Arbitrary)]
+enum Token {
+    Keyword(u8),
+    Instruction(u8),
+    /// An instruction with an immediate value, e.g., `push.5`.
+    Immediate(u8, u64),
+    Number(u64),
+    Newline,
+    /// Any text, including whitespace and special characters.
+    Raw(String),
+}
+
+impl Token {
+    fn write_into(&self, source: &mut String) {
+        match self {
+            Self::Keyword(idx) => source.push_str(KEYWORDS[*idx as usize % KEYWORDS.len()]),
+            Self::Instruction(idx) => {
+                source.push_str(INSTRUCTIONS[*idx as usize % INSTRUCTIONS.len()])
+            }
+            Self::Immediate(idx, value) => {
+                source.push_str(INSTRUCTIONS[*idx as usize % INSTRUCTIONS.len()]);
+                source.push_str(&format!(".{value}"));
+            }
+            Self::Number(value) => source.push_str(&value.to_string()),
+            Self::Newline => source.push('\n'),
+            Self::Raw(text) => source.push_str(text),
+        }
+        source.push(' ');
+    }
+}
+
+fuzz_target!(|tokens: Vec<Token>| {
+    let mut source = String::new();
+    for token in tokens.iter() {
+        token.write_into(&mut source);
+    }
+    pipeline::run(&source);
+});
@@ -0,0 +1,17 @@
+#![no_main]
+
+use assembly::{
+    utils::{Deserializable, Serializable},
+    MaslLibrary,
+};
+use libfuzzer_sys::fuzz_target;
+
+// Any library which is read successfully must be written back into bytes from which the same
+// library is read.
+fuzz_target!(|data: &[u8]| {
+    if let Ok(library) = MaslLibrary::read_from_bytes(data) {
+        let bytes = library.to_bytes();
+        let library2 = MaslLibrary::read_from_bytes(&bytes).expect("failed to read library back");
+        assert_eq!(library, library2);
+    }
+});
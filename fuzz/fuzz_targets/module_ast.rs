@@ -0,0 +1,8 @@
+#![no_main]
+
+use assembly::ast::ModuleAst;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = ModuleAst::from_bytes(data);
+});
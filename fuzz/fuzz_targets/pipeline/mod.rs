@@ -0,0 +1,54 @@
+//! The assembler -> processor pipeline shared by the fuzz targets which assemble and execute
+//! programs.
+
+use assembly::{
+    ast::{AstSerdeOptions, ProgramAst},
+    utils::{Deserializable, Serializable},
+    Assembler,
+};
+use miden_stdlib::StdLibrary;
+use processor::{DefaultHost, ExecutionOptions, StackInputs};
+use vm_core::Program;
+
+/// Maximum number of cycles a fuzzed program is executed for.
+const MAX_CYCLES: u32 = 1 << 14;
+
+/// Parses, assembles and executes the specified source, and checks that the program AST and the
+/// program MAST survive serialization round-trips.
+///
+/// Parsing, assembly and execution are allowed to fail, but must never panic.
+pub fn run(source: &str) {
+    let Ok(ast) = ProgramAst::parse(source) else {
+        return;
+    };
+    check_ast_round_trip(&ast);
+
+    let assembler = Assembler::default()
+        .with_library(&StdLibrary::default())
+        .expect("failed to load stdlib");
+    let Ok(program) = assembler.compile_ast(&ast) else {
+        return;
+    };
+    check_mast_round_trip(&program);
+
+    // the expected number of cycles is rounded up to the minimum trace length
+    let options =
+        ExecutionOptions::new(Some(MAX_CYCLES), 0, false).expect("invalid execution options");
+    let _ = processor::execute(&program, StackInputs::default(), DefaultHost::default(), options);
+}
+
+/// Checks that a serialized program AST is read back into an AST which serializes into the same
+/// bytes. Source locations are not serialized, and thus, the ASTs themselves are not compared.
+fn check_ast_round_trip(ast: &ProgramAst) {
+    let options = AstSerdeOptions::new(true);
+    let bytes = ast.to_bytes(options);
+    let ast2 = ProgramAst::from_bytes(&bytes).expect("failed to read program AST back");
+    assert_eq!(bytes, ast2.to_bytes(options), "program AST changed in a round-trip");
+}
+
+/// Checks that a serialized program is read back into a program with the same MAST root.
+fn check_mast_round_trip(program: &Program) {
+    let program2 =
+        Program::read_from_bytes(&program.to_bytes()).expect("failed to read program back");
+    assert_eq!(program.hash(), program2.hash(), "program MAST changed in a round-trip");
+}
@@ -0,0 +1,8 @@
+#![no_main]
+
+use assembly::ast::ProgramAst;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = ProgramAst::from_bytes(data);
+});
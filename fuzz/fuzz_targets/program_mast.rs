@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use vm_core::{utils::Deserializable, Program};
+
+fuzz_target!(|data: &[u8]| {
+    let _ = Program::read_from_bytes(data);
+});
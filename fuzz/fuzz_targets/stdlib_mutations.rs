@@ -0,0 +1,79 @@
+#![no_main]
+
+//! Structure-aware fuzzing of library deserialization.
+//!
+//! Random bytes are rejected early by the deserializer (e.g., when reading the library namespace),
+//! and thus, they rarely exercise the deserialization of procedure bodies. Instead, this target
+//! applies a small number of mutations to the serialized standard library, so that most of the
+//! input remains well-formed.
+
+use assembly::{
+    utils::{Deserializable, Serializable},
+    MaslLibrary,
+};
+use libfuzzer_sys::{arbitrary::Arbitrary, fuzz_target};
+use miden_stdlib::StdLibrary;
+use std::sync::OnceLock;
+
+/// Maximum number of mutations applied to the serialized library.
+const MAX_MUTATIONS: usize = 8;
+
+#[derive(Debug, Arbitrary)]
+enum Mutation {
+    /// Overwrites the byte at the specified offset.
+    Set { offset: u32, value: u8 },
+    /// Inserts the specified bytes at the specified offset.
+    Insert { offset: u32, bytes: Vec<u8> },
+    /// Removes up to the specified number of bytes at the specified offset.
+    Remove { offset: u32, len: u16 },
+    /// Truncates the library at the specified offset.
+    Truncate { offset: u32 },
+}
+
+impl Mutation {
+    fn apply(&self, bytes: &mut Vec<u8>) {
+        let offset = |offset: u32| offset as usize % (bytes.len() + 1);
+        match self {
+            Self::Set { offset: pos, value } => {
+                let pos = offset(*pos);
+                if pos < bytes.len() {
+                    bytes[pos] = *value;
+                }
+            }
+            Self::Insert {
+                offset: pos,
+                bytes: inserted,
+            } => {
+                let pos = offset(*pos);
+                bytes.splice(pos..pos, inserted.iter().copied());
+            }
+            Self::Remove { offset: pos, len } => {
+                let pos = offset(*pos);
+                let end = (pos + *len as usize).min(bytes.len());
+                bytes.drain(pos..end);
+            }
+            Self::Truncate { offset: pos } => {
+                let pos = offset(*pos);
+                bytes.truncate(pos);
+            }
+        }
+    }
+}
+
+fn stdlib_bytes() -> &'static [u8] {
+    static BYTES: OnceLock<Vec<u8>> = OnceLock::new();
+    BYTES.get_or_init(|| MaslLibrary::from(StdLibrary::default()).to_bytes())
+}
+
+fuzz_target!(|mutations: Vec<Mutation>| {
+    let mut bytes = stdlib_bytes().to_vec();
+    for mutation in mutations.iter().take(MAX_MUTATIONS) {
+        mutation.apply(&mut bytes);
+    }
+
+    if let Ok(library) = MaslLibrary::read_from_bytes(&bytes) {
+        let library2 =
+            MaslLibrary::read_from_bytes(&library.to_bytes()).expect("failed to read library back");
+        assert_eq!(library, library2);
+    }
+});
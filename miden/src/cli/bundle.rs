@@ -1,6 +1,7 @@
-use assembly::{LibraryNamespace, MaslLibrary, Version};
+use assembly::{Assembler, Library, LibraryNamespace, MaslLibrary, Version};
 use clap::Parser;
-use std::path::PathBuf;
+use std::{fs, path::PathBuf};
+use stdlib::StdLibrary;
 
 #[derive(Debug, Clone, Parser)]
 #[clap(
@@ -17,6 +18,9 @@ pub struct BundleCmd {
     /// Version of the library, defaults to `0.1.0`.
     #[clap(short, long, default_value = "0.1.0")]
     version: String,
+    /// Write MAST roots of all exported procedures into a `<namespace>.roots` file.
+    #[clap(long)]
+    roots: bool,
 }
 
 impl BundleCmd {
@@ -52,6 +56,23 @@ impl BundleCmd {
 
         println!("Built library {}", namespace);
 
+        if self.roots {
+            // the standard library is the only dependency we can resolve here
+            let std_library = StdLibrary::default();
+            let mut assembler = Assembler::default();
+            if stdlib.dependencies().contains(std_library.root_ns()) {
+                assembler = assembler.with_library(&std_library).map_err(|e| e.to_string())?;
+            }
+            let roots = assembler
+                .with_library(&stdlib)
+                .and_then(|assembler| assembler.procedure_roots(&stdlib))
+                .map_err(|e| e.to_string())?;
+
+            let roots_path = self.dir.join(format!("{namespace}.roots"));
+            fs::write(&roots_path, roots.to_string()).map_err(|e| e.to_string())?;
+            println!("Wrote MAST roots of {} procedures to {}", roots.len(), roots_path.display());
+        }
+
         Ok(())
     }
 }
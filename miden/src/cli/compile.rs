@@ -15,6 +15,9 @@ pub struct CompileCmd {
     /// Path to output file
     #[clap(short = 'o', long = "output", value_parser)]
     output_file: Option<PathBuf>,
+    /// Fail compilation if the linter reports any warnings
+    #[clap(long = "deny-warnings")]
+    deny_warnings: bool,
 }
 
 impl CompileCmd {
@@ -26,6 +29,18 @@ impl CompileCmd {
         // load the program from file and parse it
         let program = ProgramFile::read(&self.assembly_file)?;
 
+        // report lint warnings, and abort if warnings are denied
+        let warnings = program.lint()?;
+        for warning in warnings.iter() {
+            println!("{warning}");
+        }
+        if self.deny_warnings && !warnings.is_empty() {
+            return Err(format!(
+                "Compilation aborted: {} warning(s) reported and warnings are denied",
+                warnings.len()
+            ));
+        }
+
         // load libraries from files
         let libraries = Libraries::new(&self.library_paths)?;
 
@@ -1,25 +1,47 @@
-use assembly::{Library, MaslLibrary};
+use assembly::{
+    ast::{LintWarning, Linter},
+    Library, MaslLibrary,
+};
 use miden_vm::{
-    crypto::{MerkleStore, MerkleTree, NodeIndex, PartialMerkleTree, RpoDigest, SimpleSmt},
+    crypto::{
+        MerkleStore, MerkleTree, NodeIndex, PartialMerkleTree, Rpo256, RpoDigest, SimpleSmt, Smt,
+    },
     math::Felt,
-    utils::{Deserializable, SliceReader},
-    AdviceInputs, Assembler, Digest, ExecutionProof, MemAdviceProvider, Program, ProgramAst,
-    StackInputs, StackOutputs, Word,
+    utils::{
+        ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
+    },
+    AdviceInputs, AdviceProvider, Assembler, Digest, ExecutionOptions, ExecutionProof,
+    MemAdviceProvider, MemoryImage, ModuleAst, Program, ProgramAst, ProvingOptions, StackInputs,
+    StackOutputs, Word,
 };
 use serde_derive::{Deserialize, Serialize};
 use std::{
     collections::{BTreeMap, HashMap},
-    fs,
-    io::Write,
+    env, fs,
+    io::{self, Read, Write},
+    ops::Range,
     path::{Path, PathBuf},
 };
 use stdlib::StdLibrary;
 pub use tracing::{event, instrument, Level};
+use vm_core::crypto::{
+    dsa::rpo_falcon512::{PublicKey, SecretKey, Signature},
+    hash::Blake3_256,
+};
 
 // CONSTANTS
 // ================================================================================================
 const SIMPLE_SMT_DEPTH: u8 = u64::BITS as u8;
 
+/// The latest version of the input file schema.
+pub const INPUT_FILE_VERSION: u64 = 2;
+
+/// Magic bytes at the start of binary input files.
+const BINARY_INPUT_FILE_MAGIC: &[u8; 4] = b"MINP";
+
+/// Input file path which denotes that the input data should be read from the standard input.
+const STDIN_PATH: &str = "-";
+
 // HELPERS
 // ================================================================================================
 
@@ -36,12 +58,25 @@ impl Debug {
     }
 }
 
+/// Writes the provided string into the target as its length followed by its UTF-8 bytes.
+fn write_string(target: &mut Vec<u8>, value: &str) {
+    target.write_usize(value.len());
+    target.write_bytes(value.as_bytes());
+}
+
+/// Reads a string written via [write_string()].
+fn read_string(source: &mut SliceReader) -> Result<String, DeserializationError> {
+    let len = source.read_usize()?;
+    let bytes = source.read_vec(len)?;
+    String::from_utf8(bytes).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+}
+
 // MERKLE DATA
 // ================================================================================================
 
 /// Struct used to deserialize merkle data from input file. Merkle data can be represented as a
 /// merkle tree or a Sparse Merkle Tree.
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Serialize, Debug)]
 pub enum MerkleData {
     /// String representation of a merkle tree. The merkle tree is represented as a vector of
     /// 32 byte hex strings where each string represents a leaf in the tree.
@@ -57,6 +92,11 @@ pub enum MerkleData {
     /// byte hex string representing the value of the leaf.
     #[serde(rename = "partial_merkle_tree")]
     PartialMerkleTree(Vec<((u8, u64), String)>),
+    /// String representation of a Sparse Merkle Tree with 256-bit keys. The tree is represented
+    /// as a vector of tuples where each tuple consists of a 32 byte hex string representing the
+    /// key and a 32 byte hex string representing the value.
+    #[serde(rename = "smt")]
+    Smt(Vec<(String, String)>),
 }
 
 // INPUT FILE
@@ -69,7 +109,23 @@ pub enum MerkleData {
 /// - advice_stack
 /// - advice_map
 /// - merkle_store
-#[derive(Deserialize, Debug)]
+///
+/// This struct directly describes the original (version 1) input file format. Files in the
+/// current format (see [InputFileV2]) are converted into this struct when read.
+///
+/// The format of an input file is determined by its extension:
+/// - `.toml` files contain the input data in TOML.
+/// - `.bin` files contain the input data in a compact binary format: the `MINP` magic bytes
+///   followed by the serialized [StackInputs] and [AdviceInputs].
+/// - Files with any other extension contain the input data in JSON.
+///
+/// Input data can also be read from the standard input by specifying `-` as the input file path.
+/// In this case, the format is detected from the data: binary input data starts with the magic
+/// bytes, JSON input data starts with `{`, and any other input data is parsed as TOML.
+///
+/// References to environment variables of the form `${NAME}` in JSON and TOML input data are
+/// replaced with the values of the variables before the data is parsed.
+#[derive(Deserialize, Serialize, Debug, Default)]
 pub struct InputFile {
     /// String representation of the initial operand stack, composed of chained field elements.
     pub operand_stack: Vec<String>,
@@ -81,12 +137,20 @@ pub struct InputFile {
     /// Optional vector of merkle data which will be loaded into the initial merkle store. Merkle
     /// data is represented as 32 byte hex strings and node indexes are represented as u64s.
     pub merkle_store: Option<Vec<MerkleData>>,
+    /// Advice inputs read from binary input files. Advice data specified by the other fields is
+    /// added to these inputs.
+    #[serde(skip)]
+    pub advice_inputs: AdviceInputs,
 }
 
 /// Helper methods to interact with the input file
 impl InputFile {
     #[instrument(name = "read_input_file", skip_all)]
     pub fn read(inputs_path: &Option<PathBuf>, program_path: &Path) -> Result<Self, String> {
+        if inputs_path.as_deref() == Some(Path::new(STDIN_PATH)) {
+            return Self::read_stdin();
+        }
+
         // if file not specified explicitly and corresponding file with same name as program_path
         // with '.inputs' extension does't exist, set operand_stack to empty vector
         if !inputs_path.is_some() && !program_path.with_extension("inputs").exists() {
@@ -95,6 +159,7 @@ impl InputFile {
                 advice_stack: Some(Vec::new()),
                 advice_map: Some(HashMap::new()),
                 merkle_store: None,
+                advice_inputs: AdviceInputs::default(),
             });
         }
 
@@ -105,20 +170,214 @@ impl InputFile {
             None => program_path.with_extension("inputs"),
         };
 
-        // read input file to string
-        let inputs_file = fs::read_to_string(&path)
+        Self::read_file(&path, &mut Vec::new())
+    }
+
+    /// Reads the input file at the specified path, resolving the files it includes.
+    ///
+    /// `includes` contains the chain of files which led to including this file; it is used to
+    /// detect include cycles.
+    fn read_file(path: &Path, includes: &mut Vec<PathBuf>) -> Result<Self, String> {
+        // read input file to bytes
+        let inputs_file = fs::read(path)
             .map_err(|err| format!("Failed to open input file `{}` - {}", path.display(), err))?;
 
-        // deserialize input data
-        let inputs: InputFile = serde_json::from_str(&inputs_file)
-            .map_err(|err| format!("Failed to deserialize input data - {}", err))?;
+        let path = path.canonicalize().map_err(|err| {
+            format!("Failed to resolve input file `{}` - {}", path.display(), err)
+        })?;
+        if includes.contains(&path) {
+            let chain = includes
+                .iter()
+                .chain(std::iter::once(&path))
+                .map(|path| path.display().to_string())
+                .collect::<Vec<_>>();
+            return Err(format!("Input files include each other: {}", chain.join(" -> ")));
+        }
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("bin") => Self::parse_binary(&inputs_file),
+            Some("toml") => Self::parse_text(inputs_file, true, &path, includes),
+            _ => Self::parse_text(inputs_file, false, &path, includes),
+        }
+    }
 
-        Ok(inputs)
+    /// Reads the input data from the standard input.
+    ///
+    /// Files included by the input data are resolved relative to the current directory.
+    fn read_stdin() -> Result<Self, String> {
+        let mut inputs = Vec::new();
+        io::stdin()
+            .read_to_end(&mut inputs)
+            .map_err(|err| format!("Failed to read input data from stdin - {}", err))?;
+
+        if inputs.starts_with(BINARY_INPUT_FILE_MAGIC) {
+            return Self::parse_binary(&inputs);
+        }
+        let is_toml = inputs.iter().find(|byte| !byte.is_ascii_whitespace()) != Some(&b'{');
+
+        let current_dir = env::current_dir()
+            .map_err(|err| format!("Failed to resolve the current directory - {}", err))?;
+        Self::parse_text(inputs, is_toml, &current_dir.join(STDIN_PATH), &mut Vec::new())
+    }
+
+    /// Parses the input data in the JSON or TOML format, after substituting references to
+    /// environment variables.
+    fn parse_text(
+        inputs: Vec<u8>,
+        is_toml: bool,
+        path: &Path,
+        includes: &mut Vec<PathBuf>,
+    ) -> Result<Self, String> {
+        let source = String::from_utf8(inputs)
+            .map_err(|err| format!("Failed to read input file as UTF-8 - {}", err))?;
+        let source = substitute_env_vars(&source)?;
+
+        let inputs = if is_toml {
+            toml::from_str(&source)
+                .map_err(|err| format!("Failed to deserialize input data - {}", err))?
+        } else {
+            serde_json::from_str(&source)
+                .map_err(|err| format!("Failed to deserialize input data - {}", err))?
+        };
+
+        includes.push(path.to_path_buf());
+        let inputs = Self::parse(inputs, path, includes);
+        includes.pop();
+        inputs
+    }
+
+    /// Overrides sections of the input data with the values specified on the command line.
+    ///
+    /// Each entry has the form `<section>=<values>`, where `<section>` is either `stack` (the
+    /// operand stack) or `advice_stack` (the advice stack), and `<values>` is a comma-separated
+    /// list of field elements, optionally enclosed in square brackets (e.g., `stack=[1,2,3]`).
+    /// The values replace the corresponding section of the input data, and are ordered in the
+    /// same way as in input files.
+    pub fn with_inline_inputs(mut self, entries: &[String]) -> Result<Self, String> {
+        for entry in entries {
+            let (section, values) = entry.split_once('=').ok_or_else(|| {
+                format!("Invalid inline input `{entry}` - expected `<section>=<values>`")
+            })?;
+            let values = values.trim();
+            let values = values
+                .strip_prefix('[')
+                .and_then(|values| values.strip_suffix(']'))
+                .unwrap_or(values);
+            let values = values
+                .split(',')
+                .map(|value| value.trim())
+                .filter(|value| !value.is_empty())
+                .map(|value| parse_element(value).map(|_| value.to_string()))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            match section.trim() {
+                "stack" => self.operand_stack = values,
+                "advice_stack" => self.advice_stack = Some(values),
+                section => {
+                    return Err(format!(
+                        "Invalid inline input section `{section}` - expected `stack` or \
+                        `advice_stack`"
+                    ))
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Parses the input data in any of the supported versions of the input file format.
+    ///
+    /// Files included by the input data are resolved relative to the directory of `path`.
+    fn parse(
+        inputs: serde_json::Value,
+        path: &Path,
+        includes: &mut Vec<PathBuf>,
+    ) -> Result<Self, String> {
+        // files without a version field are in the original format
+        match inputs.get("version").map(|version| version.as_u64()) {
+            None | Some(Some(1)) => serde_json::from_value(inputs)
+                .map_err(|err| format!("Failed to deserialize input data - {}", err)),
+            Some(Some(INPUT_FILE_VERSION)) => {
+                let inputs: InputFileV2 = serde_json::from_value(inputs)
+                    .map_err(|err| format!("Failed to deserialize input data - {}", err))?;
+                inputs.resolve(path, includes)
+            }
+            _ => Err(format!(
+                "Unsupported version of input file `{}` - the latest supported version is {}",
+                path.display(),
+                INPUT_FILE_VERSION
+            )),
+        }
+    }
+
+    /// Parses the input data in the binary input file format.
+    fn parse_binary(bytes: &[u8]) -> Result<Self, String> {
+        let mut source = SliceReader::new(bytes);
+        let magic = source
+            .read_array::<4>()
+            .map_err(|err| format!("Failed to read binary input data - {}", err))?;
+        if &magic != BINARY_INPUT_FILE_MAGIC {
+            return Err("Failed to read binary input data - invalid magic bytes".to_string());
+        }
+
+        let stack_inputs = StackInputs::read_from(&mut source)
+            .map_err(|err| format!("Failed to deserialize stack inputs - {}", err))?;
+        let advice_inputs = AdviceInputs::read_from(&mut source)
+            .map_err(|err| format!("Failed to deserialize advice inputs - {}", err))?;
+        if source.has_more_bytes() {
+            return Err("Failed to read binary input data - unexpected trailing bytes".to_string());
+        }
+
+        Ok(Self {
+            operand_stack: stack_inputs.values().iter().rev().map(|v| v.to_string()).collect(),
+            advice_inputs,
+            ..Default::default()
+        })
+    }
+
+    /// Writes the input data to the specified file in JSON format.
+    ///
+    /// # Errors
+    /// Returns an error if the input data contains advice inputs read from a binary input file, as
+    /// such inputs cannot be represented in JSON.
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        // an empty Merkle store contains the nodes of empty subtrees
+        if !self.advice_inputs.stack().is_empty()
+            || self.advice_inputs.map().iter().next().is_some()
+            || self.advice_inputs.merkle_store().num_internal_nodes()
+                != MerkleStore::<BTreeMap<_, _>>::default().num_internal_nodes()
+        {
+            return Err(
+                "Failed to write input data - binary advice inputs cannot be written as JSON"
+                    .to_string(),
+            );
+        }
+
+        let file = fs::File::create(path)
+            .map_err(|err| format!("Failed to create input file `{}` - {}", path.display(), err))?;
+        serde_json::to_writer_pretty(file, self)
+            .map_err(|err| format!("Failed to write input data - {}", err))
+    }
+
+    /// Appends the data of the provided input file to the data of this file. Values of the
+    /// operand and advice stacks are appended to the existing values, advice map entries replace
+    /// existing entries with the same keys, and Merkle data is added to the existing data.
+    fn merge(&mut self, other: InputFile) {
+        self.operand_stack.extend(other.operand_stack);
+        if let Some(stack) = other.advice_stack {
+            self.advice_stack.get_or_insert_with(Vec::new).extend(stack);
+        }
+        if let Some(map) = other.advice_map {
+            self.advice_map.get_or_insert_with(HashMap::new).extend(map);
+        }
+        if let Some(merkle_store) = other.merkle_store {
+            self.merkle_store.get_or_insert_with(Vec::new).extend(merkle_store);
+        }
+        self.advice_inputs.extend(other.advice_inputs);
     }
 
     /// Parse advice provider data from the input file.
     pub fn parse_advice_provider(&self) -> Result<MemAdviceProvider, String> {
-        let mut advice_inputs = AdviceInputs::default();
+        let mut advice_inputs = self.advice_inputs.clone();
 
         let stack = self
             .parse_advice_stack()
@@ -136,7 +395,7 @@ impl InputFile {
             .parse_merkle_store()
             .map_err(|e| format!("failed to parse advice provider: {e}"))?
         {
-            advice_inputs = advice_inputs.with_merkle_store(merkle_store);
+            advice_inputs.extend_merkle_store(merkle_store.inner_nodes());
         }
 
         Ok(MemAdviceProvider::from(advice_inputs))
@@ -150,8 +409,7 @@ impl InputFile {
             .unwrap_or(&[])
             .iter()
             .map(|v| {
-                v.parse::<u64>()
-                    .map_err(|e| format!("failed to parse advice stack value '{v}': {e}"))
+                parse_element(v).map_err(|e| format!("failed to parse advice stack value: {e}"))
             })
             .collect::<Result<Vec<_>, _>>()
     }
@@ -229,6 +487,17 @@ impl InputFile {
                         tree.root()
                     );
                 }
+                MerkleData::Smt(data) => {
+                    let entries = Self::parse_smt(data)?;
+                    let tree = Smt::with_entries(entries)
+                        .map_err(|e| format!("failed to parse a Sparse Merkle Tree: {e}"))?;
+                    merkle_store.extend(tree.inner_nodes());
+                    event!(
+                        Level::TRACE,
+                        "Added Sparse Merkle tree with root {} to the Merkle store",
+                        tree.root()
+                    );
+                }
             }
         }
 
@@ -272,6 +541,17 @@ impl InputFile {
             .collect()
     }
 
+    /// Parse and return entries of a Sparse Merkle Tree with 256-bit keys.
+    fn parse_smt(tree: &[(String, String)]) -> Result<Vec<(RpoDigest, Word)>, String> {
+        tree.iter()
+            .map(|(key, v)| {
+                let key = Self::parse_word(key)?;
+                let value = Self::parse_word(v)?;
+                Ok((RpoDigest::new(key), value))
+            })
+            .collect()
+    }
+
     /// Parse a `Word` from a hex string.
     pub fn parse_word(word_hex: &str) -> Result<Word, String> {
         let word_value = &word_hex[2..];
@@ -292,13 +572,130 @@ impl InputFile {
         let stack_inputs = self
             .operand_stack
             .iter()
-            .map(|v| v.parse::<u64>().map_err(|e| e.to_string()))
+            .map(|v| parse_element(v))
             .collect::<Result<Vec<_>, _>>()?;
 
         StackInputs::try_from_ints(stack_inputs).map_err(|e| e.to_string())
     }
 }
 
+// INPUT FILE V2
+// ================================================================================================
+
+/// Version 2 of the input file format, which organizes inputs into typed sections:
+/// - `version` - must be set to 2.
+/// - `include` - optional list of input files (relative to the directory of this file) whose data
+///   is merged into this file before its own sections.
+/// - `stack` - optional initial operand stack.
+/// - `advice` - optional advice inputs consisting of the `stack`, `map`, and `merkle_store`
+///   sections.
+///
+/// Field elements can be written either in decimal or in hexadecimal notation (with `0x` prefix).
+#[derive(Deserialize, Debug)]
+#[serde(deny_unknown_fields)]
+pub struct InputFileV2 {
+    pub version: u64,
+    #[serde(default)]
+    pub include: Vec<PathBuf>,
+    #[serde(default)]
+    pub stack: Vec<String>,
+    #[serde(default)]
+    pub advice: AdviceSection,
+}
+
+/// Advice inputs section of the [InputFileV2].
+#[derive(Deserialize, Debug, Default)]
+#[serde(deny_unknown_fields)]
+pub struct AdviceSection {
+    /// Initial advice stack.
+    #[serde(default)]
+    pub stack: Vec<String>,
+    /// Map of 32 byte hex strings to the lists of field elements.
+    #[serde(default)]
+    pub map: BTreeMap<String, Vec<String>>,
+    /// Merkle data which will be loaded into the initial Merkle store.
+    #[serde(default)]
+    pub merkle_store: Vec<MerkleData>,
+}
+
+impl InputFileV2 {
+    /// Converts this file into an [InputFile], merging in the data of all included files.
+    fn resolve(self, path: &Path, includes: &mut Vec<PathBuf>) -> Result<InputFile, String> {
+        if self.version != INPUT_FILE_VERSION {
+            return Err(format!(
+                "Unsupported version {} of input file `{}` - expected version {}",
+                self.version,
+                path.display(),
+                INPUT_FILE_VERSION
+            ));
+        }
+
+        let mut inputs = InputFile::default();
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+        for include in self.include {
+            inputs.merge(InputFile::read_file(&base_dir.join(include), includes)?);
+        }
+
+        let advice_map = self
+            .advice
+            .map
+            .into_iter()
+            .map(|(key, values)| {
+                let values = values
+                    .iter()
+                    .map(|v| parse_element(v))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|e| format!("failed to parse advice map value: {e}"))?;
+                Ok((key, values))
+            })
+            .collect::<Result<HashMap<_, _>, String>>()?;
+
+        inputs.merge(InputFile {
+            operand_stack: self.stack,
+            advice_stack: Some(self.advice.stack),
+            advice_map: Some(advice_map),
+            merkle_store: Some(self.advice.merkle_store),
+            advice_inputs: AdviceInputs::default(),
+        });
+        Ok(inputs)
+    }
+}
+
+/// Replaces all references to environment variables of the form `${NAME}` in the provided source
+/// with the values of the variables.
+///
+/// # Errors
+/// Returns an error if a reference is not terminated, or if a referenced variable is not set.
+fn substitute_env_vars(source: &str) -> Result<String, String> {
+    let mut result = String::with_capacity(source.len());
+    let mut rest = source;
+    while let Some(start) = rest.find("${") {
+        result.push_str(&rest[..start]);
+        let reference = &rest[start + 2..];
+        let end = reference.find('}').ok_or_else(|| {
+            "Unterminated environment variable reference in input data".to_string()
+        })?;
+        let name = &reference[..end];
+        let value = env::var(name).map_err(|err| {
+            format!("Failed to substitute environment variable `{name}` in input data - {err}")
+        })?;
+        result.push_str(&value);
+        rest = &reference[end + 1..];
+    }
+    result.push_str(rest);
+    Ok(result)
+}
+
+/// Parses a field element written either in decimal or in hexadecimal notation (with `0x`
+/// prefix) and returns its integer value.
+fn parse_element(value: &str) -> Result<u64, String> {
+    let result = match value.strip_prefix("0x") {
+        Some(hex) => u64::from_str_radix(hex, 16),
+        None => value.parse::<u64>(),
+    };
+    result.map_err(|e| format!("failed to parse field element '{value}' - {e}"))
+}
+
 // OUTPUT FILE
 // ================================================================================================
 
@@ -373,12 +770,136 @@ impl OutputFile {
     }
 }
 
+// MEMORY FILE
+// ================================================================================================
+
+/// Helper methods to read and write the contents of memory loaded by a program via the advice map
+/// or exported once the program finishes executing.
+///
+/// The format of a memory file is determined by its extension:
+/// - `.bin` files contain a serialized [MemoryImage].
+/// - Files with any other extension contain text, where every line which is not empty and does not
+///   start with `#` consists of a memory address followed by a colon and one or more words, each
+///   encoded as a 32 byte hex string (e.g., `100: 0x0100...00 0x0200...00`). The words are written
+///   to consecutive addresses starting at the specified address.
+pub struct MemoryFile;
+
+impl MemoryFile {
+    /// Reads the memory image from the specified file.
+    #[instrument(name = "read_memory_file", fields(path = %path.display()), skip_all)]
+    pub fn read(path: &Path) -> Result<MemoryImage, String> {
+        let bytes = fs::read(path)
+            .map_err(|err| format!("Failed to open memory file `{}` - {}", path.display(), err))?;
+
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("bin") => MemoryImage::read_from_bytes(&bytes)
+                .map_err(|err| format!("Failed to decode memory data - {}", err)),
+            _ => {
+                let text = String::from_utf8(bytes)
+                    .map_err(|err| format!("Failed to decode memory data - {}", err))?;
+                Self::parse_text(&text)
+            }
+        }
+    }
+
+    /// Reads the memory image from the specified file and inserts it into the advice map of the
+    /// specified advice provider, from which the program loads the image via
+    /// `std::mem::load_image`.
+    pub fn read_into_advice(
+        path: &Path,
+        adv_provider: &mut MemAdviceProvider,
+    ) -> Result<MemoryImage, String> {
+        let image = Self::read(path)?;
+        let (key, values) = image.to_advice_map_entry();
+        adv_provider
+            .insert_into_map(key.into(), values)
+            .map_err(|err| format!("Failed to load memory image - {err}"))?;
+        Ok(image)
+    }
+
+    /// Writes the memory image to the specified file in the text format, with every word on a
+    /// separate line.
+    pub fn write(image: &MemoryImage, path: &Path) -> Result<(), String> {
+        fs::write(path, Self::to_text(image))
+            .map_err(|err| format!("Failed to write memory file `{}` - {}", path.display(), err))
+    }
+
+    /// Returns the text format of the memory image, with every word on a separate line.
+    pub fn to_text(image: &MemoryImage) -> String {
+        image
+            .iter()
+            .map(|(addr, word)| {
+                let bytes = word.iter().flat_map(|element| element.as_int().to_le_bytes());
+                format!("{addr}: 0x{}\n", hex::encode(bytes.collect::<Vec<_>>()))
+            })
+            .collect()
+    }
+
+    /// Parses a range of memory addresses of the form `<start>..<end>`, where the end address is
+    /// exclusive.
+    pub fn parse_range(range: &str) -> Result<Range<u32>, String> {
+        let (start, end) = range
+            .split_once("..")
+            .ok_or_else(|| format!("Invalid memory range `{range}` - expected `<start>..<end>`"))?;
+        let start = start
+            .trim()
+            .parse::<u32>()
+            .map_err(|err| format!("Invalid start of memory range `{range}` - {err}"))?;
+        let end = end
+            .trim()
+            .parse::<u32>()
+            .map_err(|err| format!("Invalid end of memory range `{range}` - {err}"))?;
+        if start >= end {
+            return Err(format!("Invalid memory range `{range}` - the range is empty"));
+        }
+        Ok(start..end)
+    }
+
+    /// Parses the memory image from the text format.
+    fn parse_text(text: &str) -> Result<MemoryImage, String> {
+        let mut image = MemoryImage::new();
+        for (line_idx, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let line_num = line_idx + 1;
+            let (addr, words) = line.split_once(':').ok_or_else(|| {
+                format!(
+                    "Failed to parse memory data on line {line_num} - expected `<addr>: <words>`"
+                )
+            })?;
+            let addr = addr.trim().parse::<u32>().map_err(|err| {
+                format!("Failed to parse memory address on line {line_num} - {err}")
+            })?;
+            let words = words
+                .split_whitespace()
+                .map(|word| {
+                    if word.starts_with("0x") {
+                        InputFile::parse_word(word)
+                    } else {
+                        Err(format!("failed to decode `Word` from hex {word} - missing 0x prefix"))
+                    }
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|err| format!("Failed to parse memory data on line {line_num} - {err}"))?;
+            image
+                .insert_words(addr, &words)
+                .map_err(|err| format!("Failed to parse memory data on line {line_num} - {err}"))?;
+        }
+        Ok(image)
+    }
+}
+
 // PROGRAM FILE
 // ================================================================================================
 
 pub struct ProgramFile {
     ast: ProgramAst,
     path: PathBuf,
+    source: String,
+    is_module: bool,
 }
 
 /// Helper methods to interact with masm program file.
@@ -399,6 +920,62 @@ impl ProgramFile {
         Ok(Self {
             ast,
             path: path.clone(),
+            source,
+            is_module: false,
+        })
+    }
+
+    /// Reads the masm file at the specified path and parses it into a [ProgramAst] which executes
+    /// the specified entrypoint, if one is provided.
+    ///
+    /// When an entrypoint is provided, the file is parsed as a module, and the program executes
+    /// the exported procedure with the entrypoint's name. Otherwise, the file is parsed as a
+    /// program.
+    #[instrument(name = "read_program_entrypoint", fields(path = %path.display()))]
+    pub fn read_with_entrypoint(path: &PathBuf, entrypoint: Option<&str>) -> Result<Self, String> {
+        let entrypoint = match entrypoint {
+            Some(entrypoint) => entrypoint,
+            None => return Self::read(path),
+        };
+
+        // read program file to string
+        let source = fs::read_to_string(path).map_err(|err| {
+            format!("Failed to open program file `{}` - {}\n", path.display(), err)
+        })?;
+
+        // parse the program into a module AST and select the entrypoint
+        let module = ModuleAst::parse(&source).map_err(|err| {
+            format!("Failed to parse program file `{}` - {}\n", path.display(), err)
+        })?;
+        let ast = module.entrypoint_program(entrypoint).ok_or_else(|| {
+            let entrypoints = module.entrypoints().collect::<Vec<_>>().join(", ");
+            format!(
+                "Program file `{}` does not export entrypoint `{entrypoint}`; available \
+                entrypoints: {entrypoints}\n",
+                path.display()
+            )
+        })?;
+
+        Ok(Self {
+            ast,
+            path: path.clone(),
+            source,
+            is_module: true,
+        })
+    }
+
+    /// Returns the warnings reported by the default [Linter] for the source of this program file.
+    ///
+    /// If the file was read with an entrypoint, its source is linted as a module.
+    pub fn lint(&self) -> Result<Vec<LintWarning>, String> {
+        let linter = Linter::default();
+        let warnings = if self.is_module {
+            linter.lint_module(&self.source)
+        } else {
+            linter.lint_program(&self.source)
+        };
+        warnings.map_err(|err| {
+            format!("Failed to lint program file `{}` - {}\n", self.path.display(), err)
         })
     }
 
@@ -471,7 +1048,7 @@ impl ProofFile {
             .map_err(|err| format!("Failed to decode proof data - {}", err))
     }
 
-    /// Write stark proof to file
+    /// Write stark proof to file, and return the path to the file
     #[instrument(name = "write_data_to_proof_file",
                  fields(
                     path = %proof_path.clone().unwrap_or(program_path.with_extension("proof")).display(),
@@ -480,7 +1057,7 @@ impl ProofFile {
         proof: ExecutionProof,
         proof_path: &Option<PathBuf>,
         program_path: &Path,
-    ) -> Result<(), String> {
+    ) -> Result<PathBuf, String> {
         // If proof_path has been provided then use this as path.  Alternatively we will
         // replace the program_path extension with `.proof` and use this as a default.
         let path = match proof_path {
@@ -497,7 +1074,262 @@ impl ProofFile {
         // write proof bytes to file
         file.write_all(&proof_bytes).unwrap();
 
-        Ok(())
+        Ok(path)
+    }
+}
+
+// PROGRAM BUNDLE
+// ================================================================================================
+
+/// Magic bytes at the start of program bundle files.
+const BUNDLE_MAGIC: &[u8; 4] = b"MBDL";
+
+/// Version of the program bundle file format.
+const BUNDLE_VERSION: u8 = 1;
+
+/// Default proving options stored in a program bundle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundleOptions {
+    /// Security level of the proofs, either `96bits` or `128bits`.
+    pub security: String,
+    /// Whether the proofs should be suitable for recursive verification.
+    pub recursive: bool,
+    /// Maximum number of cycles the program is allowed to consume.
+    pub max_cycles: u32,
+    /// Number of cycles the program is expected to consume.
+    pub expected_cycles: u32,
+}
+
+/// Digest of a library against which a bundled program was compiled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LibraryDigest {
+    pub namespace: String,
+    pub version: String,
+    /// Blake3 hash of the serialized library.
+    pub digest: [u8; 32],
+}
+
+impl LibraryDigest {
+    /// Returns the digest of the provided library.
+    pub fn new(library: &MaslLibrary) -> Self {
+        Self {
+            namespace: library.root_ns().to_string(),
+            version: library.version().to_string(),
+            digest: Blake3_256::hash(&library.to_bytes()).into(),
+        }
+    }
+}
+
+impl BundleOptions {
+    /// Returns the options for proving the execution of the bundled program.
+    pub fn proving_options(&self) -> Result<ProvingOptions, String> {
+        let exec_options =
+            ExecutionOptions::new(Some(self.max_cycles), self.expected_cycles, false)
+                .map_err(|err| format!("{err}"))?;
+        let options = match self.security.as_str() {
+            "96bits" => ProvingOptions::with_96_bit_security(self.recursive),
+            "128bits" => ProvingOptions::with_128_bit_security(self.recursive),
+            other => return Err(format!("{other} is not a valid security setting")),
+        };
+        Ok(options.with_execution_options(exec_options))
+    }
+}
+
+/// A single deployable artifact containing a compiled program (with debug info), digests of the
+/// libraries it was compiled against, and default options for proving its execution.
+///
+/// A bundle can be signed with an RPO Falcon512 key: the signature covers the RPO hash of the
+/// bundle contents, and is verified whenever the bundle is read. A valid signature only shows that
+/// the bundle was not modified after it was signed; the bundle is authenticated only if it is read
+/// with a set of trusted keys, in which case it must be signed with one of them.
+pub struct BundleFile {
+    pub program: Program,
+    pub libraries: Vec<LibraryDigest>,
+    pub options: BundleOptions,
+    /// Public key of the signer and the signature of the bundle contents, if the bundle is signed.
+    pub signature: Option<(Word, Signature)>,
+}
+
+impl BundleFile {
+    /// Returns a new unsigned bundle.
+    pub fn new(program: Program, libraries: Vec<LibraryDigest>, options: BundleOptions) -> Self {
+        Self {
+            program,
+            libraries,
+            options,
+            signature: None,
+        }
+    }
+
+    /// Signs this bundle with the provided secret key.
+    pub fn sign(&mut self, secret_key: &SecretKey) {
+        let message = Self::message(&self.contents_to_bytes());
+        let signature = secret_key.sign(message);
+        self.signature = Some((secret_key.public_key().into(), signature));
+    }
+
+    /// Reads the bundle from the specified path, verifying its signature if it is signed.
+    ///
+    /// If any trusted keys are provided, the bundle must be signed with one of them.
+    #[instrument(name = "read_bundle_file", fields(path = %path.display()), skip(trusted_keys))]
+    pub fn read(path: &Path, trusted_keys: &[Word]) -> Result<Self, String> {
+        let bytes = fs::read(path)
+            .map_err(|err| format!("Failed to open bundle file `{}` - {}", path.display(), err))?;
+        let bundle = Self::from_bytes(&bytes)
+            .map_err(|err| format!("Failed to read bundle file `{}` - {}", path.display(), err))?;
+        bundle
+            .check_signer(trusted_keys)
+            .map_err(|err| format!("Failed to read bundle file `{}` - {}", path.display(), err))?;
+        Ok(bundle)
+    }
+
+    /// Writes the bundle into the specified path.
+    #[instrument(name = "write_bundle_file", fields(path = %path.display()), skip_all)]
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_bytes())
+            .map_err(|err| format!("Failed to write bundle file `{}` - {}", path.display(), err))
+    }
+
+    /// Serializes this bundle into bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.contents_to_bytes();
+        match &self.signature {
+            Some((public_key, signature)) => {
+                bytes.write_bool(true);
+                bytes.write_many(public_key);
+                signature.write_into(&mut bytes);
+            }
+            None => bytes.write_bool(false),
+        }
+        bytes
+    }
+
+    /// Deserializes a bundle from the provided bytes and verifies its signature, if any.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let mut source = SliceReader::new(bytes);
+        let magic = source.read_array::<4>().map_err(|err| err.to_string())?;
+        if &magic != BUNDLE_MAGIC {
+            return Err("invalid magic bytes".to_string());
+        }
+        let version = source.read_u8().map_err(|err| err.to_string())?;
+        if version != BUNDLE_VERSION {
+            return Err(format!("unsupported bundle version {version}"));
+        }
+
+        let (program, libraries, options) =
+            Self::read_contents(&mut source).map_err(|err| err.to_string())?;
+        let signature = Self::read_signature(&mut source).map_err(|err| err.to_string())?;
+        if source.has_more_bytes() {
+            return Err("unexpected trailing bytes".to_string());
+        }
+
+        let bundle = Self {
+            program,
+            libraries,
+            options,
+            signature,
+        };
+
+        // the serialization of the contents is canonical; thus, the signature can be checked
+        // against the re-serialized contents
+        if let Some((public_key, signature)) = &bundle.signature {
+            let message = Self::message(&bundle.contents_to_bytes());
+            if !PublicKey::new(*public_key).verify(message, signature) {
+                return Err("invalid bundle signature".to_string());
+            }
+        }
+
+        Ok(bundle)
+    }
+
+    /// Checks that this bundle is signed with one of the provided trusted keys; any bundle passes
+    /// the check if no trusted keys are provided.
+    ///
+    /// The signature itself is verified when the bundle is deserialized, and thus, only the public
+    /// key of the signer is checked here.
+    pub fn check_signer(&self, trusted_keys: &[Word]) -> Result<(), String> {
+        if trusted_keys.is_empty() {
+            return Ok(());
+        }
+        match &self.signature {
+            Some((public_key, _)) if trusted_keys.contains(public_key) => Ok(()),
+            Some(_) => Err("bundle is not signed with a trusted key".to_string()),
+            None => Err("bundle is not signed".to_string()),
+        }
+    }
+
+    /// Parses a public key from its hex encoding, as printed when a bundle is signed.
+    pub fn parse_public_key(key_hex: &str) -> Result<Word, String> {
+        let key_bytes = hex::decode(key_hex)
+            .map_err(|err| format!("Failed to convert public key to bytes {}", err))?;
+        let public_key = Digest::read_from_bytes(&key_bytes)
+            .map_err(|err| format!("Failed to deserialize public key from bytes - {}", err))?;
+        Ok(public_key.into())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Serializes the bundle contents covered by the signature.
+    fn contents_to_bytes(&self) -> Vec<u8> {
+        let mut bytes = BUNDLE_MAGIC.to_vec();
+        bytes.write_u8(BUNDLE_VERSION);
+        self.program.write_into(&mut bytes);
+
+        bytes.write_usize(self.libraries.len());
+        for library in self.libraries.iter() {
+            write_string(&mut bytes, &library.namespace);
+            write_string(&mut bytes, &library.version);
+            bytes.write_bytes(&library.digest);
+        }
+
+        write_string(&mut bytes, &self.options.security);
+        bytes.write_bool(self.options.recursive);
+        bytes.write_u32(self.options.max_cycles);
+        bytes.write_u32(self.options.expected_cycles);
+        bytes
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn read_contents(
+        source: &mut SliceReader,
+    ) -> Result<(Program, Vec<LibraryDigest>, BundleOptions), DeserializationError> {
+        let program = Program::read_from(source)?;
+
+        let num_libraries = source.read_usize()?;
+        let mut libraries = Vec::new();
+        for _ in 0..num_libraries {
+            libraries.push(LibraryDigest {
+                namespace: read_string(source)?,
+                version: read_string(source)?,
+                digest: source.read_array::<32>()?,
+            });
+        }
+
+        let options = BundleOptions {
+            security: read_string(source)?,
+            recursive: source.read_bool()?,
+            max_cycles: source.read_u32()?,
+            expected_cycles: source.read_u32()?,
+        };
+        Ok((program, libraries, options))
+    }
+
+    fn read_signature(
+        source: &mut SliceReader,
+    ) -> Result<Option<(Word, Signature)>, DeserializationError> {
+        if !source.read_bool()? {
+            return Ok(None);
+        }
+        let public_key = source.read_many::<Felt>(4)?;
+        let public_key = public_key.try_into().expect("public key must contain 4 elements");
+        let signature = Signature::read_from(source)?;
+        Ok(Some((public_key, signature)))
+    }
+
+    /// Returns the message which is signed for the provided bundle contents.
+    fn message(contents: &[u8]) -> Word {
+        Rpo256::hash(contents).into()
     }
 }
 
@@ -555,7 +1387,11 @@ impl Libraries {
 // ================================================================================================
 #[cfg(test)]
 mod test {
-    use super::InputFile;
+    use super::{
+        AdviceInputs, Assembler, BundleFile, BundleOptions, Felt, InputFile, SecretKey, StackInputs,
+    };
+    use miden_vm::utils::Serializable;
+    use std::{fs, path::Path};
 
     #[test]
     fn test_merkle_data_parsing() {
@@ -629,4 +1465,184 @@ mod test {
         let merkle_store = inputs.parse_merkle_store().unwrap();
         assert!(merkle_store.is_some());
     }
+
+    #[test]
+    fn test_input_file_v2() {
+        let dir = std::env::temp_dir().join(format!("miden-inputs-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let common = "
+        {
+            \"version\": 2,
+            \"stack\": [\"1\"],
+            \"advice\": {
+                \"stack\": [\"0x10\"]
+            }
+        }";
+        fs::write(dir.join("common.inputs"), common).unwrap();
+
+        let inputs = "
+        {
+            \"version\": 2,
+            \"include\": [\"common.inputs\"],
+            \"stack\": [\"0xff\", \"2\"],
+            \"advice\": {
+                \"stack\": [\"3\"],
+                \"map\": {
+                    \"0x0100000000000000000000000000000000000000000000000000000000000000\": [\"0x1\", \"2\"]
+                },
+                \"merkle_store\": [
+                    {
+                        \"smt\": [
+                            [
+                                \"0x0100000000000000000000000000000000000000000000000000000000000000\",
+                                \"0x1400000000000000000000000000000000000000000000000000000000000000\"
+                            ]
+                        ]
+                    }
+                ]
+            }
+        }";
+        let path = dir.join("program.inputs");
+        fs::write(&path, inputs).unwrap();
+
+        // sections of included files come first
+        let inputs = InputFile::read(&Some(path.clone()), Path::new("program.masm")).unwrap();
+        let stack = inputs.parse_stack_inputs().unwrap();
+        assert_eq!(stack.values(), [Felt::new(2), Felt::new(255), Felt::new(1)]);
+        assert_eq!(inputs.parse_advice_stack().unwrap(), [16, 3]);
+        assert_eq!(inputs.parse_advice_map().unwrap().unwrap().len(), 1);
+        assert!(inputs.parse_merkle_store().unwrap().is_some());
+
+        // include cycles are detected
+        fs::write(
+            dir.join("common.inputs"),
+            "{ \"version\": 2, \"include\": [\"program.inputs\"] }",
+        )
+        .unwrap();
+        let err = InputFile::read(&Some(path.clone()), Path::new("program.masm")).unwrap_err();
+        assert!(err.contains("include each other"));
+
+        // unknown versions and sections are rejected
+        fs::write(&path, "{ \"version\": 3 }").unwrap();
+        assert!(InputFile::read(&Some(path.clone()), Path::new("program.masm")).is_err());
+        fs::write(&path, "{ \"version\": 2, \"operand_stack\": [] }").unwrap();
+        assert!(InputFile::read(&Some(path.clone()), Path::new("program.masm")).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_toml_and_binary_input_files() {
+        let dir = std::env::temp_dir().join(format!("miden-inputs-fmt-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let mut bytes = b"MINP".to_vec();
+        StackInputs::try_from_ints([1, 2]).unwrap().write_into(&mut bytes);
+        AdviceInputs::default()
+            .with_stack_values([3, 4])
+            .unwrap()
+            .write_into(&mut bytes);
+        fs::write(dir.join("advice.bin"), &bytes).unwrap();
+
+        let inputs = "
+            version = 2
+            include = [\"advice.bin\"]
+            stack = [\"0x5\"]
+
+            [advice]
+            stack = [\"6\"]
+        ";
+        let path = dir.join("program.toml");
+        fs::write(&path, inputs).unwrap();
+
+        let inputs = InputFile::read(&Some(path), Path::new("program.masm")).unwrap();
+        let stack = inputs.parse_stack_inputs().unwrap();
+        assert_eq!(stack.values(), [Felt::new(5), Felt::new(2), Felt::new(1)]);
+        assert_eq!(inputs.advice_inputs.stack(), [Felt::new(3), Felt::new(4)]);
+        assert_eq!(inputs.parse_advice_stack().unwrap(), [6]);
+        assert!(inputs.parse_advice_provider().is_ok());
+
+        // binary files with invalid magic bytes are rejected
+        bytes[0] = b'X';
+        fs::write(dir.join("advice.bin"), &bytes).unwrap();
+        let path = dir.join("advice.bin");
+        assert!(InputFile::read(&Some(path), Path::new("program.masm")).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_env_vars_and_inline_inputs() {
+        let dir = std::env::temp_dir().join(format!("miden-inputs-env-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        std::env::set_var("MIDEN_TEST_INPUT_VALUE", "7");
+        let path = dir.join("program.inputs");
+        fs::write(&path, "{ \"version\": 2, \"stack\": [\"${MIDEN_TEST_INPUT_VALUE}\", \"8\"] }")
+            .unwrap();
+        let inputs = InputFile::read(&Some(path.clone()), Path::new("program.masm")).unwrap();
+        let stack = inputs.parse_stack_inputs().unwrap();
+        assert_eq!(stack.values(), [Felt::new(8), Felt::new(7)]);
+
+        // inline inputs replace the sections of the input file
+        let inline = vec!["stack=[1, 0x2,3]".to_string(), "advice_stack=4".to_string()];
+        let inputs = inputs.with_inline_inputs(&inline).unwrap();
+        let stack = inputs.parse_stack_inputs().unwrap();
+        assert_eq!(stack.values(), [Felt::new(3), Felt::new(2), Felt::new(1)]);
+        assert_eq!(inputs.parse_advice_stack().unwrap(), [4]);
+
+        let inputs = InputFile::default();
+        assert!(inputs.with_inline_inputs(&["memory=[1]".to_string()]).is_err());
+        let inputs = InputFile::default();
+        assert!(inputs.with_inline_inputs(&["stack=[x]".to_string()]).is_err());
+
+        // references to variables which are not set are rejected
+        fs::write(&path, "{ \"version\": 2, \"stack\": [\"${MIDEN_TEST_UNSET_VARIABLE}\"] }")
+            .unwrap();
+        assert!(InputFile::read(&Some(path), Path::new("program.masm")).is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_bundle_file() {
+        let program = Assembler::default().compile("begin push.1 push.2 add end").unwrap();
+        let options = BundleOptions {
+            security: "96bits".to_string(),
+            recursive: false,
+            max_cycles: 1 << 20,
+            expected_cycles: 64,
+        };
+        let mut bundle = BundleFile::new(program, Vec::new(), options.clone());
+
+        // unsigned bundles
+        let deser = BundleFile::from_bytes(&bundle.to_bytes()).unwrap();
+        assert_eq!(deser.program.hash(), bundle.program.hash());
+        assert_eq!(deser.options, options);
+        assert!(deser.signature.is_none());
+
+        // signed bundles
+        bundle.sign(&SecretKey::new());
+        let deser = BundleFile::from_bytes(&bundle.to_bytes()).unwrap();
+        assert_eq!(deser.signature.as_ref().unwrap().0, bundle.signature.as_ref().unwrap().0);
+
+        // only bundles signed with one of the trusted keys are accepted
+        let public_key = bundle.signature.as_ref().unwrap().0;
+        let other_key = SecretKey::new().public_key().into();
+        assert!(deser.check_signer(&[]).is_ok());
+        assert!(deser.check_signer(&[other_key, public_key]).is_ok());
+        assert!(deser.check_signer(&[other_key]).is_err());
+        assert!(BundleFile::new(deser.program.clone(), Vec::new(), options)
+            .check_signer(&[public_key])
+            .is_err());
+
+        // public keys are parsed from the hex encoding of their digest
+        let key_hex = hex::encode(miden_vm::Digest::from(public_key).as_bytes());
+        assert_eq!(BundleFile::parse_public_key(&key_hex).unwrap(), public_key);
+
+        // modified contents are detected
+        bundle.options.max_cycles += 1;
+        assert!(BundleFile::from_bytes(&bundle.to_bytes()).is_err());
+    }
 }
@@ -11,6 +11,11 @@ pub enum DebugCommand {
     PrintMem,
     PrintMemAddress(u64),
     Clock,
+    Watch,
+    WatchMem(u64, u64),
+    WatchStack(usize),
+    Unwatch(Option<usize>),
+    MemDiff(u32, u32),
     Quit,
     Help,
 }
@@ -39,6 +44,9 @@ impl DebugCommand {
             "r" | "rewind" => Self::Rewind,
             "p" | "print" => Self::parse_print(tokens.by_ref())?,
             "l" | "clock" => Self::Clock,
+            "w" | "watch" => Self::parse_watch(tokens.by_ref())?,
+            "u" | "unwatch" => Self::parse_unwatch(tokens.by_ref())?,
+            "d" | "memdiff" | "!memdiff" => Self::parse_memdiff(tokens.by_ref())?,
             "h" | "?" | "help" => Self::Help,
             "q" | "quit" => Self::Quit,
             _ => {
@@ -128,4 +136,71 @@ impl DebugCommand {
             (_, None) => Ok(command),
         }
     }
+
+    /// parse watch command - watch [m <start> [end] | s <index>]
+    fn parse_watch<'a, I>(mut tokens: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let command = match tokens.next() {
+            Some(c) => c,
+            None => return Ok(Self::Watch),
+        };
+
+        let mut parse_argument = |name: &str| match tokens.next() {
+            Some(t) => t.parse::<u64>().map(Some).map_err(|err| {
+                format!("malformed `watch` command - failed to parse {name}: `{t}` {err}")
+            }),
+            None => Ok(None),
+        };
+
+        match command {
+            "m" | "mem" => {
+                let start = parse_argument("start address")?
+                    .ok_or("malformed `watch` command - missing start address".to_string())?;
+                let end = parse_argument("end address")?.unwrap_or(start);
+                if end < start {
+                    return Err(format!(
+                        "malformed `watch` command - end address {end} is below {start}"
+                    ));
+                }
+                Ok(Self::WatchMem(start, end))
+            }
+            "s" | "stack" => {
+                let index = parse_argument("stack index")?
+                    .ok_or("malformed `watch` command - missing stack index".to_string())?;
+                Ok(Self::WatchStack(index as usize))
+            }
+            _ => Err(format!("malformed `watch` command - unexpected subcommand: `{command}`")),
+        }
+    }
+
+    /// parse unwatch command - unwatch [index]
+    fn parse_unwatch<'a, I>(mut tokens: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let index =
+            tokens.next().map(|t| t.parse::<usize>()).transpose().map_err(|err| {
+                format!("malformed `unwatch` command - failed to parse index: {err}")
+            })?;
+        Ok(Self::Unwatch(index))
+    }
+
+    /// parse memdiff command - memdiff <start> <end>
+    fn parse_memdiff<'a, I>(mut tokens: I) -> Result<Self, String>
+    where
+        I: Iterator<Item = &'a str>,
+    {
+        let mut parse_cycle = |name: &str| match tokens.next() {
+            Some(t) => t.parse::<u32>().map_err(|err| {
+                format!("malformed `memdiff` command - failed to parse {name} cycle: `{t}` {err}")
+            }),
+            None => Err(format!("malformed `memdiff` command - missing {name} cycle")),
+        };
+
+        let start = parse_cycle("start")?;
+        let end = parse_cycle("end")?;
+        Ok(Self::MemDiff(start, end))
+    }
 }
@@ -1,12 +1,14 @@
 use super::DebugCommand;
 use miden_vm::{
     math::Felt, DefaultHost, MemAdviceProvider, Program, StackInputs, VmState, VmStateIterator,
+    Watchpoint,
 };
 
 /// Holds debugger state and iterator used for debugging.
 pub struct DebugExecutor {
     vm_state_iter: VmStateIterator,
     vm_state: VmState,
+    watchpoints: Vec<Watchpoint>,
 }
 
 impl DebugExecutor {
@@ -33,6 +35,7 @@ impl DebugExecutor {
         Ok(Self {
             vm_state_iter,
             vm_state,
+            watchpoints: Vec::new(),
         })
     }
 
@@ -44,8 +47,7 @@ impl DebugExecutor {
         match command {
             DebugCommand::Continue => {
                 while let Some(new_vm_state) = self.next_vm_state() {
-                    self.vm_state = new_vm_state;
-                    if self.should_break() {
+                    if self.update_vm_state(new_vm_state) {
                         break;
                     }
                 }
@@ -55,8 +57,7 @@ impl DebugExecutor {
                 for _cycle in 0..cycles {
                     match self.next_vm_state() {
                         Some(next_vm_state) => {
-                            self.vm_state = next_vm_state;
-                            if self.should_break() {
+                            if self.update_vm_state(next_vm_state) {
                                 break;
                             }
                         }
@@ -75,8 +76,7 @@ impl DebugExecutor {
                 for _cycle in 0..cycles {
                     match self.vm_state_iter.back() {
                         Some(new_vm_state) => {
-                            self.vm_state = new_vm_state;
-                            if self.should_break() {
+                            if self.update_vm_state(new_vm_state) {
                                 break;
                             }
                         }
@@ -91,12 +91,57 @@ impl DebugExecutor {
             DebugCommand::PrintMem => self.print_memory(),
             DebugCommand::PrintMemAddress(address) => self.print_memory_entry(address),
             DebugCommand::Clock => println!("{}", self.vm_state.clk),
+            DebugCommand::Watch => self.print_watchpoints(),
+            DebugCommand::WatchMem(start, end) => {
+                self.add_watchpoint(Watchpoint::Memory(start..end.saturating_add(1)))
+            }
+            DebugCommand::WatchStack(index) => self.add_watchpoint(Watchpoint::Stack(index)),
+            DebugCommand::Unwatch(index) => self.remove_watchpoints(index),
+            DebugCommand::MemDiff(start, end) => self.print_memory_diff(start, end),
             DebugCommand::Help => Self::print_help(),
             DebugCommand::Quit => return false,
         }
         true
     }
 
+    /// adds a watchpoint at which the execution breaks when the watched state changes.
+    fn add_watchpoint(&mut self, watchpoint: Watchpoint) {
+        println!("watchpoint {} set on {watchpoint}", self.watchpoints.len());
+        self.watchpoints.push(watchpoint);
+    }
+
+    /// removes the watchpoint with the specified index, or all watchpoints if no index is given.
+    fn remove_watchpoints(&mut self, index: Option<usize>) {
+        match index {
+            Some(index) if index < self.watchpoints.len() => {
+                let watchpoint = self.watchpoints.remove(index);
+                println!("watchpoint {index} on {watchpoint} removed");
+            }
+            Some(index) => println!(
+                "invalid watchpoint index {index} - the index must be < {}",
+                self.watchpoints.len()
+            ),
+            None => {
+                self.watchpoints.clear();
+                println!("all watchpoints removed");
+            }
+        }
+    }
+
+    /// moves to the specified state, and returns `true` if the execution should break at it due
+    /// to a breakpoint or a triggered watchpoint.
+    fn update_vm_state(&mut self, vm_state: VmState) -> bool {
+        let mut triggered = false;
+        for (index, watchpoint) in self.watchpoints.iter().enumerate() {
+            if watchpoint.is_triggered(&self.vm_state, &vm_state) {
+                println!("watchpoint {index} on {watchpoint} triggered at clk={}", vm_state.clk);
+                triggered = true;
+            }
+        }
+        self.vm_state = vm_state;
+        triggered || self.should_break()
+    }
+
     /// iterates to the next clock cycle.
     fn next_vm_state(&mut self) -> Option<VmState> {
         match self.vm_state_iter.next() {
@@ -167,6 +212,27 @@ impl DebugExecutor {
         }
     }
 
+    /// print memory addresses whose contents differ between the specified clock cycles.
+    pub fn print_memory_diff(&self, start: u32, end: u32) {
+        let diff = self.vm_state_iter.memory_diff(start, end);
+        if diff.is_empty() {
+            println!("memory is the same at clock cycles {start} and {end}");
+        }
+        for entry in diff {
+            println!("{entry}");
+        }
+    }
+
+    /// print all watchpoints.
+    pub fn print_watchpoints(&self) {
+        if self.watchpoints.is_empty() {
+            println!("no watchpoints set");
+        }
+        for (index, watchpoint) in self.watchpoints.iter().enumerate() {
+            println!("[{index}] {watchpoint}");
+        }
+    }
+
     // HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -193,6 +259,13 @@ impl DebugExecutor {
             print stack        displays the complete state of the stack\n\
             print stack <i>    displays the stack element at index `i`\n\
             clock              displays the current clock cycle\n\
+            watch              displays all watchpoints\n\
+            watch mem <i>      breaks when memory at address `i` changes\n\
+            watch mem <i> <j>  breaks when memory at addresses `i` through `j` changes\n\
+            watch stack <i>    breaks when the stack element at index `i` changes\n\
+            unwatch            removes all watchpoints\n\
+            unwatch <i>        removes the watchpoint with index `i`\n\
+            memdiff <s> <e>    displays memory which differs between clock cycles `s` and `e`\n\
             quit               quits the debugger\n\
             help               displays this message\n\
             \n\
@@ -205,6 +278,9 @@ impl DebugExecutor {
             m -> mem\n\
             s -> stack\n\
             l -> clock\n\
+            w -> watch\n\
+            u -> unwatch\n\
+            d -> memdiff\n\
             q -> quit\n\
             h -> help\n\
             ? -> help";
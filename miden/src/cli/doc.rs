@@ -0,0 +1,116 @@
+use super::data::Libraries;
+use assembly::{LibraryDocs, LibraryNamespace, MaslLibrary, Version};
+use clap::{Parser, ValueEnum};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+use stdlib::StdLibrary;
+
+// DOC FORMAT
+// ================================================================================================
+
+/// Format of the generated documentation.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum DocFormat {
+    /// A single Markdown document.
+    #[default]
+    Markdown,
+    /// A single HTML page.
+    Html,
+}
+
+impl DocFormat {
+    /// Returns the extension of the files in this format.
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Markdown => "md",
+            Self::Html => "html",
+        }
+    }
+}
+
+// DOC COMMAND
+// ================================================================================================
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Generate documentation of a library from its doc comments")]
+pub struct DocCmd {
+    /// Path to a .masl library file, or to a directory containing the `.masm` files of a library;
+    /// the standard library is documented if no path is provided.
+    #[clap(value_parser)]
+    library: Option<PathBuf>,
+    /// Top-level namespace of a library read from a directory, otherwise the directory name is
+    /// used.
+    #[clap(short, long)]
+    namespace: Option<String>,
+    /// Version of a library read from a directory, defaults to `0.1.0`.
+    #[clap(short, long, default_value = "0.1.0")]
+    version: String,
+    /// Format of the generated documentation.
+    #[clap(long = "format", value_enum, default_value_t = DocFormat::Markdown)]
+    format: DocFormat,
+    /// Path to the output file, defaults to `<namespace>.md` or `<namespace>.html` in the current
+    /// directory.
+    #[clap(short = 'o', long = "output", value_parser)]
+    output_file: Option<PathBuf>,
+}
+
+impl DocCmd {
+    pub fn execute(&self) -> Result<(), String> {
+        println!("============================================================");
+        println!("Generate library documentation");
+        println!("============================================================");
+
+        let docs = match &self.library {
+            None => LibraryDocs::new(&StdLibrary::default()),
+            Some(path) if path.is_dir() => LibraryDocs::new(&self.read_library_dir(path)?),
+            Some(path) => {
+                let libraries = Libraries::new([path])?;
+                LibraryDocs::new(&libraries.libraries[0])
+            }
+        };
+
+        let contents = match self.format {
+            DocFormat::Markdown => docs.to_markdown(),
+            DocFormat::Html => docs.to_html(),
+        };
+
+        let out_path = self.output_file.clone().unwrap_or_else(|| {
+            PathBuf::from(format!("{}.{}", docs.namespace().as_ref(), self.format.extension()))
+        });
+        fs::write(&out_path, contents).map_err(|err| {
+            format!("Failed to write documentation to `{}` - {}", out_path.display(), err)
+        })?;
+
+        println!(
+            "Documented {} modules of library {} in {}",
+            docs.modules().len(),
+            docs.namespace().as_ref(),
+            out_path.display()
+        );
+
+        Ok(())
+    }
+
+    /// Reads the library with the `.masm` files in the specified directory.
+    fn read_library_dir(&self, dir: &Path) -> Result<MaslLibrary, String> {
+        let namespace = match &self.namespace {
+            Some(namespace) => namespace.to_string(),
+            None => dir
+                .file_name()
+                .ok_or_else(|| format!("Failed to read namespace of `{}`", dir.display()))?
+                .to_string_lossy()
+                .into_owned(),
+        };
+        let namespace = LibraryNamespace::try_from(namespace)
+            .map_err(|err| format!("Invalid library namespace - {err}"))?;
+
+        let version = Version::try_from(self.version.as_ref())
+            .map_err(|err| format!("Invalid library version - {err}"))?;
+
+        let with_source_locations = false;
+        MaslLibrary::read_from_dir(dir, namespace, with_source_locations, version)
+            .map_err(|err| format!("Failed to read library - {err}"))
+    }
+}
@@ -2,8 +2,12 @@ mod bundle;
 mod compile;
 mod data;
 mod debug;
+mod doc;
+mod new;
+mod pack;
 mod prove;
 mod repl;
+pub mod report;
 mod run;
 mod verify;
 
@@ -11,6 +15,9 @@ pub use bundle::BundleCmd;
 pub use compile::CompileCmd;
 pub use data::InputFile;
 pub use debug::DebugCmd;
+pub use doc::DocCmd;
+pub use new::NewCmd;
+pub use pack::PackCmd;
 pub use prove::ProveCmd;
 pub use repl::ReplCmd;
 pub use run::RunCmd;
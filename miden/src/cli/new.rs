@@ -0,0 +1,151 @@
+use assembly::LibraryNamespace;
+use clap::Parser;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// TEMPLATES
+// ================================================================================================
+
+/// Files of a new project as (path, template) pairs. Occurrences of `{{name}}` in the templates
+/// are replaced with the name of the project, and occurrences of `{{vm_dir}}` with the root of the
+/// VM checkout this binary was built from.
+const TEMPLATES: [(&str, &str); 9] = [
+    ("Cargo.toml", include_str!("templates/Cargo.toml.tmpl")),
+    ("README.md", include_str!("templates/README.md.tmpl")),
+    (".gitignore", include_str!("templates/gitignore.tmpl")),
+    ("kernel/kernel.masm", include_str!("templates/kernel.masm")),
+    ("lib/account.masm", include_str!("templates/account.masm")),
+    ("src/lib.rs", include_str!("templates/lib.rs.tmpl")),
+    ("src/main.masm", include_str!("templates/main.masm")),
+    ("src/main.inputs", include_str!("templates/main.inputs")),
+    ("tests/main.rs", include_str!("templates/tests.rs.tmpl")),
+];
+
+// NEW COMMAND
+// ================================================================================================
+
+#[derive(Debug, Clone, Parser)]
+#[clap(about = "Create a new Miden assembly project with a kernel, a library, a program and tests")]
+pub struct NewCmd {
+    /// Name of the project, which is also the namespace of the project's library
+    #[clap(value_parser)]
+    name: String,
+
+    /// Directory in which the project is created, defaults to `./<name>`
+    #[clap(short = 'p', long = "path", value_parser)]
+    path: Option<PathBuf>,
+}
+
+impl NewCmd {
+    pub fn execute(&self) -> Result<(), String> {
+        println!("============================================================");
+        println!("Create project");
+        println!("============================================================");
+
+        let dir = self.path.clone().unwrap_or_else(|| PathBuf::from(&self.name));
+        create_project(&self.name, &dir)?;
+
+        println!("Created project {} in {}", self.name, dir.display());
+        println!("Run `cargo test` in the project directory to test it.");
+        Ok(())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Creates a project with the specified name in the specified directory.
+///
+/// The directory must either not exist or be empty, so that existing files are never overwritten.
+fn create_project(name: &str, dir: &Path) -> Result<(), String> {
+    LibraryNamespace::try_from(name.to_string())
+        .map_err(|err| format!("Invalid project name `{name}` - {err}"))?;
+
+    if dir.exists() {
+        let mut entries = fs::read_dir(dir)
+            .map_err(|err| format!("Failed to read directory `{}` - {err}", dir.display()))?;
+        if entries.next().is_some() {
+            return Err(format!("Directory `{}` is not empty", dir.display()));
+        }
+    }
+
+    let vm_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the miden crate is a member of the VM workspace")
+        .display()
+        .to_string();
+    for (path, template) in TEMPLATES {
+        let path = dir.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(|err| {
+                format!("Failed to create directory `{}` - {err}", parent.display())
+            })?;
+        }
+        let contents = template.replace("{{name}}", name).replace("{{vm_dir}}", &vm_dir);
+        fs::write(&path, contents)
+            .map_err(|err| format!("Failed to write file `{}` - {err}", path.display()))?;
+    }
+
+    Ok(())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::create_project;
+    use assembly::{Assembler, LibraryNamespace, MaslLibrary, Version};
+    use miden_vm::{math::Felt, DefaultHost, ExecutionOptions, StackInputs};
+    use std::{fs, path::Path};
+
+    #[test]
+    fn new_project_runs() {
+        let dir = std::env::temp_dir().join("miden_new_project_runs");
+        let _ = fs::remove_dir_all(&dir);
+        create_project("my_project", &dir).unwrap();
+
+        // all placeholders are replaced
+        let main = fs::read_to_string(dir.join("src/main.masm")).unwrap();
+        assert!(main.starts_with("use.my_project::account"));
+        let manifest = fs::read_to_string(dir.join("Cargo.toml")).unwrap();
+        assert!(!manifest.contains("{{"));
+
+        // the dev-dependencies point to the crates of this checkout
+        let vm_dir = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        for crate_dir in ["assembly", "test-framework"] {
+            let path = format!("{}/{crate_dir}", vm_dir.display());
+            assert!(manifest.contains(&format!("path = '{path}'")));
+            assert!(Path::new(&path).join("Cargo.toml").exists());
+        }
+
+        // the program runs against the generated kernel and library
+        let namespace = LibraryNamespace::try_from("my_project".to_string()).unwrap();
+        let library =
+            MaslLibrary::read_from_dir(dir.join("lib"), namespace, true, Version::MIN).unwrap();
+        let kernel = fs::read_to_string(dir.join("kernel/kernel.masm")).unwrap();
+        let program = Assembler::default()
+            .with_library(&library)
+            .and_then(|assembler| assembler.with_kernel(&kernel))
+            .and_then(|assembler| assembler.compile(&main))
+            .unwrap();
+        let stack_inputs = StackInputs::try_from_ints([5]).unwrap();
+        let host = DefaultHost::default();
+        let trace =
+            miden_vm::execute(&program, stack_inputs, host, ExecutionOptions::default()).unwrap();
+        assert_eq!(trace.stack_outputs().stack_truncated(1), &[Felt::new(5)]);
+
+        // existing projects are never overwritten
+        assert!(create_project("my_project", &dir).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn new_project_invalid_name() {
+        let dir = std::env::temp_dir().join("miden_new_project_invalid_name");
+        assert!(create_project("my::project", &dir).is_err());
+        assert!(!dir.exists());
+    }
+}
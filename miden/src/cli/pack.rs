@@ -0,0 +1,116 @@
+use super::data::{BundleFile, BundleOptions, Debug, Libraries, LibraryDigest, ProgramFile};
+use clap::Parser;
+use miden_vm::{
+    utils::{Deserializable, Serializable},
+    ProgramInfo, ProgramRegistry,
+};
+use std::{fs, path::PathBuf};
+use stdlib::StdLibrary;
+use vm_core::crypto::dsa::rpo_falcon512::SecretKey;
+
+#[derive(Debug, Clone, Parser)]
+#[clap(
+    name = "Pack Program",
+    about = "Packs a .masm program into a single deployable .mbundle artifact"
+)]
+pub struct PackCmd {
+    /// Path to the `.masm` program file.
+    #[clap(value_parser)]
+    program_file: PathBuf,
+    /// Paths to .masl library files the program is compiled against.
+    #[clap(short = 'l', long = "libraries", value_parser)]
+    library_paths: Vec<PathBuf>,
+    /// Path to the bundle file, defaults to the program path with `.mbundle` extension.
+    #[clap(short = 'o', long = "output", value_parser)]
+    output_file: Option<PathBuf>,
+    /// Path to an RPO Falcon512 secret key used to sign the bundle; a new key is generated and
+    /// written to this path if the file does not exist.
+    #[clap(short = 'k', long = "key", value_parser)]
+    key_file: Option<PathBuf>,
+    /// Default security level for execution proofs: 96bits or 128bits.
+    #[clap(short = 's', long = "security", default_value = "96bits")]
+    security: String,
+    /// Default to proofs suitable for recursive verification.
+    #[clap(short = 'r', long = "recursive")]
+    recursive: bool,
+    /// Default maximum number of cycles the program is allowed to consume.
+    #[clap(short = 'm', long = "max-cycles", default_value = "4294967295")]
+    max_cycles: u32,
+    /// Default number of cycles the program is expected to consume.
+    #[clap(short = 'e', long = "exp-cycles", default_value = "64")]
+    expected_cycles: u32,
+    /// Path to a registry directory in which the program info is registered, so that proofs of the
+    /// program can be verified by the program hash alone.
+    #[clap(long = "registry", value_parser)]
+    registry_dir: Option<PathBuf>,
+}
+
+impl PackCmd {
+    /// Compiles the program (with debug info) and packages it into a bundle file.
+    pub fn execute(&self) -> Result<(), String> {
+        println!("============================================================");
+        println!("Pack program");
+        println!("============================================================");
+
+        let options = BundleOptions {
+            security: self.security.clone(),
+            recursive: self.recursive,
+            max_cycles: self.max_cycles,
+            expected_cycles: self.expected_cycles,
+        };
+        // make sure the bundle can be proven with its default options
+        options.proving_options()?;
+
+        let libraries = Libraries::new(&self.library_paths)?;
+        let program = ProgramFile::read(&self.program_file)?
+            .compile(&Debug::On, libraries.libraries.iter())?;
+
+        let mut library_digests = vec![LibraryDigest::new(&StdLibrary::default().into())];
+        library_digests.extend(libraries.libraries.iter().map(LibraryDigest::new));
+
+        let mut bundle = BundleFile::new(program, library_digests, options);
+
+        if let Some(key_file) = &self.key_file {
+            let secret_key = if key_file.exists() {
+                let bytes = fs::read(key_file).map_err(|err| {
+                    format!("Failed to open key file `{}` - {}", key_file.display(), err)
+                })?;
+                SecretKey::read_from_bytes(&bytes)
+                    .map_err(|err| format!("Failed to decode secret key - {}", err))?
+            } else {
+                let secret_key = SecretKey::new();
+                fs::write(key_file, secret_key.to_bytes()).map_err(|err| {
+                    format!("Failed to write key file `{}` - {}", key_file.display(), err)
+                })?;
+                println!("Generated a new signing key at {}", key_file.display());
+                secret_key
+            };
+            bundle.sign(&secret_key);
+        }
+
+        let out_path = self
+            .output_file
+            .clone()
+            .unwrap_or_else(|| self.program_file.with_extension("mbundle"));
+        bundle.write(&out_path)?;
+
+        if let Some(registry_dir) = &self.registry_dir {
+            ProgramRegistry::new(registry_dir)
+                .register(&ProgramInfo::from(bundle.program.clone()))
+                .map_err(|err| format!("Failed to register program - {}", err))?;
+            println!("Registered program info in {}", registry_dir.display());
+        }
+
+        println!(
+            "Built bundle of program with hash {} at {}",
+            hex::encode(bundle.program.hash().as_bytes()),
+            out_path.display()
+        );
+        if let Some((public_key, _)) = &bundle.signature {
+            let public_key: miden_vm::Digest = (*public_key).into();
+            println!("Signed with public key {}", hex::encode(public_key.as_bytes()));
+        }
+
+        Ok(())
+    }
+}
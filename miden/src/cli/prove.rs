@@ -1,25 +1,88 @@
-use super::data::{instrument, Debug, InputFile, Libraries, OutputFile, ProgramFile, ProofFile};
+use super::{
+    data::{
+        instrument, BundleFile, BundleOptions, Debug, InputFile, Libraries, MemoryFile, OutputFile,
+        ProgramFile, ProofFile,
+    },
+    report::{millis, output_values, OutputFormat, ProveReport, Timing},
+};
 use clap::Parser;
-use miden_vm::ProvingOptions;
-use processor::{DefaultHost, ExecutionOptions, ExecutionOptionsError, Program};
+use miden_vm::{MemoryUsage, ProgressObserver, ProverMemoryBudget, ProvingOptions, ProvingStage};
+use processor::{DefaultHost, ExecutionOptions, ExecutionOptionsError, Program, VmProfile, Word};
 
-use std::{path::PathBuf, time::Instant};
+use std::{
+    cell::Cell,
+    io::{self, IsTerminal, Write},
+    ops::Range,
+    path::PathBuf,
+    time::Instant,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Width of the proving progress bar in characters.
+const PROGRESS_BAR_WIDTH: usize = 40;
 
 #[derive(Debug, Clone, Parser)]
 #[clap(about = "Prove a miden program")]
 pub struct ProveCmd {
     /// Path to .masm assembly file
-    #[clap(short = 'a', long = "assembly", value_parser)]
-    assembly_file: PathBuf,
+    #[clap(
+        short = 'a',
+        long = "assembly",
+        value_parser,
+        required_unless_present = "bundle_file"
+    )]
+    assembly_file: Option<PathBuf>,
+
+    /// Path to .mbundle program bundle; the program is proven with the proving options stored in
+    /// the bundle
+    #[clap(
+        short = 'b',
+        long = "bundle",
+        value_parser,
+        conflicts_with_all = [
+            "assembly_file", "entrypoint", "library_paths", "max_cycles", "expected_cycles",
+            "recursive", "security"
+        ]
+    )]
+    bundle_file: Option<PathBuf>,
+
+    /// Range of memory addresses of the root context exported once the program finishes
+    /// executing; the exported memory is not covered by the proof. Can be repeated
+    #[clap(long = "dump-memory", value_name = "START..END", value_parser = MemoryFile::parse_range)]
+    dump_ranges: Vec<Range<u32>>,
+
+    /// Name of the exported procedure to prove; the assembly file is compiled as a package with
+    /// one entrypoint per exported procedure
+    #[clap(long = "entrypoint")]
+    entrypoint: Option<String>,
+
+    /// Optional chiplets (bitwise, memory) to exclude from the VM profile
+    #[clap(long = "exclude-chiplet")]
+    excluded_chiplets: Vec<String>,
 
     /// Number of cycles the program is expected to consume
     #[clap(short = 'e', long = "exp-cycles", default_value = "64")]
     expected_cycles: u32,
 
-    /// Path to input file
+    /// Format in which the results are printed; `json` prints a single JSON object
+    #[clap(long = "format", value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Path to input file; `-` reads the input data from stdin
     #[clap(short = 'i', long = "input", value_parser)]
     input_file: Option<PathBuf>,
 
+    /// Inline input of the form `<section>=<values>` (e.g., `stack=[1,2,3]`), where the section
+    /// is either `stack` or `advice_stack`; replaces the section of the input file
+    #[clap(long = "inline-input", value_name = "SECTION=VALUES")]
+    inline_inputs: Vec<String>,
+
+    /// Label to be included into the proof metadata; implies `--metadata`
+    #[clap(long = "label")]
+    label: Option<String>,
+
     /// Paths to .masl library files
     #[clap(short = 'l', long = "libraries", value_parser)]
     library_paths: Vec<PathBuf>,
@@ -28,6 +91,24 @@ pub struct ProveCmd {
     #[clap(short = 'm', long = "max-cycles", default_value = "4294967295")]
     max_cycles: u32,
 
+    /// Path to a file with the contents of memory provided to the program via the advice map under
+    /// the commitment to the memory; the program loads it via `std::mem::load_image`
+    #[clap(long = "memory", value_parser)]
+    memory_file: Option<PathBuf>,
+
+    /// Maximum amount of memory (in MB) proof generation is allowed to require; the program is not
+    /// proven if the estimated memory usage exceeds it
+    #[clap(long = "memory-budget", value_name = "MB")]
+    memory_budget: Option<usize>,
+
+    /// Path to a file to which the memory exported via `--dump-memory` is written
+    #[clap(long = "memory-output", value_parser, requires = "dump_ranges")]
+    memory_output_file: Option<PathBuf>,
+
+    /// Embed metadata (program hash, VM version, proving options, and timestamp) into the proof
+    #[clap(long = "metadata")]
+    metadata: bool,
+
     /// Number of outputs
     #[clap(short = 'n', long = "num-outputs", default_value = "16")]
     num_outputs: usize,
@@ -44,91 +125,275 @@ pub struct ProveCmd {
     #[clap(short = 'r', long = "recursive")]
     recursive: bool,
 
-    /// Security level for execution proofs generated by the VM
+    /// Security level for execution proofs generated by the VM: 96bits, 128bits, or
+    /// 128bits-proven
     #[clap(short = 's', long = "security", default_value = "96bits")]
     security: String,
 
     /// Enable tracing to monitor execution of the VM
     #[clap(short = 't', long = "tracing")]
     tracing: bool,
+
+    /// Public key (hex) of a trusted bundle signer; if any trusted keys are specified, the bundle
+    /// must be signed with one of them. Can be repeated
+    #[clap(
+        long = "trusted-key",
+        value_name = "KEY",
+        requires = "bundle_file",
+        value_parser = BundleFile::parse_public_key
+    )]
+    trusted_keys: Vec<Word>,
 }
 
 impl ProveCmd {
-    pub fn get_proof_options(&self) -> Result<ProvingOptions, ExecutionOptionsError> {
+    /// Returns the proving options for the program; the options stored in the bundle take the
+    /// place of the security, recursion and cycle settings if a bundle is proven.
+    pub fn get_proof_options(
+        &self,
+        bundle_options: Option<&BundleOptions>,
+    ) -> Result<ProvingOptions, String> {
+        let options = match bundle_options {
+            Some(bundle_options) => bundle_options.proving_options()?,
+            None => self.get_default_proof_options().map_err(|err| format!("{err}"))?,
+        };
+        let options = match self.memory_budget {
+            Some(megabytes) => options.with_memory_budget(ProverMemoryBudget::new(megabytes << 20)),
+            None => options,
+        };
+
+        if self.metadata || self.label.is_some() {
+            Ok(options.with_metadata(self.label.clone()))
+        } else {
+            Ok(options)
+        }
+    }
+
+    /// Returns the proving options specified via the command-line arguments.
+    fn get_default_proof_options(&self) -> Result<ProvingOptions, ExecutionOptionsError> {
+        let mut profile = VmProfile::full();
+        for chiplet in self.excluded_chiplets.iter() {
+            profile = match chiplet.as_str() {
+                "bitwise" => profile.without_bitwise(),
+                "memory" => profile.without_memory(),
+                other => {
+                    return Err(ExecutionOptionsError::OtherErrors(format!(
+                        "{other} is not an optional chiplet"
+                    )))
+                }
+            };
+        }
         let exec_options =
-            ExecutionOptions::new(Some(self.max_cycles), self.expected_cycles, self.tracing)?;
-        Ok(match self.security.as_str() {
+            ExecutionOptions::new(Some(self.max_cycles), self.expected_cycles, self.tracing)?
+                .with_profile(profile);
+        let options = match self.security.as_str() {
             "96bits" => ProvingOptions::with_96_bit_security(self.recursive),
             "128bits" => ProvingOptions::with_128_bit_security(self.recursive),
+            "128bits-proven" => ProvingOptions::with_128bit_proven(self.recursive),
             other => panic!("{} is not a valid security setting", other),
         }
-        .with_execution_options(exec_options))
+        .with_execution_options(exec_options);
+        Ok(options)
     }
 
     pub fn execute(&self) -> Result<(), String> {
-        println!("===============================================================================");
-        println!("Prove program: {}", self.assembly_file.display());
-        println!("-------------------------------------------------------------------------------");
+        self.format
+            .print_banner(&format!("Prove program: {}", self.program_path().display()));
 
-        let (program, input_data) = load_data(self)?;
+        let now = Instant::now();
+        let (program, bundle_options, input_data) = load_data(self)?;
+        let compile_time = now.elapsed();
 
         let program_hash: [u8; 32] = program.hash().into();
-        println!("Proving program with hash {}...", hex::encode(program_hash));
+        if self.format.is_text() {
+            println!("Proving program with hash {}...", hex::encode(program_hash));
+        }
         let now = Instant::now();
 
         // fetch the stack and program inputs from the arguments
         let stack_inputs = input_data.parse_stack_inputs()?;
-        let host = DefaultHost::new(input_data.parse_advice_provider()?);
+        let mut adv_provider = input_data.parse_advice_provider()?;
 
-        let proving_options = self.get_proof_options().map_err(|err| format!("{err}"))?;
+        // provide the memory image to the program via the advice map
+        if let Some(memory_path) = &self.memory_file {
+            let memory_image = MemoryFile::read_into_advice(memory_path, &mut adv_provider)?;
+            if self.format.is_text() {
+                println!(
+                    "Providing {} words of memory with commitment {}",
+                    memory_image.len(),
+                    hex::encode(memory_image.commitment().as_bytes())
+                );
+            }
+        }
+
+        let mut host =
+            DefaultHost::new(adv_provider).with_error_codes(program.error_codes().clone());
+        let proving_options = self.get_proof_options(bundle_options.as_ref())?;
+
+        // export the requested ranges of memory
+        if !self.dump_ranges.is_empty() {
+            host = host
+                .with_memory_outputs(self.dump_ranges.iter().cloned())
+                .map_err(|err| format!("Invalid memory ranges: {err}"))?;
+        }
+
+        // the progress bar is shown only when stderr is not redirected
+        let show_progress = self.format.is_text() && io::stderr().is_terminal();
+        let progress = ProofProgress::new(show_progress);
 
         // execute program and generate proof
-        let (stack_outputs, proof) =
-            prover::prove(&program, stack_inputs, host, proving_options)
-                .map_err(|err| format!("Failed to prove program - {:?}", err))?;
-
-        println!(
-            "Program with hash {} proved in {} ms",
-            hex::encode(program_hash),
-            now.elapsed().as_millis()
-        );
+        let (stack_outputs, memory_outputs, proof) = prover::prove_with_memory_outputs(
+            &program,
+            stack_inputs,
+            host,
+            proving_options,
+            &progress,
+        )
+        .map_err(|err| format!("Failed to prove program - {:?}", err))?;
+        let prove_time = now.elapsed();
+        let memory_usage = progress.memory_usage.get().unwrap_or_default();
+
+        if self.format.is_text() {
+            println!(
+                "Program with hash {} proved in {} ms",
+                hex::encode(program_hash),
+                prove_time.as_millis()
+            );
+            println!("Estimated peak memory usage: {memory_usage}");
+        }
 
         // write proof to file
-        ProofFile::write(proof, &self.proof_file, &self.assembly_file)?;
+        let security_level = proof.security_level();
+        let proof_path = ProofFile::write(proof, &self.proof_file, self.program_path())?;
 
-        // provide outputs
-        if let Some(output_path) = &self.output_file {
-            // write all outputs to specified file.
-            OutputFile::write(&stack_outputs, output_path)?;
-        } else {
-            // if no output path was provided, get the stack outputs for printing to the screen.
-            let stack = stack_outputs.stack_truncated(self.num_outputs).to_vec();
+        // provide outputs; if no output path was provided, write all outputs to default location
+        let output_path = match &self.output_file {
+            Some(output_path) => output_path.clone(),
+            None => self.program_path().with_extension("outputs"),
+        };
+        OutputFile::write(&stack_outputs, &output_path)?;
+        if let Some(memory_output_path) = &self.memory_output_file {
+            MemoryFile::write(&memory_outputs, memory_output_path)?;
+        }
+        let stack = stack_outputs.stack_truncated(self.num_outputs).to_vec();
 
-            // write all outputs to default location if none was provided
-            OutputFile::write(&stack_outputs, &self.assembly_file.with_extension("outputs"))?;
+        if !self.format.is_text() {
+            let report = ProveReport {
+                program_hash: hex::encode(program_hash),
+                outputs: output_values(&stack),
+                output_file: output_path,
+                proof_file: proof_path,
+                security_level,
+                peak_memory_bytes: memory_usage.peak(),
+                timing: Timing {
+                    compile_ms: millis(compile_time),
+                    prove_ms: millis(prove_time),
+                    ..Default::default()
+                },
+            };
+            return self.format.print_report(&report);
+        }
 
+        if self.output_file.is_none() {
             // print stack outputs to screen.
             println!("Output: {:?}", stack);
         }
+        if !self.dump_ranges.is_empty() && self.memory_output_file.is_none() {
+            print!("Memory outputs:\n{}", MemoryFile::to_text(&memory_outputs));
+        }
 
         Ok(())
     }
+
+    /// Returns the path to the program file, which is either a `.masm` file or a bundle.
+    fn program_path(&self) -> &PathBuf {
+        match (&self.bundle_file, &self.assembly_file) {
+            (Some(path), _) | (None, Some(path)) => path,
+            (None, None) => unreachable!("either an assembly file or a bundle must be specified"),
+        }
+    }
 }
 
 // HELPER FUNCTIONS
 // ================================================================================================
 
 #[instrument(skip_all)]
-fn load_data(params: &ProveCmd) -> Result<(Program, InputFile), String> {
-    // load libraries from files
-    let libraries = Libraries::new(&params.library_paths)?;
+fn load_data(params: &ProveCmd) -> Result<(Program, Option<BundleOptions>, InputFile), String> {
+    let (program, bundle_options) = match &params.bundle_file {
+        Some(bundle_path) => {
+            // load the compiled program and the proving options from the bundle
+            let bundle = BundleFile::read(bundle_path, &params.trusted_keys)?;
+            (bundle.program, Some(bundle.options))
+        }
+        None => {
+            // load libraries from files
+            let libraries = Libraries::new(&params.library_paths)?;
 
-    // load program from file and compile
-    let program =
-        ProgramFile::read(&params.assembly_file)?.compile(&Debug::Off, libraries.libraries)?;
+            // load program from file and compile
+            let program = ProgramFile::read_with_entrypoint(
+                params.program_path(),
+                params.entrypoint.as_deref(),
+            )?
+            .compile(&Debug::Off, libraries.libraries)?;
+            (program, None)
+        }
+    };
 
     // load input data from file
-    let input_data = InputFile::read(&params.input_file, &params.assembly_file)?;
+    let input_data = InputFile::read(&params.input_file, params.program_path())?
+        .with_inline_inputs(&params.inline_inputs)?;
+
+    Ok((program, bundle_options, input_data))
+}
+
+// PROOF PROGRESS
+// ================================================================================================
+
+/// Tracks the progress of proof generation, optionally rendering it as a progress bar on stderr,
+/// and records the estimated memory usage reported by the prover.
+struct ProofProgress {
+    show_bar: bool,
+    memory_usage: Cell<Option<MemoryUsage>>,
+}
+
+impl ProofProgress {
+    fn new(show_bar: bool) -> Self {
+        Self {
+            show_bar,
+            memory_usage: Cell::new(None),
+        }
+    }
+
+    /// Redraws the progress bar with the specified percentage and label.
+    fn render(&self, percentage: u8, label: &str) {
+        let filled = PROGRESS_BAR_WIDTH * percentage as usize / 100;
+        let mut stderr = io::stderr();
+        let _ = write!(
+            stderr,
+            "\r[{}{}] {:>3}% {:<40}",
+            "#".repeat(filled),
+            " ".repeat(PROGRESS_BAR_WIDTH - filled),
+            percentage,
+            label
+        );
+        let _ = stderr.flush();
+    }
+}
 
-    Ok((program, input_data))
+impl ProgressObserver for ProofProgress {
+    fn on_stage_started(&self, stage: ProvingStage) {
+        if self.show_bar {
+            self.render(stage.start_percentage(), &stage.to_string());
+        }
+    }
+
+    fn on_stage_completed(&self, stage: ProvingStage) {
+        if self.show_bar && stage == ProvingStage::Fri {
+            self.render(stage.end_percentage(), "done");
+            eprintln!();
+        }
+    }
+
+    fn on_memory_usage(&self, usage: &MemoryUsage) {
+        self.memory_usage.set(Some(*usage));
+    }
 }
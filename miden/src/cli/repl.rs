@@ -1,15 +1,24 @@
 use clap::Parser;
-use std::path::PathBuf;
+use std::{fs, path::PathBuf};
 
+use super::data::InputFile;
 use crate::repl::start_repl;
 
 #[derive(Debug, Clone, Parser)]
 #[clap(about = "Initiates the Miden REPL tool")]
 pub struct ReplCmd {
+    /// Path to input file with the initial state of the stack and of the advice provider
+    #[clap(short = 'i', long = "input", value_parser)]
+    input_file: Option<PathBuf>,
+
     /// Paths to .masl library files
     #[clap(short = 'l', long = "libraries", value_parser)]
     library_paths: Vec<PathBuf>,
 
+    /// Path to a file with REPL lines which are executed instead of reading lines interactively
+    #[clap(long = "script", value_parser)]
+    script_path: Option<PathBuf>,
+
     /// Usage of standard library
     #[clap(short = 's', long = "stdlib")]
     use_stdlib: bool,
@@ -17,8 +26,24 @@ pub struct ReplCmd {
 
 impl ReplCmd {
     pub fn execute(&self) -> Result<(), String> {
+        // load input data; if no input file was specified, the REPL starts with empty inputs
+        let inputs = match &self.input_file {
+            Some(_) => InputFile::read(&self.input_file, &PathBuf::new())?,
+            None => InputFile::default(),
+        };
+
+        // load the script lines
+        let script = match &self.script_path {
+            Some(path) => {
+                let script = fs::read_to_string(path).map_err(|err| {
+                    format!("Failed to open script file `{}` - {}", path.display(), err)
+                })?;
+                Some(script.lines().map(String::from).collect())
+            }
+            None => None,
+        };
+
         // initiates repl tool.
-        start_repl(&self.library_paths, self.use_stdlib);
-        Ok(())
+        start_repl(&self.library_paths, self.use_stdlib, inputs, script)
     }
 }
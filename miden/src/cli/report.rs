@@ -0,0 +1,162 @@
+use clap::ValueEnum;
+use processor::{Felt, TraceLenSummary};
+use serde_derive::Serialize;
+use std::{path::PathBuf, time::Duration};
+
+// OUTPUT FORMAT
+// ================================================================================================
+
+/// Format in which the results of a command are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable text.
+    #[default]
+    Text,
+    /// A single JSON object printed once the command completes.
+    Json,
+}
+
+impl OutputFormat {
+    /// Returns true if the results should be printed as human-readable text.
+    pub fn is_text(&self) -> bool {
+        matches!(self, Self::Text)
+    }
+
+    /// Prints the banner which opens the text output of a command if this format is text;
+    /// otherwise, does nothing.
+    pub fn print_banner(&self, title: &str) {
+        if !self.is_text() {
+            return;
+        }
+        println!("===============================================================================");
+        println!("{title}");
+        println!("-------------------------------------------------------------------------------");
+    }
+
+    /// Prints the specified report as a single line of JSON if this format is JSON; otherwise,
+    /// does nothing.
+    pub fn print_report<R: serde::Serialize>(&self, report: &R) -> Result<(), String> {
+        if let Self::Json = self {
+            let json = serde_json::to_string(report)
+                .map_err(|err| format!("Failed to serialize report - {err}"))?;
+            println!("{json}");
+        }
+        Ok(())
+    }
+}
+
+// REPORTS
+// ================================================================================================
+
+/// Results of the `run` command.
+#[derive(Debug, Serialize)]
+pub struct RunReport {
+    pub program_hash: String,
+    pub outputs: Vec<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_file: Option<PathBuf>,
+    pub cycles: CycleCounts,
+    pub timing: Timing,
+}
+
+/// Results of the `prove` command.
+#[derive(Debug, Serialize)]
+pub struct ProveReport {
+    pub program_hash: String,
+    pub outputs: Vec<u64>,
+    pub output_file: PathBuf,
+    pub proof_file: PathBuf,
+    pub security_level: u32,
+    pub peak_memory_bytes: usize,
+    pub timing: Timing,
+}
+
+/// Results of the `verify` command.
+#[derive(Debug, Serialize)]
+pub struct VerifyReport {
+    pub program_hash: String,
+    pub verified: bool,
+    pub security_level: u32,
+    pub proven_security_level: u32,
+    pub field_security: u32,
+    pub query_security: u32,
+    pub hash_security: u32,
+    pub timing: Timing,
+}
+
+/// Results of the `analyze` command.
+#[derive(Debug, Serialize)]
+pub struct AnalyzeReport {
+    pub program: PathBuf,
+    pub cycles: CycleCounts,
+    pub total_noops: usize,
+    pub max_stack_depth: usize,
+    pub instructions: Vec<InstructionStats>,
+    pub timing: Timing,
+}
+
+/// Execution statistics of an assembly instruction.
+#[derive(Debug, Serialize)]
+pub struct InstructionStats {
+    pub op: String,
+    pub frequency: usize,
+    pub total_cycles: usize,
+}
+
+// REPORT COMPONENTS
+// ================================================================================================
+
+/// Numbers of rows in the execution trace and its components.
+#[derive(Debug, Serialize)]
+pub struct CycleCounts {
+    pub trace_len: usize,
+    pub padded_trace_len: usize,
+    pub stack_rows: usize,
+    pub range_checker_rows: usize,
+    pub chiplets_rows: usize,
+    pub hash_chiplet_rows: usize,
+    pub bitwise_chiplet_rows: usize,
+    pub memory_chiplet_rows: usize,
+    pub kernel_rom_rows: usize,
+}
+
+impl From<&TraceLenSummary> for CycleCounts {
+    fn from(summary: &TraceLenSummary) -> Self {
+        let chiplets = summary.chiplets_trace_len();
+        Self {
+            trace_len: summary.trace_len(),
+            padded_trace_len: summary.padded_trace_len(),
+            stack_rows: summary.main_trace_len(),
+            range_checker_rows: summary.range_trace_len(),
+            chiplets_rows: chiplets.trace_len(),
+            hash_chiplet_rows: chiplets.hash_chiplet_len(),
+            bitwise_chiplet_rows: chiplets.bitwise_chiplet_len(),
+            memory_chiplet_rows: chiplets.memory_chiplet_len(),
+            kernel_rom_rows: chiplets.kernel_rom_len(),
+        }
+    }
+}
+
+/// Durations of the stages of a command in milliseconds; stages which were not performed by the
+/// command are omitted.
+#[derive(Debug, Default, Serialize)]
+pub struct Timing {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compile_ms: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execute_ms: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prove_ms: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verify_ms: Option<u128>,
+}
+
+/// Returns the duration in whole milliseconds.
+pub fn millis(duration: Duration) -> Option<u128> {
+    Some(duration.as_millis())
+}
+
+/// Returns the integer values of the specified stack outputs.
+pub fn output_values(outputs: &[Felt]) -> Vec<u64> {
+    outputs.iter().map(|value| value.as_int()).collect()
+}
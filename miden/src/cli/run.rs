@@ -1,23 +1,78 @@
-use super::data::{instrument, Debug, InputFile, Libraries, OutputFile, ProgramFile};
+use super::{
+    data::{
+        instrument, BundleFile, Debug, InputFile, Libraries, MemoryFile, OutputFile, ProgramFile,
+    },
+    report::{millis, output_values, CycleCounts, OutputFormat, RunReport, Timing},
+};
 use clap::Parser;
-use processor::{DefaultHost, ExecutionOptions, ExecutionTrace};
-use std::{path::PathBuf, time::Instant};
+use processor::{DefaultHost, ExecutionOptions, ExecutionTrace, Felt, Word};
+use std::{
+    collections::BTreeMap,
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Interval at which watched files are polled for changes in watch mode.
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Extensions of files in the source tree of the program which trigger a re-run in watch mode.
+const WATCHED_EXTENSIONS: [&str; 2] = ["masm", "inputs"];
 
 #[derive(Debug, Clone, Parser)]
 #[clap(about = "Run a miden program")]
 pub struct RunCmd {
     /// Path to .masm assembly file
-    #[clap(short = 'a', long = "assembly", value_parser)]
-    assembly_file: PathBuf,
+    #[clap(
+        short = 'a',
+        long = "assembly",
+        value_parser,
+        required_unless_present = "bundle_file"
+    )]
+    assembly_file: Option<PathBuf>,
+
+    /// Path to .mbundle program bundle; the program is run with the cycle limits stored in the
+    /// bundle
+    #[clap(
+        short = 'b',
+        long = "bundle",
+        value_parser,
+        conflicts_with_all = ["assembly_file", "library_paths"]
+    )]
+    bundle_file: Option<PathBuf>,
+
+    /// Range of memory addresses of the root context exported once the program finishes
+    /// executing; can be repeated
+    #[clap(long = "dump-memory", value_name = "START..END", value_parser = MemoryFile::parse_range)]
+    dump_ranges: Vec<Range<u32>>,
+
+    /// Name of the exported procedure to run; the assembly file is compiled as a package with one
+    /// entrypoint per exported procedure
+    #[clap(long = "entrypoint", conflicts_with = "bundle_file")]
+    entrypoint: Option<String>,
 
     /// Number of cycles the program is expected to consume
     #[clap(short = 'e', long = "exp-cycles", default_value = "64")]
     expected_cycles: u32,
 
-    /// Path to input file
+    /// Format in which the results are printed; `json` prints a single JSON object per run
+    #[clap(long = "format", value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Path to input file; `-` reads the input data from stdin
     #[clap(short = 'i', long = "input", value_parser)]
     input_file: Option<PathBuf>,
 
+    /// Inline input of the form `<section>=<values>` (e.g., `stack=[1,2,3]`), where the section
+    /// is either `stack` or `advice_stack`; replaces the section of the input file
+    #[clap(long = "inline-input", value_name = "SECTION=VALUES")]
+    inline_inputs: Vec<String>,
+
     /// Paths to .masl library files
     #[clap(short = 'l', long = "libraries", value_parser)]
     library_paths: Vec<PathBuf>,
@@ -26,6 +81,15 @@ pub struct RunCmd {
     #[clap(short = 'm', long = "max-cycles", default_value = "4294967295")]
     max_cycles: u32,
 
+    /// Path to a file with the contents of memory provided to the program via the advice map under
+    /// the commitment to the memory; the program loads it via `std::mem::load_image`
+    #[clap(long = "memory", value_parser)]
+    memory_file: Option<PathBuf>,
+
+    /// Path to a file to which the memory exported via `--dump-memory` is written
+    #[clap(long = "memory-output", value_parser, requires = "dump_ranges")]
+    memory_output_file: Option<PathBuf>,
+
     /// Number of outputs
     #[clap(short = 'n', long = "num-outputs", default_value = "16")]
     num_outputs: usize,
@@ -34,20 +98,104 @@ pub struct RunCmd {
     #[clap(short = 'o', long = "output", value_parser)]
     output_file: Option<PathBuf>,
 
+    /// Stack depth above which a warning is logged; deep stacks spill into the overflow table,
+    /// which slows down execution
+    #[clap(long = "stack-depth-warning", value_name = "DEPTH")]
+    stack_depth_warning: Option<u32>,
+
     /// Enable tracing to monitor execution of the VM
     #[clap(short = 't', long = "tracing")]
     tracing: bool,
+
+    /// Public key (hex) of a trusted bundle signer; if any trusted keys are specified, the bundle
+    /// must be signed with one of them. Can be repeated
+    #[clap(
+        long = "trusted-key",
+        value_name = "KEY",
+        requires = "bundle_file",
+        value_parser = BundleFile::parse_public_key
+    )]
+    trusted_keys: Vec<Word>,
+
+    /// Watch the program source tree, the input file and the libraries, and re-run the program
+    /// whenever any of them changes
+    #[clap(short = 'w', long = "watch")]
+    watch: bool,
 }
 
 impl RunCmd {
     pub fn execute(&self) -> Result<(), String> {
-        println!("===============================================================================");
-        println!("Run program: {}", self.assembly_file.display());
-        println!("-------------------------------------------------------------------------------");
+        if !self.watch {
+            let libraries = Libraries::new(&self.library_paths)?;
+            return self.run(&libraries).map(|_| ());
+        }
+
+        let mut watcher = SourceWatcher::new(self.watched_paths(), self.source_root());
+        let mut libraries = None;
+        let mut previous_outputs: Option<Vec<Felt>> = None;
+        loop {
+            // libraries are reloaded only if some of the library files changed
+            if libraries.is_none() || watcher.libraries_changed(&self.library_paths) {
+                libraries = match Libraries::new(&self.library_paths) {
+                    Ok(libraries) => Some(libraries),
+                    Err(err) => {
+                        println!("{err}");
+                        None
+                    }
+                };
+            }
+
+            if let Some(libraries) = &libraries {
+                match self.run(libraries) {
+                    Ok(outputs) => {
+                        match &previous_outputs {
+                            Some(previous) if self.format.is_text() => {
+                                print_outputs_diff(previous, &outputs)
+                            }
+                            _ => (),
+                        }
+                        previous_outputs = Some(outputs);
+                    }
+                    Err(err) => println!("{err}"),
+                }
+            }
+
+            if self.format.is_text() {
+                println!("Watching for changes...");
+            }
+            watcher.wait_for_change(self.format.is_text());
+        }
+    }
+
+    /// Compiles and runs the program once, and returns the top `num_outputs` stack outputs.
+    fn run(&self, libraries: &Libraries) -> Result<Vec<Felt>, String> {
+        self.format
+            .print_banner(&format!("Run program: {}", self.program_path().display()));
 
         let now = Instant::now();
 
-        let (trace, program_hash) = run_program(self)?;
+        let (trace, program_hash, timing) = run_program(self, libraries)?;
+        let outputs = trace.stack_outputs().stack_truncated(self.num_outputs).to_vec();
+
+        if let Some(output_path) = &self.output_file {
+            // write outputs to file if one was specified
+            OutputFile::write(trace.stack_outputs(), output_path)?;
+        }
+        if let Some(memory_output_path) = &self.memory_output_file {
+            MemoryFile::write(trace.memory_outputs(), memory_output_path)?;
+        }
+
+        if !self.format.is_text() {
+            let report = RunReport {
+                program_hash: hex::encode(program_hash),
+                outputs: output_values(&outputs),
+                output_file: self.output_file.clone(),
+                cycles: CycleCounts::from(trace.trace_len_summary()),
+                timing,
+            };
+            self.format.print_report(&report)?;
+            return Ok(outputs);
+        }
 
         println!(
             "Executed the program with hash {} in {} ms",
@@ -55,12 +203,12 @@ impl RunCmd {
             now.elapsed().as_millis()
         );
 
-        if let Some(output_path) = &self.output_file {
-            // write outputs to file if one was specified
-            OutputFile::write(trace.stack_outputs(), output_path)?;
-        } else {
+        if self.output_file.is_none() {
             // write the stack outputs to the screen.
-            println!("Output: {:?}", trace.stack_outputs().stack_truncated(self.num_outputs));
+            println!("Output: {:?}", outputs);
+        }
+        if !self.dump_ranges.is_empty() && self.memory_output_file.is_none() {
+            print!("Memory outputs:\n{}", MemoryFile::to_text(trace.memory_outputs()));
         }
 
         // calculate the percentage of padded rows
@@ -89,8 +237,103 @@ impl RunCmd {
             trace.trace_len_summary().chiplets_trace_len().memory_chiplet_len(),
             trace.trace_len_summary().chiplets_trace_len().kernel_rom_len(),
         );
+        println!("Maximum stack depth: {}", trace.max_stack_depth());
+
+        Ok(outputs)
+    }
+
+    /// Returns the path to the program file, which is either a `.masm` file or a bundle.
+    fn program_path(&self) -> &Path {
+        match (&self.bundle_file, &self.assembly_file) {
+            (Some(path), _) | (None, Some(path)) => path,
+            (None, None) => unreachable!("either an assembly file or a bundle must be specified"),
+        }
+    }
 
-        Ok(())
+    /// Returns the files watched in watch mode in addition to the source tree of the program: the
+    /// program file, the input file, the memory file and the library files.
+    fn watched_paths(&self) -> Vec<PathBuf> {
+        let mut paths = vec![self.program_path().to_path_buf()];
+        paths.push(match &self.input_file {
+            Some(path) => path.clone(),
+            None => self.program_path().with_extension("inputs"),
+        });
+        paths.extend(self.memory_file.iter().cloned());
+        paths.extend(self.library_paths.iter().cloned());
+        paths
+    }
+
+    /// Returns the root of the source tree of the program, i.e., the directory containing the
+    /// program file.
+    fn source_root(&self) -> PathBuf {
+        match self.program_path().parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+            _ => PathBuf::from("."),
+        }
+    }
+}
+
+// SOURCE WATCHER
+// ================================================================================================
+
+/// Detects changes of the files a program run depends on by polling their modification times.
+///
+/// The watched files are the explicitly specified paths, and all files with one of the
+/// [WATCHED_EXTENSIONS] in the source tree of the program. Files which are created or removed
+/// between polls are detected as changes as well.
+struct SourceWatcher {
+    paths: Vec<PathBuf>,
+    root: PathBuf,
+    snapshot: BTreeMap<PathBuf, SystemTime>,
+    changed: Vec<PathBuf>,
+}
+
+impl SourceWatcher {
+    fn new(paths: Vec<PathBuf>, root: PathBuf) -> Self {
+        let mut watcher = Self {
+            paths,
+            root,
+            snapshot: BTreeMap::new(),
+            changed: Vec::new(),
+        };
+        watcher.snapshot = watcher.take_snapshot();
+        watcher
+    }
+
+    /// Blocks until at least one of the watched files changes; the changed files are printed if
+    /// `verbose` is true.
+    fn wait_for_change(&mut self, verbose: bool) {
+        loop {
+            thread::sleep(WATCH_POLL_INTERVAL);
+            let snapshot = self.take_snapshot();
+            if snapshot != self.snapshot {
+                self.changed = changed_paths(&self.snapshot, &snapshot);
+                self.snapshot = snapshot;
+                if verbose {
+                    for path in self.changed.iter() {
+                        println!("Changed: {}", path.display());
+                    }
+                }
+                return;
+            }
+        }
+    }
+
+    /// Returns true if any of the specified library files changed during the last wait.
+    fn libraries_changed(&self, library_paths: &[PathBuf]) -> bool {
+        self.changed.iter().any(|path| library_paths.contains(path))
+    }
+
+    /// Returns the modification times of all watched files which currently exist.
+    fn take_snapshot(&self) -> BTreeMap<PathBuf, SystemTime> {
+        let mut snapshot = BTreeMap::new();
+        for path in self.paths.iter() {
+            if let Some(modified) = modified_time(path) {
+                snapshot.insert(path.clone(), modified);
+            }
+        }
+        collect_source_files(&self.root, &mut snapshot);
+        snapshot
     }
 }
 
@@ -98,31 +341,137 @@ impl RunCmd {
 // ================================================================================================
 
 #[instrument(name = "run_program", skip_all)]
-fn run_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), String> {
-    // load libraries from files
-    let libraries = Libraries::new(&params.library_paths)?;
-
-    // load program from file and compile
-    let program =
-        ProgramFile::read(&params.assembly_file)?.compile(&Debug::Off, libraries.libraries)?;
+fn run_program(
+    params: &RunCmd,
+    libraries: &Libraries,
+) -> Result<(ExecutionTrace, [u8; 32], Timing), String> {
+    let now = Instant::now();
+    let (program, max_cycles, expected_cycles) = match &params.bundle_file {
+        Some(bundle_path) => {
+            // load the compiled program from the bundle
+            let bundle = BundleFile::read(bundle_path, &params.trusted_keys)?;
+            if params.format.is_text() {
+                for library in bundle.libraries.iter() {
+                    println!(
+                        "Linked library {} {} ({})",
+                        library.namespace,
+                        library.version,
+                        hex::encode(library.digest)
+                    );
+                }
+            }
+            (bundle.program, bundle.options.max_cycles, bundle.options.expected_cycles)
+        }
+        None => {
+            // load program from file and compile
+            let program = ProgramFile::read_with_entrypoint(
+                &params.program_path().to_path_buf(),
+                params.entrypoint.as_deref(),
+            )?
+            .compile(&Debug::Off, libraries.libraries.iter())?;
+            (program, params.max_cycles, params.expected_cycles)
+        }
+    };
 
     // load input data from file
-    let input_data = InputFile::read(&params.input_file, &params.assembly_file)?;
+    let input_data = InputFile::read(&params.input_file, params.program_path())?
+        .with_inline_inputs(&params.inline_inputs)?;
 
     // get execution options
-    let execution_options =
-        ExecutionOptions::new(Some(params.max_cycles), params.expected_cycles, params.tracing)
+    let mut execution_options =
+        ExecutionOptions::new(Some(max_cycles), expected_cycles, params.tracing)
             .map_err(|err| format!("{err}"))?;
+    if let Some(threshold) = params.stack_depth_warning {
+        execution_options = execution_options.with_stack_depth_warning(threshold);
+    }
 
     // fetch the stack and program inputs from the arguments
     let stack_inputs = input_data.parse_stack_inputs()?;
-    let host = DefaultHost::new(input_data.parse_advice_provider()?);
+    let mut adv_provider = input_data.parse_advice_provider()?;
+    if let Some(memory_path) = &params.memory_file {
+        MemoryFile::read_into_advice(memory_path, &mut adv_provider)?;
+    }
+    let host = DefaultHost::new(adv_provider)
+        .with_error_codes(program.error_codes().clone())
+        .with_memory_outputs(params.dump_ranges.iter().cloned())
+        .map_err(|err| format!("Invalid memory ranges: {err}"))?;
 
     let program_hash: [u8; 32] = program.hash().into();
+    let compile_time = now.elapsed();
 
     // execute program and generate outputs
+    let now = Instant::now();
     let trace = processor::execute(&program, stack_inputs, host, execution_options)
         .map_err(|err| format!("Failed to generate execution trace = {:?}", err))?;
 
-    Ok((trace, program_hash))
+    let timing = Timing {
+        compile_ms: millis(compile_time),
+        execute_ms: millis(now.elapsed()),
+        ..Default::default()
+    };
+    Ok((trace, program_hash, timing))
+}
+
+/// Prints the positions at which the outputs of the current run differ from the outputs of the
+/// previous run.
+fn print_outputs_diff(previous: &[Felt], current: &[Felt]) {
+    let num_outputs = previous.len().max(current.len());
+    let changes = (0..num_outputs)
+        .filter_map(|i| {
+            let (old, new) = (previous.get(i), current.get(i));
+            (old != new)
+                .then(|| format!("  [{i}]: {} -> {}", format_output(old), format_output(new)))
+        })
+        .collect::<Vec<_>>();
+
+    if changes.is_empty() {
+        println!("Outputs unchanged since the previous run");
+    } else {
+        println!("Outputs changed since the previous run:");
+        changes.iter().for_each(|change| println!("{change}"));
+    }
+}
+
+fn format_output(value: Option<&Felt>) -> String {
+    value.map_or_else(|| "-".to_string(), |value| value.to_string())
+}
+
+/// Returns the paths which were added, removed, or modified between the two snapshots.
+fn changed_paths(
+    old: &BTreeMap<PathBuf, SystemTime>,
+    new: &BTreeMap<PathBuf, SystemTime>,
+) -> Vec<PathBuf> {
+    let modified = new.iter().filter(|(path, time)| old.get(*path) != Some(time));
+    let removed = old.iter().filter(|(path, _)| !new.contains_key(*path));
+    modified.chain(removed).map(|(path, _)| path.clone()).collect()
+}
+
+/// Adds the modification times of all files with one of the [WATCHED_EXTENSIONS] in the specified
+/// directory and its subdirectories to the snapshot. Hidden directories are skipped.
+fn collect_source_files(dir: &Path, snapshot: &mut BTreeMap<PathBuf, SystemTime>) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_hidden =
+            path.file_name().is_some_and(|name| name.to_string_lossy().starts_with('.'));
+        if path.is_dir() {
+            if !is_hidden {
+                collect_source_files(&path, snapshot);
+            }
+        } else if path
+            .extension()
+            .is_some_and(|ext| WATCHED_EXTENSIONS.iter().any(|watched| ext == *watched))
+        {
+            if let Some(modified) = modified_time(&path) {
+                snapshot.insert(path, modified);
+            }
+        }
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|metadata| metadata.modified()).ok()
 }
@@ -1,6 +1,9 @@
-use super::data::{InputFile, OutputFile, ProgramHash, ProofFile};
+use super::{
+    data::{BundleFile, InputFile, OutputFile, ProgramHash, ProofFile},
+    report::{millis, OutputFormat, Timing, VerifyReport},
+};
 use clap::Parser;
-use miden_vm::{Kernel, ProgramInfo};
+use miden_vm::{Kernel, ProgramInfo, ProgramRegistry, Word};
 use std::{path::PathBuf, time::Instant};
 
 #[derive(Debug, Clone, Parser)]
@@ -16,18 +19,35 @@ pub struct VerifyCmd {
     #[clap(short = 'p', long = "proof", value_parser)]
     proof_file: PathBuf,
     /// Program hash (hex)
-    #[clap(short = 'h', long = "program-hash")]
-    program_hash: String,
+    #[clap(short = 'h', long = "program-hash", required_unless_present = "bundle_file")]
+    program_hash: Option<String>,
+    /// Path to .mbundle program bundle from which the program info is taken
+    #[clap(short = 'b', long = "bundle", value_parser, conflicts_with = "registry_dir")]
+    bundle_file: Option<PathBuf>,
+    /// Path to a registry directory from which the program info is fetched by the program hash
+    #[clap(short = 'r', long = "registry", value_parser)]
+    registry_dir: Option<PathBuf>,
+    /// Public key (hex) of a trusted bundle signer; if any trusted keys are specified, the bundle
+    /// must be signed with one of them. Can be repeated
+    #[clap(
+        long = "trusted-key",
+        value_name = "KEY",
+        requires = "bundle_file",
+        value_parser = BundleFile::parse_public_key
+    )]
+    trusted_keys: Vec<Word>,
+    /// Format in which the results are printed; `json` prints a single JSON object
+    #[clap(long = "format", value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
 }
 
 impl VerifyCmd {
     pub fn execute(&self) -> Result<(), String> {
-        println!("===============================================================================");
-        println!("Verifying proof: {}", self.proof_file.display());
-        println!("-------------------------------------------------------------------------------");
+        self.format
+            .print_banner(&format!("Verifying proof: {}", self.proof_file.display()));
 
-        // read program hash from input
-        let program_hash = ProgramHash::read(&self.program_hash)?;
+        // read program info from the bundle or the registry, or build it from the program hash
+        let program_info = self.load_program_info()?;
 
         // load input data from file
         let input_data = InputFile::read(&self.input_file, &self.proof_file)?;
@@ -40,19 +60,81 @@ impl VerifyCmd {
 
         // load proof from file
         let proof = ProofFile::read(&Some(self.proof_file.clone()), &self.proof_file)?;
+        if self.format.is_text() {
+            if let Some(metadata) = proof.metadata() {
+                println!(
+                    "Proof of program {} generated by Miden VM v{} at {} (Unix time){}",
+                    metadata.program_hash(),
+                    metadata.version(),
+                    metadata.timestamp(),
+                    metadata
+                        .label()
+                        .map(|label| format!(", labeled `{label}`"))
+                        .unwrap_or_default()
+                );
+            }
+        }
 
+        let program_hash: [u8; 32] = (*program_info.program_hash()).into();
         let now = Instant::now();
 
-        // TODO accept kernel as CLI argument
-        let kernel = Kernel::default();
-        let program_info = ProgramInfo::new(program_hash, kernel);
-
         // verify proof
-        verifier::verify(program_info, stack_inputs, outputs_data.stack_outputs()?, proof)
-            .map_err(|err| format!("Program failed verification! - {}", err))?;
+        let security =
+            verifier::verify(program_info, stack_inputs, outputs_data.stack_outputs()?, proof)
+                .map_err(|err| format!("Program failed verification! - {}", err))?;
+
+        if !self.format.is_text() {
+            let report = VerifyReport {
+                program_hash: hex::encode(program_hash),
+                verified: true,
+                security_level: security.conjectured_security,
+                proven_security_level: security.proven_security,
+                field_security: security.field_security,
+                query_security: security.query_security,
+                hash_security: security.hash_security,
+                timing: Timing {
+                    verify_ms: millis(now.elapsed()),
+                    ..Default::default()
+                },
+            };
+            return self.format.print_report(&report);
+        }
 
         println!("Verification complete in {} ms", now.elapsed().as_millis());
+        println!(
+            "Security level: {} bits conjectured, {} bits proven (field: {} bits, queries: {} bits, \
+            hash function: {} bits)",
+            security.conjectured_security,
+            security.proven_security,
+            security.field_security,
+            security.query_security,
+            security.hash_security,
+        );
 
         Ok(())
     }
+
+    /// Returns info of the program whose execution is verified.
+    fn load_program_info(&self) -> Result<ProgramInfo, String> {
+        let program_hash = self.program_hash.as_ref().map(ProgramHash::read).transpose()?;
+
+        if let Some(bundle_path) = &self.bundle_file {
+            let program_info =
+                ProgramInfo::from(BundleFile::read(bundle_path, &self.trusted_keys)?.program);
+            if program_hash.is_some_and(|hash| &hash != program_info.program_hash()) {
+                return Err("Program hash does not match the program in the bundle".to_string());
+            }
+            return Ok(program_info);
+        }
+
+        let program_hash = program_hash.expect("program hash must be specified");
+        match &self.registry_dir {
+            Some(registry_dir) => ProgramRegistry::new(registry_dir)
+                .get(&program_hash)
+                .map_err(|err| format!("Failed to read program registry - {}", err))?
+                .ok_or_else(|| format!("Program {} is not in the registry", program_hash)),
+            // TODO accept kernel as CLI argument
+            None => Ok(ProgramInfo::new(program_hash, Kernel::default())),
+        }
+    }
 }
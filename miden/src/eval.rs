@@ -0,0 +1,113 @@
+use alloc::{format, string::String};
+use assembly::{Assembler, AssemblyError};
+use core::fmt;
+use processor::{
+    execute_fast, AdviceInputs, DefaultHost, ExecutionError, ExecutionOptions, MemAdviceProvider,
+    StackInputs, StackOutputs,
+};
+
+// SNIPPET EVALUATION
+// ================================================================================================
+
+/// Compiles the specified snippet of Miden assembly using the default assembler, executes it
+/// against the provided stack and advice inputs, and returns the resulting state of the stack.
+///
+/// A snippet is a sequence of instructions (including control flow statements), optionally
+/// preceded by module imports, which is executed as the body of a program; that is, the snippet
+/// does not need to be wrapped in a `begin ... end` block. Procedures cannot be defined in a
+/// snippet.
+///
+/// For example, evaluating `push.3 mul` against stack inputs `[2]` results in `[6]` on the top of
+/// the stack.
+///
+/// # Errors
+/// Returns an error if the snippet fails to compile or to execute.
+pub fn eval_snippet(
+    source: &str,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+) -> Result<StackOutputs, SnippetError> {
+    eval_snippet_with_assembler(&Assembler::default(), source, stack_inputs, advice_inputs)
+}
+
+/// Compiles the specified snippet of Miden assembly using the provided assembler, executes it
+/// against the provided stack and advice inputs, and returns the resulting state of the stack.
+///
+/// This is the same as [eval_snippet()], but allows the snippet to use libraries and the kernel
+/// of the provided assembler.
+///
+/// # Errors
+/// Returns an error if the snippet fails to compile or to execute.
+pub fn eval_snippet_with_assembler(
+    assembler: &Assembler,
+    source: &str,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+) -> Result<StackOutputs, SnippetError> {
+    let program = assembler.compile(wrap_snippet(source))?;
+    let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs))
+        .with_error_codes(program.error_codes().clone());
+    let (outputs, _) = execute_fast(&program, stack_inputs, host, ExecutionOptions::default())?;
+    Ok(outputs)
+}
+
+// SNIPPET ERROR
+// ================================================================================================
+
+/// An error which can occur during evaluation of a snippet of Miden assembly.
+#[derive(Debug)]
+pub enum SnippetError {
+    Assembly(AssemblyError),
+    Execution(ExecutionError),
+}
+
+impl fmt::Display for SnippetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use SnippetError::*;
+        match self {
+            Assembly(err) => write!(f, "failed to compile the snippet: {err}"),
+            Execution(err) => write!(f, "failed to execute the snippet: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SnippetError {}
+
+impl From<AssemblyError> for SnippetError {
+    fn from(err: AssemblyError) -> Self {
+        Self::Assembly(err)
+    }
+}
+
+impl From<ExecutionError> for SnippetError {
+    fn from(err: ExecutionError) -> Self {
+        Self::Execution(err)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds the source of a program executing the specified snippet; module imports are moved in
+/// front of the body of the program.
+fn wrap_snippet(source: &str) -> String {
+    let mut imports = String::new();
+    let mut body = String::new();
+    for line in source.lines() {
+        let target = if line.trim_start().starts_with("use.") {
+            &mut imports
+        } else {
+            &mut body
+        };
+        target.push_str(line);
+        target.push('\n');
+    }
+
+    // a program body cannot be empty; an empty snippet leaves the stack unchanged
+    if body.trim().is_empty() {
+        body.push_str("push.0 drop\n");
+    }
+
+    format!("{imports}begin\n{body}end")
+}
@@ -1,6 +1,8 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 #![doc = include_str!("../README.md")]
 
+extern crate alloc;
+
 // EXPORTS
 // ================================================================================================
 
@@ -8,13 +10,37 @@ pub use assembly::{
     ast::{ModuleAst, ProgramAst},
     Assembler, AssemblyError, ParsingError,
 };
+#[cfg(feature = "std")]
+pub use processor::FalconSigner;
 pub use processor::{
-    crypto, execute, execute_iter, utils, AdviceInputs, AdviceProvider, AsmOpInfo, DefaultHost,
-    ExecutionError, ExecutionTrace, Host, Kernel, MemAdviceProvider, Operation, Program,
-    ProgramInfo, StackInputs, VmState, VmStateIterator, ZERO,
+    crypto, execute, execute_fast, execute_iter, execute_to_snapshot, execute_with_witness, utils,
+    AdviceInputs, AdviceMapNamespace, AdviceProvider, AdviceStorage, AsmOpInfo, CodeBlockTable,
+    DefaultHost, ErrorCodeInfo, ErrorCodeRegistry, ExecutionError, ExecutionOptions,
+    ExecutionTrace, ExecutionWitness, ForkedAdviceProvider, Host, Kernel, MastStore,
+    MemAdviceProvider, MemAdviceStorage, MemoryDiff, MemoryImage, Operation, OverflowTableRow,
+    PersistentAdviceProvider, ProcessState, Program, ProgramInfo, ProgramPackage, Signer,
+    StackDecode, StackEncode, StackInputs, TraceComponent, TraceHandler, TraceLenSummary,
+    VmProfile, VmSnapshot, VmState, VmStateIterator, WatchedStateIterator, Watchpoint,
+    WatchpointHit, ZERO,
 };
+#[cfg(feature = "remote")]
+pub use prover::HttpTransport;
 pub use prover::{
-    math, prove, Digest, ExecutionProof, FieldExtension, HashFunction, InputError, ProvingOptions,
-    StackOutputs, StarkProof, Word,
+    math, prove, prove_from_witness, prove_with_memory_outputs, prove_with_observer, Digest,
+    ExecutionProof, FieldExtension, HashFunction, InputError, LocalProver, MemoryUsage,
+    ProgramProver, ProgressObserver, ProofMetadata, ProofRequest, ProofResponse, ProofTransport,
+    ProverMemoryBudget, ProvingOptions, ProvingStage, RemoteProver, RemoteProverError,
+    StackOutputs, StarkProof, TraceBufferPool, TraceBufferPoolStats, Word,
+};
+pub use verifier::{
+    verify, verify_all, verify_bounded, verify_many, verify_with_kernels, SecurityReport,
+    VerificationError, VerifierLimits,
 };
-pub use verifier::{verify, VerificationError};
+#[cfg(feature = "std")]
+pub use verifier::{verify_registered, ProgramRegistry, RegistryError};
+
+mod eval;
+pub use eval::{eval_snippet, eval_snippet_with_assembler, SnippetError};
+
+mod output;
+pub use output::{OutputBuffer, OutputBufferError};
@@ -27,7 +27,10 @@ pub enum Actions {
     Compile(cli::CompileCmd),
     Bundle(cli::BundleCmd),
     Debug(cli::DebugCmd),
+    Doc(cli::DocCmd),
     Example(examples::ExampleOptions),
+    New(cli::NewCmd),
+    Pack(cli::PackCmd),
     Prove(cli::ProveCmd),
     Run(cli::RunCmd),
     Verify(cli::VerifyCmd),
@@ -43,7 +46,10 @@ impl Cli {
             Actions::Compile(compile) => compile.execute(),
             Actions::Bundle(compile) => compile.execute(),
             Actions::Debug(debug) => debug.execute(),
+            Actions::Doc(doc) => doc.execute(),
             Actions::Example(example) => example.execute(),
+            Actions::New(new) => new.execute(),
+            Actions::Pack(pack) => pack.execute(),
             Actions::Prove(prove) => prove.execute(),
             Actions::Run(run) => run.execute(),
             Actions::Verify(verify) => verify.execute(),
@@ -85,6 +91,7 @@ pub fn main() {
     // execute cli action
     if let Err(error) = cli.execute() {
         println!("{}", error);
+        std::process::exit(1);
     }
 }
 
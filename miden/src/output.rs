@@ -0,0 +1,151 @@
+use alloc::vec::Vec;
+use core::fmt;
+use processor::{crypto::Rpo256, AdviceProvider, Digest, StackOutputs, Word};
+
+// OUTPUT BUFFER
+// ================================================================================================
+
+/// Words of memory returned by a program via a commitment on the stack.
+///
+/// This implements the convention of the `std::output` module of the standard library: a program
+/// writes its results into a contiguous region of memory, and finishes with the commitment to the
+/// region (the sequential RPO hash of its words) at the top of the stack. Procedure
+/// `std::output::commit` additionally saves the words into the advice map under the commitment.
+///
+/// The host extracts the buffer via [OutputBuffer::extract()] once the program finishes executing,
+/// while a verifier which received the words from the prover checks them via
+/// [OutputBuffer::verify()]. In both cases, the words are checked against the commitment in the
+/// stack outputs, which are bound to the proof of the execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputBuffer {
+    commitment: Digest,
+    words: Vec<Word>,
+}
+
+impl OutputBuffer {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Extracts the output buffer committed to at the top of the specified stack outputs from the
+    /// advice map of the specified advice provider.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The stack outputs do not contain a commitment.
+    /// - The advice map does not contain an entry for the commitment, or the entry does not
+    ///   consist of whole words.
+    /// - The words do not match the commitment.
+    pub fn extract<A: AdviceProvider>(
+        stack_outputs: &StackOutputs,
+        advice_provider: &A,
+    ) -> Result<Self, OutputBufferError> {
+        let commitment = read_commitment(stack_outputs)?;
+        let elements = advice_provider
+            .get_mapped_values(&commitment)
+            .ok_or(OutputBufferError::MissingBuffer(commitment))?;
+        if elements.len() % 4 != 0 {
+            return Err(OutputBufferError::InvalidBufferLength(elements.len()));
+        }
+
+        let words = elements
+            .chunks(4)
+            .map(|word| word.try_into().expect("a word contains 4 elements"))
+            .collect::<Vec<Word>>();
+        Self::verify(stack_outputs, words)
+    }
+
+    /// Checks the specified words against the commitment at the top of the specified stack outputs
+    /// and returns the resulting output buffer.
+    ///
+    /// # Errors
+    /// Returns an error if the stack outputs do not contain a commitment, or if the words do not
+    /// match the commitment.
+    pub fn verify(
+        stack_outputs: &StackOutputs,
+        words: Vec<Word>,
+    ) -> Result<Self, OutputBufferError> {
+        let expected = read_commitment(stack_outputs)?;
+        let actual = Self::commitment_to(&words);
+        if expected != actual {
+            return Err(OutputBufferError::CommitmentMismatch { expected, actual });
+        }
+
+        Ok(Self {
+            commitment: actual,
+            words,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the commitment to the specified words, as computed by `std::output::commit`.
+    pub fn commitment_to(words: &[Word]) -> Digest {
+        let elements = words.iter().flatten().copied().collect::<Vec<_>>();
+        Rpo256::hash_elements(&elements)
+    }
+
+    /// Returns the commitment to this output buffer.
+    pub fn commitment(&self) -> Digest {
+        self.commitment
+    }
+
+    /// Returns the words of this output buffer.
+    pub fn words(&self) -> &[Word] {
+        &self.words
+    }
+
+    /// Returns the words of this output buffer, consuming the buffer.
+    pub fn into_words(self) -> Vec<Word> {
+        self.words
+    }
+}
+
+// OUTPUT BUFFER ERROR
+// ================================================================================================
+
+/// An error which can occur when extracting or verifying an [OutputBuffer].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutputBufferError {
+    CommitmentMismatch { expected: Digest, actual: Digest },
+    InvalidBufferLength(usize),
+    MissingBuffer(Digest),
+    MissingCommitment,
+}
+
+impl fmt::Display for OutputBufferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use OutputBufferError::*;
+        match self {
+            CommitmentMismatch { expected, actual } => {
+                write!(f, "output buffer commitment {actual} does not match commitment {expected}")
+            }
+            InvalidBufferLength(len) => {
+                write!(f, "output buffer of {len} elements does not consist of whole words")
+            }
+            MissingBuffer(commitment) => {
+                write!(
+                    f,
+                    "advice map does not contain an output buffer for commitment {commitment}"
+                )
+            }
+            MissingCommitment => {
+                write!(f, "stack outputs do not contain an output buffer commitment")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OutputBufferError {}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Reads the output buffer commitment from the top of the specified stack outputs.
+fn read_commitment(stack_outputs: &StackOutputs) -> Result<Digest, OutputBufferError> {
+    stack_outputs
+        .get_stack_word(0)
+        .map(Digest::new)
+        .ok_or(OutputBufferError::MissingCommitment)
+}
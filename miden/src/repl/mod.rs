@@ -1,10 +1,19 @@
 use assembly::{Assembler, Library, MaslLibrary};
-use miden_vm::{math::Felt, DefaultHost, StackInputs, Word};
+use miden_vm::{math::Felt, DefaultHost, Word};
 use processor::ContextId;
 use rustyline::{error::ReadlineError, DefaultEditor};
-use std::{collections::BTreeSet, path::PathBuf};
+use std::{
+    collections::BTreeSet,
+    fs,
+    ops::Range,
+    path::{Path, PathBuf},
+};
 use stdlib::StdLibrary;
 
+use crate::cli::InputFile;
+
+/// Initiates the Miden Repl tool.
+///
 /// This work is in continuation to the amazing work done by team `Scribe`
 /// [here](https://github.com/ControlCplusControlV/Scribe/blob/main/transpiler/src/repl.rs#L8)
 ///
@@ -41,8 +50,8 @@ use stdlib::StdLibrary;
 /// To execute a control flow operation, one must write the entire statement in a single line with
 /// spaces between individual operations.
 /// >> repeat.20
-///       pow2
-///    end
+/// >>       pow2
+/// >>    end
 ///
 /// The above example should be written as follows in the REPL tool:
 /// >> repeat.20 pow2 end
@@ -60,7 +69,7 @@ use stdlib::StdLibrary;
 ///
 /// The `!stack` command will print out the following state of the stack:
 /// >> !stack
-/// 3072 1 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0
+/// >> 3072 1 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0 0
 ///
 /// `!undo`
 /// The `!undo` command reverts to the previous state of the stack and memory by dropping off the
@@ -71,14 +80,14 @@ use stdlib::StdLibrary;
 /// >> push.1 push.2 push.3
 /// >> push.4
 /// >> !stack
-/// 4 3 2 1 0 0 0 0 0 0 0 0 0 0 0 0
+/// >> 4 3 2 1 0 0 0 0 0 0 0 0 0 0 0 0
 /// >> push.5
 /// >> !stack
-/// 5 4 3 2 1 0 0 0 0 0 0 0 0 0 0 0
+/// >> 5 4 3 2 1 0 0 0 0 0 0 0 0 0 0 0
 /// >> !undo
-/// 4 3 2 1 0 0 0 0 0 0 0 0 0 0 0 0
+/// >> 4 3 2 1 0 0 0 0 0 0 0 0 0 0 0 0
 /// >> !undo
-/// 3 2 1 0 0 0 0 0 0 0 0 0 0 0 0 0
+/// >> 3 2 1 0 0 0 0 0 0 0 0 0 0 0 0 0
 ///
 ///`!program`
 /// The `!program` command prints out the entire miden program getting executed. E.g., in the below scenario:
@@ -88,13 +97,13 @@ use stdlib::StdLibrary;
 /// >> add
 /// >> add
 /// >> !program
-/// begin
-///    push.1
-///    push.2
-///    push.3
-///    add
-///    add
-/// end
+/// >> begin
+/// >>    push.1
+/// >>    push.2
+/// >>    push.3
+/// >>    add
+/// >>    add
+/// >> end
 ///
 /// `!help`
 /// The `!help` command prints out all the available commands in the REPL tool.
@@ -105,26 +114,56 @@ use stdlib::StdLibrary;
 /// address.
 /// If the memory has at least one value that has been initialized:
 /// >> !mem
-/// 7: [1, 2, 0, 3]
-/// 8: [5, 7, 3, 32]
-/// 9: [9, 10, 2, 0]
+/// >> 7: [1, 2, 0, 3]
+/// >> 8: [5, 7, 3, 32]
+/// >> 9: [9, 10, 2, 0]
 ///
 /// If the memory is not yet been initialized:
 /// >> !mem
-/// The memory has not been initialized yet
+/// >> The memory has not been initialized yet
 ///
 /// `!mem[addr]`
 /// The `!mem[addr]` command prints out memory contents at the address specified by `addr`.
 /// If the `addr` has been initialized:
 /// >> !mem[9]
-/// 9: [9, 10, 2, 0]
+/// >> 9: [9, 10, 2, 0]
 ///
 /// If the `addr` has not been initialized:
 /// >> !mem[87]
-/// Memory at address 87 is empty
-
-/// Initiates the Miden Repl tool.
-pub fn start_repl(library_paths: &Vec<PathBuf>, use_stdlib: bool) {
+/// >> Memory at address 87 is empty
+///
+/// `!mem[start..end]`
+/// The `!mem[start..end]` command prints out the contents of all initialized memory locations with
+/// addresses from `start` (inclusive) to `end` (exclusive).
+/// >> !mem[7..9]
+/// >> 7: [1, 2, 0, 3]
+/// >> 8: [5, 7, 3, 32]
+///
+/// `!use`
+/// The `!use` command prints out the list of all modules available for import, and with a module
+/// name, adds the specified module to the program imports. Modules can also be imported by entering
+/// a regular `use` statement. Modules of the standard library can be imported even if the REPL was
+/// started without it, in which case the standard library gets loaded on the first such import.
+/// >> use.std::math::u64
+///
+/// `!lib`
+/// The `!lib` command prints out the list of loaded libraries, and with a path to a `.masl` file,
+/// loads the library stored in this file.
+/// >> !lib example/library.masl
+/// >> Library example/library.masl has been loaded
+///
+/// `!export`
+/// The `!export <name>` command writes the program built from the executed lines to
+/// `<name>.masm`, and the inputs of the session to `<name>.inputs`, so that running the exported
+/// program reproduces the current state of the REPL.
+/// >> !export session
+/// >> The session has been exported to session.masm and session.inputs
+pub fn start_repl(
+    library_paths: &Vec<PathBuf>,
+    use_stdlib: bool,
+    inputs: InputFile,
+    script: Option<Vec<String>>,
+) -> Result<(), String> {
     let mut program_lines: Vec<String> = Vec::new();
 
     // set of user imported modules
@@ -133,10 +172,9 @@ pub fn start_repl(library_paths: &Vec<PathBuf>, use_stdlib: bool) {
     // load libraries from files
     let mut provided_libraries = Vec::new();
     for path in library_paths {
-        let library = MaslLibrary::read_from_file(path)
-            .map_err(|e| format!("Failed to read library: {e}"))
-            .unwrap();
-        provided_libraries.push(library);
+        if let Err(msg) = load_library(path, &mut provided_libraries) {
+            println!("{msg}");
+        }
     }
     if use_stdlib {
         provided_libraries.push(MaslLibrary::from(StdLibrary::default()));
@@ -147,60 +185,41 @@ pub fn start_repl(library_paths: &Vec<PathBuf>, use_stdlib: bool) {
     // prints out all the available commands in the Miden Repl tool.
     print_instructions();
 
-    // flag to determine if the stack should be printed or not post the execution of the
-    // last command.
-    let mut should_print_stack = false;
+    // state of the entire memory and of the stack at the latest clock cycle. the state is updated
+    // only when the program changes, and is kept intact if the last entered line fails.
+    let (mut memory, mut stack_state) =
+        execute_lines(&imported_modules, &program_lines, &provided_libraries, &inputs)?;
 
-    // state of the entire memory at the latest clock cycle.
-    let mut memory: Vec<(u64, Word)> = Vec::new();
+    // lines of the script are executed in place of the lines read from the user.
+    let mut script_lines = script.map(|lines| lines.into_iter());
 
     // initializing readline.
     let mut rl = DefaultEditor::new().expect("Readline couldn't be initialized");
     loop {
-        let mut program = String::new();
-        for module in imported_modules.iter() {
-            program.push_str(module);
-            program.push('\n');
-        }
-        program.push_str(&format!(
-            "\nbegin\n{}\nend",
-            program_lines
-                .iter()
-                .map(|l| format!("    {}", l))
-                .collect::<Vec<_>>()
-                .join("\n")
-        ));
-
-        let result = execute(program.clone(), &provided_libraries);
-
-        if !program_lines.is_empty() {
-            match result {
-                Ok((mem, stack_state)) => {
-                    if should_print_stack {
-                        print_stack(stack_state);
-                    }
-                    memory = mem;
-                }
-                Err(e) => {
-                    println!("Error running program: {:?}", e);
-                    program_lines.pop();
+        let input = match script_lines.as_mut() {
+            Some(lines) => match lines.next() {
+                Some(line) => {
+                    println!(">> {line}");
+                    Ok(line)
                 }
-            }
-        } else if should_print_stack {
-            println!("{}", str::repeat("0 ", 16));
-        }
+                None => break,
+            },
+            None => rl.readline(">> "),
+        };
 
-        match rl.readline(">> ") {
+        match input {
             Ok(line) => {
+                let line = line.trim().to_string();
+                if line.is_empty() {
+                    continue;
+                }
+
                 if line == "!program" {
-                    println!("{}", program);
-                    should_print_stack = false;
+                    println!("{}", build_program(&imported_modules, &program_lines));
                 } else if line == "!help" {
                     // prints out all the available commands in the Miden Repl tool.
                     print_instructions();
-                    should_print_stack = false;
                 } else if line == "!mem" {
-                    should_print_stack = false;
                     if memory.is_empty() {
                         println!("The memory has not been initialized yet");
                         continue;
@@ -209,6 +228,26 @@ pub fn start_repl(library_paths: &Vec<PathBuf>, use_stdlib: bool) {
                         // prints out the address and memory value at that address.
                         print_mem_address(*addr, mem);
                     }
+                } else if line.len() > 6 && &line[..5] == "!mem[" && line.contains("..") {
+                    // extracts the range of addresses from user input.
+                    match read_mem_range(&line) {
+                        Ok(range) => {
+                            let mut mem_in_range_present = false;
+                            for (addr, memory_value) in &memory {
+                                if range.contains(addr) {
+                                    print_mem_address(*addr, memory_value);
+                                    mem_in_range_present = true;
+                                }
+                            }
+                            if !mem_in_range_present {
+                                println!(
+                                    "Memory at addresses {}..{} is empty",
+                                    range.start, range.end
+                                );
+                            }
+                        }
+                        Err(msg) => println!("{}", msg),
+                    }
                 } else if line.len() > 6 && &line[..5] == "!mem[" {
                     // if user wants to see the state of a particular address in a memory, the input should be atleast
                     // of length 5.
@@ -235,27 +274,69 @@ pub fn start_repl(library_paths: &Vec<PathBuf>, use_stdlib: bool) {
                         }
                         Err(msg) => println!("{}", msg),
                     }
-
-                    should_print_stack = false;
                 } else if line == "!undo" {
                     match program_lines.pop() {
                         Some(last_line) => {
                             println!("Undoing {}", last_line);
-                            should_print_stack = true;
-                        }
-                        None => {
-                            println!("There's no previously executed command");
-                            should_print_stack = false;
+                            // the remaining lines have been executed successfully before, and thus,
+                            // re-executing them restores the state preceding the undone line.
+                            match execute_lines(
+                                &imported_modules,
+                                &program_lines,
+                                &provided_libraries,
+                                &inputs,
+                            ) {
+                                Ok((mem, stack)) => {
+                                    print_stack(&stack);
+                                    memory = mem;
+                                    stack_state = stack;
+                                }
+                                Err(e) => println!("Error running program: {}", e),
+                            }
                         }
+                        None => println!("There's no previously executed command"),
                     };
                 } else if line == "!stack" {
-                    should_print_stack = true;
+                    print_stack(&stack_state);
                 } else if line.starts_with("!use") {
-                    handle_use_command(line, &provided_libraries, &mut imported_modules);
+                    handle_use_command(line, &mut provided_libraries, &mut imported_modules);
+                } else if line.starts_with("!export") {
+                    if program_lines.is_empty() {
+                        println!("There's no previously executed command");
+                        continue;
+                    }
+                    let program = build_program(&imported_modules, &program_lines);
+                    handle_export_command(line, &program, &inputs);
+                } else if line.starts_with("!lib") {
+                    handle_lib_command(line, &mut provided_libraries);
+                } else if let Some(module) = line.strip_prefix("use.") {
+                    // imports entered as assembly are handled in the same way as the `!use` command
+                    let module = module.to_string();
+                    rl.add_history_entry(line.clone()).expect("Failed to add a history entry");
+                    handle_use_command(
+                        format!("!use {module}"),
+                        &mut provided_libraries,
+                        &mut imported_modules,
+                    );
                 } else {
                     rl.add_history_entry(line.clone()).expect("Failed to add a history entry");
-                    program_lines.push(line.clone());
-                    should_print_stack = true;
+                    program_lines.push(line);
+                    match execute_lines(
+                        &imported_modules,
+                        &program_lines,
+                        &provided_libraries,
+                        &inputs,
+                    ) {
+                        Ok((mem, stack)) => {
+                            print_stack(&stack);
+                            memory = mem;
+                            stack_state = stack;
+                        }
+                        Err(e) => {
+                            println!("Error running program: {}", e);
+                            program_lines.pop();
+                        }
+                    }
                 }
             }
             Err(ReadlineError::Interrupted) => {
@@ -272,12 +353,55 @@ pub fn start_repl(library_paths: &Vec<PathBuf>, use_stdlib: bool) {
             }
         };
     }
-    rl.save_history("history.txt")
-        .expect("Couldn't dump the program into the history file");
+    if script_lines.is_none() {
+        rl.save_history("history.txt")
+            .expect("Couldn't dump the program into the history file");
+    }
+
+    Ok(())
+}
+
+// HELPER METHODS
+// ------------------------------------------------------------------------------------------------
+
+/// Memory (as a list of addresses and the words stored at them) and stack of the VM.
+type VmState = (Vec<(u64, Word)>, Vec<Felt>);
+
+/// Builds the source of a Miden program from the imported modules and the lines of its body.
+fn build_program(imported_modules: &BTreeSet<String>, program_lines: &[String]) -> String {
+    let mut program = String::new();
+    for module in imported_modules.iter() {
+        program.push_str(module);
+        program.push('\n');
+    }
+    program.push_str(&format!(
+        "\nbegin\n{}\nend",
+        program_lines
+            .iter()
+            .map(|l| format!("    {}", l))
+            .collect::<Vec<_>>()
+            .join("\n")
+    ));
+    program
 }
 
-/// HELPER METHODS
-/// --------------------------------------------------------------------------------------------
+/// Executes the program built from the imported modules and the specified lines, returning the
+/// memory and the stack at the latest clock cycle. If there are no lines, the initial state of the
+/// VM is returned.
+fn execute_lines(
+    imported_modules: &BTreeSet<String>,
+    program_lines: &[String],
+    provided_libraries: &[MaslLibrary],
+    inputs: &InputFile,
+) -> Result<VmState, String> {
+    let program = if program_lines.is_empty() {
+        // a program body cannot be empty; this program leaves the initial state intact
+        "begin push.0 drop end".to_string()
+    } else {
+        build_program(imported_modules, program_lines)
+    };
+    execute(program, provided_libraries, inputs)
+}
 
 /// Compiles and executes a compiled Miden program, returning the stack, memory and any Miden errors.
 /// The program is passed in as a String, passed to the Miden Assembler, and then passed into the Miden
@@ -285,7 +409,8 @@ pub fn start_repl(library_paths: &Vec<PathBuf>, use_stdlib: bool) {
 fn execute(
     program: String,
     provided_libraries: &[MaslLibrary],
-) -> Result<(Vec<(u64, Word)>, Vec<Felt>), String> {
+    inputs: &InputFile,
+) -> Result<VmState, String> {
     // compile program
     let mut assembler = Assembler::default();
 
@@ -295,8 +420,8 @@ fn execute(
 
     let program = assembler.compile(program).map_err(|err| format!("{err}"))?;
 
-    let stack_inputs = StackInputs::default();
-    let host = DefaultHost::default();
+    let stack_inputs = inputs.parse_stack_inputs()?;
+    let host = DefaultHost::new(inputs.parse_advice_provider()?);
 
     let state_iter = processor::execute_iter(&program, stack_inputs, host);
     let (system, _, stack, chiplets, err) = state_iter.into_parts();
@@ -312,6 +437,22 @@ fn execute(
     Ok((mem_state, stack_state))
 }
 
+/// Parses the range of addresses from "!mem[start..end]" command, otherwise throws an error.
+fn read_mem_range(mem_str: &str) -> Result<Range<u64>, String> {
+    const ERR_MSG: &str = "Please enter memory command correctly. It should be !mem[start..end]";
+
+    // the first five characters is "!mem[" and the last character should be "]".
+    let remainder = mem_str[5..].strip_suffix(']').ok_or_else(|| ERR_MSG.to_string())?;
+    let (start, end) = remainder.split_once("..").ok_or_else(|| ERR_MSG.to_string())?;
+    let start = start.trim().parse::<u64>().map_err(|_| ERR_MSG.to_string())?;
+    let end = end.trim().parse::<u64>().map_err(|_| ERR_MSG.to_string())?;
+    if start >= end {
+        return Err(format!("The memory range {start}..{end} is empty"));
+    }
+
+    Ok(start..end)
+}
+
 /// Parses the address in integer form from "!mem[addr]" command, otherwise throws an error.
 fn read_mem_address(mem_str: &str) -> Result<u64, String> {
     // the first five characters is "!mem[" and the digit character should start from 6th
@@ -336,9 +477,12 @@ fn read_mem_address(mem_str: &str) -> Result<u64, String> {
 
 /// Parses `!use` command. Adds the provided module to the program imports, or prints the list of
 /// all available modules if no module name was provided.
+///
+/// Modules of the standard library can be imported even if the REPL was started without it; in
+/// this case, the standard library is loaded on the first import of its module.
 fn handle_use_command(
     line: String,
-    provided_libraries: &Vec<MaslLibrary>,
+    provided_libraries: &mut Vec<MaslLibrary>,
     imported_modules: &mut BTreeSet<String>,
 ) {
     let tokens: Vec<&str> = line.split_whitespace().collect();
@@ -346,17 +490,96 @@ fn handle_use_command(
     match tokens.len() {
         1 => {
             println!("Modules available for importing:");
-            for lib in provided_libraries {
+            for lib in provided_libraries.iter() {
                 lib.modules().for_each(|module| println!("{}", module.path));
             }
         }
         2 => {
-            imported_modules.insert(format!("use.{}", tokens[1]).to_string());
+            // the module can be imported under an alias, e.g. "std::math::u64->long"
+            let module = tokens[1];
+            let path = module.split_once("->").map_or(module, |(path, _)| path);
+
+            let stdlib_loaded =
+                provided_libraries.iter().any(|lib| lib.root_ns().as_ref() == "std");
+            if path.starts_with("std::") && !stdlib_loaded {
+                provided_libraries.push(MaslLibrary::from(StdLibrary::default()));
+            }
+
+            let module_found = provided_libraries
+                .iter()
+                .any(|lib| lib.modules().any(|m| m.path.path() == path));
+            if module_found {
+                imported_modules.insert(format!("use.{}", module).to_string());
+            } else {
+                println!("Module {path} was not found in the loaded libraries");
+            }
         }
         _ => println!("malformed instruction '!use': too many parameters provided"),
     }
 }
 
+/// Parses `!export` command. Writes the program built from the entered lines to `<name>.masm`,
+/// and the inputs against which the program was executed to `<name>.inputs`, so that running the
+/// exported program reproduces the current state of the REPL.
+fn handle_export_command(line: String, program: &str, inputs: &InputFile) {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+
+    match tokens.len() {
+        1 => println!("malformed instruction '!export': no file name provided"),
+        2 => {
+            let program_path = PathBuf::from(tokens[1]).with_extension("masm");
+            let inputs_path = program_path.with_extension("inputs");
+            let result = fs::write(&program_path, program)
+                .map_err(|err| {
+                    format!("Failed to write program file `{}` - {}", program_path.display(), err)
+                })
+                .and_then(|_| inputs.write(&inputs_path));
+            match result {
+                Ok(()) => println!(
+                    "The session has been exported to {} and {}",
+                    program_path.display(),
+                    inputs_path.display()
+                ),
+                Err(msg) => println!("{msg}"),
+            }
+        }
+        _ => println!("malformed instruction '!export': too many parameters provided"),
+    }
+}
+
+/// Parses `!lib` command. Loads the library from the provided `.masl` file, or prints the list of
+/// all loaded libraries if no path was provided.
+fn handle_lib_command(line: String, provided_libraries: &mut Vec<MaslLibrary>) {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+
+    match tokens.len() {
+        1 => {
+            println!("Loaded libraries:");
+            for lib in provided_libraries.iter() {
+                println!("{} {}", lib.root_ns().as_ref(), lib.version());
+            }
+        }
+        2 => match load_library(Path::new(tokens[1]), provided_libraries) {
+            Ok(()) => println!("Library {} has been loaded", tokens[1]),
+            Err(msg) => println!("{msg}"),
+        },
+        _ => println!("malformed instruction '!lib': too many parameters provided"),
+    }
+}
+
+/// Reads the library from the provided `.masl` file and adds it to the provided libraries, unless
+/// a library with the same namespace has been loaded already.
+fn load_library(path: &Path, provided_libraries: &mut Vec<MaslLibrary>) -> Result<(), String> {
+    let library =
+        MaslLibrary::read_from_file(path).map_err(|e| format!("Failed to read library: {e}"))?;
+    let namespace = library.root_ns().as_ref();
+    if provided_libraries.iter().any(|lib| lib.root_ns().as_ref() == namespace) {
+        return Err(format!("Library with namespace {namespace} has already been loaded"));
+    }
+    provided_libraries.push(library);
+    Ok(())
+}
+
 /// Prints out all the available command present in the Miden Repl tool.
 fn print_instructions() {
     println!("Available commands:");
@@ -364,16 +587,20 @@ fn print_instructions() {
     println!("!stack: display the complete state of the stack");
     println!("!mem: display the state of the entire memory");
     println!("!mem[i]: display the state of the memory at address i");
+    println!("!mem[i..j]: display the state of the memory at addresses from i to j (exclusive)");
     println!("!undo: remove the last instruction");
     println!("!use: display a list of modules available for import");
     println!("!use <full_module_name>: import the specified module");
+    println!("!lib: display a list of loaded libraries");
+    println!("!lib <path>: load the library from the specified .masl file");
     println!("!program: display the program");
+    println!("!export <name>: write the program and its inputs to <name>.masm and <name>.inputs");
     println!("!help: print out all the available commands");
     println!();
 }
 
 /// Returns the state of the stack along with its overflown part in a string format.
-fn print_stack(stack: Vec<Felt>) {
+fn print_stack(stack: &[Felt]) {
     // converts the stack which is a vector of felt into string and prints it.
     println!("{}", stack.iter().map(|f| format!("{}", f)).collect::<Vec<_>>().join(" "),)
 }
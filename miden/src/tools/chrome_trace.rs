@@ -0,0 +1,182 @@
+use super::ProgramError;
+use miden_vm::{Assembler, Host, StackInputs, VmState};
+use processor::ExecutionError;
+use serde_derive::Serialize;
+use stdlib::StdLibrary;
+
+// CHROME TRACE
+// ================================================================================================
+
+/// A profile of a program's execution in the Chrome trace event format.
+///
+/// Procedures are represented as nested spans which start at the cycle at which the first
+/// instruction of a procedure is executed, and last for the number of cycles spent in the
+/// procedure, including the cycles spent in the procedures invoked from it. One cycle is
+/// represented as one microsecond.
+///
+/// The profile can be opened in `chrome://tracing`, [Perfetto](https://ui.perfetto.dev), and
+/// other tools which support the format.
+///
+/// Procedures are inlined into their callers, and thus, procedure boundaries are inferred from
+/// the procedures to which the executed instructions belong: when an instruction belongs to a
+/// procedure which is already open, all procedures opened after it are closed; otherwise, a new
+/// span is opened for the procedure. Consequently, a procedure invoked right after another
+/// procedure returned, without any instructions of the caller executed in between, is shown as
+/// nested in the previous procedure.
+#[derive(Debug, Default, Serialize)]
+pub struct ChromeTrace {
+    #[serde(rename = "traceEvents")]
+    events: Vec<TraceEvent>,
+}
+
+impl ChromeTrace {
+    /// Builds a profile from the states of a program's execution, as yielded by
+    /// [VmStateIterator](miden_vm::VmStateIterator).
+    ///
+    /// # Errors
+    /// Returns an error if the execution failed.
+    pub fn from_states<I>(states: I) -> Result<Self, ExecutionError>
+    where
+        I: IntoIterator<Item = Result<VmState, ExecutionError>>,
+    {
+        let mut trace = Self::default();
+        let mut open_spans: Vec<(String, u32)> = Vec::new();
+        let mut last_clk = 0;
+
+        for state in states {
+            let state = state?;
+            last_clk = state.clk;
+
+            let name = match &state.asmop {
+                Some(asmop) => asmop.context_name(),
+                None => continue,
+            };
+            if open_spans.last().is_some_and(|(open, _)| open == name) {
+                continue;
+            }
+
+            // the instruction is executed at the previous cycle
+            let clk = state.clk - 1;
+            match open_spans.iter().position(|(open, _)| open == name) {
+                Some(idx) => {
+                    while open_spans.len() > idx + 1 {
+                        let (name, start) = open_spans.pop().expect("no open span");
+                        trace.add_span(name, start, clk);
+                    }
+                }
+                None => open_spans.push((name.to_string(), clk)),
+            }
+        }
+
+        while let Some((name, start)) = open_spans.pop() {
+            trace.add_span(name, start, last_clk);
+        }
+        trace.events.sort_by(|a, b| a.ts.cmp(&b.ts).then(b.dur.cmp(&a.dur)));
+
+        Ok(trace)
+    }
+
+    /// Returns the number of spans in this profile.
+    pub fn num_spans(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns this profile serialized into JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("failed to serialize chrome trace")
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds a span for the specified procedure which lasts from `start` (inclusive) to `end`
+    /// (exclusive) cycle.
+    fn add_span(&mut self, name: String, start: u32, end: u32) {
+        self.events.push(TraceEvent {
+            name,
+            cat: "procedure",
+            ph: "X",
+            ts: start as u64,
+            dur: (end - start) as u64,
+            pid: 0,
+            tid: 0,
+        });
+    }
+}
+
+/// A complete event of the Chrome trace event format.
+#[derive(Debug, Serialize)]
+struct TraceEvent {
+    name: String,
+    cat: &'static str,
+    ph: &'static str,
+    ts: u64,
+    dur: u64,
+    pid: u32,
+    tid: u32,
+}
+
+/// Returns the profile of executing a given program in the Chrome trace event format.
+pub fn profile<H>(
+    program: &str,
+    stack_inputs: StackInputs,
+    host: H,
+) -> Result<ChromeTrace, ProgramError>
+where
+    H: Host,
+{
+    let program = Assembler::default()
+        .with_debug_mode(true)
+        .with_library(&StdLibrary::default())
+        .map_err(ProgramError::AssemblyError)?
+        .compile(program)
+        .map_err(ProgramError::AssemblyError)?;
+
+    let states = processor::execute_iter(&program, stack_inputs, host);
+    ChromeTrace::from_states(states).map_err(ProgramError::ExecutionError)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{ProgramError, StackInputs};
+    use processor::DefaultHost;
+
+    #[test]
+    fn chrome_trace_nested_procedures() {
+        let source = "
+            proc.bar push.1 drop end
+            proc.foo push.2 exec.bar drop end
+            begin push.3 exec.foo push.4 exec.bar drop drop end";
+        let trace = super::profile(source, StackInputs::default(), DefaultHost::default())
+            .expect("chrome_trace_nested_procedures: Unexpected Error");
+
+        let spans = trace
+            .events
+            .iter()
+            .map(|event| (event.name.as_str(), event.ts, event.dur))
+            .collect::<Vec<_>>();
+
+        // main spans the whole execution; foo contains the first invocation of bar
+        assert_eq!(spans.len(), 4);
+        assert_eq!(spans[0].0, "#main");
+        assert_eq!(spans[1].0, "foo");
+        assert_eq!(spans[2].0, "bar");
+        assert_eq!(spans[3].0, "bar");
+        assert!(spans[1].1 <= spans[2].1 && spans[2].1 + spans[2].2 <= spans[1].1 + spans[1].2);
+        assert!(spans[3].1 >= spans[1].1 + spans[1].2);
+        assert!(spans[1..].iter().all(|(_, ts, dur)| ts + dur <= spans[0].1 + spans[0].2));
+
+        let json = trace.to_json();
+        assert!(json.starts_with("{\"traceEvents\":[{\"name\":\"#main\""));
+    }
+
+    #[test]
+    fn chrome_trace_execution_error() {
+        let source = "begin push.1 push.0 div end";
+        let trace = super::profile(source, StackInputs::default(), DefaultHost::default());
+        assert!(matches!(trace, Err(ProgramError::ExecutionError(_))));
+    }
+}
@@ -1,10 +1,20 @@
-use super::{cli::InputFile, ProgramError};
+use super::{
+    cli::{
+        report::{millis, AnalyzeReport, CycleCounts, InstructionStats, OutputFormat, Timing},
+        InputFile,
+    },
+    ProgramError,
+};
 use clap::Parser;
 use core::fmt;
 use miden_vm::{Assembler, DefaultHost, Host, Operation, StackInputs};
 use processor::{AsmOpInfo, TraceLenSummary};
-use std::{fs, path::PathBuf};
+use std::{fs, path::PathBuf, time::Instant};
 use stdlib::StdLibrary;
+use vm_core::stack::STACK_TOP_SIZE;
+
+mod chrome_trace;
+pub use chrome_trace::profile;
 
 // CLI
 // ================================================================================================
@@ -19,6 +29,12 @@ pub struct Analyze {
     /// Path to .inputs file
     #[clap(short = 'i', long = "input", value_parser)]
     input_file: Option<PathBuf>,
+    /// Path to which a profile of the execution in the Chrome trace event format is written
+    #[clap(long = "chrome-trace", value_parser)]
+    chrome_trace_file: Option<PathBuf>,
+    /// Format in which the results are printed; `json` prints a single JSON object
+    #[clap(long = "format", value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
 }
 
 /// Implements CLI execution logic
@@ -34,8 +50,10 @@ impl Analyze {
         let stack_inputs = input_data.parse_stack_inputs()?;
         let host = DefaultHost::new(input_data.parse_advice_provider()?);
 
+        let now = Instant::now();
         let execution_details: ExecutionDetails = analyze(program.as_str(), stack_inputs, host)
             .expect("Could not retrieve execution details");
+        let analysis_time = now.elapsed();
         let program_name = self
             .assembly_file
             .file_name()
@@ -43,20 +61,61 @@ impl Analyze {
             .to_str()
             .unwrap();
 
-        println!("============================================================");
-        print!("Analyzed {} program", program_name);
-        if let Some(input_path) = &self.input_file {
-            let input_name = input_path
-                .file_name()
-                .expect("provided input path is incorrect")
-                .to_str()
-                .unwrap();
-            println!(" with {}", input_name);
+        if self.format.is_text() {
+            println!("============================================================");
+            print!("Analyzed {} program", program_name);
+            if let Some(input_path) = &self.input_file {
+                let input_name = input_path
+                    .file_name()
+                    .expect("provided input path is incorrect")
+                    .to_str()
+                    .unwrap();
+                println!(" with {}", input_name);
+            }
+
+            println!("{}", execution_details);
         }
 
-        println!("{}", execution_details);
+        // optionally export a profile of the execution
+        if let Some(chrome_trace_file) = &self.chrome_trace_file {
+            let host = DefaultHost::new(input_data.parse_advice_provider()?);
+            let trace = profile(program.as_str(), input_data.parse_stack_inputs()?, host)
+                .map_err(|err| format!("failed to profile the program: {err}"))?;
+            fs::write(chrome_trace_file, trace.to_json())
+                .map_err(|err| format!("failed to write chrome trace file: {err}"))?;
+            if self.format.is_text() {
+                println!(
+                    "Chrome trace with {} spans written to {}",
+                    trace.num_spans(),
+                    chrome_trace_file.display()
+                );
+            }
+        }
 
-        Ok(())
+        if self.format.is_text() {
+            return Ok(());
+        }
+
+        let report = AnalyzeReport {
+            program: self.assembly_file.clone(),
+            cycles: CycleCounts::from(&execution_details.trace_len_summary()),
+            total_noops: execution_details.total_noops(),
+            max_stack_depth: execution_details.max_stack_depth(),
+            instructions: execution_details
+                .asm_op_stats()
+                .iter()
+                .map(|op_info| InstructionStats {
+                    op: op_info.op().clone(),
+                    frequency: op_info.frequency(),
+                    total_cycles: op_info.total_vm_cycles(),
+                })
+                .collect(),
+            timing: Timing {
+                execute_ms: millis(analysis_time),
+                ..Default::default()
+            },
+        };
+        self.format.print_report(&report)
     }
 }
 
@@ -72,6 +131,8 @@ pub struct ExecutionDetails {
     asm_op_stats: Vec<AsmOpStats>,
     /// Information about VM components trace lengths.
     trace_len_summary: TraceLenSummary,
+    /// Maximum depth of the stack reached during the execution.
+    max_stack_depth: usize,
 }
 
 impl ExecutionDetails {
@@ -91,6 +152,11 @@ impl ExecutionDetails {
         self.trace_len_summary
     }
 
+    /// Returns the maximum depth of the stack reached during the execution.
+    pub fn max_stack_depth(&self) -> usize {
+        self.max_stack_depth
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -132,15 +198,20 @@ impl ExecutionDetails {
     pub fn set_trace_len_summary(&mut self, extended_cycles_info: &TraceLenSummary) {
         self.trace_len_summary = *extended_cycles_info;
     }
+
+    /// Sets the maximum depth of the stack reached during the execution.
+    pub fn set_max_stack_depth(&mut self, max_stack_depth: usize) {
+        self.max_stack_depth = max_stack_depth;
+    }
 }
 
 impl fmt::Display for ExecutionDetails {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        // calculate the percentage of padded rows
-        let padding_percentage = (self.trace_len_summary().padded_trace_len()
-            - self.trace_len_summary().trace_len())
-            * 100
-            / self.trace_len_summary().padded_trace_len();
+        let summary = self.trace_len_summary();
+        let chiplets = summary.chiplets_trace_len();
+        let padded_trace_len = summary.padded_trace_len();
+        let rows =
+            |len: usize| format!("{len} ({}% of padded trace)", len * 100 / padded_trace_len);
 
         writeln!(
             f,
@@ -152,16 +223,24 @@ impl fmt::Display for ExecutionDetails {
     ├── Bitwise chiplet rows: {}
     ├── Memory chiplet rows: {}
     └── Kernel ROM rows: {}\n",
-            self.trace_len_summary().trace_len(),
-            self.trace_len_summary().padded_trace_len(),
-            padding_percentage,
-            self.trace_len_summary().main_trace_len(),
-            self.trace_len_summary().range_trace_len(),
-            self.trace_len_summary().chiplets_trace_len().trace_len(),
-            self.trace_len_summary().chiplets_trace_len().hash_chiplet_len(),
-            self.trace_len_summary().chiplets_trace_len().bitwise_chiplet_len(),
-            self.trace_len_summary().chiplets_trace_len().memory_chiplet_len(),
-            self.trace_len_summary().chiplets_trace_len().kernel_rom_len(),
+            summary.trace_len(),
+            padded_trace_len,
+            summary.padding_percentage(),
+            rows(summary.main_trace_len()),
+            rows(summary.range_trace_len()),
+            rows(chiplets.trace_len()),
+            rows(chiplets.hash_chiplet_len()),
+            rows(chiplets.bitwise_chiplet_len()),
+            rows(chiplets.memory_chiplet_len()),
+            rows(chiplets.kernel_rom_len()),
+        )?;
+        writeln!(
+            f,
+            "Trace length is determined by {} rows; {} more rows fit before the trace length \
+            doubles to {} steps.\n",
+            summary.longest_component(),
+            summary.headroom(),
+            padded_trace_len * 2,
         )?;
         let total_noops = self.total_noops();
         let asm_op_stats = self.asm_op_stats();
@@ -196,6 +275,12 @@ impl fmt::Display for ExecutionDetails {
         }
 
         writeln!(f, "\nTotal number of NOOPs executed: {}", total_noops)?;
+        writeln!(
+            f,
+            "Maximum stack depth: {} ({} items in the overflow table)",
+            self.max_stack_depth,
+            self.max_stack_depth.saturating_sub(STACK_TOP_SIZE),
+        )?;
 
         Ok(())
     }
@@ -220,6 +305,7 @@ where
 
     let vm_state_iterator = processor::execute_iter(&program, stack_inputs, host);
     execution_details.set_trace_len_summary(vm_state_iterator.trace_len_summary());
+    execution_details.set_max_stack_depth(vm_state_iterator.max_stack_depth());
 
     for state in vm_state_iterator {
         let vm_state = state.map_err(ProgramError::ExecutionError)?;
@@ -291,7 +377,7 @@ impl AsmOpStats {
 #[cfg(test)]
 mod tests {
     use super::{AsmOpStats, ExecutionDetails, StackInputs};
-    use processor::{ChipletsLengths, DefaultHost, TraceLenSummary};
+    use processor::{ChipletsLengths, DefaultHost, TraceComponent, TraceLenSummary};
 
     #[test]
     fn analyze_test() {
@@ -315,8 +401,14 @@ mod tests {
                 39,
                 ChipletsLengths::from_parts(8, 0, 2, 0),
             ),
+            max_stack_depth: 19,
         };
         assert_eq!(execution_details, expected_details);
+
+        // the range checker trace determines the trace length of 64 rows, one of which is random
+        let trace_len_summary = execution_details.trace_len_summary();
+        assert_eq!(trace_len_summary.longest_component(), TraceComponent::RangeChecker);
+        assert_eq!(trace_len_summary.headroom(), 64 - 1 - 39);
     }
 
     #[test]
@@ -0,0 +1,48 @@
+//! The trace buffer pool is shared by the whole process, so the tests configuring it run in their
+//! own test binary and do not affect the traces built by the integration tests.
+
+#[test]
+fn prover_memory_budget() {
+    use miden_vm::{
+        prove, Assembler, DefaultHost, ExecutionError, MemoryUsage, ProverMemoryBudget,
+        ProvingOptions, StackInputs, TraceBufferPool,
+    };
+
+    let program = Assembler::default().compile("begin push.3 push.4 add end").unwrap();
+    let options = ProvingOptions::default();
+    let trace = miden_vm::execute(
+        &program,
+        StackInputs::default(),
+        DefaultHost::default(),
+        *options.execution_options(),
+    )
+    .unwrap();
+    let usage = MemoryUsage::estimate(trace.trace_len_summary(), &options);
+    drop(trace);
+
+    // proof generation fails early if it does not fit into the budget
+    let budget = ProverMemoryBudget::new(usage.peak() - 1);
+    let result = prove(
+        &program,
+        StackInputs::default(),
+        DefaultHost::default(),
+        options.clone().with_memory_budget(budget),
+    );
+    assert_eq!(
+        result.unwrap_err(),
+        ExecutionError::ProverMemoryBudgetExceeded {
+            required: usage.peak(),
+            budget: usage.peak() - 1,
+        }
+    );
+
+    // the trace buffer pool never retains more memory than allowed by the budget
+    let budget = ProverMemoryBudget::new(usage.peak()).with_buffer_pool(usage.main_trace);
+    let options = options.with_memory_budget(budget);
+    for _ in 0..2 {
+        prove(&program, StackInputs::default(), DefaultHost::default(), options.clone()).unwrap();
+    }
+    assert_eq!(TraceBufferPool::max_bytes(), usage.main_trace);
+    assert!(TraceBufferPool::stats().pooled_bytes <= usage.main_trace);
+    TraceBufferPool::set_max_bytes(0);
+}
@@ -35,3 +35,101 @@ fn cli_run() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+fn cli_run_entrypoint() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = escargot::CargoBuild::new()
+        .bin("miden")
+        .features("executable")
+        .current_release()
+        .current_target()
+        .run()
+        .unwrap();
+
+    let package = std::env::temp_dir().join("miden_cli_run_entrypoint.masm");
+    std::fs::write(
+        &package,
+        "proc.helper push.2 mul end
+        export.double push.3 exec.helper end
+        export.quadruple push.3 exec.helper exec.helper end",
+    )?;
+
+    // each entrypoint of the package is run as a separate program
+    for (entrypoint, output) in [("double", "Output: [6, "), ("quadruple", "Output: [12, ")] {
+        let mut cmd = bin_under_test.command();
+        cmd.arg("run").arg("-a").arg(&package).arg("--entrypoint").arg(entrypoint);
+        cmd.unwrap().assert().stdout(predicate::str::contains(output));
+    }
+
+    // unknown entrypoints are reported together with the available ones
+    let mut cmd = bin_under_test.command();
+    cmd.arg("run").arg("-a").arg(&package).arg("--entrypoint").arg("helper");
+    cmd.assert()
+        .stdout(predicate::str::contains("available entrypoints: double, quadruple"));
+
+    Ok(())
+}
+
+#[test]
+fn cli_run_json() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = escargot::CargoBuild::new()
+        .bin("miden")
+        .features("executable")
+        .current_release()
+        .current_target()
+        .run()
+        .unwrap();
+
+    let mut cmd = bin_under_test.command();
+    cmd.arg("run")
+        .arg("-a")
+        .arg("./examples/fib/fib.masm")
+        .arg("-n")
+        .arg("1")
+        .arg("--format")
+        .arg("json");
+
+    // the results are printed as a single JSON object without the human-readable output
+    let output = cmd.unwrap();
+    output
+        .assert()
+        .success()
+        .stdout(predicate::str::starts_with("{\"program_hash\":\""))
+        .stdout(predicate::str::contains("\"cycles\":{\"trace_len\":"))
+        .stdout(predicate::str::contains("VM cycles").not());
+
+    Ok(())
+}
+
+#[test]
+fn cli_repl_script_export() -> Result<(), Box<dyn std::error::Error>> {
+    let bin_under_test = escargot::CargoBuild::new()
+        .bin("miden")
+        .features("executable")
+        .current_release()
+        .current_target()
+        .run()
+        .unwrap();
+
+    let dir = std::env::temp_dir();
+    let inputs = dir.join("miden_cli_repl_script.inputs");
+    std::fs::write(&inputs, r#"{ "operand_stack": ["5"] }"#)?;
+    let session = dir.join("miden_cli_repl_session");
+    let script = dir.join("miden_cli_repl_script.txt");
+    std::fs::write(&script, format!("push.2 mul\n!stack\n!export {}", session.display()))?;
+
+    // the script is executed against the provided inputs, and the session is exported
+    let mut cmd = bin_under_test.command();
+    cmd.arg("repl").arg("-i").arg(&inputs).arg("--script").arg(&script);
+    cmd.assert()
+        .success()
+        .stdout(predicate::str::contains("10 0 0 0"))
+        .stdout(predicate::str::contains("The session has been exported"));
+
+    // running the exported program with the exported inputs reproduces the state of the session
+    let mut cmd = bin_under_test.command();
+    cmd.arg("run").arg("-a").arg(session.with_extension("masm"));
+    cmd.assert().success().stdout(predicate::str::contains("Output: [10, "));
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+use test_utils::{
+    build_test,
+    differential::{self, ReferenceError, ReferenceInterpreter},
+    proptest::prelude::*,
+    Felt, StackInputs, Test, U32_BOUND,
+};
+
+// REFERENCE INTERPRETER TESTS
+// ================================================================================================
+
+#[test]
+fn reference_stack_and_memory() {
+    let test = build_test!(
+        "begin push.7 mem_store.2 u32assert2 u32divmod movup.9 push.1 add.2 dupw mem_storew.5 end",
+        &[1, 2, 3, 4, 5, 6, 7, 8, 9, 17, 3]
+    );
+    let program = test.compile().unwrap();
+    let state = ReferenceInterpreter::new(&test.stack_inputs).execute(&program).unwrap();
+
+    // 17 / 3 = 5 with the remainder of 2, and mem_storew leaves the stored word on the stack
+    let expected = [3, 2, 2, 5, 3, 2, 2, 5, 9, 8, 7, 6, 5, 4, 3, 1, 0, 0, 0, 0, 0];
+    assert_eq!(state.stack, expected.map(Felt::new).to_vec());
+    assert_eq!(state.memory.len(), 2);
+    assert_eq!(state.memory[&2], [Felt::new(7), Felt::new(0), Felt::new(0), Felt::new(0)]);
+    assert_eq!(state.memory[&5], [Felt::new(5), Felt::new(2), Felt::new(2), Felt::new(3)]);
+
+    test.prop_expect_reference().unwrap();
+}
+
+#[test]
+fn reference_errors() {
+    let program = build_test!("begin push.2 assert.err=5 end").compile().unwrap();
+    let result = ReferenceInterpreter::new(&StackInputs::default()).execute(&program);
+    assert_eq!(result.unwrap_err(), ReferenceError::FailedAssertion(5));
+
+    let test = build_test!("begin u32wrapping_add end", &[U32_BOUND, 1]);
+    let program = test.compile().unwrap();
+    let result = ReferenceInterpreter::new(&test.stack_inputs).execute(&program);
+    assert!(matches!(result, Err(ReferenceError::UndefinedResult(_))));
+
+    let program = build_test!("begin push.1 while.true push.1 end end").compile().unwrap();
+    let result = ReferenceInterpreter::new(&StackInputs::default())
+        .with_max_steps(100)
+        .execute(&program);
+    assert_eq!(result.unwrap_err(), ReferenceError::StepLimitExceeded(100));
+}
+
+// DIFFERENTIAL TESTS - RANDOMIZED
+// ================================================================================================
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    #[test]
+    fn differential_random_programs(
+        source in differential::program(24, 3),
+        inputs in prop::collection::vec(any::<u32>(), 0..20),
+    ) {
+        let mut test = Test::new(&source, false);
+        test.stack_inputs =
+            StackInputs::try_from_ints(inputs.into_iter().map(u64::from)).unwrap();
+        test.prop_expect_reference()?;
+    }
+}
@@ -1,4 +1,4 @@
-use processor::{AsmOpInfo, ContextId, VmState};
+use processor::{AsmOpInfo, ContextId, MemoryDiff, VmState, Watchpoint};
 use test_utils::{build_debug_test, Felt, ToElements, ONE};
 use vm_core::{AssemblyOp, Operation};
 
@@ -220,6 +220,66 @@ fn test_exec_iter() {
     }
 }
 
+#[test]
+fn test_exec_iter_watchpoints() {
+    let source = "begin push.5 mem_store.10 push.6 push.7 add mem_store.11 push.8 mem_store.20 end";
+    let test = build_debug_test!(source);
+    let hits = test
+        .execute_iter()
+        .watch(vec![Watchpoint::Memory(10..12)])
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    // only the writes to the watched addresses trigger the memory watchpoint
+    assert_eq!(hits.len(), 2);
+    assert!(hits.iter().all(|hit| hit.watchpoints == vec![0]));
+    assert_eq!(hits[0].state.memory, vec![(10, slice_to_word(&[5, 0, 0, 0]))]);
+    assert_eq!(
+        hits[1].state.memory,
+        vec![(10, slice_to_word(&[5, 0, 0, 0])), (11, slice_to_word(&[13, 0, 0, 0]))]
+    );
+}
+
+#[test]
+fn test_exec_iter_memory_diff() {
+    let source = "begin push.5 mem_store.10 push.6 mem_store.11 push.7 mem_store.10 end";
+    let test = build_debug_test!(source);
+    let iter = test.execute_iter();
+
+    // find the cycles at which the writes to memory become visible
+    let states = test.execute_iter().collect::<Result<Vec<_>, _>>().unwrap();
+    let first_write = states.iter().position(|state| !state.memory.is_empty()).unwrap() as u32;
+    let last_clk = states.last().unwrap().clk;
+
+    assert_eq!(iter.memory_diff(0, 0), vec![]);
+    assert_eq!(
+        iter.memory_diff(0, first_write),
+        vec![MemoryDiff {
+            ctx: ContextId::root(),
+            addr: 10,
+            old_value: slice_to_word(&[0, 0, 0, 0]),
+            new_value: slice_to_word(&[5, 0, 0, 0]),
+        }]
+    );
+    assert_eq!(
+        iter.memory_diff(first_write, last_clk),
+        vec![
+            MemoryDiff {
+                ctx: ContextId::root(),
+                addr: 10,
+                old_value: slice_to_word(&[5, 0, 0, 0]),
+                new_value: slice_to_word(&[7, 0, 0, 0]),
+            },
+            MemoryDiff {
+                ctx: ContextId::root(),
+                addr: 11,
+                old_value: slice_to_word(&[0, 0, 0, 0]),
+                new_value: slice_to_word(&[6, 0, 0, 0]),
+            },
+        ]
+    );
+}
+
 // HELPER FUNCTIONS
 // =================================================================
 fn slice_to_word(values: &[i32]) -> [Felt; 4] {
@@ -389,6 +389,64 @@ fn simple_dyncall() {
     );
 }
 
+#[test]
+fn dynsyscall_with_procref() {
+    let kernel_source = "
+        export.foo
+            # drop the hash of foo, which is on top of the stack when it is invoked dynamically
+            dropw
+            add
+        end
+
+        export.bar
+            dropw
+            mul
+        end
+    ";
+
+    let program_source = "
+        begin
+            procref.$kernel::foo
+            dynsyscall
+        end";
+
+    let test = Test {
+        source: program_source.to_string(),
+        kernel: Some(kernel_source.to_string()),
+        stack_inputs: StackInputs::try_from_ints([1, 2]).unwrap(),
+        advice_inputs: AdviceInputs::default(),
+        in_debug_mode: false,
+        libraries: Vec::default(),
+    };
+    test.expect_stack(&[3]);
+
+    test.prove_and_verify(vec![1, 2], false);
+
+    // procedures which are not exported from the kernel cannot be invoked via dynamic syscalls;
+    // the body of baz differs from all kernel procedures, as procedures are identified by their roots
+    let program_source = "
+        proc.baz
+            dropw
+            sub
+        end
+
+        begin
+            procref.baz
+            dynsyscall
+        end";
+
+    let test = Test {
+        source: program_source.to_string(),
+        kernel: Some(kernel_source.to_string()),
+        stack_inputs: StackInputs::try_from_ints([1, 2]).unwrap(),
+        advice_inputs: AdviceInputs::default(),
+        in_debug_mode: false,
+        libraries: Vec::default(),
+    };
+    let result = test.execute();
+    assert!(matches!(result, Err(ExecutionError::SyscallTargetNotInKernel(_))));
+}
+
 // PROCREF INSTRUCTION
 // ================================================================================================
 
@@ -2,6 +2,7 @@ use test_utils::build_test;
 
 mod air;
 mod cli;
+mod differential;
 mod exec_iters;
 mod flow_control;
 mod operations;
@@ -19,3 +20,1011 @@ fn multi_output_program() {
     let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
     test.prove_and_verify(vec![1, 2, 3], false);
 }
+
+#[test]
+fn proof_metadata() {
+    use miden_vm::{
+        prove, verify, Assembler, DefaultHost, ExecutionProof, ProgramInfo, ProvingOptions,
+        StackInputs, VerificationError,
+    };
+
+    let program = Assembler::default().compile("begin push.1 push.2 add end").unwrap();
+    let options = ProvingOptions::default().with_metadata(Some("test proof".to_string()));
+    let (stack_outputs, proof) =
+        prove(&program, StackInputs::default(), DefaultHost::default(), options).unwrap();
+
+    let metadata = proof.metadata().expect("proof should carry metadata").clone();
+    assert_eq!(metadata.program_hash(), &program.hash());
+    assert_eq!(metadata.label(), Some("test proof"));
+    assert_eq!(metadata.options(), proof.stark_proof().options());
+
+    // metadata survives serialization
+    let proof = ExecutionProof::from_bytes(&proof.to_bytes()).unwrap();
+    assert_eq!(proof.metadata(), Some(&metadata));
+
+    let program_info = ProgramInfo::from(program);
+    let result = verify(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        proof.clone(),
+    );
+    assert!(result.is_ok(), "error: {result:?}");
+
+    // the program hash in the metadata is checked against the expected one
+    let other_program = Assembler::default().compile("begin push.1 push.3 add end").unwrap();
+    let result = verify(
+        ProgramInfo::from(other_program),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        proof.clone(),
+    );
+    assert!(matches!(result, Err(VerificationError::ProgramHashMismatch { .. })));
+
+    // metadata is bound to the proof, and thus, cannot be altered
+    let forged_metadata = miden_vm::ProofMetadata::new(
+        *metadata.program_hash(),
+        metadata.version().to_string(),
+        metadata.hash_fn(),
+        metadata.options().clone(),
+        metadata.timestamp(),
+        Some("forged label".to_string()),
+    );
+    let forged_proof = proof.with_metadata(forged_metadata);
+    let result = verify(program_info, StackInputs::default(), stack_outputs, forged_proof);
+    assert!(matches!(result, Err(VerificationError::VerifierError(_))));
+}
+
+// the proof-of-work nonce is searched for in parallel with the `concurrent` feature
+#[cfg(not(feature = "concurrent"))]
+#[test]
+fn reproducible_proofs() {
+    use miden_vm::{
+        math::Felt, prove, verify, Assembler, DefaultHost, ProvingOptions, StackInputs,
+    };
+
+    let program = Assembler::default().compile("begin push.1 push.2 add end").unwrap();
+    let seed = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let options = ProvingOptions::default()
+        .with_metadata(Some("reproducible".to_string()))
+        .with_seed(seed);
+
+    let (stack_outputs, proof) =
+        prove(&program, StackInputs::default(), DefaultHost::default(), options.clone()).unwrap();
+    let (_, other_proof) =
+        prove(&program, StackInputs::default(), DefaultHost::default(), options).unwrap();
+    assert_eq!(proof.to_bytes(), other_proof.to_bytes());
+    assert_eq!(proof.metadata().unwrap().timestamp(), 0);
+
+    // a different seed results in a different proof
+    let options = ProvingOptions::default()
+        .with_metadata(Some("reproducible".to_string()))
+        .with_seed([Felt::new(5); 4]);
+    let (_, other_proof) =
+        prove(&program, StackInputs::default(), DefaultHost::default(), options).unwrap();
+    assert_ne!(proof.to_bytes(), other_proof.to_bytes());
+
+    let result = verify(program.into(), StackInputs::default(), stack_outputs, proof);
+    assert!(result.is_ok(), "error: {result:?}");
+}
+
+#[test]
+fn vm_profile() {
+    use miden_vm::{
+        execute, prove, verify, Assembler, DefaultHost, ExecutionError, ExecutionOptions,
+        ExecutionProof, ProgramInfo, ProvingOptions, StackInputs, VerificationError, VmProfile,
+    };
+
+    let profile = VmProfile::full().without_bitwise().without_memory();
+    let exec_options = ExecutionOptions::default().with_profile(profile);
+
+    let program = Assembler::default().compile("begin push.1 push.2 add end").unwrap();
+    let options = ProvingOptions::default().with_execution_options(exec_options);
+    let (stack_outputs, proof) =
+        prove(&program, StackInputs::default(), DefaultHost::default(), options).unwrap();
+    assert_eq!(proof.profile(), profile);
+
+    // the profile survives serialization
+    let proof = ExecutionProof::from_bytes(&proof.to_bytes()).unwrap();
+    assert_eq!(proof.profile(), profile);
+
+    let program_info = ProgramInfo::from(program);
+    let result = verify(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        proof.clone(),
+    );
+    assert!(result.is_ok(), "error: {result:?}");
+
+    // the proof cannot be verified against a different profile
+    let forged_proof = proof.with_profile(VmProfile::full());
+    let result = verify(program_info, StackInputs::default(), stack_outputs, forged_proof);
+    assert!(matches!(result, Err(VerificationError::VerifierError(_))));
+
+    // operations which require an excluded chiplet cannot be executed
+    let program = Assembler::default().compile("begin push.1 push.2 u32and end").unwrap();
+    let result = execute(&program, StackInputs::default(), DefaultHost::default(), exec_options);
+    assert!(matches!(result, Err(ExecutionError::ChipletNotInProfile("bitwise", _))));
+
+    let program = Assembler::default().compile("begin push.1 mem_load end").unwrap();
+    let result = execute(&program, StackInputs::default(), DefaultHost::default(), exec_options);
+    assert!(matches!(result, Err(ExecutionError::ChipletNotInProfile("memory", _))));
+}
+
+#[test]
+fn memory_preload() {
+    use miden_vm::{
+        math::Felt, prove, verify, AdviceInputs, Assembler, DefaultHost, MemAdviceProvider,
+        MemoryImage, ProgramInfo, ProvingOptions, StackInputs,
+    };
+    use stdlib::StdLibrary;
+
+    // the program loads the image whose commitment is at the top of the stack
+    let source = "use.std::mem begin exec.mem::load_image padw mem_loadw.100 add add add end";
+    let program = Assembler::default()
+        .with_library(&StdLibrary::default())
+        .and_then(|assembler| assembler.compile(source))
+        .unwrap();
+    let words = [[1_u32.into(), 2_u32.into(), 3_u32.into(), 4_u32.into()]];
+    let image = MemoryImage::new().with_words(100, &words).unwrap();
+    let (commitment, values) = image.to_advice_map_entry();
+    let stack_inputs = StackInputs::new(commitment.as_elements().to_vec()).unwrap();
+
+    let advice_inputs = AdviceInputs::default().with_map([(commitment, values.clone())]);
+    let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+    let (stack_outputs, proof) =
+        prove(&program, stack_inputs.clone(), host, ProvingOptions::default()).unwrap();
+    assert_eq!(stack_outputs.get_stack_item(0), Some(10_u32.into()));
+
+    // the commitment is a public input of the program
+    let program_info = ProgramInfo::from(program.clone());
+    let result = verify(program_info, stack_inputs.clone(), stack_outputs, proof);
+    assert!(result.is_ok(), "error: {result:?}");
+
+    // the program cannot be executed against memory contents which do not match the commitment
+    let mut tampered = values;
+    tampered[5] += Felt::from(1_u32);
+    let advice_inputs = AdviceInputs::default().with_map([(commitment, tampered)]);
+    let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+    assert!(prove(&program, stack_inputs, host, ProvingOptions::default()).is_err());
+}
+
+#[test]
+fn memory_outputs() {
+    use miden_vm::{
+        execute, prove_with_memory_outputs, verify, Assembler, DefaultHost, ExecutionError,
+        ExecutionOptions, MemoryImage, OutputBuffer, ProgramInfo, ProvingOptions, StackInputs,
+    };
+    use stdlib::StdLibrary;
+
+    // the program returns a commitment to the words it leaves in memory via the stack
+    let assembler = Assembler::default().with_library(&StdLibrary::default()).unwrap();
+    let source = "use.std::output
+        begin
+            push.1.2.3.4 mem_storew.10 dropw push.5 mem_store.12 push.3.10 exec.output::digest
+        end";
+    let program = assembler.compile(source).unwrap();
+    let words = [
+        [1_u32.into(), 2_u32.into(), 3_u32.into(), 4_u32.into()],
+        [0_u32.into(); 4],
+        [5_u32.into(), 0_u32.into(), 0_u32.into(), 0_u32.into()],
+    ];
+    let expected = MemoryImage::new().with_words(10, &words).unwrap();
+
+    // memory outputs are exported from the execution trace
+    let host = DefaultHost::default().with_memory_outputs(core::iter::once(10..13)).unwrap();
+    let trace =
+        execute(&program, StackInputs::default(), host, ExecutionOptions::default()).unwrap();
+    assert_eq!(&expected, trace.memory_outputs());
+
+    // and returned together with the proof, which does not change the public inputs
+    let host = DefaultHost::default().with_memory_outputs(core::iter::once(10..13)).unwrap();
+    let (stack_outputs, memory_outputs, proof) = prove_with_memory_outputs(
+        &program,
+        StackInputs::default(),
+        host,
+        ProvingOptions::default(),
+        &(),
+    )
+    .unwrap();
+    assert_eq!(expected, memory_outputs);
+
+    let program_info = ProgramInfo::from(program);
+    let result = verify(program_info, StackInputs::default(), stack_outputs.clone(), proof);
+    assert!(result.is_ok(), "error: {result:?}");
+
+    // the memory outputs are verified against the commitment in the verified stack outputs
+    let exported = memory_outputs.iter().map(|(_, word)| *word).collect::<Vec<_>>();
+    assert!(OutputBuffer::verify(&stack_outputs, exported).is_ok());
+
+    let mut tampered = words.to_vec();
+    tampered[2][0] = 6_u32.into();
+    assert!(OutputBuffer::verify(&stack_outputs, tampered).is_err());
+
+    // the requested ranges are validated when they are registered
+    #[allow(clippy::reversed_empty_ranges)]
+    let result = DefaultHost::default().with_memory_outputs(core::iter::once(13..10));
+    assert!(matches!(result, Err(ExecutionError::InvalidMemoryRange { .. })));
+    let result = DefaultHost::default().with_memory_outputs(core::iter::once(0..u32::MAX));
+    assert!(matches!(result, Err(ExecutionError::MemoryOutputsTooLarge { .. })));
+}
+
+#[test]
+fn deep_stack_inputs() {
+    use miden_vm::{
+        prove, verify, Assembler, DefaultHost, ProgramInfo, ProvingOptions, StackInputs,
+    };
+
+    // the two deepest inputs are in the overflow table when execution begins
+    let program = Assembler::default().compile("begin dropw dropw dropw dropw add end").unwrap();
+    let stack_inputs = StackInputs::try_from_ints(1..=18).unwrap();
+    assert!(stack_inputs.overflow_commitment().is_some());
+
+    let host = DefaultHost::default();
+    let options = ProvingOptions::default();
+    let (stack_outputs, proof) = prove(&program, stack_inputs.clone(), host, options).unwrap();
+    assert_eq!(stack_outputs.get_stack_item(0), Some(3_u32.into()));
+
+    let program_info = ProgramInfo::from(program);
+    let result = verify(program_info.clone(), stack_inputs, stack_outputs.clone(), proof.clone());
+    assert!(result.is_ok(), "error: {result:?}");
+
+    // the proof cannot be verified against other values in the overflow table
+    let other_inputs = StackInputs::try_from_ints([5].into_iter().chain(2..=18)).unwrap();
+    let result = verify(program_info, other_inputs, stack_outputs, proof);
+    assert!(result.is_err());
+}
+
+#[test]
+fn fast_execution() {
+    use miden_vm::{
+        execute, execute_fast, Assembler, DefaultHost, ExecutionError, ExecutionOptions,
+        StackInputs,
+    };
+
+    let source = "
+        begin
+            push.7 mem_store.100 debug.stack mem_load.100
+            push.3 u32and
+            repeat.10 push.1 push.2 hperm dropw dropw drop drop end
+        end";
+    let program = Assembler::default().compile(source).unwrap();
+    let stack_inputs = StackInputs::try_from_ints([1, 2, 3]).unwrap();
+    let options = ExecutionOptions::default().with_debugging();
+
+    let trace = execute(&program, stack_inputs.clone(), DefaultHost::default(), options).unwrap();
+    let (outputs, summary) =
+        execute_fast(&program, stack_inputs.clone(), DefaultHost::default(), options).unwrap();
+    assert_eq!(&outputs, trace.stack_outputs());
+
+    // the lengths of the trace match the ones of the trace built by execute()
+    assert_eq!(&summary, trace.trace_len_summary());
+    assert_eq!(summary.padded_trace_len(), trace.get_trace_len());
+
+    // the expected number of cycles may be rounded up beyond the cycle limit
+    let options = ExecutionOptions::new(Some(1000), 700, false).unwrap().with_debugging();
+    let (outputs, _) =
+        execute_fast(&program, stack_inputs.clone(), DefaultHost::default(), options).unwrap();
+    assert_eq!(&outputs, trace.stack_outputs());
+
+    // the cycle limit is still enforced
+    let options = ExecutionOptions::new(Some(64), 64, false).unwrap();
+    let result = execute_fast(&program, stack_inputs, DefaultHost::default(), options);
+    assert!(matches!(result, Err(ExecutionError::CycleLimitExceeded(64))));
+}
+
+#[test]
+fn snapshot_and_resume() {
+    use miden_vm::{
+        execute, execute_to_snapshot,
+        utils::{ByteWriter, Deserializable, Serializable},
+        AdviceInputs, Assembler, DefaultHost, ExecutionOptions, MemAdviceProvider, StackInputs,
+        VmSnapshot,
+    };
+
+    let first = Assembler::default()
+        .compile("begin push.5 mem_store.10 adv_push.1 push.7 end")
+        .unwrap();
+    let second = Assembler::default()
+        .compile("begin mem_load.10 adv_push.1 add add add end")
+        .unwrap();
+    let whole = Assembler::default()
+        .compile(
+            "begin push.5 mem_store.10 adv_push.1 push.7 mem_load.10 adv_push.1 add add add end",
+        )
+        .unwrap();
+    let advice_inputs = AdviceInputs::default().with_stack_values([3, 4]).unwrap();
+    let stack_inputs = StackInputs::try_from_ints([1, 2]).unwrap();
+    let options = ExecutionOptions::default();
+
+    let snapshot =
+        execute_to_snapshot(&first, stack_inputs.clone(), advice_inputs.clone(), options).unwrap();
+    assert_eq!(snapshot.memory().len(), 1);
+    assert_eq!(snapshot.advice().stack().len(), 1);
+
+    // the computation can be resumed from a deserialized snapshot
+    let snapshot = VmSnapshot::read_from_bytes(&snapshot.to_bytes()).unwrap();
+    let resumed = snapshot.resume(&second, options).unwrap();
+    assert!(resumed.num_cycles() > snapshot.num_cycles());
+
+    let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+    let trace = execute(&whole, stack_inputs, host, options).unwrap();
+    assert_eq!(resumed.stack(), trace.stack_outputs().stack());
+    assert_eq!(resumed.stack()[0].as_int(), 19);
+
+    // snapshots declaring stacks deeper than the stack inputs allow are rejected
+    let mut bytes = Vec::new();
+    bytes.write_u64(0);
+    bytes.write_usize(usize::MAX);
+    assert!(VmSnapshot::read_from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn output_buffer() {
+    use miden_vm::{
+        execute, Assembler, DefaultHost, ExecutionOptions, OutputBuffer, OutputBufferError,
+        StackInputs,
+    };
+    use stdlib::StdLibrary;
+
+    // the program returns two words via an output buffer
+    let assembler = Assembler::default().with_library(&StdLibrary::default()).unwrap();
+    let program = assembler
+        .compile(
+            "use.std::output
+            begin
+                push.1.2.3.4 mem_storew.100 dropw push.5.6.7.8 mem_storew.101 dropw
+                push.2.100 exec.output::commit
+            end",
+        )
+        .unwrap();
+
+    let mut host = DefaultHost::default();
+    let trace =
+        execute(&program, StackInputs::default(), &mut host, ExecutionOptions::default()).unwrap();
+    let advice_provider = host.into_inner();
+
+    let expected = vec![
+        [1_u32.into(), 2_u32.into(), 3_u32.into(), 4_u32.into()],
+        [5_u32.into(), 6_u32.into(), 7_u32.into(), 8_u32.into()],
+    ];
+    let buffer = OutputBuffer::extract(trace.stack_outputs(), &advice_provider).unwrap();
+    assert_eq!(buffer.words(), &expected[..]);
+    assert_eq!(buffer.commitment(), OutputBuffer::commitment_to(&expected));
+
+    // the verifier checks the words it received against the stack outputs
+    let result = OutputBuffer::verify(trace.stack_outputs(), expected.clone());
+    assert_eq!(result, Ok(buffer));
+
+    let mut tampered = expected;
+    tampered[1][0] = 9_u32.into();
+    let result = OutputBuffer::verify(trace.stack_outputs(), tampered);
+    assert!(matches!(result, Err(OutputBufferError::CommitmentMismatch { .. })));
+}
+
+#[test]
+fn batch_verification() {
+    use miden_vm::{
+        prove, verify_all, verify_many, Assembler, DefaultHost, ProgramInfo, ProvingOptions,
+        StackInputs, VerificationError,
+    };
+
+    let sources = ["begin push.1 push.2 add end", "begin push.3 push.4 mul end"];
+    let mut batch = Vec::new();
+    for source in sources {
+        let program = Assembler::default().compile(source).unwrap();
+        let (stack_outputs, proof) = prove(
+            &program,
+            StackInputs::default(),
+            DefaultHost::default(),
+            ProvingOptions::default(),
+        )
+        .unwrap();
+        batch.push((ProgramInfo::from(program), StackInputs::default(), stack_outputs, proof));
+    }
+
+    let results = verify_many(batch.clone());
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|result| result.is_ok()));
+    assert_eq!(verify_all(batch.clone()).unwrap().len(), 2);
+
+    // use the outputs of the second program for the first one to make the first proof invalid
+    batch[0].2 = batch[1].2.clone();
+    let results = verify_many(batch.clone());
+    assert!(matches!(results[0], Err(VerificationError::VerifierError(_))));
+    assert!(results[1].is_ok());
+    assert!(matches!(verify_all(batch), Err((0, VerificationError::VerifierError(_)))));
+}
+
+#[test]
+fn kernel_upgrade() {
+    use miden_vm::{
+        prove, verify_with_kernels, Assembler, DefaultHost, ProgramInfo, ProvingOptions,
+        StackInputs, VerificationError,
+    };
+
+    let kernel_v1 = "export.foo add end";
+    let kernel_v2 = "export.foo add end export.bar mul end";
+    let source = "begin push.2 push.3 syscall.foo end";
+
+    let program = Assembler::default().with_kernel(kernel_v1).unwrap().compile(source).unwrap();
+    let kernel_v1 = program.kernel().clone();
+    let kernel_v2 = Assembler::default().with_kernel(kernel_v2).unwrap().kernel().clone();
+    let program_info = ProgramInfo::from(program.clone());
+    assert_eq!(program.syscall_targets(), kernel_v1.proc_hashes());
+
+    // the program can be executed against the upgraded kernel without being recompiled
+    assert!(program.is_compatible_with(&kernel_v2));
+    let upgraded = program.clone().replace_kernel(kernel_v2.clone()).unwrap();
+    assert_eq!(program.hash(), upgraded.hash());
+    let (stack_outputs, proof) = prove(
+        &upgraded,
+        StackInputs::default(),
+        DefaultHost::default(),
+        ProvingOptions::default(),
+    )
+    .unwrap();
+
+    // the proof is accepted only if the program declares compatibility with the upgraded kernel
+    let kernels = [kernel_v1.clone(), kernel_v2.clone()];
+    let result = verify_with_kernels(
+        program_info.clone(),
+        &kernels,
+        StackInputs::default(),
+        stack_outputs.clone(),
+        proof.clone(),
+    );
+    assert!(matches!(result, Err(VerificationError::VerifierError(_))));
+
+    let program_info = program_info.with_compatible_kernels([kernel_v2.commitment()]);
+    let result = verify_with_kernels(
+        program_info.clone(),
+        &kernels,
+        StackInputs::default(),
+        stack_outputs.clone(),
+        proof.clone(),
+    );
+    assert!(result.is_ok(), "error: {result:?}");
+
+    // and only if the upgraded kernel is whitelisted
+    let result = verify_with_kernels(
+        program_info,
+        &[kernel_v1],
+        StackInputs::default(),
+        stack_outputs,
+        proof,
+    );
+    assert!(matches!(result, Err(VerificationError::VerifierError(_))));
+
+    // programs cannot be executed against kernels missing the procedures they invoke
+    let kernel_v3 = Assembler::default().with_kernel("export.bar mul end").unwrap();
+    assert!(!program.is_compatible_with(kernel_v3.kernel()));
+    assert!(program.replace_kernel(kernel_v3.kernel().clone()).is_err());
+}
+
+#[test]
+fn trace_handlers() {
+    use miden_vm::{execute, Assembler, DefaultHost, ExecutionOptions, StackInputs};
+    use std::sync::{Arc, Mutex};
+
+    let source = "begin repeat.3 trace.1 push.1 add trace.2 end end";
+    let program = Assembler::default().compile(source).unwrap();
+
+    // traces do not affect the hash of the program
+    let untraced = Assembler::default().compile("begin repeat.3 push.1 add end end").unwrap();
+    assert_eq!(program.hash(), untraced.hash());
+
+    // handlers are invoked with the state of the VM at the point the trace is emitted
+    let traces = Arc::new(Mutex::new(Vec::new()));
+    let handler_traces = traces.clone();
+    let host = DefaultHost::default().with_trace_handler(2, move |process, trace_id| {
+        let top = process.get_stack_item(0).as_int();
+        handler_traces.lock().unwrap().push((trace_id, top));
+        Ok(())
+    });
+    let options = ExecutionOptions::default().with_tracing();
+    let stack_inputs = StackInputs::try_from_ints([10]).unwrap();
+    execute(&program, stack_inputs, host, options).unwrap();
+    assert_eq!(*traces.lock().unwrap(), [(2, 11), (2, 12), (2, 13)]);
+
+    // handlers are not invoked when tracing is disabled
+    let handler_traces = traces.clone();
+    let host = DefaultHost::default().with_trace_handler(2, move |_, trace_id| {
+        handler_traces.lock().unwrap().push((trace_id, 0));
+        Ok(())
+    });
+    let stack_inputs = StackInputs::try_from_ints([10]).unwrap();
+    execute(&program, stack_inputs, host, ExecutionOptions::default()).unwrap();
+    assert_eq!(traces.lock().unwrap().len(), 3);
+}
+
+#[test]
+fn max_stack_depth() {
+    use miden_vm::{execute, execute_iter, Assembler, DefaultHost, ExecutionOptions, StackInputs};
+
+    // the items hidden from the procedure executed in a new context count towards the maximum
+    // depth: 2 items are pushed in the root context and 4 more items inside the procedure
+    let source = "
+        proc.foo push.1 push.2 push.3 push.4 dropw end
+        begin push.5 push.6 call.foo drop drop end";
+    let program = Assembler::default().compile(source).unwrap();
+
+    let options = ExecutionOptions::default().with_stack_depth_warning(17);
+    let trace = execute(&program, StackInputs::default(), DefaultHost::default(), options).unwrap();
+    assert_eq!(trace.max_stack_depth(), 22);
+
+    let iter = execute_iter(&program, StackInputs::default(), DefaultHost::default());
+    assert_eq!(iter.max_stack_depth(), 22);
+}
+
+#[test]
+fn security_report() {
+    use miden_vm::{
+        prove, verify, Assembler, DefaultHost, FieldExtension, HashFunction, ProgramInfo,
+        ProvingOptions, StackInputs,
+    };
+
+    let program = Assembler::default().compile("begin push.1 push.2 add end").unwrap();
+    let (stack_outputs, proof) = prove(
+        &program,
+        StackInputs::default(),
+        DefaultHost::default(),
+        ProvingOptions::with_96_bit_security(false),
+    )
+    .unwrap();
+    let security_level = proof.security_level();
+    let trace_len = proof.stark_proof().get_trace_info().length();
+
+    let report =
+        verify(ProgramInfo::from(program), StackInputs::default(), stack_outputs, proof).unwrap();
+    assert_eq!(report.security_level(), security_level);
+    assert_eq!(report.hash_fn, HashFunction::Blake3_192);
+    assert_eq!(report.field_extension, FieldExtension::Quadratic);
+    assert_eq!(report.trace_len, trace_len);
+    assert_eq!((report.blowup_factor, report.num_queries, report.grinding_factor), (8, 27, 16));
+    assert_eq!((report.fri_folding_factor, report.fri_remainder_max_degree), (8, 255));
+
+    // the field is large enough for the queries and the hash function to determine the security
+    assert_eq!(report.field_security, 128 - (trace_len * 8).ilog2());
+    assert_eq!(report.query_security, 27 * 3 + 16);
+    assert_eq!(report.hash_security, 96);
+    assert_eq!(report.conjectured_security, 96);
+    assert!(report.proven_security <= report.conjectured_security);
+}
+
+#[test]
+fn proven_security() {
+    use miden_vm::{
+        prove, verify, Assembler, DefaultHost, FieldExtension, ProgramInfo, ProvingOptions,
+        StackInputs,
+    };
+
+    let program = Assembler::default().compile("begin push.1 push.2 add end").unwrap();
+    let program_info = ProgramInfo::from(program.clone());
+    for recursive in [false, true] {
+        let (stack_outputs, proof) = prove(
+            &program,
+            StackInputs::default(),
+            DefaultHost::default(),
+            ProvingOptions::with_128bit_proven(recursive),
+        )
+        .unwrap();
+
+        let report =
+            verify(program_info.clone(), StackInputs::default(), stack_outputs, proof).unwrap();
+        assert_eq!(report.field_extension, FieldExtension::Cubic);
+        assert_eq!(report.grinding_factor, 0);
+        assert_eq!(report.conjectured_security, 128);
+        assert_eq!(report.proven_security, 128);
+    }
+}
+
+#[test]
+fn bounded_verification() {
+    use miden_vm::{
+        prove, verify, verify_bounded, Assembler, DefaultHost, ProgramInfo, ProvingOptions,
+        StackInputs, VerificationError, VerifierLimits,
+    };
+
+    let program = Assembler::default().compile("begin push.1 push.2 add end").unwrap();
+    let (stack_outputs, proof) = prove(
+        &program,
+        StackInputs::default(),
+        DefaultHost::default(),
+        ProvingOptions::default(),
+    )
+    .unwrap();
+    let program_info = ProgramInfo::from(program);
+    let proof_bytes = proof.to_bytes();
+
+    // the security level is the same as the one returned by the regular verifier
+    let security_level = verify_bounded(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        &proof_bytes,
+        &VerifierLimits::default(),
+    )
+    .unwrap();
+    let expected =
+        verify(program_info.clone(), StackInputs::default(), stack_outputs.clone(), proof)
+            .map(|report| report.security_level());
+    assert_eq!(Ok(security_level), expected);
+
+    let limits = VerifierLimits {
+        max_proof_size: proof_bytes.len() - 1,
+        ..VerifierLimits::default()
+    };
+    let result = verify_bounded(
+        program_info.clone(),
+        StackInputs::default(),
+        stack_outputs.clone(),
+        &proof_bytes,
+        &limits,
+    );
+    assert!(matches!(
+        result,
+        Err(VerificationError::LimitExceeded {
+            resource: "size",
+            ..
+        })
+    ));
+
+    // the trace length is checked against the header of the proof, before the rest of the proof
+    // is deserialized
+    let limits = VerifierLimits {
+        max_trace_len: 1,
+        ..VerifierLimits::default()
+    };
+    let truncated_proof = &proof_bytes[..proof_bytes.len() / 2];
+    let result = verify_bounded(
+        program_info,
+        StackInputs::default(),
+        stack_outputs,
+        truncated_proof,
+        &limits,
+    );
+    assert!(matches!(
+        result,
+        Err(VerificationError::LimitExceeded {
+            resource: "trace length",
+            ..
+        })
+    ));
+}
+
+#[test]
+fn bounded_verification_128bit_proven() {
+    use miden_vm::{
+        prove, verify_bounded, Assembler, DefaultHost, ProgramInfo, ProvingOptions, StackInputs,
+        VerifierLimits,
+    };
+
+    // proofs generated with the 128-bit proven presets are accepted under the default limits
+    let program = Assembler::default().compile("begin push.1 push.2 add end").unwrap();
+    for recursive in [false, true] {
+        let (stack_outputs, proof) = prove(
+            &program,
+            StackInputs::default(),
+            DefaultHost::default(),
+            ProvingOptions::with_128bit_proven(recursive),
+        )
+        .unwrap();
+        let proof_bytes = proof.to_bytes();
+
+        let security_level = verify_bounded(
+            ProgramInfo::from(program.clone()),
+            StackInputs::default(),
+            stack_outputs,
+            &proof_bytes,
+            &VerifierLimits::default(),
+        )
+        .unwrap();
+        assert_eq!(proof.security_level(), security_level);
+    }
+}
+
+#[test]
+fn remote_proving() {
+    use miden_vm::{
+        utils::{Deserializable, Serializable},
+        verify, AdviceInputs, Assembler, LocalProver, ProgramInfo, ProgramProver, ProofRequest,
+        ProofTransport, ProvingOptions, RemoteProver, RemoteProverError, StackInputs,
+    };
+
+    /// Proves requests in the current process, optionally tampering with the returned outputs or
+    /// with the inputs the program is proven against.
+    struct InProcessTransport {
+        tamper: bool,
+        tamper_inputs: bool,
+    }
+
+    impl ProofTransport for InProcessTransport {
+        fn send(&self, request: &[u8]) -> Result<Vec<u8>, String> {
+            let mut request =
+                ProofRequest::read_from_bytes(request).map_err(|err| err.to_string())?;
+            if self.tamper_inputs {
+                request.stack_inputs = StackInputs::try_from_ints([6]).unwrap();
+            }
+            let mut response = request.prove(&LocalProver).map_err(|err| format!("{err:?}"))?;
+            if self.tamper {
+                response.stack_outputs = Default::default();
+            }
+            Ok(response.to_bytes())
+        }
+    }
+
+    let program = Assembler::default().compile("begin adv_push.1 add end").unwrap();
+    let stack_inputs = StackInputs::try_from_ints([5]).unwrap();
+    let advice_inputs = AdviceInputs::default().with_stack_values([3]).unwrap();
+
+    let prover = RemoteProver::new(InProcessTransport {
+        tamper: false,
+        tamper_inputs: false,
+    });
+    let (stack_outputs, proof) = prover
+        .prove_program(
+            &program,
+            stack_inputs.clone(),
+            advice_inputs.clone(),
+            ProvingOptions::default(),
+        )
+        .unwrap();
+    assert_eq!(stack_outputs.stack()[0].as_int(), 8);
+    let result =
+        verify(ProgramInfo::from(program.clone()), stack_inputs.clone(), stack_outputs, proof);
+    assert!(result.is_ok(), "error: {result:?}");
+
+    // outputs returned by the endpoint are checked against the outputs of local execution
+    let prover = RemoteProver::new(InProcessTransport {
+        tamper: true,
+        tamper_inputs: false,
+    });
+    let result = prover.prove_program(
+        &program,
+        stack_inputs.clone(),
+        advice_inputs,
+        ProvingOptions::default(),
+    );
+    assert!(matches!(result, Err(RemoteProverError::OutputsMismatch { .. })));
+
+    // programs which fail to execute locally are not sent to the endpoint
+    let result = prover.prove_program(
+        &program,
+        stack_inputs.clone(),
+        AdviceInputs::default(),
+        ProvingOptions::default(),
+    );
+    assert!(matches!(result, Err(RemoteProverError::PreflightFailed(_))));
+
+    // proofs returned by the endpoint are verified locally, even without preflight
+    let prover = RemoteProver::new(InProcessTransport {
+        tamper: false,
+        tamper_inputs: true,
+    })
+    .without_preflight();
+    let result = prover.prove_program(
+        &program,
+        stack_inputs,
+        AdviceInputs::default().with_stack_values([3]).unwrap(),
+        ProvingOptions::default(),
+    );
+    assert!(matches!(result, Err(RemoteProverError::InvalidProof(_))));
+}
+
+#[test]
+fn execution_witness() {
+    use miden_vm::{
+        crypto::RpoDigest,
+        execute_with_witness,
+        math::Felt,
+        prove_from_witness,
+        utils::{Deserializable, Serializable},
+        verify, AdviceInputs, Assembler, ExecutionError, ExecutionOptions, ExecutionWitness,
+        ProgramInfo, ProvingOptions, StackInputs,
+    };
+
+    let program = Assembler::default().compile("begin adv_push.2 add add end").unwrap();
+    let stack_inputs = StackInputs::try_from_ints([5]).unwrap();
+    let unused_key = RpoDigest::new([Felt::new(1); 4]);
+    let advice_inputs = AdviceInputs::default()
+        .with_stack_values([3, 4])
+        .unwrap()
+        .with_map([(unused_key, vec![Felt::new(7)])]);
+
+    let (trace, witness) = execute_with_witness(
+        &program,
+        stack_inputs.clone(),
+        advice_inputs,
+        ExecutionOptions::default(),
+    )
+    .unwrap();
+    assert_eq!(witness.stack_outputs(), trace.stack_outputs());
+    assert_eq!(witness.stack_outputs().stack()[0], Felt::new(12));
+
+    // advice which was not consumed during execution is not recorded
+    assert_eq!(witness.advice_inputs().mapped_values(&unused_key), None);
+
+    // the witness can be proven after being shipped to a different process
+    let witness = ExecutionWitness::read_from_bytes(&witness.to_bytes()).unwrap();
+    let (stack_outputs, proof) = prove_from_witness(&witness, ProvingOptions::default()).unwrap();
+    assert_eq!(&stack_outputs, witness.stack_outputs());
+    let result = verify(ProgramInfo::from(program), stack_inputs, stack_outputs, proof);
+    assert!(result.is_ok(), "error: {result:?}");
+
+    // the recorded number of cycles is untrusted, and thus, it is used to pre-allocate the trace
+    // only up to a bound
+    let mut bytes = witness.to_bytes();
+    let num_cycles_offset = bytes.len() - 4;
+    bytes[num_cycles_offset..].copy_from_slice(&(1_u32 << 31).to_le_bytes());
+    let forged_witness = ExecutionWitness::read_from_bytes(&bytes).unwrap();
+    assert_eq!(forged_witness.num_cycles(), 1 << 31);
+    let options = forged_witness.execution_options(ExecutionOptions::default());
+    assert_eq!(options.expected_cycles(), ExecutionWitness::MAX_EXPECTED_CYCLES);
+
+    // a witness which does not reproduce the recorded outputs is rejected
+    let other_program = Assembler::default().compile("begin adv_push.2 add mul end").unwrap();
+    let mut bytes = other_program.to_bytes();
+    bytes.extend_from_slice(&witness.to_bytes()[witness.program().to_bytes().len()..]);
+    let witness = ExecutionWitness::read_from_bytes(&bytes).unwrap();
+    let result = prove_from_witness(&witness, ProvingOptions::default());
+    assert!(matches!(result, Err(ExecutionError::InconsistentExecutionWitness)));
+}
+
+#[test]
+fn prover_progress() {
+    use core::cell::RefCell;
+    use miden_vm::{
+        prove_with_observer, Assembler, DefaultHost, ProgressObserver, ProvingOptions,
+        ProvingStage, StackInputs,
+    };
+
+    #[derive(Default)]
+    struct Recorder(RefCell<Vec<(ProvingStage, bool)>>);
+
+    impl ProgressObserver for Recorder {
+        fn on_stage_started(&self, stage: ProvingStage) {
+            self.0.borrow_mut().push((stage, false));
+        }
+
+        fn on_stage_completed(&self, stage: ProvingStage) {
+            self.0.borrow_mut().push((stage, true));
+        }
+    }
+
+    let program = Assembler::default().compile("begin push.3 push.4 add end").unwrap();
+    let recorder = Recorder::default();
+    prove_with_observer(
+        &program,
+        StackInputs::default(),
+        DefaultHost::default(),
+        ProvingOptions::default(),
+        &recorder,
+    )
+    .unwrap();
+
+    // every stage is started and completed in order
+    let expected = [
+        ProvingStage::Execution,
+        ProvingStage::TraceLde,
+        ProvingStage::AuxTrace,
+        ProvingStage::ConstraintEvaluation,
+        ProvingStage::ConstraintCommitment,
+        ProvingStage::Fri,
+    ]
+    .into_iter()
+    .flat_map(|stage| [(stage, false), (stage, true)])
+    .collect::<Vec<_>>();
+    assert_eq!(recorder.0.into_inner(), expected);
+    assert_eq!(ProvingStage::Fri.end_percentage(), 100);
+}
+
+#[test]
+fn mast_store() {
+    use miden_vm::{
+        execute_fast,
+        utils::{Deserializable, Serializable},
+        Assembler, CodeBlockTable, DefaultHost, ExecutionError, ExecutionOptions, Program,
+        StackInputs,
+    };
+    use std::sync::Arc;
+
+    let source = "
+        proc.foo
+            push.3 mul
+        end
+
+        begin
+            push.2 call.foo
+        end";
+    let program = Assembler::default().compile(source).unwrap();
+    let options = ExecutionOptions::default();
+    let (expected, _) =
+        execute_fast(&program, StackInputs::default(), DefaultHost::default(), options).unwrap();
+
+    // move the code of the called procedure into a store, persisting every block separately
+    let mut store = CodeBlockTable::default();
+    for (digest, block) in program.cb_table().iter() {
+        assert_eq!(digest, block.hash());
+        let bytes = block.to_bytes();
+        store.insert(Deserializable::read_from_bytes(&bytes).unwrap());
+    }
+    let program = Program::with_kernel(
+        program.root().clone(),
+        program.kernel().clone(),
+        CodeBlockTable::default(),
+    );
+
+    // without the store, the called procedure cannot be found
+    let result = execute_fast(&program, StackInputs::default(), DefaultHost::default(), options);
+    assert!(matches!(result, Err(ExecutionError::CodeBlockNotFound(_))));
+
+    // with the store, the called procedure is loaded on demand
+    let host = DefaultHost::default().with_mast_store(Arc::new(store));
+    let (outputs, _) = execute_fast(&program, StackInputs::default(), host, options).unwrap();
+    assert_eq!(expected, outputs);
+}
+
+#[test]
+fn snippet_evaluation() {
+    use miden_vm::{
+        eval_snippet, eval_snippet_with_assembler, AdviceInputs, Assembler, ExecutionError,
+        SnippetError, StackInputs, StackOutputs,
+    };
+    use stdlib::StdLibrary;
+
+    let top = |outputs: &StackOutputs, n: usize| {
+        outputs
+            .stack_truncated(n)
+            .iter()
+            .map(|value| value.as_int())
+            .collect::<Vec<_>>()
+    };
+
+    let stack_inputs = StackInputs::try_from_ints([2]).unwrap();
+    let outputs = eval_snippet("push.3 mul", stack_inputs, AdviceInputs::default()).unwrap();
+    assert_eq!(top(&outputs, 1), [6]);
+
+    // advice inputs are available to the snippet
+    let advice_inputs = AdviceInputs::default().with_stack_values([5]).unwrap();
+    let outputs = eval_snippet(
+        "adv_push.1\nif.true push.7 else push.9 end",
+        StackInputs::default(),
+        advice_inputs,
+    );
+    assert!(matches!(outputs, Err(SnippetError::Execution(_))));
+    let advice_inputs = AdviceInputs::default().with_stack_values([1]).unwrap();
+    let outputs = eval_snippet(
+        "adv_push.1\nif.true push.7 else push.9 end",
+        StackInputs::default(),
+        advice_inputs,
+    )
+    .unwrap();
+    assert_eq!(top(&outputs, 1), [7]);
+
+    // modules can be imported from the libraries of the assembler
+    let assembler = Assembler::default().with_library(&StdLibrary::default()).unwrap();
+    let outputs = eval_snippet_with_assembler(
+        &assembler,
+        "use.std::math::u64\nexec.u64::wrapping_add",
+        StackInputs::try_from_ints([0, 2, 0, 3]).unwrap(),
+        AdviceInputs::default(),
+    )
+    .unwrap();
+    assert_eq!(top(&outputs, 2), [5, 0]);
+
+    // an empty snippet leaves the stack unchanged
+    let stack_inputs = StackInputs::try_from_ints([3, 5]).unwrap();
+    let outputs = eval_snippet("", stack_inputs, AdviceInputs::default()).unwrap();
+    assert_eq!(top(&outputs, 2), [5, 3]);
+
+    // compilation and execution errors are reported separately
+    let result = eval_snippet("push.1 foo", StackInputs::default(), AdviceInputs::default());
+    assert!(matches!(result, Err(SnippetError::Assembly(_))));
+    let result = eval_snippet("push.1 assertz", StackInputs::default(), AdviceInputs::default());
+    assert!(matches!(
+        result,
+        Err(SnippetError::Execution(ExecutionError::FailedAssertion { .. }))
+    ));
+}
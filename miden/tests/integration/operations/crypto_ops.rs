@@ -1,6 +1,9 @@
 use test_utils::{
     build_expected_hash, build_expected_perm, build_op_test,
-    crypto::{init_merkle_leaf, init_merkle_store, MerkleTree, NodeIndex},
+    crypto::{
+        init_merkle_leaf, init_merkle_store, partial_merkle_tree_from_proofs, MerkleStore,
+        MerkleTree, NodeIndex,
+    },
     rand::rand_vector,
     Felt,
 };
@@ -133,6 +136,46 @@ fn mtree_get() {
     test.expect_stack(&final_stack);
 }
 
+#[test]
+fn mtree_get_from_proofs() {
+    let asm_op = "mtree_get";
+
+    // only the proofs for leaves 2 and 3 are available, as if returned by an external API
+    let (leaves, _) = init_merkle_store(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let tree = MerkleTree::new(leaves.clone()).unwrap();
+    let proofs = [2_u64, 3].map(|index| {
+        let path = tree.get_path(NodeIndex::new(tree.depth(), index).unwrap()).unwrap();
+        (index, leaves[index as usize].into(), path)
+    });
+    let partial_tree = partial_merkle_tree_from_proofs(tree.root(), proofs).unwrap();
+    let mut store = MerkleStore::new();
+    store.extend(partial_tree.inner_nodes());
+
+    let index = 3usize;
+    let stack_inputs = [
+        tree.root()[0].as_int(),
+        tree.root()[1].as_int(),
+        tree.root()[2].as_int(),
+        tree.root()[3].as_int(),
+        index as u64,
+        tree.depth() as u64,
+    ];
+
+    let final_stack = [
+        leaves[index][3].as_int(),
+        leaves[index][2].as_int(),
+        leaves[index][1].as_int(),
+        leaves[index][0].as_int(),
+        tree.root()[3].as_int(),
+        tree.root()[2].as_int(),
+        tree.root()[1].as_int(),
+        tree.root()[0].as_int(),
+    ];
+
+    let test = build_op_test!(asm_op, &stack_inputs, &[], store);
+    test.expect_stack(&final_stack);
+}
+
 #[test]
 fn mtree_verify() {
     let asm_op = "mtree_verify";
@@ -1,6 +1,8 @@
+use processor::{AdviceMap, AdviceMapNamespace, ExecutionError};
 use test_utils::{
     build_test,
     crypto::{MerkleStore, RpoDigest},
+    hash_elements,
     rand::rand_value,
     Felt,
 };
@@ -179,6 +181,37 @@ fn advice_insert_mem() {
     test.expect_stack(&[1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
+#[test]
+fn advice_insert_mem_range() {
+    let source = "begin
+    # write two words into memory
+    push.1.2.3.4 mem_storew.10 dropw
+    push.5.6.7.8 mem_storew.11 dropw
+    # => [COM, ...]
+
+    # spill the words into the advice map under their commitment
+    push.12.10 movdn.5 movdn.5
+    adv.insert_mem_range
+    # => [COM, 10, 12, ...]
+
+    # reload the words from the advice map into memory at address 20
+    adv.push_mapval
+    dropw drop drop
+    padw adv_loadw mem_storew.20 dropw
+    padw adv_loadw mem_storew.21 dropw
+    end";
+
+    let data = [1, 2, 3, 4, 5, 6, 7, 8];
+    let commitment = hash_elements(&data.map(Felt::new));
+    let stack_inputs = commitment.as_elements().iter().map(|e| e.as_int()).collect::<Vec<_>>();
+    let test = build_test!(source, &stack_inputs);
+    test.expect_stack_and_memory(&[], 20, &data);
+
+    // the commitment must match the data in memory
+    let test = build_test!(source, &[1, 2, 3, 4]);
+    assert!(matches!(test.execute(), Err(ExecutionError::MemoryCommitmentMismatch(_))));
+}
+
 #[test]
 fn advice_push_mapval() {
     // --- test simple adv.mapval ---------------------------------------------
@@ -278,6 +311,36 @@ fn advice_push_mapval() {
     test.expect_stack(&[15, 14, 13, 12, 11, 5]);
 }
 
+#[test]
+fn advice_push_mapval_ns() {
+    let source: &str = "begin
+    # stack: [NS, KEY, ...]
+
+    # load the advice stack with values from the advice map (including the number
+    # of elements) and drop the namespace and the key
+    adv.push_mapvaln_ns
+    dropw dropw
+
+    # move the values from the advice stack to the operand stack
+    adv_push.5
+
+    end";
+
+    let key = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let namespace = AdviceMapNamespace::new("test::a");
+
+    // the same key is used in another namespace, but the entries do not collide
+    let mut adv_map = AdviceMap::new();
+    adv_map.insert_u64s(namespace, key, &[u64::MAX - 1, 5]);
+    adv_map.insert_u64s(AdviceMapNamespace::new("test::b"), key, &[7]);
+
+    let mut stack_inputs = key.iter().map(|value| value.as_int()).collect::<Vec<_>>();
+    stack_inputs.extend(namespace.digest().as_elements().iter().map(|value| value.as_int()));
+
+    let test = build_test!(source, &stack_inputs, [], MerkleStore::default(), adv_map);
+    test.expect_stack(&[0, 5, u32::MAX as u64, u32::MAX as u64 - 1, 4]);
+}
+
 #[test]
 fn advice_insert_hdword() {
     // --- test hashing without domain ----------------------------------------
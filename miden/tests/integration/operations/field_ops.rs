@@ -352,6 +352,20 @@ fn exp_small_pow() {
     test.expect_stack(&[expected.as_int()]);
 }
 
+#[test]
+fn exp_imm_schedules() {
+    let build_asm_op = |param: u64| format!("exp.{param}");
+    let base = rand_value::<u64>();
+
+    // powers computed via repeated multiplication, square-and-multiply and EXPACC respectively
+    for pow in [0, 1, 2, 3, 4, 5, 6, 7, 8, 13, 1 << 20, (1 << 20) + 1, 1021, Felt::MODULUS - 1] {
+        let expected = Felt::new(base).exp(pow);
+
+        let test = build_op_test!(build_asm_op(pow), &[base]);
+        test.expect_stack(&[expected.as_int()]);
+    }
+}
+
 #[test]
 fn ilog2() {
     let asm_op = "ilog2";
@@ -369,6 +383,50 @@ fn ilog2_fail() {
         .expect_error(TestError::ExecutionError(ExecutionError::LogArgumentZero(1)));
 }
 
+#[test]
+fn range_check() {
+    // values which fit into the specified number of bits are left on the stack
+    build_op_test!("range_check.8", &[255]).expect_stack(&[255]);
+    build_op_test!("range_check.32", &[u32::MAX as u64]).expect_stack(&[u32::MAX as u64]);
+    build_op_test!("range_check.48", &[(1 << 48) - 1]).expect_stack(&[(1 << 48) - 1]);
+    build_op_test!("range_check.63", &[(1 << 63) - 1]).expect_stack(&[(1 << 63) - 1]);
+    build_op_test!("range_check.1", &[0]).expect_stack(&[0]);
+}
+
+#[test]
+fn range_check_fail() {
+    // the value has non-zero upper 32 bits
+    build_op_test!("range_check.8", &[1 << 32]).expect_error(TestError::ExecutionError(
+        ExecutionError::FailedAssertion {
+            clk: 4,
+            err_code: 0,
+            err_msg: None,
+        },
+    ));
+
+    // the lower 32 bits of the value do not fit into the specified number of bits
+    build_op_test!("range_check.8", &[256]).expect_error(TestError::ExecutionError(
+        ExecutionError::NotU32Value(Felt::new(1 << 32), Felt::ZERO),
+    ));
+
+    // the upper 32 bits of the value do not fit into the specified number of bits
+    build_op_test!("range_check.48", &[1 << 48]).expect_error(TestError::ExecutionError(
+        ExecutionError::NotU32Value(Felt::new(1 << 32), Felt::ZERO),
+    ));
+
+    build_op_test!("range_check.32", &[1 << 32]).expect_error(TestError::ExecutionError(
+        ExecutionError::NotU32Value(Felt::new(1 << 32), Felt::ZERO),
+    ));
+
+    // the number of bits must be in the range [1, 63]
+    build_op_test!("range_check.0", &[0]).expect_error(TestError::AssemblyError(
+        AssemblyError::ParsingError(String::from(
+            "malformed instruction 'range_check.0', parameter 0 is invalid: parameter value must \
+            be greater than or equal to 1 and less than or equal to 63",
+        )),
+    ));
+}
+
 // FIELD OPS BOOLEAN - MANUAL TESTS
 // ================================================================================================
 
@@ -114,3 +114,36 @@ fn adv_pipe_with_hperm() {
     let test = build_test!(source, &[], &advice_stack);
     test.expect_stack(&final_stack);
 }
+
+#[test]
+fn adv_pipe_words() {
+    let asm_op = "adv_pipe_words";
+    let advice_stack = (1..=20).collect::<Vec<u64>>();
+
+    // --- even number of words -------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[100, 4], &advice_stack);
+    test.expect_stack_and_memory(&[104], 100, &advice_stack[..16]);
+
+    // --- odd number of words --------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[100, 5], &advice_stack);
+    test.expect_stack_and_memory(&[105], 100, &advice_stack);
+
+    // --- single word ----------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[7, 1], &advice_stack);
+    test.expect_stack_and_memory(&[8], 7, &advice_stack[..4]);
+
+    // --- no words -------------------------------------------------------------------------------
+    let test = build_op_test!(asm_op, &[7, 0], &advice_stack);
+    test.expect_stack(&[7]);
+}
+
+#[test]
+fn adv_pipe_words_invalid() {
+    // attempting to read more words than the advice stack holds should throw an error
+    let test = build_op_test!("adv_pipe_words", &[0, 3], &[1, 2, 3, 4, 5, 6, 7, 8]);
+    assert!(matches!(test.execute(), Err(ExecutionError::AdviceStackReadFailed(_))));
+
+    // the end address must be a valid u32 value
+    let test = build_op_test!("adv_pipe_words", &[u32::MAX as u64, 2], &[1, 2, 3, 4, 5, 6, 7, 8]);
+    assert!(matches!(test.execute(), Err(ExecutionError::FailedAssertion { .. })));
+}
@@ -1,6 +1,6 @@
-use processor::FMP_MIN;
+use processor::{DefaultHost, ExecutionOptions, FMP_MIN};
 use test_utils::{
-    build_op_test, build_test, AdviceInputs, StackInputs, Test, Word, STACK_TOP_SIZE,
+    build_op_test, build_test, AdviceInputs, Felt, StackInputs, Test, Word, STACK_TOP_SIZE,
 };
 use vm_core::{code_blocks::CodeBlock, Operation};
 
@@ -192,3 +192,24 @@ fn clk() {
     let test = build_test!(source, &[]);
     test.expect_stack(&[3, 4, 5]);
 }
+
+// CYCLE BUDGET INSTRUCTIONS
+// ================================================================================================
+
+#[test]
+fn max_cycles() {
+    let test = build_op_test!("max_cycles");
+    test.expect_stack(&[u32::MAX as u64]);
+
+    // the clk operation of remaining_cycles is executed at the second cycle of the instruction
+    let test = build_op_test!("remaining_cycles");
+    test.expect_stack(&[u32::MAX as u64 - 2]);
+
+    let test = build_test!("begin push.1 drop remaining_cycles max_cycles end");
+    let program = test.compile().unwrap();
+    let options = ExecutionOptions::new(Some(1024), 64, false).unwrap();
+    let trace =
+        processor::execute(&program, StackInputs::default(), DefaultHost::default(), options)
+            .unwrap();
+    assert_eq!(trace.stack_outputs().stack_truncated(2), [Felt::new(1024), Felt::new(1024 - 5)]);
+}
@@ -304,6 +304,35 @@ fn cdrop() {
     test.expect_stack(&[1, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0, 0]);
 }
 
+#[test]
+fn cselect() {
+    // cselect is an alias of cdrop
+    let asm_op = "cselect";
+    let test = build_op_test!(asm_op, &[15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+    test.expect_stack(&[2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0, 0]);
+
+    let test = build_op_test!(asm_op, &[15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 1]);
+    test.expect_stack(&[1, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0, 0]);
+
+    // select the smaller of two values without branching
+    let source = "dup.1 dup.1 gt cselect";
+    let test = build_op_test!(source, &[7, 3]);
+    test.expect_stack(&[3]);
+    let test = build_op_test!(source, &[3, 7]);
+    test.expect_stack(&[3]);
+}
+
+#[test]
+fn cselectw() {
+    // cselectw is an alias of cdropw
+    let asm_op = "cselectw";
+    let test = build_op_test!(asm_op, &[15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+    test.expect_stack(&[5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 0, 0, 0, 0, 0]);
+
+    let test = build_op_test!(asm_op, &[15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 1]);
+    test.expect_stack(&[1, 2, 3, 4, 9, 10, 11, 12, 13, 14, 15, 0, 0, 0, 0, 0]);
+}
+
 #[test]
 fn cdropw() {
     let asm_op = "cdropw";
@@ -1,5 +1,5 @@
 use processor::ExecutionError;
-use test_utils::{build_op_test, TestError};
+use test_utils::{build_op_test, AdviceInputs, StackInputs, Test, TestError};
 
 // SYSTEM OPS ASSERTIONS - MANUAL TESTS
 // ================================================================================================
@@ -28,6 +28,38 @@ fn assert_with_code() {
     }));
 }
 
+#[test]
+fn assert_with_named_code() {
+    let kernel_source = "\
+        errors.100.199
+        error.ERR_NOT_ONE=101
+
+        export.check_one
+            assert.err=ERR_NOT_ONE
+        end";
+
+    let test = Test {
+        source: "begin syscall.check_one end".to_string(),
+        kernel: Some(kernel_source.to_string()),
+        stack_inputs: StackInputs::try_from_ints([0]).unwrap(),
+        advice_inputs: AdviceInputs::default(),
+        in_debug_mode: false,
+        libraries: Vec::default(),
+    };
+
+    // the error code is decoded into the name declared by the kernel module
+    match test.execute() {
+        Err(ExecutionError::FailedAssertion {
+            err_code, err_msg, ..
+        }) => {
+            assert_eq!(err_code, 101);
+            assert_eq!(err_msg.as_deref(), Some("#sys::ERR_NOT_ONE"));
+        }
+        Err(err) => panic!("expected a failed assertion, found {err:?}"),
+        Ok(_) => panic!("expected a failed assertion, but execution succeeded"),
+    }
+}
+
 #[test]
 fn assert_fail() {
     let asm_op = "assert";
@@ -69,3 +101,15 @@ fn assert_eq_fail() {
         err_msg: None,
     }));
 }
+
+#[test]
+fn assert_eq_with_code() {
+    let asm_op = "assert_eq.err=456";
+
+    let test = build_op_test!(asm_op, &[1, 1]);
+    test.expect_stack(&[]);
+
+    // only the error code is matched, regardless of the cycle at which the assertion failed
+    let test = build_op_test!(asm_op, &[2, 1]);
+    test.expect_error_code(456);
+}
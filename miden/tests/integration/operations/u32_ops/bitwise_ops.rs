@@ -482,6 +482,53 @@ fn u32cto() {
     build_op_test!(asm_op, &[4294967295]).expect_stack(&[32]);
 }
 
+#[test]
+fn u32bswap() {
+    let asm_op = "u32bswap";
+    build_op_test!(asm_op, &[0]).expect_stack(&[0]);
+    build_op_test!(asm_op, &[0x12345678]).expect_stack(&[0x78563412]);
+    build_op_test!(asm_op, &[0xff]).expect_stack(&[0xff000000]);
+    build_op_test!(asm_op, &[u32::MAX as u64]).expect_stack(&[u32::MAX as u64]);
+}
+
+#[test]
+fn u32extract() {
+    build_op_test!("u32extract.8.8", &[0x12345678]).expect_stack(&[0x56]);
+    build_op_test!("u32extract.0.4", &[0x12345678]).expect_stack(&[0x8]);
+    build_op_test!("u32extract.20.12", &[0x12345678]).expect_stack(&[0x123]);
+    build_op_test!("u32extract.0.32", &[0x12345678]).expect_stack(&[0x12345678]);
+    build_op_test!("u32extract.31.1", &[0x80000000]).expect_stack(&[1]);
+}
+
+#[test]
+fn u32insert() {
+    build_op_test!("u32insert.8.8", &[0x12345678, 0xab]).expect_stack(&[0x1234ab78]);
+    build_op_test!("u32insert.0.32", &[0x12345678, 0xabcdef01]).expect_stack(&[0xabcdef01]);
+    build_op_test!("u32insert.28.4", &[0x12345678, 0xf]).expect_stack(&[0xf2345678]);
+
+    // bits of the inserted value beyond the width of the bit field are ignored
+    build_op_test!("u32insert.4.4", &[0x12345678, 0xffa]).expect_stack(&[0x123456a8]);
+}
+
+#[test]
+fn u32fshl() {
+    // the high limb is deeper in the stack than the low limb
+    build_op_test!("u32fshl.8", &[0x12345678, 0x9abcdef0]).expect_stack(&[0x3456789a]);
+    build_op_test!("u32fshl.0", &[0x12345678, 0x9abcdef0]).expect_stack(&[0x12345678]);
+    build_op_test!("u32fshl.31", &[1, 0x80000000]).expect_stack(&[0xc0000000]);
+
+    // rotating a 64-bit value left by n < 32 bits takes two funnel shifts
+    let source = "dup.1 dup.1 u32fshl.4 movdn.2 swap u32fshl.4";
+    build_op_test!(source, &[0x12345678, 0x9abcdef0]).expect_stack(&[0xabcdef01, 0x23456789]);
+}
+
+#[test]
+fn u32fshr() {
+    build_op_test!("u32fshr.8", &[0x12345678, 0x9abcdef0]).expect_stack(&[0x789abcde]);
+    build_op_test!("u32fshr.0", &[0x12345678, 0x9abcdef0]).expect_stack(&[0x9abcdef0]);
+    build_op_test!("u32fshr.31", &[1, 0x80000000]).expect_stack(&[3]);
+}
+
 // U32 OPERATIONS TESTS - RANDOMIZED - BITWISE OPERATIONS
 // ================================================================================================
 
@@ -625,4 +672,43 @@ proptest! {
         let test = build_op_test!(asm_opcode, &[a as u64]);
         test.prop_expect_stack(&[expected as u64])?;
     }
+
+    #[test]
+    fn u32fshl_fshr_proptest(a in any::<u32>(), b in any::<u32>(), n in 0_u32..32) {
+        let value = ((a as u64) << 32) | b as u64;
+
+        let asm_opcode = format!("u32fshl.{n}");
+        let expected = (value << n) >> 32;
+        let test = build_op_test!(&asm_opcode, &[a as u64, b as u64]);
+        test.prop_expect_stack(&[expected & u32::MAX as u64])?;
+
+        let asm_opcode = format!("u32fshr.{n}");
+        let expected = value >> n;
+        let test = build_op_test!(&asm_opcode, &[a as u64, b as u64]);
+        test.prop_expect_stack(&[expected & u32::MAX as u64])?;
+    }
+
+    #[test]
+    fn u32bswap_proptest(a in any::<u32>()) {
+        let asm_opcode = "u32bswap";
+        let expected = a.swap_bytes();
+        let test = build_op_test!(asm_opcode, &[a as u64]);
+        test.prop_expect_stack(&[expected as u64])?;
+    }
+
+    #[test]
+    fn u32extract_insert_proptest(a in any::<u32>(), b in any::<u32>(), offset in 0_u32..32, width in 1_u32..=32) {
+        let width = width.min(32 - offset);
+        let mask = (((1_u64 << width) - 1) as u32) << offset;
+
+        let asm_opcode = format!("u32extract.{offset}.{width}");
+        let expected = (a & mask) >> offset;
+        let test = build_op_test!(&asm_opcode, &[a as u64]);
+        test.prop_expect_stack(&[expected as u64])?;
+
+        let asm_opcode = format!("u32insert.{offset}.{width}");
+        let expected = (a & !mask) | ((b << offset) & mask);
+        let test = build_op_test!(&asm_opcode, &[a as u64, b as u64]);
+        test.prop_expect_stack(&[expected as u64])?;
+    }
 }
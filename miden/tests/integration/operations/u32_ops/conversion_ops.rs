@@ -185,6 +185,40 @@ fn u32assertw_fail() {
     )));
 }
 
+#[test]
+fn u32assertn() {
+    // assertion passes and leaves the stack unchanged if each of the top n elements < 2^32
+    let values: Vec<u64> = (1..=16).collect();
+    let mut expected = values.clone();
+    expected.reverse();
+
+    for n in 1..=16 {
+        let asm_op = format!("u32assertn.{n}");
+        let test = build_op_test!(&asm_op, &values);
+        test.expect_stack(&expected);
+    }
+
+    // elements beyond the top n elements are not checked
+    let test = build_op_test!("u32assertn.3", &[U32_BOUND, 1, 2, 3]);
+    test.expect_stack(&[3, 2, 1, U32_BOUND]);
+}
+
+#[test]
+fn u32assertn_fail() {
+    // fails if any of the top n elements >= 2^32
+    for n in [1, 2, 3, 8, 9, 10, 15, 16] {
+        let asm_op = format!("u32assertn.{n}");
+        test_inputs_out_of_bounds(&asm_op, n);
+    }
+
+    // the error code is reported on failure
+    let test = build_op_test!("u32assertn.5.err=7", &[1, 2, U32_BOUND, 4, 5]);
+    test.expect_error(TestError::ExecutionError(ExecutionError::NotU32Value(
+        Felt::new(U32_BOUND),
+        Felt::new(7),
+    )));
+}
+
 #[test]
 fn u32cast() {
     let asm_op = "u32cast";
@@ -284,6 +318,20 @@ proptest! {
         test.prop_expect_stack(&expected)?;
 }
 
+    #[test]
+    fn u32assertn_proptest(values in prop::collection::vec(any::<u32>(), 1..=16)) {
+        let asm_op = format!("u32assertn.{}", values.len());
+
+        // should pass and leave the stack unchanged if a < 2^32 for all top n values
+        let values: Vec<u64> = values.iter().map(|a| *a as u64).collect();
+        let mut expected = values.clone();
+        // reverse the values to put the expected array in stack order
+        expected.reverse();
+
+        let test = build_op_test!(&asm_op, &values);
+        test.prop_expect_stack(&expected)?;
+    }
+
     #[test]
     fn u32cast_proptest(value in any::<u64>()) {
         let asm_op = "u32cast";
@@ -15,7 +15,7 @@ use miden_air::trace::{
     main_trace::MainTrace,
 };
 
-use vm_core::{Operation, Word, ONE, ZERO};
+use vm_core::{code_blocks::Dyn, Operation, Word, ONE, ZERO};
 
 // CONSTANTS
 // ================================================================================================
@@ -283,6 +283,9 @@ fn build_control_block_request<E: FieldElement<BaseField = Felt>>(
 }
 
 /// Builds requests made to kernel ROM chiplet when initializing a syscall block.
+///
+/// For dynamic syscalls (i.e., syscalls whose target is DYN), the requested kernel procedure is
+/// the one whose MAST root is on top of the stack.
 fn build_syscall_block_request<E: FieldElement<BaseField = Felt>>(
     main_trace: &MainTrace,
     op_code_felt: Felt,
@@ -292,13 +295,16 @@ fn build_syscall_block_request<E: FieldElement<BaseField = Felt>>(
     let factor1 = build_control_block_request(main_trace, op_code_felt, alphas, row);
 
     let op_label = KERNEL_PROC_LABEL;
-    let state = main_trace.decoder_hasher_state(row);
+    let mut proc_hash = main_trace.decoder_hasher_state_first_half(row);
+    if proc_hash == Word::from(Dyn::dyn_hash()) {
+        proc_hash = [3, 2, 1, 0].map(|column| main_trace.stack_element(column, row));
+    }
     let factor2 = alphas[0]
         + alphas[1].mul_base(op_label)
-        + alphas[2].mul_base(state[0])
-        + alphas[3].mul_base(state[1])
-        + alphas[4].mul_base(state[2])
-        + alphas[5].mul_base(state[3]);
+        + alphas[2].mul_base(proc_hash[0])
+        + alphas[3].mul_base(proc_hash[1])
+        + alphas[4].mul_base(proc_hash[2])
+        + alphas[5].mul_base(proc_hash[3]);
 
     factor1 * factor2
 }
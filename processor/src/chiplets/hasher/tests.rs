@@ -75,6 +75,24 @@ fn hasher_permute() {
     check_hasher_state_trace(&trace, 0, init_state1);
     check_hasher_state_trace(&trace, 8, init_state2);
     assert_eq!(trace.last().unwrap(), &[ZERO; 16]);
+
+    // --- test many permutations ---------------------------------------------
+
+    // the number of permutations is large enough for the trace to be filled in several segments
+    let mut hasher = Hasher::default();
+    let init_states: Vec<HasherState> = (0..600).map(|_| rand_array()).collect();
+    for (i, &init_state) in init_states.iter().enumerate() {
+        let (addr, final_state) = hasher.permute(init_state);
+        assert_eq!(Felt::new((i * HASH_CYCLE_LEN + 1) as u64), addr);
+        assert_eq!(apply_permutation(init_state), final_state);
+    }
+
+    // build the trace and make sure all permutation cycles are in order
+    let trace = build_trace(hasher, init_states.len() * HASH_CYCLE_LEN);
+    for (i, &init_state) in init_states.iter().enumerate() {
+        check_selector_trace(&trace, i * HASH_CYCLE_LEN, LINEAR_HASH, RETURN_STATE);
+        check_hasher_state_trace(&trace, i * HASH_CYCLE_LEN, init_state);
+    }
 }
 
 // MERKLE TREE TESTS
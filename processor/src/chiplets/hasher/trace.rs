@@ -1,8 +1,18 @@
 use super::{Felt, HasherState, Selectors, TraceFragment, STATE_WIDTH, TRACE_WIDTH, ZERO};
 use alloc::vec::Vec;
 use core::ops::Range;
-use miden_air::trace::chiplets::hasher::NUM_ROUNDS;
-use vm_core::chiplets::hasher::apply_round;
+use miden_air::trace::chiplets::hasher::{HASH_CYCLE_LEN, NUM_ROUNDS, NUM_SELECTORS};
+use vm_core::chiplets::hasher::{apply_permutation, apply_round};
+
+#[cfg(feature = "concurrent")]
+use rayon::prelude::*;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of permutation cycles expanded into the execution trace by a single task when the trace
+/// is generated in parallel.
+const CYCLES_PER_TASK: usize = 256;
 
 // HASHER TRACE
 // ================================================================================================
@@ -13,11 +23,15 @@ use vm_core::chiplets::hasher::apply_round;
 /// - 3 selector columns.
 /// - 12 columns describing hasher state.
 /// - 1 node index column used for Merkle path related computations.
+///
+/// Every 8 rows of the trace describe a single permutation of the hash function. Since the rows of
+/// a permutation cycle are fully determined by the initial state of the hasher, the selectors, and
+/// the node index values, the trace keeps only these values for every cycle while the VM executes.
+/// The rows of all cycles are expanded when the trace is filled, which is done in parallel when
+/// the `concurrent` feature is enabled.
 #[derive(Default)]
 pub struct HasherTrace {
-    selectors: [Vec<Felt>; 3],
-    hasher_state: [Vec<Felt>; STATE_WIDTH],
-    node_index: Vec<Felt>,
+    cycles: Vec<PermutationCycle>,
 }
 
 impl HasherTrace {
@@ -26,7 +40,7 @@ impl HasherTrace {
 
     /// Returns current length of this execution trace.
     pub fn trace_len(&self) -> usize {
-        self.selectors[0].len()
+        self.cycles.len() * HASH_CYCLE_LEN
     }
 
     /// Returns the next row address. The address is equal to the current trace length + 1.
@@ -43,8 +57,9 @@ impl HasherTrace {
 
     /// Appends 8 rows to the execution trace describing a single permutation of the hash function.
     ///
-    /// The initial state of the hasher is provided via the `state` parameter. All subsequent
-    /// states are derived by applying a single round of the hash function to the previous state.
+    /// The initial state of the hasher is provided via the `state` parameter, and is updated to
+    /// the result of the permutation. All intermediate states are derived by applying a single
+    /// round of the hash function to the previous state when the trace is filled.
     ///
     /// Selector values for the first and last rows are provided via `init_selectors` and
     /// `final_selectors` parameters. Selector values for all other rows are derived from the
@@ -60,22 +75,17 @@ impl HasherTrace {
         init_index: Felt,
         rest_index: Felt,
     ) {
-        // append the first row of the permutation cycle
-        self.append_row(init_selectors, state, init_index);
-
-        // append the next 6 rows of the permutation cycle. for these rows:
-        // - the last two selectors are carried over from row to row; the first selector is set
-        //   to ZERO.
-        // - hasher state is updated by applying a single round of the hash function for every row.
-        let next_selectors = [ZERO, init_selectors[1], init_selectors[2]];
-        for i in 0..NUM_ROUNDS - 1 {
-            apply_round(state, i);
-            self.append_row(next_selectors, state, rest_index);
-        }
-
-        // apply the last round and append the last row to the trace
-        apply_round(state, NUM_ROUNDS - 1);
-        self.append_row(final_selectors, state, rest_index);
+        let init_state = *state;
+        apply_permutation(state);
+
+        self.cycles.push(PermutationCycle {
+            init_state,
+            final_state: *state,
+            init_selectors,
+            final_selectors,
+            init_index,
+            rest_index,
+        });
     }
 
     /// Appends 8 rows to the execution trace describing a single permutation of the hash function.
@@ -92,56 +102,125 @@ impl HasherTrace {
         self.append_permutation_with_index(state, init_selectors, final_selectors, ZERO, ZERO);
     }
 
-    /// Appends a new row to the execution trace based on the supplied parameters.
-    fn append_row(&mut self, selectors: Selectors, state: &HasherState, index: Felt) {
-        for (trace_col, selector_val) in self.selectors.iter_mut().zip(selectors) {
-            trace_col.push(selector_val);
-        }
-        for (trace_col, &state_val) in self.hasher_state.iter_mut().zip(state) {
-            trace_col.push(state_val);
-        }
-        self.node_index.push(index);
-    }
-
     /// Copies section of trace from the given range of start and end rows at the end of the trace.
     /// The hasher state of the last row is copied to the provided state input.
+    ///
+    /// # Panics
+    /// Panics if the range does not consist of whole permutation cycles.
     pub fn copy_trace(&mut self, state: &mut [Felt; STATE_WIDTH], range: Range<usize>) {
-        for selector in self.selectors.iter_mut() {
-            selector.extend_from_within(range.clone());
-        }
-
-        for hasher in self.hasher_state.iter_mut() {
-            hasher.extend_from_within(range.clone());
-        }
-
-        self.node_index.extend_from_within(range.clone());
+        assert!(
+            range.start % HASH_CYCLE_LEN == 0 && range.end % HASH_CYCLE_LEN == 0,
+            "range does not consist of whole permutation cycles"
+        );
+        let cycles = range.start / HASH_CYCLE_LEN..range.end / HASH_CYCLE_LEN;
+        self.cycles.extend_from_within(cycles);
 
         // copy the latest hasher state to the provided state slice
-        for (col, hasher) in self.hasher_state.iter().enumerate() {
-            state[col] = hasher[range.end - 1];
-        }
+        *state = self.cycles.last().expect("no permutation cycles copied").final_state;
     }
 
     // EXECUTION TRACE GENERATION
     // --------------------------------------------------------------------------------------------
 
     /// Fills the provided trace fragment with trace data from this hasher trace instance.
+    ///
+    /// The fragment is split into row ranges spanning a fixed number of permutation cycles, and
+    /// when the `concurrent` feature is enabled, the rows of the different ranges are computed in
+    /// parallel.
     pub fn fill_trace(self, trace: &mut TraceFragment) {
         // make sure fragment dimensions are consistent with the dimensions of this trace
         debug_assert_eq!(self.trace_len(), trace.len(), "inconsistent trace lengths");
         debug_assert_eq!(TRACE_WIDTH, trace.width(), "inconsistent trace widths");
 
-        // collect all trace columns into a single vector
-        let mut columns = Vec::new();
-        self.selectors.into_iter().for_each(|c| columns.push(c));
+        // split every column of the fragment into segments covering the rows of CYCLES_PER_TASK
+        // permutation cycles, and group the segments at the same position across all columns
+        let segment_len = CYCLES_PER_TASK * HASH_CYCLE_LEN;
+        let num_segments = self.cycles.len().div_ceil(CYCLES_PER_TASK);
+        let mut segments: Vec<Vec<&mut [Felt]>> =
+            (0..num_segments).map(|_| Vec::with_capacity(TRACE_WIDTH)).collect();
+        for column in trace.columns() {
+            for (segment, column_segment) in segments.iter_mut().zip(column.chunks_mut(segment_len))
+            {
+                segment.push(column_segment);
+            }
+        }
+
+        #[cfg(feature = "concurrent")]
+        let tasks = segments.par_iter_mut().zip(self.cycles.par_chunks(CYCLES_PER_TASK));
+        #[cfg(not(feature = "concurrent"))]
+        let tasks = segments.iter_mut().zip(self.cycles.chunks(CYCLES_PER_TASK));
 
-        self.hasher_state.into_iter().for_each(|c| columns.push(c));
-        columns.push(self.node_index);
+        tasks.for_each(|(segment, cycles)| {
+            for (i, cycle) in cycles.iter().enumerate() {
+                cycle.fill_rows(segment, i * HASH_CYCLE_LEN);
+            }
+        });
+    }
+}
 
-        // copy trace into the fragment column-by-column
-        // TODO: this can be parallelized to copy columns in multiple threads
-        for (out_column, column) in trace.columns().zip(columns) {
-            out_column.copy_from_slice(&column);
+// PERMUTATION CYCLE
+// ================================================================================================
+
+/// Values from which the 8 rows describing a single permutation of the hash function are built.
+#[derive(Debug, Clone, Copy)]
+struct PermutationCycle {
+    init_state: HasherState,
+    final_state: HasherState,
+    init_selectors: Selectors,
+    final_selectors: Selectors,
+    init_index: Felt,
+    rest_index: Felt,
+}
+
+impl PermutationCycle {
+    /// Writes the rows of this permutation cycle into the provided columns, starting at the
+    /// specified row.
+    fn fill_rows(&self, columns: &mut [&mut [Felt]], start_row: usize) {
+        // write the first row of the permutation cycle
+        let mut state = self.init_state;
+        write_row(columns, start_row, &self.init_selectors, &state, self.init_index);
+
+        // write the next 6 rows of the permutation cycle. for these rows:
+        // - the last two selectors are carried over from row to row; the first selector is set
+        //   to ZERO.
+        // - hasher state is updated by applying a single round of the hash function for every row.
+        let next_selectors = [ZERO, self.init_selectors[1], self.init_selectors[2]];
+        for i in 0..NUM_ROUNDS - 1 {
+            apply_round(&mut state, i);
+            write_row(columns, start_row + i + 1, &next_selectors, &state, self.rest_index);
         }
+
+        // the last round was applied when the permutation was appended; write the last row
+        write_row(
+            columns,
+            start_row + NUM_ROUNDS,
+            &self.final_selectors,
+            &self.final_state,
+            self.rest_index,
+        );
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Writes a single row of the execution trace into the provided columns.
+#[inline(always)]
+fn write_row(
+    columns: &mut [&mut [Felt]],
+    row: usize,
+    selectors: &Selectors,
+    state: &HasherState,
+    index: Felt,
+) {
+    let (selector_columns, rest) = columns.split_at_mut(NUM_SELECTORS);
+    let (state_columns, index_column) = rest.split_at_mut(STATE_WIDTH);
+
+    for (column, &value) in selector_columns.iter_mut().zip(selectors) {
+        column[row] = value;
+    }
+    for (column, &value) in state_columns.iter_mut().zip(state) {
+        column[row] = value;
     }
+    index_column[0][row] = index;
 }
@@ -1,12 +1,13 @@
 use super::{
     utils::{split_element_u32_into_u16, split_u32_into_u16},
-    Felt, FieldElement, RangeChecker, TraceFragment, Word, EMPTY_WORD, ONE,
+    Felt, RangeChecker, TraceFragment, Word, EMPTY_WORD, ONE,
 };
 use crate::system::ContextId;
 use alloc::{collections::BTreeMap, vec::Vec};
 use miden_air::trace::chiplets::memory::{
     ADDR_COL_IDX, CLK_COL_IDX, CTX_COL_IDX, D0_COL_IDX, D1_COL_IDX, D_INV_COL_IDX, V_COL_RANGE,
 };
+use vm_core::batch;
 
 mod segment;
 use segment::MemorySegmentTrace;
@@ -124,6 +125,25 @@ impl Memory {
         }
     }
 
+    /// Returns the memory addresses across all execution contexts whose values at the specified
+    /// cycles differ, as a vector of (context, address, value at `start_clk`, value at `end_clk`)
+    /// tuples.
+    pub fn get_state_diff(
+        &self,
+        start_clk: u32,
+        end_clk: u32,
+    ) -> Vec<(ContextId, u64, Word, Word)> {
+        self.trace
+            .iter()
+            .flat_map(|(&ctx, segment)| {
+                segment
+                    .get_state_diff(start_clk, end_clk)
+                    .into_iter()
+                    .map(move |(addr, old_value, new_value)| (ctx, addr, old_value, new_value))
+            })
+            .collect()
+    }
+
     // STATE ACCESSORS AND MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -236,8 +256,8 @@ impl Memory {
                     let (delta_hi, delta_lo) = split_element_u32_into_u16(delta);
                     trace.set(row, D0_COL_IDX, delta_lo);
                     trace.set(row, D1_COL_IDX, delta_hi);
-                    // TODO: switch to batch inversion to improve efficiency.
-                    trace.set(row, D_INV_COL_IDX, delta.inv());
+                    // the delta is replaced with its inverse once all rows have been filled
+                    trace.set(row, D_INV_COL_IDX, delta);
 
                     // update values for the next iteration of the loop
                     prev_ctx = ctx;
@@ -247,6 +267,9 @@ impl Memory {
                 }
             }
         }
+
+        // compute the inverses of the deltas using batch inversion
+        batch::inv_in_place(trace.column_mut(D_INV_COL_IDX));
     }
 
     // HELPER METHODS
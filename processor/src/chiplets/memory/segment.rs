@@ -62,6 +62,22 @@ impl MemorySegmentTrace {
         result
     }
 
+    /// Returns the addresses whose values at the beginning of the specified cycles differ, as a
+    /// vector of (address, value at `start_clk`, value at `end_clk`) tuples.
+    ///
+    /// Addresses which have not been accessed before a cycle are treated as holding the initial
+    /// memory value at that cycle.
+    pub fn get_state_diff(&self, start_clk: u32, end_clk: u32) -> Vec<(u64, Word, Word)> {
+        self.0
+            .iter()
+            .filter_map(|(&addr, addr_trace)| {
+                let old_value = get_value_at(addr_trace, start_clk);
+                let new_value = get_value_at(addr_trace, end_clk);
+                (old_value != new_value).then_some((addr.into(), old_value, new_value))
+            })
+            .collect()
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -126,6 +142,24 @@ impl MemorySegmentTrace {
     }
 }
 
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the value in the specified address trace at the beginning of the specified cycle, or
+/// the initial memory value if the address had not been accessed before that cycle.
+fn get_value_at(addr_trace: &[MemorySegmentAccess], clk: u32) -> Word {
+    // memory state is recorded at the end of a cycle, and thus, the state at the beginning of a
+    // cycle is the state at the end of the previous cycle
+    if clk == 0 {
+        return INIT_MEM_VALUE;
+    }
+    let search_clk = (clk - 1) as u64;
+    match addr_trace.partition_point(|access| access.clk().as_int() <= search_clk) {
+        0 => INIT_MEM_VALUE,
+        i => addr_trace[i - 1].value(),
+    }
+}
+
 // MEMORY ACCESS
 // ================================================================================================
 
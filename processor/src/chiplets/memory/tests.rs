@@ -1,6 +1,7 @@
 use super::{
-    super::ZERO, Felt, FieldElement, Memory, TraceFragment, ADDR_COL_IDX, CLK_COL_IDX, CTX_COL_IDX,
-    D0_COL_IDX, D1_COL_IDX, D_INV_COL_IDX, EMPTY_WORD, ONE, V_COL_RANGE,
+    super::{FieldElement, ZERO},
+    Felt, Memory, TraceFragment, ADDR_COL_IDX, CLK_COL_IDX, CTX_COL_IDX, D0_COL_IDX, D1_COL_IDX,
+    D_INV_COL_IDX, EMPTY_WORD, ONE, V_COL_RANGE,
 };
 use crate::ContextId;
 use alloc::vec::Vec;
@@ -293,6 +294,36 @@ fn mem_get_state_at() {
     assert_eq!(mem.get_state_at(3.into(), 5), vec![(3, value7)]);
 }
 
+#[test]
+fn mem_get_state_diff() {
+    let mut mem = Memory::default();
+
+    // write 1 into (ctx = 0, addr = 5) at clk = 1, and overwrite it with 2 at clk = 3
+    let value1 = [ONE, ZERO, ZERO, ZERO];
+    let value2 = [Felt::new(2), ZERO, ZERO, ZERO];
+    mem.write(ContextId::root(), 5, 1, value1);
+    mem.write(ContextId::root(), 5, 3, value2);
+
+    // read (ctx = 0, addr = 2) at clk = 2; this does not change its value
+    mem.read(ContextId::root(), 2, 2);
+
+    // write 7 into (ctx = 3, addr = 3) at clk = 4
+    let value7 = [Felt::new(7), ZERO, ZERO, ZERO];
+    mem.write(3.into(), 3, 4, value7);
+
+    assert_eq!(mem.get_state_diff(0, 2), vec![(ContextId::root(), 5, EMPTY_WORD, value1)]);
+    assert_eq!(mem.get_state_diff(2, 4), vec![(ContextId::root(), 5, value1, value2)]);
+    assert_eq!(
+        mem.get_state_diff(2, 5),
+        vec![(ContextId::root(), 5, value1, value2), (3.into(), 3, EMPTY_WORD, value7)]
+    );
+    assert_eq!(
+        mem.get_state_diff(5, 2),
+        vec![(ContextId::root(), 5, value2, value1), (3.into(), 3, value7, EMPTY_WORD)]
+    );
+    assert_eq!(mem.get_state_diff(4, 4), vec![]);
+}
+
 // HELPER STRUCT & FUNCTIONS
 // ================================================================================================
 
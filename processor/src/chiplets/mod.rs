@@ -2,7 +2,7 @@ use crate::system::ContextId;
 
 use super::{
     crypto::MerklePath, utils, ChipletsTrace, ExecutionError, Felt, FieldElement, RangeChecker,
-    TraceFragment, Word, CHIPLETS_WIDTH, EMPTY_WORD, ONE, ZERO,
+    TraceBufferPool, TraceFragment, Word, CHIPLETS_WIDTH, EMPTY_WORD, ONE, ZERO,
 };
 use alloc::vec::Vec;
 use miden_air::trace::chiplets::hasher::{Digest, HasherState};
@@ -346,6 +346,17 @@ impl Chiplets {
         self.memory.get_state_at(ctx, clk)
     }
 
+    /// Returns the memory addresses across all execution contexts whose values at the specified
+    /// cycles differ, as a vector of (context, address, value at `start_clk`, value at `end_clk`)
+    /// tuples.
+    pub fn get_mem_state_diff(
+        &self,
+        start_clk: u32,
+        end_clk: u32,
+    ) -> Vec<(ContextId, u64, Word, Word)> {
+        self.memory.get_state_diff(start_clk, end_clk)
+    }
+
     /// Returns current size of the memory (in words) across all execution contexts.
     #[cfg(test)]
     pub fn get_mem_size(&self) -> usize {
@@ -394,7 +405,7 @@ impl Chiplets {
 
         // Allocate columns for the trace of the chiplets.
         let mut trace = (0..CHIPLETS_WIDTH)
-            .map(|_| Felt::zeroed_vector(trace_len))
+            .map(|_| TraceBufferPool::zeroed_column(trace_len))
             .collect::<Vec<_>>()
             .try_into()
             .expect("failed to convert vector to array");
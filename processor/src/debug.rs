@@ -4,8 +4,8 @@ use crate::{
 };
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use core::fmt;
-use vm_core::{AssemblyOp, Operation, StackOutputs, Word};
+use core::{fmt, ops::Range};
+use vm_core::{AssemblyOp, Operation, StackOutputs, Word, ZERO};
 
 /// VmState holds a current process state information at a specific clock cycle.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -180,6 +180,38 @@ impl VmStateIterator {
         &self.trace_len_summary
     }
 
+    /// Returns the maximum depth the stack reached during the execution.
+    pub fn max_stack_depth(&self) -> usize {
+        self.stack.max_depth()
+    }
+
+    /// Returns the memory addresses across all execution contexts whose contents differ between
+    /// the beginning of the specified clock cycles, sorted by context and then by address.
+    ///
+    /// Cycles beyond the end of the execution are treated as the last cycle of the execution.
+    pub fn memory_diff(&self, start_clk: u32, end_clk: u32) -> Vec<MemoryDiff> {
+        self.chiplets
+            .get_mem_state_diff(start_clk, end_clk)
+            .into_iter()
+            .map(|(ctx, addr, old_value, new_value)| MemoryDiff {
+                ctx,
+                addr,
+                old_value,
+                new_value,
+            })
+            .collect()
+    }
+
+    /// Returns an iterator which yields only the states at which at least one of the specified
+    /// watchpoints was triggered, starting from the current state of this iterator.
+    pub fn watch(self, watchpoints: Vec<Watchpoint>) -> WatchedStateIterator {
+        WatchedStateIterator {
+            states: self,
+            watchpoints,
+            prev_state: None,
+        }
+    }
+
     /// Returns an instance of [TraceLenSummary] based on provided data.
     fn build_trace_len_summary(
         system: &System,
@@ -243,8 +275,148 @@ impl Iterator for VmStateIterator {
     }
 }
 
+// MEMORY DIFF
+// ================================================================================================
+
+/// A memory address whose contents differ between two clock cycles.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemoryDiff {
+    pub ctx: ContextId,
+    pub addr: u64,
+    /// Contents of the address at the first of the compared cycles.
+    pub old_value: Word,
+    /// Contents of the address at the second of the compared cycles.
+    pub new_value: Word,
+}
+
+impl fmt::Display for MemoryDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ctx={}, addr={}: {:?} -> {:?}",
+            self.ctx,
+            self.addr,
+            word_to_ints(&self.old_value),
+            word_to_ints(&self.new_value)
+        )
+    }
+}
+
+// WATCHPOINTS
+// ================================================================================================
+
+/// A part of the VM state which is compared between consecutive clock cycles.
+///
+/// A watchpoint is triggered when the watched part of the state differs between two consecutive
+/// states. Watchpoints make it possible to inspect long executions without looking at the full VM
+/// state at every cycle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Watchpoint {
+    /// Memory addresses in the specified range of the context in which the VM is executing.
+    /// Addresses which have not been accessed yet are treated as holding zeros.
+    Memory(Range<u64>),
+    /// The item at the specified position of the operand stack, where position 0 is the top of
+    /// the stack.
+    Stack(usize),
+}
+
+impl Watchpoint {
+    /// Returns true if the watched part of the state differs between the specified states.
+    pub fn is_triggered(&self, prev: &VmState, next: &VmState) -> bool {
+        match self {
+            Self::Memory(range) => watched_memory(prev, range).ne(watched_memory(next, range)),
+            Self::Stack(pos) => prev.stack.get(*pos) != next.stack.get(*pos),
+        }
+    }
+}
+
+impl fmt::Display for Watchpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Memory(range) => write!(f, "memory[{}..{}]", range.start, range.end),
+            Self::Stack(pos) => write!(f, "stack[{pos}]"),
+        }
+    }
+}
+
+/// A VM state at which at least one watchpoint was triggered.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WatchpointHit {
+    /// The state at which the watchpoints were triggered.
+    pub state: VmState,
+    /// Indexes of the triggered watchpoints in the list of watched watchpoints.
+    pub watchpoints: Vec<usize>,
+}
+
+/// Iterator that iterates through the VM states at which at least one of the watched watchpoints
+/// was triggered, skipping all other states.
+///
+/// If the execution returned an error, it returns that error after the last state.
+pub struct WatchedStateIterator {
+    states: VmStateIterator,
+    watchpoints: Vec<Watchpoint>,
+    prev_state: Option<VmState>,
+}
+
+impl WatchedStateIterator {
+    /// Returns the watched watchpoints.
+    pub fn watchpoints(&self) -> &[Watchpoint] {
+        &self.watchpoints
+    }
+
+    /// Returns the underlying [VmStateIterator].
+    pub fn into_inner(self) -> VmStateIterator {
+        self.states
+    }
+}
+
+impl Iterator for WatchedStateIterator {
+    type Item = Result<WatchpointHit, ExecutionError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let state = match self.states.next()? {
+                Ok(state) => state,
+                Err(err) => return Some(Err(err)),
+            };
+
+            let triggered = match &self.prev_state {
+                Some(prev_state) => self
+                    .watchpoints
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, watchpoint)| watchpoint.is_triggered(prev_state, &state))
+                    .map(|(idx, _)| idx)
+                    .collect(),
+                None => Vec::new(),
+            };
+            self.prev_state = Some(state);
+
+            if !triggered.is_empty() {
+                let state = self.prev_state.clone().expect("no previous state");
+                return Some(Ok(WatchpointHit {
+                    state,
+                    watchpoints: triggered,
+                }));
+            }
+        }
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
+
+/// Returns the non-zero memory entries of the specified state within the specified address range.
+fn watched_memory<'a>(
+    state: &'a VmState,
+    range: &'a Range<u64>,
+) -> impl Iterator<Item = &'a (u64, Word)> {
+    state
+        .memory
+        .iter()
+        .filter(move |(addr, value)| range.contains(addr) && *value != [ZERO; 4])
+}
+
 fn word_to_ints(word: &Word) -> [u64; 4] {
     [word[0].as_int(), word[1].as_int(), word[2].as_int(), word[3].as_int()]
 }
@@ -18,7 +18,9 @@ use std::error::Error;
 pub enum ExecutionError {
     AdviceMapKeyNotFound(Word),
     AdviceStackReadFailed(u32),
+    AdviceStorageFailed(String),
     CallerNotInSyscall,
+    ChipletNotInProfile(&'static str, u32),
     CodeBlockNotFound(Digest),
     CycleLimitExceeded(u32),
     DivideByZero(u32),
@@ -31,6 +33,8 @@ pub enum ExecutionError {
         err_msg: Option<String>,
     },
     FailedSignatureGeneration(&'static str),
+    InconsistentExecutionWitness,
+    InvalidExecutionOptions(String),
     InvalidFmpValue(Felt, Felt),
     InvalidFriDomainSegment(u64),
     InvalidFriLayerFolding(QuadFelt, QuadFelt),
@@ -47,9 +51,15 @@ pub enum ExecutionError {
         depth: Felt,
         value: Felt,
     },
+    InvalidVmSnapshot(String),
     LogArgumentZero(u32),
     MalformedSignatureKey(&'static str),
     MemoryAddressOutOfBounds(u64),
+    MemoryCommitmentMismatch(Word),
+    MemoryOutputsTooLarge {
+        num_words: u64,
+        max_words: usize,
+    },
     MerklePathVerificationFailed {
         value: Word,
         index: Felt,
@@ -61,6 +71,12 @@ pub enum ExecutionError {
     NotBinaryValue(Felt),
     NotU32Value(Felt, Felt),
     ProverError(ProverError),
+    ProverMemoryBudgetExceeded {
+        required: usize,
+        budget: usize,
+    },
+    SignedDataMismatch(Word),
+    SigningKeyNotFound(Word),
     SmtNodeNotFound(Word),
     SmtNodePreImageNotValid(Word, usize),
     SyscallTargetNotInKernel(Digest),
@@ -77,9 +93,16 @@ impl Display for ExecutionError {
                 write!(f, "Value for key {hex} not present in the advice map")
             }
             AdviceStackReadFailed(step) => write!(f, "Advice stack read failed at step {step}"),
+            AdviceStorageFailed(err) => write!(f, "Advice provider storage backend failed: {err}"),
             CallerNotInSyscall => {
                 write!(f, "Instruction `caller` used outside of kernel context")
             }
+            ChipletNotInProfile(chiplet, clk) => {
+                write!(
+                    f,
+                    "Operation at clock cycle {clk} requires the {chiplet} chiplet which is not included into the VM profile"
+                )
+            }
             CodeBlockNotFound(digest) => {
                 let hex = to_hex(&digest.as_bytes())?;
                 write!(
@@ -117,6 +140,13 @@ impl Display for ExecutionError {
             FailedSignatureGeneration(signature) => {
                 write!(f, "Failed to generate signature: {signature}")
             }
+            InconsistentExecutionWitness => {
+                write!(
+                    f,
+                    "Re-execution of the witness produced outputs different from the recorded ones"
+                )
+            }
+            InvalidExecutionOptions(err) => write!(f, "Invalid execution options: {err}"),
             InvalidFmpValue(old, new) => {
                 write!(f, "Updating FMP register from {old} to {new} failed because {new} is outside of {FMP_MIN}..{FMP_MAX}")
             }
@@ -144,6 +174,7 @@ impl Display for ExecutionError {
             InvalidTreeNodeIndex { depth, value } => {
                 write!(f, "The provided index {value} is out of bounds for a node at depth {depth}")
             }
+            InvalidVmSnapshot(err) => write!(f, "Failed to restore the VM snapshot: {err}"),
             LogArgumentZero(clk) => {
                 write!(
                     f,
@@ -154,6 +185,16 @@ impl Display for ExecutionError {
             MemoryAddressOutOfBounds(addr) => {
                 write!(f, "Memory address cannot exceed 2^32 but was {addr}")
             }
+            MemoryCommitmentMismatch(commitment) => {
+                let hex = to_hex(Felt::elements_as_bytes(commitment))?;
+                write!(f, "Commitment {hex} does not match the data in the specified memory range")
+            }
+            MemoryOutputsTooLarge {
+                num_words,
+                max_words,
+            } => {
+                write!(f, "Memory output ranges span {num_words} words, but at most {max_words} words can be exported")
+            }
             MerklePathVerificationFailed { value, index, root } => {
                 let value = to_hex(Felt::elements_as_bytes(value))?;
                 let root = to_hex(&root.as_bytes())?;
@@ -177,6 +218,17 @@ impl Display for ExecutionError {
                     "An operation expected a u32 value, but received {v} (error code: {err_code})"
                 )
             }
+            SignedDataMismatch(commitment) => {
+                let hex = to_hex(Felt::elements_as_bytes(commitment))?;
+                write!(f, "Signature request failed: {hex} is not the commitment to the data in the specified memory range")
+            }
+            SigningKeyNotFound(pub_key) => {
+                let hex = to_hex(Felt::elements_as_bytes(pub_key))?;
+                write!(
+                    f,
+                    "Signature request failed: the host has no secret key for public key {hex}"
+                )
+            }
             SmtNodeNotFound(node) => {
                 let node_hex = to_hex(Felt::elements_as_bytes(node))?;
                 write!(f, "Smt node {node_hex} not found")
@@ -186,6 +238,12 @@ impl Display for ExecutionError {
                 write!(f, "Invalid pre-image for node {node_hex}. Expected pre-image length to be a multiple of 8, but was {preimage_len}")
             }
             ProverError(error) => write!(f, "Proof generation failed: {error}"),
+            ProverMemoryBudgetExceeded { required, budget } => {
+                write!(
+                    f,
+                    "Proof generation is estimated to require {required} bytes of memory, which exceeds the budget of {budget} bytes"
+                )
+            }
             SyscallTargetNotInKernel(proc) => {
                 let hex = to_hex(&proc.as_bytes())?;
                 write!(f, "Syscall failed: procedure with root {hex} was not found in the kernel")
@@ -1,5 +1,5 @@
 use super::super::{AdviceProvider, ExecutionError, Felt, HostResponse};
-use crate::ProcessState;
+use crate::{ProcessState, Word};
 use alloc::vec::Vec;
 use vm_core::{
     crypto::hash::{Rpo256, RpoDigest},
@@ -31,14 +31,7 @@ pub(crate) fn insert_mem_values_into_adv_map<S: ProcessState, A: AdviceProvider>
     advice_provider: &mut A,
     process: &S,
 ) -> Result<HostResponse, ExecutionError> {
-    let (start_addr, end_addr) = get_mem_addr_range(process, 4, 5)?;
-    let ctx = process.ctx();
-
-    let mut values = Vec::with_capacity(((end_addr - start_addr) as usize) * WORD_SIZE);
-    for addr in start_addr..end_addr {
-        let mem_value = process.get_mem_value(ctx, addr).unwrap_or(EMPTY_WORD);
-        values.extend_from_slice(&mem_value);
-    }
+    let values = read_mem_range(process, 4, 5)?;
 
     let key = process.get_stack_word(0);
     advice_provider.insert_into_map(key, values)?;
@@ -46,6 +39,42 @@ pub(crate) fn insert_mem_values_into_adv_map<S: ProcessState, A: AdviceProvider>
     Ok(HostResponse::None)
 }
 
+/// Reads words from memory at the specified range and inserts them into the advice map under
+/// the commitment to these words located at the top of the stack.
+///
+/// Inputs:
+///   Operand stack: [COM, start_addr, end_addr, ...]
+///   Advice map: {...}
+///
+/// Outputs:
+///   Operand stack: [COM, start_addr, end_addr, ...]
+///   Advice map: {COM: values}
+///
+/// Where `values` are the elements located in memory[start_addr..end_addr]. Since the values are
+/// checked against COM, they can be moved back into memory later on (e.g., via
+/// `std::mem::pipe_preimage_to_memory`) at the cost of hashing them.
+///
+/// # Errors
+/// Returns an error:
+/// - `start_addr` is greater than or equal to 2^32.
+/// - `end_addr` is greater than or equal to 2^32.
+/// - `start_addr` > `end_addr`.
+/// - COM is not the sequential RPO hash of `values`.
+pub(crate) fn insert_mem_range_into_adv_map<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let values = read_mem_range(process, 4, 5)?;
+
+    let commitment = process.get_stack_word(0);
+    if Word::from(Rpo256::hash_elements(&values)) != commitment {
+        return Err(ExecutionError::MemoryCommitmentMismatch(commitment));
+    }
+    advice_provider.insert_into_map(commitment, values)?;
+
+    Ok(HostResponse::None)
+}
+
 /// Reads two word from the operand stack and inserts them into the advice map under the key
 /// defined by the hash of these words.
 ///
@@ -186,3 +215,22 @@ fn get_mem_addr_range<S: ProcessState>(
 
     Ok((start_addr as u32, end_addr as u32))
 }
+
+/// Reads the words located in memory of the current context at the range specified by the
+/// elements of the operand stack at positions `start_idx` and `end_idx`, and returns the elements
+/// of these words.
+pub(super) fn read_mem_range<S: ProcessState>(
+    process: &S,
+    start_idx: usize,
+    end_idx: usize,
+) -> Result<Vec<Felt>, ExecutionError> {
+    let (start_addr, end_addr) = get_mem_addr_range(process, start_idx, end_idx)?;
+    let ctx = process.ctx();
+
+    let mut values = Vec::with_capacity(((end_addr - start_addr) as usize) * WORD_SIZE);
+    for addr in start_addr..end_addr {
+        let mem_value = process.get_mem_value(ctx, addr).unwrap_or(EMPTY_WORD);
+        values.extend_from_slice(&mem_value);
+    }
+    Ok(values)
+}
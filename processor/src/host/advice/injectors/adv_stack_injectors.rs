@@ -1,7 +1,16 @@
-use super::super::{AdviceSource, ExecutionError, Felt, HostResponse};
-use crate::{AdviceProvider, Ext2InttError, FieldElement, ProcessState, ZERO};
+use super::{
+    super::{AdviceMapNamespace, AdviceSource, ExecutionError, Felt, HostResponse},
+    adv_map_injectors::read_mem_range,
+};
+use crate::{AdviceProvider, Ext2InttError, FieldElement, ProcessState, Word, ZERO};
 use alloc::vec::Vec;
-use vm_core::{QuadExtension, SignatureKind};
+use vm_core::{
+    crypto::{
+        hash::{Rpo256, RpoDigest},
+        merkle::{multiproof_sibling_indices, DigestMerkleTree, NodeIndex, SMT_DEPTH},
+    },
+    QuadExtension, SignatureKind, EMPTY_WORD,
+};
 use winter_prover::math::fft;
 
 // TYPE ALIASES
@@ -57,6 +66,148 @@ pub(crate) fn copy_merkle_node_to_adv_stack<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Pushes onto the advice stack the nodes which are required to verify the leaves at the
+/// specified indices against the root of a Merkle tree, omitting the nodes which can be computed
+/// from the leaves.
+///
+/// Inputs:
+///   Operand stack: [depth, n, ptr, TREE_ROOT, ...]
+///   Advice stack: [...]
+///   Merkle store: {TREE_ROOT<-NODES}
+///
+/// Outputs:
+///   Operand stack: [depth, n, ptr, TREE_ROOT, ...]
+///   Advice stack: [NODES, ...]
+///   Merkle store: {TREE_ROOT<-NODES}
+///
+/// Where the leaf indices are read from the first elements of the memory words at addresses
+/// `ptr`, `ptr + 2`, ..., `ptr + 2 * (n - 1)`, and the nodes are ordered as described in
+/// [multiproof_sibling_indices()], with the first node at the top of the advice stack.
+///
+/// # Errors
+/// Returns an error if:
+/// - The depth is greater than 64.
+/// - The memory range holding the leaf indices is out of bounds.
+/// - A leaf index is not valid for the specified depth.
+/// - Merkle tree for the specified root cannot be found in the advice provider, or a required
+///   node is not known to the advice provider.
+pub(crate) fn push_merkle_multiproof<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    // read the tree depth, the location of the leaf indices, and the tree root from the stack
+    let depth = process.get_stack_item(0);
+    let num_leaves = process.get_stack_item(1).as_int();
+    let ptr = process.get_stack_item(2).as_int();
+    let root = [
+        process.get_stack_item(6),
+        process.get_stack_item(5),
+        process.get_stack_item(4),
+        process.get_stack_item(3),
+    ];
+
+    if depth.as_int() > SMT_DEPTH as u64 {
+        return Err(ExecutionError::InvalidTreeDepth { depth });
+    }
+    let end_addr = ptr.saturating_add(num_leaves.saturating_mul(2));
+    if end_addr > u32::MAX as u64 {
+        return Err(ExecutionError::MemoryAddressOutOfBounds(end_addr));
+    }
+
+    // read the leaf indices from memory
+    let ctx = process.ctx();
+    let indices = (0..num_leaves).map(|i| {
+        let addr = (ptr + 2 * i) as u32;
+        process.get_mem_value(ctx, addr).unwrap_or(EMPTY_WORD)[0].as_int()
+    });
+    let siblings = multiproof_sibling_indices(depth.as_int() as u8, indices)
+        .map_err(ExecutionError::MerkleStoreLookupFailed)?;
+
+    // look up the nodes, and push them onto the advice stack so that the first node can be
+    // popped first
+    let mut nodes = Vec::with_capacity(siblings.len());
+    for sibling in siblings {
+        let node_depth = Felt::from(sibling.depth());
+        let node_index = Felt::new(sibling.value());
+        nodes.push(advice_provider.get_tree_node(root, &node_depth, &node_index)?);
+    }
+    for node in nodes.into_iter().rev() {
+        advice_provider.push_stack(AdviceSource::Word(node))?;
+    }
+
+    Ok(HostResponse::None)
+}
+
+/// Pushes onto the advice stack the node of a BLAKE3 or SHA256 Merkle tree specified by the
+/// values on the top of the operand stack, followed by the authentication path of the node.
+///
+/// Inputs:
+///   Operand stack: [depth, index, TREE_ROOT, ...]
+///   Advice stack: [...]
+///   Advice map: {hash(TREE_ROOT || d || i): NODE}
+///
+/// Outputs:
+///   Operand stack: [depth, index, TREE_ROOT, ...]
+///   Advice stack: [NODE, SIBLING_0, ..., SIBLING_(depth-1), ...]
+///   Advice map: {hash(TREE_ROOT || d || i): NODE}
+///
+/// Where TREE_ROOT and the nodes consist of 8 elements, the keys of the nodes are computed as
+/// described in [DigestMerkleTree::node_key()], and the elements of each node are pushed in
+/// reverse order, so that the node can be read via `adv_push.8`.
+///
+/// # Errors
+/// Returns an error if:
+/// - The depth is greater than 64, or the index is not valid for the depth.
+/// - The node or one of the nodes of its authentication path is not in the advice map, or is not
+///   a list of 8 elements.
+pub(crate) fn push_digest_merkle_path<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    // read node depth, node index, and tree root from the stack
+    let depth = process.get_stack_item(0);
+    let index = process.get_stack_item(1);
+    let mut root = [ZERO; 8];
+    for (i, element) in root.iter_mut().enumerate() {
+        *element = process.get_stack_item(2 + i);
+    }
+
+    let mut node_index = NodeIndex::from_elements(&depth, &index).map_err(|_| {
+        ExecutionError::InvalidTreeNodeIndex {
+            depth,
+            value: index,
+        }
+    })?;
+
+    // look up the node and its siblings, from the node up to the root
+    let get_node = |node_index: NodeIndex| {
+        let key = DigestMerkleTree::node_key(
+            &root,
+            Felt::from(node_index.depth()),
+            Felt::new(node_index.value()),
+        );
+        match advice_provider.get_mapped_values(&key) {
+            Some(values) if values.len() == 8 => Ok(values),
+            _ => Err(ExecutionError::AdviceMapKeyNotFound(key.into())),
+        }
+    };
+    let mut nodes = vec![get_node(node_index)?];
+    while !node_index.is_root() {
+        nodes.push(get_node(node_index.sibling())?);
+        node_index.move_up();
+    }
+
+    // push the nodes so that the first node is at the top of the advice stack, with the elements
+    // of every node in reverse order
+    for node in nodes.into_iter().rev() {
+        for value in node {
+            advice_provider.push_stack(AdviceSource::Value(value))?;
+        }
+    }
+
+    Ok(HostResponse::None)
+}
+
 /// Pushes a list of field elements onto the advice stack. The list is looked up in the advice
 /// map using the specified word from the operand stack as the key. If `include_len` is set to
 /// true, the number of elements in the value is also pushed onto the advice stack.
@@ -102,6 +253,37 @@ pub(crate) fn copy_map_value_to_adv_stack<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Pushes a list of field elements stored under a key of an advice map namespace onto the advice
+/// stack. If `include_len` is set to true, the number of elements in the value is also pushed
+/// onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [NS, KEY, ...]
+///   Advice stack: [...]
+///   Advice map: {hash(NS || KEY): values}
+///
+/// Outputs:
+///   Operand stack: [NS, KEY, ...]
+///   Advice stack: [values_len?, values, ...]
+///   Advice map: {hash(NS || KEY): values}
+///
+/// # Errors
+/// Returns an error if the required key was not found in the key-value map.
+pub(crate) fn copy_namespaced_map_value_to_adv_stack<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+    include_len: bool,
+) -> Result<HostResponse, ExecutionError> {
+    let namespace = AdviceMapNamespace::from(RpoDigest::from(process.get_stack_word(0)));
+    let key = namespace.key(process.get_stack_word(1));
+    advice_provider.push_stack(AdviceSource::Map {
+        key: key.into(),
+        include_len,
+    })?;
+
+    Ok(HostResponse::None)
+}
+
 /// Pushes the result of [u64] division (both the quotient and the remainder) onto the advice
 /// stack.
 ///
@@ -297,6 +479,41 @@ pub(crate) fn push_signature<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Pushes values onto the advice stack which are required for verification of a DSA over the data
+/// located in the specified memory range.
+///
+/// Inputs:
+///   Operand stack: [PK, COM, start_addr, end_addr, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [PK, COM, start_addr, end_addr, ...]
+///   Advice stack: [DATA]
+///
+/// Where:
+/// - PK is the digest of an expanded public.
+/// - COM is the sequential RPO hash of the words located in memory[start_addr..end_addr], which is
+///   the message to be signed.
+/// - DATA is the needed data for signature verification in the VM.
+///
+/// The advice provider is expected to contain the private key associated to the public key PK.
+///
+/// # Errors
+/// Returns an error if the memory range is invalid, or if COM is not the commitment to the words
+/// located in the memory range.
+pub(crate) fn push_mem_signature<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+    kind: SignatureKind,
+) -> Result<HostResponse, ExecutionError> {
+    let (pub_key, msg) = get_signed_mem_message(process)?;
+    let result: Vec<Felt> = advice_provider.get_signature(kind, pub_key, msg)?;
+    for r in result {
+        advice_provider.push_stack(AdviceSource::Value(r))?;
+    }
+    Ok(HostResponse::None)
+}
+
 /// Pushes the number of the leading zeros of the top stack element onto the advice stack.
 ///
 /// Inputs:
@@ -393,9 +610,45 @@ pub(crate) fn push_ilog2<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Pushes the maximum number of cycles the program is allowed to execute onto the advice stack.
+///
+/// Inputs:
+///   Operand stack: [...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [...]
+///   Advice stack: [max_cycles, ...]
+pub(crate) fn push_max_cycles<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let max_cycles = Felt::from(process.max_cycles());
+    advice_provider.push_stack(AdviceSource::Value(max_cycles))?;
+    Ok(HostResponse::None)
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Reads the public key and the commitment to the data to be signed from the operand stack laid
+/// out as [PK, COM, start_addr, end_addr, ...], and returns them after checking that COM is the
+/// sequential RPO hash of the words located in memory[start_addr..end_addr] of the current
+/// context.
+pub(crate) fn get_signed_mem_message<S: ProcessState>(
+    process: &S,
+) -> Result<(Word, Word), ExecutionError> {
+    let pub_key = process.get_stack_word(0);
+    let commitment = process.get_stack_word(1);
+    let data = read_mem_range(process, 8, 9)?;
+
+    let data_commitment: Word = Rpo256::hash_elements(&data).into();
+    if data_commitment != commitment {
+        return Err(ExecutionError::SignedDataMismatch(commitment));
+    }
+    Ok((pub_key, commitment))
+}
+
 fn u64_to_u32_elements(value: u64) -> (Felt, Felt) {
     let hi = Felt::from((value >> 32) as u32);
     let lo = Felt::from(value as u32);
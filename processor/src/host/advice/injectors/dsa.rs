@@ -1,6 +1,12 @@
 use super::super::{ExecutionError, Felt, Word};
 use alloc::vec::Vec;
 
+#[cfg(feature = "std")]
+use vm_core::crypto::{
+    dsa::rpo_falcon512::{Polynomial, SecretKey, Signature},
+    hash::RpoDigest,
+};
+
 /// Gets as input a vector containing a secret key, and a word representing a message and outputs a
 /// vector of values to be pushed onto the advice stack.
 /// The values are the ones required for a Falcon signature verification inside the VM and they are:
@@ -11,16 +17,16 @@ use alloc::vec::Vec;
 /// 4. The product of the above two polynomials in the ring of polynomials with coefficients
 /// in the Miden field.
 ///
+/// The values are returned in the order in which they should be pushed onto the advice stack, i.e.,
+/// the first element of the nonce is the last value of the returned vector.
+///
 /// # Errors
 /// Will return an error if either:
 /// - The secret key is malformed due to either incorrect length or failed decoding.
 /// - The signature generation failed.
 #[cfg(feature = "std")]
 pub fn falcon_sign(sk: &[Felt], msg: Word) -> Result<Vec<Felt>, ExecutionError> {
-    use vm_core::{
-        crypto::dsa::rpo_falcon512::{Polynomial, SecretKey},
-        utils::Deserializable,
-    };
+    use vm_core::utils::Deserializable;
 
     // Create the corresponding secret key
     let mut sk_bytes = Vec::with_capacity(sk.len());
@@ -35,39 +41,70 @@ pub fn falcon_sign(sk: &[Felt], msg: Word) -> Result<Vec<Felt>, ExecutionError>
     let sk = SecretKey::read_from_bytes(&sk_bytes)
         .map_err(|_| ExecutionError::MalformedSignatureKey("RPO Falcon512"))?;
 
-    // We can now generate the signature
+    // We can now generate the signature and encode it
     let sig = sk.sign(msg);
+    let mut result = falcon_signature_to_advice(&sig);
+    result.reverse();
+    Ok(result)
+}
 
-    // The signature is composed of a nonce and a polynomial s2
+#[cfg(not(feature = "std"))]
+pub fn falcon_sign(sk: &[Felt], msg: Word) -> Result<Vec<Felt>, ExecutionError> {
+    Err(ExecutionError::FailedSignatureGeneration(
+        "RPO Falcon512 signature generation is not available in no_std context",
+    ))
+}
+
+// PUBLIC ENCODERS
+// ================================================================================================
 
+/// Returns the advice map entry from which the `adv.push_sig.rpo_falcon512` decorator generates
+/// signatures with the specified secret key.
+///
+/// The entry maps the public key commitment (i.e., the `PK` value expected by
+/// `std::crypto::dsa::rpo_falcon512::verify`) to the serialized secret key, with one byte per
+/// field element.
+#[cfg(feature = "std")]
+pub fn falcon_secret_key_to_advice(sk: &SecretKey) -> (RpoDigest, Vec<Felt>) {
+    use vm_core::utils::Serializable;
+
+    let pub_key: Word = sk.public_key().into();
+    let sk_elements = sk.to_bytes().into_iter().map(|byte| Felt::from(byte as u32)).collect();
+    (pub_key.into(), sk_elements)
+}
+
+/// Encodes the specified signature into the values which
+/// `std::crypto::dsa::rpo_falcon512::verify_from_advice` expects to find on the advice stack.
+///
+/// The values are, in order:
+///
+/// 1. The nonce represented as 8 field elements.
+/// 2. The expanded public key represented as the coefficients of a polynomial of degree < 512.
+/// 3. The signature represented as the coefficients of a polynomial of degree < 512.
+/// 4. The product of the above two polynomials in the ring of polynomials with coefficients
+///    in the Miden field.
+///
+/// The first element of the returned vector is expected to be at the top of the advice stack, and
+/// thus, the vector can be passed directly to
+/// [AdviceInputs::with_stack()](crate::AdviceInputs::with_stack).
+#[cfg(feature = "std")]
+pub fn falcon_signature_to_advice(sig: &Signature) -> Vec<Felt> {
     // The nonce is represented as 8 field elements.
     let nonce = sig.nonce();
 
-    // We convert the signature to a polynomial
-    let s2 = sig.sig_poly();
-
-    // We also need in the VM the expanded key corresponding to the public key the was provided
+    // The signature polynomial s2 and the expanded key h corresponding to the public key provided
     // via the operand stack
-    let h = sk.compute_pub_key_poly().0;
+    let s2 = sig.sig_poly();
+    let h = &sig.pk_poly().0;
 
     // Lastly, for the probabilistic product routine that is part of the verification procedure,
     // we need to compute the product of the expanded key and the signature polynomial in
     // the ring of polynomials with coefficients in the Miden field.
-    let pi = Polynomial::mul_modulo_p(&h, s2);
+    let pi = Polynomial::mul_modulo_p(h, s2);
 
-    // We now push the nonce, the expanded key, the signature polynomial, and the product of the
-    // expanded key and the signature polynomial to the advice stack.
     let mut result: Vec<Felt> = nonce.to_elements().to_vec();
     result.extend(h.coefficients.iter().map(|a| Felt::from(a.value() as u32)));
     result.extend(s2.coefficients.iter().map(|a| Felt::from(a.value() as u32)));
     result.extend(pi.iter().map(|a| Felt::new(*a)));
-    result.reverse();
-    Ok(result)
-}
-
-#[cfg(not(feature = "std"))]
-pub fn falcon_sign(sk: &[Felt], msg: Word) -> Result<Vec<Felt>, ExecutionError> {
-    Err(ExecutionError::FailedSignatureGeneration(
-        "RPO Falcon512 signature generation is not available in no_std context",
-    ))
+    result
 }
@@ -6,7 +6,7 @@ use vm_core::{
         hash::RpoDigest,
         merkle::{EmptySubtreeRoots, Smt, SMT_DEPTH},
     },
-    WORD_SIZE,
+    ONE, WORD_SIZE, ZERO,
 };
 
 // SMT INJECTORS
@@ -65,6 +65,78 @@ pub(crate) fn push_smtpeek_result<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Pushes onto the advice stack the values required to navigate a compact Sparse Merkle Tree
+/// to the specified key.
+///
+/// Inputs:
+///   Operand stack: [depth, KEY, ROOT, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [depth, KEY, ROOT, ...]
+///   Advice stack: [leaf_depth, is_sibling_leaf, collapse_depth, ...]
+///
+/// # Errors
+/// Returns an error if the depth is not in the range [1, 64], or if the provided Merkle root
+/// doesn't exist on the advice provider.
+pub(crate) fn push_compact_smtpeek_result<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    // fetch the arguments from the operand stack
+    let depth = process.get_stack_item(0);
+    let key_msb = process.get_stack_item(1).as_int();
+    let root = [
+        process.get_stack_item(8),
+        process.get_stack_item(7),
+        process.get_stack_item(6),
+        process.get_stack_item(5),
+    ];
+
+    if depth.as_int() == 0 || depth.as_int() > SMT_DEPTH as u64 {
+        return Err(ExecutionError::InvalidTreeDepth { depth });
+    }
+    let tree_depth = depth.as_int() as u8;
+
+    // the path to the key is defined by the most significant bits of its most significant element
+    let index_at = |depth: u8| key_msb >> (SMT_DEPTH - depth);
+    let sibling_at = |depth: u8| {
+        advice_provider.get_tree_node(root, &Felt::from(depth), &Felt::new(index_at(depth) ^ 1))
+    };
+    let is_empty =
+        |node: Word, depth: u8| node == Word::from(EmptySubtreeRoots::entry(tree_depth, depth));
+
+    // leaves are never placed at the root, so the first leaf or empty subtree is searched for
+    // starting from depth 1
+    let leaf_depth = advice_provider
+        .get_leaf_depth(root, &depth, &Felt::new(index_at(tree_depth)))?
+        .max(1);
+
+    // the sibling is a leaf if it is neither the root of an empty subtree nor an internal node
+    let sibling = sibling_at(leaf_depth)?;
+    let is_sibling_leaf = !is_empty(sibling, leaf_depth)
+        && advice_provider.get_tree_node(sibling, &ONE, &ZERO).is_err();
+
+    // if the node is removed, the sibling leaf moves up for as long as the sibling of its parent
+    // is empty, but not above depth 1
+    let mut collapse_depth = 0;
+    if is_sibling_leaf {
+        collapse_depth = 1;
+        for depth in (2..leaf_depth).rev() {
+            if !is_empty(sibling_at(depth)?, depth) {
+                collapse_depth = depth;
+                break;
+            }
+        }
+    }
+
+    advice_provider.push_stack(AdviceSource::Value(Felt::from(collapse_depth)))?;
+    advice_provider.push_stack(AdviceSource::Value(Felt::from(is_sibling_leaf as u8)))?;
+    advice_provider.push_stack(AdviceSource::Value(Felt::from(leaf_depth)))?;
+
+    Ok(HostResponse::None)
+}
+
 /// Currently unimplemented
 pub(crate) fn push_smtget_inputs<S: ProcessState, A: AdviceProvider>(
     _advice_provider: &mut A,
@@ -1,6 +1,12 @@
-use super::{AdviceMap, Felt, InnerNodeInfo, InputError, MerkleStore};
+use super::{AdviceMap, AdviceMapNamespace, Felt, InnerNodeInfo, InputError, MerkleStore, Word};
 use alloc::vec::Vec;
-use vm_core::crypto::hash::RpoDigest;
+use vm_core::{
+    crypto::{
+        hash::RpoDigest,
+        merkle::{partial_merkle_tree_from_proofs, DigestMerkleTree, MerklePath},
+    },
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
 
 // ADVICE INPUTS
 // ================================================================================================
@@ -18,6 +24,7 @@ use vm_core::crypto::hash::RpoDigest;
 ///    operates with Merkle trees.
 #[cfg(not(feature = "internals"))]
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdviceInputs {
     stack: Vec<Felt>,
     map: AdviceMap,
@@ -67,6 +74,31 @@ impl AdviceInputs {
         self
     }
 
+    /// Extends the [MerkleStore] with the nodes of the specified Merkle proofs, where each proof
+    /// is a tuple of (leaf index, leaf value, authentication path) against the specified root.
+    ///
+    /// # Errors
+    /// Returns an error if any of the proofs is malformed or does not open to the specified root.
+    pub fn with_merkle_proofs<I>(mut self, root: RpoDigest, proofs: I) -> Result<Self, InputError>
+    where
+        I: IntoIterator<Item = (u64, RpoDigest, MerklePath)>,
+    {
+        let tree = partial_merkle_tree_from_proofs(root, proofs)?;
+        self.store.extend(tree.inner_nodes());
+        Ok(self)
+    }
+
+    /// Extends the map of values with the nodes of the specified BLAKE3 or SHA256 Merkle tree, so
+    /// that the nodes can be accessed via `adv.push_dmtpath`.
+    ///
+    /// The hash function of the tree is selected when the tree is built, and the program selects
+    /// the procedure which verifies the nodes accordingly (`get_blake3_node` or `get_sha256_node`
+    /// of `std::crypto::merkle`).
+    pub fn with_digest_merkle_tree(mut self, tree: &DigestMerkleTree) -> Self {
+        self.map.extend(tree.to_advice_map_entries());
+        self
+    }
+
     // PUBLIC MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -94,6 +126,22 @@ impl AdviceInputs {
         self.store.extend(iter);
     }
 
+    /// Inserts the specified words into the advice map under the specified key of the namespace.
+    pub fn insert_word_slice(&mut self, namespace: AdviceMapNamespace, key: Word, words: &[Word]) {
+        self.map.insert_word_slice(namespace, key, words);
+    }
+
+    /// Inserts the specified bytes into the advice map under the specified key of the namespace.
+    pub fn insert_bytes(&mut self, namespace: AdviceMapNamespace, key: Word, bytes: &[u8]) {
+        self.map.insert_bytes(namespace, key, bytes);
+    }
+
+    /// Inserts the specified integers into the advice map under the specified key of the
+    /// namespace.
+    pub fn insert_u64s(&mut self, namespace: AdviceMapNamespace, key: Word, values: &[u64]) {
+        self.map.insert_u64s(namespace, key, values);
+    }
+
     /// Extends the contents of this instance with the contents of the other instance.
     pub fn extend(&mut self, other: Self) {
         self.stack.extend(other.stack);
@@ -114,6 +162,11 @@ impl AdviceInputs {
         self.map.get(key)
     }
 
+    /// Returns a reference to the advice map.
+    pub fn map(&self) -> &AdviceMap {
+        &self.map
+    }
+
     /// Returns the underlying [MerkleStore].
     pub const fn merkle_store(&self) -> &MerkleStore {
         &self.store
@@ -135,8 +188,89 @@ impl AdviceInputs {
 
 #[cfg(feature = "internals")]
 #[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdviceInputs {
     pub stack: Vec<Felt>,
     pub map: AdviceMap,
     pub store: MerkleStore,
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AdviceInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.stack.len());
+        target.write_many(&self.stack);
+        self.map.write_into(target);
+
+        // the Merkle store is serialized as the list of its nodes
+        let nodes = self.store.inner_nodes().collect::<Vec<_>>();
+        target.write_usize(nodes.len());
+        for node in nodes {
+            node.value.write_into(target);
+            node.left.write_into(target);
+            node.right.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for AdviceInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        // lengths are not used to pre-allocate memory, as they cannot be trusted
+        let stack_len = source.read_usize()?;
+        let mut stack = Vec::new();
+        for _ in 0..stack_len {
+            stack.push(Felt::read_from(source)?);
+        }
+        let map = AdviceMap::read_from(source)?;
+
+        let num_nodes = source.read_usize()?;
+        let mut nodes = Vec::new();
+        for _ in 0..num_nodes {
+            let value = RpoDigest::read_from(source)?;
+            let left = RpoDigest::read_from(source)?;
+            let right = RpoDigest::read_from(source)?;
+            nodes.push(InnerNodeInfo { value, left, right });
+        }
+        let mut store = MerkleStore::default();
+        store.extend(nodes);
+
+        Ok(Self { stack, map, store })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AdviceInputs, Felt, RpoDigest};
+    use alloc::vec::Vec;
+    use vm_core::utils::{ByteWriter, Deserializable, Serializable};
+
+    #[test]
+    fn advice_inputs_serialization() {
+        let key = RpoDigest::from([Felt::new(3); 4]);
+        let inputs = AdviceInputs::default()
+            .with_stack([Felt::new(1), Felt::new(2)])
+            .with_map([(key, vec![Felt::new(4)])]);
+        let result = AdviceInputs::read_from_bytes(&inputs.to_bytes()).unwrap();
+        assert_eq!(inputs.stack, result.stack);
+        assert_eq!(inputs.map.get(&key), result.map.get(&key));
+
+        // truncated inputs declaring huge lengths are rejected without allocating memory for them
+        let mut bytes = Vec::new();
+        bytes.write_usize(usize::MAX);
+        Felt::new(1).write_into(&mut bytes);
+        assert!(AdviceInputs::read_from_bytes(&bytes).is_err());
+
+        let mut bytes = Vec::new();
+        bytes.write_usize(0);
+        bytes.write_usize(1);
+        key.write_into(&mut bytes);
+        bytes.write_usize(usize::MAX);
+        Felt::new(4).write_into(&mut bytes);
+        assert!(AdviceInputs::read_from_bytes(&bytes).is_err());
+    }
+}
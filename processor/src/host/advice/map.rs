@@ -2,7 +2,11 @@ use super::Felt;
 use alloc::collections::btree_map::IntoIter;
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
-use vm_core::crypto::hash::RpoDigest;
+use vm_core::{
+    crypto::hash::{Rpo256, RpoDigest},
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Word,
+};
 
 // ADVICE MAP
 // ================================================================================================
@@ -13,6 +17,7 @@ use vm_core::crypto::hash::RpoDigest;
 /// associated with a given key onto the advice stack using `adv.push_mapval` instruction. The VM
 /// can also insert new values into the advice map during execution.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AdviceMap(BTreeMap<RpoDigest, Vec<Felt>>);
 
 impl AdviceMap {
@@ -35,6 +40,56 @@ impl AdviceMap {
     pub fn remove(&mut self, key: RpoDigest) -> Option<Vec<Felt>> {
         self.0.remove(&key)
     }
+
+    /// Returns an iterator over the entries of the advice map, in the order of their keys.
+    pub fn iter(&self) -> impl Iterator<Item = (&RpoDigest, &[Felt])> {
+        self.0.iter().map(|(key, values)| (key, values.as_slice()))
+    }
+
+    // TYPED ENTRIES
+    // --------------------------------------------------------------------------------------------
+
+    /// Inserts the specified words under the specified key of the namespace.
+    ///
+    /// The words are encoded as described in [AdviceMapNamespace].
+    pub fn insert_word_slice(&mut self, namespace: AdviceMapNamespace, key: Word, words: &[Word]) {
+        self.0.insert(namespace.key(key), encode_word_slice(words));
+    }
+
+    /// Inserts the specified bytes under the specified key of the namespace.
+    ///
+    /// The bytes are encoded as described in [AdviceMapNamespace].
+    pub fn insert_bytes(&mut self, namespace: AdviceMapNamespace, key: Word, bytes: &[u8]) {
+        self.0.insert(namespace.key(key), encode_bytes(bytes));
+    }
+
+    /// Inserts the specified integers under the specified key of the namespace.
+    ///
+    /// The integers are encoded as described in [AdviceMapNamespace].
+    pub fn insert_u64s(&mut self, namespace: AdviceMapNamespace, key: Word, values: &[u64]) {
+        self.0.insert(namespace.key(key), encode_u64s(values));
+    }
+
+    /// Returns the words stored under the specified key of the namespace.
+    ///
+    /// Returns `None` if the entry does not exist or if it is not a valid encoding of words.
+    pub fn get_word_slice(&self, namespace: AdviceMapNamespace, key: Word) -> Option<Vec<Word>> {
+        self.get(&namespace.key(key)).and_then(decode_word_slice)
+    }
+
+    /// Returns the bytes stored under the specified key of the namespace.
+    ///
+    /// Returns `None` if the entry does not exist or if it is not a valid encoding of bytes.
+    pub fn get_bytes(&self, namespace: AdviceMapNamespace, key: Word) -> Option<Vec<u8>> {
+        self.get(&namespace.key(key)).and_then(decode_bytes)
+    }
+
+    /// Returns the integers stored under the specified key of the namespace.
+    ///
+    /// Returns `None` if the entry does not exist or if it is not a valid encoding of integers.
+    pub fn get_u64s(&self, namespace: AdviceMapNamespace, key: Word) -> Option<Vec<u64>> {
+        self.get(&namespace.key(key)).and_then(decode_u64s)
+    }
 }
 
 impl From<BTreeMap<RpoDigest, Vec<Felt>>> for AdviceMap {
@@ -57,3 +112,183 @@ impl Extend<(RpoDigest, Vec<Felt>)> for AdviceMap {
         self.0.extend(iter)
     }
 }
+
+// ADVICE MAP NAMESPACE
+// ================================================================================================
+
+/// A namespace of advice map entries.
+///
+/// Entries of a namespace are stored in the advice map under keys derived from the namespace
+/// digest and the key of the entry as `hash(NS || KEY)`, where `NS` is the hash of the namespace
+/// name. Thus, libraries which store their data under distinct namespaces cannot overwrite each
+/// other's entries even if they use the same keys. In Miden assembly, the values of such entries
+/// can be moved onto the advice stack via `adv.push_mapval_ns` and `adv.push_mapvaln_ns`
+/// instructions, or the key can be derived via `hmerge`.
+///
+/// Typed values are encoded into field elements as follows:
+/// - Words are encoded as their elements concatenated, such that the first element of the first
+///   word is the first element of the value.
+/// - Bytes are encoded as the number of bytes followed by the bytes packed into 32-bit limbs in
+///   little-endian order, such that each limb is encoded as a single element. The last limb is
+///   padded with zeros.
+/// - 64-bit integers are encoded as pairs of their 32-bit limbs, with the low limb preceding the
+///   high limb. Thus, after the values are moved onto the advice stack, `adv_push.2` places an
+///   integer onto the operand stack in the `[hi, lo, ...]` layout expected by `std::math::u64`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AdviceMapNamespace(RpoDigest);
+
+impl AdviceMapNamespace {
+    /// Returns the namespace with the specified name.
+    pub fn new(name: &str) -> Self {
+        Self(Rpo256::hash(name.as_bytes()))
+    }
+
+    /// Returns the digest identifying this namespace.
+    pub fn digest(&self) -> RpoDigest {
+        self.0
+    }
+
+    /// Returns the advice map key under which the entry with the specified key of this namespace
+    /// is stored.
+    pub fn key(&self, key: Word) -> RpoDigest {
+        Rpo256::merge(&[self.0, key.into()])
+    }
+}
+
+impl From<RpoDigest> for AdviceMapNamespace {
+    fn from(digest: RpoDigest) -> Self {
+        Self(digest)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+pub(super) fn encode_word_slice(words: &[Word]) -> Vec<Felt> {
+    words.iter().flatten().copied().collect()
+}
+
+pub(super) fn decode_word_slice(values: &[Felt]) -> Option<Vec<Word>> {
+    if values.len() % 4 != 0 {
+        return None;
+    }
+    Some(values.chunks(4).map(|chunk| [chunk[0], chunk[1], chunk[2], chunk[3]]).collect())
+}
+
+pub(super) fn encode_bytes(bytes: &[u8]) -> Vec<Felt> {
+    let mut values = Vec::with_capacity(1 + bytes.len().div_ceil(4));
+    values.push(Felt::new(bytes.len() as u64));
+    for chunk in bytes.chunks(4) {
+        let mut limb = [0_u8; 4];
+        limb[..chunk.len()].copy_from_slice(chunk);
+        values.push(Felt::from(u32::from_le_bytes(limb)));
+    }
+    values
+}
+
+pub(super) fn decode_bytes(values: &[Felt]) -> Option<Vec<u8>> {
+    let (len, limbs) = values.split_first()?;
+    let len = usize::try_from(len.as_int()).ok()?;
+    if limbs.len() != len.div_ceil(4) {
+        return None;
+    }
+
+    let mut bytes = Vec::with_capacity(limbs.len() * 4);
+    for limb in limbs {
+        let limb = u32::try_from(limb.as_int()).ok()?;
+        bytes.extend_from_slice(&limb.to_le_bytes());
+    }
+    if bytes[len..].iter().any(|&byte| byte != 0) {
+        return None;
+    }
+    bytes.truncate(len);
+    Some(bytes)
+}
+
+pub(super) fn encode_u64s(values: &[u64]) -> Vec<Felt> {
+    values
+        .iter()
+        .flat_map(|&value| [Felt::new(value & u32::MAX as u64), Felt::new(value >> 32)])
+        .collect()
+}
+
+pub(super) fn decode_u64s(values: &[Felt]) -> Option<Vec<u64>> {
+    if values.len() % 2 != 0 {
+        return None;
+    }
+    values
+        .chunks(2)
+        .map(|limbs| {
+            let lo = u32::try_from(limbs[0].as_int()).ok()?;
+            let hi = u32::try_from(limbs[1].as_int()).ok()?;
+            Some(((hi as u64) << 32) | lo as u64)
+        })
+        .collect()
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AdviceMap {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.0.len());
+        for (key, values) in self.0.iter() {
+            key.write_into(target);
+            target.write_usize(values.len());
+            target.write_many(values);
+        }
+    }
+}
+
+impl Deserializable for AdviceMap {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut map = BTreeMap::new();
+        let num_entries = source.read_usize()?;
+        for _ in 0..num_entries {
+            let key = RpoDigest::read_from(source)?;
+            // the number of values is not used to pre-allocate memory, as it cannot be trusted
+            let num_values = source.read_usize()?;
+            let mut values = Vec::new();
+            for _ in 0..num_values {
+                values.push(Felt::read_from(source)?);
+            }
+            map.insert(key, values);
+        }
+        Ok(Self(map))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AdviceMap, AdviceMapNamespace, Felt};
+    use vm_core::ONE;
+
+    #[test]
+    fn typed_entries() {
+        let ns_a = AdviceMapNamespace::new("a");
+        let ns_b = AdviceMapNamespace::new("b");
+        let key = [ONE; 4];
+
+        let mut map = AdviceMap::new();
+        map.insert_bytes(ns_a, key, b"hello");
+        map.insert_u64s(ns_b, key, &[u64::MAX, 1 << 32]);
+
+        // entries with the same key in different namespaces do not collide
+        assert_eq!(map.get_bytes(ns_a, key), Some(b"hello".to_vec()));
+        assert_eq!(map.get_u64s(ns_b, key), Some(vec![u64::MAX, 1 << 32]));
+        assert_eq!(map.get(&ns_a.key(key)).unwrap().len(), 3);
+        assert!(map.get(&key.into()).is_none());
+
+        // entries are decoded only as the type they were encoded with
+        assert_eq!(map.get_word_slice(ns_a, key), None);
+        assert_eq!(map.get_u64s(ns_a, key), None);
+
+        let words = [[ONE, Felt::new(2), Felt::new(3), Felt::new(4)], [ONE; 4]];
+        map.insert_word_slice(ns_a, key, &words);
+        assert_eq!(map.get_word_slice(ns_a, key), Some(words.to_vec()));
+        assert_eq!(map.get_bytes(ns_a, key), None);
+    }
+}
@@ -17,15 +17,25 @@ mod inputs;
 pub use inputs::AdviceInputs;
 
 mod injectors;
+pub(super) use injectors::adv_stack_injectors::get_signed_mem_message;
+#[cfg(feature = "std")]
+pub use injectors::dsa::{falcon_secret_key_to_advice, falcon_signature_to_advice};
 
 mod providers;
-pub use providers::{MemAdviceProvider, RecAdviceProvider};
+pub use providers::{
+    ForkedAdviceProvider, MemAdviceProvider, PersistentAdviceProvider, RecAdviceProvider,
+};
+
+mod storage;
+#[cfg(feature = "sled")]
+pub use storage::SledAdviceStorage;
+pub use storage::{AdviceStorage, ForkedAdviceStorage, MemAdviceStorage};
 
 mod source;
 pub use source::AdviceSource;
 
 mod map;
-pub use map::AdviceMap;
+pub use map::{AdviceMap, AdviceMapNamespace};
 
 // ADVICE PROVIDER
 // ================================================================================================
@@ -58,10 +68,15 @@ pub trait AdviceProvider: Sized {
         match advice_injector {
             AdviceInjector::MerkleNodeMerge => self.merge_merkle_nodes(process),
             AdviceInjector::MerkleNodeToStack => self.copy_merkle_node_to_adv_stack(process),
+            AdviceInjector::MerkleMultiProofToStack => self.push_merkle_multiproof(process),
+            AdviceInjector::DigestMerklePathToStack => self.push_digest_merkle_path(process),
             AdviceInjector::MapValueToStack {
                 include_len,
                 key_offset,
             } => self.copy_map_value_to_adv_stack(process, *include_len, *key_offset),
+            AdviceInjector::NamespacedMapValueToStack { include_len } => {
+                self.copy_namespaced_map_value_to_adv_stack(process, *include_len)
+            }
             AdviceInjector::UpdateMerkleNode => self.update_operand_stack_merkle_node(process),
             AdviceInjector::U64Div => self.push_u64_div_result(process),
             AdviceInjector::Ext2Inv => self.push_ext2_inv_result(process),
@@ -69,18 +84,22 @@ pub trait AdviceProvider: Sized {
             AdviceInjector::SmtGet => self.push_smtget_inputs(process),
             AdviceInjector::SmtSet => self.push_smtset_inputs(process),
             AdviceInjector::SmtPeek => self.push_smtpeek_result(process),
+            AdviceInjector::CompactSmtPeek => self.push_compact_smtpeek_result(process),
             AdviceInjector::U32Clz => self.push_leading_zeros(process),
             AdviceInjector::U32Ctz => self.push_trailing_zeros(process),
             AdviceInjector::U32Clo => self.push_leading_ones(process),
             AdviceInjector::U32Cto => self.push_trailing_ones(process),
             AdviceInjector::ILog2 => self.push_ilog2(process),
+            AdviceInjector::MaxCyclesToStack => self.push_max_cycles(process),
 
             AdviceInjector::MemToMap => self.insert_mem_values_into_adv_map(process),
+            AdviceInjector::MemRangeToMap => self.insert_mem_range_into_adv_map(process),
             AdviceInjector::HdwordToMap { domain } => {
                 self.insert_hdword_into_adv_map(process, *domain)
             }
             AdviceInjector::HpermToMap => self.insert_hperm_into_adv_map(process),
             AdviceInjector::SigToStack { kind } => self.push_signature(process, *kind),
+            AdviceInjector::SigMemToStack { kind } => self.push_mem_signature(process, *kind),
         }
     }
 
@@ -128,6 +147,32 @@ pub trait AdviceProvider: Sized {
         injectors::adv_map_injectors::insert_mem_values_into_adv_map(self, process)
     }
 
+    /// Reads words from memory at the specified range and inserts them into the advice map under
+    /// the commitment to these words located at the top of the stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [COM, start_addr, end_addr, ...]
+    ///   Advice map: {...}
+    ///
+    /// Outputs:
+    ///   Operand stack: [COM, start_addr, end_addr, ...]
+    ///   Advice map: {COM: values}
+    ///
+    /// Where `values` are the elements located in memory[start_addr..end_addr].
+    ///
+    /// # Errors
+    /// Returns an error:
+    /// - `start_addr` is greater than or equal to 2^32.
+    /// - `end_addr` is greater than or equal to 2^32.
+    /// - `start_addr` > `end_addr`.
+    /// - COM is not the sequential RPO hash of `values`.
+    fn insert_mem_range_into_adv_map<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_map_injectors::insert_mem_range_into_adv_map(self, process)
+    }
+
     /// Reads two word from the operand stack and inserts them into the advice map under the key
     /// defined by the hash of these words.
     ///
@@ -220,6 +265,61 @@ pub trait AdviceProvider: Sized {
         injectors::adv_stack_injectors::copy_merkle_node_to_adv_stack(self, process)
     }
 
+    /// Pushes onto the advice stack the nodes which are required to verify the leaves at the
+    /// specified indices against the root of a Merkle tree, omitting the nodes which can be
+    /// computed from the leaves.
+    ///
+    /// Inputs:
+    ///   Operand stack: [depth, n, ptr, TREE_ROOT, ...]
+    ///   Advice stack: [...]
+    ///   Merkle store: {TREE_ROOT<-NODES}
+    ///
+    /// Outputs:
+    ///   Operand stack: [depth, n, ptr, TREE_ROOT, ...]
+    ///   Advice stack: [NODES, ...]
+    ///   Merkle store: {TREE_ROOT<-NODES}
+    ///
+    /// See [AdviceInjector::MerkleMultiProofToStack] for the description of the inputs.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The depth is greater than 64, or a leaf index is not valid for the depth.
+    /// - The memory range holding the leaf indices is out of bounds.
+    /// - Merkle tree for the specified root cannot be found in the advice provider, or a required
+    ///   node is not known to the advice provider.
+    fn push_merkle_multiproof<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_merkle_multiproof(self, process)
+    }
+
+    /// Pushes onto the advice stack the node of a BLAKE3 or SHA256 Merkle tree specified by the
+    /// values on the top of the operand stack, followed by the authentication path of the node.
+    ///
+    /// Inputs:
+    ///   Operand stack: [depth, index, TREE_ROOT, ...]
+    ///   Advice stack: [...]
+    ///   Advice map: {hash(TREE_ROOT || d || i): NODE}
+    ///
+    /// Outputs:
+    ///   Operand stack: [depth, index, TREE_ROOT, ...]
+    ///   Advice stack: [NODE, SIBLING_0, ..., SIBLING_(depth-1), ...]
+    ///   Advice map: {hash(TREE_ROOT || d || i): NODE}
+    ///
+    /// See [AdviceInjector::DigestMerklePathToStack] for the description of the inputs.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The depth is greater than 64, or the index is not valid for the depth.
+    /// - The node or one of the nodes of its authentication path is not in the advice map.
+    fn push_digest_merkle_path<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_digest_merkle_path(self, process)
+    }
+
     /// Pushes a list of field elements onto the advice stack. The list is looked up in the advice
     /// map using the specified word from the operand stack as the key. If `include_len` is set to
     /// true, the number of elements in the value is also pushed onto the advice stack.
@@ -258,6 +358,36 @@ pub trait AdviceProvider: Sized {
         )
     }
 
+    /// Pushes a list of field elements stored under a key of an [AdviceMapNamespace] onto the
+    /// advice stack. If `include_len` is set to true, the number of elements in the value is also
+    /// pushed onto the advice stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [NS, KEY, ...]
+    ///   Advice stack: [...]
+    ///   Advice map: {hash(NS || KEY): values}
+    ///
+    /// Outputs:
+    ///   Operand stack: [NS, KEY, ...]
+    ///   Advice stack: [values_len?, values, ...]
+    ///   Advice map: {hash(NS || KEY): values}
+    ///
+    /// Where NS is the digest of the namespace.
+    ///
+    /// # Errors
+    /// Returns an error if the required key was not found in the key-value map.
+    fn copy_namespaced_map_value_to_adv_stack<S: ProcessState>(
+        &mut self,
+        process: &S,
+        include_len: bool,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::copy_namespaced_map_value_to_adv_stack(
+            self,
+            process,
+            include_len,
+        )
+    }
+
     /// Pushes the result of [u64] division (both the quotient and the remainder) onto the advice
     /// stack.
     ///
@@ -366,6 +496,32 @@ pub trait AdviceProvider: Sized {
         injectors::adv_stack_injectors::push_signature(self, process, kind)
     }
 
+    /// Pushes values onto the advice stack which are required for verification of a DSA over the
+    /// data located in the specified memory range.
+    ///
+    /// Inputs:
+    ///   Operand stack: [PK, COM, start_addr, end_addr, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [PK, COM, start_addr, end_addr, ...]
+    ///   Advice stack: [DATA]
+    ///
+    /// Where:
+    /// - PK is the digest of an expanded public.
+    /// - COM is the sequential RPO hash of the words located in memory[start_addr..end_addr],
+    ///   which is the message to be signed.
+    /// - DATA is the needed data for signature verification in the VM.
+    ///
+    /// The advice provider is expected to contain the private key associated to the public key PK.
+    fn push_mem_signature<S: ProcessState>(
+        &mut self,
+        process: &S,
+        kind: SignatureKind,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_mem_signature(self, process, kind)
+    }
+
     /// Pushes the number of the leading zeros of the top stack element onto the advice stack.
     ///
     /// Inputs:
@@ -445,6 +601,23 @@ pub trait AdviceProvider: Sized {
         injectors::adv_stack_injectors::push_ilog2(self, process)
     }
 
+    /// Pushes the maximum number of cycles the program is allowed to execute onto the advice
+    /// stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [...]
+    ///   Advice stack: [max_cycles, ...]
+    fn push_max_cycles<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_max_cycles(self, process)
+    }
+
     // DEFAULT MERKLE STORE INJECTORS
     // --------------------------------------------------------------------------------------------
 
@@ -533,6 +706,29 @@ pub trait AdviceProvider: Sized {
         injectors::smt::push_smtpeek_result(self, process)
     }
 
+    /// Pushes onto the advice stack the values required to navigate a compact Sparse Merkle Tree
+    /// to the specified key.
+    ///
+    /// Inputs:
+    ///   Operand stack: [depth, KEY, ROOT, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [depth, KEY, ROOT, ...]
+    ///   Advice stack: [leaf_depth, is_sibling_leaf, collapse_depth, ...]
+    ///
+    /// See [AdviceInjector::CompactSmtPeek] for the description of the pushed values.
+    ///
+    /// # Errors
+    /// Returns an error if the depth is not in the range [1, 64], or if the tree with the provided
+    /// root doesn't exist on the advice provider.
+    fn push_compact_smtpeek_result<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::smt::push_compact_smtpeek_result(self, process)
+    }
+
     /// Currently unimplemented
     fn push_smtget_inputs<S: ProcessState>(
         &mut self,
@@ -549,6 +745,70 @@ pub trait AdviceProvider: Sized {
         injectors::smt::push_smtset_inputs(self, process)
     }
 
+    // TYPED ADVICE MAP ENTRIES
+    // --------------------------------------------------------------------------------------------
+
+    /// Inserts the specified words into the advice map under the specified key of the namespace.
+    ///
+    /// The words are encoded as described in [AdviceMapNamespace].
+    fn insert_word_slice(
+        &mut self,
+        namespace: AdviceMapNamespace,
+        key: Word,
+        words: &[Word],
+    ) -> Result<(), ExecutionError> {
+        self.insert_into_map(namespace.key(key).into(), map::encode_word_slice(words))
+    }
+
+    /// Inserts the specified bytes into the advice map under the specified key of the namespace.
+    ///
+    /// The bytes are encoded as described in [AdviceMapNamespace].
+    fn insert_bytes(
+        &mut self,
+        namespace: AdviceMapNamespace,
+        key: Word,
+        bytes: &[u8],
+    ) -> Result<(), ExecutionError> {
+        self.insert_into_map(namespace.key(key).into(), map::encode_bytes(bytes))
+    }
+
+    /// Inserts the specified integers into the advice map under the specified key of the
+    /// namespace.
+    ///
+    /// The integers are encoded as described in [AdviceMapNamespace].
+    fn insert_u64s(
+        &mut self,
+        namespace: AdviceMapNamespace,
+        key: Word,
+        values: &[u64],
+    ) -> Result<(), ExecutionError> {
+        self.insert_into_map(namespace.key(key).into(), map::encode_u64s(values))
+    }
+
+    /// Returns the words stored in the advice map under the specified key of the namespace.
+    ///
+    /// Returns `None` if the entry does not exist or if it is not a valid encoding of words.
+    fn get_word_slice(&self, namespace: AdviceMapNamespace, key: Word) -> Option<Vec<Word>> {
+        self.get_mapped_values(&namespace.key(key))
+            .and_then(|values| map::decode_word_slice(&values))
+    }
+
+    /// Returns the bytes stored in the advice map under the specified key of the namespace.
+    ///
+    /// Returns `None` if the entry does not exist or if it is not a valid encoding of bytes.
+    fn get_bytes(&self, namespace: AdviceMapNamespace, key: Word) -> Option<Vec<u8>> {
+        self.get_mapped_values(&namespace.key(key))
+            .and_then(|values| map::decode_bytes(&values))
+    }
+
+    /// Returns the integers stored in the advice map under the specified key of the namespace.
+    ///
+    /// Returns `None` if the entry does not exist or if it is not a valid encoding of integers.
+    fn get_u64s(&self, namespace: AdviceMapNamespace, key: Word) -> Option<Vec<u64>> {
+        self.get_mapped_values(&namespace.key(key))
+            .and_then(|values| map::decode_u64s(&values))
+    }
+
     // ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -567,12 +827,27 @@ pub trait AdviceProvider: Sized {
         self
     }
 
+    /// Returns a copy-on-write fork of this advice provider.
+    ///
+    /// The fork starts out with a copy of the advice stack of this provider, and reads advice map
+    /// entries and Merkle store nodes from this provider on demand. Changes made via the fork
+    /// (e.g., new map entries or updated Merkle trees) are kept in the fork only, and thus, the
+    /// fork can be used to speculatively execute a program and then be discarded without copying
+    /// the Merkle store of this provider.
+    fn fork(&self) -> ForkedAdviceProvider<'_, Self> {
+        PersistentAdviceProvider::from_parts(ForkedAdviceStorage::new(self), self.stack().to_vec())
+    }
+
     // REQUIRED METHODS
     // --------------------------------------------------------------------------------------------
 
     // ADVICE STACK
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the current state of the advice stack, with the top of the stack as the last
+    /// element.
+    fn stack(&self) -> &[Felt];
+
     /// Pops an element from the advice stack and returns it.
     ///
     /// # Errors
@@ -610,8 +885,8 @@ pub trait AdviceProvider: Sized {
     // ADVICE MAP
     // --------------------------------------------------------------------------------------------
 
-    /// Returns a reference to the value(s) associated with the specified key in the advice map.
-    fn get_mapped_values(&self, key: &RpoDigest) -> Option<&[Felt]>;
+    /// Returns the value(s) associated with the specified key in the advice map.
+    fn get_mapped_values(&self, key: &RpoDigest) -> Option<Vec<Felt>>;
 
     /// Inserts the provided value into the advice map under the specified key.
     ///
@@ -720,6 +995,10 @@ impl<'a, T> AdviceProvider for &'a mut T
 where
     T: AdviceProvider,
 {
+    fn stack(&self) -> &[Felt] {
+        T::stack(self)
+    }
+
     fn pop_stack<S: ProcessState>(&mut self, process: &S) -> Result<Felt, ExecutionError> {
         T::pop_stack(self, process)
     }
@@ -752,7 +1031,7 @@ where
         T::get_signature(self, kind, pub_key, msg)
     }
 
-    fn get_mapped_values(&self, key: &RpoDigest) -> Option<&[Felt]> {
+    fn get_mapped_values(&self, key: &RpoDigest) -> Option<Vec<Felt>> {
         T::get_mapped_values(self, key)
     }
 
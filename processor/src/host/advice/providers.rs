@@ -1,11 +1,17 @@
 use crate::ProcessState;
 
 use super::{
-    injectors, AdviceInputs, AdviceProvider, AdviceSource, ExecutionError, Felt, MerklePath,
-    MerkleStore, NodeIndex, RpoDigest, StoreNode, Word,
+    injectors, AdviceInputs, AdviceProvider, AdviceSource, AdviceStorage, ExecutionError, Felt,
+    ForkedAdviceStorage, InnerNodeInfo, MerklePath, MerkleStore, NodeIndex, RpoDigest, StoreNode,
+    Word,
 };
-use alloc::collections::BTreeMap;
+use alloc::collections::{BTreeMap, BTreeSet};
 use alloc::vec::Vec;
+use core::cell::RefCell;
+use vm_core::crypto::{
+    hash::Rpo256,
+    merkle::{EmptySubtreeRoots, MerkleError},
+};
 use vm_core::utils::collections::KvMap;
 use vm_core::utils::collections::RecordingMap;
 use vm_core::SignatureKind;
@@ -59,6 +65,10 @@ where
     // ADVICE STACK
     // --------------------------------------------------------------------------------------------
 
+    fn stack(&self) -> &[Felt] {
+        &self.stack
+    }
+
     fn pop_stack<P: ProcessState>(&mut self, process: &P) -> Result<Felt, ExecutionError> {
         self.stack.pop().ok_or(ExecutionError::AdviceStackReadFailed(process.clk()))
     }
@@ -129,8 +139,8 @@ where
     // ADVICE MAP
     // --------------------------------------------------------------------------------------------
 
-    fn get_mapped_values(&self, key: &RpoDigest) -> Option<&[Felt]> {
-        self.map.get(key).map(|v| v.as_slice())
+    fn get_mapped_values(&self, key: &RpoDigest) -> Option<Vec<Felt>> {
+        self.map.get(key).cloned()
     }
 
     fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
@@ -244,11 +254,6 @@ impl From<AdviceInputs> for MemAdviceProvider {
 /// Accessors to internal data structures of the provider used for testing purposes.
 #[cfg(any(test, feature = "internals"))]
 impl MemAdviceProvider {
-    /// Returns the current state of the advice stack.
-    pub fn stack(&self) -> &[Felt] {
-        &self.provider.stack
-    }
-
     /// Returns the current state of the advice map.
     pub fn map(&self) -> &SimpleAdviceMap {
         &self.provider.map
@@ -270,6 +275,10 @@ impl MemAdviceProvider {
 /// TODO: potentially do this via a macro.
 #[rustfmt::skip]
 impl AdviceProvider for MemAdviceProvider {
+    fn stack(&self) -> &[Felt] {
+        self.provider.stack()
+    }
+
     fn pop_stack<S: ProcessState>(&mut self, process: &S)-> Result<Felt, ExecutionError> {
         self.provider.pop_stack(process)
     }
@@ -294,7 +303,7 @@ impl AdviceProvider for MemAdviceProvider {
         self.provider.get_signature(kind, pub_key, msg)
     }
 
-    fn get_mapped_values(&self, key: &RpoDigest) -> Option<&[Felt]> {
+    fn get_mapped_values(&self, key: &RpoDigest) -> Option<Vec<Felt>> {
         self.provider.get_mapped_values(key)
     }
 
@@ -365,11 +374,6 @@ impl From<AdviceInputs> for RecAdviceProvider {
 /// Accessors to internal data structures of the provider used for testing purposes.
 #[cfg(any(test, feature = "internals"))]
 impl RecAdviceProvider {
-    /// Returns the current state of the advice stack.
-    pub fn stack(&self) -> &[Felt] {
-        &self.provider.stack
-    }
-
     /// Returns the current state of the advice map.
     pub fn map(&self) -> &RecordingAdviceMap {
         &self.provider.map
@@ -391,6 +395,10 @@ impl RecAdviceProvider {
 /// TODO: potentially do this via a macro.
 #[rustfmt::skip]
 impl AdviceProvider for RecAdviceProvider {
+    fn stack(&self) -> &[Felt] {
+        self.provider.stack()
+    }
+
     fn pop_stack<S: ProcessState>(&mut self, process: &S) -> Result<Felt, ExecutionError> {
         self.provider.pop_stack(process)
     }
@@ -415,7 +423,7 @@ impl AdviceProvider for RecAdviceProvider {
         self.provider.get_signature(kind, pub_key, msg)
     }
 
-    fn get_mapped_values(&self, key: &RpoDigest) -> Option<&[Felt]> {
+    fn get_mapped_values(&self, key: &RpoDigest) -> Option<Vec<Felt>> {
         self.provider.get_mapped_values(key)
     }
 
@@ -478,3 +486,557 @@ impl RecAdviceProvider {
         (proof, stack, map, store.into())
     }
 }
+
+// PERSISTENT ADVICE PROVIDER
+// ================================================================================================
+
+/// An [AdviceProvider] which serves the advice map and the Merkle store from an [AdviceStorage]
+/// backend, while keeping only the advice stack in memory.
+///
+/// This allows executing programs against advice data which does not fit into memory (e.g.,
+/// multi-GB state trees) without loading the data before execution starts. Merkle store nodes
+/// read from the backend are kept in an LRU cache, and all writes are passed through to the
+/// backend.
+#[derive(Debug)]
+pub struct PersistentAdviceProvider<T: AdviceStorage> {
+    stack: Vec<Felt>,
+    storage: T,
+    cache: RefCell<NodeCache>,
+}
+
+impl<T: AdviceStorage> PersistentAdviceProvider<T> {
+    /// Default number of Merkle store nodes kept in the node cache.
+    pub const DEFAULT_CACHE_CAPACITY: usize = 1 << 16;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [PersistentAdviceProvider] serving advice data from the specified storage.
+    ///
+    /// The nodes of empty subtrees are inserted into the storage so that trees with empty
+    /// subtrees can be traversed.
+    ///
+    /// # Errors
+    /// Returns an error if the nodes of empty subtrees could not be stored.
+    pub fn new(mut storage: T) -> Result<Self, ExecutionError> {
+        storage
+            .import(Vec::new(), &MerkleStore::default())
+            .map_err(ExecutionError::AdviceStorageFailed)?;
+        Ok(Self {
+            stack: Vec::new(),
+            storage,
+            cache: RefCell::new(NodeCache::new(Self::DEFAULT_CACHE_CAPACITY)),
+        })
+    }
+
+    /// Returns a new [PersistentAdviceProvider] with the specified advice stack serving advice data
+    /// from the specified storage, assuming that the nodes of empty subtrees are already stored.
+    pub(super) fn from_parts(storage: T, stack: Vec<Felt>) -> Self {
+        Self {
+            stack,
+            storage,
+            cache: RefCell::new(NodeCache::new(Self::DEFAULT_CACHE_CAPACITY)),
+        }
+    }
+
+    /// Sets the maximum number of Merkle store nodes kept in the node cache.
+    pub fn with_cache_capacity(self, capacity: usize) -> Self {
+        Self {
+            cache: RefCell::new(NodeCache::new(capacity)),
+            ..self
+        }
+    }
+
+    /// Extends the advice stack with the given elements.
+    ///
+    /// The first element is placed at the top of the advice stack, consistent with
+    /// [AdviceInputs::with_stack()].
+    pub fn with_stack<I>(mut self, iter: I) -> Self
+    where
+        I: IntoIterator<Item = Felt>,
+    {
+        let mut values = iter.into_iter().collect::<Vec<_>>();
+        values.reverse();
+        values.extend_from_slice(&self.stack);
+        self.stack = values;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the storage from which this provider serves the advice map and the Merkle store.
+    pub fn storage(&self) -> &T {
+        &self.storage
+    }
+
+    /// Consumes this provider and returns its storage.
+    pub fn into_storage(self) -> T {
+        self.storage
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the children of the node with the specified hash, or `None` if the node is a leaf
+    /// or is not present in the storage.
+    fn get_children(&self, hash: RpoDigest) -> Result<Option<[RpoDigest; 2]>, ExecutionError> {
+        if let Some(children) = self.cache.borrow_mut().get(&hash) {
+            return Ok(Some(children));
+        }
+        let children = self.storage.get_node(&hash).map_err(ExecutionError::AdviceStorageFailed)?;
+        if let Some(children) = children {
+            self.cache.borrow_mut().insert(hash, children);
+        }
+        Ok(children)
+    }
+
+    /// Stores the node with the specified children and returns its hash.
+    fn insert_node(&mut self, children: [RpoDigest; 2]) -> Result<RpoDigest, ExecutionError> {
+        let hash = Rpo256::merge(&children);
+        self.storage
+            .insert_node(hash, children)
+            .map_err(ExecutionError::AdviceStorageFailed)?;
+        self.cache.get_mut().insert(hash, children);
+        Ok(hash)
+    }
+
+    /// Returns the hash of the node at the specified index in the tree with the specified root,
+    /// together with the hashes of the siblings of the nodes on the path to it, ordered from the
+    /// root to the node.
+    fn traverse(
+        &self,
+        root: RpoDigest,
+        index: NodeIndex,
+    ) -> Result<(RpoDigest, Vec<RpoDigest>), ExecutionError> {
+        if self.get_children(root)?.is_none() {
+            return Err(ExecutionError::MerkleStoreLookupFailed(MerkleError::RootNotInStore(root)));
+        }
+
+        let mut hash = root;
+        let mut siblings = Vec::with_capacity(index.depth() as usize);
+        for level in (0..index.depth()).rev() {
+            let children = self.get_children(hash)?.ok_or(
+                ExecutionError::MerkleStoreLookupFailed(MerkleError::NodeNotInStore(hash, index)),
+            )?;
+            let is_right = ((index.value() >> level) & 1) as usize;
+            siblings.push(children[1 - is_right]);
+            hash = children[is_right];
+        }
+        Ok((hash, siblings))
+    }
+}
+
+impl<T: AdviceStorage> AdviceProvider for PersistentAdviceProvider<T> {
+    // ADVICE STACK
+    // --------------------------------------------------------------------------------------------
+
+    fn stack(&self) -> &[Felt] {
+        &self.stack
+    }
+
+    fn pop_stack<P: ProcessState>(&mut self, process: &P) -> Result<Felt, ExecutionError> {
+        self.stack.pop().ok_or(ExecutionError::AdviceStackReadFailed(process.clk()))
+    }
+
+    fn pop_stack_word<P: ProcessState>(&mut self, process: &P) -> Result<Word, ExecutionError> {
+        if self.stack.len() < 4 {
+            return Err(ExecutionError::AdviceStackReadFailed(process.clk()));
+        }
+
+        let idx = self.stack.len() - 4;
+        let result =
+            [self.stack[idx + 3], self.stack[idx + 2], self.stack[idx + 1], self.stack[idx]];
+
+        self.stack.truncate(idx);
+
+        Ok(result)
+    }
+
+    fn pop_stack_dword<P: ProcessState>(
+        &mut self,
+        process: &P,
+    ) -> Result<[Word; 2], ExecutionError> {
+        let word0 = self.pop_stack_word(process)?;
+        let word1 = self.pop_stack_word(process)?;
+
+        Ok([word0, word1])
+    }
+
+    fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError> {
+        match source {
+            AdviceSource::Value(value) => {
+                self.stack.push(value);
+            }
+            AdviceSource::Word(word) => {
+                self.stack.extend(word.iter().rev());
+            }
+            AdviceSource::Map { key, include_len } => {
+                let values = self
+                    .storage
+                    .get_map_values(&key.into())
+                    .map_err(ExecutionError::AdviceStorageFailed)?
+                    .ok_or(ExecutionError::AdviceMapKeyNotFound(key))?;
+
+                self.stack.extend(values.iter().rev());
+                if include_len {
+                    self.stack
+                        .push(Felt::try_from(values.len() as u64).expect("value length too big"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_signature(
+        &self,
+        kind: SignatureKind,
+        pub_key: Word,
+        msg: Word,
+    ) -> Result<Vec<Felt>, ExecutionError> {
+        let pk_sk = self
+            .storage
+            .get_map_values(&pub_key.into())
+            .map_err(ExecutionError::AdviceStorageFailed)?
+            .ok_or(ExecutionError::AdviceMapKeyNotFound(pub_key))?;
+
+        match kind {
+            SignatureKind::RpoFalcon512 => injectors::dsa::falcon_sign(&pk_sk, msg),
+        }
+    }
+
+    // ADVICE MAP
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the values associated with the specified key in the storage.
+    ///
+    /// Failures of the storage backend are reported as missing values.
+    fn get_mapped_values(&self, key: &RpoDigest) -> Option<Vec<Felt>> {
+        self.storage.get_map_values(key).ok().flatten()
+    }
+
+    fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
+        self.storage
+            .insert_map_values(key.into(), values)
+            .map_err(ExecutionError::AdviceStorageFailed)
+    }
+
+    // MERKLE STORE
+    // --------------------------------------------------------------------------------------------
+
+    fn get_tree_node(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<Word, ExecutionError> {
+        let index = NodeIndex::from_elements(depth, index).map_err(|_| {
+            ExecutionError::InvalidTreeNodeIndex {
+                depth: *depth,
+                value: *index,
+            }
+        })?;
+        self.traverse(root.into(), index).map(|(node, _)| node.into())
+    }
+
+    fn get_merkle_path(
+        &self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+    ) -> Result<MerklePath, ExecutionError> {
+        let index = NodeIndex::from_elements(depth, index).map_err(|_| {
+            ExecutionError::InvalidTreeNodeIndex {
+                depth: *depth,
+                value: *index,
+            }
+        })?;
+        let (_, mut siblings) = self.traverse(root.into(), index)?;
+        siblings.reverse();
+        Ok(MerklePath::new(siblings))
+    }
+
+    fn get_leaf_depth(
+        &self,
+        root: Word,
+        tree_depth: &Felt,
+        index: &Felt,
+    ) -> Result<u8, ExecutionError> {
+        let tree_depth = u8::try_from(tree_depth.as_int())
+            .map_err(|_| ExecutionError::InvalidTreeDepth { depth: *tree_depth })?;
+        if tree_depth == 0 {
+            return Err(ExecutionError::MerkleStoreLookupFailed(MerkleError::DepthTooSmall(
+                tree_depth,
+            )));
+        }
+        if tree_depth > 64 {
+            return Err(ExecutionError::MerkleStoreLookupFailed(MerkleError::DepthTooBig(
+                tree_depth as u64,
+            )));
+        }
+        let index = index.as_int();
+        NodeIndex::new(tree_depth, index).map_err(ExecutionError::MerkleStoreLookupFailed)?;
+
+        let root = RpoDigest::from(root);
+        if self.get_children(root)?.is_none() {
+            return Err(ExecutionError::MerkleStoreLookupFailed(MerkleError::RootNotInStore(root)));
+        }
+
+        // traverse the tree from the root until a leaf or the root of an empty subtree is found
+        let empty_roots = EmptySubtreeRoots::empty_hashes(tree_depth);
+        let mut hash = root;
+        for depth in 0..=tree_depth {
+            if hash == empty_roots[depth as usize] {
+                return Ok(depth);
+            }
+            let children = match self.get_children(hash)? {
+                Some(children) => children,
+                None => return Ok(depth),
+            };
+            let is_right = depth < tree_depth && (index >> (tree_depth - depth - 1)) & 1 == 1;
+            hash = children[is_right as usize];
+        }
+
+        Err(ExecutionError::MerkleStoreLookupFailed(MerkleError::DepthTooBig(
+            tree_depth as u64 + 1,
+        )))
+    }
+
+    fn update_merkle_node(
+        &mut self,
+        root: Word,
+        depth: &Felt,
+        index: &Felt,
+        value: Word,
+    ) -> Result<(MerklePath, Word), ExecutionError> {
+        let node_index = NodeIndex::from_elements(depth, index).map_err(|_| {
+            ExecutionError::InvalidTreeNodeIndex {
+                depth: *depth,
+                value: *index,
+            }
+        })?;
+        let (_, mut siblings) =
+            self.traverse(root.into(), node_index).map_err(|err| match err {
+                ExecutionError::MerkleStoreLookupFailed(err) => {
+                    ExecutionError::MerkleStoreUpdateFailed(err)
+                }
+                err => err,
+            })?;
+        siblings.reverse();
+
+        // insert the nodes on the path from the updated node to the new root; the nodes of the
+        // original tree are retained
+        let mut hash = RpoDigest::from(value);
+        let mut position = node_index.value();
+        for &sibling in siblings.iter() {
+            let children = if position & 1 == 0 {
+                [hash, sibling]
+            } else {
+                [sibling, hash]
+            };
+            hash = self.insert_node(children)?;
+            position >>= 1;
+        }
+
+        Ok((MerklePath::new(siblings), hash.into()))
+    }
+
+    fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError> {
+        self.insert_node([lhs.into(), rhs.into()]).map(|root| root.into())
+    }
+
+    /// Returns a Merkle store containing all nodes which are descendants of the specified roots.
+    ///
+    /// Nodes which could not be read from the storage are omitted.
+    fn get_store_subset<I, R>(&self, roots: I) -> MerkleStore
+    where
+        I: Iterator<Item = R>,
+        R: core::borrow::Borrow<RpoDigest>,
+    {
+        let mut visited = BTreeSet::new();
+        let mut pending = roots.map(|root| *root.borrow()).collect::<Vec<_>>();
+        let mut nodes = Vec::new();
+        while let Some(hash) = pending.pop() {
+            if !visited.insert(hash) {
+                continue;
+            }
+            if let Ok(Some([left, right])) = self.get_children(hash) {
+                nodes.push(InnerNodeInfo {
+                    value: hash,
+                    left,
+                    right,
+                });
+                pending.push(left);
+                pending.push(right);
+            }
+        }
+
+        let mut store = MerkleStore::default();
+        store.extend(nodes);
+        store
+    }
+}
+
+/// A copy-on-write fork of an advice provider produced by [AdviceProvider::fork()].
+pub type ForkedAdviceProvider<'a, P> = PersistentAdviceProvider<ForkedAdviceStorage<'a, P>>;
+
+// NODE CACHE
+// ================================================================================================
+
+/// A cache of Merkle store nodes which evicts the least recently used node once its capacity is
+/// exceeded.
+#[derive(Debug)]
+struct NodeCache {
+    capacity: usize,
+    clock: u64,
+    nodes: BTreeMap<RpoDigest, ([RpoDigest; 2], u64)>,
+    recency: BTreeMap<u64, RpoDigest>,
+}
+
+impl NodeCache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            clock: 0,
+            nodes: BTreeMap::new(),
+            recency: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the children of the node with the specified hash and marks the node as the most
+    /// recently used one.
+    fn get(&mut self, hash: &RpoDigest) -> Option<[RpoDigest; 2]> {
+        let (children, last_used) = self.nodes.get_mut(hash)?;
+        self.recency.remove(last_used);
+        self.clock += 1;
+        *last_used = self.clock;
+        self.recency.insert(self.clock, *hash);
+        Some(*children)
+    }
+
+    /// Inserts the node into the cache, evicting the least recently used node if the cache is
+    /// full.
+    fn insert(&mut self, hash: RpoDigest, children: [RpoDigest; 2]) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        self.clock += 1;
+        if let Some((_, last_used)) = self.nodes.insert(hash, (children, self.clock)) {
+            self.recency.remove(&last_used);
+        }
+        self.recency.insert(self.clock, hash);
+
+        if self.nodes.len() > self.capacity {
+            if let Some((_, evicted)) = self.recency.pop_first() {
+                self.nodes.remove(&evicted);
+            }
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AdviceInputs, AdviceProvider, AdviceSource, AdviceStorage, Felt, MemAdviceProvider,
+        MerkleStore, NodeIndex, PersistentAdviceProvider, RpoDigest, Word,
+    };
+    use crate::{crypto::MerkleTree, MemAdviceStorage};
+    use alloc::vec::Vec;
+    use vm_core::{ONE, ZERO};
+
+    #[test]
+    fn persistent_provider_matches_mem_provider() {
+        let leaves: Vec<Word> = (0..8).map(|i| [Felt::new(i), ONE, ONE, ONE]).collect();
+        let tree = MerkleTree::new(leaves).unwrap();
+        let store = MerkleStore::from(&tree);
+        let key = RpoDigest::from([ONE; 4]);
+        let values = vec![Felt::new(3), Felt::new(5)];
+
+        let inputs = AdviceInputs::default()
+            .with_merkle_store(store.clone())
+            .with_map([(key, values.clone())]);
+        let mut expected = MemAdviceProvider::from(inputs);
+
+        // a cache of only two nodes forces most lookups to go to the storage
+        let mut storage = MemAdviceStorage::new();
+        storage.import([(key, values.clone())], &store).unwrap();
+        let mut provider = PersistentAdviceProvider::new(storage).unwrap().with_cache_capacity(2);
+
+        assert_eq!(provider.get_mapped_values(&key), Some(values));
+        assert_eq!(provider.get_mapped_values(&RpoDigest::default()), None);
+
+        let root: Word = tree.root().into();
+        let depth = Felt::new(3);
+        for index in 0..8 {
+            let index = Felt::new(index);
+            assert_eq!(
+                provider.get_tree_node(root, &depth, &index).unwrap(),
+                expected.get_tree_node(root, &depth, &index).unwrap()
+            );
+            assert_eq!(
+                provider.get_merkle_path(root, &depth, &index).unwrap(),
+                expected.get_merkle_path(root, &depth, &index).unwrap()
+            );
+        }
+
+        // updates produce the same paths and roots, and keep the original tree available
+        let index = Felt::new(5);
+        let value = [Felt::new(42); 4];
+        let (path, new_root) = provider.update_merkle_node(root, &depth, &index, value).unwrap();
+        assert_eq!(
+            (path, new_root),
+            expected.update_merkle_node(root, &depth, &index, value).unwrap()
+        );
+        assert_eq!(provider.get_tree_node(new_root, &depth, &index).unwrap(), value);
+        assert_eq!(
+            provider.get_tree_node(root, &depth, &index).unwrap(),
+            expected.get_tree_node(root, &depth, &index).unwrap()
+        );
+
+        let merged = provider.merge_roots(root, new_root).unwrap();
+        assert_eq!(merged, expected.merge_roots(root, new_root).unwrap());
+        assert_eq!(provider.get_tree_node(merged, &Felt::new(4), &Felt::new(13)).unwrap(), value);
+
+        // lookups of unknown roots fail
+        assert!(provider.get_tree_node([ONE; 4], &depth, &index).is_err());
+    }
+
+    #[test]
+    fn forked_provider_discards_changes() {
+        let leaves: Vec<Word> = (0..4).map(|i| [Felt::new(i), ONE, ONE, ONE]).collect();
+        let tree = MerkleTree::new(leaves).unwrap();
+        let inputs = AdviceInputs::default()
+            .with_stack([Felt::new(1), Felt::new(2)])
+            .with_merkle_store(MerkleStore::from(&tree));
+        let mut provider = MemAdviceProvider::from(inputs);
+
+        let root: Word = tree.root().into();
+        let depth = Felt::new(2);
+        let index = Felt::new(3);
+        let value = [Felt::new(42); 4];
+        let key = RpoDigest::from([ONE; 4]);
+        {
+            let mut fork = provider.fork();
+            fork.push_stack(AdviceSource::Value(Felt::new(3))).unwrap();
+            assert_eq!(fork.stack(), &[Felt::new(2), Felt::new(1), Felt::new(3)]);
+            fork.insert_into_map(key.into(), vec![Felt::new(7)]).unwrap();
+            let (path, new_root) = fork.update_merkle_node(root, &depth, &index, value).unwrap();
+            assert_eq!(path, tree.get_path(NodeIndex::new(2, 3).unwrap()).unwrap());
+            assert_eq!(fork.get_tree_node(new_root, &depth, &index).unwrap(), value);
+            assert_eq!(fork.get_tree_node(root, &depth, &Felt::new(0)).unwrap()[0], ZERO);
+            assert_eq!(fork.get_mapped_values(&key), Some(vec![Felt::new(7)]));
+            assert_eq!(fork.storage().changes().num_nodes(), 2);
+        }
+
+        // none of the changes made via the fork are visible in the original provider
+        assert_eq!(provider.stack(), &[Felt::new(2), Felt::new(1)]);
+        assert_eq!(provider.get_mapped_values(&key), None);
+        let new_root = provider.update_merkle_node(root, &depth, &index, value).unwrap().1;
+        assert_eq!(provider.get_tree_node(new_root, &depth, &index).unwrap(), value);
+    }
+}
@@ -0,0 +1,251 @@
+use super::{AdviceProvider, Felt, InnerNodeInfo, MerkleStore, RpoDigest};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use vm_core::{ONE, ZERO};
+
+#[cfg(feature = "sled")]
+use alloc::string::ToString;
+#[cfg(feature = "sled")]
+use vm_core::utils::{Deserializable, Serializable};
+
+// ADVICE STORAGE
+// ================================================================================================
+
+/// Defines a storage backend for the advice map and the Merkle store of a
+/// [PersistentAdviceProvider](super::PersistentAdviceProvider).
+///
+/// Merkle store nodes are stored as the hashes of their children keyed by the hash of the node
+/// itself. Leaves of Merkle trees are not stored as nodes, as they have no children.
+///
+/// Implementing this trait allows serving advice data which does not fit into memory from a
+/// database (e.g., RocksDB or sled). The backend is never required to remove entries, as the
+/// advice provider retains both the original and the updated versions of Merkle trees.
+pub trait AdviceStorage {
+    /// Returns the values stored in the advice map under the specified key.
+    fn get_map_values(&self, key: &RpoDigest) -> Result<Option<Vec<Felt>>, String>;
+
+    /// Stores the specified values in the advice map under the specified key, replacing the
+    /// values previously stored under the key.
+    fn insert_map_values(&mut self, key: RpoDigest, values: Vec<Felt>) -> Result<(), String>;
+
+    /// Returns the hashes of the left and right children of the Merkle store node with the
+    /// specified hash.
+    fn get_node(&self, hash: &RpoDigest) -> Result<Option<[RpoDigest; 2]>, String>;
+
+    /// Stores a Merkle store node with the specified hash and the specified children.
+    fn insert_node(&mut self, hash: RpoDigest, children: [RpoDigest; 2]) -> Result<(), String>;
+
+    /// Stores all entries of the specified advice map and all nodes of the specified Merkle store.
+    fn import<M>(&mut self, map: M, store: &MerkleStore) -> Result<(), String>
+    where
+        M: IntoIterator<Item = (RpoDigest, Vec<Felt>)>,
+    {
+        for (key, values) in map {
+            self.insert_map_values(key, values)?;
+        }
+        for InnerNodeInfo { value, left, right } in store.inner_nodes() {
+            self.insert_node(value, [left, right])?;
+        }
+        Ok(())
+    }
+}
+
+// MEMORY STORAGE
+// ================================================================================================
+
+/// An [AdviceStorage] which keeps all data in memory.
+#[derive(Debug, Clone, Default)]
+pub struct MemAdviceStorage {
+    map: BTreeMap<RpoDigest, Vec<Felt>>,
+    nodes: BTreeMap<RpoDigest, [RpoDigest; 2]>,
+}
+
+impl MemAdviceStorage {
+    /// Returns a new empty [MemAdviceStorage].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of Merkle store nodes in this storage.
+    pub fn num_nodes(&self) -> usize {
+        self.nodes.len()
+    }
+}
+
+impl AdviceStorage for MemAdviceStorage {
+    fn get_map_values(&self, key: &RpoDigest) -> Result<Option<Vec<Felt>>, String> {
+        Ok(self.map.get(key).cloned())
+    }
+
+    fn insert_map_values(&mut self, key: RpoDigest, values: Vec<Felt>) -> Result<(), String> {
+        self.map.insert(key, values);
+        Ok(())
+    }
+
+    fn get_node(&self, hash: &RpoDigest) -> Result<Option<[RpoDigest; 2]>, String> {
+        Ok(self.nodes.get(hash).copied())
+    }
+
+    fn insert_node(&mut self, hash: RpoDigest, children: [RpoDigest; 2]) -> Result<(), String> {
+        self.nodes.insert(hash, children);
+        Ok(())
+    }
+}
+
+// FORKED STORAGE
+// ================================================================================================
+
+/// An [AdviceStorage] which keeps all changes in memory, and reads all other data from a parent
+/// advice provider.
+///
+/// This storage backs providers returned by [AdviceProvider::fork()]. The parent provider is never
+/// modified, and thus, discarding the fork discards all changes made via it.
+#[derive(Debug)]
+pub struct ForkedAdviceStorage<'a, P: AdviceProvider> {
+    parent: &'a P,
+    changes: MemAdviceStorage,
+}
+
+impl<'a, P: AdviceProvider> ForkedAdviceStorage<'a, P> {
+    /// Returns a new [ForkedAdviceStorage] without changes on top of the specified provider.
+    pub fn new(parent: &'a P) -> Self {
+        Self {
+            parent,
+            changes: MemAdviceStorage::new(),
+        }
+    }
+
+    /// Returns the provider from which unchanged data is read.
+    pub fn parent(&self) -> &P {
+        self.parent
+    }
+
+    /// Returns the advice map entries and Merkle store nodes added via the fork.
+    pub fn changes(&self) -> &MemAdviceStorage {
+        &self.changes
+    }
+}
+
+impl<'a, P: AdviceProvider> AdviceStorage for ForkedAdviceStorage<'a, P> {
+    fn get_map_values(&self, key: &RpoDigest) -> Result<Option<Vec<Felt>>, String> {
+        match self.changes.get_map_values(key)? {
+            Some(values) => Ok(Some(values)),
+            None => Ok(self.parent.get_mapped_values(key)),
+        }
+    }
+
+    fn insert_map_values(&mut self, key: RpoDigest, values: Vec<Felt>) -> Result<(), String> {
+        self.changes.insert_map_values(key, values)
+    }
+
+    fn get_node(&self, hash: &RpoDigest) -> Result<Option<[RpoDigest; 2]>, String> {
+        if let Some(children) = self.changes.get_node(hash)? {
+            return Ok(Some(children));
+        }
+
+        // the children of a node are the nodes at depth 1 of the tree rooted at it; the parent
+        // fails to look them up only if the node is a leaf or is not in its Merkle store
+        let root = (*hash).into();
+        let left = self.parent.get_tree_node(root, &ONE, &ZERO);
+        let right = self.parent.get_tree_node(root, &ONE, &ONE);
+        match (left, right) {
+            (Ok(left), Ok(right)) => Ok(Some([left.into(), right.into()])),
+            _ => Ok(None),
+        }
+    }
+
+    fn insert_node(&mut self, hash: RpoDigest, children: [RpoDigest; 2]) -> Result<(), String> {
+        self.changes.insert_node(hash, children)
+    }
+}
+
+// SLED STORAGE
+// ================================================================================================
+
+/// An [AdviceStorage] which keeps all data in a [sled](https://docs.rs/sled) database.
+///
+/// Advice map entries and Merkle store nodes are kept in separate trees of the database, and thus,
+/// multiple providers can share a database as long as they use distinct tree names.
+#[cfg(feature = "sled")]
+#[derive(Debug, Clone)]
+pub struct SledAdviceStorage {
+    map: sled::Tree,
+    nodes: sled::Tree,
+}
+
+#[cfg(feature = "sled")]
+impl SledAdviceStorage {
+    /// Opens (or creates) a database at the specified path and returns a storage backed by it.
+    pub fn open<P: AsRef<std::path::Path>>(path: P) -> Result<Self, String> {
+        let db = sled::open(path).map_err(|err| err.to_string())?;
+        Self::with_db(&db, "advice")
+    }
+
+    /// Returns a storage backed by the trees with the specified name prefix in the database.
+    pub fn with_db(db: &sled::Db, name: &str) -> Result<Self, String> {
+        let map = db.open_tree(format!("{name}_map")).map_err(|err| err.to_string())?;
+        let nodes = db.open_tree(format!("{name}_nodes")).map_err(|err| err.to_string())?;
+        Ok(Self { map, nodes })
+    }
+
+    /// Flushes all pending writes to disk.
+    pub fn flush(&self) -> Result<(), String> {
+        self.map.flush().map_err(|err| err.to_string())?;
+        self.nodes.flush().map_err(|err| err.to_string())?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sled")]
+impl AdviceStorage for SledAdviceStorage {
+    fn get_map_values(&self, key: &RpoDigest) -> Result<Option<Vec<Felt>>, String> {
+        let values = match self.map.get(key.as_bytes()).map_err(|err| err.to_string())? {
+            Some(values) => values,
+            None => return Ok(None),
+        };
+        if values.len() % 8 != 0 {
+            return Err("malformed advice map entry".to_string());
+        }
+        values
+            .chunks(8)
+            .map(Felt::read_from_bytes)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some)
+            .map_err(|_| "malformed advice map entry".to_string())
+    }
+
+    fn insert_map_values(&mut self, key: RpoDigest, values: Vec<Felt>) -> Result<(), String> {
+        let mut bytes = Vec::with_capacity(values.len() * 8);
+        for value in values {
+            value.write_into(&mut bytes);
+        }
+        self.map
+            .insert(key.as_bytes(), bytes)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+
+    fn get_node(&self, hash: &RpoDigest) -> Result<Option<[RpoDigest; 2]>, String> {
+        let node = match self.nodes.get(hash.as_bytes()).map_err(|err| err.to_string())? {
+            Some(node) => node,
+            None => return Ok(None),
+        };
+        if node.len() != 64 {
+            return Err("malformed Merkle store node".to_string());
+        }
+        let left = RpoDigest::read_from_bytes(&node[..32]);
+        let right = RpoDigest::read_from_bytes(&node[32..]);
+        match (left, right) {
+            (Ok(left), Ok(right)) => Ok(Some([left, right])),
+            _ => Err("malformed Merkle store node".to_string()),
+        }
+    }
+
+    fn insert_node(&mut self, hash: RpoDigest, children: [RpoDigest; 2]) -> Result<(), String> {
+        let mut bytes = children[0].as_bytes().to_vec();
+        bytes.extend_from_slice(&children[1].as_bytes());
+        self.nodes
+            .insert(hash.as_bytes(), bytes)
+            .map(|_| ())
+            .map_err(|err| err.to_string())
+    }
+}
@@ -0,0 +1,230 @@
+use super::{ExecutionError, Felt, Word};
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use core::ops::Range;
+use vm_core::{
+    chiplets::hasher::Digest,
+    crypto::hash::Rpo256,
+    errors::InputError,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    ZERO,
+};
+
+// MEMORY IMAGE
+// ================================================================================================
+
+/// Contents of a set of memory addresses, such as memory loaded by a program before it starts its
+/// computation, or memory exported once a program finishes executing.
+///
+/// An image is loaded into memory by the program itself: the image is provided to the program via
+/// the advice map (see [MemoryImage::to_advice_map_entry()]), and the `std::mem::load_image`
+/// procedure writes the image into memory and checks it against its commitment (see
+/// [MemoryImage::commitment()]). Thus, the loaded memory is constrained like any other memory
+/// written by the program, and the program is bound to the image via the commitment, which is
+/// either a constant of the program or one of its public inputs.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryImage {
+    words: BTreeMap<u32, Word>,
+}
+
+impl MemoryImage {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The maximum number of words which can be exported as memory outputs of a program (see
+    /// [MemoryImage::validate_output_ranges()]).
+    pub const MAX_OUTPUT_WORDS: usize = 1 << 20;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an empty [MemoryImage].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writes the specified words to consecutive addresses starting at `start_addr`, and returns
+    /// the resulting image.
+    ///
+    /// # Errors
+    /// Returns an error if the words do not fit into the memory address space.
+    pub fn with_words(mut self, start_addr: u32, words: &[Word]) -> Result<Self, InputError> {
+        self.insert_words(start_addr, words)?;
+        Ok(self)
+    }
+
+    // MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Writes the specified words to consecutive addresses starting at `start_addr`, replacing
+    /// the words previously written to these addresses.
+    ///
+    /// # Errors
+    /// Returns an error if the words do not fit into the memory address space.
+    pub fn insert_words(&mut self, start_addr: u32, words: &[Word]) -> Result<(), InputError> {
+        let end_addr = start_addr as u64 + words.len() as u64;
+        if end_addr > u32::MAX as u64 + 1 {
+            return Err(InputError::InvalidMemoryAddress(end_addr - 1));
+        }
+
+        for (addr, word) in (start_addr..).zip(words) {
+            self.words.insert(addr, *word);
+        }
+        Ok(())
+    }
+
+    /// Checks that the specified ranges of addresses can be exported as memory outputs of a
+    /// program.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The start address of any of the ranges exceeds its end address.
+    /// - The ranges span more than [MemoryImage::MAX_OUTPUT_WORDS] words in total.
+    pub fn validate_output_ranges(ranges: &[Range<u32>]) -> Result<(), ExecutionError> {
+        let mut num_words = 0_u64;
+        for range in ranges {
+            if range.start > range.end {
+                return Err(ExecutionError::InvalidMemoryRange {
+                    start_addr: range.start as u64,
+                    end_addr: range.end as u64,
+                });
+            }
+            num_words += (range.end - range.start) as u64;
+        }
+
+        if num_words > Self::MAX_OUTPUT_WORDS as u64 {
+            return Err(ExecutionError::MemoryOutputsTooLarge {
+                num_words,
+                max_words: Self::MAX_OUTPUT_WORDS,
+            });
+        }
+        Ok(())
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the word of this image at the specified address, if any.
+    pub fn get(&self, addr: u32) -> Option<&Word> {
+        self.words.get(&addr)
+    }
+
+    /// Returns the number of words in this image.
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Returns true if this image does not contain any words.
+    pub fn is_empty(&self) -> bool {
+        self.words.is_empty()
+    }
+
+    /// Returns an iterator over the words of this image and their addresses, in the order of the
+    /// addresses.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &Word)> {
+        self.words.iter().map(|(&addr, word)| (addr, word))
+    }
+
+    /// Returns an iterator over the runs of this image, in the order of their start addresses.
+    ///
+    /// A run is a maximal sequence of words at consecutive addresses, and is returned as its start
+    /// address and its words.
+    pub fn runs(&self) -> impl Iterator<Item = (u32, Vec<Word>)> + '_ {
+        let mut words = self.words.iter().peekable();
+        core::iter::from_fn(move || {
+            let (&start_addr, &word) = words.next()?;
+            let mut run = vec![word];
+            let mut next_addr = start_addr as u64 + 1;
+            while let Some((_, &word)) = words.next_if(|(&addr, _)| addr as u64 == next_addr) {
+                run.push(word);
+                next_addr += 1;
+            }
+            Some((start_addr, run))
+        })
+    }
+
+    /// Returns a commitment to this image.
+    ///
+    /// The image is split into runs of words at consecutive addresses (see [MemoryImage::runs()]),
+    /// and every run is committed to by the RPO hash of its words. The commitment to the image is
+    /// computed as an RPO hash of every run header [start_addr, num_words, 0, 0] followed by the
+    /// commitment to the run, in the order of the start addresses. This is the commitment checked
+    /// by the `std::mem::load_image` procedure.
+    pub fn commitment(&self) -> Digest {
+        let elements = self
+            .runs()
+            .flat_map(|(start_addr, words)| {
+                let mut elements = run_header(start_addr, &words).to_vec();
+                elements.extend_from_slice(run_commitment(&words).as_elements());
+                elements
+            })
+            .collect::<Vec<_>>();
+        Rpo256::hash_elements(&elements)
+    }
+
+    /// Returns the entry of the advice map from which the `std::mem::load_image` procedure loads
+    /// this image into memory.
+    ///
+    /// The entry is keyed by the commitment to the image, and its value consists of the number of
+    /// runs in the image followed by every run, each encoded as its header, its commitment, and
+    /// its words (see [MemoryImage::commitment()]).
+    pub fn to_advice_map_entry(&self) -> (Digest, Vec<Felt>) {
+        let mut values = Vec::with_capacity(self.words.len() * 4 + 1);
+        values.push(ZERO);
+        let mut num_runs = 0_u64;
+        for (start_addr, words) in self.runs() {
+            values.extend_from_slice(&run_header(start_addr, &words));
+            values.extend_from_slice(run_commitment(&words).as_elements());
+            values.extend(words.iter().flatten());
+            num_runs += 1;
+        }
+        values[0] = Felt::new(num_runs);
+        (self.commitment(), values)
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the header of a run starting at the specified address, padded to a word.
+fn run_header(start_addr: u32, words: &[Word]) -> Word {
+    [Felt::from(start_addr), Felt::new(words.len() as u64), ZERO, ZERO]
+}
+
+/// Returns the RPO hash of the words of a run, as computed by `std::mem::pipe_preimage_to_memory`.
+fn run_commitment(words: &[Word]) -> Digest {
+    Rpo256::hash_elements(&words.iter().flatten().copied().collect::<Vec<_>>())
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for MemoryImage {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.words.len());
+        for (&addr, word) in self.words.iter() {
+            target.write_u32(addr);
+            target.write_many(word);
+        }
+    }
+}
+
+impl Deserializable for MemoryImage {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_words = source.read_usize()?;
+
+        // the number of words is not used to pre-allocate memory, as it cannot be trusted
+        let mut words = BTreeMap::new();
+        for _ in 0..num_words {
+            let addr = source.read_u32()?;
+            let word = source.read_many::<Felt>(4)?;
+            let word: Word = word.try_into().expect("four elements were read");
+            if words.insert(addr, word).is_some() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "address {addr} is present in the image more than once"
+                )));
+            }
+        }
+
+        Ok(Self { words })
+    }
+}
@@ -1,9 +1,22 @@
 use super::{ExecutionError, Felt, ProcessState};
 use crate::MemAdviceProvider;
-use vm_core::{crypto::merkle::MerklePath, AdviceInjector, DebugOptions, Word};
+use alloc::{boxed::Box, collections::BTreeMap, string::ToString, sync::Arc, vec::Vec};
+use core::ops::Range;
+use vm_core::{
+    chiplets::hasher::Digest, code_blocks::CodeBlock, crypto::merkle::MerklePath, AdviceInjector,
+    DebugOptions, ErrorCodeRegistry, MastStore, Word,
+};
 
 pub(super) mod advice;
-use advice::{AdviceExtractor, AdviceProvider};
+use advice::{get_signed_mem_message, AdviceExtractor, AdviceProvider, AdviceSource};
+
+mod memory;
+pub use memory::MemoryImage;
+
+mod signer;
+#[cfg(feature = "std")]
+pub use signer::FalconSigner;
+pub use signer::Signer;
 
 #[cfg(feature = "std")]
 mod debug;
@@ -88,13 +101,7 @@ pub trait Host {
         process: &S,
         trace_id: u32,
     ) -> Result<HostResponse, ExecutionError> {
-        #[cfg(feature = "std")]
-        std::println!(
-            "Trace with id {} emitted at step {} in context {}",
-            trace_id,
-            process.clk(),
-            process.ctx()
-        );
+        print_trace(process, trace_id);
         Ok(HostResponse::None)
     }
 
@@ -107,6 +114,24 @@ pub trait Host {
         }
     }
 
+    /// Returns the code block with the specified hash.
+    ///
+    /// This is invoked when a procedure called by the program (or the target of a dynamic call)
+    /// is not in the code block table of the program, and allows the host to load the code of
+    /// called procedures on demand (e.g., from a [MastStore]).
+    fn get_code_block(&mut self, _hash: Digest) -> Option<CodeBlock> {
+        None
+    }
+
+    /// Returns the ranges of addresses in the memory of the root context whose contents are
+    /// exported as memory outputs once the program finishes executing.
+    ///
+    /// The ranges are checked via [MemoryImage::validate_output_ranges()] before the program is
+    /// executed.
+    fn memory_output_ranges(&self) -> &[Range<u32>] {
+        &[]
+    }
+
     /// Pops an element from the advice stack and returns it.
     ///
     /// # Errors
@@ -209,6 +234,14 @@ where
     fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
         H::on_assert_failed(self, process, err_code)
     }
+
+    fn get_code_block(&mut self, hash: Digest) -> Option<CodeBlock> {
+        H::get_code_block(self, hash)
+    }
+
+    fn memory_output_ranges(&self) -> &[Range<u32>] {
+        H::memory_output_ranges(self)
+    }
 }
 
 // HOST RESPONSE
@@ -260,25 +293,112 @@ impl From<HostResponse> for Felt {
     }
 }
 
+// TRACE HANDLER
+// ================================================================================================
+
+/// A callback invoked by [DefaultHost] whenever a trace with a given id is emitted via a
+/// `trace.<id>` instruction.
+///
+/// The callback is provided with the current state of the VM and the id of the trace. Since traces
+/// are decorators, they affect neither the hash of the program nor its proof; the callback may
+/// inspect the state of the VM, but cannot modify it.
+pub type TraceHandler = Box<dyn FnMut(&dyn ProcessState, u32) -> Result<(), ExecutionError> + Send>;
+
 // DEFAULT HOST IMPLEMENTATION
 // ================================================================================================
 
 /// A default [Host] implementation that provides the essential functionality required by the VM.
+///
+/// If an error code registry is attached to the host via [DefaultHost::with_error_codes()], error
+/// codes of failed assertions are decoded into the names of the errors declared by libraries.
+///
+/// If a MAST store is attached to the host via [DefaultHost::with_mast_store()], code of called
+/// procedures missing from the program is loaded from the store.
+///
+/// Ranges of memory exported once the program finishes executing are specified via
+/// [DefaultHost::with_memory_outputs()].
+///
+/// If a signer is attached to the host via [DefaultHost::with_signer()], signatures requested via
+/// `adv.push_sig` and `adv.push_sig_mem` instructions are generated by the signer rather than
+/// with secret keys stored in the advice provider.
+///
+/// Traces emitted via `trace.<id>` instructions are dispatched to the handlers registered via
+/// [DefaultHost::with_trace_handler()]; traces with ids for which no handler is registered are
+/// printed to stdout. Note that traces are emitted only when tracing is enabled in the execution
+/// options.
 pub struct DefaultHost<A> {
     adv_provider: A,
+    error_codes: ErrorCodeRegistry,
+    mast_store: Option<Arc<dyn MastStore + Send + Sync>>,
+    memory_outputs: Vec<Range<u32>>,
+    signer: Option<Arc<dyn Signer + Send + Sync>>,
+    trace_handlers: BTreeMap<u32, TraceHandler>,
 }
 
 impl Default for DefaultHost<MemAdviceProvider> {
     fn default() -> Self {
-        Self {
-            adv_provider: MemAdviceProvider::default(),
-        }
+        Self::new(MemAdviceProvider::default())
     }
 }
 
 impl<A: AdviceProvider> DefaultHost<A> {
     pub fn new(adv_provider: A) -> Self {
-        Self { adv_provider }
+        Self {
+            adv_provider,
+            error_codes: ErrorCodeRegistry::default(),
+            mast_store: None,
+            memory_outputs: Vec::new(),
+            signer: None,
+            trace_handlers: BTreeMap::new(),
+        }
+    }
+
+    /// Attaches the specified error code registry (usually, the one of the executed program) to
+    /// this host.
+    pub fn with_error_codes(mut self, error_codes: ErrorCodeRegistry) -> Self {
+        self.error_codes = error_codes;
+        self
+    }
+
+    /// Attaches the specified MAST store to this host; the store can be shared between many hosts.
+    pub fn with_mast_store(mut self, store: Arc<dyn MastStore + Send + Sync>) -> Self {
+        self.mast_store = Some(store);
+        self
+    }
+
+    /// Sets the ranges of addresses in the memory of the root context whose contents are exported
+    /// as memory outputs once the program finishes executing (see
+    /// `ExecutionTrace::memory_outputs()`).
+    ///
+    /// # Errors
+    /// Returns an error if the ranges cannot be exported, as described in
+    /// [MemoryImage::validate_output_ranges()].
+    pub fn with_memory_outputs<I>(mut self, ranges: I) -> Result<Self, ExecutionError>
+    where
+        I: IntoIterator<Item = Range<u32>>,
+    {
+        let ranges = ranges.into_iter().collect::<Vec<_>>();
+        MemoryImage::validate_output_ranges(&ranges)?;
+        self.memory_outputs = ranges;
+        Ok(self)
+    }
+
+    /// Attaches the specified signer to this host; signatures requested by the program are
+    /// generated by the signer, and thus, secret keys do not need to be loaded into the advice
+    /// provider.
+    pub fn with_signer(mut self, signer: Arc<dyn Signer + Send + Sync>) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Registers a handler invoked whenever a trace with the specified id is emitted, replacing
+    /// the handler previously registered for this id, if any.
+    pub fn with_trace_handler<F>(mut self, trace_id: u32, handler: F) -> Self
+    where
+        F: FnMut(&dyn ProcessState, u32) -> Result<(), ExecutionError> + Send + 'static,
+    {
+        self.trace_handlers.insert(trace_id, Box::new(handler));
+        self
     }
 
     #[cfg(any(test, feature = "internals"))]
@@ -310,6 +430,65 @@ impl<A: AdviceProvider> Host for DefaultHost<A> {
         process: &S,
         injector: AdviceInjector,
     ) -> Result<HostResponse, ExecutionError> {
-        self.adv_provider.set_advice(process, &injector)
+        let signature = match (&injector, &self.signer) {
+            (AdviceInjector::SigToStack { kind }, Some(signer)) => {
+                let pub_key = process.get_stack_word(0);
+                let msg = process.get_stack_word(1);
+                signer.sign(*kind, pub_key, msg)?
+            }
+            (AdviceInjector::SigMemToStack { kind }, Some(signer)) => {
+                let (pub_key, msg) = get_signed_mem_message(process)?;
+                signer.sign(*kind, pub_key, msg)?
+            }
+            _ => return self.adv_provider.set_advice(process, &injector),
+        };
+
+        for value in signature {
+            self.adv_provider.push_stack(AdviceSource::Value(value))?;
+        }
+        Ok(HostResponse::None)
+    }
+
+    fn on_trace<S: ProcessState>(
+        &mut self,
+        process: &S,
+        trace_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        match self.trace_handlers.get_mut(&trace_id) {
+            Some(handler) => handler(process, trace_id)?,
+            None => print_trace(process, trace_id),
+        }
+        Ok(HostResponse::None)
+    }
+
+    fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
+        ExecutionError::FailedAssertion {
+            clk: process.clk(),
+            err_code,
+            err_msg: self.error_codes.decode(err_code).map(|info| info.to_string()),
+        }
+    }
+
+    fn get_code_block(&mut self, hash: Digest) -> Option<CodeBlock> {
+        self.mast_store.as_ref()?.get_code_block(hash)
+    }
+
+    fn memory_output_ranges(&self) -> &[Range<u32>] {
+        &self.memory_outputs
     }
 }
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Prints the info about the emitted trace to stdout.
+#[allow(unused_variables)]
+fn print_trace<S: ProcessState>(process: &S, trace_id: u32) {
+    #[cfg(feature = "std")]
+    std::println!(
+        "Trace with id {} emitted at step {} in context {}",
+        trace_id,
+        process.clk(),
+        process.ctx()
+    );
+}
@@ -0,0 +1,83 @@
+use super::{ExecutionError, Felt};
+use alloc::vec::Vec;
+use vm_core::{SignatureKind, Word};
+
+#[cfg(feature = "std")]
+use super::advice::falcon_signature_to_advice;
+#[cfg(feature = "std")]
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use vm_core::crypto::{dsa::rpo_falcon512::SecretKey, hash::RpoDigest};
+
+// SIGNER
+// ================================================================================================
+
+/// Generates signatures requested by programs via the `adv.push_sig_mem` instruction.
+///
+/// A signer allows the host to keep secret keys outside of the advice provider: the keys are held
+/// by the signer (e.g., a wallet), and are used only to sign data which the program committed to.
+pub trait Signer {
+    /// Returns the values required for verification of a signature of the specified kind over
+    /// `msg` against the public key commitment `pub_key` in the VM.
+    ///
+    /// The values are returned in the order in which they are pushed onto the advice stack, i.e.,
+    /// the value expected at the top of the advice stack is the last value of the returned vector.
+    ///
+    /// # Errors
+    /// Returns an error if the signer does not hold the secret key associated with `pub_key`, or
+    /// if signatures of the specified kind cannot be generated.
+    fn sign(
+        &self,
+        kind: SignatureKind,
+        pub_key: Word,
+        msg: Word,
+    ) -> Result<Vec<Felt>, ExecutionError>;
+}
+
+// FALCON SIGNER
+// ================================================================================================
+
+/// A [Signer] generating RPO Falcon512 signatures with the secret keys it holds.
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub struct FalconSigner {
+    keys: BTreeMap<RpoDigest, SecretKey>,
+}
+
+#[cfg(feature = "std")]
+impl FalconSigner {
+    /// Returns a new [FalconSigner] which does not hold any keys.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the specified secret key to this signer; the key is looked up by the commitment to
+    /// its public key.
+    pub fn with_key(mut self, secret_key: SecretKey) -> Self {
+        let pub_key: Word = secret_key.public_key().into();
+        self.keys.insert(pub_key.into(), secret_key);
+        self
+    }
+}
+
+#[cfg(feature = "std")]
+impl Signer for FalconSigner {
+    fn sign(
+        &self,
+        kind: SignatureKind,
+        pub_key: Word,
+        msg: Word,
+    ) -> Result<Vec<Felt>, ExecutionError> {
+        match kind {
+            SignatureKind::RpoFalcon512 => {
+                let secret_key = self
+                    .keys
+                    .get(&pub_key.into())
+                    .ok_or(ExecutionError::SigningKeyNotFound(pub_key))?;
+                let mut result = falcon_signature_to_advice(&secret_key.sign(msg));
+                result.reverse();
+                Ok(result)
+            }
+        }
+    }
+}
@@ -24,6 +24,9 @@ where
 {
     /// Executes the specified operation.
     pub(super) fn execute_op(&mut self, op: Operation) -> Result<(), ExecutionError> {
+        // make sure the chiplets required by the operation are included into the VM profile
+        self.check_profile(op)?;
+
         // make sure there is enough memory allocated to hold the execution trace
         self.ensure_trace_capacity();
 
@@ -157,6 +160,28 @@ where
         Ok(())
     }
 
+    /// Returns an error if the specified operation requires a chiplet which is not included into
+    /// the VM profile of this process.
+    fn check_profile(&self, op: Operation) -> Result<(), ExecutionError> {
+        match op {
+            Operation::U32and | Operation::U32xor if !self.profile.has_bitwise() => {
+                Err(ExecutionError::ChipletNotInProfile("bitwise", self.system.clk()))
+            }
+            Operation::MLoad
+            | Operation::MLoadW
+            | Operation::MStore
+            | Operation::MStoreW
+            | Operation::MStream
+            | Operation::Pipe
+            | Operation::RCombBase
+                if !self.profile.has_memory() =>
+            {
+                Err(ExecutionError::ChipletNotInProfile("memory", self.system.clk()))
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Increments the clock cycle for all components of the process.
     fn advance_clock(&mut self) -> Result<(), ExecutionError> {
         self.system.advance_clock(self.max_cycles)?;
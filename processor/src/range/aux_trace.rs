@@ -3,6 +3,7 @@ use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 use miden_air::trace::main_trace::MainTrace;
 use miden_air::trace::range::{M_COL_IDX, V_COL_IDX};
+use vm_core::batch;
 
 // AUXILIARY TRACE BUILDER
 // ================================================================================================
@@ -148,27 +149,9 @@ fn get_divisors<E: FieldElement<BaseField = Felt>>(
     alpha: E,
 ) -> BTreeMap<u16, E> {
     // run batch inversion on the lookup values
-    let mut values = unsafe { uninit_vector(lookup_values.len()) };
-    let mut inv_values = unsafe { uninit_vector(lookup_values.len()) };
-    let mut log_values = BTreeMap::new();
-
-    let mut acc = E::ONE;
-    for (i, (value, inv_value)) in values.iter_mut().zip(inv_values.iter_mut()).enumerate() {
-        *inv_value = acc;
-        *value = alpha - E::from(lookup_values[i]);
-        acc *= *value;
-    }
-
-    // invert the accumulated product
-    acc = acc.inv();
+    let values = lookup_values.iter().map(|&value| alpha - E::from(value)).collect::<Vec<_>>();
+    let inv_values = batch::inv(&values);
 
-    // multiply the accumulated product by the original values to compute the inverses, then
     // build a map of inverses for the lookup values
-    for i in (0..lookup_values.len()).rev() {
-        inv_values[i] *= acc;
-        acc *= values[i];
-        log_values.insert(lookup_values[i], inv_values[i]);
-    }
-
-    log_values
+    lookup_values.iter().copied().zip(inv_values).collect()
 }
@@ -0,0 +1,214 @@
+use super::{
+    AdviceInputs, ContextId, DefaultHost, ExecutionError, ExecutionOptions, Felt,
+    MemAdviceProvider, Process, ProcessState, Program, StackInputs, StackOutputs, Word,
+};
+use alloc::{format, string::ToString, vec::Vec};
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// VM SNAPSHOT
+// ================================================================================================
+
+/// State of the VM captured at the end of a program's execution, from which a computation can be
+/// continued by executing another program, possibly in a different process.
+///
+/// This allows long-running computations to be split into a sequence of programs and checkpointed
+/// between them: the snapshot can be serialized after each program completes, and the next program
+/// of the sequence can be executed from the deserialized snapshot after a restart.
+///
+/// The snapshot consists of:
+/// - The total number of cycles executed since the start of the computation.
+/// - The state of the operand stack, including the stack overflow table.
+/// - The state of the memory of the root context. Memory of other contexts is discarded when the
+///   contexts are exited, and thus, it is not a part of the VM state at the end of a program.
+/// - The state of the advice provider, i.e., the remaining advice stack, the advice map, and the
+///   Merkle store.
+///
+/// These are program-sequence checkpoints: snapshots can be taken only at program boundaries, i.e.,
+/// when no code block is being executed, and they do not capture the state of the decoder required
+/// to checkpoint a program in the middle of its execution. Memory restored from a snapshot is not
+/// authenticated by the execution trace, and thus, executions resumed from snapshots produce stack
+/// outputs rather than execution traces, and cannot be proven.
+#[derive(Debug, Clone)]
+pub struct VmSnapshot {
+    num_cycles: u64,
+    stack: Vec<Felt>,
+    memory: Vec<(u64, Word)>,
+    advice: AdviceInputs,
+}
+
+impl VmSnapshot {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the total number of cycles executed since the start of the computation.
+    pub fn num_cycles(&self) -> u64 {
+        self.num_cycles
+    }
+
+    /// Returns the state of the operand stack, with the top of the stack at the first position.
+    pub fn stack(&self) -> &[Felt] {
+        &self.stack
+    }
+
+    /// Returns the state of the memory of the root context as a list of (address, value) tuples
+    /// sorted by address.
+    ///
+    /// Only the addresses which have been accessed at least once are included.
+    pub fn memory(&self) -> &[(u64, Word)] {
+        &self.memory
+    }
+
+    /// Returns the state of the advice provider.
+    pub fn advice(&self) -> &AdviceInputs {
+        &self.advice
+    }
+
+    // EXECUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Continues the computation captured in this snapshot by executing the specified program, and
+    /// returns the snapshot of the VM state at the end of the program's execution.
+    ///
+    /// The program is executed against the stack, the memory, and the advice provider restored from
+    /// this snapshot. The cycle limit and the VM profile are taken from the specified options, and
+    /// the limit applies to the specified program only.
+    ///
+    /// # Errors
+    /// Returns an error if the snapshot cannot be restored (e.g., the stack is too deep to be
+    /// used as stack inputs), or if the program's execution fails.
+    pub fn resume(
+        &self,
+        program: &Program,
+        options: ExecutionOptions,
+    ) -> Result<Self, ExecutionError> {
+        let stack_inputs = StackInputs::new(self.stack.iter().rev().copied().collect())
+            .map_err(|err| ExecutionError::InvalidVmSnapshot(err.to_string()))?;
+
+        let mut process = new_process(program, stack_inputs, self.advice.clone(), options);
+        for &(addr, value) in self.memory.iter() {
+            let addr =
+                u32::try_from(addr).map_err(|_| ExecutionError::MemoryAddressOutOfBounds(addr))?;
+            process.chiplets.write_mem(ContextId::root(), addr, value);
+        }
+
+        let stack_outputs = process.execute(program)?;
+        Ok(Self::capture(self.num_cycles, process, stack_outputs))
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds a snapshot of the state of the specified process which completed execution of a
+    /// program with the specified outputs.
+    fn capture(
+        prev_cycles: u64,
+        process: Process<DefaultHost<MemAdviceProvider>>,
+        stack_outputs: StackOutputs,
+    ) -> Self {
+        let num_cycles = prev_cycles + process.clk() as u64;
+        let mut memory = process.get_mem_state(ContextId::root());
+        memory.sort_by_key(|&(addr, _)| addr);
+
+        let (_, _, _, _, _, host) = process.into_parts();
+        let (mut advice_stack, advice_map, merkle_store) = host.into_inner().into_parts();
+        advice_stack.reverse();
+        let advice = AdviceInputs::default()
+            .with_stack(advice_stack)
+            .with_map(advice_map)
+            .with_merkle_store(merkle_store);
+
+        Self {
+            num_cycles,
+            stack: stack_outputs.stack().to_vec(),
+            memory,
+            advice,
+        }
+    }
+}
+
+// EXECUTOR
+// ================================================================================================
+
+/// Executes the specified program against the specified inputs, and returns the snapshot of the
+/// VM state at the end of the program's execution.
+///
+/// The computation can be continued from the returned snapshot via [VmSnapshot::resume()]. Unlike
+/// [execute()](super::execute), this function takes non-deterministic inputs as [AdviceInputs]
+/// rather than as a [Host](super::Host), as the state of the advice provider is a part of the
+/// snapshot.
+pub fn execute_to_snapshot(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+    options: ExecutionOptions,
+) -> Result<VmSnapshot, ExecutionError> {
+    let mut process = new_process(program, stack_inputs, advice_inputs, options);
+    let stack_outputs = process.execute(program)?;
+    Ok(VmSnapshot::capture(0, process, stack_outputs))
+}
+
+/// Returns a new process for executing the specified program with debugging and tracing disabled,
+/// as snapshots are intended for long-running computations.
+fn new_process(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+    options: ExecutionOptions,
+) -> Process<DefaultHost<MemAdviceProvider>> {
+    let snapshot_options =
+        ExecutionOptions::new(Some(options.max_cycles()), options.expected_cycles(), false)
+            .map(|snapshot_options| snapshot_options.with_profile(options.profile()))
+            .unwrap_or(options);
+    let options = match options.stack_depth_warning() {
+        Some(threshold) => snapshot_options.with_stack_depth_warning(threshold),
+        None => snapshot_options,
+    };
+    let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+    Process::new(program.kernel().clone(), stack_inputs, host, options)
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for VmSnapshot {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u64(self.num_cycles);
+        target.write_usize(self.stack.len());
+        target.write_many(&self.stack);
+        target.write_usize(self.memory.len());
+        for (addr, value) in self.memory.iter() {
+            target.write_u64(*addr);
+            target.write_many(value);
+        }
+        self.advice.write_into(target);
+    }
+}
+
+impl Deserializable for VmSnapshot {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_cycles = source.read_u64()?;
+        let stack_len = source.read_usize()?;
+        if stack_len > StackInputs::MAX_LEN {
+            return Err(DeserializationError::InvalidValue(format!(
+                "snapshot stack cannot be deeper than {} elements, but was {stack_len}",
+                StackInputs::MAX_LEN
+            )));
+        }
+        let stack = source.read_many::<Felt>(stack_len)?;
+        let memory_len = source.read_usize()?;
+        let mut memory = Vec::with_capacity(memory_len.min(1 << 16));
+        for _ in 0..memory_len {
+            let addr = source.read_u64()?;
+            let value = source.read_many::<Felt>(4)?;
+            memory.push((addr, [value[0], value[1], value[2], value[3]]));
+        }
+        let advice = AdviceInputs::read_from(source)?;
+
+        Ok(Self {
+            num_cycles,
+            stack,
+            memory,
+            advice,
+        })
+    }
+}
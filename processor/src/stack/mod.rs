@@ -57,6 +57,8 @@ pub struct Stack {
     overflow: OverflowTable,
     active_depth: usize,
     full_depth: usize,
+    max_depth: usize,
+    depth_warning: Option<usize>,
 }
 
 impl Stack {
@@ -91,9 +93,21 @@ impl Stack {
             overflow,
             active_depth: depth,
             full_depth: depth,
+            max_depth: depth,
+            depth_warning: None,
         }
     }
 
+    /// Sets the stack depth above which a warning is emitted; the warning is emitted only once,
+    /// when the depth of the stack exceeds the threshold for the first time.
+    ///
+    /// Items beyond the top 16 slots of the stack are kept in the overflow table, and thus, a
+    /// deep stack makes execution slower and the auxiliary trace of the stack larger.
+    pub fn with_depth_warning(mut self, threshold: usize) -> Self {
+        self.depth_warning = Some(threshold);
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -102,6 +116,12 @@ impl Stack {
         self.active_depth
     }
 
+    /// Returns the maximum depth the stack reached so far, including the items hidden from the
+    /// current execution context.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
     /// Returns the current clock cycle of the execution trace.
     pub fn current_clk(&self) -> u32 {
         self.clk
@@ -137,6 +157,20 @@ impl Stack {
         result
     }
 
+    /// Returns the rows of the overflow table at the specified clock cycle, starting with the row
+    /// holding the deepest stack item.
+    ///
+    /// # Panics
+    /// Panics if invoked for non-last clock cycle on a stack instantiated with
+    /// `keep_overflow_trace` set to false.
+    pub fn get_overflow_at(&self, clk: u32) -> Vec<OverflowTableRow> {
+        if clk == self.clk {
+            self.overflow.rows()
+        } else {
+            self.overflow.rows_at(clk as u64)
+        }
+    }
+
     /// Returns [StackOutputs] consisting of all values on the stack and all addresses in the
     /// overflow table that are required to rebuild the rows in the overflow table.
     pub fn build_stack_outputs(&self) -> StackOutputs {
@@ -245,6 +279,17 @@ impl Stack {
         // Stack depth always increases on right shift.
         self.active_depth += 1;
         self.full_depth += 1;
+        if self.full_depth > self.max_depth {
+            self.max_depth = self.full_depth;
+            if let Some(threshold) = self.depth_warning.filter(|&t| self.max_depth > t) {
+                self.depth_warning = None;
+                tracing::warn!(
+                    "stack depth exceeded {threshold} items at clock cycle {}; items beyond the \
+                    top {STACK_TOP_SIZE} are kept in the overflow table",
+                    self.clk
+                );
+            }
+        }
     }
 
     // CONTEXT MANAGEMENT
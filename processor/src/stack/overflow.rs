@@ -19,8 +19,9 @@ pub struct OverflowTable {
     /// overflow table.
     active_rows: Vec<usize>,
     /// A map which records the full state of the overflow table at every cycle during which an
-    /// update happened. This map is populated only when `trace_enabled` = true.
-    trace: BTreeMap<u64, Vec<Felt>>,
+    /// update happened. The state is recorded as a list of indices into the `all_rows` vector.
+    /// This map is populated only when `trace_enabled` = true.
+    trace: BTreeMap<u64, Vec<usize>>,
     /// A flag which specifies whether we should record the full state of the overflow table
     /// whenever an update happens. This is set to true only when executing programs for debug
     /// purposes.
@@ -165,12 +166,33 @@ impl OverflowTable {
     pub fn append_state_into(&self, target: &mut Vec<Felt>, clk: u64) {
         assert!(self.trace_enabled, "overflow trace not enabled");
         if let Some(x) = self.trace.range(0..=clk).last() {
-            for item in x.1.iter().rev() {
-                target.push(*item);
+            for &idx in x.1.iter().rev() {
+                target.push(self.all_rows[idx].val);
             }
         }
     }
 
+    /// Returns the rows currently in the overflow table, starting with the row holding the
+    /// deepest stack item.
+    ///
+    /// This includes the rows which are not accessible from the current execution context.
+    pub fn rows(&self) -> Vec<OverflowTableRow> {
+        self.active_rows.iter().map(|&idx| self.all_rows[idx]).collect()
+    }
+
+    /// Returns the rows in the overflow table at the specified clock cycle, starting with the row
+    /// holding the deepest stack item.
+    ///
+    /// # Panics
+    /// Panics when this overflow table was not initialized with `enable_trace` set to true.
+    pub fn rows_at(&self, clk: u64) -> Vec<OverflowTableRow> {
+        assert!(self.trace_enabled, "overflow trace not enabled");
+        match self.trace.range(0..=clk).last() {
+            Some((_, row_indices)) => row_indices.iter().map(|&idx| self.all_rows[idx]).collect(),
+            None => Vec::new(),
+        }
+    }
+
     /// Returns the addresses of active rows in the table required to reconstruct the table (when
     /// combined with the values). This is a vector of all of the `clk` values (the address of each
     /// row), preceded by the `prev` value in the first row of the table. (It's also equivalent to
@@ -209,8 +231,7 @@ impl OverflowTable {
     /// Saves a copy of the current table state into the trace at the specified clock cycle.
     fn save_current_state(&mut self, clk: u64) {
         debug_assert!(self.trace_enabled, "overflow table trace not enabled");
-        let current_state = self.active_rows.iter().map(|&idx| self.all_rows[idx].val).collect();
-        self.trace.insert(clk, current_state);
+        self.trace.insert(clk, self.active_rows.clone());
     }
 
     // TEST ACCESSORS
@@ -235,7 +256,7 @@ impl OverflowTable {
 /// - The clock cycle at which the stack item was pushed into the overflow table.
 /// - The clock cycle of the value which was at the top of the overflow table when this value
 ///   was pushed onto it.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct OverflowTableRow {
     val: Felt,
     clk: Felt,
@@ -246,6 +267,26 @@ impl OverflowTableRow {
     pub fn new(clk: Felt, val: Felt, prev: Felt) -> Self {
         Self { val, clk, prev }
     }
+
+    /// Returns the value of the stack item stored in this row.
+    pub fn value(&self) -> Felt {
+        self.val
+    }
+
+    /// Returns the address of this row, which is the clock cycle at which the stack item was
+    /// pushed into the overflow table.
+    ///
+    /// Rows holding the initial stack inputs beyond the top 16 elements have "negative" (mod p)
+    /// addresses, since they are added before the first execution cycle.
+    pub fn addr(&self) -> Felt {
+        self.clk
+    }
+
+    /// Returns the address of the row which was at the top of the overflow table when this row
+    /// was added, or ZERO if the table was empty in the execution context of this row.
+    pub fn prev(&self) -> Felt {
+        self.prev
+    }
 }
 
 impl OverflowTableRow {
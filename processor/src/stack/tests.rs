@@ -162,6 +162,84 @@ fn shift_right() {
     assert_eq!(stack.helpers_state(), expected_helpers);
 }
 
+// MAXIMUM DEPTH TEST
+// ================================================================================================
+
+#[test]
+fn max_depth() {
+    let stack_inputs = [1, 2, 3, 4];
+    let stack_inputs = StackInputs::try_from_ints(stack_inputs).unwrap();
+    let mut stack = Stack::new(&stack_inputs, 16, false).with_depth_warning(17);
+    assert_eq!(stack.max_depth(), 16);
+
+    // make sure the first right shift is not executed at clk = 0
+    stack.copy_state(0);
+    stack.advance_clock();
+
+    // push 2 items into the overflow table and remove one of them
+    stack.shift_right(0);
+    stack.advance_clock();
+    stack.shift_right(0);
+    stack.advance_clock();
+    stack.shift_left(1);
+    stack.advance_clock();
+    assert_eq!(stack.depth(), 17);
+    assert_eq!(stack.max_depth(), 18);
+
+    // the warning is emitted only once
+    assert_eq!(stack.depth_warning, None);
+
+    // the depth of the stack hidden by a new context counts towards the maximum depth
+    let (depth, overflow_addr) = stack.start_context();
+    stack.shift_right(0);
+    stack.advance_clock();
+    stack.shift_right(0);
+    stack.advance_clock();
+    assert_eq!(stack.depth(), 18);
+    assert_eq!(stack.max_depth(), 19);
+
+    stack.shift_left(1);
+    stack.advance_clock();
+    stack.shift_left(1);
+    stack.advance_clock();
+    stack.restore_context(depth, overflow_addr);
+    assert_eq!(stack.depth(), 17);
+    assert_eq!(stack.max_depth(), 19);
+}
+
+// OVERFLOW TABLE INTROSPECTION TEST
+// ================================================================================================
+
+#[test]
+fn get_overflow_at() {
+    let stack_inputs = (1..=16).collect::<Vec<_>>();
+    let stack_inputs = StackInputs::try_from_ints(stack_inputs).unwrap();
+    let mut stack = Stack::new(&stack_inputs, 8, true);
+
+    // make sure the first right shift is not executed at clk = 0
+    stack.copy_state(0);
+    stack.advance_clock();
+
+    // push two items into the overflow table at clk = 1 and clk = 2, and remove one at clk = 3
+    stack.shift_right(0);
+    stack.advance_clock();
+    stack.shift_right(0);
+    stack.advance_clock();
+    stack.shift_left(1);
+    stack.advance_clock();
+
+    let row1 = OverflowTableRow::new(ONE, ONE, ZERO);
+    let row2 = OverflowTableRow::new(Felt::new(2), Felt::new(2), ONE);
+
+    assert!(stack.get_overflow_at(0).is_empty());
+    assert_eq!(stack.get_overflow_at(1), vec![row1]);
+    assert_eq!(stack.get_overflow_at(2), vec![row1, row2]);
+    assert_eq!(stack.get_overflow_at(3), vec![row1]);
+    assert_eq!(stack.get_overflow_at(stack.current_clk()), vec![row1]);
+    assert_eq!(row2.value(), Felt::new(2));
+    assert_eq!(row2.prev(), row1.addr());
+}
+
 // CONTEXT MANAGEMENT TEST
 // ================================================================================================
 
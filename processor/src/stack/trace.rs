@@ -1,9 +1,10 @@
 use super::{
-    super::utils::get_trace_len, Felt, FieldElement, MAX_TOP_IDX, ONE, STACK_TRACE_WIDTH, ZERO,
+    super::{utils::get_trace_len, TraceBufferPool},
+    Felt, MAX_TOP_IDX, ONE, STACK_TRACE_WIDTH, ZERO,
 };
-use crate::utils::math::batch_inversion;
 use alloc::vec::Vec;
 use miden_air::trace::stack::{H0_COL_IDX, NUM_STACK_HELPER_COLS, STACK_TOP_SIZE};
+use vm_core::batch;
 
 // STACK TRACE
 // ================================================================================================
@@ -179,7 +180,7 @@ impl StackTrace {
 
         // compute inverses in the h0 helper column using batch inversion; any ZERO in the vector
         // will remain unchanged
-        trace[H0_COL_IDX] = batch_inversion(&trace[H0_COL_IDX]);
+        batch::inv_in_place(&mut trace[H0_COL_IDX]);
 
         trace.try_into().expect("Failed to convert vector to an array")
     }
@@ -231,7 +232,7 @@ fn init_stack_columns(
 ) -> [Vec<Felt>; STACK_TOP_SIZE] {
     let mut stack: Vec<Vec<Felt>> = Vec::with_capacity(STACK_TOP_SIZE);
     for i in 0..STACK_TOP_SIZE {
-        let mut column = Felt::zeroed_vector(init_trace_capacity);
+        let mut column = TraceBufferPool::zeroed_column(init_trace_capacity);
         if i < init_values.len() {
             column[0] = init_values[i];
         }
@@ -248,15 +249,15 @@ fn init_helper_columns(
     init_overflow_addr: Felt,
 ) -> [Vec<Felt>; NUM_STACK_HELPER_COLS] {
     // initialize b0 to the initial stack depth.
-    let mut b0 = Felt::zeroed_vector(init_trace_capacity);
+    let mut b0 = TraceBufferPool::zeroed_column(init_trace_capacity);
     b0[0] = Felt::new(init_depth as u64);
 
     // initialize b1 to the address of the last row in the stack overflow table.
-    let mut b1 = Felt::zeroed_vector(init_trace_capacity);
+    let mut b1 = TraceBufferPool::zeroed_column(init_trace_capacity);
     b1[0] = init_overflow_addr;
 
     // if the overflow table is not empty, set h0 to (init_depth - 16)
-    let mut h0 = Felt::zeroed_vector(init_trace_capacity);
+    let mut h0 = TraceBufferPool::zeroed_column(init_trace_capacity);
     // TODO: change type of `init_depth` to `u32`
     h0[0] = Felt::try_from((init_depth - STACK_TOP_SIZE) as u64)
         .expect("value is greater than or equal to the field modulus");
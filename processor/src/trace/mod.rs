@@ -2,8 +2,8 @@ use super::{
     chiplets::AuxTraceBuilder as ChipletsAuxTraceBuilder, crypto::RpoRandomCoin,
     decoder::AuxTraceBuilder as DecoderAuxTraceBuilder,
     range::AuxTraceBuilder as RangeCheckerAuxTraceBuilder,
-    stack::AuxTraceBuilder as StackAuxTraceBuilder, ColMatrix, Digest, Felt, FieldElement, Host,
-    Process, StackTopState,
+    stack::AuxTraceBuilder as StackAuxTraceBuilder, ColMatrix, ContextId, Digest, Felt,
+    FieldElement, Host, MemoryImage, OverflowTableRow, Process, StackTopState, VmProfile,
 };
 use alloc::vec::Vec;
 use miden_air::trace::{
@@ -12,16 +12,19 @@ use miden_air::trace::{
     AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, DECODER_TRACE_OFFSET, MIN_TRACE_LEN,
     STACK_TRACE_OFFSET, TRACE_WIDTH,
 };
-use vm_core::{stack::STACK_TOP_SIZE, ProgramInfo, StackOutputs, ZERO};
+use vm_core::{stack::STACK_TOP_SIZE, ProgramInfo, StackOutputs, Word, EMPTY_WORD, ZERO};
 use winter_prover::{crypto::RandomCoin, EvaluationFrame, Trace, TraceLayout};
 
+mod pool;
+pub use pool::{TraceBufferPool, TraceBufferPoolStats};
+
 mod utils;
-pub use utils::{AuxColumnBuilder, ChipletsLengths, TraceFragment, TraceLenSummary};
+pub use utils::{
+    AuxColumnBuilder, ChipletsLengths, TraceComponent, TraceFragment, TraceLenSummary,
+};
 
 #[cfg(test)]
 mod tests;
-#[cfg(test)]
-use super::EMPTY_WORD;
 
 // CONSTANTS
 // ================================================================================================
@@ -49,11 +52,16 @@ pub struct AuxTraceBuilders {
 pub struct ExecutionTrace {
     meta: Vec<u8>,
     layout: TraceLayout,
-    main_trace: MainTrace,
+    /// The main trace is only taken out of this option when the trace is dropped.
+    main_trace: Option<MainTrace>,
     aux_trace_builders: AuxTraceBuilders,
     program_info: ProgramInfo,
     stack_outputs: StackOutputs,
+    memory_outputs: MemoryImage,
     trace_len_summary: TraceLenSummary,
+    max_stack_depth: usize,
+    profile: VmProfile,
+    rand_seed: Word,
 }
 
 impl ExecutionTrace {
@@ -80,19 +88,39 @@ impl ExecutionTrace {
         // create a new program info instance with the underlying kernel
         let kernel = process.kernel().clone();
         let program_info = ProgramInfo::new(program_hash.into(), kernel);
+        let profile = process.profile;
+        let memory_outputs = build_memory_outputs(&process);
+        let max_stack_depth = process.stack.max_depth();
         let (main_trace, aux_trace_hints, trace_len_summary) = finalize_trace(process, rng);
 
         Self {
             meta: Vec::new(),
             layout: TraceLayout::new(TRACE_WIDTH, [AUX_TRACE_WIDTH], [AUX_TRACE_RAND_ELEMENTS]),
             aux_trace_builders: aux_trace_hints,
-            main_trace,
+            main_trace: Some(main_trace),
             program_info,
             stack_outputs,
+            memory_outputs,
             trace_len_summary,
+            max_stack_depth,
+            profile,
+            rand_seed: program_hash,
         }
     }
 
+    /// Re-draws the random values injected into the last rows of this trace from a random
+    /// element generator initialized with the specified seed.
+    ///
+    /// By default, the random values are drawn from a generator initialized with the program
+    /// hash. The random values injected into the auxiliary trace are drawn using the same seed.
+    pub fn reseed(&mut self, seed: Word) {
+        let main_trace = self.main_trace.take().expect("main trace was taken");
+        let mut columns = main_trace.into_columns();
+        inject_random_rows(&mut columns, RpoRandomCoin::new(seed));
+        self.main_trace = Some(MainTrace::new(ColMatrix::new(columns)));
+        self.rand_seed = seed;
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -111,11 +139,27 @@ impl ExecutionTrace {
         &self.stack_outputs
     }
 
+    /// Returns the contents of the memory of the root context at the end of the program execution
+    /// which resulted in this execution trace, restricted to the address ranges requested by the
+    /// host (see `Host::memory_output_ranges()`).
+    ///
+    /// Every address in the requested ranges is included; addresses which were never written to
+    /// hold zeros.
+    pub fn memory_outputs(&self) -> &MemoryImage {
+        &self.memory_outputs
+    }
+
+    /// Returns the set of chiplets which the program was allowed to use during the execution
+    /// which resulted in this execution trace.
+    pub fn profile(&self) -> VmProfile {
+        self.profile
+    }
+
     /// Returns the initial state of the top 16 stack registers.
     pub fn init_stack_state(&self) -> StackTopState {
         let mut result = [ZERO; STACK_TOP_SIZE];
         for (i, result) in result.iter_mut().enumerate() {
-            *result = self.main_trace.get_column(i + STACK_TRACE_OFFSET)[0];
+            *result = self.main_trace().get_column(i + STACK_TRACE_OFFSET)[0];
         }
         result
     }
@@ -125,7 +169,24 @@ impl ExecutionTrace {
         let last_step = self.last_step();
         let mut result = [ZERO; STACK_TOP_SIZE];
         for (i, result) in result.iter_mut().enumerate() {
-            *result = self.main_trace.get_column(i + STACK_TRACE_OFFSET)[last_step];
+            *result = self.main_trace().get_column(i + STACK_TRACE_OFFSET)[last_step];
+        }
+        result
+    }
+
+    /// Returns the final state of the stack overflow table, starting with the row holding the
+    /// deepest stack item.
+    pub fn last_stack_overflow(&self) -> Vec<OverflowTableRow> {
+        let overflow = self.stack_outputs.stack_overflow();
+        let mut prev = match overflow.is_empty() {
+            true => ZERO,
+            false => self.stack_outputs.overflow_prev(),
+        };
+
+        let mut result = Vec::with_capacity(overflow.len());
+        for (addr, value) in overflow {
+            result.push(OverflowTableRow::new(addr, value, prev));
+            prev = addr;
         }
         result
     }
@@ -134,14 +195,15 @@ impl ExecutionTrace {
     pub fn get_user_op_helpers_at(&self, clk: u32) -> [Felt; NUM_USER_OP_HELPERS] {
         let mut result = [ZERO; NUM_USER_OP_HELPERS];
         for (i, result) in result.iter_mut().enumerate() {
-            *result = self.main_trace.get_column(DECODER_TRACE_OFFSET + USER_OP_HELPERS_OFFSET + i)
-                [clk as usize];
+            *result = self
+                .main_trace()
+                .get_column(DECODER_TRACE_OFFSET + USER_OP_HELPERS_OFFSET + i)[clk as usize];
         }
         result
     }
 
     pub fn get_trace_len(&self) -> usize {
-        self.main_trace.num_rows()
+        self.main_trace().num_rows()
     }
 
     /// Returns a summary of the lengths of main, range and chiplet traces.
@@ -149,9 +211,23 @@ impl ExecutionTrace {
         &self.trace_len_summary
     }
 
+    /// Returns the maximum depth the stack reached during the program execution which resulted in
+    /// this execution trace.
+    ///
+    /// Stack items beyond the top 16 slots are kept in the overflow table; a maximum depth above
+    /// 16 indicates that the program made use of the overflow table.
+    pub fn max_stack_depth(&self) -> usize {
+        self.max_stack_depth
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
+    /// Returns the main trace; it is present for as long as this trace is not dropped.
+    fn main_trace(&self) -> &MainTrace {
+        self.main_trace.as_ref().expect("main trace was taken")
+    }
+
     /// Returns the index of the last row in the trace.
     fn last_step(&self) -> usize {
         self.length() - NUM_RAND_ROWS - 1
@@ -164,7 +240,7 @@ impl ExecutionTrace {
     pub fn print(&self) {
         let mut row = [ZERO; TRACE_WIDTH];
         for i in 0..self.length() {
-            self.main_trace.read_row_into(i, &mut row);
+            self.main_trace().read_row_into(i, &mut row);
             std::println!("{:?}", row.iter().map(|v| v.as_int()).collect::<Vec<_>>());
         }
     }
@@ -181,6 +257,18 @@ impl ExecutionTrace {
     }
 }
 
+impl Drop for ExecutionTrace {
+    fn drop(&mut self) {
+        // return the columns of the main trace into the pool, so that they can be reused by
+        // subsequent executions
+        if TraceBufferPool::max_bytes() != 0 {
+            if let Some(main_trace) = self.main_trace.take() {
+                TraceBufferPool::recycle(main_trace.into_columns());
+            }
+        }
+    }
+}
+
 // TRACE TRAIT IMPLEMENTATION
 // ================================================================================================
 
@@ -192,7 +280,7 @@ impl Trace for ExecutionTrace {
     }
 
     fn length(&self) -> usize {
-        self.main_trace.num_rows()
+        self.main_trace().num_rows()
     }
 
     fn meta(&self) -> &[u8] {
@@ -200,7 +288,7 @@ impl Trace for ExecutionTrace {
     }
 
     fn main_segment(&self) -> &ColMatrix<Felt> {
-        &self.main_trace
+        self.main_trace()
     }
 
     fn build_aux_segment<E: FieldElement<BaseField = Felt>>(
@@ -219,21 +307,25 @@ impl Trace for ExecutionTrace {
         let decoder_aux_columns = self
             .aux_trace_builders
             .decoder
-            .build_aux_columns(&self.main_trace, rand_elements);
+            .build_aux_columns(self.main_trace(), rand_elements);
 
         // add stack's running product columns
-        let stack_aux_columns =
-            self.aux_trace_builders.stack.build_aux_columns(&self.main_trace, rand_elements);
+        let stack_aux_columns = self
+            .aux_trace_builders
+            .stack
+            .build_aux_columns(self.main_trace(), rand_elements);
 
         // add the range checker's running product columns
-        let range_aux_columns =
-            self.aux_trace_builders.range.build_aux_columns(&self.main_trace, rand_elements);
+        let range_aux_columns = self
+            .aux_trace_builders
+            .range
+            .build_aux_columns(self.main_trace(), rand_elements);
 
         // add the running product columns for the chiplets
         let chiplets = self
             .aux_trace_builders
             .chiplets
-            .build_aux_columns(&self.main_trace, rand_elements);
+            .build_aux_columns(self.main_trace(), rand_elements);
 
         // combine all auxiliary columns into a single vector
         let mut aux_columns = decoder_aux_columns
@@ -244,20 +336,15 @@ impl Trace for ExecutionTrace {
             .collect::<Vec<_>>();
 
         // inject random values into the last rows of the trace
-        let mut rng = RpoRandomCoin::new(self.program_hash().into());
-        for i in self.length() - NUM_RAND_ROWS..self.length() {
-            for column in aux_columns.iter_mut() {
-                column[i] = rng.draw().expect("failed to draw a random value");
-            }
-        }
+        inject_random_rows(&mut aux_columns, RpoRandomCoin::new(self.rand_seed));
 
         Some(ColMatrix::new(aux_columns))
     }
 
     fn read_main_frame(&self, row_idx: usize, frame: &mut EvaluationFrame<Felt>) {
         let next_row_idx = (row_idx + 1) % self.length();
-        self.main_trace.read_row_into(row_idx, frame.current_mut());
-        self.main_trace.read_row_into(next_row_idx, frame.next_mut());
+        self.main_trace().read_row_into(row_idx, frame.current_mut());
+        self.main_trace().read_row_into(next_row_idx, frame.next_mut());
     }
 }
 
@@ -274,7 +361,7 @@ impl Trace for ExecutionTrace {
 ///   values. This, in turn, ensures that polynomial degrees of all columns are stable.
 fn finalize_trace<H>(
     process: Process<H>,
-    mut rng: RpoRandomCoin,
+    rng: RpoRandomCoin,
 ) -> (MainTrace, AuxTraceBuilders, TraceLenSummary)
 where
     H: Host,
@@ -327,11 +414,7 @@ where
         .collect::<Vec<_>>();
 
     // inject random values into the last rows of the trace
-    for i in trace_len - NUM_RAND_ROWS..trace_len {
-        for column in trace.iter_mut() {
-            column[i] = rng.draw().expect("failed to draw a random value");
-        }
-    }
+    inject_random_rows(&mut trace, rng);
 
     let aux_trace_hints = AuxTraceBuilders {
         decoder: decoder_trace.aux_builder,
@@ -344,3 +427,61 @@ where
 
     (main_trace, aux_trace_hints, trace_len_summary)
 }
+
+/// Replaces the values in the last [NUM_RAND_ROWS] rows of the specified columns with random
+/// values drawn from the provided random element generator.
+///
+/// The values are drawn row by row, i.e., all values of a row are drawn before the values of the
+/// next row.
+fn inject_random_rows<E>(columns: &mut [Vec<E>], mut rng: RpoRandomCoin)
+where
+    E: FieldElement<BaseField = Felt>,
+{
+    let trace_len = columns[0].len();
+    for i in trace_len - NUM_RAND_ROWS..trace_len {
+        for column in columns.iter_mut() {
+            column[i] = rng.draw().expect("failed to draw a random value");
+        }
+    }
+}
+
+/// Returns the contents of the memory of the root context in the address ranges requested by the
+/// host of the process, as of the end of the execution.
+///
+/// The ranges are validated when the execution starts (see [Process::execute()]).
+fn build_memory_outputs<H>(process: &Process<H>) -> MemoryImage
+where
+    H: Host,
+{
+    let host = process.host.borrow();
+    let mut memory_outputs = MemoryImage::new();
+    for range in host.memory_output_ranges() {
+        let words = range
+            .clone()
+            .map(|addr| {
+                process.chiplets.get_mem_value(ContextId::root(), addr).unwrap_or(EMPTY_WORD)
+            })
+            .collect::<Vec<Word>>();
+        memory_outputs
+            .insert_words(range.start, &words)
+            .expect("memory output ranges were validated before execution");
+    }
+    memory_outputs
+}
+
+/// Returns the lengths of the execution trace components of the provided process without building
+/// the execution trace.
+///
+/// The lengths are computed in the same way as in [finalize_trace()].
+pub(super) fn build_trace_len_summary<H>(process: Process<H>) -> TraceLenSummary
+where
+    H: Host,
+{
+    let (system, _, _, mut range, chiplets, _) = process.into_parts();
+
+    // the range checker table depends on the range checks required by the chiplets
+    chiplets.append_range_checks(&mut range);
+    let range_table_len = range.get_number_range_checker_rows();
+
+    TraceLenSummary::new(system.clk() as usize, range_table_len, ChipletsLengths::new(&chiplets))
+}
@@ -0,0 +1,153 @@
+use super::Felt;
+use alloc::vec::Vec;
+use vm_core::FieldElement;
+
+#[cfg(feature = "std")]
+use {
+    super::ZERO,
+    std::sync::{Mutex, MutexGuard},
+};
+
+// TRACE BUFFER POOL
+// ================================================================================================
+
+/// A process-wide pool of execution trace column buffers.
+///
+/// When an [ExecutionTrace](super::ExecutionTrace) is dropped (e.g., once a proof of the execution
+/// has been generated), its columns are returned into the pool, and subsequent executions take
+/// their column buffers from the pool instead of allocating new ones. This avoids repeatedly
+/// allocating and faulting in large buffers when many programs are executed or proven by the same
+/// process.
+///
+/// The pool retains at most [TraceBufferPool::max_bytes()] of memory, which is zero by default,
+/// i.e., the pool is disabled unless a limit is set via [TraceBufferPool::set_max_bytes()]. The
+/// pool is shared by all threads of the process, so the limit affects every trace built after it
+/// is set. The pool is available only when the `std` feature is enabled.
+pub struct TraceBufferPool;
+
+/// Statistics of the [TraceBufferPool].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TraceBufferPoolStats {
+    /// Number of bytes currently retained by the pool.
+    pub pooled_bytes: usize,
+    /// Number of column buffers taken from the pool since the process started.
+    pub reused_buffers: usize,
+    /// Number of column buffers allocated because the pool had no suitable buffer.
+    pub allocated_buffers: usize,
+}
+
+#[cfg(feature = "std")]
+struct PoolState {
+    buffers: Vec<Vec<Felt>>,
+    max_bytes: usize,
+    stats: TraceBufferPoolStats,
+}
+
+#[cfg(feature = "std")]
+static POOL: Mutex<PoolState> = Mutex::new(PoolState {
+    buffers: Vec::new(),
+    max_bytes: 0,
+    stats: TraceBufferPoolStats {
+        pooled_bytes: 0,
+        reused_buffers: 0,
+        allocated_buffers: 0,
+    },
+});
+
+#[cfg(feature = "std")]
+impl TraceBufferPool {
+    /// Sets the maximum number of bytes retained by the pool; the buffers exceeding the new limit
+    /// are released.
+    pub fn set_max_bytes(max_bytes: usize) {
+        let mut pool = Self::lock();
+        pool.max_bytes = max_bytes;
+        while pool.stats.pooled_bytes > max_bytes {
+            let buffer = pool.buffers.pop().expect("pooled bytes without buffers");
+            pool.stats.pooled_bytes -= buffer_size(&buffer);
+        }
+    }
+
+    /// Returns the maximum number of bytes retained by the pool.
+    pub fn max_bytes() -> usize {
+        Self::lock().max_bytes
+    }
+
+    /// Returns the statistics of the pool.
+    pub fn stats() -> TraceBufferPoolStats {
+        Self::lock().stats
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a column of `len` zeros, reusing a pooled buffer with sufficient capacity if
+    /// there is one.
+    pub(crate) fn zeroed_column(len: usize) -> Vec<Felt> {
+        let mut pool = Self::lock();
+        if pool.max_bytes == 0 {
+            return Felt::zeroed_vector(len);
+        }
+
+        match pool.buffers.iter().position(|buffer| buffer.capacity() >= len) {
+            Some(position) => {
+                let mut buffer = pool.buffers.swap_remove(position);
+                pool.stats.pooled_bytes -= buffer_size(&buffer);
+                pool.stats.reused_buffers += 1;
+                drop(pool);
+
+                buffer.clear();
+                buffer.resize(len, ZERO);
+                buffer
+            }
+            None => {
+                pool.stats.allocated_buffers += 1;
+                Felt::zeroed_vector(len)
+            }
+        }
+    }
+
+    /// Returns the specified columns into the pool, as long as the pool has room for them.
+    pub(crate) fn recycle(columns: Vec<Vec<Felt>>) {
+        let mut pool = Self::lock();
+        for column in columns {
+            let size = buffer_size(&column);
+            if pool.stats.pooled_bytes + size > pool.max_bytes {
+                break;
+            }
+            pool.stats.pooled_bytes += size;
+            pool.buffers.push(column);
+        }
+    }
+
+    fn lock() -> MutexGuard<'static, PoolState> {
+        POOL.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+#[cfg(not(feature = "std"))]
+impl TraceBufferPool {
+    /// Does nothing, as the pool is not available without the `std` feature.
+    pub fn set_max_bytes(_max_bytes: usize) {}
+
+    /// Returns zero, as the pool is not available without the `std` feature.
+    pub fn max_bytes() -> usize {
+        0
+    }
+
+    /// Returns empty statistics, as the pool is not available without the `std` feature.
+    pub fn stats() -> TraceBufferPoolStats {
+        TraceBufferPoolStats::default()
+    }
+
+    pub(crate) fn zeroed_column(len: usize) -> Vec<Felt> {
+        Felt::zeroed_vector(len)
+    }
+
+    pub(crate) fn recycle(_columns: Vec<Vec<Felt>>) {}
+}
+
+/// Returns the number of bytes allocated by the specified buffer.
+#[cfg(feature = "std")]
+fn buffer_size(buffer: &Vec<Felt>) -> usize {
+    buffer.capacity() * core::mem::size_of::<Felt>()
+}
@@ -169,7 +169,7 @@ fn build_expected_bitwise(alphas: &[Felt], label: Felt, a: Felt, b: Felt, result
 }
 
 fn build_expected_bitwise_from_trace(trace: &ExecutionTrace, alphas: &[Felt], row: usize) -> Felt {
-    let selector = trace.main_trace.get_column(BITWISE_TRACE_OFFSET)[row];
+    let selector = trace.main_trace().get_column(BITWISE_TRACE_OFFSET)[row];
 
     let op_id = if selector == BITWISE_AND {
         BITWISE_AND_LABEL
@@ -179,9 +179,9 @@ fn build_expected_bitwise_from_trace(trace: &ExecutionTrace, alphas: &[Felt], ro
         panic!("Execution trace contains an invalid bitwise operation.")
     };
 
-    let a = trace.main_trace.get_column(BITWISE_A_COL_IDX)[row];
-    let b = trace.main_trace.get_column(BITWISE_B_COL_IDX)[row];
-    let output = trace.main_trace.get_column(BITWISE_OUTPUT_COL_IDX)[row];
+    let a = trace.main_trace().get_column(BITWISE_A_COL_IDX)[row];
+    let b = trace.main_trace().get_column(BITWISE_B_COL_IDX)[row];
+    let output = trace.main_trace().get_column(BITWISE_OUTPUT_COL_IDX)[row];
 
     build_expected_bitwise(alphas, op_id, a, b, output)
 }
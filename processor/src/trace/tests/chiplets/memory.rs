@@ -166,8 +166,8 @@ fn build_expected_memory(
 
 fn build_expected_memory_from_trace(trace: &ExecutionTrace, alphas: &[Felt], row: usize) -> Felt {
     // get the memory access operation
-    let s0 = trace.main_trace.get_column(MEMORY_SELECTORS_COL_IDX)[row];
-    let s1 = trace.main_trace.get_column(MEMORY_SELECTORS_COL_IDX + 1)[row];
+    let s0 = trace.main_trace().get_column(MEMORY_SELECTORS_COL_IDX)[row];
+    let s1 = trace.main_trace().get_column(MEMORY_SELECTORS_COL_IDX + 1)[row];
     let op_label = if s0 == MEMORY_WRITE[0] {
         debug_assert!(s1 == ZERO);
         MEMORY_WRITE_LABEL
@@ -176,14 +176,14 @@ fn build_expected_memory_from_trace(trace: &ExecutionTrace, alphas: &[Felt], row
     };
 
     // get the memory access data
-    let ctx = trace.main_trace.get_column(MEMORY_CTX_COL_IDX)[row];
-    let addr = trace.main_trace.get_column(MEMORY_ADDR_COL_IDX)[row];
-    let clk = trace.main_trace.get_column(MEMORY_CLK_COL_IDX)[row];
+    let ctx = trace.main_trace().get_column(MEMORY_CTX_COL_IDX)[row];
+    let addr = trace.main_trace().get_column(MEMORY_ADDR_COL_IDX)[row];
+    let clk = trace.main_trace().get_column(MEMORY_CLK_COL_IDX)[row];
 
     // get the memory value
     let mut word = [ZERO; NUM_ELEMENTS];
     for (i, element) in word.iter_mut().enumerate() {
-        *element = trace.main_trace.get_column(MEMORY_V_COL_RANGE.start + i)[row];
+        *element = trace.main_trace().get_column(MEMORY_V_COL_RANGE.start + i)[row];
     }
 
     build_expected_memory(alphas, op_label, ctx, addr, clk, word)
@@ -75,11 +75,12 @@ fn hasher_p1_mr_update() {
     let p1 = aux_columns.get_column(P1_COL_IDX);
 
     let row_values = [
-        SiblingTableRow::new(Felt::new(index), path[0].into()).to_value(&trace.main_trace, &alphas),
+        SiblingTableRow::new(Felt::new(index), path[0].into())
+            .to_value(trace.main_trace(), &alphas),
         SiblingTableRow::new(Felt::new(index >> 1), path[1].into())
-            .to_value(&trace.main_trace, &alphas),
+            .to_value(trace.main_trace(), &alphas),
         SiblingTableRow::new(Felt::new(index >> 2), path[2].into())
-            .to_value(&trace.main_trace, &alphas),
+            .to_value(trace.main_trace(), &alphas),
     ];
 
     // make sure the first entry is ONE
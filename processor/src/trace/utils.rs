@@ -1,7 +1,7 @@
 use super::{Felt, FieldElement, NUM_RAND_ROWS};
 use crate::{chiplets::Chiplets, utils::uninit_vector};
 use alloc::vec::Vec;
-use core::slice;
+use core::{fmt, slice};
 use miden_air::trace::main_trace::MainTrace;
 
 #[cfg(test)]
@@ -45,6 +45,11 @@ impl<'a> TraceFragment<'a> {
         self.data[col_idx][row_idx] = value;
     }
 
+    /// Returns the column at the specified index.
+    pub fn column_mut(&mut self, col_idx: usize) -> &mut [Felt] {
+        &mut self.data[col_idx][..]
+    }
+
     /// Returns a mutable iterator to the columns of this fragment.
     pub fn columns(&mut self) -> slice::IterMut<'_, &'a mut [Felt]> {
         self.data.iter_mut()
@@ -133,6 +138,49 @@ impl TraceLenSummary {
     pub fn padding_percentage(&self) -> usize {
         (self.padded_trace_len() - self.trace_len()) * 100 / self.padded_trace_len()
     }
+
+    /// Returns the trace component with the largest number of rows, i.e., the component which
+    /// determines the length of the trace.
+    ///
+    /// If several components have the same length, the main trace takes precedence over the range
+    /// checker trace, which in turn takes precedence over the chiplets trace.
+    pub fn longest_component(&self) -> TraceComponent {
+        let trace_len = self.trace_len();
+        if self.main_trace_len == trace_len {
+            TraceComponent::Main
+        } else if self.range_trace_len == trace_len {
+            TraceComponent::RangeChecker
+        } else {
+            TraceComponent::Chiplets
+        }
+    }
+
+    /// Returns the number of rows which can be added to the longest trace component before the
+    /// padded trace length doubles.
+    pub fn headroom(&self) -> usize {
+        self.padded_trace_len() - NUM_RAND_ROWS - self.trace_len()
+    }
+}
+
+/// A component of the execution trace whose length is tracked in [TraceLenSummary].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TraceComponent {
+    /// The main trace, with one row per VM cycle.
+    Main,
+    /// The range checker trace.
+    RangeChecker,
+    /// The chiplets trace, which consists of the hash, bitwise, memory, and kernel ROM chiplets.
+    Chiplets,
+}
+
+impl fmt::Display for TraceComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Main => write!(f, "stack"),
+            Self::RangeChecker => write!(f, "range checker"),
+            Self::Chiplets => write!(f, "chiplets"),
+        }
+    }
 }
 
 /// Contains trace lengths of all chilplets: hash, bitwise, memory and kernel ROM trace
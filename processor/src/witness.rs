@@ -0,0 +1,174 @@
+use super::{
+    execute, AdviceInputs, DefaultHost, ExecutionError, ExecutionOptions, ExecutionTrace,
+    MemAdviceProvider, Program, RecAdviceProvider, StackInputs, StackOutputs,
+};
+use vm_core::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+
+// EXECUTION WITNESS
+// ================================================================================================
+
+/// Data required to reproduce execution of a program, and thus, to prove it in a process separate
+/// from the one in which the program was executed.
+///
+/// The witness consists of:
+/// - The program and the stack inputs against which it was executed.
+/// - The advice consumption log, i.e., the subset of the advice inputs (advice stack, advice map
+///   entries, and Merkle store nodes) which was requested during execution. Advice inputs which
+///   were never read by the program are not included.
+/// - The stack outputs, including the data required to reconstruct the stack overflow table at the
+///   end of execution.
+/// - The number of cycles the execution took, which is used to pre-allocate the trace when the
+///   execution is reproduced.
+///
+/// Reproducing execution from the witness requires only a small fraction of the resources needed
+/// to prove it, so the witness can be produced by running a program in a sandbox and proven on a
+/// different machine.
+#[derive(Debug, Clone)]
+pub struct ExecutionWitness {
+    program: Program,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+    stack_outputs: StackOutputs,
+    num_cycles: u32,
+}
+
+impl ExecutionWitness {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Maximum number of cycles for which the trace is pre-allocated based on the number of cycles
+    /// recorded in a witness.
+    pub const MAX_EXPECTED_CYCLES: u32 = 1 << 20;
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the program executed to produce this witness.
+    pub fn program(&self) -> &Program {
+        &self.program
+    }
+
+    /// Returns the stack inputs against which the program was executed.
+    pub fn stack_inputs(&self) -> &StackInputs {
+        &self.stack_inputs
+    }
+
+    /// Returns the advice inputs consumed during program execution.
+    pub fn advice_inputs(&self) -> &AdviceInputs {
+        &self.advice_inputs
+    }
+
+    /// Returns the outputs of program execution.
+    pub fn stack_outputs(&self) -> &StackOutputs {
+        &self.stack_outputs
+    }
+
+    /// Returns the number of cycles program execution took.
+    pub fn num_cycles(&self) -> u32 {
+        self.num_cycles
+    }
+
+    /// Returns [ExecutionOptions] for reproducing the execution recorded in this witness with the
+    /// trace pre-allocated for the recorded number of cycles.
+    ///
+    /// The recorded number of cycles comes from the witness, which may be untrusted, and thus, the
+    /// trace is pre-allocated for at most [Self::MAX_EXPECTED_CYCLES] cycles, unless the
+    /// specified options expect more cycles; the trace grows as needed beyond that. The cycle
+    /// limit, the VM profile, and other settings are taken from the specified options.
+    pub fn execution_options(&self, options: ExecutionOptions) -> ExecutionOptions {
+        let max_cycles = options.max_cycles();
+        let expected_cycles = self
+            .num_cycles
+            .min(Self::MAX_EXPECTED_CYCLES)
+            .max(options.expected_cycles())
+            .min(max_cycles);
+        let witness_options =
+            ExecutionOptions::new(Some(max_cycles), expected_cycles, options.enable_tracing())
+                .map(|witness_options| witness_options.with_profile(options.profile()))
+                .unwrap_or(options);
+        let witness_options = match options.stack_depth_warning() {
+            Some(threshold) => witness_options.with_stack_depth_warning(threshold),
+            None => witness_options,
+        };
+        if options.enable_debugging() {
+            witness_options.with_debugging()
+        } else {
+            witness_options
+        }
+    }
+
+    // EXECUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Re-executes the program recorded in this witness and returns the resulting execution trace.
+    ///
+    /// # Errors
+    /// Returns an error if the execution fails, or if it produces outputs different from the ones
+    /// recorded in the witness.
+    pub fn execute(&self, options: ExecutionOptions) -> Result<ExecutionTrace, ExecutionError> {
+        let host = DefaultHost::new(MemAdviceProvider::from(self.advice_inputs.clone()));
+        let trace = execute(
+            &self.program,
+            self.stack_inputs.clone(),
+            host,
+            self.execution_options(options),
+        )?;
+        if trace.stack_outputs() != &self.stack_outputs {
+            return Err(ExecutionError::InconsistentExecutionWitness);
+        }
+        Ok(trace)
+    }
+}
+
+// EXECUTOR
+// ================================================================================================
+
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, together with an [ExecutionWitness] from which the execution can be reproduced.
+///
+/// Unlike [execute()], this function takes non-deterministic inputs as [AdviceInputs] rather than
+/// as a [Host](super::Host), as consumption of the inputs is recorded in the witness.
+pub fn execute_with_witness(
+    program: &Program,
+    stack_inputs: StackInputs,
+    advice_inputs: AdviceInputs,
+    options: ExecutionOptions,
+) -> Result<(ExecutionTrace, ExecutionWitness), ExecutionError> {
+    let mut host = DefaultHost::new(RecAdviceProvider::from(advice_inputs));
+    let trace = execute(program, stack_inputs.clone(), &mut host, options)?;
+    let (advice_inputs, ..) = host.into_inner().finalize();
+
+    let witness = ExecutionWitness {
+        program: program.clone(),
+        stack_inputs,
+        advice_inputs,
+        stack_outputs: trace.stack_outputs().clone(),
+        num_cycles: trace.trace_len_summary().main_trace_len() as u32,
+    };
+    Ok((trace, witness))
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ExecutionWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.program.write_into(target);
+        self.stack_inputs.write_into(target);
+        self.advice_inputs.write_into(target);
+        self.stack_outputs.write_into(target);
+        target.write_u32(self.num_cycles);
+    }
+}
+
+impl Deserializable for ExecutionWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            program: Program::read_from(source)?,
+            stack_inputs: StackInputs::read_from(source)?,
+            advice_inputs: AdviceInputs::read_from(source)?,
+            stack_outputs: StackOutputs::read_from(source)?,
+            num_cycles: source.read_u32()?,
+        })
+    }
+}
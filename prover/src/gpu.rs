@@ -5,8 +5,8 @@ use super::{
     crypto::{RandomCoin, Rpo256, RpoDigest},
     event,
     math::fft,
-    ExecutionProver, ExecutionTrace, Felt, FieldElement, Level, ProcessorAir, PublicInputs,
-    WinterProofOptions,
+    ExecutionProver, ExecutionTrace, Felt, FieldElement, Level, ProcessorAir, ProvingStage,
+    PublicInputs, WinterProofOptions,
 };
 use elsa::FrozenVec;
 use ministark_gpu::{
@@ -34,11 +34,11 @@ const RPO_RATE: usize = Rpo256::RATE_RANGE.end - Rpo256::RATE_RANGE.start;
 // ================================================================================================
 
 /// Wraps an [ExecutionProver] and provides GPU acceleration for building Rpo256 trace commitments.
-pub(crate) struct MetalRpoExecutionProver<R>(pub ExecutionProver<Rpo256, R>)
+pub(crate) struct MetalRpoExecutionProver<'o, R>(pub ExecutionProver<'o, Rpo256, R>)
 where
     R: RandomCoin<BaseField = Felt, Hasher = Rpo256>;
 
-impl<R> Prover for MetalRpoExecutionProver<R>
+impl<'o, R> Prover for MetalRpoExecutionProver<'o, R>
 where
     R: RandomCoin<BaseField = Felt, Hasher = Rpo256>,
 {
@@ -65,7 +65,10 @@ where
         main_trace: &ColMatrix<Felt>,
         domain: &StarkDomain<Felt>,
     ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
-        MetalRpoTraceLde::new(trace_info, main_trace, domain)
+        self.0.observer.on_stage_started(ProvingStage::TraceLde);
+        let result = MetalRpoTraceLde::new(trace_info, main_trace, domain);
+        self.0.advance_stage(ProvingStage::TraceLde, ProvingStage::AuxTrace);
+        result
     }
 
     fn new_evaluator<'a, E: FieldElement<BaseField = Felt>>(
@@ -107,6 +110,9 @@ where
         num_trace_poly_columns: usize,
         domain: &StarkDomain<Felt>,
     ) -> (ConstraintCommitment<E, Rpo256>, CompositionPoly<E>) {
+        self.0
+            .advance_stage(ProvingStage::ConstraintEvaluation, ProvingStage::ConstraintCommitment);
+
         // evaluate composition polynomial columns over the LDE domain
         let now = Instant::now();
         let composition_poly =
@@ -162,6 +168,7 @@ where
             constraint_commitment.tree_depth(),
             now.elapsed().as_millis()
         );
+        self.0.advance_stage(ProvingStage::ConstraintCommitment, ProvingStage::Fri);
         (constraint_commitment, composition_poly)
     }
 }
@@ -670,7 +677,7 @@ mod tests {
         TraceInfo::new(num_cols, num_rows)
     }
 
-    fn create_test_prover() -> ExecutionProver<Rpo256, RpoRandomCoin> {
+    fn create_test_prover() -> ExecutionProver<'static, Rpo256, RpoRandomCoin> {
         ExecutionProver::new(
             ProvingOptions::with_128_bit_security(true),
             StackInputs::default(),
@@ -1,6 +1,9 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 use air::{ProcessorAir, PublicInputs};
+use alloc::string::ToString;
 use core::marker::PhantomData;
 use processor::{
     crypto::{
@@ -11,9 +14,11 @@ use processor::{
 };
 use tracing::{event, instrument, Level};
 use winter_prover::{
-    matrix::ColMatrix, AuxTraceRandElements, ConstraintCompositionCoefficients,
-    DefaultConstraintEvaluator, DefaultTraceLde, ProofOptions as WinterProofOptions, Prover,
-    StarkDomain, TraceInfo, TracePolyTable,
+    matrix::{ColMatrix, RowMatrix},
+    AuxTraceRandElements, CompositionPoly, CompositionPolyTrace, ConstraintCommitment,
+    ConstraintCompositionCoefficients, DefaultConstraintEvaluator, DefaultTraceLde,
+    ProofOptions as WinterProofOptions, Prover, ProverError, StarkDomain, TraceInfo,
+    TracePolyTable,
 };
 
 #[cfg(feature = "std")]
@@ -22,16 +27,42 @@ use {std::time::Instant, winter_prover::Trace};
 #[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
 mod gpu;
 
+mod memory;
+#[cfg(feature = "mmap")]
+mod mmap;
+mod progress;
+mod remote;
+
 // EXPORTS
 // ================================================================================================
 
-pub use air::{DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProvingOptions};
+pub use air::{
+    DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProofMetadata,
+    ProverMemoryBudget, ProvingOptions, VmProfile,
+};
+pub use memory::MemoryUsage;
 pub use processor::{
-    crypto, math, utils, AdviceInputs, Digest, ExecutionError, Host, InputError, MemAdviceProvider,
-    Program, StackInputs, StackOutputs, Word,
+    crypto, execute_with_witness, math, utils, AdviceInputs, Digest, ExecutionError,
+    ExecutionWitness, Host, InputError, MemAdviceProvider, MemoryImage, Program, ProgramInfo,
+    StackInputs, StackOutputs, TraceBufferPool, TraceBufferPoolStats, Word,
 };
+pub use progress::{ProgressObserver, ProvingStage};
 pub use winter_prover::StarkProof;
 
+#[cfg(feature = "remote")]
+pub use remote::HttpTransport;
+pub use remote::{
+    LocalProver, ProgramProver, ProofRequest, ProofResponse, ProofTransport, RemoteProver,
+    RemoteProverError,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of columns in a segment of the row-major matrix of constraint composition polynomial
+/// evaluations.
+const CONSTRAINT_SEGMENT_WIDTH: usize = 8;
+
 // PROVER
 // ================================================================================================
 
@@ -44,17 +75,112 @@ pub use winter_prover::StarkProof;
 ///
 /// # Errors
 /// Returns an error if program execution or STARK proof generation fails for any reason.
-#[instrument("prove_program", skip_all)]
 pub fn prove<H>(
     program: &Program,
     stack_inputs: StackInputs,
     host: H,
     options: ProvingOptions,
 ) -> Result<(StackOutputs, ExecutionProof), ExecutionError>
+where
+    H: Host,
+{
+    prove_with_observer(program, stack_inputs, host, options, &())
+}
+
+/// Executes and proves the specified `program` in the same way as [prove()], and notifies the
+/// specified `observer` about the progress of proof generation.
+///
+/// See [ProgressObserver] for the description of the notifications.
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason.
+pub fn prove_with_observer<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ProvingOptions,
+    observer: &dyn ProgressObserver,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError>
+where
+    H: Host,
+{
+    let (stack_outputs, _, proof) =
+        execute_and_prove(program, stack_inputs, host, options, observer)?;
+    Ok((stack_outputs, proof))
+}
+
+/// Executes and proves the specified `program` in the same way as [prove_with_observer()], and
+/// additionally returns the memory outputs of the execution.
+///
+/// Memory outputs are the contents of the address ranges requested by the host (see
+/// `Host::memory_output_ranges()`) at the end of the execution. They are not covered by the proof;
+/// programs whose memory outputs must be verified should return a commitment to them via the stack
+/// outputs (see `std::output`).
+///
+/// # Errors
+/// Returns an error if program execution or STARK proof generation fails for any reason.
+pub fn prove_with_memory_outputs<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ProvingOptions,
+    observer: &dyn ProgressObserver,
+) -> Result<(StackOutputs, MemoryImage, ExecutionProof), ExecutionError>
+where
+    H: Host,
+{
+    execute_and_prove(program, stack_inputs, host, options, observer)
+}
+
+/// Proves the execution recorded in the specified [ExecutionWitness] and returns the outputs of
+/// the execution together with a STARK-based proof of the program's execution.
+///
+/// The witness is typically produced by [execute_with_witness()](processor::execute_with_witness)
+/// in a different process (e.g., in a sandbox running an untrusted program). The execution is
+/// reproduced from the witness to build the execution trace, which takes only a small fraction of
+/// the time needed to generate the proof.
+///
+/// # Errors
+/// Returns an error if the execution cannot be reproduced from the witness, if it produces outputs
+/// different from the ones recorded in the witness, or if STARK proof generation fails.
+#[instrument("prove_witness", skip_all)]
+pub fn prove_from_witness(
+    witness: &ExecutionWitness,
+    options: ProvingOptions,
+) -> Result<(StackOutputs, ExecutionProof), ExecutionError> {
+    #[cfg(feature = "std")]
+    let now = Instant::now();
+    configure_buffer_pool(&options);
+    let trace = witness.execute(*options.execution_options())?;
+    #[cfg(feature = "std")]
+    event!(
+        Level::INFO,
+        "Reproduced execution trace of {} steps from the witness in {} ms",
+        trace.trace_len_summary().padded_trace_len(),
+        now.elapsed().as_millis()
+    );
+
+    let (stack_outputs, _, proof) =
+        prove_trace(witness.program(), witness.stack_inputs().clone(), trace, options, &())?;
+    Ok((stack_outputs, proof))
+}
+
+/// Executes the specified `program` and generates a STARK-based proof of its execution, notifying
+/// the specified observer about the progress of proof generation.
+#[instrument("prove_program", skip_all)]
+fn execute_and_prove<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ProvingOptions,
+    observer: &dyn ProgressObserver,
+) -> Result<(StackOutputs, MemoryImage, ExecutionProof), ExecutionError>
 where
     H: Host,
 {
     // execute the program to create an execution trace
+    observer.on_stage_started(ProvingStage::Execution);
+    configure_buffer_pool(&options);
     #[cfg(feature = "std")]
     let now = Instant::now();
     let trace =
@@ -68,45 +194,178 @@ where
         trace.trace_len_summary().padding_percentage(),
         now.elapsed().as_millis()
     );
+    observer.on_stage_completed(ProvingStage::Execution);
+
+    prove_trace(program, stack_inputs, trace, options, observer)
+}
+
+/// Generates a STARK-based proof of the program's execution from the execution trace, and
+/// notifies the specified observer about the progress of proof generation.
+fn prove_trace(
+    program: &Program,
+    stack_inputs: StackInputs,
+    mut trace: ExecutionTrace,
+    options: ProvingOptions,
+    observer: &dyn ProgressObserver,
+) -> Result<(StackOutputs, MemoryImage, ExecutionProof), ExecutionError> {
+    // make sure proof generation fits into the memory budget before starting it
+    let memory_usage = MemoryUsage::estimate(trace.trace_len_summary(), &options);
+    event!(Level::INFO, "Estimated peak memory usage of proof generation: {memory_usage}");
+    observer.on_memory_usage(&memory_usage);
+    if let Some(budget) = options.memory_budget() {
+        if memory_usage.peak() > budget.max_bytes() {
+            return Err(ExecutionError::ProverMemoryBudgetExceeded {
+                required: memory_usage.peak(),
+                budget: budget.max_bytes(),
+            });
+        }
+    }
+
+    // derive the random values injected into the trace from the seed of reproducible proofs
+    if let Some(seed) = options.seed() {
+        trace.reseed(*seed);
+        #[cfg(feature = "concurrent")]
+        event!(
+            Level::WARN,
+            "The proof-of-work nonce is searched for in parallel; the proof may not be reproducible"
+        );
+    }
 
     let stack_outputs = trace.stack_outputs().clone();
+    let memory_outputs = trace.memory_outputs().clone();
+    let profile = trace.profile();
     let hash_fn = options.hash_fn();
+    let metadata = options.embed_metadata().then(|| build_metadata(program, &options));
 
     // generate STARK proof
     let proof = match hash_fn {
-        HashFunction::Blake3_192 => ExecutionProver::<Blake3_192, WinterRandomCoin<_>>::new(
-            options,
-            stack_inputs,
-            stack_outputs.clone(),
-        )
-        .prove(trace),
-        HashFunction::Blake3_256 => ExecutionProver::<Blake3_256, WinterRandomCoin<_>>::new(
-            options,
-            stack_inputs,
-            stack_outputs.clone(),
-        )
-        .prove(trace),
-        HashFunction::Rpo256 => {
-            let prover = ExecutionProver::<Rpo256, RpoRandomCoin>::new(
+        HashFunction::Blake3_192 => prove_execution(
+            ExecutionProver::<Blake3_192, WinterRandomCoin<_>>::new(
                 options,
                 stack_inputs,
                 stack_outputs.clone(),
-            );
-            #[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
-            let prover = gpu::MetalRpoExecutionProver(prover);
-            prover.prove(trace)
-        }
+            )
+            .with_metadata(metadata.clone())
+            .with_observer(observer),
+            trace,
+        ),
+        HashFunction::Blake3_256 => prove_execution(
+            ExecutionProver::<Blake3_256, WinterRandomCoin<_>>::new(
+                options,
+                stack_inputs,
+                stack_outputs.clone(),
+            )
+            .with_metadata(metadata.clone())
+            .with_observer(observer),
+            trace,
+        ),
+        HashFunction::Rpo256 => prove_rpo_execution(
+            ExecutionProver::<Rpo256, RpoRandomCoin>::new(
+                options,
+                stack_inputs,
+                stack_outputs.clone(),
+            )
+            .with_metadata(metadata.clone())
+            .with_observer(observer),
+            trace,
+        ),
     }
     .map_err(ExecutionError::ProverError)?;
-    let proof = ExecutionProof::new(proof, hash_fn);
+    observer.on_stage_completed(ProvingStage::Fri);
 
-    Ok((stack_outputs, proof))
+    let proof = ExecutionProof::new(proof, hash_fn).with_profile(profile);
+    let proof = match metadata {
+        Some(metadata) => proof.with_metadata(metadata),
+        None => proof,
+    };
+
+    Ok((stack_outputs, memory_outputs, proof))
+}
+
+/// Generates a STARK proof of the execution trace using the specified prover.
+///
+/// If the proving options request a memory-mapped trace, the low-degree extension of the main
+/// trace segment is stored in a scratch file; this requires the `mmap` feature.
+fn prove_execution<H, R>(
+    prover: ExecutionProver<'_, H, R>,
+    trace: ExecutionTrace,
+) -> Result<StarkProof, ProverError>
+where
+    H: ElementHasher<BaseField = Felt>,
+    R: RandomCoin<BaseField = Felt, Hasher = H>,
+{
+    if prover.memory_mapped_trace {
+        #[cfg(feature = "mmap")]
+        return mmap::MmapExecutionProver(prover).prove(trace);
+        #[cfg(not(feature = "mmap"))]
+        event!(
+            Level::WARN,
+            "Memory-mapped traces require the `mmap` feature; the trace is kept in memory"
+        );
+    }
+    prover.prove(trace)
+}
+
+/// Generates a STARK proof of the execution trace using the specified Rpo256-based prover.
+///
+/// Unless the proving options request a memory-mapped trace, trace commitments are built on the
+/// GPU.
+#[cfg(all(feature = "metal", target_arch = "aarch64", target_os = "macos"))]
+fn prove_rpo_execution(
+    prover: ExecutionProver<'_, Rpo256, RpoRandomCoin>,
+    trace: ExecutionTrace,
+) -> Result<StarkProof, ProverError> {
+    if prover.memory_mapped_trace {
+        prove_execution(prover, trace)
+    } else {
+        gpu::MetalRpoExecutionProver(prover).prove(trace)
+    }
+}
+
+/// Generates a STARK proof of the execution trace using the specified Rpo256-based prover.
+#[cfg(not(all(feature = "metal", target_arch = "aarch64", target_os = "macos")))]
+fn prove_rpo_execution(
+    prover: ExecutionProver<'_, Rpo256, RpoRandomCoin>,
+    trace: ExecutionTrace,
+) -> Result<StarkProof, ProverError> {
+    prove_execution(prover, trace)
+}
+
+/// Sets the size of the [TraceBufferPool] according to the memory budget in the specified options,
+/// if there is one.
+fn configure_buffer_pool(options: &ProvingOptions) {
+    if let Some(budget) = options.memory_budget() {
+        TraceBufferPool::set_max_bytes(budget.max_pooled_bytes());
+    }
+}
+
+/// Returns metadata to be embedded into the proof of the specified program's execution.
+fn build_metadata(program: &Program, options: &ProvingOptions) -> ProofMetadata {
+    // the timestamp is omitted from reproducible proofs, as it would differ between runs
+    #[cfg(feature = "std")]
+    let timestamp = match options.seed() {
+        Some(_) => 0,
+        None => std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs()),
+    };
+    #[cfg(not(feature = "std"))]
+    let timestamp = 0;
+
+    ProofMetadata::new(
+        program.hash(),
+        env!("CARGO_PKG_VERSION").to_string(),
+        options.hash_fn(),
+        options.clone().into(),
+        timestamp,
+        options.metadata_label().map(|label| label.to_string()),
+    )
 }
 
 // PROVER
 // ================================================================================================
 
-struct ExecutionProver<H, R>
+struct ExecutionProver<'o, H, R>
 where
     H: ElementHasher<BaseField = Felt>,
     R: RandomCoin<BaseField = Felt, Hasher = H>,
@@ -115,9 +374,12 @@ where
     options: WinterProofOptions,
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
+    metadata: Option<ProofMetadata>,
+    memory_mapped_trace: bool,
+    observer: &'o dyn ProgressObserver,
 }
 
-impl<H, R> ExecutionProver<H, R>
+impl<'o, H, R> ExecutionProver<'o, H, R>
 where
     H: ElementHasher<BaseField = Felt>,
     R: RandomCoin<BaseField = Felt, Hasher = H>,
@@ -129,15 +391,38 @@ where
     ) -> Self {
         Self {
             random_coin: PhantomData,
+            memory_mapped_trace: options
+                .memory_budget()
+                .is_some_and(|budget| budget.memory_mapped_trace()),
             options: options.into(),
             stack_inputs,
             stack_outputs,
+            metadata: None,
+            observer: &(),
         }
     }
 
+    /// Binds the specified metadata to the public inputs of the generated proof.
+    pub fn with_metadata(mut self, metadata: Option<ProofMetadata>) -> Self {
+        self.metadata = metadata;
+        self
+    }
+
+    /// Notifies the specified observer about the progress of proof generation.
+    pub fn with_observer(mut self, observer: &'o dyn ProgressObserver) -> Self {
+        self.observer = observer;
+        self
+    }
+
     // HELPER FUNCTIONS
     // --------------------------------------------------------------------------------------------
 
+    /// Notifies the observer that the `completed` stage ended and the `next` stage started.
+    fn advance_stage(&self, completed: ProvingStage, next: ProvingStage) {
+        self.observer.on_stage_completed(completed);
+        self.observer.on_stage_started(next);
+    }
+
     /// Validates the stack inputs against the provided execution trace and returns true if valid.
     fn are_inputs_valid(&self, trace: &ExecutionTrace) -> bool {
         self.stack_inputs
@@ -157,7 +442,7 @@ where
     }
 }
 
-impl<H, R> Prover for ExecutionProver<H, R>
+impl<'o, H, R> Prover for ExecutionProver<'o, H, R>
 where
     H: ElementHasher<BaseField = Felt>,
     R: RandomCoin<BaseField = Felt, Hasher = H>,
@@ -187,7 +472,13 @@ where
         );
 
         let program_info = trace.program_info().clone();
-        PublicInputs::new(program_info, self.stack_inputs.clone(), self.stack_outputs.clone())
+        let pub_inputs =
+            PublicInputs::new(program_info, self.stack_inputs.clone(), self.stack_outputs.clone())
+                .with_profile(trace.profile());
+        match &self.metadata {
+            Some(metadata) => pub_inputs.with_metadata(metadata),
+            None => pub_inputs,
+        }
     }
 
     fn new_trace_lde<E: FieldElement<BaseField = Felt>>(
@@ -196,7 +487,10 @@ where
         main_trace: &ColMatrix<Felt>,
         domain: &StarkDomain<Felt>,
     ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
-        DefaultTraceLde::new(trace_info, main_trace, domain)
+        self.observer.on_stage_started(ProvingStage::TraceLde);
+        let result = DefaultTraceLde::new(trace_info, main_trace, domain);
+        self.advance_stage(ProvingStage::TraceLde, ProvingStage::AuxTrace);
+        result
     }
 
     fn new_evaluator<'a, E: FieldElement<BaseField = Felt>>(
@@ -205,6 +499,31 @@ where
         aux_rand_elements: AuxTraceRandElements<E>,
         composition_coefficients: ConstraintCompositionCoefficients<E>,
     ) -> Self::ConstraintEvaluator<'a, E> {
+        self.advance_stage(ProvingStage::AuxTrace, ProvingStage::ConstraintEvaluation);
         DefaultConstraintEvaluator::new(air, aux_rand_elements, composition_coefficients)
     }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Felt>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_trace_poly_columns: usize,
+        domain: &StarkDomain<Felt>,
+    ) -> (ConstraintCommitment<E, H>, CompositionPoly<E>) {
+        self.advance_stage(ProvingStage::ConstraintEvaluation, ProvingStage::ConstraintCommitment);
+
+        // build the composition polynomial and evaluate its columns over the LDE domain
+        let composition_poly =
+            CompositionPoly::new(composition_poly_trace, domain, num_trace_poly_columns);
+        let composed_evaluations = RowMatrix::evaluate_polys_over::<CONSTRAINT_SEGMENT_WIDTH>(
+            composition_poly.data(),
+            domain,
+        );
+
+        // build the commitment to the evaluations
+        let commitment = composed_evaluations.commit_to_rows();
+        let constraint_commitment = ConstraintCommitment::new(composed_evaluations, commitment);
+
+        self.advance_stage(ProvingStage::ConstraintCommitment, ProvingStage::Fri);
+        (constraint_commitment, composition_poly)
+    }
 }
@@ -0,0 +1,101 @@
+use super::{ProvingOptions, WinterProofOptions};
+use air::trace::{AUX_TRACE_WIDTH, TRACE_WIDTH};
+use core::{fmt, mem::size_of};
+use processor::{math::Felt, TraceLenSummary};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Blowup factor of the constraint evaluation domain, which is also the number of columns of the
+/// constraint composition polynomial; this is determined by the highest constraint degree of the
+/// VM AIR (9).
+const CONSTRAINT_EVALUATION_BLOWUP: usize = 8;
+
+/// Number of bytes in a node of a trace commitment Merkle tree.
+const MERKLE_NODE_SIZE: usize = 32;
+
+/// Number of Merkle trees built during proof generation: one for each of the main and auxiliary
+/// trace segments, and one for the constraint evaluations.
+const NUM_MERKLE_TREES: usize = 3;
+
+// MEMORY USAGE
+// ================================================================================================
+
+/// An estimate of the memory required to generate a proof, broken down by the largest data
+/// structures built by the prover.
+///
+/// The estimate is computed from the padded length of the execution trace and the proof options;
+/// it does not include the memory used by the VM during execution, or the memory used to build the
+/// FRI layers, which is at most as large as the constraint evaluations. If the low-degree extension
+/// of the main trace segment is stored in a memory-mapped scratch file (see
+/// [ProverMemoryBudget::memory_mapped_trace()](air::ProverMemoryBudget::memory_mapped_trace)), the
+/// extension is not included into the estimate either.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Bytes taken by the main trace segment.
+    pub main_trace: usize,
+    /// Bytes taken by the polynomials and the low-degree extension of the main trace segment,
+    /// excluding the extension if it is memory-mapped.
+    pub main_trace_lde: usize,
+    /// Bytes taken by the auxiliary trace segment, its polynomials and its low-degree extension.
+    pub aux_trace_lde: usize,
+    /// Bytes taken by the constraint evaluations and the composition polynomial evaluations.
+    pub constraint_evaluations: usize,
+    /// Bytes taken by the Merkle trees committing to the extended traces and the constraint
+    /// evaluations.
+    pub merkle_trees: usize,
+}
+
+impl MemoryUsage {
+    /// Returns an estimate of the memory required to prove an execution with the specified trace
+    /// lengths using the specified options.
+    pub fn estimate(trace_len_summary: &TraceLenSummary, options: &ProvingOptions) -> Self {
+        let proof_options = WinterProofOptions::from(options.clone());
+        let trace_len = trace_len_summary.padded_trace_len();
+        let lde_len = trace_len * proof_options.blowup_factor();
+        let extension_degree = proof_options.field_extension().degree() as usize;
+        let element_size = size_of::<Felt>();
+        let ext_element_size = element_size * extension_degree;
+
+        // the extension of the main trace segment does not take memory if it is memory-mapped
+        let memory_mapped_trace = cfg!(feature = "mmap")
+            && options.memory_budget().is_some_and(|budget| budget.memory_mapped_trace());
+        let main_lde_len = if memory_mapped_trace { 0 } else { lde_len };
+
+        Self {
+            main_trace: trace_len * TRACE_WIDTH * element_size,
+            main_trace_lde: (trace_len + main_lde_len) * TRACE_WIDTH * element_size,
+            aux_trace_lde: (2 * trace_len + lde_len) * AUX_TRACE_WIDTH * ext_element_size,
+            constraint_evaluations: (trace_len + lde_len)
+                * CONSTRAINT_EVALUATION_BLOWUP
+                * ext_element_size,
+            merkle_trees: NUM_MERKLE_TREES * 2 * lde_len * MERKLE_NODE_SIZE,
+        }
+    }
+
+    /// Returns the estimated peak memory usage in bytes.
+    pub fn peak(&self) -> usize {
+        self.main_trace
+            + self.main_trace_lde
+            + self.aux_trace_lde
+            + self.constraint_evaluations
+            + self.merkle_trees
+    }
+}
+
+impl fmt::Display for MemoryUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const MB: usize = 1 << 20;
+        write!(
+            f,
+            "{} MB (main trace: {} MB, main trace LDE: {} MB, auxiliary trace LDE: {} MB, \
+            constraint evaluations: {} MB, Merkle trees: {} MB)",
+            self.peak() / MB,
+            self.main_trace / MB,
+            self.main_trace_lde / MB,
+            self.aux_trace_lde / MB,
+            self.constraint_evaluations / MB,
+            self.merkle_trees / MB,
+        )
+    }
+}
@@ -0,0 +1,546 @@
+//! This module contains the logic for storing the low-degree extension of the main trace segment
+//! in a memory-mapped scratch file, which allows proving traces whose extension does not fit into
+//! RAM at the cost of lower throughput.
+
+use super::{
+    crypto::{ElementHasher, RandomCoin},
+    event,
+    math::{batch, fft},
+    ExecutionProver, ExecutionTrace, Felt, FieldElement, Level, ProcessorAir, ProvingStage,
+    PublicInputs, WinterProofOptions,
+};
+use memmap2::MmapMut;
+use std::{
+    env,
+    fs::{self, OpenOptions},
+    io,
+    mem::size_of,
+    path::PathBuf,
+    process, slice,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Instant,
+};
+use winter_prover::math::{fft::fft_inputs::FftInputs, get_power_series, StarkField};
+use winter_prover::{
+    crypto::{Hasher, MerkleTree},
+    matrix::{ColMatrix, RowMatrix},
+    proof::Queries,
+    AuxTraceRandElements, CompositionPoly, CompositionPolyTrace, ConstraintCommitment,
+    ConstraintCompositionCoefficients, DefaultConstraintEvaluator, EvaluationFrame, Prover,
+    StarkDomain, TraceInfo, TraceLayout, TraceLde, TracePolyTable,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Number of columns in a segment of the row-major matrix of the auxiliary trace segment LDE.
+const AUX_SEGMENT_WIDTH: usize = 8;
+
+/// Counter used to generate unique names of the scratch files created by this process.
+static NEXT_SCRATCH_FILE_ID: AtomicUsize = AtomicUsize::new(0);
+
+// MEMORY-MAPPED PROVER
+// ================================================================================================
+
+/// Wraps an [ExecutionProver] and stores the low-degree extension of the main trace segment in a
+/// memory-mapped scratch file.
+pub(crate) struct MmapExecutionProver<'o, H, R>(pub ExecutionProver<'o, H, R>)
+where
+    H: ElementHasher<BaseField = Felt>,
+    R: RandomCoin<BaseField = Felt, Hasher = H>;
+
+impl<'o, H, R> Prover for MmapExecutionProver<'o, H, R>
+where
+    H: ElementHasher<BaseField = Felt>,
+    R: RandomCoin<BaseField = Felt, Hasher = H>,
+{
+    type BaseField = Felt;
+    type Air = ProcessorAir;
+    type Trace = ExecutionTrace;
+    type HashFn = H;
+    type RandomCoin = R;
+    type TraceLde<E: FieldElement<BaseField = Felt>> = MmapTraceLde<E, H>;
+    type ConstraintEvaluator<'a, E: FieldElement<BaseField = Felt>> =
+        DefaultConstraintEvaluator<'a, ProcessorAir, E>;
+
+    fn options(&self) -> &WinterProofOptions {
+        self.0.options()
+    }
+
+    fn get_pub_inputs(&self, trace: &ExecutionTrace) -> PublicInputs {
+        self.0.get_pub_inputs(trace)
+    }
+
+    fn new_trace_lde<E: FieldElement<BaseField = Felt>>(
+        &self,
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Felt>,
+        domain: &StarkDomain<Felt>,
+    ) -> (Self::TraceLde<E>, TracePolyTable<E>) {
+        self.0.observer.on_stage_started(ProvingStage::TraceLde);
+        let result = MmapTraceLde::new(trace_info, main_trace, domain);
+        self.0.advance_stage(ProvingStage::TraceLde, ProvingStage::AuxTrace);
+        result
+    }
+
+    fn new_evaluator<'a, E: FieldElement<BaseField = Felt>>(
+        &self,
+        air: &'a ProcessorAir,
+        aux_rand_elements: AuxTraceRandElements<E>,
+        composition_coefficients: ConstraintCompositionCoefficients<E>,
+    ) -> Self::ConstraintEvaluator<'a, E> {
+        self.0.new_evaluator(air, aux_rand_elements, composition_coefficients)
+    }
+
+    fn build_constraint_commitment<E: FieldElement<BaseField = Felt>>(
+        &self,
+        composition_poly_trace: CompositionPolyTrace<E>,
+        num_trace_poly_columns: usize,
+        domain: &StarkDomain<Felt>,
+    ) -> (ConstraintCommitment<E, H>, CompositionPoly<E>) {
+        self.0
+            .build_constraint_commitment(composition_poly_trace, num_trace_poly_columns, domain)
+    }
+}
+
+// MEMORY-MAPPED TRACE LOW DEGREE EXTENSION
+// ================================================================================================
+
+/// Contains all segments of the extended execution trace and their commitments.
+///
+/// The low-degree extension of the main segment is stored in a memory-mapped scratch file, while
+/// the extensions of the auxiliary segments, which are much narrower, are kept in memory.
+pub struct MmapTraceLde<E: FieldElement<BaseField = Felt>, H: ElementHasher<BaseField = Felt>> {
+    // low-degree extension of the main segment of the trace
+    main_segment_lde: ScratchMatrix,
+    // commitment to the main segment of the trace
+    main_segment_tree: MerkleTree<H>,
+    // low-degree extensions of the auxiliary segments of the trace
+    aux_segment_ldes: Vec<RowMatrix<E>>,
+    // commitment to the auxiliary segments of the trace
+    aux_segment_trees: Vec<MerkleTree<H>>,
+    blowup: usize,
+    trace_info: TraceInfo,
+}
+
+impl<E, H> MmapTraceLde<E, H>
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+{
+    /// Takes the main trace segment columns as input, interpolates them into polynomials in
+    /// coefficient form, evaluates the polynomials over the LDE domain, writes the evaluations
+    /// into a scratch file, and commits to them.
+    ///
+    /// Returns a tuple containing a [TracePolyTable] with the trace polynomials for the main trace
+    /// segment and the new [MmapTraceLde].
+    pub fn new(
+        trace_info: &TraceInfo,
+        main_trace: &ColMatrix<Felt>,
+        domain: &StarkDomain<Felt>,
+    ) -> (Self, TracePolyTable<E>) {
+        let now = Instant::now();
+        let main_segment_polys = main_trace.interpolate_columns();
+
+        // extend the polynomials one column at a time, so that only the scratch matrix holds the
+        // complete extension; the powers of the coset offsets are shared by all columns
+        let mut main_segment_lde =
+            ScratchMatrix::new(domain.lde_domain_size(), main_segment_polys.num_cols());
+        let offset_powers = get_coset_offset_powers(domain);
+        let mut evaluations = Felt::zeroed_vector(domain.lde_domain_size());
+        for (col_idx, poly) in main_segment_polys.columns().enumerate() {
+            evaluate_poly_over_cosets(
+                poly,
+                &offset_powers,
+                domain.trace_twiddles(),
+                &mut evaluations,
+            );
+            main_segment_lde.set_column(col_idx, &evaluations);
+        }
+
+        // commit to the rows of the extended trace
+        let leaves = (0..main_segment_lde.num_rows())
+            .map(|row_idx| H::hash_elements(main_segment_lde.row(row_idx)))
+            .collect();
+        let main_segment_tree =
+            MerkleTree::new(leaves).expect("failed to build a Merkle tree of the trace LDE");
+        event!(
+            Level::INFO,
+            "Extended and committed to an execution trace of {} columns from 2^{} to 2^{} steps in {} ms ({})",
+            main_segment_polys.num_cols(),
+            main_segment_polys.num_rows().ilog2(),
+            main_segment_lde.num_rows().ilog2(),
+            now.elapsed().as_millis(),
+            if main_segment_lde.is_mapped() { "memory-mapped" } else { "in memory" }
+        );
+
+        let trace_lde = MmapTraceLde {
+            main_segment_lde,
+            main_segment_tree,
+            aux_segment_ldes: Vec::new(),
+            aux_segment_trees: Vec::new(),
+            blowup: domain.trace_to_lde_blowup(),
+            trace_info: trace_info.clone(),
+        };
+
+        (trace_lde, TracePolyTable::new(main_segment_polys))
+    }
+}
+
+impl<E, H> TraceLde<E> for MmapTraceLde<E, H>
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+{
+    type HashFn = H;
+
+    /// Returns the commitment to the low-degree extension of the main trace segment.
+    fn get_main_trace_commitment(&self) -> H::Digest {
+        *self.main_segment_tree.root()
+    }
+
+    /// Takes auxiliary trace segment columns as input, interpolates them into polynomials in
+    /// coefficient form, evaluates the polynomials over the LDE domain, and commits to the
+    /// polynomial evaluations.
+    ///
+    /// Returns a tuple containing the column polynomials in coefficient from and the commitment
+    /// to the polynomial evaluations over the LDE domain.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if any of the following are true:
+    /// - the number of rows in the provided `aux_trace` does not match the main trace.
+    /// - this segment would exceed the number of segments specified by the trace layout.
+    fn add_aux_segment(
+        &mut self,
+        aux_trace: &ColMatrix<E>,
+        domain: &StarkDomain<Felt>,
+    ) -> (ColMatrix<E>, H::Digest) {
+        // extend the auxiliary trace segment and build a Merkle tree from the extended trace
+        let aux_segment_polys = aux_trace.interpolate_columns();
+        let aux_segment_lde =
+            RowMatrix::evaluate_polys_over::<AUX_SEGMENT_WIDTH>(&aux_segment_polys, domain);
+        let aux_segment_tree = aux_segment_lde.commit_to_rows::<H>();
+
+        // check errors
+        assert!(
+            self.aux_segment_ldes.len() < self.trace_info.layout().num_aux_segments(),
+            "the specified number of auxiliary segments has already been added"
+        );
+        assert_eq!(
+            self.main_segment_lde.num_rows(),
+            aux_segment_lde.num_rows(),
+            "the number of rows in the auxiliary segment must be the same as in the main segment"
+        );
+
+        // save the lde and commitment
+        self.aux_segment_ldes.push(aux_segment_lde);
+        let root_hash = *aux_segment_tree.root();
+        self.aux_segment_trees.push(aux_segment_tree);
+
+        (aux_segment_polys, root_hash)
+    }
+
+    /// Reads current and next rows from the main trace segment into the specified frame.
+    fn read_main_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<Felt>) {
+        // at the end of the trace, next state wraps around and we read the first step again
+        let next_lde_step = (lde_step + self.blowup()) % self.trace_len();
+
+        // copy main trace segment values into the frame
+        frame.current_mut().copy_from_slice(self.main_segment_lde.row(lde_step));
+        frame.next_mut().copy_from_slice(self.main_segment_lde.row(next_lde_step));
+    }
+
+    /// Reads current and next rows from the auxiliary trace segment into the specified frame.
+    ///
+    /// # Panics
+    /// This currently assumes that there is exactly one auxiliary trace segment, and will panic
+    /// otherwise.
+    fn read_aux_trace_frame_into(&self, lde_step: usize, frame: &mut EvaluationFrame<E>) {
+        // at the end of the trace, next state wraps around and we read the first step again
+        let next_lde_step = (lde_step + self.blowup()) % self.trace_len();
+
+        // copy auxiliary trace segment values into the frame
+        let segment = &self.aux_segment_ldes[0];
+        frame.current_mut().copy_from_slice(segment.row(lde_step));
+        frame.next_mut().copy_from_slice(segment.row(next_lde_step));
+    }
+
+    /// Returns trace table rows at the specified positions along with Merkle authentication paths
+    /// from the commitment root to these rows.
+    fn query(&self, positions: &[usize]) -> Vec<Queries> {
+        // build queries for the main trace segment
+        let main_states = positions
+            .iter()
+            .map(|&pos| self.main_segment_lde.row(pos).to_vec())
+            .collect::<Vec<_>>();
+        let mut result =
+            vec![build_segment_queries(main_states, &self.main_segment_tree, positions)];
+
+        // build queries for auxiliary trace segments
+        for (segment_lde, segment_tree) in self.aux_segment_ldes.iter().zip(&self.aux_segment_trees)
+        {
+            let states = positions.iter().map(|&pos| segment_lde.row(pos).to_vec()).collect();
+            result.push(build_segment_queries::<E, H>(states, segment_tree, positions));
+        }
+
+        result
+    }
+
+    /// Returns the number of rows in the execution trace.
+    fn trace_len(&self) -> usize {
+        self.main_segment_lde.num_rows()
+    }
+
+    /// Returns blowup factor which was used to extend original execution trace into trace LDE.
+    fn blowup(&self) -> usize {
+        self.blowup
+    }
+
+    /// Returns the trace layout of the execution trace.
+    fn trace_layout(&self) -> &TraceLayout {
+        self.trace_info.layout()
+    }
+}
+
+/// Builds the queries of a trace segment from the rows of the segment at the specified positions.
+fn build_segment_queries<E, H>(
+    trace_states: Vec<Vec<E>>,
+    segment_tree: &MerkleTree<H>,
+    positions: &[usize],
+) -> Queries
+where
+    E: FieldElement<BaseField = Felt>,
+    H: Hasher,
+{
+    // build Merkle authentication paths to the leaves specified by positions
+    let trace_proof = segment_tree
+        .prove_batch(positions)
+        .expect("failed to generate a Merkle proof for trace queries");
+
+    Queries::new(trace_proof, trace_states)
+}
+
+// COSET EVALUATION
+// ================================================================================================
+
+/// Returns the powers of the offsets of the cosets of the trace domain which make up the LDE
+/// domain, in the order in which the cosets are evaluated by [evaluate_poly_over_cosets()].
+///
+/// The result consists of one chunk per coset, and the chunk of a coset with offset s holds
+/// s^0, s^1, ..., s^(n - 1), where n is the length of the trace.
+fn get_coset_offset_powers(domain: &StarkDomain<Felt>) -> Vec<Felt> {
+    let blowup = domain.trace_to_lde_blowup();
+    let g = Felt::get_root_of_unity(domain.lde_domain_size().ilog2());
+
+    let mut result = Vec::with_capacity(domain.lde_domain_size());
+    for coset_idx in 0..blowup {
+        let exp = fft::permute_index(blowup, coset_idx) as u64;
+        let offset = g.exp(exp) * domain.offset();
+        result.extend(get_power_series(offset, domain.trace_length()));
+    }
+    result
+}
+
+/// Evaluates the polynomial `poly` over the LDE domain, and writes the evaluations into `result`.
+///
+/// This computes the same evaluations as [fft::evaluate_poly_with_offset()]; however, the
+/// coefficients of the polynomial are scaled by the precomputed powers of the coset offsets
+/// using vectorized multiplication, rather than by powers computed anew for each polynomial.
+fn evaluate_poly_over_cosets(
+    poly: &[Felt],
+    offset_powers: &[Felt],
+    twiddles: &[Felt],
+    result: &mut [Felt],
+) {
+    debug_assert_eq!(offset_powers.len(), result.len());
+    for (chunk, powers) in result.chunks_mut(poly.len()).zip(offset_powers.chunks(poly.len())) {
+        chunk.copy_from_slice(poly);
+        batch::mul_assign(chunk, powers);
+        chunk.fft_in_place(twiddles);
+    }
+    result.permute();
+}
+
+// SCRATCH MATRIX
+// ================================================================================================
+
+/// A row-major matrix of field elements stored in a memory-mapped scratch file.
+///
+/// The scratch file is created in the directory for temporary files (see [env::temp_dir()]) and
+/// is removed when the matrix is dropped. If the scratch file cannot be created or mapped, the
+/// matrix falls back to being stored in memory.
+struct ScratchMatrix {
+    storage: ScratchStorage,
+    num_rows: usize,
+    num_cols: usize,
+}
+
+enum ScratchStorage {
+    Mapped { map: Option<MmapMut>, path: PathBuf },
+    Memory(Vec<Felt>),
+}
+
+impl ScratchMatrix {
+    /// Returns a new matrix of zeros with the specified dimensions.
+    fn new(num_rows: usize, num_cols: usize) -> Self {
+        let len = num_rows * num_cols;
+        let storage = match map_scratch_file(len * size_of::<Felt>()) {
+            Ok((map, path)) => ScratchStorage::Mapped {
+                map: Some(map),
+                path,
+            },
+            Err(err) => {
+                event!(
+                    Level::WARN,
+                    "Failed to map a scratch file for the trace LDE, keeping it in memory: {err}"
+                );
+                ScratchStorage::Memory(Felt::zeroed_vector(len))
+            }
+        };
+
+        Self {
+            storage,
+            num_rows,
+            num_cols,
+        }
+    }
+
+    fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    fn is_mapped(&self) -> bool {
+        matches!(self.storage, ScratchStorage::Mapped { .. })
+    }
+
+    /// Returns the row at the specified index.
+    fn row(&self, row_idx: usize) -> &[Felt] {
+        let start = row_idx * self.num_cols;
+        &self.as_slice()[start..start + self.num_cols]
+    }
+
+    /// Writes the specified values into the column at the specified index.
+    fn set_column(&mut self, col_idx: usize, values: &[Felt]) {
+        debug_assert_eq!(values.len(), self.num_rows);
+        let num_cols = self.num_cols;
+        let data = self.as_mut_slice();
+        for (row_idx, &value) in values.iter().enumerate() {
+            data[row_idx * num_cols + col_idx] = value;
+        }
+    }
+
+    fn as_slice(&self) -> &[Felt] {
+        match &self.storage {
+            ScratchStorage::Mapped { map, .. } => {
+                let map = map.as_ref().expect("scratch file is not mapped");
+                // SAFETY: the map is page-aligned and holds `num_rows * num_cols` field elements;
+                // every bit pattern written into it comes from a valid field element, and zero
+                // bytes represent the zero element.
+                unsafe {
+                    slice::from_raw_parts(
+                        map.as_ptr() as *const Felt,
+                        self.num_rows * self.num_cols,
+                    )
+                }
+            }
+            ScratchStorage::Memory(data) => data,
+        }
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [Felt] {
+        let len = self.num_rows * self.num_cols;
+        match &mut self.storage {
+            ScratchStorage::Mapped { map, .. } => {
+                let map = map.as_mut().expect("scratch file is not mapped");
+                // SAFETY: see as_slice(); the map is borrowed mutably for the lifetime of the
+                // returned slice.
+                unsafe { slice::from_raw_parts_mut(map.as_mut_ptr() as *mut Felt, len) }
+            }
+            ScratchStorage::Memory(data) => data,
+        }
+    }
+}
+
+impl Drop for ScratchMatrix {
+    fn drop(&mut self) {
+        if let ScratchStorage::Mapped { map, path } = &mut self.storage {
+            // unmap the file before removing it, as some platforms do not allow removing mapped
+            // files
+            drop(map.take());
+            let _ = fs::remove_file(path);
+        }
+    }
+}
+
+/// Creates a scratch file of the specified size in the directory for temporary files and maps it
+/// into memory.
+fn map_scratch_file(size: usize) -> io::Result<(MmapMut, PathBuf)> {
+    let file_id = NEXT_SCRATCH_FILE_ID.fetch_add(1, Ordering::Relaxed);
+    let path = env::temp_dir().join(format!("miden-trace-{}-{file_id}.lde", process::id()));
+    let file = OpenOptions::new().read(true).write(true).create_new(true).open(&path)?;
+
+    // SAFETY: the file has just been created by this process under a unique name, and thus, it
+    // is not modified by anyone else while it is mapped.
+    let map = file.set_len(size as u64).and_then(|_| unsafe { MmapMut::map_mut(&file) });
+    match map {
+        Ok(map) => Ok((map, path)),
+        Err(err) => {
+            let _ = fs::remove_file(&path);
+            Err(err)
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use air::{ProvingOptions, StarkField};
+    use processor::{
+        crypto::{Rpo256, RpoRandomCoin},
+        StackInputs, StackOutputs,
+    };
+    use winter_prover::math::fields::QuadExtension;
+
+    type QuadFelt = QuadExtension<Felt>;
+
+    #[test]
+    fn mmap_trace_commitment_matches_default() {
+        let default_prover = create_test_prover();
+        let mmap_prover = MmapExecutionProver(create_test_prover());
+        let num_rows = 1 << 8;
+        let trace_info = TraceInfo::new(9, num_rows);
+        let trace = ColMatrix::new(
+            (0..9_u64)
+                .map(|col| (0..num_rows as u64).map(|row| Felt::new(col * row + 1)).collect())
+                .collect(),
+        );
+        let domain = StarkDomain::from_twiddles(fft::get_twiddles(num_rows), 8, Felt::GENERATOR);
+
+        let (default_trace_lde, default_polys) =
+            default_prover.new_trace_lde::<QuadFelt>(&trace_info, &trace, &domain);
+        let (mmap_trace_lde, mmap_polys) =
+            mmap_prover.new_trace_lde::<QuadFelt>(&trace_info, &trace, &domain);
+
+        assert!(mmap_trace_lde.main_segment_lde.is_mapped());
+        assert_eq!(
+            default_trace_lde.get_main_trace_commitment(),
+            mmap_trace_lde.get_main_trace_commitment()
+        );
+        assert_eq!(
+            default_polys.main_trace_polys().collect::<Vec<_>>(),
+            mmap_polys.main_trace_polys().collect::<Vec<_>>()
+        );
+    }
+
+    fn create_test_prover() -> ExecutionProver<'static, Rpo256, RpoRandomCoin> {
+        ExecutionProver::new(
+            ProvingOptions::with_96_bit_security(false),
+            StackInputs::default(),
+            StackOutputs::default(),
+        )
+    }
+}
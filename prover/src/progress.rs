@@ -0,0 +1,95 @@
+use super::MemoryUsage;
+use core::fmt;
+
+// PROVING STAGE
+// ================================================================================================
+
+/// A stage of proof generation.
+///
+/// The stages are listed in the order in which they are performed by the prover.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ProvingStage {
+    /// Execution of the program and construction of the main segment of the execution trace.
+    Execution,
+    /// Low-degree extension of the main trace segment and commitment to the extended segment.
+    TraceLde,
+    /// Construction, low-degree extension and commitment of the auxiliary trace segment.
+    AuxTrace,
+    /// Evaluation of the AIR constraints over the constraint evaluation domain.
+    ConstraintEvaluation,
+    /// Construction of the constraint composition polynomial and commitment to its evaluations.
+    ConstraintCommitment,
+    /// Construction of the DEEP composition polynomial, the FRI layers, and the query openings.
+    Fri,
+}
+
+impl ProvingStage {
+    /// Returns an approximate share of the proof generation completed before this stage starts,
+    /// in percent.
+    ///
+    /// The shares are based on the typical relative durations of the stages, and are meant to be
+    /// used for progress indicators only.
+    pub const fn start_percentage(&self) -> u8 {
+        match self {
+            Self::Execution => 0,
+            Self::TraceLde => 10,
+            Self::AuxTrace => 35,
+            Self::ConstraintEvaluation => 45,
+            Self::ConstraintCommitment => 65,
+            Self::Fri => 80,
+        }
+    }
+
+    /// Returns an approximate share of the proof generation completed once this stage ends, in
+    /// percent.
+    pub const fn end_percentage(&self) -> u8 {
+        match self {
+            Self::Execution => Self::TraceLde.start_percentage(),
+            Self::TraceLde => Self::AuxTrace.start_percentage(),
+            Self::AuxTrace => Self::ConstraintEvaluation.start_percentage(),
+            Self::ConstraintEvaluation => Self::ConstraintCommitment.start_percentage(),
+            Self::ConstraintCommitment => Self::Fri.start_percentage(),
+            Self::Fri => 100,
+        }
+    }
+}
+
+impl fmt::Display for ProvingStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Execution => write!(f, "building execution trace"),
+            Self::TraceLde => write!(f, "extending and committing to main trace"),
+            Self::AuxTrace => write!(f, "building auxiliary trace"),
+            Self::ConstraintEvaluation => write!(f, "evaluating constraints"),
+            Self::ConstraintCommitment => write!(f, "committing to constraint evaluations"),
+            Self::Fri => write!(f, "computing FRI layers and queries"),
+        }
+    }
+}
+
+// PROGRESS OBSERVER
+// ================================================================================================
+
+/// An observer of the progress of proof generation.
+///
+/// The prover notifies the observer when each [ProvingStage] starts and ends. The stages are
+/// performed sequentially, and thus, the end of a stage is always followed by the start of the
+/// next one, until [ProvingStage::Fri] ends, at which point the proof has been generated. If
+/// proof generation fails, no further notifications are made.
+///
+/// Once the execution trace has been built, the observer is also notified about the estimated
+/// memory usage of proof generation via [ProgressObserver::on_memory_usage()].
+pub trait ProgressObserver {
+    /// Called when the prover starts the specified stage.
+    fn on_stage_started(&self, _stage: ProvingStage) {}
+
+    /// Called when the prover completes the specified stage.
+    fn on_stage_completed(&self, _stage: ProvingStage) {}
+
+    /// Called with the estimated memory usage of proof generation before the proof of the
+    /// execution trace is generated.
+    fn on_memory_usage(&self, _usage: &MemoryUsage) {}
+}
+
+/// An observer which ignores all notifications.
+impl ProgressObserver for () {}
@@ -0,0 +1,332 @@
+use super::{
+    prove, AdviceInputs, ExecutionError, ExecutionProof, MemAdviceProvider, Program, ProgramInfo,
+    ProvingOptions, StackInputs, StackOutputs,
+};
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+use processor::{
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    DefaultHost,
+};
+use verifier::VerificationError;
+
+// PROGRAM PROVER
+// ================================================================================================
+
+/// An interface for generating proofs of program execution.
+///
+/// Unlike [prove()], the interface takes non-deterministic inputs as [AdviceInputs] rather than as
+/// a [Host](super::Host), so that the inputs can be shipped to wherever the proof is generated.
+pub trait ProgramProver {
+    type Error;
+
+    /// Executes and proves the specified `program` and returns the result together with a
+    /// STARK-based proof of the program's execution.
+    fn prove_program(
+        &self,
+        program: &Program,
+        stack_inputs: StackInputs,
+        advice_inputs: AdviceInputs,
+        options: ProvingOptions,
+    ) -> Result<(StackOutputs, ExecutionProof), Self::Error>;
+}
+
+/// A [ProgramProver] which generates proofs in the current process.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LocalProver;
+
+impl ProgramProver for LocalProver {
+    type Error = ExecutionError;
+
+    fn prove_program(
+        &self,
+        program: &Program,
+        stack_inputs: StackInputs,
+        advice_inputs: AdviceInputs,
+        options: ProvingOptions,
+    ) -> Result<(StackOutputs, ExecutionProof), Self::Error> {
+        let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
+        prove(program, stack_inputs, host, options)
+    }
+}
+
+// REMOTE PROVER
+// ================================================================================================
+
+/// A channel through which [RemoteProver] sends serialized [ProofRequest]s to a proving endpoint.
+///
+/// The transport is responsible for delivering the request to the endpoint and for returning the
+/// serialized [ProofResponse]. This allows delegating proof generation over any medium available
+/// to the client (e.g., HTTP in native applications, or `fetch` in browsers).
+pub trait ProofTransport {
+    /// Sends the serialized request to the proving endpoint and returns the serialized response.
+    ///
+    /// # Errors
+    /// Returns an error if the request could not be delivered or if the endpoint failed to
+    /// generate the proof.
+    fn send(&self, request: &[u8]) -> Result<Vec<u8>, String>;
+}
+
+/// A [ProgramProver] which delegates proof generation to a remote proving endpoint.
+///
+/// By default, the program is first executed locally (preflight) to make sure it executes
+/// successfully against the provided inputs before the comparatively expensive proof is requested,
+/// and the outputs returned by the endpoint are checked against the outputs of the local execution.
+///
+/// The proof returned by the endpoint is verified locally against the program, the inputs, and the
+/// returned outputs before it is returned, as the endpoint is not trusted.
+pub struct RemoteProver<T: ProofTransport> {
+    transport: T,
+    preflight: bool,
+    verification: bool,
+}
+
+impl<T: ProofTransport> RemoteProver<T> {
+    /// Returns a new [RemoteProver] sending proof requests via the specified transport.
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            preflight: true,
+            verification: true,
+        }
+    }
+
+    /// Disables local execution of programs before their proofs are requested.
+    pub fn without_preflight(mut self) -> Self {
+        self.preflight = false;
+        self
+    }
+
+    /// Disables local verification of the proofs returned by the endpoint.
+    ///
+    /// This should be used only if the proofs are verified elsewhere before they are relied upon.
+    pub fn without_verification(mut self) -> Self {
+        self.verification = false;
+        self
+    }
+
+    /// Returns the transport via which this prover sends proof requests.
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+}
+
+impl<T: ProofTransport> ProgramProver for RemoteProver<T> {
+    type Error = RemoteProverError;
+
+    fn prove_program(
+        &self,
+        program: &Program,
+        stack_inputs: StackInputs,
+        advice_inputs: AdviceInputs,
+        options: ProvingOptions,
+    ) -> Result<(StackOutputs, ExecutionProof), Self::Error> {
+        let expected_outputs = if self.preflight {
+            let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs.clone()));
+            let trace = processor::execute(
+                program,
+                stack_inputs.clone(),
+                host,
+                *options.execution_options(),
+            )
+            .map_err(RemoteProverError::PreflightFailed)?;
+            Some(trace.stack_outputs().clone())
+        } else {
+            None
+        };
+
+        let request = ProofRequest {
+            program: program.clone(),
+            stack_inputs: stack_inputs.clone(),
+            advice_inputs,
+            options,
+        };
+        let response = self
+            .transport
+            .send(&request.to_bytes())
+            .map_err(RemoteProverError::TransportFailed)?;
+        let response = ProofResponse::read_from_bytes(&response)
+            .map_err(RemoteProverError::InvalidResponse)?;
+
+        if let Some(expected_outputs) = expected_outputs {
+            if response.stack_outputs != expected_outputs {
+                return Err(RemoteProverError::OutputsMismatch {
+                    expected: expected_outputs,
+                    actual: response.stack_outputs,
+                });
+            }
+        }
+
+        if self.verification {
+            verifier::verify(
+                ProgramInfo::from(program.clone()),
+                stack_inputs,
+                response.stack_outputs.clone(),
+                response.proof.clone(),
+            )
+            .map_err(RemoteProverError::InvalidProof)?;
+        }
+
+        Ok((response.stack_outputs, response.proof))
+    }
+}
+
+// PROOF REQUEST
+// ================================================================================================
+
+/// A request to prove execution of a program, as sent by [RemoteProver] to a proving endpoint.
+#[derive(Debug, Clone)]
+pub struct ProofRequest {
+    pub program: Program,
+    pub stack_inputs: StackInputs,
+    pub advice_inputs: AdviceInputs,
+    pub options: ProvingOptions,
+}
+
+impl ProofRequest {
+    /// Proves execution of the requested program with the specified prover.
+    pub fn prove<P: ProgramProver>(self, prover: &P) -> Result<ProofResponse, P::Error> {
+        let (stack_outputs, proof) = prover.prove_program(
+            &self.program,
+            self.stack_inputs,
+            self.advice_inputs,
+            self.options,
+        )?;
+        Ok(ProofResponse {
+            stack_outputs,
+            proof,
+        })
+    }
+}
+
+/// A response of a proving endpoint to a [ProofRequest].
+#[derive(Debug, Clone)]
+pub struct ProofResponse {
+    pub stack_outputs: StackOutputs,
+    pub proof: ExecutionProof,
+}
+
+impl Serializable for ProofRequest {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.program.write_into(target);
+        self.stack_inputs.write_into(target);
+        self.advice_inputs.write_into(target);
+        self.options.write_into(target);
+    }
+}
+
+impl Deserializable for ProofRequest {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            program: Program::read_from(source)?,
+            stack_inputs: StackInputs::read_from(source)?,
+            advice_inputs: AdviceInputs::read_from(source)?,
+            options: ProvingOptions::read_from(source)?,
+        })
+    }
+}
+
+impl Serializable for ProofResponse {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.stack_outputs.write_into(target);
+        self.proof.write_into(target);
+    }
+}
+
+impl Deserializable for ProofResponse {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            stack_outputs: StackOutputs::read_from(source)?,
+            proof: ExecutionProof::read_from(source)?,
+        })
+    }
+}
+
+// HTTP TRANSPORT
+// ================================================================================================
+
+/// A [ProofTransport] which sends proof requests as bodies of HTTP `POST` requests.
+///
+/// The transport is compatible with the `/prove` endpoint of `miden-proving-service`.
+#[cfg(feature = "remote")]
+#[derive(Debug, Clone)]
+pub struct HttpTransport {
+    endpoint: String,
+}
+
+#[cfg(feature = "remote")]
+impl HttpTransport {
+    /// Returns a new transport sending requests to the specified URL.
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+        }
+    }
+
+    /// Returns the URL to which requests are sent.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+}
+
+#[cfg(feature = "remote")]
+impl ProofTransport for HttpTransport {
+    fn send(&self, request: &[u8]) -> Result<Vec<u8>, String> {
+        use alloc::{format, string::ToString};
+        use std::io::Read;
+
+        let response = ureq::post(&self.endpoint)
+            .set("Content-Type", "application/octet-stream")
+            .send_bytes(request)
+            .map_err(|err| match err {
+                ureq::Error::Status(code, response) => {
+                    let message = response.into_string().unwrap_or_default();
+                    format!("proving endpoint responded with status {code}: {message}")
+                }
+                ureq::Error::Transport(err) => err.to_string(),
+            })?;
+
+        let mut bytes = Vec::new();
+        response
+            .into_reader()
+            .read_to_end(&mut bytes)
+            .map_err(|err| format!("failed to read response: {err}"))?;
+        Ok(bytes)
+    }
+}
+
+// ERRORS
+// ================================================================================================
+
+#[derive(Debug)]
+pub enum RemoteProverError {
+    InvalidProof(VerificationError),
+    InvalidResponse(DeserializationError),
+    OutputsMismatch {
+        expected: StackOutputs,
+        actual: StackOutputs,
+    },
+    PreflightFailed(ExecutionError),
+    TransportFailed(String),
+}
+
+impl fmt::Display for RemoteProverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use RemoteProverError::*;
+        match self {
+            InvalidProof(err) => write!(f, "proof returned by remote prover is invalid: {err}"),
+            InvalidResponse(err) => write!(f, "failed to deserialize proof response: {err}"),
+            OutputsMismatch { expected, actual } => write!(
+                f,
+                "remote prover returned outputs {:?}, but local execution produced {:?}",
+                actual.stack(),
+                expected.stack()
+            ),
+            PreflightFailed(err) => write!(f, "local execution of the program failed: {err:?}"),
+            TransportFailed(err) => write!(f, "failed to obtain proof from remote prover: {err}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RemoteProverError {}
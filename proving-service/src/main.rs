@@ -0,0 +1,193 @@
+use clap::Parser;
+use miden_vm::ProverMemoryBudget;
+use service::{ProvingService, ServiceConfig};
+use std::{
+    io::Read,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+mod rpc;
+mod service;
+
+#[cfg(test)]
+mod tests;
+
+/// Path on which serialized proof requests sent by remote provers are accepted.
+const PROVE_PATH: &str = "/prove";
+
+/// Root CLI struct
+#[derive(Parser, Debug)]
+#[clap(
+    name = "miden-proving-service",
+    about = "JSON-RPC service for proving Miden programs",
+    version,
+    rename_all = "kebab-case"
+)]
+pub struct Cli {
+    /// Address on which the service listens for requests
+    #[clap(short = 'l', long = "listen", default_value = "127.0.0.1:8080")]
+    listen: String,
+
+    /// Number of proofs generated concurrently
+    #[clap(short = 'w', long = "workers", default_value_t = 1)]
+    workers: usize,
+
+    /// Maximum number of proving jobs waiting in the queue
+    #[clap(short = 'q', long = "max-queued-jobs", default_value_t = 64)]
+    max_queued_jobs: usize,
+
+    /// Number of seconds for which finished jobs, programs and inputs are kept after their last use
+    #[clap(long = "job-ttl", default_value_t = 3600)]
+    job_ttl: u64,
+
+    /// Maximum size of a request body in bytes
+    #[clap(long = "max-body-size", default_value_t = 16 * 1024 * 1024)]
+    max_body_size: u64,
+
+    /// Maximum number of JSON-RPC requests handled concurrently
+    #[clap(long = "max-rpc-requests", default_value_t = 16)]
+    max_rpc_requests: usize,
+
+    /// Maximum number of programs stored at the same time
+    #[clap(long = "max-programs", default_value_t = 1024)]
+    max_programs: usize,
+
+    /// Maximum number of inputs stored at the same time
+    #[clap(long = "max-inputs", default_value_t = 1024)]
+    max_inputs: usize,
+
+    /// Maximum number of cycles a proved program is allowed to execute
+    #[clap(long = "max-cycles", default_value_t = 1 << 24)]
+    max_cycles: u32,
+
+    /// Maximum amount of memory in megabytes a single proof is allowed to require
+    #[clap(long = "memory-budget", value_name = "MB", default_value_t = 16 * 1024)]
+    memory_budget: usize,
+
+    /// Number of seconds for which a request to `/prove` waits for its proof
+    #[clap(long = "proof-timeout", default_value_t = 600)]
+    proof_timeout: u64,
+}
+
+/// Executable entry point
+pub fn main() {
+    let cli = Cli::parse();
+    if let Err(err) = serve(&cli) {
+        eprintln!("{err}");
+        std::process::exit(1);
+    }
+}
+
+/// Handles requests until the process is terminated.
+///
+/// Serialized proof requests are accepted as the bodies of POST requests to `/prove`, while JSON-RPC
+/// requests are accepted as the bodies of POST requests to any other path.
+fn serve(cli: &Cli) -> Result<(), String> {
+    let server = Server::http(&cli.listen)
+        .map_err(|err| format!("failed to listen on {}: {err}", cli.listen))?;
+    let service = Arc::new(ProvingService::new(ServiceConfig {
+        workers: cli.workers,
+        max_queued_jobs: cli.max_queued_jobs,
+        job_ttl: Duration::from_secs(cli.job_ttl),
+        max_programs: cli.max_programs,
+        max_inputs: cli.max_inputs,
+        max_cycles: cli.max_cycles,
+        memory_budget: ProverMemoryBudget::new(cli.memory_budget << 20),
+        wait_timeout: Duration::from_secs(cli.proof_timeout),
+    }));
+    println!("Proving service listening on {}", cli.listen);
+
+    // at most this many proof requests can be running or queued at the same time; any further
+    // requests would be rejected by the service anyway
+    let max_proof_requests = cli.workers.max(1) + cli.max_queued_jobs;
+    let proof_requests = Arc::new(AtomicUsize::new(0));
+    let rpc_requests = Arc::new(AtomicUsize::new(0));
+
+    let content_type = Header::from_bytes("Content-Type", "application/json")
+        .expect("content type header must be valid");
+    for mut request in server.incoming_requests() {
+        if request.method() != &Method::Post {
+            let _ = request.respond(Response::empty(405));
+            continue;
+        }
+
+        let body = match read_body(&mut request, cli.max_body_size) {
+            Ok(body) => body,
+            Err(code) => {
+                let _ = request.respond(Response::empty(code));
+                continue;
+            }
+        };
+
+        // requests are served on separate threads, the number of which is bounded, so that
+        // neither proof requests of remote provers, which block until the proof is generated, nor
+        // JSON-RPC requests compiling programs block accepting further requests
+        let service = service.clone();
+        if request.url() == PROVE_PATH {
+            spawn_limited(request, &proof_requests, max_proof_requests, move |request| {
+                let _ = match rpc::handle_proof_request(&service, &body) {
+                    Ok(response) => request.respond(Response::from_data(response)),
+                    Err((code, err)) => {
+                        request.respond(Response::from_string(err).with_status_code(code))
+                    }
+                };
+            });
+        } else {
+            let content_type = content_type.clone();
+            spawn_limited(request, &rpc_requests, cli.max_rpc_requests, move |request| {
+                let response = rpc::handle_request(&service, &String::from_utf8_lossy(&body));
+                let response =
+                    Response::from_string(response.to_string()).with_header(content_type);
+                let _ = request.respond(response);
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Handles the specified request on a new thread, unless `max` requests counted by `counter` are
+/// already being handled, in which case the request is rejected with status code 503.
+fn spawn_limited<F>(request: Request, counter: &Arc<AtomicUsize>, max: usize, handler: F)
+where
+    F: FnOnce(Request) + Send + 'static,
+{
+    if counter.fetch_add(1, Ordering::SeqCst) >= max {
+        counter.fetch_sub(1, Ordering::SeqCst);
+        let _ = request.respond(Response::empty(503));
+        return;
+    }
+    let counter = counter.clone();
+    thread::spawn(move || {
+        handler(request);
+        counter.fetch_sub(1, Ordering::SeqCst);
+    });
+}
+
+/// Reads the body of the specified request.
+///
+/// # Errors
+/// Returns the HTTP status code to respond with if the body is larger than `max_size` bytes or
+/// could not be read.
+fn read_body(request: &mut Request, max_size: u64) -> Result<Vec<u8>, u16> {
+    if request.body_length().is_some_and(|len| len as u64 > max_size) {
+        return Err(413);
+    }
+
+    let mut body = Vec::new();
+    request
+        .as_reader()
+        .take(max_size + 1)
+        .read_to_end(&mut body)
+        .map_err(|_| 400_u16)?;
+    if body.len() as u64 > max_size {
+        return Err(413);
+    }
+    Ok(body)
+}
@@ -0,0 +1,257 @@
+use super::service::{JobInputs, JobStatus, ProvingService, ServiceError};
+use miden_vm::{
+    math::Felt,
+    utils::{Deserializable, Serializable},
+    AdviceInputs, Digest, ProofRequest, ProofResponse, ProvingOptions, StackInputs,
+};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::{json, Value};
+
+// CONSTANTS
+// ================================================================================================
+
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const SERVICE_ERROR: i64 = -32000;
+
+// JSON-RPC
+// ================================================================================================
+
+#[derive(Deserialize)]
+struct Request {
+    jsonrpc: String,
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// An error returned to the client in a JSON-RPC response.
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RpcError {
+    fn new(code: i64, message: impl ToString) -> Self {
+        Self {
+            code,
+            message: message.to_string(),
+        }
+    }
+}
+
+/// Handles the specified JSON-RPC 2.0 request and returns the response.
+///
+/// The following methods are supported:
+/// - `submit_program` - compiles a program and returns its hash.
+/// - `submit_inputs` - stores stack and advice inputs and returns their ID.
+/// - `prove` - queues a job proving execution of a program against inputs, and returns its ID.
+/// - `status` - returns the status of a job.
+/// - `result` - returns the outputs and the proof generated by a completed job.
+pub fn handle_request(service: &ProvingService, body: &str) -> Value {
+    let request = match serde_json::from_str::<Value>(body) {
+        Ok(request) => request,
+        Err(err) => return error_response(Value::Null, RpcError::new(PARSE_ERROR, err)),
+    };
+    let request = match serde_json::from_value::<Request>(request) {
+        Ok(request) if request.jsonrpc == "2.0" => request,
+        Ok(request) => {
+            let err = RpcError::new(INVALID_REQUEST, "only JSON-RPC 2.0 is supported");
+            return error_response(request.id, err);
+        }
+        Err(err) => return error_response(Value::Null, RpcError::new(INVALID_REQUEST, err)),
+    };
+
+    let result = match request.method.as_str() {
+        "submit_program" => submit_program(service, request.params),
+        "submit_inputs" => submit_inputs(service, request.params),
+        "prove" => prove(service, request.params),
+        "status" => status(service, request.params),
+        "result" => result(service, request.params),
+        method => Err(RpcError::new(METHOD_NOT_FOUND, format!("unknown method `{method}`"))),
+    };
+
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": request.id, "result": result }),
+        Err(err) => error_response(request.id, err),
+    }
+}
+
+/// Handles the specified serialized [ProofRequest], as sent by a
+/// [RemoteProver](miden_vm::RemoteProver), and returns the serialized [ProofResponse].
+///
+/// Unlike the `prove` JSON-RPC method, the request blocks until the proof is generated or the
+/// wait timeout of the service elapses. The limits of the service are applied to the proving
+/// options of the request in the same way as for the `prove` method.
+///
+/// # Errors
+/// Returns the HTTP status code and the description of the error if the request is malformed, if
+/// the service cannot accept it, or if the proof could not be generated in time.
+pub fn handle_proof_request(
+    service: &ProvingService,
+    body: &[u8],
+) -> Result<Vec<u8>, (u16, String)> {
+    let request = ProofRequest::read_from_bytes(body)
+        .map_err(|err| (400, format!("invalid proof request: {err}")))?;
+
+    let to_status = |err: ServiceError| {
+        let code = match err {
+            ServiceError::InvalidOptions(_) => 400,
+            ServiceError::JobFailed(..) => 500,
+            ServiceError::JobTimedOut(_) => 504,
+            _ => 503,
+        };
+        (code, err.to_string())
+    };
+    let program_hash = service.submit_compiled_program(request.program).map_err(to_status)?;
+    let inputs_id = service
+        .submit_inputs(JobInputs {
+            stack_inputs: request.stack_inputs,
+            advice_inputs: request.advice_inputs,
+        })
+        .map_err(to_status)?;
+    let job_id = service.prove(program_hash, inputs_id, request.options).map_err(to_status)?;
+    let result = service.wait(job_id).map_err(to_status)?;
+
+    let response = ProofResponse {
+        stack_outputs: result.stack_outputs,
+        proof: result.proof,
+    };
+    Ok(response.to_bytes())
+}
+
+fn error_response(id: Value, err: RpcError) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": err.code, "message": err.message },
+    })
+}
+
+// METHODS
+// ================================================================================================
+
+#[derive(Deserialize)]
+struct SubmitProgramParams {
+    source: String,
+}
+
+fn submit_program(service: &ProvingService, params: Value) -> Result<Value, RpcError> {
+    let params: SubmitProgramParams = parse_params(params)?;
+    let program_hash = service.submit_program(&params.source).map_err(service_error)?;
+    Ok(json!({ "program_hash": hex::encode(program_hash.as_bytes()) }))
+}
+
+#[derive(Deserialize)]
+struct SubmitInputsParams {
+    #[serde(default)]
+    stack_inputs: Vec<u64>,
+    #[serde(default)]
+    advice_stack: Vec<u64>,
+    /// Hex-encoded serialized advice inputs, which are combined with the advice stack.
+    #[serde(default)]
+    advice_inputs: Option<String>,
+}
+
+fn submit_inputs(service: &ProvingService, params: Value) -> Result<Value, RpcError> {
+    let params: SubmitInputsParams = parse_params(params)?;
+    let invalid_params = |err: String| RpcError::new(INVALID_PARAMS, err);
+
+    let stack_inputs = StackInputs::try_from_ints(params.stack_inputs)
+        .map_err(|err| invalid_params(err.to_string()))?;
+    let mut advice_inputs = match params.advice_inputs {
+        Some(advice_inputs) => {
+            let bytes =
+                hex::decode(advice_inputs).map_err(|err| invalid_params(err.to_string()))?;
+            AdviceInputs::read_from_bytes(&bytes).map_err(|err| invalid_params(err.to_string()))?
+        }
+        None => AdviceInputs::default(),
+    };
+    advice_inputs = advice_inputs
+        .with_stack_values(params.advice_stack)
+        .map_err(|err| invalid_params(err.to_string()))?;
+
+    let inputs_id = service
+        .submit_inputs(JobInputs {
+            stack_inputs,
+            advice_inputs,
+        })
+        .map_err(service_error)?;
+    Ok(json!({ "inputs_id": inputs_id }))
+}
+
+#[derive(Deserialize)]
+struct ProveParams {
+    program_hash: String,
+    inputs_id: u64,
+    #[serde(default = "default_security")]
+    security: String,
+    #[serde(default)]
+    recursive: bool,
+}
+
+fn default_security() -> String {
+    "96bits".to_string()
+}
+
+fn prove(service: &ProvingService, params: Value) -> Result<Value, RpcError> {
+    let params: ProveParams = parse_params(params)?;
+    let program_hash = hex::decode(&params.program_hash)
+        .map_err(|err| err.to_string())
+        .and_then(|bytes| Digest::read_from_bytes(&bytes).map_err(|err| err.to_string()))
+        .map_err(|err| RpcError::new(INVALID_PARAMS, format!("invalid program hash: {err}")))?;
+    let options = match params.security.as_str() {
+        "96bits" => ProvingOptions::with_96_bit_security(params.recursive),
+        "128bits" => ProvingOptions::with_128_bit_security(params.recursive),
+        "128bits-proven" => ProvingOptions::with_128bit_proven(params.recursive),
+        other => {
+            let message = format!("{other} is not a valid security setting");
+            return Err(RpcError::new(INVALID_PARAMS, message));
+        }
+    };
+
+    let job_id = service.prove(program_hash, params.inputs_id, options).map_err(service_error)?;
+    Ok(json!({ "job_id": job_id }))
+}
+
+#[derive(Deserialize)]
+struct JobParams {
+    job_id: u64,
+}
+
+fn status(service: &ProvingService, params: Value) -> Result<Value, RpcError> {
+    let params: JobParams = parse_params(params)?;
+    Ok(match service.status(params.job_id).map_err(service_error)? {
+        JobStatus::Queued => json!({ "status": "queued" }),
+        JobStatus::Running => json!({ "status": "running" }),
+        JobStatus::Completed => json!({ "status": "completed" }),
+        JobStatus::Failed(err) => json!({ "status": "failed", "error": err }),
+    })
+}
+
+fn result(service: &ProvingService, params: Value) -> Result<Value, RpcError> {
+    let params: JobParams = parse_params(params)?;
+    let result = service.result(params.job_id).map_err(service_error)?;
+    let to_ints =
+        |values: &[Felt]| -> Vec<u64> { values.iter().map(|value| value.as_int()).collect() };
+    Ok(json!({
+        "stack_outputs": to_ints(result.stack_outputs.stack()),
+        "overflow_addrs": to_ints(result.stack_outputs.overflow_addrs()),
+        "proof": hex::encode(result.proof.to_bytes()),
+    }))
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, RpcError> {
+    serde_json::from_value(params).map_err(|err| RpcError::new(INVALID_PARAMS, err))
+}
+
+fn service_error(err: impl ToString) -> RpcError {
+    RpcError::new(SERVICE_ERROR, err)
+}
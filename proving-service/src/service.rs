@@ -0,0 +1,486 @@
+use core::fmt;
+use miden_vm::{
+    prove, AdviceInputs, Assembler, DefaultHost, Digest, ExecutionOptions, ExecutionProof,
+    MemAdviceProvider, Program, ProverMemoryBudget, ProvingOptions, StackInputs, StackOutputs,
+};
+use std::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Condvar, Mutex, MutexGuard},
+    thread::{self, JoinHandle},
+    time::{Duration, Instant},
+};
+use stdlib::StdLibrary;
+
+// SERVICE CONFIG
+// ================================================================================================
+
+/// Limits on the amount of work the proving service performs on behalf of its clients.
+#[derive(Debug, Clone, Copy)]
+pub struct ServiceConfig {
+    /// Number of proofs generated concurrently; each proof is generated by a separate worker.
+    pub workers: usize,
+    /// Maximum number of jobs waiting in the queue; new jobs are rejected once it is full.
+    pub max_queued_jobs: usize,
+    /// Time for which finished jobs, as well as programs and inputs not referenced by any
+    /// unfinished job, are kept after they were last used.
+    pub job_ttl: Duration,
+    /// Maximum number of programs stored at the same time.
+    pub max_programs: usize,
+    /// Maximum number of inputs stored at the same time.
+    pub max_inputs: usize,
+    /// Maximum number of cycles a job is allowed to execute, regardless of the options of the job.
+    pub max_cycles: u32,
+    /// Memory budget of every job, which replaces the memory budget requested by the client.
+    pub memory_budget: ProverMemoryBudget,
+    /// Time for which [ProvingService::wait()] waits for a job to finish.
+    pub wait_timeout: Duration,
+}
+
+impl Default for ServiceConfig {
+    fn default() -> Self {
+        Self {
+            workers: 1,
+            max_queued_jobs: 64,
+            job_ttl: Duration::from_secs(3600),
+            max_programs: 1024,
+            max_inputs: 1024,
+            max_cycles: 1 << 24,
+            memory_budget: ProverMemoryBudget::new(16 << 30),
+            wait_timeout: Duration::from_secs(600),
+        }
+    }
+}
+
+// JOBS
+// ================================================================================================
+
+/// Public and secret inputs against which a program is executed.
+#[derive(Debug, Clone, Default)]
+pub struct JobInputs {
+    pub stack_inputs: StackInputs,
+    pub advice_inputs: AdviceInputs,
+}
+
+/// Status of a proving job.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed(String),
+}
+
+/// Outputs and proof of a completed proving job.
+#[derive(Debug, Clone)]
+pub struct JobResult {
+    pub stack_outputs: StackOutputs,
+    pub proof: ExecutionProof,
+}
+
+struct Job {
+    program_hash: Digest,
+    inputs_id: u64,
+    options: ProvingOptions,
+    status: JobStatus,
+    result: Option<JobResult>,
+    finished_at: Option<Instant>,
+}
+
+/// An item stored by the service together with the time it was last used.
+struct Stored<T> {
+    item: T,
+    last_used: Instant,
+}
+
+impl<T> Stored<T> {
+    fn new(item: T) -> Self {
+        Self {
+            item,
+            last_used: Instant::now(),
+        }
+    }
+}
+
+// PROVING SERVICE
+// ================================================================================================
+
+/// A service which compiles submitted programs and proves their execution against submitted
+/// inputs in the background.
+///
+/// Proving jobs are queued and executed by a fixed number of worker threads. Programs, inputs,
+/// and finished jobs are kept in memory until they have not been used for the configured time to
+/// live; expired items are evicted whenever new items are submitted.
+pub struct ProvingService {
+    shared: Arc<Shared>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+struct Shared {
+    config: ServiceConfig,
+    state: Mutex<State>,
+    job_queued: Condvar,
+    job_finished: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    programs: BTreeMap<Digest, Stored<Program>>,
+    inputs: BTreeMap<u64, Stored<JobInputs>>,
+    jobs: BTreeMap<u64, Job>,
+    queue: VecDeque<u64>,
+    next_id: u64,
+    shutdown: bool,
+}
+
+impl State {
+    fn next_id(&mut self) -> u64 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    /// Removes jobs which finished more than `ttl` ago, as well as programs and inputs which were
+    /// last used more than `ttl` ago and are not referenced by any unfinished job.
+    fn evict_expired(&mut self, ttl: Duration) {
+        let now = Instant::now();
+        let is_expired = |time: Instant| now.saturating_duration_since(time) >= ttl;
+
+        self.jobs.retain(|_, job| !job.finished_at.is_some_and(is_expired));
+
+        let mut used_programs = BTreeSet::new();
+        let mut used_inputs = BTreeSet::new();
+        for job in self.jobs.values().filter(|job| job.finished_at.is_none()) {
+            used_programs.insert(job.program_hash);
+            used_inputs.insert(job.inputs_id);
+        }
+        self.programs
+            .retain(|hash, program| used_programs.contains(hash) || !is_expired(program.last_used));
+        self.inputs
+            .retain(|id, inputs| used_inputs.contains(id) || !is_expired(inputs.last_used));
+    }
+}
+
+impl ProvingService {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new proving service and starts its worker threads.
+    pub fn new(config: ServiceConfig) -> Self {
+        let shared = Arc::new(Shared {
+            config,
+            state: Mutex::new(State::default()),
+            job_queued: Condvar::new(),
+            job_finished: Condvar::new(),
+        });
+        let workers = (0..config.workers.max(1))
+            .map(|_| {
+                let shared = shared.clone();
+                thread::spawn(move || run_worker(&shared))
+            })
+            .collect();
+
+        Self { shared, workers }
+    }
+
+    // PUBLIC METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Compiles the specified Miden assembly program and returns its hash, by which the program
+    /// can be referenced in proving jobs.
+    ///
+    /// # Errors
+    /// Returns an error if the program could not be compiled, or if the maximum number of programs
+    /// is already stored.
+    pub fn submit_program(&self, source: &str) -> Result<Digest, ServiceError> {
+        let program = Assembler::default()
+            .with_library(&StdLibrary::default())
+            .and_then(|assembler| assembler.compile(source))
+            .map_err(|err| ServiceError::CompilationFailed(err.to_string()))?;
+
+        self.submit_compiled_program(program)
+    }
+
+    /// Stores the specified compiled program and returns its hash, by which the program can be
+    /// referenced in proving jobs.
+    ///
+    /// # Errors
+    /// Returns an error if the maximum number of programs is already stored.
+    pub fn submit_compiled_program(&self, program: Program) -> Result<Digest, ServiceError> {
+        let program_hash = program.hash();
+        let max_programs = self.shared.config.max_programs;
+        let mut state = self.state();
+        state.evict_expired(self.shared.config.job_ttl);
+        if !state.programs.contains_key(&program_hash) && state.programs.len() >= max_programs {
+            return Err(ServiceError::TooManyPrograms(max_programs));
+        }
+        state.programs.insert(program_hash, Stored::new(program));
+        Ok(program_hash)
+    }
+
+    /// Stores the specified inputs and returns their ID, by which the inputs can be referenced in
+    /// proving jobs.
+    ///
+    /// # Errors
+    /// Returns an error if the maximum number of inputs is already stored.
+    pub fn submit_inputs(&self, inputs: JobInputs) -> Result<u64, ServiceError> {
+        let max_inputs = self.shared.config.max_inputs;
+        let mut state = self.state();
+        state.evict_expired(self.shared.config.job_ttl);
+        if state.inputs.len() >= max_inputs {
+            return Err(ServiceError::TooManyInputs(max_inputs));
+        }
+        let inputs_id = state.next_id();
+        state.inputs.insert(inputs_id, Stored::new(inputs));
+        Ok(inputs_id)
+    }
+
+    /// Queues a job proving execution of the specified program against the specified inputs and
+    /// returns the ID of the job.
+    ///
+    /// The limits of the service are applied to the specified options: the maximum number of
+    /// cycles is capped at the configured maximum, and the memory budget is replaced with the
+    /// configured one.
+    ///
+    /// # Errors
+    /// Returns an error if the program or the inputs have not been submitted, if the options are
+    /// invalid or specify a seed, or if the job queue is full.
+    pub fn prove(
+        &self,
+        program_hash: Digest,
+        inputs_id: u64,
+        options: ProvingOptions,
+    ) -> Result<u64, ServiceError> {
+        let options = self.limit_options(options)?;
+        let mut state = self.state();
+        state.evict_expired(self.shared.config.job_ttl);
+        let now = Instant::now();
+        state
+            .programs
+            .get_mut(&program_hash)
+            .ok_or(ServiceError::UnknownProgram(program_hash))?
+            .last_used = now;
+        state
+            .inputs
+            .get_mut(&inputs_id)
+            .ok_or(ServiceError::UnknownInputs(inputs_id))?
+            .last_used = now;
+        if state.queue.len() >= self.shared.config.max_queued_jobs {
+            return Err(ServiceError::QueueFull(self.shared.config.max_queued_jobs));
+        }
+
+        let job_id = state.next_id();
+        let job = Job {
+            program_hash,
+            inputs_id,
+            options,
+            status: JobStatus::Queued,
+            result: None,
+            finished_at: None,
+        };
+        state.jobs.insert(job_id, job);
+        state.queue.push_back(job_id);
+        self.shared.job_queued.notify_one();
+        Ok(job_id)
+    }
+
+    /// Returns the status of the specified job.
+    pub fn status(&self, job_id: u64) -> Result<JobStatus, ServiceError> {
+        let state = self.state();
+        let job = state.jobs.get(&job_id).ok_or(ServiceError::UnknownJob(job_id))?;
+        Ok(job.status.clone())
+    }
+
+    /// Returns the outputs and the proof generated by the specified job.
+    ///
+    /// # Errors
+    /// Returns an error if the job does not exist or has not completed successfully.
+    pub fn result(&self, job_id: u64) -> Result<JobResult, ServiceError> {
+        let state = self.state();
+        let job = state.jobs.get(&job_id).ok_or(ServiceError::UnknownJob(job_id))?;
+        match (&job.status, &job.result) {
+            (JobStatus::Completed, Some(result)) => Ok(result.clone()),
+            (JobStatus::Failed(err), _) => Err(ServiceError::JobFailed(job_id, err.clone())),
+            _ => Err(ServiceError::JobNotCompleted(job_id)),
+        }
+    }
+
+    /// Blocks until the specified job finishes, and returns the outputs and the proof generated by
+    /// the job.
+    ///
+    /// # Errors
+    /// Returns an error if the job does not exist or has failed, or if it has not finished within
+    /// the configured wait timeout.
+    pub fn wait(&self, job_id: u64) -> Result<JobResult, ServiceError> {
+        let deadline = Instant::now() + self.shared.config.wait_timeout;
+        let mut state = self.state();
+        loop {
+            let job = state.jobs.get(&job_id).ok_or(ServiceError::UnknownJob(job_id))?;
+            match (&job.status, &job.result) {
+                (JobStatus::Completed, Some(result)) => return Ok(result.clone()),
+                (JobStatus::Failed(err), _) => {
+                    return Err(ServiceError::JobFailed(job_id, err.clone()))
+                }
+                _ => {
+                    let timeout = deadline.saturating_duration_since(Instant::now());
+                    if timeout.is_zero() {
+                        return Err(ServiceError::JobTimedOut(job_id));
+                    }
+                    state = self
+                        .shared
+                        .job_finished
+                        .wait_timeout(state, timeout)
+                        .expect("service state lock poisoned")
+                        .0;
+                }
+            }
+        }
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies the limits of the service to the specified proving options.
+    ///
+    /// Options specifying a seed are rejected, so that the randomness of the proofs generated by
+    /// the service is never chosen by its clients.
+    fn limit_options(&self, options: ProvingOptions) -> Result<ProvingOptions, ServiceError> {
+        if options.seed().is_some() {
+            return Err(ServiceError::InvalidOptions("seeded proofs are not supported".into()));
+        }
+
+        let exec_options = options.execution_options();
+        let max_cycles = exec_options.max_cycles().min(self.shared.config.max_cycles);
+        let expected_cycles = exec_options.expected_cycles().min(max_cycles);
+        let mut limited_exec_options =
+            ExecutionOptions::new(Some(max_cycles), expected_cycles, exec_options.enable_tracing())
+                .map_err(|err| ServiceError::InvalidOptions(err.to_string()))?
+                .with_profile(exec_options.profile());
+        if exec_options.enable_debugging() {
+            limited_exec_options = limited_exec_options.with_debugging();
+        }
+        if let Some(threshold) = exec_options.stack_depth_warning() {
+            limited_exec_options = limited_exec_options.with_stack_depth_warning(threshold);
+        }
+
+        Ok(options
+            .with_execution_options(limited_exec_options)
+            .with_memory_budget(self.shared.config.memory_budget))
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.shared.state.lock().expect("service state lock poisoned")
+    }
+}
+
+impl Drop for ProvingService {
+    fn drop(&mut self) {
+        self.shared.state.lock().expect("service state lock poisoned").shutdown = true;
+        self.shared.job_queued.notify_all();
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+// WORKER
+// ================================================================================================
+
+/// Executes queued jobs until the service is shut down.
+fn run_worker(shared: &Shared) {
+    loop {
+        // wait for the next job and take everything needed to execute it
+        let (job_id, program, inputs, options) = {
+            let mut state = shared.state.lock().expect("service state lock poisoned");
+            let job_id = loop {
+                if state.shutdown {
+                    return;
+                }
+                match state.queue.pop_front() {
+                    Some(job_id) => break job_id,
+                    None => {
+                        state = shared.job_queued.wait(state).expect("service state lock poisoned")
+                    }
+                }
+            };
+
+            let job = state.jobs.get_mut(&job_id).expect("queued job must exist");
+            job.status = JobStatus::Running;
+            let (program_hash, inputs_id, options) =
+                (job.program_hash, job.inputs_id, job.options.clone());
+            // programs and inputs referenced by unfinished jobs are never evicted
+            let program = state.programs[&program_hash].item.clone();
+            let inputs = state.inputs[&inputs_id].item.clone();
+            (job_id, program, inputs, options)
+        };
+
+        // a panic of the prover fails the job rather than the worker
+        let host = DefaultHost::new(MemAdviceProvider::from(inputs.advice_inputs));
+        let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+            prove(&program, inputs.stack_inputs, host, options)
+        }));
+
+        let mut state = shared.state.lock().expect("service state lock poisoned");
+        let job = state.jobs.get_mut(&job_id).expect("running job must exist");
+        job.finished_at = Some(Instant::now());
+        match outcome {
+            Ok(Ok((stack_outputs, proof))) => {
+                job.status = JobStatus::Completed;
+                job.result = Some(JobResult {
+                    stack_outputs,
+                    proof,
+                });
+            }
+            Ok(Err(err)) => job.status = JobStatus::Failed(format!("{err:?}")),
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .copied()
+                    .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                    .unwrap_or("unknown error");
+                job.status = JobStatus::Failed(format!("prover panicked: {message}"));
+            }
+        }
+        shared.job_finished.notify_all();
+    }
+}
+
+// ERRORS
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ServiceError {
+    CompilationFailed(String),
+    InvalidOptions(String),
+    JobFailed(u64, String),
+    JobNotCompleted(u64),
+    JobTimedOut(u64),
+    QueueFull(usize),
+    TooManyInputs(usize),
+    TooManyPrograms(usize),
+    UnknownInputs(u64),
+    UnknownJob(u64),
+    UnknownProgram(Digest),
+}
+
+impl fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ServiceError::*;
+        match self {
+            CompilationFailed(err) => write!(f, "failed to compile program: {err}"),
+            InvalidOptions(err) => write!(f, "invalid proving options: {err}"),
+            JobFailed(job_id, err) => write!(f, "job {job_id} failed: {err}"),
+            JobNotCompleted(job_id) => write!(f, "job {job_id} has not completed yet"),
+            JobTimedOut(job_id) => write!(f, "job {job_id} has not finished in time"),
+            QueueFull(max) => write!(f, "job queue is full ({max} jobs are already queued)"),
+            TooManyInputs(max) => write!(f, "too many inputs ({max} inputs are already stored)"),
+            TooManyPrograms(max) => {
+                write!(f, "too many programs ({max} programs are already stored)")
+            }
+            UnknownInputs(inputs_id) => write!(f, "inputs {inputs_id} have not been submitted"),
+            UnknownJob(job_id) => write!(f, "job {job_id} does not exist"),
+            UnknownProgram(hash) => write!(f, "program {hash} has not been submitted"),
+        }
+    }
+}
+
+impl std::error::Error for ServiceError {}
@@ -0,0 +1,277 @@
+use super::{
+    rpc,
+    service::{JobInputs, JobStatus, ProvingService, ServiceConfig, ServiceError},
+};
+use miden_vm::{
+    utils::{ByteWriter, Deserializable, Serializable},
+    verify, AdviceInputs, Assembler, Digest, ExecutionProof, Kernel, ProgramInfo, ProofRequest,
+    ProofResponse, ProvingOptions, StackInputs, StackOutputs, ZERO,
+};
+use serde_json::{json, Value};
+use std::{thread, time::Duration};
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn prove_submitted_program() {
+    let service = ProvingService::new(ServiceConfig {
+        workers: 2,
+        max_queued_jobs: 4,
+        job_ttl: Duration::from_secs(60),
+        ..Default::default()
+    });
+
+    let program_hash = service.submit_program("begin push.3 add end").unwrap();
+    let stack_inputs = StackInputs::try_from_ints([5]).unwrap();
+    let inputs_id = service
+        .submit_inputs(JobInputs {
+            stack_inputs: stack_inputs.clone(),
+            ..Default::default()
+        })
+        .unwrap();
+    let options = ProvingOptions::default();
+    let job_id = service.prove(program_hash, inputs_id, options).unwrap();
+
+    wait_for_job(&service, job_id);
+    let result = service.result(job_id).unwrap();
+    assert_eq!(result.stack_outputs.stack()[0].as_int(), 8);
+
+    let program_info = ProgramInfo::new(program_hash, Kernel::default());
+    assert!(verify(program_info, stack_inputs, result.stack_outputs, result.proof).is_ok());
+}
+
+#[test]
+fn service_errors() {
+    let service = ProvingService::new(ServiceConfig {
+        workers: 1,
+        max_queued_jobs: 0,
+        job_ttl: Duration::from_secs(60),
+        ..Default::default()
+    });
+
+    assert!(matches!(
+        service.submit_program("begin push.3 foo end"),
+        Err(ServiceError::CompilationFailed(_))
+    ));
+
+    let program_hash = service.submit_program("begin push.3 add end").unwrap();
+    let inputs_id = service.submit_inputs(JobInputs::default()).unwrap();
+    let options = ProvingOptions::default();
+    assert_eq!(
+        service.prove(Digest::default(), inputs_id, options.clone()),
+        Err(ServiceError::UnknownProgram(Digest::default()))
+    );
+    assert_eq!(
+        service.prove(program_hash, inputs_id + 1, options.clone()),
+        Err(ServiceError::UnknownInputs(inputs_id + 1))
+    );
+    assert_eq!(service.prove(program_hash, inputs_id, options), Err(ServiceError::QueueFull(0)));
+    assert_eq!(service.status(42), Err(ServiceError::UnknownJob(42)));
+}
+
+#[test]
+fn service_limits() {
+    let service = ProvingService::new(ServiceConfig {
+        max_programs: 1,
+        max_inputs: 1,
+        max_cycles: 64,
+        ..Default::default()
+    });
+
+    // programs and inputs are rejected once the maximum number of them is stored
+    let source = "begin repeat.100 push.1 drop end end";
+    let program_hash = service.submit_program(source).unwrap();
+    assert_eq!(service.submit_program(source), Ok(program_hash));
+    assert_eq!(
+        service.submit_program("begin push.3 add end"),
+        Err(ServiceError::TooManyPrograms(1))
+    );
+    let inputs_id = service.submit_inputs(JobInputs::default()).unwrap();
+    assert_eq!(service.submit_inputs(JobInputs::default()), Err(ServiceError::TooManyInputs(1)));
+
+    // seeds are not accepted from clients
+    let options = ProvingOptions::default().with_seed([ZERO; 4]);
+    assert!(matches!(
+        service.prove(program_hash, inputs_id, options),
+        Err(ServiceError::InvalidOptions(_))
+    ));
+
+    // the maximum number of cycles of the service applies regardless of the options of the job
+    let job_id = service.prove(program_hash, inputs_id, ProvingOptions::default()).unwrap();
+    assert!(matches!(service.wait(job_id), Err(ServiceError::JobFailed(..))));
+}
+
+#[test]
+fn wait_times_out() {
+    let service = ProvingService::new(ServiceConfig {
+        wait_timeout: Duration::from_millis(1),
+        ..Default::default()
+    });
+
+    let program_hash = service.submit_program("begin push.3 add end").unwrap();
+    let inputs_id = service.submit_inputs(JobInputs::default()).unwrap();
+    let job_id = service.prove(program_hash, inputs_id, ProvingOptions::default()).unwrap();
+    assert!(matches!(service.wait(job_id), Err(ServiceError::JobTimedOut(id)) if id == job_id));
+}
+
+#[test]
+fn expired_jobs_are_evicted() {
+    let service = ProvingService::new(ServiceConfig {
+        workers: 1,
+        max_queued_jobs: 4,
+        job_ttl: Duration::from_millis(500),
+        ..Default::default()
+    });
+
+    let program_hash = service.submit_program("begin push.3 add end").unwrap();
+    let inputs_id = service.submit_inputs(JobInputs::default()).unwrap();
+    let job_id = service.prove(program_hash, inputs_id, ProvingOptions::default()).unwrap();
+    wait_for_job(&service, job_id);
+    assert!(service.result(job_id).is_ok());
+    thread::sleep(Duration::from_millis(600));
+
+    // submitting new items evicts the expired job along with its program and inputs
+    let new_inputs_id = service.submit_inputs(JobInputs::default()).unwrap();
+    assert_eq!(service.status(job_id), Err(ServiceError::UnknownJob(job_id)));
+    assert_eq!(
+        service.prove(program_hash, new_inputs_id, ProvingOptions::default()),
+        Err(ServiceError::UnknownProgram(program_hash))
+    );
+    let program_hash = service.submit_program("begin push.3 add end").unwrap();
+    assert_eq!(
+        service.prove(program_hash, inputs_id, ProvingOptions::default()),
+        Err(ServiceError::UnknownInputs(inputs_id))
+    );
+}
+
+#[test]
+fn rpc_requests() {
+    let service = ProvingService::new(ServiceConfig {
+        workers: 1,
+        max_queued_jobs: 4,
+        job_ttl: Duration::from_secs(60),
+        ..Default::default()
+    });
+
+    let response = call(&service, "submit_program", json!({ "source": "begin push.3 add end" }));
+    let program_hash = response["result"]["program_hash"].as_str().unwrap().to_string();
+
+    let response = call(&service, "submit_inputs", json!({ "stack_inputs": [5] }));
+    let inputs_id = response["result"]["inputs_id"].as_u64().unwrap();
+
+    let params = json!({ "program_hash": program_hash, "inputs_id": inputs_id });
+    let response = call(&service, "prove", params);
+    let job_id = response["result"]["job_id"].as_u64().unwrap();
+
+    wait_for_job(&service, job_id);
+    let response = call(&service, "status", json!({ "job_id": job_id }));
+    assert_eq!(response["result"]["status"], "completed");
+
+    // the returned proof must verify against the returned outputs
+    let response = call(&service, "result", json!({ "job_id": job_id }));
+    let stack_outputs = to_ints(&response["result"]["stack_outputs"]);
+    let overflow_addrs = to_ints(&response["result"]["overflow_addrs"]);
+    assert_eq!(stack_outputs[0], 8);
+    let stack_outputs = StackOutputs::try_from_ints(stack_outputs, overflow_addrs).unwrap();
+    let proof = hex::decode(response["result"]["proof"].as_str().unwrap()).unwrap();
+    let proof = ExecutionProof::from_bytes(&proof).unwrap();
+    let program_hash = Digest::read_from_bytes(&hex::decode(program_hash).unwrap()).unwrap();
+    let program_info = ProgramInfo::new(program_hash, Kernel::default());
+    let stack_inputs = StackInputs::try_from_ints([5]).unwrap();
+    assert!(verify(program_info, stack_inputs, stack_outputs, proof).is_ok());
+
+    // proofs with 128-bit proven security can be requested
+    let params = json!({
+        "program_hash": hex::encode(program_hash.as_bytes()),
+        "inputs_id": inputs_id,
+        "security": "128bits-proven",
+    });
+    let response = call(&service, "prove", params);
+    assert!(response["result"]["job_id"].is_u64());
+
+    // malformed requests are rejected with standard error codes
+    let response = rpc::handle_request(&service, "{");
+    assert_eq!(response["error"]["code"], -32700);
+    let response = call(&service, "foo", Value::Null);
+    assert_eq!(response["error"]["code"], -32601);
+    let response = call(&service, "prove", json!({ "program_hash": "00" }));
+    assert_eq!(response["error"]["code"], -32602);
+    let response = call(&service, "result", json!({ "job_id": 42 }));
+    assert_eq!(response["error"]["code"], -32000);
+}
+
+#[test]
+fn binary_proof_requests() {
+    let service = ProvingService::new(ServiceConfig {
+        workers: 1,
+        max_queued_jobs: 4,
+        job_ttl: Duration::from_secs(60),
+        ..Default::default()
+    });
+
+    let program = Assembler::default().compile("begin push.3 add end").unwrap();
+    let stack_inputs = StackInputs::try_from_ints([5]).unwrap();
+    let request = ProofRequest {
+        program: program.clone(),
+        stack_inputs: stack_inputs.clone(),
+        advice_inputs: AdviceInputs::default(),
+        options: ProvingOptions::default(),
+    };
+
+    let response = rpc::handle_proof_request(&service, &request.to_bytes()).unwrap();
+    let response = ProofResponse::read_from_bytes(&response).unwrap();
+    assert_eq!(response.stack_outputs.stack()[0].as_int(), 8);
+    let program_info = ProgramInfo::from(program);
+    assert!(verify(program_info, stack_inputs, response.stack_outputs, response.proof).is_ok());
+
+    let result = rpc::handle_proof_request(&service, &[1, 2, 3]);
+    assert!(matches!(result, Err((400, _))));
+}
+
+#[test]
+fn proof_requests_with_untrusted_lengths() {
+    let service = ProvingService::new(ServiceConfig {
+        workers: 1,
+        max_queued_jobs: 4,
+        job_ttl: Duration::from_secs(60),
+        ..Default::default()
+    });
+
+    // a request claiming a huge advice stack must be rejected without allocating memory for it
+    let program = Assembler::default().compile("begin push.3 add end").unwrap();
+    let mut body = program.to_bytes();
+    StackInputs::default().write_into(&mut body);
+    body.write_usize(usize::MAX);
+    body.extend_from_slice(&[1, 2, 3]);
+
+    let result = rpc::handle_proof_request(&service, &body);
+    assert!(matches!(result, Err((400, _))));
+
+    // the service keeps accepting requests afterwards
+    assert!(service.submit_compiled_program(program).is_ok());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn call(service: &ProvingService, method: &str, params: Value) -> Value {
+    let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+    let response = rpc::handle_request(service, &request.to_string());
+    assert_eq!(response["id"], 1);
+    response
+}
+
+fn to_ints(values: &Value) -> Vec<u64> {
+    values.as_array().unwrap().iter().map(|value| value.as_u64().unwrap()).collect()
+}
+
+fn wait_for_job(service: &ProvingService, job_id: u64) {
+    loop {
+        match service.status(job_id).unwrap() {
+            JobStatus::Completed => return,
+            JobStatus::Failed(err) => panic!("job {job_id} failed: {err}"),
+            _ => thread::sleep(Duration::from_millis(10)),
+        }
+    }
+}
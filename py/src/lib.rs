@@ -0,0 +1,291 @@
+//! Python bindings for Miden VM.
+//!
+//! The bindings are exposed as the `miden` Python module. Sequences of field elements (stack
+//! inputs, advice stack values, words, etc.) can be passed as any iterable of integers, including
+//! NumPy arrays of unsigned integers.
+
+use miden_vm::{
+    crypto::{MerkleTree, RpoDigest},
+    math::{Felt, StarkField},
+    utils::{Deserializable, Serializable},
+    AdviceInputs, Assembler, DefaultHost, Digest, ExecutionOptions, ExecutionProof, Kernel,
+    MemAdviceProvider, Program, ProgramInfo, ProvingOptions, StackInputs, StackOutputs, Word,
+};
+use pyo3::{
+    create_exception,
+    exceptions::PyException,
+    prelude::*,
+    types::{PyBytes, PyDict},
+};
+use stdlib::StdLibrary;
+
+create_exception!(miden, MidenError, PyException, "Error raised by Miden VM.");
+
+// PROGRAM
+// ================================================================================================
+
+/// A compiled Miden program.
+#[pyclass(name = "Program", module = "miden", frozen)]
+struct PyProgram(Program);
+
+#[pymethods]
+impl PyProgram {
+    /// The 32-byte hash of the program.
+    #[getter]
+    fn hash<'py>(&self, py: Python<'py>) -> &'py PyBytes {
+        PyBytes::new(py, &self.0.hash().as_bytes())
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Program(hash={})", self.0.hash())
+    }
+}
+
+// ADVICE INPUTS
+// ================================================================================================
+
+/// Non-deterministic inputs supplied to the VM via the advice provider.
+#[pyclass(name = "AdviceInputs", module = "miden")]
+#[derive(Clone, Default)]
+struct PyAdviceInputs(AdviceInputs);
+
+#[pymethods]
+impl PyAdviceInputs {
+    /// Creates advice inputs with the specified advice stack values and advice map entries.
+    ///
+    /// The first of the advice stack values is the one at the top of the advice stack. Keys of the
+    /// advice map are words (i.e., sequences of 4 field elements).
+    #[new]
+    #[pyo3(signature = (stack = None, map = None))]
+    fn new(stack: Option<&PyAny>, map: Option<&PyDict>) -> PyResult<Self> {
+        let mut inputs = Self::default();
+        if let Some(stack) = stack {
+            inputs.push_stack(stack)?;
+        }
+        if let Some(map) = map {
+            for (key, values) in map.iter() {
+                inputs.insert(key, values)?;
+            }
+        }
+        Ok(inputs)
+    }
+
+    /// Appends the specified values to the bottom of the advice stack.
+    fn push_stack(&mut self, values: &PyAny) -> PyResult<()> {
+        self.0.extend_stack(extract_elements(values)?);
+        Ok(())
+    }
+
+    /// Inserts the specified values into the advice map under the specified word key.
+    fn insert(&mut self, key: &PyAny, values: &PyAny) -> PyResult<()> {
+        let key = RpoDigest::new(extract_word(key)?);
+        self.0.extend_map([(key, extract_elements(values)?)]);
+        Ok(())
+    }
+
+    /// Builds a Merkle tree from the specified leaves, adds its nodes to the Merkle store, and
+    /// returns the root of the tree as a list of 4 field elements.
+    ///
+    /// Each leaf is a word, and the number of leaves must be a power of two.
+    fn add_merkle_tree(&mut self, leaves: &PyAny) -> PyResult<Vec<u64>> {
+        let leaves = leaves.iter()?.map(|leaf| extract_word(leaf?)).collect::<PyResult<_>>()?;
+        let tree = MerkleTree::new(leaves).map_err(to_py_err)?;
+        self.0.extend_merkle_store(tree.inner_nodes());
+        Ok(word_to_ints(&tree.root().into()))
+    }
+
+    /// Serializes the advice inputs into bytes.
+    fn to_bytes<'py>(&self, py: Python<'py>) -> &'py PyBytes {
+        PyBytes::new(py, &self.0.to_bytes())
+    }
+
+    /// Deserializes advice inputs from bytes.
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        AdviceInputs::read_from_bytes(bytes).map(Self).map_err(to_py_err)
+    }
+}
+
+// STACK OUTPUTS
+// ================================================================================================
+
+/// Outputs of a program execution.
+#[pyclass(name = "StackOutputs", module = "miden", frozen)]
+#[derive(Clone)]
+struct PyStackOutputs(StackOutputs);
+
+#[pymethods]
+impl PyStackOutputs {
+    /// Values remaining on the stack, with the value at the top of the stack first.
+    #[getter]
+    fn stack(&self) -> Vec<u64> {
+        self.0.stack().iter().map(|value| value.as_int()).collect()
+    }
+
+    /// Addresses required to reconstruct the stack overflow table.
+    #[getter]
+    fn overflow_addrs(&self) -> Vec<u64> {
+        self.0.overflow_addrs().iter().map(|value| value.as_int()).collect()
+    }
+
+    /// Serializes the outputs into bytes.
+    fn to_bytes<'py>(&self, py: Python<'py>) -> &'py PyBytes {
+        PyBytes::new(py, &self.0.to_bytes())
+    }
+
+    /// Deserializes outputs from bytes.
+    #[staticmethod]
+    fn from_bytes(bytes: &[u8]) -> PyResult<Self> {
+        StackOutputs::read_from_bytes(bytes).map(Self).map_err(to_py_err)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("StackOutputs(stack={:?})", self.stack())
+    }
+}
+
+// PIPELINE
+// ================================================================================================
+
+/// Compiles Miden assembly source into a program. The program is compiled against the Miden
+/// standard library.
+#[pyfunction]
+fn compile(source: &str) -> PyResult<PyProgram> {
+    Assembler::default()
+        .with_library(&StdLibrary::default())
+        .and_then(|assembler| assembler.compile(source))
+        .map(PyProgram)
+        .map_err(to_py_err)
+}
+
+/// Executes the program and returns its outputs.
+///
+/// Stack inputs are ordered as if they would be pushed onto the stack one by one.
+#[pyfunction]
+#[pyo3(signature = (program, stack_inputs = None, advice = None))]
+fn run(
+    py: Python<'_>,
+    program: &PyProgram,
+    stack_inputs: Option<&PyAny>,
+    advice: Option<&PyAdviceInputs>,
+) -> PyResult<PyStackOutputs> {
+    let stack_inputs = build_stack_inputs(stack_inputs)?;
+    let host = build_host(advice);
+
+    let trace = py
+        .allow_threads(|| {
+            miden_vm::execute(&program.0, stack_inputs, host, ExecutionOptions::default())
+        })
+        .map_err(|err| MidenError::new_err(format!("{err:?}")))?;
+
+    Ok(PyStackOutputs(trace.stack_outputs().clone()))
+}
+
+/// Executes and proves the program, and returns its outputs together with the serialized proof.
+///
+/// `security` must be either 96 or 128 (bits). If `recursive` is set, the proof is generated
+/// using an arithmetization-friendly hash function.
+#[pyfunction]
+#[pyo3(signature = (program, stack_inputs = None, advice = None, security = 96, recursive = false))]
+fn prove<'py>(
+    py: Python<'py>,
+    program: &PyProgram,
+    stack_inputs: Option<&PyAny>,
+    advice: Option<&PyAdviceInputs>,
+    security: u32,
+    recursive: bool,
+) -> PyResult<(PyStackOutputs, &'py PyBytes)> {
+    let stack_inputs = build_stack_inputs(stack_inputs)?;
+    let host = build_host(advice);
+    let options = match security {
+        96 => ProvingOptions::with_96_bit_security(recursive),
+        128 => ProvingOptions::with_128_bit_security(recursive),
+        _ => return Err(MidenError::new_err(format!("{security} is not a valid security level"))),
+    };
+
+    let (stack_outputs, proof) = py
+        .allow_threads(|| miden_vm::prove(&program.0, stack_inputs, host, options))
+        .map_err(|err| MidenError::new_err(format!("{err:?}")))?;
+
+    Ok((PyStackOutputs(stack_outputs), PyBytes::new(py, &proof.to_bytes())))
+}
+
+/// Verifies the proof of execution of the program with the specified 32-byte hash, and returns
+/// the security level of the proof.
+///
+/// The program is expected to be executed against an empty kernel.
+#[pyfunction]
+fn verify(
+    py: Python<'_>,
+    program_hash: &[u8],
+    stack_inputs: Option<&PyAny>,
+    outputs: &PyStackOutputs,
+    proof: &[u8],
+) -> PyResult<u32> {
+    let program_hash = Digest::read_from_bytes(program_hash).map_err(to_py_err)?;
+    let program_info = ProgramInfo::new(program_hash, Kernel::default());
+    let stack_inputs = build_stack_inputs(stack_inputs)?;
+    let stack_outputs = outputs.0.clone();
+    let proof = ExecutionProof::from_bytes(proof).map_err(to_py_err)?;
+
+    py.allow_threads(|| miden_vm::verify(program_info, stack_inputs, stack_outputs, proof))
+        .map(|report| report.security_level())
+        .map_err(to_py_err)
+}
+
+// MODULE
+// ================================================================================================
+
+#[pymodule]
+fn miden(py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    m.add("MidenError", py.get_type::<MidenError>())?;
+    m.add_class::<PyProgram>()?;
+    m.add_class::<PyAdviceInputs>()?;
+    m.add_class::<PyStackOutputs>()?;
+    m.add_function(wrap_pyfunction!(compile, m)?)?;
+    m.add_function(wrap_pyfunction!(run, m)?)?;
+    m.add_function(wrap_pyfunction!(prove, m)?)?;
+    m.add_function(wrap_pyfunction!(verify, m)?)?;
+    Ok(())
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn to_py_err(err: impl ToString) -> PyErr {
+    MidenError::new_err(err.to_string())
+}
+
+/// Extracts field elements from an iterable of integers (e.g., a list or a NumPy array).
+fn extract_elements(values: &PyAny) -> PyResult<Vec<Felt>> {
+    values
+        .iter()?
+        .map(|value| {
+            let value: u64 = value?.extract()?;
+            Felt::try_from(value)
+                .map_err(|_| MidenError::new_err(format!("{value} is not a valid field element")))
+        })
+        .collect()
+}
+
+fn extract_word(values: &PyAny) -> PyResult<Word> {
+    let elements = extract_elements(values)?;
+    let len = elements.len();
+    elements.try_into().map_err(|_| {
+        MidenError::new_err(format!("a word must contain 4 elements, but {len} given"))
+    })
+}
+
+fn word_to_ints(word: &Word) -> Vec<u64> {
+    word.iter().map(|value| value.as_int()).collect()
+}
+
+fn build_stack_inputs(values: Option<&PyAny>) -> PyResult<StackInputs> {
+    let values = values.map(extract_elements).transpose()?.unwrap_or_default();
+    StackInputs::new(values).map_err(to_py_err)
+}
+
+fn build_host(advice: Option<&PyAdviceInputs>) -> DefaultHost<MemAdviceProvider> {
+    let advice_inputs = advice.map(|advice| advice.0.clone()).unwrap_or_default();
+    DefaultHost::new(MemAdviceProvider::from(advice_inputs))
+}
@@ -0,0 +1,40 @@
+import miden
+import numpy as np
+import pytest
+
+
+def test_compile_run_prove_verify():
+    program = miden.compile("begin push.3 add end")
+    assert len(program.hash) == 32
+
+    outputs = miden.run(program, [5])
+    assert outputs.stack[0] == 8
+
+    outputs, proof = miden.prove(program, np.array([5], dtype=np.uint64))
+    assert outputs.stack[0] == 8
+    assert miden.verify(program.hash, [5], outputs, proof) >= 90
+
+    with pytest.raises(miden.MidenError):
+        miden.verify(program.hash, [6], outputs, proof)
+
+
+def test_advice_inputs():
+    advice = miden.AdviceInputs(stack=[1, 2], map={(1, 2, 3, 4): [7, 8]})
+    root = advice.add_merkle_tree([[i, 0, 0, 0] for i in range(4)])
+    assert len(root) == 4
+
+    # values are popped from the top of the advice stack and pushed onto the operand stack
+    program = miden.compile("begin adv_push.2 end")
+    assert miden.run(program, advice=advice).stack[:2] == [2, 1]
+
+    restored = miden.AdviceInputs.from_bytes(advice.to_bytes())
+    assert restored.to_bytes() == advice.to_bytes()
+
+
+def test_errors():
+    with pytest.raises(miden.MidenError):
+        miden.compile("begin foo end")
+
+    program = miden.compile("begin push.3 add end")
+    with pytest.raises(miden.MidenError):
+        miden.run(program, [2**64 - 1])
@@ -1,4 +1,6 @@
-use assembly::{ast::ModuleAst, Library, LibraryNamespace, MaslLibrary, Version};
+use assembly::{
+    ast::ModuleAst, utils::Serializable, Assembler, Library, LibraryNamespace, MaslLibrary, Version,
+};
 use std::{collections::BTreeMap, env, fs, io, path::Path};
 
 mod md_renderer;
@@ -10,6 +12,7 @@ use md_renderer::MarkdownRenderer;
 const ASM_DIR_PATH: &str = "./asm";
 const ASL_DIR_PATH: &str = "./assets";
 const DOC_DIR_PATH: &str = "./docs";
+const ROOTS_FILE_NAME: &str = "std.roots";
 
 // TYPE ALIASES and HELPER STRUCTS
 // ================================================================================================
@@ -21,6 +24,9 @@ type ModuleMap = BTreeMap<String, ModuleAst>;
 
 /// Read and parse the contents from `./asm` into a `LibraryContents` struct, serializing it into
 /// `assets` folder under `std` namespace.
+///
+/// The MAST roots of all procedures exported from the library are computed as well, and are
+/// serialized next to the library, so that they can be pinned into the crate.
 #[cfg(not(feature = "docs-rs"))]
 fn main() -> io::Result<()> {
     // re-build the `[OUT_DIR]/assets/std.masl` file iff something in the `./asm` directory
@@ -39,7 +45,15 @@ fn main() -> io::Result<()> {
 
     // write the masl output
     let build_dir = env::var("OUT_DIR").unwrap();
-    stdlib.write_to_dir(Path::new(&build_dir).join(ASL_DIR_PATH))?;
+    let asl_dir = Path::new(&build_dir).join(ASL_DIR_PATH);
+    stdlib.write_to_dir(&asl_dir)?;
+
+    // compile the library and write the MAST roots of its exported procedures
+    let roots = Assembler::default()
+        .with_library(&stdlib)
+        .and_then(|assembler| assembler.procedure_roots(&stdlib))
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err.to_string()))?;
+    fs::write(asl_dir.join(ROOTS_FILE_NAME), roots.to_bytes())?;
 
     // updates the documentation of these modules
     build_stdlib_docs(&docs, DOC_DIR_PATH)?;
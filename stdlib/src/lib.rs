@@ -1,27 +1,134 @@
 #![no_std]
 
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
 use assembly::{
-    ast::ModuleAst, utils::Deserializable, Library, LibraryNamespace, LibraryPath, MaslLibrary,
-    Version,
+    ast::ModuleAst, utils::Deserializable, Assembler, AssemblyError, Library, LibraryNamespace,
+    LibraryPath, MaslLibrary, ProcedureRootChange, ProcedureRoots, Version,
 };
+use core::fmt;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Serialized standard library, as compiled from MASM sources when this crate was built.
+const STDLIB_BYTES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/assets/std.masl"));
+
+/// Serialized MAST roots of all procedures exported from the standard library, as computed when
+/// this crate was built.
+const STDLIB_ROOTS_BYTES: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/assets/std.roots"));
 
 // STANDARD LIBRARY
 // ================================================================================================
 
-/// TODO: add docs
-pub struct StdLibrary(MaslLibrary);
+/// The Miden standard library.
+///
+/// The library is parsed from MASM sources when this crate is built and is embedded into the
+/// crate in serialized form, together with the MAST roots of all of its exported procedures. Thus,
+/// loading the library requires neither parsing nor compiling MASM sources. When the `std` feature
+/// is enabled, the library is deserialized only once per process, and every subsequent
+/// [StdLibrary::default()] call returns a copy of it.
+///
+/// When the library is loaded, its version is checked against the version of this crate, and the
+/// exported procedures are checked against the pinned MAST roots. The roots themselves can be
+/// checked via [StdLibrary::verify_roots()], which compiles the entire library.
+#[derive(Debug, Clone)]
+pub struct StdLibrary {
+    library: MaslLibrary,
+    roots: ProcedureRoots,
+}
+
+impl StdLibrary {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the MAST roots of all procedures exported from the library, as pinned when this
+    /// crate was built.
+    ///
+    /// Programs which invoke standard library procedures by MAST root (e.g., via `call.0x...`)
+    /// can check the roots they use against this report.
+    pub fn procedure_roots(&self) -> &ProcedureRoots {
+        &self.roots
+    }
+
+    // INTEGRITY CHECKS
+    // --------------------------------------------------------------------------------------------
+
+    /// Compiles all procedures exported from the library, and checks that their MAST roots match
+    /// the pinned roots.
+    ///
+    /// # Errors
+    /// Returns an error if the library cannot be compiled, or if the MAST roots of any of its
+    /// exported procedures differ from the pinned roots.
+    pub fn verify_roots(&self) -> Result<(), IntegrityError> {
+        let roots = Assembler::default()
+            .with_library(self)
+            .and_then(|assembler| assembler.procedure_roots(self))
+            .map_err(IntegrityError::CompilationFailed)?;
+
+        let changes = self.roots.diff(&roots);
+        if !changes.is_empty() {
+            return Err(IntegrityError::RootsMismatch(changes));
+        }
+        Ok(())
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Deserializes the library and its pinned MAST roots from the bytes embedded into this crate.
+    ///
+    /// # Panics
+    /// Panics if the embedded bytes cannot be deserialized, if the version of the library differs
+    /// from the version of this crate, or if the procedures exported from the library differ from
+    /// the procedures for which the MAST roots were pinned.
+    fn load() -> Self {
+        let library = MaslLibrary::read_from_bytes(STDLIB_BYTES).expect("failed to read std masl!");
+        let roots =
+            ProcedureRoots::read_from_bytes(STDLIB_ROOTS_BYTES).expect("failed to read std roots!");
+
+        let version = Version::try_from(env!("CARGO_PKG_VERSION")).expect("invalid crate version");
+        assert_eq!(library.version(), &version, "std masl version does not match crate version");
+
+        let exports = library
+            .modules()
+            .flat_map(|module| {
+                let reexported = module.ast.reexported_procs().iter().map(|proc| proc.name());
+                let local = module.ast.procs().iter().filter(|p| p.is_export).map(|p| &p.name);
+                reexported
+                    .chain(local)
+                    .map(move |name| module.path.append_unchecked(name.as_str()))
+            })
+            .collect::<BTreeSet<String>>();
+        assert!(
+            exports.iter().map(String::as_str).eq(roots.iter().map(|(path, _)| path)),
+            "std masl exports do not match pinned std roots"
+        );
+
+        Self { library, roots }
+    }
+}
 
 impl From<StdLibrary> for MaslLibrary {
     fn from(value: StdLibrary) -> Self {
-        value.0
+        value.library
     }
 }
 
 impl Default for StdLibrary {
+    #[cfg(feature = "std")]
+    fn default() -> Self {
+        static STDLIB: std::sync::OnceLock<StdLibrary> = std::sync::OnceLock::new();
+        STDLIB.get_or_init(Self::load).clone()
+    }
+
+    #[cfg(not(feature = "std"))]
     fn default() -> Self {
-        let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/std.masl"));
-        let contents = MaslLibrary::read_from_bytes(bytes).expect("failed to read std masl!");
-        Self(contents)
+        Self::load()
     }
 }
 
@@ -29,26 +136,57 @@ impl Library for StdLibrary {
     type ModuleIterator<'a> = <MaslLibrary as Library>::ModuleIterator<'a>;
 
     fn root_ns(&self) -> &LibraryNamespace {
-        self.0.root_ns()
+        self.library.root_ns()
     }
 
     fn version(&self) -> &Version {
-        self.0.version()
+        self.library.version()
     }
 
     fn modules(&self) -> Self::ModuleIterator<'_> {
-        self.0.modules()
+        self.library.modules()
     }
 
     fn dependencies(&self) -> &[assembly::LibraryNamespace] {
-        self.0.dependencies()
+        self.library.dependencies()
     }
 
     fn get_module_ast(&self, path: &LibraryPath) -> Option<&ModuleAst> {
-        self.0.get_module_ast(path)
+        self.library.get_module_ast(path)
     }
 }
 
+// INTEGRITY ERROR
+// ================================================================================================
+
+/// An error returned when the MAST roots of the standard library cannot be verified.
+#[derive(Debug)]
+pub enum IntegrityError {
+    /// The library could not be compiled.
+    CompilationFailed(AssemblyError),
+    /// The MAST roots of some of the exported procedures differ from the pinned roots.
+    RootsMismatch(Vec<ProcedureRootChange>),
+}
+
+impl fmt::Display for IntegrityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use IntegrityError::*;
+        match self {
+            CompilationFailed(err) => write!(f, "failed to compile the standard library: {err}"),
+            RootsMismatch(changes) => {
+                writeln!(f, "MAST roots of the standard library differ from the pinned roots:")?;
+                for change in changes {
+                    writeln!(f, "{change}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IntegrityError {}
+
 #[test]
 fn test_compile() {
     let path = "std::math::u64::overflowing_add";
@@ -63,3 +201,17 @@ fn test_compile() {
 
     assert!(exists);
 }
+
+#[test]
+fn test_pinned_roots() {
+    let stdlib = StdLibrary::default();
+    let roots = stdlib.procedure_roots();
+    assert!(roots.get("std::math::u64::overflowing_add").is_some());
+
+    // re-exported procedures are pinned under the path of the re-exporting module
+    let verify = roots.get("std::crypto::stark::verify");
+    assert!(verify.is_some());
+    assert_eq!(verify, roots.get("std::crypto::stark::verifier::verify"));
+
+    stdlib.verify_roots().unwrap();
+}
@@ -1,5 +1,5 @@
 use test_utils::{
-    crypto::{MerkleStore, RpoDigest, Smt},
+    crypto::{CompactSmt, MerkleStore, RpoDigest, Smt},
     Felt, Word, EMPTY_WORD,
 };
 
@@ -250,6 +250,162 @@ fn test_set_empty_key_in_non_empty_leaf() {
     build_test!(source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
 }
 
+// COMPACT TREES
+// ================================================================================================
+
+const COMPACT_DEPTH: u8 = 8;
+
+/// Tests `get_compact` on keys present in the tree, on a key mapping to an empty subtree, and on a
+/// key mapping to a leaf which holds another key.
+#[test]
+fn test_compact_smt_get() {
+    fn expect_value_from_get(key: RpoDigest, value: Word, tree: &CompactSmt) {
+        let source = format!(
+            "
+            use.std::collections::smt
+            begin
+                push.{COMPACT_DEPTH}
+                exec.smt::get_compact
+            end
+        "
+        );
+        let mut initial_stack = Vec::new();
+        append_word_to_vec(&mut initial_stack, tree.root().into());
+        append_word_to_vec(&mut initial_stack, key.into());
+        let expected_output = build_expected_stack(value, tree.root().into());
+
+        let (store, advice_map) = build_compact_advice_inputs(tree);
+        build_test!(&source, &initial_stack, &[], store, advice_map).expect_stack(&expected_output);
+    }
+
+    let leaves = [
+        (compact_key(0b0001), [Felt::new(1); 4]),
+        (compact_key(0b0010), [Felt::new(2); 4]),
+        (compact_key(0b1000), [Felt::new(3); 4]),
+    ];
+    let tree = CompactSmt::with_entries(COMPACT_DEPTH, leaves).unwrap();
+
+    for (key, value) in leaves {
+        expect_value_from_get(key, value, &tree);
+    }
+
+    // the subtree on the path to the key is empty
+    expect_value_from_get(compact_key(0b0100), EMPTY_WORD, &tree);
+
+    // the leaf on the path to the key holds another key
+    expect_value_from_get(compact_key(0b1100), EMPTY_WORD, &tree);
+}
+
+/// Tests `set_compact` on a sequence of insertions, updates, and removals which place leaves at
+/// different depths, and checks that the roots match the ones computed on the host.
+#[test]
+fn test_compact_smt_set() {
+    let mut tree = CompactSmt::new(COMPACT_DEPTH).unwrap();
+    let empty_tree_root = tree.root();
+
+    let key_a = compact_key(0b0001);
+    let key_b = compact_key(0b0010);
+    let key_c = compact_key(0b0011);
+    let key_d = compact_key(0b0100);
+    let key_e = compact_key(0b1000);
+
+    // insert into an empty tree, and split leaves at increasing depths
+    assert_compact_set(key_a, [Felt::new(1); 4], &mut tree);
+    assert_compact_set(key_e, [Felt::new(5); 4], &mut tree);
+    assert_compact_set(key_b, [Felt::new(2); 4], &mut tree);
+    assert_compact_set(key_c, [Felt::new(3); 4], &mut tree);
+    assert_compact_set(key_d, [Felt::new(4); 4], &mut tree);
+    assert_eq!(tree.get_leaf_depth(&key_c), Some(4));
+
+    // update a value, and remove an absent key mapping to a leaf which holds another key
+    assert_compact_set(key_b, [Felt::new(6); 4], &mut tree);
+    assert_compact_set(compact_key(0b0101), EMPTY_WORD, &mut tree);
+
+    // remove a leaf whose sibling is an internal node
+    assert_compact_set(key_a, EMPTY_WORD, &mut tree);
+
+    // remove leaves whose siblings are leaves, which moves the siblings up
+    assert_compact_set(key_c, EMPTY_WORD, &mut tree);
+    assert_eq!(tree.get_leaf_depth(&key_b), Some(2));
+    assert_compact_set(key_d, EMPTY_WORD, &mut tree);
+    assert_eq!(tree.get_leaf_depth(&key_b), Some(1));
+
+    // remove the remaining leaves at depth 1, and an absent key mapping to an empty subtree
+    assert_compact_set(key_b, EMPTY_WORD, &mut tree);
+    assert_compact_set(key_e, EMPTY_WORD, &mut tree);
+    assert_compact_set(key_a, EMPTY_WORD, &mut tree);
+    assert_eq!(tree.root(), empty_tree_root);
+}
+
+/// Tests that the leaves and nodes created by `set_compact` are recorded in the advice provider,
+/// and thus, that they can be read by `get_compact`.
+#[test]
+fn test_compact_smt_set_then_get() {
+    let source = format!(
+        "
+        use.std::collections::smt
+        # Stack: [V, K, R]
+        begin
+            # save a copy of K for the subsequent get
+            dupw.1 movdnw.3
+            # => [V, K, R, K]
+
+            push.{COMPACT_DEPTH} exec.smt::set_compact dropw swapw
+            # => [K, R_new]
+
+            push.{COMPACT_DEPTH} exec.smt::get_compact
+            # => [V, R_new]
+        end
+    "
+    );
+
+    let mut tree = CompactSmt::with_entries(
+        COMPACT_DEPTH,
+        [
+            (compact_key(0b0001), [Felt::new(1); 4]),
+            (compact_key(0b0011), [Felt::new(3); 4]),
+        ],
+    )
+    .unwrap();
+
+    // the first insertion splits a leaf, and the second one moves a leaf up
+    for (key, value) in
+        [(compact_key(0b0000), [Felt::new(7); 4]), (compact_key(0b0001), EMPTY_WORD)]
+    {
+        let (init_stack, _, store, advice_map) = prepare_compact_set(key, value, &mut tree);
+        let expected_output = build_expected_stack(value, tree.root().into());
+        build_test!(&source, &init_stack, &[], store, advice_map).expect_stack(&expected_output);
+    }
+}
+
+/// Tests that `set_compact` fails if the paths to two keys coincide down to the depth of the tree.
+#[test]
+fn test_compact_smt_set_colliding_keys() {
+    let source = format!(
+        "
+        use.std::collections::smt
+        begin
+            push.{COMPACT_DEPTH}
+            exec.smt::set_compact
+        end
+    "
+    );
+
+    let key = compact_key(0b0001);
+    let tree = CompactSmt::with_entries(COMPACT_DEPTH, [(key, [Felt::new(1); 4])]).unwrap();
+
+    // the keys differ only in the bits below the depth of the tree
+    let colliding_key = RpoDigest::new([key[0], key[1], key[2], key[3] + Felt::new(1)]);
+    let mut init_stack = Vec::new();
+    append_word_to_vec(&mut init_stack, tree.root().into());
+    append_word_to_vec(&mut init_stack, colliding_key.into());
+    append_word_to_vec(&mut init_stack, [Felt::new(2); 4]);
+
+    let (store, advice_map) = build_compact_advice_inputs(&tree);
+    let test = build_test!(&source, &init_stack, &[], store, advice_map);
+    assert!(test.execute().is_err());
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -306,3 +462,52 @@ fn append_word_to_vec(target: &mut Vec<u64>, word: Word) {
     target.push(word[2].as_int());
     target.push(word[3].as_int());
 }
+
+/// Returns a key whose most significant element starts with the specified 4 bits.
+fn compact_key(prefix: u64) -> RpoDigest {
+    RpoDigest::new([Felt::new(11), Felt::new(12), Felt::new(13), Felt::new(prefix << 60)])
+}
+
+/// Executes `set_compact` against the specified tree, and checks that the outputs match the ones of
+/// the same insertion on the host.
+fn assert_compact_set(key: RpoDigest, value: Word, tree: &mut CompactSmt) {
+    let source = format!(
+        "
+        use.std::collections::smt
+        begin
+            push.{COMPACT_DEPTH}
+            exec.smt::set_compact
+        end
+    "
+    );
+    let (init_stack, final_stack, store, advice_map) = prepare_compact_set(key, value, tree);
+    build_test!(&source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
+}
+
+/// Initial stack, expected final stack, Merkle store and advice map of a `set_compact` invocation.
+type CompactSetInputs = (Vec<u64>, Vec<u64>, MerkleStore, Vec<(RpoDigest, Vec<Felt>)>);
+
+fn prepare_compact_set(key: RpoDigest, value: Word, tree: &mut CompactSmt) -> CompactSetInputs {
+    // set initial state of the stack to be [VALUE, KEY, ROOT, ...]
+    let mut initial_stack = Vec::new();
+    append_word_to_vec(&mut initial_stack, tree.root().into());
+    append_word_to_vec(&mut initial_stack, key.into());
+    append_word_to_vec(&mut initial_stack, value);
+
+    // build advice inputs for the test before the tree is updated, and then update the tree
+    let (store, advice_map) = build_compact_advice_inputs(tree);
+    let old_value = tree.insert(key, value).unwrap();
+
+    // after the insertion, the stack should be [OLD_VALUE, ROOT, ...]
+    let expected_output = build_expected_stack(old_value, tree.root().into());
+
+    (initial_stack, expected_output, store, advice_map)
+}
+
+fn build_compact_advice_inputs(tree: &CompactSmt) -> (MerkleStore, Vec<(RpoDigest, Vec<Felt>)>) {
+    let mut store = MerkleStore::new();
+    store.extend(tree.inner_nodes());
+    let advice_map = tree.leaf_preimages().collect::<Vec<_>>();
+
+    (store, advice_map)
+}
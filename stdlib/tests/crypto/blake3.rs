@@ -1,4 +1,4 @@
-use test_utils::{group_slice_elements, rand::rand_array, Felt, IntoBytes};
+use test_utils::{group_slice_elements, rand::rand_array, Felt, IntoBytes, StarkField};
 
 #[test]
 fn blake3_hash_64_bytes() {
@@ -61,3 +61,79 @@ fn blake3_hash_32_bytes() {
     let test = build_test!(source, &ifelts);
     test.expect_stack(&ofelts);
 }
+
+#[test]
+fn blake3_hash_felts_1to1() {
+    let source = "
+    use.std::crypto::hashes::blake3
+
+    begin
+        exec.blake3::hash_felts_1to1
+    end
+    ";
+
+    // values close to the field modulus make sure that both limbs of each element are used
+    let vectors = [
+        [Felt::new(0), Felt::new(0), Felt::new(0), Felt::new(0)],
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+        [
+            Felt::new(Felt::MODULUS - 1),
+            Felt::new(u32::MAX as u64),
+            Felt::new(1 << 32),
+            Felt::new(7),
+        ],
+        rand_array::<Felt, 4>(),
+    ];
+
+    for input in vectors {
+        let test = build_test!(source, &felts_to_stack(&input));
+        test.expect_stack(&blake3_digest(&felts_to_bytes(&input)));
+    }
+}
+
+#[test]
+fn blake3_hash_felts_2to1() {
+    let source = "
+    use.std::crypto::hashes::blake3
+
+    begin
+        exec.blake3::hash_felts_2to1
+    end
+    ";
+
+    let vectors = [
+        [Felt::new(0); 8],
+        [1, 2, 3, 4, 5, 6, 7, 8].map(Felt::new),
+        [Felt::MODULUS - 1, u32::MAX as u64, 1 << 32, 7, 0, Felt::MODULUS - 2, 1 << 63, 9]
+            .map(Felt::new),
+        rand_array::<Felt, 8>(),
+    ];
+
+    for input in vectors {
+        let test = build_test!(source, &felts_to_stack(&input));
+        test.expect_stack(&blake3_digest(&felts_to_bytes(&input)));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the stack inputs which put the provided elements on the stack in order, with the first
+/// element on the top of the stack.
+fn felts_to_stack(felts: &[Felt]) -> Vec<u64> {
+    felts.iter().rev().map(|felt| felt.as_int()).collect()
+}
+
+/// Returns the little-endian encoding of the canonical representations of the provided elements.
+fn felts_to_bytes(felts: &[Felt]) -> Vec<u8> {
+    felts.iter().flat_map(|felt| felt.as_int().to_le_bytes()).collect()
+}
+
+/// Returns the BLAKE3 digest of the provided bytes as eight 32-bit words.
+fn blake3_digest(bytes: &[u8]) -> Vec<u64> {
+    let hasher = blake3::hash(bytes);
+    group_slice_elements::<u8, 4>(hasher.as_bytes())
+        .iter()
+        .map(|&bytes| u32::from_le_bytes(bytes) as u64)
+        .collect()
+}
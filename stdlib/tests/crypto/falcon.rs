@@ -1,9 +1,12 @@
-use assembly::{utils::Serializable, Assembler};
+use assembly::Assembler;
 use miden_air::{Felt, ProvingOptions};
 use miden_stdlib::StdLibrary;
 use processor::{
-    crypto::RpoRandomCoin, AdviceInputs, DefaultHost, Digest, MemAdviceProvider, StackInputs,
+    crypto::{falcon_secret_key_to_advice, falcon_signature_to_advice, RpoRandomCoin},
+    AdviceInputs, DefaultHost, Digest, ExecutionError, ExecutionOptions, FalconSigner,
+    MemAdviceProvider, StackInputs,
 };
+use std::sync::Arc;
 use test_utils::{
     crypto::{rpo_falcon512::SecretKey, MerkleStore},
     rand::rand_vector,
@@ -22,6 +25,97 @@ fn falcon_execution() {
     test.expect_stack(&[])
 }
 
+#[test]
+fn falcon_execution_from_advice() {
+    let seed = Word::default();
+    let mut rng = RpoRandomCoin::new(seed);
+    let sk = SecretKey::with_rng(&mut rng);
+    let message: Word = rand_vector::<Felt>(4).try_into().unwrap();
+
+    // the signature is generated outside of the VM, and thus, the secret key is never provided to
+    // the host
+    let signature = sk.sign(message);
+    let adv_stack = falcon_signature_to_advice(&signature)
+        .into_iter()
+        .map(|value| value.as_int())
+        .collect::<Vec<u64>>();
+
+    let source = "
+    use.std::crypto::dsa::rpo_falcon512
+
+    begin
+        exec.rpo_falcon512::verify_from_advice
+    end
+    ";
+
+    let pk: Word = sk.public_key().into();
+    let mut op_stack = message.iter().map(|a| a.as_int()).collect::<Vec<u64>>();
+    op_stack.extend(pk.iter().map(|a| a.as_int()));
+
+    let test = build_test!(source, &op_stack, &adv_stack);
+    test.expect_stack(&[]);
+
+    // a signature for a different message must be rejected
+    let signature = sk.sign([Felt::new(1); 4]);
+    let adv_stack = falcon_signature_to_advice(&signature)
+        .into_iter()
+        .map(|value| value.as_int())
+        .collect::<Vec<u64>>();
+    let test = build_test!(source, &op_stack, &adv_stack);
+    assert!(test.execute().is_err());
+}
+
+#[test]
+fn falcon_execution_mem() {
+    let seed = Word::default();
+    let mut rng = RpoRandomCoin::new(seed);
+    let sk = SecretKey::with_rng(&mut rng);
+    let pk: Word = sk.public_key().into();
+
+    let source = "
+    use.std::crypto::dsa::rpo_falcon512
+
+    begin
+        push.1.2.3.4 mem_storew.100 dropw
+        push.5.6.7.8 mem_storew.101 dropw
+        push.2.100 movdn.5 movdn.5
+        exec.rpo_falcon512::verify_mem
+    end
+    ";
+    let program = Assembler::default()
+        .with_library(&StdLibrary::default())
+        .expect("failed to load stdlib")
+        .compile(source)
+        .expect("failed to compile test source");
+    let stack_inputs =
+        StackInputs::try_from_ints(pk.iter().map(|a| a.as_int())).expect("invalid stack inputs");
+
+    // the secret key is held by the signer attached to the host, and not by the advice provider
+    let signer = Arc::new(FalconSigner::new().with_key(sk));
+    let host = DefaultHost::default().with_signer(signer);
+    processor::execute(&program, stack_inputs.clone(), host, ExecutionOptions::default())
+        .expect("failed to execute the program");
+
+    // a signer which does not hold the secret key for PK cannot sign the data
+    let host = DefaultHost::default().with_signer(Arc::new(FalconSigner::new()));
+    let result = processor::execute(&program, stack_inputs, host, ExecutionOptions::default());
+    assert!(matches!(result, Err(ExecutionError::SigningKeyNotFound(key)) if key == pk));
+}
+
+#[test]
+fn falcon_mem_signature_commitment_mismatch() {
+    // the host refuses to sign a commitment which does not match the data in memory
+    let source = "
+    begin
+        push.1.2.3.4 mem_storew.100 dropw
+        push.101.100 padw push.1.2.3.4
+        adv.push_sig_mem.rpo_falcon512
+    end
+    ";
+    let test = build_test!(source, &[]);
+    assert!(matches!(test.execute(), Err(ExecutionError::SignedDataMismatch(_))));
+}
+
 #[test]
 #[ignore]
 fn falcon_prove_verify() {
@@ -62,13 +156,8 @@ fn generate_test(
     end
     ";
 
-    let pk: Word = sk.public_key().into();
-    let pk: Digest = pk.into();
-    let sk_bytes = sk.to_bytes();
-
-    let to_adv_map = sk_bytes.iter().map(|a| Felt::new(*a as u64)).collect::<Vec<Felt>>();
-
-    let advice_map: Vec<(Digest, Vec<Felt>)> = vec![(pk, to_adv_map)];
+    let (pk, sk_elements) = falcon_secret_key_to_advice(&sk);
+    let advice_map: Vec<(Digest, Vec<Felt>)> = vec![(pk, sk_elements)];
 
     let mut op_stack = vec![];
     let message = message.into_iter().map(|a| a.as_int()).collect::<Vec<u64>>();
@@ -1,6 +1,6 @@
 use processor::Digest;
 use std::collections::BTreeMap;
-use test_utils::{crypto::MerkleStore, Felt, StarkField};
+use test_utils::{crypto::MerkleStore, Felt, FieldElement, StarkField};
 
 mod channel;
 
@@ -105,6 +105,71 @@ fn fri_fold4_ext2_remainder64() {
     test.expect_stack(&[]);
 }
 
+#[test]
+fn fri_fold4_ext2_verify_layers() {
+    let source = "
+        use.std::crypto::fri::frie2f4
+        use.std::crypto::fri::layer
+
+        begin
+            exec.frie2f4::preprocess
+            # => [query_ptr, layer_ptr, rem_ptr, g]
+
+            movup.3 drop
+            padw movup.4 mem_loadw
+            movup.5 movup.5
+            # => [layer_ptr, rem_ptr, poe, p, e1, e0]
+
+            exec.layer::verify_layers
+        end
+        ";
+
+    let trace_len_e = 13;
+    let blowup_exp = 3;
+    let depth = trace_len_e + blowup_exp;
+    let domain_size = 1 << depth;
+
+    let FriResult {
+        partial_trees,
+        advice_maps,
+        positions,
+        alphas,
+        commitments,
+        remainder,
+        num_queries,
+    } = fri_prove_verify_fold4_ext2(trace_len_e).unwrap();
+
+    // the first query is given as (e0, e1, p, poe); after all layers are folded, the query must
+    // point into the remainder codeword
+    let num_layers = commitments.len() / 4 - 1;
+    let p = positions[2];
+    let poe = (0..num_layers).fold(Felt::new(positions[3]), |poe, _| poe.exp(4));
+    let f_pos = p % (remainder.len() as u64 / 2);
+    let ne0 = remainder[2 * f_pos as usize];
+    let ne1 = remainder[2 * f_pos as usize + 1];
+
+    let advice_stack = prepare_advice_stack(
+        depth,
+        domain_size,
+        num_queries,
+        positions,
+        alphas,
+        commitments,
+        remainder,
+    );
+
+    let advice_map: BTreeMap<Digest, Vec<Felt>> = BTreeMap::from_iter(advice_maps);
+    let domain_generator = Felt::get_root_of_unity(domain_size.ilog2()).as_int();
+
+    let mut store = MerkleStore::new();
+    for partial_tree in &partial_trees {
+        store.extend(partial_tree.inner_nodes());
+    }
+    let test = build_test!(source, &[domain_generator], &advice_stack, store, advice_map);
+
+    test.expect_stack(&[poe.as_int(), f_pos, ne1, ne0]);
+}
+
 fn prepare_advice_stack(
     depth: usize,
     domain_size: u32,
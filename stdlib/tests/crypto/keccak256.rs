@@ -99,3 +99,18 @@ fn to_stack(i_digest: &[u8], stack: &mut [u64]) {
         stack[(i << 1) + 1] = low as u64;
     }
 }
+
+/// Checks the number of cycles of `keccak256::hash` quoted in the stdlib docs.
+#[test]
+fn keccak256_hash_cycle_count() {
+    let source = "
+    use.std::crypto::hashes::keccak256
+
+    begin
+        exec.keccak256::hash
+    end
+    ";
+
+    let trace = build_test!(source, &[0; 16]).execute().unwrap();
+    assert_eq!(trace.trace_len_summary().main_trace_len(), 85079);
+}
@@ -0,0 +1,346 @@
+use sha2::{Digest, Sha256};
+use test_utils::{
+    crypto::{init_merkle_leaves, DigestHashFunction, DigestMerkleTree, MerkleStore, MerkleTree},
+    group_slice_elements, Felt, Test, Word,
+};
+
+// MULTIPROOFS
+// ================================================================================================
+
+const LIST_PTR: u32 = 1000;
+
+#[test]
+fn verify_multi() {
+    let leaves = init_merkle_leaves(&(0..16).collect::<Vec<_>>());
+    let tree = MerkleTree::new(leaves.clone()).unwrap();
+    let store = MerkleStore::from(&tree);
+    let root: Word = tree.root().into();
+
+    // a single leaf, a full subtree, and leaves whose paths share only some of the nodes
+    for indices in [vec![5], vec![0, 1, 2, 3], vec![1, 6, 7, 12, 15], (0..16).collect()] {
+        let entries = indices.iter().map(|&i| (i, leaves[i as usize])).collect::<Vec<_>>();
+        let source = build_verify_multi_source(4, &entries);
+
+        let test = build_test!(&source, &to_stack_inputs(root), &[], store.clone());
+        test.expect_stack(&[root[3], root[2], root[1], root[0]].map(|e| e.as_int()));
+    }
+}
+
+#[test]
+fn verify_multi_fails() {
+    let leaves = init_merkle_leaves(&(0..16).collect::<Vec<_>>());
+    let tree = MerkleTree::new(leaves.clone()).unwrap();
+    let store = MerkleStore::from(&tree);
+    let root: Word = tree.root().into();
+
+    // a leaf is not located at the specified index
+    let entries = [(2, leaves[2]), (3, leaves[4])];
+    let source = build_verify_multi_source(4, &entries);
+    let test = build_test!(&source, &to_stack_inputs(root), &[], store.clone());
+    assert!(test.execute().is_err());
+
+    // the indices are not in strictly increasing order
+    let entries = [(3, leaves[3]), (2, leaves[2])];
+    let source = build_verify_multi_source(4, &entries);
+    let test = build_test!(&source, &to_stack_inputs(root), &[], store.clone());
+    assert!(test.execute().is_err());
+
+    // the same leaf is opened twice
+    let entries = [(3, leaves[3]), (3, leaves[3])];
+    let source = build_verify_multi_source(4, &entries);
+    let test = build_test!(&source, &to_stack_inputs(root), &[], store);
+    assert!(test.execute().is_err());
+}
+
+#[test]
+fn set_multi() {
+    let leaves = init_merkle_leaves(&(0..16).collect::<Vec<_>>());
+    let tree = MerkleTree::new(leaves).unwrap();
+    let store = MerkleStore::from(&tree);
+    let root: Word = tree.root().into();
+
+    for indices in [vec![5], vec![0, 1, 2, 3], vec![1, 6, 7, 12, 15], (0..16).collect()] {
+        let entries = indices
+            .iter()
+            .map(|&i| (i, [Felt::new(i + 100), Felt::new(1), Felt::new(2), Felt::new(3)]))
+            .collect::<Vec<_>>();
+
+        let mut expected = tree.clone();
+        for &(index, value) in entries.iter() {
+            expected.update_leaf(index, value).unwrap();
+        }
+        let new_root: Word = expected.root().into();
+
+        // the updated leaves must be readable from the new tree
+        let (index, value) = entries[entries.len() / 2];
+        let source = build_set_multi_source(4, &entries)
+            .replace("end\n", &format!("    push.{index} push.4 mtree_get\nend\n"));
+        let test = build_test!(&source, &to_stack_inputs(root), &[], store.clone());
+        let mut expected_stack = [value[3], value[2], value[1], value[0]].to_vec();
+        expected_stack.extend([new_root[3], new_root[2], new_root[1], new_root[0]]);
+        test.expect_stack(&expected_stack.iter().map(|e| e.as_int()).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn set_multi_fails() {
+    let leaves = init_merkle_leaves(&(0..16).collect::<Vec<_>>());
+    let tree = MerkleTree::new(leaves).unwrap();
+    let store = MerkleStore::from(&tree);
+    let root: Word = tree.root().into();
+    let value = [Felt::new(7), Felt::new(8), Felt::new(9), Felt::new(10)];
+
+    // the indices are not in strictly increasing order
+    let source = build_set_multi_source(4, &[(3, value), (2, value)]);
+    let test = build_test!(&source, &to_stack_inputs(root), &[], store.clone());
+    assert!(test.execute().is_err());
+
+    // an index is outside of the tree
+    let source = build_set_multi_source(4, &[(3, value), (16, value)]);
+    let test = build_test!(&source, &to_stack_inputs(root), &[], store.clone());
+    assert!(test.execute().is_err());
+
+    // the tree is not in the advice provider
+    let source = build_set_multi_source(4, &[(3, value)]);
+    let test = build_test!(&source, &to_stack_inputs(value), &[], store);
+    assert!(test.execute().is_err());
+}
+
+// BLAKE3 AND SHA256 PATHS
+// ================================================================================================
+
+#[test]
+fn verify_blake3_path() {
+    let tree = DigestTree::new(3, blake3_merge, u32::from_le_bytes);
+    let root = tree.node_words(1);
+    for index in 0..8 {
+        let test = tree.build_verify_path_test("verify_blake3_path", index, &root);
+        test.expect_stack(&root);
+    }
+}
+
+#[test]
+fn verify_sha256_path() {
+    let tree = DigestTree::new(3, sha256_merge, u32::from_be_bytes);
+    let root = tree.node_words(1);
+    for index in 0..8 {
+        let test = tree.build_verify_path_test("verify_sha256_path", index, &root);
+        test.expect_stack(&root);
+    }
+}
+
+#[test]
+fn verify_digest_path_fails() {
+    for (procedure, tree) in [
+        ("verify_blake3_path", DigestTree::new(3, blake3_merge, u32::from_le_bytes)),
+        ("verify_sha256_path", DigestTree::new(3, sha256_merge, u32::from_be_bytes)),
+    ] {
+        // the root is not the root of the tree
+        let root = tree.node_words(2);
+        let test = tree.build_verify_path_test(procedure, 5, &root);
+        assert!(test.execute().is_err());
+
+        // the index is outside of the tree
+        let root = tree.node_words(1);
+        let test = tree.build_verify_path_test(procedure, 13, &root);
+        assert!(test.execute().is_err());
+    }
+}
+
+#[test]
+fn get_digest_node() {
+    for (procedure, hash_fn, tree) in [
+        (
+            "get_blake3_node",
+            DigestHashFunction::Blake3,
+            DigestTree::new(3, blake3_merge, u32::from_le_bytes),
+        ),
+        (
+            "get_sha256_node",
+            DigestHashFunction::Sha256,
+            DigestTree::new(3, sha256_merge, u32::from_be_bytes),
+        ),
+    ] {
+        let digest_tree = DigestMerkleTree::new(hash_fn, tree.nodes[8..].to_vec()).unwrap();
+        let root = tree.node_words(1);
+        assert_eq!(to_values(&digest_tree.root_elements()), root);
+
+        let advice_map = digest_tree.to_advice_map_entries().collect::<Vec<_>>();
+        for index in 0..8 {
+            let source = build_get_node_source(procedure, index, &root);
+            let mut expected = tree.node_words(8 + index as usize);
+            expected.extend_from_slice(&root);
+            build_test!(&source, &[], &[], MerkleStore::default(), advice_map.clone())
+                .expect_stack(&expected);
+        }
+
+        // the index is outside of the tree
+        let source = build_get_node_source(procedure, 8, &root);
+        let test = build_test!(&source, &[], &[], MerkleStore::default(), advice_map.clone());
+        assert!(test.execute().is_err());
+
+        // the tree is not in the advice map
+        let test = build_test!(&build_get_node_source(procedure, 5, &root), &[]);
+        assert!(test.execute().is_err());
+
+        // the node in the advice map is not the node of the tree
+        let mut tampered = advice_map;
+        let key =
+            DigestMerkleTree::node_key(&digest_tree.root_elements(), Felt::new(3), Felt::new(5));
+        let node = tree.node_words(8 + 4);
+        for (entry_key, values) in tampered.iter_mut() {
+            if *entry_key == key {
+                *values = node.iter().map(|&value| Felt::new(value)).collect();
+            }
+        }
+        let source = build_get_node_source(procedure, 5, &root);
+        let test = build_test!(&source, &[], &[], MerkleStore::default(), tampered);
+        assert!(test.execute().is_err());
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a program which writes the specified list of leaves into memory and verifies it via
+/// `verify_multi`.
+fn build_verify_multi_source(depth: u8, entries: &[(u64, Word)]) -> String {
+    let mut source = String::from("use.std::crypto::merkle\nbegin\n");
+    for (j, (index, leaf)) in entries.iter().enumerate() {
+        let addr = LIST_PTR + 2 * j as u32;
+        source.push_str(&format!("    push.{index} push.{addr} mem_store\n"));
+        source.push_str(&format!(
+            "    push.{}.{}.{}.{} push.{} mem_storew dropw\n",
+            leaf[0],
+            leaf[1],
+            leaf[2],
+            leaf[3],
+            addr + 1
+        ));
+    }
+    source.push_str(&format!(
+        "    push.{LIST_PTR} push.{} push.{depth} exec.merkle::verify_multi\nend\n",
+        entries.len()
+    ));
+    source
+}
+
+/// Returns a program which writes the specified list of leaves into memory and sets them via
+/// `set_multi`.
+fn build_set_multi_source(depth: u8, entries: &[(u64, Word)]) -> String {
+    let mut source = String::from("use.std::crypto::merkle\nbegin\n");
+    for (j, (index, value)) in entries.iter().enumerate() {
+        let addr = LIST_PTR + 3 * j as u32;
+        source.push_str(&format!("    push.{index} push.{addr} mem_store\n"));
+        source.push_str(&format!(
+            "    push.{}.{}.{}.{} push.{} mem_storew dropw\n",
+            value[0],
+            value[1],
+            value[2],
+            value[3],
+            addr + 1
+        ));
+    }
+    source.push_str(&format!(
+        "    push.{LIST_PTR} push.{} push.{depth} exec.merkle::set_multi\nend\n",
+        entries.len()
+    ));
+    source
+}
+
+/// Returns a program which reads the node at the specified index of the tree of depth 3 with the
+/// specified root via the specified procedure.
+fn build_get_node_source(procedure: &str, index: u64, root: &[u64]) -> String {
+    format!(
+        "
+        use.std::crypto::merkle
+
+        begin
+            push.{} push.{index}.3 exec.merkle::{procedure}
+        end",
+        to_push_values(root)
+    )
+}
+
+fn to_values(elements: &[Felt]) -> Vec<u64> {
+    elements.iter().map(Felt::as_int).collect()
+}
+
+fn to_stack_inputs(word: Word) -> Vec<u64> {
+    word.iter().map(Felt::as_int).collect()
+}
+
+/// A Merkle tree over 32-byte digests, in which the children of node k are nodes 2k and 2k + 1, and
+/// the root is node 1.
+struct DigestTree {
+    depth: u32,
+    nodes: Vec<[u8; 32]>,
+    to_u32: fn([u8; 4]) -> u32,
+}
+
+impl DigestTree {
+    fn new(depth: u32, merge: fn(&[u8]) -> [u8; 32], to_u32: fn([u8; 4]) -> u32) -> Self {
+        let num_leaves = 1 << depth;
+        let mut nodes = vec![[0; 32]; 2 * num_leaves];
+        for (i, leaf) in nodes[num_leaves..].iter_mut().enumerate() {
+            *leaf = merge(&(i as u64).to_le_bytes());
+        }
+        for k in (1..num_leaves).rev() {
+            nodes[k] = merge(&[nodes[2 * k], nodes[2 * k + 1]].concat());
+        }
+        Self {
+            depth,
+            nodes,
+            to_u32,
+        }
+    }
+
+    /// Returns the specified node as eight 32-bit values.
+    fn node_words(&self, k: usize) -> Vec<u64> {
+        group_slice_elements::<u8, 4>(&self.nodes[k])
+            .iter()
+            .map(|&bytes| (self.to_u32)(bytes) as u64)
+            .collect()
+    }
+
+    /// Returns a test which verifies the path of the leaf at the specified index against the
+    /// specified root. Only the lowest bits of the index are used to select the leaf.
+    fn build_verify_path_test(&self, procedure: &str, index: usize, root: &[u64]) -> Test {
+        let num_leaves = 1 << self.depth;
+        let node = num_leaves + index % num_leaves;
+
+        let mut advice_stack = Vec::new();
+        let mut k = node;
+        while k > 1 {
+            advice_stack.extend(self.node_words(k ^ 1).into_iter().rev());
+            k /= 2;
+        }
+
+        let source = format!(
+            "
+            use.std::crypto::merkle
+
+            begin
+                push.{} push.{} push.{index}.{} exec.merkle::{procedure}
+            end",
+            to_push_values(root),
+            to_push_values(&self.node_words(node)),
+            self.depth
+        );
+        build_test!(&source, &[], &advice_stack)
+    }
+}
+
+fn blake3_merge(bytes: &[u8]) -> [u8; 32] {
+    *blake3::hash(bytes).as_bytes()
+}
+
+fn sha256_merge(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(bytes).into()
+}
+
+/// Returns the immediate values of a `push` instruction which puts the specified values on the
+/// stack, with the first value on the top of the stack.
+fn to_push_values(values: &[u64]) -> String {
+    values.iter().rev().map(|value| value.to_string()).collect::<Vec<_>>().join(".")
+}
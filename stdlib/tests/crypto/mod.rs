@@ -6,6 +6,8 @@ mod ecdsa_secp256k1;
 mod elgamal;
 mod fri;
 mod keccak256;
+mod merkle;
 mod native;
+mod rng;
 mod sha256;
 mod stark;
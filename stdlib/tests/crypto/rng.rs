@@ -0,0 +1,78 @@
+use processor::crypto::RpoTranscript;
+use test_utils::Felt;
+
+#[test]
+fn rng_matches_rpo_random_coin() {
+    let source = "
+    use.std::crypto::rng
+
+    begin
+        push.1000 push.1.2.3.4
+        exec.rng::init
+
+        repeat.9
+            push.1000 exec.rng::next_felt
+        end
+        push.1000 exec.rng::next_word
+    end
+    ";
+
+    // draw 9 elements to make sure the state is permuted once the rate is exhausted
+    let mut transcript = RpoTranscript::new(to_word([1, 2, 3, 4]));
+    let mut expected = (0..13).map(|_| transcript.draw_felt().as_int()).collect::<Vec<_>>();
+    expected.reverse();
+
+    build_test!(source, &[]).expect_stack(&expected);
+}
+
+#[test]
+fn rng_reseed() {
+    let source = "
+    use.std::crypto::rng
+
+    begin
+        push.1000 push.1.2.3.4
+        exec.rng::init
+
+        # draw an element so that reseeding has to reset the number of drawn elements
+        push.1000 exec.rng::next_felt drop
+
+        push.1000 push.5.6.7.8
+        exec.rng::reseed
+
+        push.1000 exec.rng::next_felt
+        push.1000 push.10 exec.rng::next_u32_range
+    end
+    ";
+
+    let mut transcript = RpoTranscript::new(to_word([1, 2, 3, 4]));
+    transcript.draw_felt();
+    transcript.reseed(to_word([5, 6, 7, 8]));
+    let x = transcript.draw_felt().as_int();
+    let y = transcript.draw_u32_range(10) as u64;
+
+    build_test!(source, &[]).expect_stack(&[y, x]);
+}
+
+#[test]
+fn rng_invalid_range() {
+    let source = "
+    use.std::crypto::rng
+
+    begin
+        push.1000 push.1.2.3.4
+        exec.rng::init
+
+        push.1000 push.0 exec.rng::next_u32_range
+    end
+    ";
+
+    assert!(build_test!(source, &[]).execute().is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn to_word(values: [u64; 4]) -> [Felt; 4] {
+    values.map(Felt::new)
+}
@@ -124,3 +124,26 @@ fn sha256_1_to_1_hash() {
     let test = build_test!(source, &ifelts);
     test.expect_stack(&ofelts);
 }
+
+/// Checks the number of cycles of `sha256::hash_1to1` and `sha256::hash_2to1` quoted in the
+/// stdlib docs.
+#[test]
+fn sha256_hash_cycle_counts() {
+    let source = "
+    use.std::crypto::hashes::sha256
+
+    begin
+        exec.sha256::hash_1to1
+    end";
+    let trace = build_test!(source, &[0; 8]).execute().unwrap();
+    assert_eq!(trace.trace_len_summary().main_trace_len(), 8519);
+
+    let source = "
+    use.std::crypto::hashes::sha256
+
+    begin
+        exec.sha256::hash_2to1
+    end";
+    let trace = build_test!(source, &[0; 16]).execute().unwrap();
+    assert_eq!(trace.trace_len_summary().main_trace_len(), 14517);
+}
@@ -12,4 +12,5 @@ mod collections;
 mod crypto;
 mod math;
 mod mem;
+mod output;
 mod sys;
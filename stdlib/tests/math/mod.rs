@@ -1,4 +1,7 @@
 pub mod ecgfp5;
+mod ntt;
+mod poly;
 mod secp256k1;
 mod u256_mod;
+mod u32vec_mod;
 mod u64_mod;
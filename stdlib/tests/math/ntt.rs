@@ -0,0 +1,162 @@
+use test_utils::{rand::rand_vector, Felt, FieldElement, StarkField, Test, ONE, ZERO};
+
+// CONSTANTS
+// ================================================================================================
+
+const A_PTR: u32 = 1000;
+const TW_PTR: u32 = 100000;
+
+// TWIDDLES
+// ================================================================================================
+
+#[test]
+fn precompute_twiddles() {
+    for log_n in [1, 4, 16] {
+        let source = format!(
+            "
+            use.std::math::ntt
+            begin
+                push.{TW_PTR}.{log_n} exec.ntt::precompute_twiddles
+                push.{}.{log_n} exec.ntt::precompute_inv_twiddles
+            end",
+            TW_PTR + (1 << 15)
+        );
+        let test = build_test!(&source);
+
+        let root = Felt::get_root_of_unity(log_n);
+        let n = 1_u32 << log_n;
+        let twiddles = powers(root, n as usize / 2);
+        let inv_twiddles = powers(root.inv(), n as usize / 2);
+
+        // each twiddle is stored in the first element of the word at its address
+        test.expect_memory(TW_PTR, &to_words(&twiddles));
+        test.expect_memory(TW_PTR + (1 << 15), &to_words(&inv_twiddles));
+    }
+}
+
+#[test]
+fn invalid_log_n() {
+    for log_n in [0, 17] {
+        let source = format!(
+            "
+            use.std::math::ntt
+            begin
+                push.{TW_PTR}.{log_n} exec.ntt::precompute_twiddles
+            end"
+        );
+        build_test!(&source).expect_error_code(0);
+    }
+}
+
+// TRANSFORMS
+// ================================================================================================
+
+#[test]
+fn forward() {
+    for log_n in [1, 2, 3, 6] {
+        let values = rand_vector::<Felt>(1 << log_n);
+        let test = build_ntt_test(log_n, &values, "exec.ntt::forward");
+        test.expect_memory(A_PTR, &to_words(&dft(&values, Felt::get_root_of_unity(log_n))));
+    }
+}
+
+#[test]
+fn forward_known_values() {
+    // the transform of [1, 0, ..., 0] is [1, 1, ..., 1], and the transform of [1, 1, ..., 1] is
+    // [n, 0, ..., 0]
+    let test =
+        build_ntt_test(3, &[ONE, ZERO, ZERO, ZERO, ZERO, ZERO, ZERO, ZERO], "exec.ntt::forward");
+    test.expect_memory(A_PTR, &to_words(&[ONE; 8]));
+
+    let test = build_ntt_test(3, &[ONE; 8], "exec.ntt::forward");
+    test.expect_memory(A_PTR, &to_words(&[Felt::new(8), ZERO, ZERO, ZERO, ZERO, ZERO, ZERO, ZERO]));
+}
+
+#[test]
+fn inverse() {
+    for log_n in [1, 3, 6] {
+        let values = rand_vector::<Felt>(1 << log_n);
+        let evaluations = dft(&values, Felt::get_root_of_unity(log_n));
+        let test = build_ntt_test(log_n, &evaluations, "exec.ntt::inverse");
+        test.expect_memory(A_PTR, &to_words(&values));
+    }
+}
+
+#[test]
+fn forward_inverse_round_trip() {
+    let log_n = 5;
+    let values = rand_vector::<Felt>(1 << log_n);
+    let mut source = format!("use.std::math::ntt\nbegin\n{}", store_values(&values));
+    source.push_str(&format!(
+        "
+        push.{TW_PTR}.{log_n} exec.ntt::precompute_twiddles
+        push.{}.{log_n} exec.ntt::precompute_inv_twiddles
+        push.{TW_PTR}.{A_PTR}.{log_n} exec.ntt::forward
+        push.{}.{A_PTR}.{log_n} exec.ntt::inverse
+        end",
+        TW_PTR + 1000,
+        TW_PTR + 1000
+    ));
+
+    let test = build_test!(&source);
+    test.expect_memory(A_PTR, &to_words(&values));
+    test.expect_stack(&[]);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds a test which stores the values at [A_PTR], computes the twiddles for the transform
+/// (forward twiddles for `exec.ntt::forward`, and inverse twiddles otherwise) at [TW_PTR], and
+/// then executes the transform.
+fn build_ntt_test(log_n: u32, values: &[Felt], transform: &str) -> Test {
+    let precompute = if transform == "exec.ntt::forward" {
+        "precompute_twiddles"
+    } else {
+        "precompute_inv_twiddles"
+    };
+    let source = format!(
+        "
+        use.std::math::ntt
+        begin
+            {}
+            push.{TW_PTR}.{log_n} exec.ntt::{precompute}
+            push.{TW_PTR}.{A_PTR}.{log_n} {transform}
+        end",
+        store_values(values)
+    );
+    build_test!(&source)
+}
+
+/// Returns instructions which store the values at consecutive addresses starting at [A_PTR].
+fn store_values(values: &[Felt]) -> String {
+    values
+        .iter()
+        .zip(A_PTR..)
+        .map(|(value, addr)| format!("push.{value}.{addr} mem_store\n"))
+        .collect()
+}
+
+/// Computes the evaluations of the polynomial with the specified coefficients at the powers of
+/// the specified root of unity.
+fn dft(coefficients: &[Felt], root: Felt) -> Vec<Felt> {
+    powers(root, coefficients.len())
+        .into_iter()
+        .map(|x| coefficients.iter().rev().fold(ZERO, |acc, &c| acc * x + c))
+        .collect()
+}
+
+fn powers(base: Felt, n: usize) -> Vec<Felt> {
+    let mut result = Vec::with_capacity(n);
+    let mut power = ONE;
+    for _ in 0..n {
+        result.push(power);
+        power *= base;
+    }
+    result
+}
+
+/// Returns the memory contents of an array with one element per word.
+fn to_words(values: &[Felt]) -> Vec<u64> {
+    values.iter().flat_map(|value| [value.as_int(), 0, 0, 0]).collect()
+}
@@ -0,0 +1,152 @@
+use test_utils::{math::polynom, rand::rand_vector, Felt, FieldElement};
+
+// CONSTANTS
+// ================================================================================================
+
+const A_PTR: u32 = 1000;
+const B_PTR: u32 = 2000;
+const C_PTR: u32 = 3000;
+
+// EVALUATION
+// ================================================================================================
+
+#[test]
+fn eval() {
+    for n in [0, 1, 2, 13] {
+        // rand_vector panics for empty vectors
+        let coefficients = if n == 0 { Vec::new() } else { rand_vector::<Felt>(n) };
+        let x = rand_vector::<Felt>(1)[0];
+        let source = format!(
+            "
+            use.std::math::poly
+            begin
+                {}
+                push.{x}.{A_PTR}.{n} exec.poly::eval
+            end",
+            store_values(A_PTR, &coefficients)
+        );
+        let expected = polynom::eval(&coefficients, x);
+        build_test!(&source).expect_stack(&[expected.as_int()]);
+    }
+}
+
+// DIVISION
+// ================================================================================================
+
+#[test]
+fn div_linear() {
+    let coefficients = rand_vector::<Felt>(9);
+    let c = rand_vector::<Felt>(1)[0];
+    let source = format!(
+        "
+        use.std::math::poly
+        begin
+            {}
+            push.{B_PTR}.{c}.{A_PTR}.9 exec.poly::div_linear
+        end",
+        store_values(A_PTR, &coefficients)
+    );
+
+    // f(X) = q(X) * (X - c) + f(c)
+    let remainder = polynom::eval(&coefficients, c);
+    let mut quotient = vec![coefficients[8]; 8];
+    for i in (0..7).rev() {
+        quotient[i] = coefficients[i + 1] + c * quotient[i + 1];
+    }
+    let product = polynom::mul(&quotient, &[-c, Felt::ONE]);
+    assert_eq!(polynom::add(&product, &[remainder]), coefficients);
+
+    let test = build_test!(&source);
+    test.expect_stack(&[remainder.as_int()]);
+    test.expect_memory(B_PTR, &to_words(&quotient));
+}
+
+#[test]
+fn div_linear_by_root() {
+    // (X - 2)(X - 3) = X^2 - 5X + 6, divided by (X - 3), is (X - 2) with no remainder
+    let source = format!(
+        "
+        use.std::math::poly
+        begin
+            {}
+            push.{B_PTR}.3.{A_PTR}.3 exec.poly::div_linear
+        end",
+        store_values(A_PTR, &[Felt::new(6), -Felt::new(5), Felt::new(1)])
+    );
+
+    let test = build_test!(&source);
+    test.expect_stack(&[0]);
+    test.expect_memory(B_PTR, &to_words(&[-Felt::new(2), Felt::new(1)]));
+}
+
+// INTERPOLATION
+// ================================================================================================
+
+#[test]
+fn barycentric_interpolation() {
+    let n = 7;
+    let xs = rand_vector::<Felt>(n);
+    let ys = rand_vector::<Felt>(n);
+    let z = rand_vector::<Felt>(1)[0];
+
+    let source = format!(
+        "
+        use.std::math::poly
+        begin
+            {}
+            {}
+            push.{C_PTR}.{A_PTR}.{n} exec.poly::barycentric_weights
+            push.{z}.{C_PTR}.{B_PTR}.{A_PTR}.{n} exec.poly::eval_barycentric
+        end",
+        store_values(A_PTR, &xs),
+        store_values(B_PTR, &ys)
+    );
+
+    let expected = polynom::eval(&polynom::interpolate(&xs, &ys, false), z);
+    let weights = xs
+        .iter()
+        .enumerate()
+        .map(|(i, xi)| {
+            xs.iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .fold(Felt::ONE, |acc, (_, xj)| acc * (*xi - *xj))
+                .inv()
+        })
+        .collect::<Vec<_>>();
+
+    let test = build_test!(&source);
+    test.expect_stack(&[expected.as_int()]);
+    test.expect_memory(C_PTR, &to_words(&weights));
+}
+
+#[test]
+fn barycentric_weights_duplicate_points() {
+    let source = format!(
+        "
+        use.std::math::poly
+        begin
+            {}
+            push.{C_PTR}.{A_PTR}.3 exec.poly::barycentric_weights
+        end",
+        store_values(A_PTR, &[Felt::new(1), Felt::new(2), Felt::new(1)])
+    );
+    assert!(build_test!(&source).execute().is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns instructions which store the values at consecutive addresses starting at `ptr`.
+fn store_values(ptr: u32, values: &[Felt]) -> String {
+    values
+        .iter()
+        .zip(ptr..)
+        .map(|(value, addr)| format!("push.{value}.{addr} mem_store\n"))
+        .collect()
+}
+
+/// Returns the memory contents of an array with one element per word.
+fn to_words(values: &[Felt]) -> Vec<u64> {
+    values.iter().flat_map(|value| [value.as_int(), 0, 0, 0]).collect()
+}
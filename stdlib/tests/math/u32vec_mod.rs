@@ -0,0 +1,102 @@
+use test_utils::{proptest::prelude::*, rand::rand_array};
+
+// CONSTANTS
+// ================================================================================================
+
+const A_PTR: u32 = 100;
+const B_PTR: u32 = 200;
+const C_PTR: u32 = 300;
+
+// ARRAY OPERATIONS
+// ================================================================================================
+
+#[test]
+fn wrapping_add() {
+    let a = rand_array::<u64, 12>().map(|v| v as u32);
+    let b = rand_array::<u64, 12>().map(|v| v as u32);
+    let expected = a.iter().zip(b.iter()).map(|(a, b)| a.wrapping_add(*b));
+    test_array_op("wrapping_add", &a, &b, C_PTR).expect_memory(C_PTR, &to_u64s(expected));
+}
+
+#[test]
+fn wrapping_sub() {
+    let a = rand_array::<u64, 12>().map(|v| v as u32);
+    let b = rand_array::<u64, 12>().map(|v| v as u32);
+    let expected = a.iter().zip(b.iter()).map(|(a, b)| a.wrapping_sub(*b));
+    test_array_op("wrapping_sub", &a, &b, C_PTR).expect_memory(C_PTR, &to_u64s(expected));
+}
+
+#[test]
+fn wrapping_mul() {
+    let a = rand_array::<u64, 12>().map(|v| v as u32);
+    let b = rand_array::<u64, 12>().map(|v| v as u32);
+    let expected = a.iter().zip(b.iter()).map(|(a, b)| a.wrapping_mul(*b));
+    test_array_op("wrapping_mul", &a, &b, C_PTR).expect_memory(C_PTR, &to_u64s(expected));
+}
+
+#[test]
+fn wrapping_add_in_place() {
+    // the results overwrite the first array, and the second array is left unchanged
+    let a = [u32::MAX, 1, 2, 3, 4, 5, 6, 7];
+    let b = [1, u32::MAX, 2, 3, 0, 0, 0, u32::MAX];
+    let expected = [0, 0, 4, 6, 4, 5, 6, 6];
+    let test = test_array_op("wrapping_add", &a, &b, A_PTR);
+    test.expect_memory(A_PTR, &to_u64s(expected));
+    test.expect_memory(B_PTR, &to_u64s(b));
+}
+
+#[test]
+fn wrapping_add_empty() {
+    let source = format!(
+        "
+        use.std::math::u32vec
+        begin
+            push.{C_PTR}.{B_PTR}.{A_PTR}.0
+            exec.u32vec::wrapping_add
+        end"
+    );
+    build_test!(&source, &[7, 8]).expect_stack(&[8, 7]);
+}
+
+proptest! {
+    #[test]
+    fn wrapping_mul_proptest(
+        words in prop::collection::vec((any::<[u32; 4]>(), any::<[u32; 4]>()), 1..8),
+    ) {
+        let a = words.iter().flat_map(|(a, _)| *a).collect::<Vec<_>>();
+        let b = words.iter().flat_map(|(_, b)| *b).collect::<Vec<_>>();
+        let expected = a.iter().zip(b.iter()).map(|(a, b)| a.wrapping_mul(*b));
+
+        let test = test_array_op("wrapping_mul", &a, &b, C_PTR);
+        test.prop_expect_stack(&[])?;
+        test.expect_memory(C_PTR, &to_u64s(expected));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Builds a test which stores arrays `a` and `b` at [A_PTR] and [B_PTR], and executes the
+/// specified array operation which writes its results at `c_ptr`.
+fn test_array_op(op: &str, a: &[u32], b: &[u32], c_ptr: u32) -> test_utils::Test {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len() % 4, 0);
+
+    let mut source = String::from("use.std::math::u32vec\nbegin\n");
+    for (ptr, values) in [(A_PTR, a), (B_PTR, b)] {
+        for (addr, word) in (ptr..).zip(values.chunks(4)) {
+            source.push_str(&format!(
+                "push.{}.{}.{}.{}.{addr} mem_storew dropw\n",
+                word[0], word[1], word[2], word[3]
+            ));
+        }
+    }
+    source
+        .push_str(&format!("push.{c_ptr}.{B_PTR}.{A_PTR}.{} exec.u32vec::{op}\nend", a.len() / 4));
+
+    build_test!(&source)
+}
+
+fn to_u64s(values: impl IntoIterator<Item = u32>) -> Vec<u64> {
+    values.into_iter().map(u64::from).collect()
+}
@@ -1,7 +1,7 @@
-use processor::{ContextId, DefaultHost, ProcessState};
+use processor::{ContextId, DefaultHost, MemoryImage, ProcessState};
 use test_utils::{
-    build_expected_hash, build_expected_perm, stack_to_ints, ExecutionOptions, Process,
-    StackInputs, ONE, ZERO,
+    build_expected_hash, build_expected_perm, crypto::MerkleStore, stack_to_ints, ExecutionOptions,
+    Felt, Process, StackInputs, ONE, ZERO,
 };
 
 #[test]
@@ -216,3 +216,59 @@ fn test_pipe_preimage_to_memory_invalid_preimage() {
     let res = build_test!(three_words, operand_stack, &advice_stack).execute();
     assert!(res.is_err());
 }
+
+#[test]
+fn test_load_image() {
+    let source = "
+    use.std::mem
+
+    begin
+        exec.mem::load_image
+    end
+    ";
+
+    let first = [1, 2, 3, 4, 5, 6, 7, 8];
+    let second = [9, 10, 11, 12];
+    let image = MemoryImage::new()
+        .with_words(1000, &to_words(&first))
+        .unwrap()
+        .with_words(2000, &to_words(&second))
+        .unwrap();
+    let (key, values) = image.to_advice_map_entry();
+    let commitment = stack_to_ints(key.as_elements());
+
+    let test = build_test!(source, &commitment, &[], MerkleStore::new(), [(key, values.clone())]);
+    test.expect_stack_and_memory(&[], 1000, &first);
+    test.expect_memory(2000, &second);
+
+    // words at consecutive addresses are loaded as a single run
+    let image = image.with_words(1002, &to_words(&second)).unwrap();
+    assert_eq!(
+        image.runs().map(|(addr, words)| (addr, words.len())).collect::<Vec<_>>(),
+        [(1000, 3), (2000, 1)]
+    );
+    let (key, values) = image.to_advice_map_entry();
+    let commitment = stack_to_ints(key.as_elements());
+    let test = build_test!(source, &commitment, &[], MerkleStore::new(), [(key, values.clone())]);
+    test.expect_stack_and_memory(&[], 1000, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+
+    // the image must match the commitment, both the commitments of the runs and their words
+    for position in [5, 9] {
+        let mut tampered = values.clone();
+        tampered[position] += ONE;
+        let test = build_test!(source, &commitment, &[], MerkleStore::new(), [(key, tampered)]);
+        assert!(test.execute().is_err());
+    }
+
+    // an empty image loads nothing
+    let (key, values) = MemoryImage::new().to_advice_map_entry();
+    let commitment = stack_to_ints(key.as_elements());
+    build_test!(source, &commitment, &[], MerkleStore::new(), [(key, values)]).expect_stack(&[]);
+}
+
+fn to_words(values: &[u64]) -> Vec<[Felt; 4]> {
+    values
+        .chunks(4)
+        .map(|chunk| [chunk[0], chunk[1], chunk[2], chunk[3]].map(Felt::new))
+        .collect()
+}
@@ -0,0 +1,37 @@
+use test_utils::build_expected_hash;
+
+#[test]
+fn digest_and_commit() {
+    for num_words in [1, 2, 3] {
+        let source = format!(
+            "
+        use.std::output
+
+        begin
+            push.1.2.3.4.1000 mem_storew dropw
+            push.5.6.7.8.1001 mem_storew dropw
+            push.9.10.11.12.1002 mem_storew dropw
+
+            push.{num_words}.1000 exec.output::digest
+            push.{num_words}.1000 exec.output::commit
+        end"
+        );
+
+        let elements = (1..=num_words * 4).collect::<Vec<u64>>();
+        let digest = build_expected_hash(&elements).map(|element| element.as_int());
+        let expected = [digest, digest].concat();
+        build_test!(&source, &[]).expect_stack(&expected);
+    }
+}
+
+#[test]
+fn commit_empty_buffer() {
+    let source = "
+    use.std::output
+
+    begin
+        push.0.1000 exec.output::commit
+    end";
+
+    assert!(build_test!(source, &[]).execute().is_err());
+}
@@ -8,6 +8,7 @@ pub use vm_core::crypto::{
     dsa::*,
     hash::{Rpo256, RpoDigest},
     merkle::{
+        partial_merkle_tree_from_proofs, CompactSmt, DigestHashFunction, DigestMerkleTree,
         EmptySubtreeRoots, LeafIndex, MerkleError, MerklePath, MerkleStore, MerkleTree, Mmr,
         MmrPeaks, NodeIndex, PartialMerkleTree, SimpleSmt, Smt,
     },
@@ -0,0 +1,669 @@
+use super::{
+    ContextId, Felt, FieldElement, ProcessState, StackInputs, StarkField, Test, Word, EMPTY_WORD,
+    ONE, ZERO,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+use proptest::prelude::*;
+use vm_core::{code_blocks::CodeBlock, Operation, Program};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Minimum depth of the operand stack.
+const MIN_STACK_DEPTH: usize = 16;
+
+/// Default maximum number of operations the reference interpreter executes before giving up.
+const DEFAULT_MAX_STEPS: usize = 1 << 20;
+
+/// Addresses used by memory instructions of random programs. The set is kept small so that
+/// loads in random programs are likely to read previously stored values.
+const MEM_ADDRESSES: [u32; 4] = [0, 1, 2, 1000];
+
+// REFERENCE INTERPRETER
+// ================================================================================================
+
+/// A slow, straightforward interpreter of Miden VM programs which defines the reference semantics
+/// of VM operations for differential testing.
+///
+/// The interpreter walks the MAST of a program and executes one operation at a time against an
+/// operand stack kept in a vector and a memory kept in a map. It does not build an execution trace
+/// and does not share any code with the processor, so that a bug in the processor is not
+/// replicated here.
+///
+/// Only operations whose results depend exclusively on the stack and the memory of the root
+/// context are supported: operations which read advice inputs, invoke the host or the hasher, or
+/// depend on the clock, as well as `call`, `syscall` and `dyn` blocks, are reported as
+/// unsupported.
+#[derive(Debug, Clone)]
+pub struct ReferenceInterpreter {
+    /// Operand stack with the top of the stack at the last position.
+    stack: Vec<Felt>,
+    memory: BTreeMap<u32, Word>,
+    max_steps: usize,
+    steps: usize,
+}
+
+impl ReferenceInterpreter {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new interpreter with the operand stack initialized from the specified inputs.
+    pub fn new(stack_inputs: &StackInputs) -> Self {
+        let mut stack = vec![ZERO; MIN_STACK_DEPTH.saturating_sub(stack_inputs.values().len())];
+        stack.extend(stack_inputs.values().iter().rev());
+
+        Self {
+            stack,
+            memory: BTreeMap::new(),
+            max_steps: DEFAULT_MAX_STEPS,
+            steps: 0,
+        }
+    }
+
+    /// Sets the maximum number of operations the interpreter executes before returning an error.
+    pub fn with_max_steps(mut self, max_steps: usize) -> Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    // EXECUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Executes the specified program and returns the resulting state of the stack and memory.
+    ///
+    /// # Errors
+    /// Returns an error if the execution fails, if the program contains unsupported operations,
+    /// or if the result of the execution is not defined by the semantics of the VM.
+    pub fn execute(mut self, program: &Program) -> Result<ReferenceState, ReferenceError> {
+        self.execute_block(program.root())?;
+
+        Ok(ReferenceState {
+            stack: self.stack.into_iter().rev().collect(),
+            memory: self.memory.into_iter().filter(|(_, word)| *word != EMPTY_WORD).collect(),
+        })
+    }
+
+    fn execute_block(&mut self, block: &CodeBlock) -> Result<(), ReferenceError> {
+        match block {
+            CodeBlock::Span(span) => {
+                for op in span.op_batches().iter().flat_map(|batch| batch.ops()) {
+                    self.execute_op(*op)?;
+                }
+                Ok(())
+            }
+            CodeBlock::Join(join) => {
+                self.execute_block(join.first())?;
+                self.execute_block(join.second())
+            }
+            CodeBlock::Split(split) => {
+                let condition = self.pop_bool()?;
+                self.pad_stack();
+                if condition {
+                    self.execute_block(split.on_true())
+                } else {
+                    self.execute_block(split.on_false())
+                }
+            }
+            CodeBlock::Loop(block) => {
+                loop {
+                    let condition = self.pop_bool()?;
+                    self.pad_stack();
+                    if !condition {
+                        break;
+                    }
+                    self.execute_block(block.body())?;
+                }
+                Ok(())
+            }
+            CodeBlock::Call(_) => Err(ReferenceError::UnsupportedBlock("call")),
+            CodeBlock::Dyn(_) => Err(ReferenceError::UnsupportedBlock("dyn")),
+            CodeBlock::Proxy(_) => Err(ReferenceError::UnsupportedBlock("proxy")),
+        }
+    }
+
+    fn execute_op(&mut self, op: Operation) -> Result<(), ReferenceError> {
+        self.steps += 1;
+        if self.steps > self.max_steps {
+            return Err(ReferenceError::StepLimitExceeded(self.max_steps));
+        }
+
+        use Operation::*;
+        match op {
+            // ----- system operations ------------------------------------------------------------
+            Noop => (),
+            Assert(err_code) => {
+                if self.pop() != ONE {
+                    return Err(ReferenceError::FailedAssertion(err_code));
+                }
+            }
+            SDepth => self.push(Felt::new(self.stack.len() as u64)),
+
+            // ----- field operations -------------------------------------------------------------
+            Add => {
+                let b = self.pop();
+                let a = self.pop();
+                self.push(a + b);
+            }
+            Neg => {
+                let a = self.pop();
+                self.push(-a);
+            }
+            Mul => {
+                let b = self.pop();
+                let a = self.pop();
+                self.push(a * b);
+            }
+            Inv => {
+                let a = self.pop();
+                if a == ZERO {
+                    return Err(ReferenceError::DivideByZero);
+                }
+                self.push(a.inv());
+            }
+            Incr => {
+                let a = self.pop();
+                self.push(a + ONE);
+            }
+            And => {
+                let b = self.pop_bool()?;
+                let a = self.pop_bool()?;
+                self.push_bool(a && b);
+            }
+            Or => {
+                let b = self.pop_bool()?;
+                let a = self.pop_bool()?;
+                self.push_bool(a || b);
+            }
+            Not => {
+                let a = self.pop_bool()?;
+                self.push_bool(!a);
+            }
+            Eq => {
+                let b = self.pop();
+                let a = self.pop();
+                self.push_bool(a == b);
+            }
+            Eqz => {
+                let a = self.pop();
+                self.push_bool(a == ZERO);
+            }
+
+            // ----- u32 operations ---------------------------------------------------------------
+            U32split => {
+                let a = self.pop().as_int();
+                self.push_split(a);
+            }
+            U32add => {
+                let b = self.pop_u32(op)?;
+                let a = self.pop_u32(op)?;
+                self.push_split(a + b);
+            }
+            U32add3 => {
+                let c = self.pop_u32(op)?;
+                let b = self.pop_u32(op)?;
+                let a = self.pop_u32(op)?;
+                self.push_split(a + b + c);
+            }
+            U32sub => {
+                let b = self.pop_u32(op)?;
+                let a = self.pop_u32(op)?;
+                let result = a.wrapping_sub(b);
+                self.push(Felt::new(result & u32::MAX as u64));
+                self.push(Felt::new(result >> 63));
+            }
+            U32mul => {
+                let b = self.pop_u32(op)?;
+                let a = self.pop_u32(op)?;
+                self.push_split(a * b);
+            }
+            U32madd => {
+                let b = self.pop_u32(op)?;
+                let a = self.pop_u32(op)?;
+                let c = self.pop_u32(op)?;
+                self.push_split(a * b + c);
+            }
+            U32div => {
+                let b = self.pop_u32(op)?;
+                let a = self.pop_u32(op)?;
+                if b == 0 {
+                    return Err(ReferenceError::DivideByZero);
+                }
+                self.push(Felt::new(a / b));
+                self.push(Felt::new(a % b));
+            }
+            U32and => {
+                let b = self.pop_checked_u32()?;
+                let a = self.pop_checked_u32()?;
+                self.push(Felt::new(a & b));
+            }
+            U32xor => {
+                let b = self.pop_checked_u32()?;
+                let a = self.pop_checked_u32()?;
+                self.push(Felt::new(a ^ b));
+            }
+            U32assert2(err_code) => {
+                for pos in 0..2 {
+                    if self.get(pos).as_int() > u32::MAX as u64 {
+                        return Err(ReferenceError::NotU32Value(
+                            self.get(pos),
+                            err_code.as_int() as u32,
+                        ));
+                    }
+                }
+            }
+
+            // ----- stack manipulation -----------------------------------------------------------
+            Pad => self.push(ZERO),
+            Drop => {
+                self.pop();
+            }
+            Dup0 => self.dup(0),
+            Dup1 => self.dup(1),
+            Dup2 => self.dup(2),
+            Dup3 => self.dup(3),
+            Dup4 => self.dup(4),
+            Dup5 => self.dup(5),
+            Dup6 => self.dup(6),
+            Dup7 => self.dup(7),
+            Dup9 => self.dup(9),
+            Dup11 => self.dup(11),
+            Dup13 => self.dup(13),
+            Dup15 => self.dup(15),
+            Swap => self.swap_ranges(0, 1, 1),
+            SwapW => self.swap_ranges(0, 4, 4),
+            SwapW2 => self.swap_ranges(0, 8, 4),
+            SwapW3 => self.swap_ranges(0, 12, 4),
+            SwapDW => self.swap_ranges(0, 8, 8),
+            MovUp2 => self.move_up(2),
+            MovUp3 => self.move_up(3),
+            MovUp4 => self.move_up(4),
+            MovUp5 => self.move_up(5),
+            MovUp6 => self.move_up(6),
+            MovUp7 => self.move_up(7),
+            MovUp8 => self.move_up(8),
+            MovDn2 => self.move_down(2),
+            MovDn3 => self.move_down(3),
+            MovDn4 => self.move_down(4),
+            MovDn5 => self.move_down(5),
+            MovDn6 => self.move_down(6),
+            MovDn7 => self.move_down(7),
+            MovDn8 => self.move_down(8),
+            CSwap => {
+                if self.pop_bool()? {
+                    self.swap_ranges(0, 1, 1);
+                }
+            }
+            CSwapW => {
+                if self.pop_bool()? {
+                    self.swap_ranges(0, 4, 4);
+                }
+            }
+
+            // ----- input / output ---------------------------------------------------------------
+            Push(value) => self.push(value),
+            MLoadW => {
+                let addr = self.pop_addr()?;
+                let word = self.memory.get(&addr).copied().unwrap_or(EMPTY_WORD);
+                for (pos, value) in word.iter().rev().enumerate() {
+                    self.set(pos, *value);
+                }
+            }
+            MStoreW => {
+                let addr = self.pop_addr()?;
+                let word = [self.get(3), self.get(2), self.get(1), self.get(0)];
+                self.memory.insert(addr, word);
+            }
+            MLoad => {
+                let addr = self.pop_addr()?;
+                let word = self.memory.get(&addr).copied().unwrap_or(EMPTY_WORD);
+                self.push(word[0]);
+            }
+            MStore => {
+                let addr = self.pop_addr()?;
+                let value = self.get(0);
+                self.memory.entry(addr).or_insert(EMPTY_WORD)[0] = value;
+            }
+
+            _ => return Err(ReferenceError::UnsupportedOperation(op)),
+        }
+
+        self.pad_stack();
+        Ok(())
+    }
+
+    // STACK HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the index in the stack vector of the item at the specified position from the top.
+    fn index(&self, pos: usize) -> usize {
+        debug_assert!(pos < self.stack.len(), "stack position out of bounds");
+        self.stack.len() - 1 - pos
+    }
+
+    fn get(&self, pos: usize) -> Felt {
+        self.stack[self.index(pos)]
+    }
+
+    fn set(&mut self, pos: usize, value: Felt) {
+        let idx = self.index(pos);
+        self.stack[idx] = value;
+    }
+
+    fn push(&mut self, value: Felt) {
+        self.stack.push(value);
+    }
+
+    /// Removes the top item from the stack.
+    ///
+    /// The stack may temporarily get shallower than the minimum depth while an operation is
+    /// executed; it is padded back to the minimum depth by [Self::pad_stack()] afterwards.
+    fn pop(&mut self) -> Felt {
+        self.stack.pop().expect("stack is never empty")
+    }
+
+    /// Shifts zeros in at the bottom of the stack until the stack is at least as deep as the
+    /// minimum depth, as the stack never gets shallower than that.
+    fn pad_stack(&mut self) {
+        let num_missing = MIN_STACK_DEPTH.saturating_sub(self.stack.len());
+        self.stack.splice(0..0, core::iter::repeat(ZERO).take(num_missing));
+    }
+
+    fn pop_bool(&mut self) -> Result<bool, ReferenceError> {
+        let value = self.pop();
+        if value == ZERO {
+            Ok(false)
+        } else if value == ONE {
+            Ok(true)
+        } else {
+            Err(ReferenceError::NotBinaryValue(value))
+        }
+    }
+
+    /// Pops a value which the operation requires to be a u32 value; the result of the operation
+    /// is not defined otherwise.
+    fn pop_u32(&mut self, op: Operation) -> Result<u64, ReferenceError> {
+        let value = self.pop().as_int();
+        if value > u32::MAX as u64 {
+            return Err(ReferenceError::UndefinedResult(op));
+        }
+        Ok(value)
+    }
+
+    /// Pops a value and returns an error if it is not a u32 value.
+    fn pop_checked_u32(&mut self) -> Result<u64, ReferenceError> {
+        let value = self.pop();
+        if value.as_int() > u32::MAX as u64 {
+            return Err(ReferenceError::NotU32Value(value, 0));
+        }
+        Ok(value.as_int())
+    }
+
+    fn pop_addr(&mut self) -> Result<u32, ReferenceError> {
+        let addr = self.pop();
+        u32::try_from(addr.as_int()).map_err(|_| ReferenceError::InvalidAddress(addr))
+    }
+
+    fn push_bool(&mut self, value: bool) {
+        self.push(if value { ONE } else { ZERO });
+    }
+
+    /// Pushes the low and then the high 32 bits of the specified value onto the stack.
+    fn push_split(&mut self, value: u64) {
+        self.push(Felt::new(value & u32::MAX as u64));
+        self.push(Felt::new(value >> 32));
+    }
+
+    fn dup(&mut self, pos: usize) {
+        self.push(self.get(pos));
+    }
+
+    fn swap_ranges(&mut self, start_a: usize, start_b: usize, len: usize) {
+        for offset in 0..len {
+            let a = self.index(start_a + offset);
+            let b = self.index(start_b + offset);
+            self.stack.swap(a, b);
+        }
+    }
+
+    fn move_up(&mut self, pos: usize) {
+        let value = self.stack.remove(self.index(pos));
+        self.push(value);
+    }
+
+    fn move_down(&mut self, pos: usize) {
+        let value = self.stack.pop().expect("stack is never empty");
+        let idx = self.stack.len() - pos;
+        self.stack.insert(idx, value);
+    }
+}
+
+// REFERENCE STATE
+// ================================================================================================
+
+/// The state of the stack and memory at the end of a program's execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReferenceState {
+    /// All items on the operand stack, with the top of the stack at the first position.
+    pub stack: Vec<Felt>,
+    /// Words of the root context memory which are not all zeros, keyed by address.
+    pub memory: BTreeMap<u32, Word>,
+}
+
+impl ReferenceState {
+    /// Returns the state of the specified process, with the memory restricted to the specified
+    /// addresses and the addresses the process accessed.
+    fn from_process<P: ProcessState>(process: &P, addresses: &BTreeSet<u32>) -> Self {
+        let ctx = ContextId::root();
+        let accessed = process.get_mem_state(ctx).into_iter().map(|(addr, _)| addr as u32);
+        let memory = addresses
+            .iter()
+            .copied()
+            .chain(accessed)
+            .filter_map(|addr| {
+                let word = process.get_mem_value(ctx, addr)?;
+                (word != EMPTY_WORD).then_some((addr, word))
+            })
+            .collect();
+
+        Self {
+            stack: process.get_stack_state(),
+            memory,
+        }
+    }
+}
+
+// REFERENCE ERROR
+// ================================================================================================
+
+/// An error returned by the [ReferenceInterpreter].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReferenceError {
+    DivideByZero,
+    FailedAssertion(u32),
+    InvalidAddress(Felt),
+    NotBinaryValue(Felt),
+    NotU32Value(Felt, u32),
+    StepLimitExceeded(usize),
+    /// The operation was applied to values for which its result is not defined, e.g., a u32
+    /// operation which does not check its inputs was applied to values which are not u32 values.
+    UndefinedResult(Operation),
+    UnsupportedBlock(&'static str),
+    UnsupportedOperation(Operation),
+}
+
+impl fmt::Display for ReferenceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ReferenceError::*;
+        match self {
+            DivideByZero => write!(f, "division by zero"),
+            FailedAssertion(err_code) => write!(f, "assertion failed with error code {err_code}"),
+            InvalidAddress(addr) => write!(f, "memory address {addr} is not a u32 value"),
+            NotBinaryValue(value) => write!(f, "expected a binary value, but was {value}"),
+            NotU32Value(value, err_code) => {
+                write!(f, "expected a u32 value, but was {value} (error code {err_code})")
+            }
+            StepLimitExceeded(max_steps) => {
+                write!(f, "execution did not finish within {max_steps} operations")
+            }
+            UndefinedResult(op) => write!(f, "result of {op} is not defined for its inputs"),
+            UnsupportedBlock(block) => write!(f, "{block} blocks are not supported"),
+            UnsupportedOperation(op) => write!(f, "operation {op} is not supported"),
+        }
+    }
+}
+
+// DIFFERENTIAL TEST
+// ================================================================================================
+
+impl Test {
+    /// Executes the test on the VM and on the [ReferenceInterpreter] inside a proptest, and checks
+    /// that either both executions fail, or both succeed and leave the stack and the memory of the
+    /// root context in the same state.
+    ///
+    /// Test cases for which the reference interpreter reports that the result of the execution is
+    /// not defined are rejected.
+    ///
+    /// # Panics
+    /// Panics if the test source cannot be compiled, or if it contains operations which are not
+    /// supported by the reference interpreter.
+    pub fn prop_expect_reference(&self) -> Result<(), TestCaseError> {
+        let program = self.compile().expect("Failed to compile test source.");
+        let expected = match ReferenceInterpreter::new(&self.stack_inputs).execute(&program) {
+            Err(ReferenceError::UndefinedResult(op)) => {
+                return Err(TestCaseError::reject(format!("undefined result of {op}")));
+            }
+            Err(
+                err @ (ReferenceError::UnsupportedBlock(_)
+                | ReferenceError::UnsupportedOperation(_)),
+            ) => {
+                panic!("the reference interpreter cannot execute the test: {err}")
+            }
+            result => result,
+        };
+
+        let actual = self.execute_process();
+        match (expected, actual) {
+            (Ok(expected), Ok(process)) => {
+                let addresses = expected.memory.keys().copied().collect();
+                let actual = ReferenceState::from_process(&process, &addresses);
+                prop_assert_eq!(expected, actual, "program:\n{}", self.source);
+            }
+            (Ok(_), Err(err)) => {
+                prop_assert!(false, "VM failed with {err}, reference succeeded:\n{}", self.source)
+            }
+            (Err(err), Ok(_)) => {
+                prop_assert!(false, "reference failed with {err}, VM succeeded:\n{}", self.source)
+            }
+            (Err(_), Err(_)) => (),
+        }
+
+        Ok(())
+    }
+}
+
+// PROGRAM STRATEGIES
+// ================================================================================================
+
+/// Returns a strategy which generates a single random instruction supported by the reference
+/// interpreter, or a short sequence of instructions which guards a u32 instruction with a check
+/// of its inputs.
+pub fn instruction() -> BoxedStrategy<String> {
+    let value = prop_oneof![
+        3 => 0..16_u64,
+        3 => any::<u32>().prop_map(u64::from),
+        1 => 0..Felt::MODULUS,
+    ];
+    let push = value.prop_map(|value| format!("push.{value}"));
+
+    let addr = proptest::sample::select(MEM_ADDRESSES.to_vec());
+    let memory = (proptest::sample::select(MEMORY_INSTRUCTIONS), addr)
+        .prop_map(|(instruction, addr)| format!("{instruction}.{addr}"));
+
+    let stack_index = (2..16_u32).prop_flat_map(|n| {
+        proptest::sample::select(INDEXED_INSTRUCTIONS)
+            .prop_map(move |instruction| format!("{instruction}.{n}"))
+    });
+
+    prop_oneof![
+        4 => push,
+        3 => proptest::sample::select(FIELD_INSTRUCTIONS).prop_map(ToString::to_string),
+        3 => proptest::sample::select(STACK_INSTRUCTIONS).prop_map(ToString::to_string),
+        2 => stack_index,
+        2 => proptest::sample::select(U32_INSTRUCTIONS).prop_map(ToString::to_string),
+        2 => memory,
+    ]
+    .boxed()
+}
+
+/// Returns a strategy which generates the body of a random program with up to `max_len`
+/// instructions, which may contain `if.true` blocks nested up to `max_depth` levels deep.
+pub fn program_body(max_len: usize, max_depth: u32) -> BoxedStrategy<String> {
+    let span = proptest::collection::vec(instruction(), 1..=max_len.max(1))
+        .prop_map(|instructions| instructions.join(" "));
+
+    span.prop_recursive(max_depth, (max_len * 4) as u32, 2, move |body| {
+        (body.clone(), body.clone(), body).prop_map(|(before, on_true, on_false)| {
+            format!("{before} if.true {on_true} else {on_false} end")
+        })
+    })
+    .boxed()
+}
+
+/// Returns a strategy which generates the source of a random program wrapped in a `begin ... end`
+/// block.
+pub fn program(max_len: usize, max_depth: u32) -> impl Strategy<Value = String> {
+    program_body(max_len, max_depth).prop_map(|body| format!("begin {body} end"))
+}
+
+/// Field and boolean instructions of random programs.
+const FIELD_INSTRUCTIONS: &[&str] = &[
+    "add",
+    "sub",
+    "mul",
+    "neg",
+    "inv",
+    "add.1",
+    "mul.2",
+    "and",
+    "or",
+    "not",
+    "eq",
+    "neq",
+    "eq.0",
+    "assert",
+    "assertz",
+    "assert_eq",
+];
+
+/// Stack manipulation instructions of random programs, apart from the indexed ones.
+const STACK_INSTRUCTIONS: &[&str] = &[
+    "drop", "dropw", "padw", "dup", "dupw", "dupw.1", "swap", "swapw", "swapw.2", "swapw.3",
+    "swapdw", "movupw.2", "movdnw.2", "cswap", "cswapw", "cdrop",
+];
+
+/// Stack manipulation instructions of random programs which take the position of a stack item.
+const INDEXED_INSTRUCTIONS: &[&str] = &["dup", "swap", "movup", "movdn"];
+
+/// Memory instructions of random programs, which take one of [MEM_ADDRESSES] as the address.
+const MEMORY_INSTRUCTIONS: &[&str] = &["mem_load", "mem_store", "mem_loadw", "mem_storew"];
+
+/// u32 instructions of random programs; instructions which do not check their inputs are guarded
+/// by `u32assert2`, so that their results are defined for all inputs which pass the check.
+const U32_INSTRUCTIONS: &[&str] = &[
+    "u32split",
+    "u32and",
+    "u32xor",
+    "u32assert2",
+    "u32assert2 u32overflowing_add",
+    "u32assert2 u32wrapping_add",
+    "u32assert2 u32overflowing_sub",
+    "u32assert2 u32wrapping_sub",
+    "u32assert2 u32overflowing_mul",
+    "u32assert2 u32wrapping_mul",
+    "u32assert2 u32divmod",
+];
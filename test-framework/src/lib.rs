@@ -46,6 +46,9 @@ pub mod serde {
 
 pub mod crypto;
 
+#[cfg(all(feature = "differential", not(target_family = "wasm")))]
+pub mod differential;
+
 #[cfg(not(target_family = "wasm"))]
 pub mod rand;
 
@@ -143,29 +146,23 @@ impl Test {
         assert_eq!(expected, result, "Expected stack to be {:?}, found {:?}", expected, result);
     }
 
-    /// Executes the test and validates that the process memory has the elements of `expected_mem`
-    /// at address `mem_start_addr` and that the end of the stack execution trace matches the
-    /// `final_stack`.
-    pub fn expect_stack_and_memory(
-        &self,
-        final_stack: &[u64],
-        mut mem_start_addr: u32,
-        expected_mem: &[u64],
-    ) {
-        // compile the program
-        let program = self.compile().expect("Failed to compile test source.");
-        let host = DefaultHost::new(MemAdviceProvider::from(self.advice_inputs.clone()));
+    /// Asserts that executing the test fails on an assertion with the specified error code.
+    pub fn expect_error_code(&self, expected_code: u32) {
+        let expected = format!("assertion to fail with error code {expected_code}");
+        match self.execute() {
+            Err(ExecutionError::FailedAssertion { err_code, .. }) => {
+                assert_eq!(expected_code, err_code, "Expected {expected}, found {err_code}")
+            }
+            Err(err) => panic!("Expected {expected}, found {err}"),
+            Ok(_) => panic!("Expected {expected}, but execution succeeded"),
+        }
+    }
 
-        // execute the test
-        let mut process = Process::new(
-            program.kernel().clone(),
-            self.stack_inputs.clone(),
-            host,
-            ExecutionOptions::default(),
-        );
-        process.execute(&program).unwrap();
+    /// Executes the test and validates that the process memory has the elements of `expected_mem`
+    /// at address `mem_start_addr`, with one word per address.
+    pub fn expect_memory(&self, mut mem_start_addr: u32, expected_mem: &[u64]) {
+        let process = self.execute_process().expect("Failed to execute test.");
 
-        // validate the memory state
         for data in expected_mem.chunks(WORD_SIZE) {
             // Main memory is zeroed by default, use zeros as a fallback when unwrap to make testing easier
             let mem_state =
@@ -179,8 +176,18 @@ impl Test {
             );
             mem_start_addr += 1;
         }
+    }
 
-        // validate the stack state
+    /// Executes the test and validates that the process memory has the elements of `expected_mem`
+    /// at address `mem_start_addr` and that the end of the stack execution trace matches the
+    /// `final_stack`.
+    pub fn expect_stack_and_memory(
+        &self,
+        final_stack: &[u64],
+        mem_start_addr: u32,
+        expected_mem: &[u64],
+    ) {
+        self.expect_memory(mem_start_addr, expected_mem);
         self.expect_stack(final_stack);
     }
 
@@ -219,7 +226,8 @@ impl Test {
     /// resulting execution trace or error.
     pub fn execute(&self) -> Result<ExecutionTrace, ExecutionError> {
         let program = self.compile().expect("Failed to compile test source.");
-        let host = DefaultHost::new(MemAdviceProvider::from(self.advice_inputs.clone()));
+        let host = DefaultHost::new(MemAdviceProvider::from(self.advice_inputs.clone()))
+            .with_error_codes(program.error_codes().clone());
         processor::execute(&program, self.stack_inputs.clone(), host, ExecutionOptions::default())
     }
 
@@ -0,0 +1,93 @@
+use super::{
+    security::ConjecturedSecurity, verify_stark, AcceptableParams, ExecutionProof, ProgramInfo,
+    StackInputs, StackOutputs, VerificationError,
+};
+
+// VERIFIER LIMITS
+// ================================================================================================
+
+/// Limits on proofs accepted by [verify_bounded()].
+///
+/// Memory allocated during verification grows with the size of the proof, the length of the
+/// execution trace, and the number of queries. Proofs exceeding any of these limits are rejected
+/// before they are deserialized or verified: the trace length and the number of queries are read
+/// from the header of the serialized proof.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VerifierLimits {
+    /// Maximum size of a serialized proof in bytes.
+    pub max_proof_size: usize,
+    /// Maximum length of the execution trace of the proven program.
+    pub max_trace_len: usize,
+    /// Maximum number of queries made by the verifier.
+    pub max_num_queries: usize,
+}
+
+impl Default for VerifierLimits {
+    /// Returns limits which accept proofs generated with the standard proving options, including
+    /// the 128-bit proven options which make 80 queries, for execution traces of up to 2^24 rows.
+    fn default() -> Self {
+        Self {
+            max_proof_size: 1 << 20,
+            max_trace_len: 1 << 24,
+            max_num_queries: 80,
+        }
+    }
+}
+
+// BOUNDED VERIFIER
+// ================================================================================================
+
+/// Returns the conjectured security level (in bits) of the serialized proof if the specified
+/// program was executed correctly against the specified inputs and outputs.
+///
+/// This is the same as [verify()](super::verify), but it is intended for constrained environments
+/// (e.g., light clients running inside a WebAssembly runtime):
+/// - The proof is rejected if it exceeds any of the specified `limits`, which bounds the amount of
+///   memory allocated during verification.
+/// - The conjectured security level of the proof is computed using integer arithmetic only. A full
+///   [SecurityReport](super::SecurityReport) is not returned, since its proven security level is
+///   computed using floating point arithmetic.
+///
+/// # Errors
+/// Returns an error if the proof exceeds any of the specified limits or could not be
+/// deserialized, or if it fails [verify()](super::verify).
+pub fn verify_bounded(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: &[u8],
+    limits: &VerifierLimits,
+) -> Result<u32, VerificationError> {
+    check_limit("size", proof.len(), limits.max_proof_size)?;
+    let (trace_info, options) =
+        ExecutionProof::read_header(proof).map_err(VerificationError::ProofDeserializationError)?;
+    check_limit("trace length", trace_info.length(), limits.max_trace_len)?;
+    check_limit("number of queries", options.num_queries(), limits.max_num_queries)?;
+
+    let proof =
+        ExecutionProof::from_bytes(proof).map_err(VerificationError::ProofDeserializationError)?;
+
+    let security_level = ConjecturedSecurity::new(&proof).level;
+    verify_stark(program_info, stack_inputs, stack_outputs, proof, &AcceptableParams::new())?;
+
+    Ok(security_level)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an error if the specified value of the resource exceeds the limit.
+fn check_limit(
+    resource: &'static str,
+    value: usize,
+    limit: usize,
+) -> Result<(), VerificationError> {
+    if value > limit {
+        return Err(VerificationError::LimitExceeded {
+            resource,
+            value,
+            limit,
+        });
+    }
+    Ok(())
+}
@@ -1,4 +1,5 @@
 #![no_std]
+#![deny(clippy::float_arithmetic)]
 
 #[cfg(feature = "std")]
 extern crate std;
@@ -6,7 +7,8 @@ extern crate std;
 #[macro_use]
 extern crate alloc;
 
-use air::{HashFunction, ProcessorAir, ProvingOptions, PublicInputs};
+use air::{ProcessorAir, ProvingOptions, PublicInputs};
+use alloc::vec::Vec;
 use core::fmt;
 use vm_core::crypto::{
     hash::{Blake3_192, Blake3_256, Rpo256},
@@ -14,6 +16,9 @@ use vm_core::crypto::{
 };
 use winter_verifier::verify as verify_proof;
 
+#[cfg(feature = "concurrent")]
+use rayon::prelude::*;
+
 // EXPORTS
 // ================================================================================================
 
@@ -22,11 +27,24 @@ pub use winter_verifier::{AcceptableOptions, VerifierError};
 pub mod math {
     pub use vm_core::{Felt, FieldElement, StarkField};
 }
-pub use air::ExecutionProof;
+pub use air::{
+    DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProofMetadata, VmProfile,
+};
+
+mod bounded;
+pub use bounded::{verify_bounded, VerifierLimits};
+
+mod security;
+pub use security::SecurityReport;
+
+#[cfg(feature = "std")]
+mod registry;
+#[cfg(feature = "std")]
+pub use registry::{ProgramRegistry, RegistryError};
 
 // VERIFIER
 // ================================================================================================
-/// Returns the security level of the proof if the specified program was executed correctly against
+/// Returns a [SecurityReport] of the proof if the specified program was executed correctly against
 /// the specified inputs and outputs.
 ///
 /// Specifically, verifies that if a program with the specified `program_hash` is executed against
@@ -42,17 +60,32 @@ pub use air::ExecutionProof;
 /// `stack_outputs` slice, and the order of the rest of the output elements will also match the
 /// order on the stack. This is the reverse of the order of the `stack_inputs` slice.
 ///
+/// Stack inputs may contain more than 16 values. The values beyond the top 16 stack slots are
+/// bound to the proof via a commitment in the public inputs (see
+/// [StackInputs::overflow_commitment()]), which the verifier recomputes from `stack_inputs`.
+///
+/// If the proof carries [ProofMetadata], the metadata is checked as well: the program hash in the
+/// metadata must match the hash of the specified program, and the proving options in the metadata
+/// must match the parameters of the STARK proof.
+///
+/// The returned report breaks the security level of the proof down into the security provided by
+/// the field, by the queries, and by the hash function, and lists the protocol parameters of the
+/// proof, which allows callers to accept only proofs meeting specific requirements.
+///
 /// The verifier accepts proofs generated using a parameter set defined in [ProvingOptions].
 /// Specifically, parameter sets targeting the following are accepted:
 /// - 96-bit security level, non-recursive context (BLAKE3 hash function).
 /// - 96-bit security level, recursive context (BLAKE3 hash function).
 /// - 128-bit security level, non-recursive context (RPO hash function).
 /// - 128-bit security level, recursive context (RPO hash function).
+/// - 128-bit proven security level, non-recursive context (BLAKE3 hash function).
+/// - 128-bit proven security level, recursive context (RPO hash function).
 ///
 /// # Errors
 /// Returns an error if:
 /// - The provided proof does not prove a correct execution of the program.
-/// - The the protocol parameters used to generate the proof is not in the set of acceptable
+/// - The metadata of the proof does not match the program or the proof.
+/// - The protocol parameters used to generate the proof are not in the set of acceptable
 ///   parameters.
 #[tracing::instrument("verify_program", skip_all)]
 pub fn verify(
@@ -60,33 +93,236 @@ pub fn verify(
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
     proof: ExecutionProof,
-) -> Result<u32, VerificationError> {
+) -> Result<SecurityReport, VerificationError> {
+    let params = AcceptableParams::new();
+    verify_with_params(program_info, stack_inputs, stack_outputs, proof, &params)
+}
+
+/// Returns a [SecurityReport] of the proof if the specified program was executed correctly against
+/// the specified inputs and outputs, and against one of the specified kernels.
+///
+/// This is the same as [verify()], but rather than requiring the proof to be generated against
+/// the kernel of `program_info`, accepts proofs generated against any kernel in the `kernels`
+/// whitelist the program is compatible with (see [ProgramInfo::is_compatible_with()]). This
+/// allows kernels to be upgraded without invalidating programs compiled against their earlier
+/// versions.
+///
+/// Since the proof does not identify the kernel it was generated against, the proof is verified
+/// against each of the compatible kernels in turn until the verification succeeds, starting with
+/// the kernel of `program_info` if it is whitelisted. Thus, the whitelist should be kept short.
+///
+/// # Errors
+/// Returns an error if the program is not compatible with any of the whitelisted kernels, or if
+/// the proof fails [verify()] against each of the compatible kernels; in the latter case, the
+/// error for the last attempted kernel is returned.
+#[tracing::instrument("verify_program", skip_all)]
+pub fn verify_with_kernels(
+    program_info: ProgramInfo,
+    kernels: &[Kernel],
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+) -> Result<SecurityReport, VerificationError> {
+    let params = AcceptableParams::new();
+
+    // try the kernel of the program first, followed by the other compatible kernels
+    let mut candidates = kernels
+        .iter()
+        .filter(|&kernel| program_info.is_compatible_with(kernel))
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|&kernel| kernel != program_info.kernel());
+
+    let mut result = Err(VerificationError::NoCompatibleKernel);
+    for kernel in candidates {
+        let program_info =
+            program_info.for_kernel(kernel.clone()).expect("kernel is not compatible");
+        result = verify_with_params(
+            program_info,
+            stack_inputs.clone(),
+            stack_outputs.clone(),
+            proof.clone(),
+            &params,
+        );
+        if result.is_ok() {
+            break;
+        }
+    }
+    result
+}
+
+/// Verifies a batch of proofs and returns the result of verification of each proof.
+///
+/// Each item of `proofs` is a tuple of arguments which would otherwise be passed to [verify()],
+/// and the results are returned in the same order as the proofs. Every proof in the batch is
+/// verified regardless of whether other proofs fail verification; use [verify_all()] to stop at
+/// the first invalid proof instead.
+///
+/// Sets of acceptable protocol parameters are built once and shared across all proofs in the
+/// batch. When the `concurrent` feature is enabled, proofs are verified in parallel.
+#[tracing::instrument("verify_programs", skip_all)]
+pub fn verify_many<I>(proofs: I) -> Vec<Result<SecurityReport, VerificationError>>
+where
+    I: IntoIterator<Item = (ProgramInfo, StackInputs, StackOutputs, ExecutionProof)>,
+{
+    let params = AcceptableParams::new();
+    let proofs: Vec<_> = proofs.into_iter().collect();
+
+    #[cfg(feature = "concurrent")]
+    let proofs = proofs.into_par_iter();
+    #[cfg(not(feature = "concurrent"))]
+    let proofs = proofs.into_iter();
+
+    proofs
+        .map(|(program_info, stack_inputs, stack_outputs, proof)| {
+            verify_with_params(program_info, stack_inputs, stack_outputs, proof, &params)
+        })
+        .collect()
+}
+
+/// Verifies a batch of proofs and returns security reports of all proofs if all of them are valid.
+///
+/// This is the same as [verify_many()], but verification stops as soon as an invalid proof is
+/// encountered.
+///
+/// # Errors
+/// Returns the position of an invalid proof in the batch together with the reason for which its
+/// verification failed. When the `concurrent` feature is enabled, the returned proof is not
+/// necessarily the first invalid proof in the batch.
+#[tracing::instrument("verify_programs", skip_all)]
+pub fn verify_all<I>(proofs: I) -> Result<Vec<SecurityReport>, (usize, VerificationError)>
+where
+    I: IntoIterator<Item = (ProgramInfo, StackInputs, StackOutputs, ExecutionProof)>,
+{
+    let params = AcceptableParams::new();
+    let proofs: Vec<_> = proofs.into_iter().collect();
+
+    #[cfg(feature = "concurrent")]
+    let proofs = proofs.into_par_iter();
+    #[cfg(not(feature = "concurrent"))]
+    let proofs = proofs.into_iter();
+
+    proofs
+        .enumerate()
+        .map(|(index, (program_info, stack_inputs, stack_outputs, proof))| {
+            verify_with_params(program_info, stack_inputs, stack_outputs, proof, &params)
+                .map_err(|err| (index, err))
+        })
+        .collect()
+}
+
+/// Returns a [SecurityReport] of the proof if the program with the specified hash was executed
+/// correctly against the specified inputs and outputs.
+///
+/// This is the same as [verify()], but the [ProgramInfo] of the program (i.e., its kernel) is
+/// fetched by the program hash from the provided registry of known programs.
+///
+/// # Errors
+/// Returns an error if the program is not in the registry or its registry entry could not be
+/// read, or if the proof fails [verify()].
+#[cfg(feature = "std")]
+pub fn verify_registered(
+    registry: &ProgramRegistry,
+    program_hash: Digest,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+) -> Result<SecurityReport, VerificationError> {
+    let program_info = registry
+        .get(&program_hash)
+        .map_err(VerificationError::RegistryError)?
+        .ok_or(VerificationError::UnknownProgram(program_hash))?;
+    verify(program_info, stack_inputs, stack_outputs, proof)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Sets of protocol parameters accepted by the verifier for each of the supported hash functions.
+struct AcceptableParams {
+    blake3_192: AcceptableOptions,
+    blake3_256: AcceptableOptions,
+    rpo256: AcceptableOptions,
+}
+
+impl AcceptableParams {
+    fn new() -> Self {
+        Self {
+            blake3_192: AcceptableOptions::OptionSet(vec![ProvingOptions::REGULAR_96_BITS]),
+            blake3_256: AcceptableOptions::OptionSet(vec![
+                ProvingOptions::REGULAR_128_BITS,
+                ProvingOptions::REGULAR_128_BITS_PROVEN,
+            ]),
+            rpo256: AcceptableOptions::OptionSet(vec![
+                ProvingOptions::RECURSIVE_96_BITS,
+                ProvingOptions::RECURSIVE_128_BITS,
+                ProvingOptions::RECURSIVE_128_BITS_PROVEN,
+            ]),
+        }
+    }
+}
+
+/// Verifies the proof against the specified inputs and outputs, accepting the proof only if it
+/// was generated using one of the specified parameter sets.
+fn verify_with_params(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+    params: &AcceptableParams,
+) -> Result<SecurityReport, VerificationError> {
     // get security level of the proof
-    let security_level = proof.security_level();
+    let report = SecurityReport::new(&proof);
+    verify_stark(program_info, stack_inputs, stack_outputs, proof, params)?;
+    Ok(report)
+}
+
+/// Checks the metadata of the proof, if any, and verifies the underlying STARK proof against the
+/// specified inputs and outputs.
+fn verify_stark(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+    params: &AcceptableParams,
+) -> Result<(), VerificationError> {
+    // make sure the metadata describes the program and the proof being verified
+    if let Some(metadata) = proof.metadata() {
+        if metadata.program_hash() != program_info.program_hash() {
+            return Err(VerificationError::ProgramHashMismatch {
+                expected: *program_info.program_hash(),
+                actual: *metadata.program_hash(),
+            });
+        }
+        if metadata.hash_fn() != proof.hash_fn()
+            || metadata.options() != proof.stark_proof().options()
+        {
+            return Err(VerificationError::ProofOptionsMismatch);
+        }
+    }
 
     // build public inputs and try to verify the proof
-    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    let mut pub_inputs =
+        PublicInputs::new(program_info, stack_inputs, stack_outputs).with_profile(proof.profile());
+    if let Some(metadata) = proof.metadata() {
+        pub_inputs = pub_inputs.with_metadata(metadata);
+    }
     let (hash_fn, proof) = proof.into_parts();
     match hash_fn {
-        HashFunction::Blake3_192 => {
-            let opts = AcceptableOptions::OptionSet(vec![ProvingOptions::REGULAR_96_BITS]);
-            verify_proof::<ProcessorAir, Blake3_192, WinterRandomCoin<_>>(proof, pub_inputs, &opts)
-        }
-        HashFunction::Blake3_256 => {
-            let opts = AcceptableOptions::OptionSet(vec![ProvingOptions::REGULAR_128_BITS]);
-            verify_proof::<ProcessorAir, Blake3_256, WinterRandomCoin<_>>(proof, pub_inputs, &opts)
-        }
+        HashFunction::Blake3_192 => verify_proof::<ProcessorAir, Blake3_192, WinterRandomCoin<_>>(
+            proof,
+            pub_inputs,
+            &params.blake3_192,
+        ),
+        HashFunction::Blake3_256 => verify_proof::<ProcessorAir, Blake3_256, WinterRandomCoin<_>>(
+            proof,
+            pub_inputs,
+            &params.blake3_256,
+        ),
         HashFunction::Rpo256 => {
-            let opts = AcceptableOptions::OptionSet(vec![
-                ProvingOptions::RECURSIVE_96_BITS,
-                ProvingOptions::RECURSIVE_128_BITS,
-            ]);
-            verify_proof::<ProcessorAir, Rpo256, RpoRandomCoin>(proof, pub_inputs, &opts)
+            verify_proof::<ProcessorAir, Rpo256, RpoRandomCoin>(proof, pub_inputs, &params.rpo256)
         }
     }
-    .map_err(VerificationError::VerifierError)?;
-
-    Ok(security_level)
+    .map_err(VerificationError::VerifierError)
 }
 
 // ERRORS
@@ -98,6 +334,28 @@ pub enum VerificationError {
     VerifierError(VerifierError),
     InputNotFieldElement(u64),
     OutputNotFieldElement(u64),
+    UnknownProgram(Digest),
+    NoCompatibleKernel,
+    ProgramHashMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    ProofOptionsMismatch,
+    ProofDeserializationError(DeserializationError),
+    NoSegments,
+    SegmentCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    SegmentProgramMismatch,
+    SegmentOutputsMismatch,
+    LimitExceeded {
+        resource: &'static str,
+        value: usize,
+        limit: usize,
+    },
+    #[cfg(feature = "std")]
+    RegistryError(RegistryError),
 }
 
 impl fmt::Display for VerificationError {
@@ -107,6 +365,34 @@ impl fmt::Display for VerificationError {
             VerifierError(e) => write!(f, "{e}"),
             InputNotFieldElement(i) => write!(f, "the input {i} is not a valid field element!"),
             OutputNotFieldElement(o) => write!(f, "the output {o} is not a valid field element!"),
+            UnknownProgram(hash) => write!(f, "program {hash} is not in the registry"),
+            NoCompatibleKernel => {
+                write!(f, "the program is not compatible with any of the accepted kernels")
+            }
+            ProgramHashMismatch { expected, actual } => {
+                write!(f, "the proof is for program {actual} rather than for program {expected}")
+            }
+            ProofOptionsMismatch => {
+                write!(f, "proving options in the proof metadata do not match the proof")
+            }
+            ProofDeserializationError(e) => write!(f, "failed to deserialize proof: {e}"),
+            NoSegments => write!(f, "the computation does not consist of any segments"),
+            SegmentCountMismatch { expected, actual } => {
+                write!(f, "expected {expected} segments, but the computation consists of {actual}")
+            }
+            SegmentProgramMismatch => {
+                write!(f, "the program of the segment is not the expected program")
+            }
+            SegmentOutputsMismatch => {
+                write!(f, "the outputs of the last segment do not match the expected outputs")
+            }
+            LimitExceeded {
+                resource,
+                value,
+                limit,
+            } => write!(f, "{resource} of the proof is {value}, but at most {limit} is allowed"),
+            #[cfg(feature = "std")]
+            RegistryError(e) => write!(f, "{e}"),
         }
     }
 }
@@ -0,0 +1,111 @@
+use super::{Digest, ProgramInfo};
+use alloc::string::{String, ToString};
+use core::fmt;
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+use vm_core::utils::{to_hex, Deserializable, Serializable};
+
+// PROGRAM REGISTRY
+// ================================================================================================
+
+/// A local registry of known programs, stored in a directory.
+///
+/// The registry maps program hashes to [ProgramInfo] of the programs. Info of each program is
+/// stored in a separate `<program hash>.info` file (where the hash is hex-encoded), which allows
+/// operators to populate the registry by simply copying files into the directory.
+#[derive(Debug, Clone)]
+pub struct ProgramRegistry {
+    dir: PathBuf,
+}
+
+impl ProgramRegistry {
+    /// Returns a new registry stored in the specified directory.
+    pub fn new<P: AsRef<Path>>(dir: P) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Returns the directory in which the registry is stored.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Adds the provided program info to the registry, replacing an existing entry for the same
+    /// program hash, if any.
+    ///
+    /// # Errors
+    /// Returns an error if the registry directory could not be created or the entry could not be
+    /// written.
+    pub fn register(&self, program_info: &ProgramInfo) -> Result<(), RegistryError> {
+        fs::create_dir_all(&self.dir).map_err(|err| RegistryError::io(&self.dir, err))?;
+        let path = self.entry_path(program_info.program_hash());
+        fs::write(&path, program_info.to_bytes()).map_err(|err| RegistryError::io(&path, err))
+    }
+
+    /// Returns info of the program with the specified hash, or None if the program is not in the
+    /// registry.
+    ///
+    /// # Errors
+    /// Returns an error if the registry entry could not be read, or if it does not contain valid
+    /// info of the program with the specified hash.
+    pub fn get(&self, program_hash: &Digest) -> Result<Option<ProgramInfo>, RegistryError> {
+        let path = self.entry_path(program_hash);
+        let bytes = match fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(RegistryError::io(&path, err)),
+        };
+
+        let program_info = ProgramInfo::read_from_bytes(&bytes)
+            .map_err(|err| RegistryError::InvalidEntry(path.clone(), err.to_string()))?;
+        if program_info.program_hash() != program_hash {
+            return Err(RegistryError::InvalidEntry(
+                path,
+                "entry contains info of a different program".to_string(),
+            ));
+        }
+
+        Ok(Some(program_info))
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    fn entry_path(&self, program_hash: &Digest) -> PathBuf {
+        let hash = to_hex(&program_hash.as_bytes()).expect("failed to encode program hash");
+        self.dir.join(hash).with_extension("info")
+    }
+}
+
+// REGISTRY ERROR
+// ================================================================================================
+
+/// An error which can be returned by a [ProgramRegistry].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryError {
+    Io(PathBuf, String),
+    InvalidEntry(PathBuf, String),
+}
+
+impl RegistryError {
+    fn io(path: &Path, err: io::Error) -> Self {
+        Self::Io(path.to_path_buf(), err.to_string())
+    }
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use RegistryError::*;
+        match self {
+            Io(path, err) => write!(f, "failed to access registry entry {}: {err}", path.display()),
+            InvalidEntry(path, err) => {
+                write!(f, "invalid registry entry {}: {err}", path.display())
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
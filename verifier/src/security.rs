@@ -0,0 +1,150 @@
+use super::{
+    math::{Felt, StarkField},
+    ExecutionProof, FieldExtension, HashFunction,
+};
+use vm_core::crypto::hash::{Blake3_192, Blake3_256, Rpo256};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Minimum query security (in bits) above which the grinding factor contributes to the security
+/// level of a proof.
+const GRINDING_CONTRIBUTION_FLOOR: u32 = 80;
+
+// SECURITY REPORT
+// ================================================================================================
+
+/// Security level of a verified proof together with the protocol parameters it is derived from.
+///
+/// The conjectured security level of a proof is the minimum of the following components:
+/// - Field security, i.e., the security provided by the size of the (extension) field from which
+///   random values are drawn, reduced by the size of the LDE domain.
+/// - Query security, i.e., the security contributed by the FRI queries and by proof-of-work
+///   grinding.
+/// - Hash security, i.e., the collision resistance of the hash function used to build the proof
+///   commitments.
+///
+/// Policy engines can use the individual components to accept only proofs meeting specific
+/// requirements (e.g., a minimum field security), rather than relying on the overall security
+/// level alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SecurityReport {
+    /// Hash function used to generate the proof.
+    pub hash_fn: HashFunction,
+    /// Extension of the base field used to draw random values.
+    pub field_extension: FieldExtension,
+    /// Length of the execution trace of the proven program.
+    pub trace_len: usize,
+    /// Blowup factor of the LDE domain.
+    pub blowup_factor: usize,
+    /// Number of queries made by the verifier.
+    pub num_queries: usize,
+    /// Number of bits of proof-of-work grinding performed by the prover.
+    pub grinding_factor: u32,
+    /// Factor by which the degree of the polynomial is reduced in each FRI layer.
+    pub fri_folding_factor: usize,
+    /// Maximum degree of the remainder polynomial of FRI.
+    pub fri_remainder_max_degree: usize,
+    /// Security (in bits) provided by the size of the field.
+    pub field_security: u32,
+    /// Security (in bits) contributed by the queries and by proof-of-work grinding.
+    pub query_security: u32,
+    /// Collision resistance (in bits) of the hash function.
+    pub hash_security: u32,
+    /// Conjectured security level (in bits) of the proof.
+    pub conjectured_security: u32,
+    /// Proven security level (in bits) of the proof.
+    pub proven_security: u32,
+}
+
+impl SecurityReport {
+    /// Returns a [SecurityReport] describing the specified proof.
+    ///
+    /// All components of the conjectured security level are computed using integer arithmetic,
+    /// while the proven security level is computed using floating point arithmetic.
+    pub fn new(proof: &ExecutionProof) -> Self {
+        let stark_proof = proof.stark_proof();
+        let options = stark_proof.options();
+        let ConjecturedSecurity {
+            field_security,
+            query_security,
+            hash_security,
+            level: conjectured_security,
+        } = ConjecturedSecurity::new(proof);
+        let proven_security = match proof.hash_fn() {
+            HashFunction::Blake3_192 => stark_proof.security_level::<Blake3_192>(false),
+            HashFunction::Blake3_256 => stark_proof.security_level::<Blake3_256>(false),
+            HashFunction::Rpo256 => stark_proof.security_level::<Rpo256>(false),
+        };
+
+        Self {
+            hash_fn: proof.hash_fn(),
+            field_extension: options.field_extension(),
+            trace_len: stark_proof.get_trace_info().length(),
+            blowup_factor: options.blowup_factor(),
+            num_queries: options.num_queries(),
+            grinding_factor: options.grinding_factor(),
+            fri_folding_factor: options.to_fri_options().folding_factor(),
+            fri_remainder_max_degree: options.to_fri_options().remainder_max_degree(),
+            field_security,
+            query_security,
+            hash_security,
+            conjectured_security,
+            proven_security,
+        }
+    }
+
+    /// Returns the conjectured security level (in bits) of the proof.
+    ///
+    /// This is the security level reported by `ExecutionProof::security_level()`.
+    pub fn security_level(&self) -> u32 {
+        self.conjectured_security
+    }
+}
+
+// CONJECTURED SECURITY
+// ================================================================================================
+
+/// Components of the conjectured security level of a proof (see [SecurityReport]).
+pub(crate) struct ConjecturedSecurity {
+    /// Security (in bits) provided by the size of the field.
+    pub field_security: u32,
+    /// Security (in bits) contributed by the queries and by proof-of-work grinding.
+    pub query_security: u32,
+    /// Collision resistance (in bits) of the hash function.
+    pub hash_security: u32,
+    /// Conjectured security level (in bits) of the proof.
+    pub level: u32,
+}
+
+impl ConjecturedSecurity {
+    /// Returns the components of the conjectured security level of the specified proof.
+    ///
+    /// Since the blowup factor is always a power of two, all components are computed without
+    /// resorting to floating point arithmetic.
+    pub fn new(proof: &ExecutionProof) -> Self {
+        let stark_proof = proof.stark_proof();
+        let options = stark_proof.options();
+        let trace_len = stark_proof.get_trace_info().length();
+        let lde_domain_size = trace_len * options.blowup_factor();
+
+        // the security is capped by the size of the field from which random values are drawn
+        let field_size = Felt::MODULUS_BITS * options.field_extension().degree();
+        let field_security = field_size - lde_domain_size.trailing_zeros();
+
+        let mut query_security = options.blowup_factor().ilog2() * options.num_queries() as u32;
+        if query_security >= GRINDING_CONTRIBUTION_FLOOR {
+            query_security += options.grinding_factor();
+        }
+
+        let hash_security = proof.hash_fn().collision_resistance();
+        let level = (field_security.min(query_security) - 1).min(hash_security);
+
+        Self {
+            field_security,
+            query_security,
+            hash_security,
+            level,
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use alloc::string::{String, ToString};
+use assembly::{AssemblyError, ParsingError};
+use core::fmt;
+
+// WASM ERROR
+// ================================================================================================
+
+/// An error which can be generated while translating a WebAssembly module into Miden assembly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WasmError {
+    AssemblyError(AssemblyError),
+    InvalidBinary(String),
+    InvalidExportName(String),
+    ParsingError(ParsingError),
+    RecursiveCall(u32),
+    UnknownEntrypoint(String),
+    Unsupported(String),
+}
+
+impl WasmError {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    pub fn invalid_binary(reason: &str) -> Self {
+        Self::InvalidBinary(reason.to_string())
+    }
+
+    pub fn unsupported(feature: &str) -> Self {
+        Self::Unsupported(feature.to_string())
+    }
+}
+
+impl From<AssemblyError> for WasmError {
+    fn from(err: AssemblyError) -> Self {
+        Self::AssemblyError(err)
+    }
+}
+
+impl From<ParsingError> for WasmError {
+    fn from(err: ParsingError) -> Self {
+        Self::ParsingError(err)
+    }
+}
+
+impl fmt::Display for WasmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use WasmError::*;
+        match self {
+            AssemblyError(err) => write!(f, "{err}"),
+            InvalidBinary(reason) => write!(f, "invalid WebAssembly binary: {reason}"),
+            InvalidExportName(name) => {
+                write!(f, "export name '{name}' is not a valid procedure name")
+            }
+            ParsingError(err) => write!(f, "{err}"),
+            RecursiveCall(func_idx) => {
+                write!(f, "function {func_idx} is recursive; recursion is not supported")
+            }
+            UnknownEntrypoint(name) => write!(f, "no function is exported under the name '{name}'"),
+            Unsupported(feature) => write!(f, "unsupported WebAssembly feature: {feature}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for WasmError {}
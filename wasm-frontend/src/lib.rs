@@ -0,0 +1,236 @@
+#![no_std]
+
+#[macro_use]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use assembly::{
+    ast::{Instruction, ModuleAst, Node, ProcedureAst, ProgramAst},
+    Assembler, ProcedureName,
+};
+use vm_core::Program;
+
+mod errors;
+pub use errors::WasmError;
+
+mod parser;
+use parser::WasmModule;
+
+mod translator;
+use translator::{callees, translate_function};
+
+#[cfg(test)]
+mod tests;
+
+// TRANSLATION
+// ================================================================================================
+
+/// Procedures translated from a WebAssembly module.
+///
+/// Each function defined in the WebAssembly module is translated into a single Miden assembly
+/// procedure. Procedures are ordered so that every procedure is defined before the procedures
+/// which invoke it, and exported functions are named after their exports. The translation can be
+/// converted into a library module via [Translation::into_module_ast()] or into an executable
+/// program via [Translation::into_program_ast()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Translation {
+    procs: Vec<ProcedureAst>,
+    exports: BTreeMap<String, u16>,
+}
+
+impl Translation {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the translated procedures.
+    pub fn procs(&self) -> &[ProcedureAst] {
+        &self.procs
+    }
+
+    /// Returns the index of the procedure translated from the function exported under the
+    /// specified name, if any.
+    pub fn get_export(&self, name: &str) -> Option<u16> {
+        self.exports.get(name).copied()
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Converts this translation into a library module in which procedures translated from
+    /// exported functions are exported.
+    ///
+    /// # Errors
+    /// Returns an error if the module could not be built (e.g., because it contains too many
+    /// procedures).
+    pub fn into_module_ast(self) -> Result<ModuleAst, WasmError> {
+        let mut procs = self.procs;
+        for proc_idx in self.exports.values() {
+            procs[*proc_idx as usize].is_export = true;
+        }
+        Ok(ModuleAst::new(procs, Vec::new(), None)?)
+    }
+
+    /// Converts this translation into an executable program which invokes the function exported
+    /// under the specified name.
+    ///
+    /// Parameters of the entrypoint function are taken from the top of the stack (with the last
+    /// parameter at the top), and its results are left on the stack.
+    ///
+    /// # Errors
+    /// Returns an error if the WebAssembly module does not export a function with the specified
+    /// name, or if the program could not be built.
+    pub fn into_program_ast(self, entrypoint: &str) -> Result<ProgramAst, WasmError> {
+        let proc_idx = self
+            .get_export(entrypoint)
+            .ok_or_else(|| WasmError::UnknownEntrypoint(entrypoint.to_string()))?;
+        let body = vec![Node::Instruction(Instruction::ExecLocal(proc_idx))];
+        Ok(ProgramAst::new(body, self.procs)?)
+    }
+}
+
+// TRANSLATOR
+// ================================================================================================
+
+/// Translates the provided WebAssembly binary into Miden assembly procedures.
+///
+/// Only a subset of WebAssembly is supported:
+/// - Functions with `i32` parameters, results, and locals. Imports, globals, tables, and data
+///   segments are not supported.
+/// - Unsigned `i32` integer operations. Signed operations and other value types are not supported.
+/// - Structured control flow via `block`, `if`/`else`, and `loop`, where the only supported
+///   branch is `br_if 0` at the end of a loop, and `return` is allowed only at the end of a
+///   function. Recursive calls are not supported.
+/// - Aligned `i32.load` and `i32.store` instructions. Each 4-byte word of the linear memory is
+///   mapped onto a single element of the VM memory at the address `byte_address / 4`.
+///
+/// # Errors
+/// Returns an error if the binary is malformed or uses features outside of the supported subset.
+pub fn translate(wasm: &[u8]) -> Result<Translation, WasmError> {
+    let module = parser::parse_module(wasm)?;
+    if module.functions.len() > u16::MAX as usize {
+        return Err(WasmError::unsupported("more than 65535 functions"));
+    }
+
+    // order functions so that callees come before their callers
+    let order = call_order(&module)?;
+    let mut proc_indexes = vec![0_u16; module.functions.len()];
+    for (proc_idx, func_idx) in order.iter().enumerate() {
+        proc_indexes[*func_idx] = proc_idx as u16;
+    }
+
+    // name procedures after their exports; the first export of a function determines its name
+    let mut names = BTreeMap::new();
+    let mut exports = BTreeMap::new();
+    for (export_name, func_idx) in module.exports.iter() {
+        let proc_idx = *proc_indexes.get(*func_idx as usize).ok_or_else(|| {
+            WasmError::invalid_binary(&format!("exported function {func_idx} out of bounds"))
+        })?;
+        let proc_name = ProcedureName::try_from(export_name.as_str())
+            .map_err(|_| WasmError::InvalidExportName(export_name.clone()))?;
+        names.entry(*func_idx).or_insert(proc_name);
+        exports.insert(export_name.clone(), proc_idx);
+    }
+
+    let mut procs = Vec::with_capacity(order.len());
+    for func_idx in order {
+        let func = &module.functions[func_idx];
+        let name = match names.remove(&(func_idx as u32)) {
+            Some(name) => name,
+            None => ProcedureName::try_from(format!("wasm_fn_{func_idx}"))
+                .expect("invalid procedure name"),
+        };
+        let num_locals = func
+            .num_params
+            .checked_add(func.num_locals)
+            .and_then(|num_locals| u16::try_from(num_locals).ok())
+            .ok_or_else(|| WasmError::unsupported("more than 65535 locals"))?;
+        let body = translate_function(func, &proc_indexes)?;
+        procs.push(ProcedureAst::new(name, num_locals, body, false, None));
+    }
+
+    Ok(Translation { procs, exports })
+}
+
+/// Translates the provided WebAssembly binary and compiles it into a [Program] which invokes the
+/// function exported under the specified name.
+///
+/// # Errors
+/// Returns an error if the translation or the compilation of the program fails.
+pub fn compile_program(
+    assembler: &Assembler,
+    wasm: &[u8],
+    entrypoint: &str,
+) -> Result<Program, WasmError> {
+    let program = translate(wasm)?.into_program_ast(entrypoint)?;
+    Ok(assembler.compile_ast(&program)?)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns indexes of the module functions ordered so that each function comes after all the
+/// functions it calls.
+///
+/// # Errors
+/// Returns an error if the call graph contains a cycle (i.e., a function is recursive).
+fn call_order(module: &WasmModule) -> Result<Vec<usize>, WasmError> {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Mark {
+        None,
+        InProgress,
+        Done,
+    }
+
+    let mut marks = vec![Mark::None; module.functions.len()];
+    let mut order = Vec::with_capacity(module.functions.len());
+
+    // depth-first search with an explicit stack, so that long chains of calls cannot overflow the
+    // native stack; each entry holds a function and the callees which are yet to be visited, in
+    // reverse order
+    let pending_callees = |func_idx: usize| {
+        let mut callees = callees(&module.functions[func_idx]);
+        callees.reverse();
+        callees
+    };
+    let mut stack: Vec<(usize, Vec<u32>)> = Vec::new();
+    for root in 0..module.functions.len() {
+        if marks[root] != Mark::None {
+            continue;
+        }
+        marks[root] = Mark::InProgress;
+        stack.push((root, pending_callees(root)));
+
+        while let Some((func_idx, pending)) = stack.last_mut() {
+            let func_idx = *func_idx;
+            let Some(callee) = pending.pop() else {
+                marks[func_idx] = Mark::Done;
+                order.push(func_idx);
+                stack.pop();
+                continue;
+            };
+
+            let callee = callee as usize;
+            match marks.get(callee) {
+                Some(Mark::Done) => (),
+                Some(Mark::InProgress) => return Err(WasmError::RecursiveCall(callee as u32)),
+                Some(Mark::None) => {
+                    marks[callee] = Mark::InProgress;
+                    stack.push((callee, pending_callees(callee)));
+                }
+                None => {
+                    return Err(WasmError::invalid_binary(&format!(
+                        "function index {callee} out of bounds"
+                    )))
+                }
+            }
+        }
+    }
+    Ok(order)
+}
@@ -0,0 +1,414 @@
+use super::WasmError;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+const WASM_MAGIC: &[u8; 4] = b"\0asm";
+const WASM_VERSION: u32 = 1;
+
+const SECTION_CUSTOM: u8 = 0;
+const SECTION_TYPE: u8 = 1;
+const SECTION_IMPORT: u8 = 2;
+const SECTION_FUNCTION: u8 = 3;
+const SECTION_MEMORY: u8 = 5;
+const SECTION_EXPORT: u8 = 7;
+const SECTION_CODE: u8 = 10;
+
+const FUNC_TYPE_FORM: u8 = 0x60;
+const EXPORT_KIND_FUNC: u8 = 0x00;
+const BLOCK_TYPE_EMPTY: u8 = 0x40;
+const VAL_TYPE_I32: u8 = 0x7f;
+
+/// Maximum nesting depth of control flow blocks in a function body.
+///
+/// Function bodies are parsed and translated recursively, and thus, the depth is bounded to make
+/// sure that malformed inputs cannot exhaust the stack.
+pub const MAX_NESTING_DEPTH: usize = 128;
+
+/// Maximum number of locals declared by a function body.
+///
+/// Locals are mapped onto the locals of a Miden procedure, which can have at most 65535 of them.
+const MAX_LOCALS: u32 = u16::MAX as u32;
+
+// WASM MODULE
+// ================================================================================================
+
+/// A WebAssembly module parsed from its binary representation.
+///
+/// Only the parts of the module relevant for translation are retained: function signatures,
+/// function bodies, and names of exported functions.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct WasmModule {
+    pub functions: Vec<Function>,
+    pub exports: Vec<(String, u32)>,
+}
+
+/// A function defined in a WebAssembly module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Function {
+    pub num_params: u32,
+    pub num_results: u32,
+    pub num_locals: u32,
+    pub body: Vec<Op>,
+}
+
+/// A structured WebAssembly instruction.
+///
+/// Control flow instructions contain their nested bodies, so that the translator does not need
+/// to track `end` and `else` markers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Op {
+    Unreachable,
+    Nop,
+    Block(Vec<Op>),
+    Loop(Vec<Op>),
+    If(Vec<Op>, Vec<Op>),
+    Br(u32),
+    BrIf(u32),
+    Return,
+    Call(u32),
+    Drop,
+    Select,
+    LocalGet(u32),
+    LocalSet(u32),
+    LocalTee(u32),
+    I32Load(u32),
+    I32Store(u32),
+    I32Const(i32),
+    I32Eqz,
+    I32Eq,
+    I32Ne,
+    I32LtU,
+    I32GtU,
+    I32LeU,
+    I32GeU,
+    I32Clz,
+    I32Ctz,
+    I32Popcnt,
+    I32Add,
+    I32Sub,
+    I32Mul,
+    I32DivU,
+    I32RemU,
+    I32And,
+    I32Or,
+    I32Xor,
+    I32Shl,
+    I32ShrU,
+    I32Rotl,
+    I32Rotr,
+}
+
+// PARSER
+// ================================================================================================
+
+/// Parses the provided WebAssembly binary into a [WasmModule].
+///
+/// # Errors
+/// Returns an error if the binary is malformed, or if it uses features outside of the supported
+/// subset of WebAssembly.
+pub fn parse_module(bytes: &[u8]) -> Result<WasmModule, WasmError> {
+    let mut reader = Reader::new(bytes);
+    if reader.read_bytes(4)? != WASM_MAGIC {
+        return Err(WasmError::invalid_binary("invalid magic number"));
+    }
+    let version = u32::from_le_bytes(reader.read_bytes(4)?.try_into().expect("invalid slice"));
+    if version != WASM_VERSION {
+        return Err(WasmError::invalid_binary(&format!("unsupported version {version}")));
+    }
+
+    let mut types = Vec::new();
+    let mut func_types = Vec::new();
+    let mut module = WasmModule::default();
+
+    while !reader.is_empty() {
+        let section_id = reader.read_u8()?;
+        let section_len = reader.read_u32()? as usize;
+        let mut section = Reader::new(reader.read_bytes(section_len)?);
+
+        match section_id {
+            SECTION_CUSTOM | SECTION_MEMORY => continue,
+            SECTION_TYPE => {
+                for _ in 0..section.read_u32()? {
+                    types.push(section.read_func_type()?);
+                }
+            }
+            SECTION_IMPORT => {
+                if section.read_u32()? != 0 {
+                    return Err(WasmError::unsupported("imports"));
+                }
+            }
+            SECTION_FUNCTION => {
+                for _ in 0..section.read_u32()? {
+                    let type_idx = section.read_u32()? as usize;
+                    let func_type = types.get(type_idx).ok_or_else(|| {
+                        WasmError::invalid_binary(&format!("type index {type_idx} out of bounds"))
+                    })?;
+                    func_types.push(*func_type);
+                }
+            }
+            SECTION_EXPORT => {
+                for _ in 0..section.read_u32()? {
+                    let name = section.read_name()?;
+                    let kind = section.read_u8()?;
+                    let index = section.read_u32()?;
+                    if kind == EXPORT_KIND_FUNC {
+                        module.exports.push((name, index));
+                    }
+                }
+            }
+            SECTION_CODE => {
+                let num_bodies = section.read_u32()? as usize;
+                if num_bodies != func_types.len() {
+                    return Err(WasmError::invalid_binary("function and code section mismatch"));
+                }
+                for (num_params, num_results) in func_types.iter().copied() {
+                    let body_len = section.read_u32()? as usize;
+                    let mut body = Reader::new(section.read_bytes(body_len)?);
+                    let num_locals = body.read_locals()?;
+                    let (ops, terminator) = body.read_ops(0)?;
+                    if terminator != Terminator::End || !body.is_empty() {
+                        return Err(WasmError::invalid_binary("malformed function body"));
+                    }
+                    module.functions.push(Function {
+                        num_params,
+                        num_results,
+                        num_locals,
+                        body: ops,
+                    });
+                }
+            }
+            _ => return Err(WasmError::unsupported(&format!("section with id {section_id}"))),
+        }
+
+        if !section.is_empty() {
+            return Err(WasmError::invalid_binary(&format!("malformed section {section_id}")));
+        }
+    }
+
+    if module.functions.len() != func_types.len() {
+        return Err(WasmError::invalid_binary("missing code section"));
+    }
+
+    Ok(module)
+}
+
+// BYTE READER
+// ================================================================================================
+
+/// The marker which ended a sequence of instructions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Terminator {
+    End,
+    Else,
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos == self.bytes.len()
+    }
+
+    fn read_u8(&mut self) -> Result<u8, WasmError> {
+        let byte = *self
+            .bytes
+            .get(self.pos)
+            .ok_or_else(|| WasmError::invalid_binary("unexpected end of input"))?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], WasmError> {
+        let end = self.pos.checked_add(len).filter(|end| *end <= self.bytes.len());
+        let end = end.ok_or_else(|| WasmError::invalid_binary("unexpected end of input"))?;
+        let bytes = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    /// Reads an unsigned LEB128-encoded 32-bit integer.
+    fn read_u32(&mut self) -> Result<u32, WasmError> {
+        let mut result = 0_u64;
+        for shift in (0..35).step_by(7) {
+            let byte = self.read_u8()?;
+            result |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return u32::try_from(result)
+                    .map_err(|_| WasmError::invalid_binary("integer too large"));
+            }
+        }
+        Err(WasmError::invalid_binary("integer representation too long"))
+    }
+
+    /// Reads a signed LEB128-encoded 32-bit integer.
+    fn read_i32(&mut self) -> Result<i32, WasmError> {
+        let mut result = 0_i64;
+        for shift in (0..35).step_by(7) {
+            let byte = self.read_u8()?;
+            result |= ((byte & 0x7f) as i64) << shift;
+            if byte & 0x80 == 0 {
+                // sign-extend the value if the sign bit of the last byte is set
+                if byte & 0x40 != 0 {
+                    result |= -1_i64 << (shift + 7);
+                }
+                return i32::try_from(result)
+                    .map_err(|_| WasmError::invalid_binary("integer too large"));
+            }
+        }
+        Err(WasmError::invalid_binary("integer representation too long"))
+    }
+
+    fn read_name(&mut self) -> Result<String, WasmError> {
+        let len = self.read_u32()? as usize;
+        let bytes = self.read_bytes(len)?;
+        core::str::from_utf8(bytes)
+            .map(|name| name.to_string())
+            .map_err(|_| WasmError::invalid_binary("name is not valid UTF-8"))
+    }
+
+    fn read_val_type(&mut self) -> Result<(), WasmError> {
+        match self.read_u8()? {
+            VAL_TYPE_I32 => Ok(()),
+            val_type => Err(WasmError::unsupported(&format!("value type 0x{val_type:02x}"))),
+        }
+    }
+
+    /// Reads a function type and returns the number of its parameters and results.
+    fn read_func_type(&mut self) -> Result<(u32, u32), WasmError> {
+        if self.read_u8()? != FUNC_TYPE_FORM {
+            return Err(WasmError::invalid_binary("invalid function type"));
+        }
+        let num_params = self.read_u32()?;
+        (0..num_params).try_for_each(|_| self.read_val_type())?;
+        let num_results = self.read_u32()?;
+        (0..num_results).try_for_each(|_| self.read_val_type())?;
+        Ok((num_params, num_results))
+    }
+
+    /// Reads local variable declarations of a function body and returns the number of locals.
+    fn read_locals(&mut self) -> Result<u32, WasmError> {
+        let mut num_locals = 0_u32;
+        for _ in 0..self.read_u32()? {
+            let count = self.read_u32()?;
+            self.read_val_type()?;
+            num_locals = num_locals
+                .checked_add(count)
+                .filter(|&num_locals| num_locals <= MAX_LOCALS)
+                .ok_or_else(|| WasmError::unsupported("more than 65535 locals"))?;
+        }
+        Ok(num_locals)
+    }
+
+    fn read_block_type(&mut self) -> Result<(), WasmError> {
+        match self.read_u8()? {
+            BLOCK_TYPE_EMPTY | VAL_TYPE_I32 => Ok(()),
+            _ => Err(WasmError::unsupported("multi-value blocks")),
+        }
+    }
+
+    /// Reads a memory argument of a load or store instruction and returns its offset.
+    fn read_mem_arg(&mut self) -> Result<u32, WasmError> {
+        let _align = self.read_u32()?;
+        self.read_u32()
+    }
+
+    /// Reads instructions nested in the specified number of blocks until the `end` or `else` marker
+    /// is encountered.
+    fn read_ops(&mut self, depth: usize) -> Result<(Vec<Op>, Terminator), WasmError> {
+        if depth > MAX_NESTING_DEPTH {
+            return Err(nesting_too_deep());
+        }
+
+        let mut ops = Vec::new();
+        loop {
+            let op = match self.read_u8()? {
+                0x0b => return Ok((ops, Terminator::End)),
+                0x05 => return Ok((ops, Terminator::Else)),
+                0x00 => Op::Unreachable,
+                0x01 => Op::Nop,
+                0x02 => {
+                    self.read_block_type()?;
+                    Op::Block(self.read_block_body(depth + 1)?)
+                }
+                0x03 => {
+                    self.read_block_type()?;
+                    Op::Loop(self.read_block_body(depth + 1)?)
+                }
+                0x04 => {
+                    self.read_block_type()?;
+                    let (true_case, terminator) = self.read_ops(depth + 1)?;
+                    let false_case = match terminator {
+                        Terminator::Else => self.read_block_body(depth + 1)?,
+                        Terminator::End => Vec::new(),
+                    };
+                    Op::If(true_case, false_case)
+                }
+                0x0c => Op::Br(self.read_u32()?),
+                0x0d => Op::BrIf(self.read_u32()?),
+                0x0f => Op::Return,
+                0x10 => Op::Call(self.read_u32()?),
+                0x1a => Op::Drop,
+                0x1b => Op::Select,
+                0x20 => Op::LocalGet(self.read_u32()?),
+                0x21 => Op::LocalSet(self.read_u32()?),
+                0x22 => Op::LocalTee(self.read_u32()?),
+                0x28 => Op::I32Load(self.read_mem_arg()?),
+                0x36 => Op::I32Store(self.read_mem_arg()?),
+                0x41 => Op::I32Const(self.read_i32()?),
+                0x45 => Op::I32Eqz,
+                0x46 => Op::I32Eq,
+                0x47 => Op::I32Ne,
+                0x49 => Op::I32LtU,
+                0x4b => Op::I32GtU,
+                0x4d => Op::I32LeU,
+                0x4f => Op::I32GeU,
+                0x67 => Op::I32Clz,
+                0x68 => Op::I32Ctz,
+                0x69 => Op::I32Popcnt,
+                0x6a => Op::I32Add,
+                0x6b => Op::I32Sub,
+                0x6c => Op::I32Mul,
+                0x6e => Op::I32DivU,
+                0x70 => Op::I32RemU,
+                0x71 => Op::I32And,
+                0x72 => Op::I32Or,
+                0x73 => Op::I32Xor,
+                0x74 => Op::I32Shl,
+                0x76 => Op::I32ShrU,
+                0x77 => Op::I32Rotl,
+                0x78 => Op::I32Rotr,
+                opcode => return Err(WasmError::unsupported(&format!("opcode 0x{opcode:02x}"))),
+            };
+            ops.push(op);
+        }
+    }
+
+    /// Reads the body of a block nested at the specified depth which must be terminated by the
+    /// `end` marker.
+    fn read_block_body(&mut self, depth: usize) -> Result<Vec<Op>, WasmError> {
+        match self.read_ops(depth)? {
+            (ops, Terminator::End) => Ok(ops),
+            (_, Terminator::Else) => Err(WasmError::invalid_binary("unexpected else")),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the error for control flow blocks nested deeper than [MAX_NESTING_DEPTH] levels.
+pub fn nesting_too_deep() -> WasmError {
+    WasmError::unsupported(&format!("blocks nested deeper than {MAX_NESTING_DEPTH} levels"))
+}
@@ -0,0 +1,241 @@
+use super::{compile_program, translate, Assembler, WasmError};
+use alloc::vec::Vec;
+
+// TRANSLATION TESTS
+// ================================================================================================
+
+#[test]
+fn translate_function_with_params() {
+    // (func (export "add") (param i32 i32) (result i32)
+    //   local.get 0 local.get 1 i32.add)
+    let wasm = build_wasm(&[(2, 1)], &[(0, 0, &[0x20, 0, 0x20, 1, 0x6a])], &[("add", 0)]);
+
+    let expected = "
+        proc.add.2
+            loc_store.1 loc_store.0
+            loc_load.0 loc_load.1 u32wrapping_add
+        end
+        begin
+            exec.add
+        end";
+    assert_translation(&wasm, "add", expected);
+}
+
+#[test]
+fn translate_loop_call_and_memory() {
+    // (func (export "main") (result i32)
+    //   i32.const 8 i32.const 5 call 1 i32.store offset=4
+    //   i32.const 12 i32.load)
+    #[rustfmt::skip]
+    let main: &[u8] = &[
+        0x41, 8, 0x41, 5, 0x10, 1, 0x36, 2, 4,
+        0x41, 12, 0x28, 2, 0,
+    ];
+    // (func (param i32) (result i32) (local i32)
+    //   loop
+    //     local.get 1 local.get 0 i32.add local.set 1
+    //     local.get 0 i32.const 1 i32.sub local.tee 0 br_if 0
+    //   end
+    //   local.get 1)
+    #[rustfmt::skip]
+    let sum: &[u8] = &[
+        0x03, 0x40,
+        0x20, 1, 0x20, 0, 0x6a, 0x21, 1,
+        0x20, 0, 0x41, 1, 0x6b, 0x22, 0, 0x0d, 0,
+        0x0b,
+        0x20, 1,
+    ];
+    let wasm = build_wasm(&[(0, 1), (1, 1)], &[(0, 0, main), (1, 1, sum)], &[("main", 0)]);
+
+    // the called function must be defined before the caller
+    let expected = "
+        proc.sum.2
+            loc_store.0 push.0 loc_store.1
+            push.1
+            while.true
+                loc_load.1 loc_load.0 u32wrapping_add loc_store.1
+                loc_load.0 push.1 u32wrapping_sub dup loc_store.0 neq.0
+            end
+            loc_load.1
+        end
+        proc.main
+            push.8 push.5 exec.sum
+            swap u32overflowing_add.4 assertz u32divmod.4 assertz mem_store
+            push.12 u32divmod.4 assertz mem_load
+        end
+        begin
+            exec.main
+        end";
+    assert_translation(&wasm, "main", expected);
+
+    // only the exported function is exported from the module
+    let module = translate(&wasm).unwrap().into_module_ast().unwrap();
+    let exports = module.procs().iter().map(|proc| proc.is_export).collect::<Vec<_>>();
+    assert_eq!(exports, [false, true]);
+}
+
+#[test]
+fn translate_if_else_and_select() {
+    // (func (export "pick") (param i32) (result i32)
+    //   local.get 0 if (result i32) i32.const 1 else i32.const 2 end
+    //   i32.const 3 local.get 0 select)
+    #[rustfmt::skip]
+    let pick: &[u8] = &[
+        0x20, 0, 0x04, 0x7f, 0x41, 1, 0x05, 0x41, 2, 0x0b,
+        0x41, 3, 0x20, 0, 0x1b,
+    ];
+    let wasm = build_wasm(&[(1, 1)], &[(0, 0, pick)], &[("pick", 0)]);
+
+    let expected = "
+        proc.pick.1
+            loc_store.0
+            loc_load.0 neq.0
+            if.true push.1 else push.2 end
+            push.3 loc_load.0 eq.0 cdrop
+        end
+        begin
+            exec.pick
+        end";
+    assert_translation(&wasm, "pick", expected);
+}
+
+#[test]
+fn translation_errors() {
+    // malformed binary
+    assert!(matches!(translate(b"\0wasm\x01\0\0\0"), Err(WasmError::InvalidBinary(_))));
+
+    // recursive function: (func call 0)
+    let wasm = build_wasm(&[(0, 0)], &[(0, 0, &[0x10, 0])], &[("f", 0)]);
+    assert_eq!(translate(&wasm), Err(WasmError::RecursiveCall(0)));
+
+    // branch out of a block: (func block br 0 end)
+    let wasm = build_wasm(&[(0, 0)], &[(0, 0, &[0x02, 0x40, 0x0c, 0, 0x0b])], &[("f", 0)]);
+    assert!(matches!(translate(&wasm), Err(WasmError::Unsupported(_))));
+
+    // signed integer operation: (func (param i32 i32) (result i32) local.get 0 local.get 1 i32.lt_s)
+    let wasm = build_wasm(&[(2, 1)], &[(0, 0, &[0x20, 0, 0x20, 1, 0x48])], &[("f", 0)]);
+    assert!(matches!(translate(&wasm), Err(WasmError::Unsupported(_))));
+
+    // unknown entrypoint
+    let wasm = build_wasm(&[(0, 0)], &[(0, 0, &[])], &[("f", 0)]);
+    let err = compile_program(&Assembler::default(), &wasm, "g").unwrap_err();
+    assert_eq!(err, WasmError::UnknownEntrypoint("g".into()));
+
+    // blocks nested deeper than the limit: (func block block ... end end)
+    let nest = |depth: usize| {
+        let mut body = [0x02, 0x40].repeat(depth);
+        body.extend(vec![0x0b; depth]);
+        build_wasm(&[(0, 0)], &[(0, 0, &body)], &[("f", 0)])
+    };
+    assert!(translate(&nest(128)).is_ok());
+    assert!(matches!(translate(&nest(129)), Err(WasmError::Unsupported(_))));
+
+    // zero-initializing locals takes more instructions than fit into a procedure body:
+    // (func (param i32) (local ...))
+    let wasm = build_wasm(&[(1, 0)], &[(0, 32767, &[])], &[("f", 0)]);
+    assert!(translate(&wasm).is_ok());
+    let wasm = build_wasm(&[(1, 0)], &[(0, 32768, &[])], &[("f", 0)]);
+    assert!(matches!(translate(&wasm), Err(WasmError::Unsupported(_))));
+
+    // more locals than a procedure can have, counting the params: (func (param i32) (local ...))
+    let wasm = build_wasm(&[(1, 0)], &[(0, 65535, &[])], &[("f", 0)]);
+    assert!(matches!(translate(&wasm), Err(WasmError::Unsupported(_))));
+    let wasm = build_wasm(&[(0, 0)], &[(0, u32::MAX, &[])], &[("f", 0)]);
+    assert!(matches!(translate(&wasm), Err(WasmError::Unsupported(_))));
+}
+
+#[test]
+fn translate_long_call_chain() {
+    // each function calls the next one: (func call i+1), with the last function being empty
+    const NUM_FUNCS: usize = 65_535;
+    let bodies = (1..NUM_FUNCS)
+        .map(|callee| {
+            let mut body = vec![0x10];
+            push_len(&mut body, callee);
+            body
+        })
+        .chain(core::iter::once(Vec::new()))
+        .collect::<Vec<_>>();
+    let funcs = bodies.iter().map(|body| (0, 0, body.as_slice())).collect::<Vec<_>>();
+    let wasm = build_wasm(&[(0, 0)], &funcs, &[("f", 0)]);
+
+    let translation = translate(&wasm).unwrap();
+    assert_eq!(translation.procs().len(), NUM_FUNCS);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Asserts that the program translated from the provided WebAssembly binary is identical to the
+/// program compiled from the expected Miden assembly source.
+fn assert_translation(wasm: &[u8], entrypoint: &str, expected: &str) {
+    let assembler = Assembler::default();
+    let program = compile_program(&assembler, wasm, entrypoint).unwrap();
+    let expected = assembler.compile(expected).unwrap();
+    assert_eq!(program.hash(), expected.hash());
+}
+
+/// Builds a WebAssembly binary from the provided function types (number of `i32` params and
+/// results), functions (type index, number of `i32` locals and body without the final `end`),
+/// and function exports.
+fn build_wasm(types: &[(u8, u8)], funcs: &[(u8, u32, &[u8])], exports: &[(&str, u8)]) -> Vec<u8> {
+    let mut wasm = b"\0asm\x01\0\0\0".to_vec();
+
+    let mut section = vec![types.len() as u8];
+    for (num_params, num_results) in types {
+        section.push(0x60);
+        section.push(*num_params);
+        section.extend((0..*num_params).map(|_| 0x7f));
+        section.push(*num_results);
+        section.extend((0..*num_results).map(|_| 0x7f));
+    }
+    push_section(&mut wasm, 1, section);
+
+    let mut section = Vec::new();
+    push_len(&mut section, funcs.len());
+    section.extend(funcs.iter().map(|(type_idx, ..)| *type_idx));
+    push_section(&mut wasm, 3, section);
+
+    let mut section = vec![exports.len() as u8];
+    for (name, func_idx) in exports {
+        section.push(name.len() as u8);
+        section.extend(name.bytes());
+        section.extend([0x00, *func_idx]);
+    }
+    push_section(&mut wasm, 7, section);
+
+    let mut section = Vec::new();
+    push_len(&mut section, funcs.len());
+    for (_, num_locals, body) in funcs {
+        let mut func = if *num_locals == 0 {
+            vec![0]
+        } else {
+            let mut locals = vec![1];
+            push_len(&mut locals, *num_locals as usize);
+            locals.push(0x7f);
+            locals
+        };
+        func.extend_from_slice(body);
+        func.push(0x0b);
+        push_len(&mut section, func.len());
+        section.extend(func);
+    }
+    push_section(&mut wasm, 10, section);
+
+    wasm
+}
+
+fn push_section(wasm: &mut Vec<u8>, id: u8, section: Vec<u8>) {
+    wasm.push(id);
+    push_len(wasm, section.len());
+    wasm.extend(section);
+}
+
+/// Appends the LEB128 encoding of the provided length.
+fn push_len(bytes: &mut Vec<u8>, mut len: usize) {
+    while len >= 0x80 {
+        bytes.push((len & 0x7f) as u8 | 0x80);
+        len >>= 7;
+    }
+    bytes.push(len as u8);
+}
@@ -0,0 +1,235 @@
+use super::{
+    parser::{nesting_too_deep, Function, Op, MAX_NESTING_DEPTH},
+    WasmError,
+};
+use alloc::vec::Vec;
+use assembly::ast::{CodeBody, Instruction, Node};
+use vm_core::ZERO;
+
+// FUNCTION TRANSLATOR
+// ================================================================================================
+
+/// Translates the body of a WebAssembly function into a list of Miden assembly nodes.
+///
+/// Function parameters and local variables are mapped onto procedure locals with the same
+/// indexes. On entry, parameters are moved from the stack into the procedure locals and all other
+/// locals are initialized to zero. `proc_indexes` maps indexes of WebAssembly functions to
+/// indexes of the procedures they were translated into.
+///
+/// # Errors
+/// Returns an error if the function uses instructions or control flow constructs outside of the
+/// supported subset.
+pub fn translate_function(func: &Function, proc_indexes: &[u16]) -> Result<Vec<Node>, WasmError> {
+    let translator = FunctionTranslator { proc_indexes };
+    let mut nodes = Vec::new();
+
+    // move parameters from the stack into procedure locals; the last parameter is at the top of
+    // the stack
+    for param_idx in (0..func.num_params).rev() {
+        nodes.push(Node::Instruction(Instruction::LocStore(local_index(param_idx)?)));
+    }
+
+    // WebAssembly requires non-parameter locals to be initialized to zero
+    for local_idx in func.num_params..func.num_params + func.num_locals {
+        nodes.push(Node::Instruction(Instruction::PushU8(0)));
+        nodes.push(Node::Instruction(Instruction::LocStore(local_index(local_idx)?)));
+    }
+
+    // a `return` is allowed only as the last instruction of the function
+    let body = match func.body.split_last() {
+        Some((Op::Return, body)) => body,
+        _ => &func.body,
+    };
+    translator.translate_ops(body, &mut nodes, 0)?;
+    check_body_len(&nodes)?;
+
+    Ok(nodes)
+}
+
+/// Returns the indexes of WebAssembly functions invoked directly from the provided function.
+pub fn callees(func: &Function) -> Vec<u32> {
+    fn visit(ops: &[Op], callees: &mut Vec<u32>) {
+        for op in ops {
+            match op {
+                Op::Call(func_idx) => callees.push(*func_idx),
+                Op::Block(body) | Op::Loop(body) => visit(body, callees),
+                Op::If(true_case, false_case) => {
+                    visit(true_case, callees);
+                    visit(false_case, callees);
+                }
+                _ => (),
+            }
+        }
+    }
+
+    let mut callees = Vec::new();
+    visit(&func.body, &mut callees);
+    callees
+}
+
+struct FunctionTranslator<'a> {
+    proc_indexes: &'a [u16],
+}
+
+impl FunctionTranslator<'_> {
+    /// Translates instructions nested in the specified number of blocks.
+    fn translate_ops(
+        &self,
+        ops: &[Op],
+        nodes: &mut Vec<Node>,
+        depth: usize,
+    ) -> Result<(), WasmError> {
+        if depth > MAX_NESTING_DEPTH {
+            return Err(nesting_too_deep());
+        }
+        ops.iter().try_for_each(|op| self.translate_op(op, nodes, depth))
+    }
+
+    fn translate_op(&self, op: &Op, nodes: &mut Vec<Node>, depth: usize) -> Result<(), WasmError> {
+        use Instruction::*;
+
+        let instructions = match op {
+            // ----- control flow -----------------------------------------------------------------
+            Op::Block(body) => return self.translate_ops(body, nodes, depth + 1),
+            Op::Loop(body) => return self.translate_loop(body, nodes, depth + 1),
+            Op::If(true_case, false_case) => {
+                nodes.push(Node::Instruction(NeqImm(ZERO)));
+                nodes.push(Node::IfElse {
+                    true_case: self.translate_body(true_case, depth + 1)?,
+                    false_case: self.translate_body(false_case, depth + 1)?,
+                });
+                return Ok(());
+            }
+            Op::Br(_) | Op::BrIf(_) => {
+                return Err(WasmError::unsupported(
+                    "branch instructions other than `br_if 0` at the end of a loop",
+                ))
+            }
+            Op::Return => {
+                return Err(WasmError::unsupported("`return` before the end of a function"))
+            }
+            Op::Call(func_idx) => {
+                let proc_idx = self.proc_indexes.get(*func_idx as usize).ok_or_else(|| {
+                    WasmError::invalid_binary(&format!("function index {func_idx} out of bounds"))
+                })?;
+                nodes.push(Node::Instruction(ExecLocal(*proc_idx)));
+                return Ok(());
+            }
+            Op::Unreachable => vec![PushU8(0), Assert],
+            Op::Nop => vec![],
+
+            // ----- parametric and variable instructions -----------------------------------------
+            Op::Drop => vec![Drop],
+            Op::Select => vec![EqImm(ZERO), CDrop],
+            Op::LocalGet(idx) => vec![LocLoad(local_index(*idx)?)],
+            Op::LocalSet(idx) => vec![LocStore(local_index(*idx)?)],
+            Op::LocalTee(idx) => vec![Dup0, LocStore(local_index(*idx)?)],
+
+            // ----- memory instructions ----------------------------------------------------------
+            Op::I32Load(offset) => {
+                self.translate_address(*offset, nodes);
+                vec![MemLoad]
+            }
+            Op::I32Store(offset) => {
+                nodes.push(Node::Instruction(Swap1));
+                self.translate_address(*offset, nodes);
+                vec![MemStore]
+            }
+
+            // ----- numeric instructions ---------------------------------------------------------
+            Op::I32Const(value) => vec![PushU32(*value as u32)],
+            Op::I32Eqz => vec![EqImm(ZERO)],
+            Op::I32Eq => vec![Eq],
+            Op::I32Ne => vec![Neq],
+            Op::I32LtU => vec![U32Lt],
+            Op::I32GtU => vec![U32Gt],
+            Op::I32LeU => vec![U32Lte],
+            Op::I32GeU => vec![U32Gte],
+            Op::I32Clz => vec![U32Clz],
+            Op::I32Ctz => vec![U32Ctz],
+            Op::I32Popcnt => vec![U32Popcnt],
+            Op::I32Add => vec![U32WrappingAdd],
+            Op::I32Sub => vec![U32WrappingSub],
+            Op::I32Mul => vec![U32WrappingMul],
+            Op::I32DivU => vec![U32Div],
+            Op::I32RemU => vec![U32Mod],
+            Op::I32And => vec![U32And],
+            Op::I32Or => vec![U32Or],
+            Op::I32Xor => vec![U32Xor],
+            // WebAssembly takes the shift amount modulo 32
+            Op::I32Shl => vec![PushU8(31), U32And, U32Shl],
+            Op::I32ShrU => vec![PushU8(31), U32And, U32Shr],
+            Op::I32Rotl => vec![PushU8(31), U32And, U32Rotl],
+            Op::I32Rotr => vec![PushU8(31), U32And, U32Rotr],
+        };
+
+        nodes.extend(instructions.into_iter().map(Node::Instruction));
+        Ok(())
+    }
+
+    fn translate_body(&self, ops: &[Op], depth: usize) -> Result<CodeBody, WasmError> {
+        let mut nodes = Vec::new();
+        self.translate_ops(ops, &mut nodes, depth)?;
+        check_body_len(&nodes)?;
+        Ok(CodeBody::new(nodes))
+    }
+
+    /// Translates a WebAssembly loop.
+    ///
+    /// A loop ending with `br_if 0` is a do-while loop: it is translated into a `while.true` loop
+    /// which is always entered and which is repeated while the condition of the `br_if` is
+    /// non-zero. A loop which does not branch back to its start is executed exactly once.
+    fn translate_loop(
+        &self,
+        body: &[Op],
+        nodes: &mut Vec<Node>,
+        depth: usize,
+    ) -> Result<(), WasmError> {
+        match body.split_last() {
+            Some((Op::BrIf(0), body)) => {
+                let mut loop_nodes = Vec::new();
+                self.translate_ops(body, &mut loop_nodes, depth)?;
+                loop_nodes.push(Node::Instruction(Instruction::NeqImm(ZERO)));
+                check_body_len(&loop_nodes)?;
+
+                nodes.push(Node::Instruction(Instruction::PushU8(1)));
+                nodes.push(Node::While {
+                    body: CodeBody::new(loop_nodes),
+                });
+                Ok(())
+            }
+            _ => self.translate_ops(body, nodes, depth),
+        }
+    }
+
+    /// Converts the byte address at the top of the stack into the address of a memory element.
+    ///
+    /// Each 4-byte word of the WebAssembly linear memory is mapped to a single memory element;
+    /// thus, accesses must be 4-byte aligned. Overflowing the address space with the static
+    /// offset or accessing an unaligned address results in a failed assertion.
+    fn translate_address(&self, offset: u32, nodes: &mut Vec<Node>) {
+        use Instruction::*;
+
+        if offset != 0 {
+            nodes.push(Node::Instruction(U32OverflowingAddImm(offset)));
+            nodes.push(Node::Instruction(Assertz));
+        }
+        nodes.push(Node::Instruction(U32DivModImm(4)));
+        nodes.push(Node::Instruction(Assertz));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns an error if the provided nodes do not fit into a single body of Miden assembly code.
+fn check_body_len(nodes: &[Node]) -> Result<(), WasmError> {
+    if nodes.len() > u16::MAX as usize {
+        return Err(WasmError::unsupported("code blocks of more than 65535 instructions"));
+    }
+    Ok(())
+}
+
+fn local_index(idx: u32) -> Result<u16, WasmError> {
+    u16::try_from(idx).map_err(|_| WasmError::unsupported("more than 65535 locals"))
+}